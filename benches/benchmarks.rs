@@ -1,6 +1,13 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use xrpl::core::binarycodec::definitions::get_field_type_name;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use serde_json::{json, Value};
+use xrpl::core::binarycodec::definitions::{get_field_instance, get_field_type_name};
+use xrpl::core::binarycodec::types::STArray;
+use xrpl::core::binarycodec::{decode, encode, BinarySerializer, Serialization};
+use xrpl::core::ledger::hash_signed_tx;
+use xrpl::models::transactions::payment::Payment;
+use xrpl::transaction::{sign, sign_batch, SignedBlob};
 use xrpl::utils::xrp_to_drops;
+use xrpl::wallet::Wallet;
 
 pub fn bench_xrp_to_drops(c: &mut Criterion) {
     c.bench_function("utils::xrp_to_drops", |b| {
@@ -14,5 +21,176 @@ pub fn bench_get_field_type_name(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_xrp_to_drops, bench_get_field_type_name);
+pub fn bench_write_field_header(c: &mut Criterion) {
+    // One header of each width: 1 byte, 2 bytes and 3 bytes.
+    let fields = ["Account", "OfferSequence", "TickSize"]
+        .map(|name| get_field_instance(name).expect("bench_write_field_header"));
+
+    c.bench_function("core::binarycodec::write_field_and_value", |b| {
+        b.iter_batched(
+            || fields,
+            |fields| {
+                let mut serializer = BinarySerializer::new();
+                for field in fields {
+                    serializer.write_field_and_value(field, black_box(&[0; 4]), false);
+                }
+                serializer
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+pub fn bench_encode(c: &mut Criterion) {
+    let tx: Value = json!({
+        "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+        "Amount": {
+            "currency": "USD",
+            "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "value": "1234.5678"
+        },
+        "Destination": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+        "Fee": "12",
+        "Flags": 2147483648u32,
+        "LastLedgerSequence": 8820051,
+        "Sequence": 359,
+        "SigningPubKey": "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020",
+        "TransactionType": "Payment",
+    });
+
+    c.bench_function("core::binarycodec::encode", |b| {
+        b.iter(|| encode(black_box(&tx)))
+    });
+}
+
+pub fn bench_encode_20_fields(c: &mut Criterion) {
+    let tx: Value = json!({
+        "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+        "AccountTxnID": "6F1DFD1D0FE8A32E40E1F2C05CF1C15545BAB56B617F9C6C2D63A6B704BEF59B",
+        "Amount": {
+            "currency": "USD",
+            "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "value": "1234.5678"
+        },
+        "DeliverMin": {
+            "currency": "USD",
+            "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "value": "1000"
+        },
+        "Destination": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+        "DestinationTag": 13,
+        "Fee": "12",
+        "Flags": 2147614720u32,
+        "InvoiceID": "6F1DFD1D0FE8A32E40E1F2C05CF1C15545BAB56B617F9C6C2D63A6B704BEF59B",
+        "LastLedgerSequence": 8820051,
+        "Memos": [{ "Memo": { "MemoData": "72656E74", "MemoType": "687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963" } }],
+        "NetworkID": 1025,
+        "Paths": [[{ "account": "rPDXxSZcuVL3ZWoyU82bcde3zwvmShkRyF" }]],
+        "SendMax": "2000000",
+        "Sequence": 359,
+        "SigningPubKey": "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020",
+        "SourceTag": 7,
+        "TicketSequence": 10,
+        "TransactionType": "Payment",
+        "TxnSignature": "3045022100D64A32A506B86E880480CCB846EFA3F9665C9B11FDCA35D7124F53C486CC1D0402206EC8663308D91C928D1FDA3C8A6D4F6F3B0BD75ABC6C6C9A4E9D4A54A6D5D2B3",
+    });
+
+    c.bench_function("core::binarycodec::encode_20_fields", |b| {
+        b.iter(|| encode(black_box(&tx)))
+    });
+}
+
+pub fn bench_st_array(c: &mut Criterion) {
+    let memos: Value = (0..50)
+        .map(|index| json!({ "Memo": { "MemoData": format!("{index:08X}") } }))
+        .collect();
+
+    c.bench_function("core::binarycodec::types::STArray::try_from_value", |b| {
+        b.iter_batched(
+            || memos.clone(),
+            |memos| STArray::try_from_value(black_box(memos)),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+pub fn bench_decode_100kb(c: &mut Criterion) {
+    // 100 memos of 1 KB each, about 100 KB once encoded.
+    let memos: Vec<Value> = (0..100)
+        .map(|index| json!({ "Memo": { "MemoData": format!("{index:02X}").repeat(1000) } }))
+        .collect();
+    let blob = encode(&json!({
+        "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+        "Fee": "12",
+        "Memos": memos,
+        "Sequence": 359,
+        "TransactionType": "AccountSet",
+    }))
+    .expect("bench_decode_100kb");
+
+    c.bench_function("core::binarycodec::decode_100kb", |b| {
+        b.iter(|| decode(black_box(&blob)))
+    });
+}
+
+pub fn bench_sign_batch(c: &mut Criterion) {
+    let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).expect("bench_sign_batch");
+    let payments: Vec<Payment> = (0..1000)
+        .map(|index| {
+            serde_json::from_value(json!({
+                "TransactionType": "Payment",
+                "Account": wallet.classic_address,
+                "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+                "Amount": (1_000_000 + index).to_string(),
+                "Fee": "12"
+            }))
+            .expect("bench_sign_batch")
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("transaction::sign_batch_1000");
+    group.sample_size(10);
+    group.bench_function("sign_batch", |b| {
+        b.iter_batched(
+            || payments.clone(),
+            |payments| sign_batch(payments, black_box(&wallet), 5, 1000).unwrap(),
+            BatchSize::LargeInput,
+        )
+    });
+    // What `sign_batch` saves: the key is derived again for each
+    // Payment.
+    group.bench_function("sign", |b| {
+        b.iter_batched(
+            || payments.clone(),
+            |payments| {
+                (5..)
+                    .zip(payments)
+                    .map(|(sequence, mut payment)| {
+                        payment.common_fields.sequence = Some(sequence);
+                        payment.common_fields.last_ledger_sequence = Some(1000);
+                        sign(&mut payment, black_box(&wallet), false).unwrap();
+                        let tx_blob = encode(&payment).unwrap();
+                        let hash = hash_signed_tx(&tx_blob).unwrap();
+
+                        SignedBlob { tx_blob, hash }
+                    })
+                    .collect::<Vec<_>>()
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_xrp_to_drops,
+    bench_get_field_type_name,
+    bench_write_field_header,
+    bench_encode,
+    bench_encode_20_fields,
+    bench_st_array,
+    bench_decode_100kb,
+    bench_sign_batch
+);
 criterion_main!(benches);
@@ -0,0 +1,92 @@
+//! Fetch golden transaction cases for `tests/golden_ledger.rs`.
+//!
+//! Usage: `fetch_golden <out dir> <name>=<hash>...`
+//!
+//! Writes `<out dir>/<name>/` with the `tx_blob.hex`, `tx.json`,
+//! `meta_blob.hex` and `meta.json` of each validated transaction,
+//! exactly as the server returns them. Pick transactions the codec is
+//! likely to get wrong: pseudo-transactions of flag ledgers
+//! (EnableAmendment, SetFee, UNLModify), NFToken mints, burns and
+//! offers, partial payments, and transactions with UInt64 or Hash256
+//! fields.
+use std::{env, fs, path::Path};
+
+use serde_json::{json, Map, Value};
+use xrpl::clients::{json_rpc::JsonRpcClient, XRPLSyncClient};
+
+fn request_tx(client: &JsonRpcClient, hash: &str, binary: bool) -> Map<String, Value> {
+    let response = client
+        .request_raw("tx", json!({ "transaction": hash, "binary": binary }))
+        .unwrap();
+    let result: Value = response.result.expect("no result").try_into().unwrap();
+    let Value::Object(result) = result else {
+        panic!("{hash}: unexpected result {result}");
+    };
+    assert_eq!(
+        result.get("validated"),
+        Some(&Value::Bool(true)),
+        "{hash}: not validated"
+    );
+
+    result
+}
+
+/// The transaction fields of a `tx` result, in the shape of either
+/// API version.
+fn tx_json(result: &Map<String, Value>) -> Map<String, Value> {
+    let fields = match result.get("tx_json") {
+        Some(Value::Object(tx_json)) => tx_json.clone(),
+        _ => result.clone(),
+    };
+    let mut tx_json: Map<String, Value> = fields
+        .into_iter()
+        .filter(|(field, _)| field.starts_with(|c: char| c.is_ascii_uppercase()))
+        .collect();
+    // `DeliverMax` is the JSON alias of a Payment's `Amount`.
+    if let Some(deliver_max) = tx_json.remove("DeliverMax") {
+        tx_json.insert("Amount".into(), deliver_max);
+    }
+    tx_json.insert("hash".into(), result["hash"].clone());
+
+    tx_json
+}
+
+fn blob<'a>(result: &'a Map<String, Value>, fields: [&str; 2]) -> &'a str {
+    fields
+        .iter()
+        .find_map(|field| result.get(*field).and_then(Value::as_str))
+        .unwrap_or_else(|| panic!("no {} in {result:?}", fields[0]))
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let out_dir = args
+        .next()
+        .expect("usage: fetch_golden <out dir> <name>=<hash>...");
+    let client = JsonRpcClient::connect("https://xrplcluster.com/".parse().unwrap());
+    for case in args {
+        let (name, hash) = case.split_once('=').expect("expected <name>=<hash>");
+        let binary = request_tx(&client, hash, true);
+        let json = request_tx(&client, hash, false);
+
+        let dir = Path::new(&out_dir).join(name);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("tx_blob.hex"), blob(&binary, ["tx_blob", "tx"])).unwrap();
+        fs::write(
+            dir.join("meta_blob.hex"),
+            blob(&binary, ["meta_blob", "meta"]),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("tx.json"),
+            serde_json::to_string_pretty(&tx_json(&json)).unwrap(),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("meta.json"),
+            serde_json::to_string_pretty(&json["meta"]).unwrap(),
+        )
+        .unwrap();
+        println!("{name}: {}", dir.display());
+    }
+}
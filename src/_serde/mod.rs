@@ -1,13 +1,12 @@
 //! Serde functionalities
 
 use crate::models::FlagCollection;
-use alloc::format;
-use alloc::vec::Vec;
+use alloc::{format, string::String};
+use core::fmt::Debug;
 use core::hash::BuildHasherDefault;
-use core::{convert::TryFrom, fmt::Debug};
 use fnv::FnvHasher;
-use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
-use serde_json::Value;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
 use strum::IntoEnumIterator;
 
 pub type HashMap<K, V> = hashbrown::HashMap<K, V, BuildHasherDefault<FnvHasher>>;
@@ -17,23 +16,7 @@ where
     F: Serialize + IntoEnumIterator,
     S: Serializer,
 {
-    let flags_value_result: Result<Value, serde_json::Error> = serde_json::to_value(flags);
-    match flags_value_result {
-        Ok(flags_as_value) => {
-            let flag_vec_result: Result<Vec<u32>, serde_json::Error> =
-                serde_json::from_value(flags_as_value);
-            match flag_vec_result {
-                Ok(flags_vec) => s.serialize_u32(flags_vec.iter().sum()),
-                Err(_) => {
-                    // TODO: Find a way to use custom errors
-                    Err(ser::Error::custom("SerdeIntermediateStepError: Failed to turn flags into `Vec<u32>` during serialization"))
-                }
-            }
-        }
-        Err(_) => Err(ser::Error::custom(
-            "SerdeIntermediateStepError: Failed to turn flags into `Value` during serialization",
-        )),
-    }
+    s.serialize_u32(flags.bits())
 }
 
 fn deserialize_flags<'de, D, F>(d: D) -> Result<FlagCollection<F>, D::Error>
@@ -41,13 +24,8 @@ where
     F: Serialize + IntoEnumIterator + Debug,
     D: Deserializer<'de>,
 {
-    let flags_u32 = u32::deserialize(d)?;
-    FlagCollection::<F>::try_from(flags_u32).map_err(|_e| {
-        de::Error::custom(format!(
-            "SerdeIntermediateStepError: Failed to turn `u32` into `FlagCollection<{}>` during deserialization",
-            core::any::type_name::<F>()
-        ))
-    })
+    // Flags unknown to this release are dropped.
+    FlagCollection::<F>::deserialize(d)
 }
 
 /// A `mod` to be used on transaction `flags` fields. It serializes the `Vec<Flag>` into a `u32`,
@@ -67,7 +45,7 @@ pub(crate) mod txn_flags {
         F: Serialize + IntoEnumIterator + Debug,
         S: Serializer,
     {
-        if flags.0.is_empty() {
+        if flags.is_empty() {
             s.serialize_u32(0)
         } else {
             serialize_flag(flags, s)
@@ -82,7 +60,7 @@ pub(crate) mod txn_flags {
         let flags_vec_result: Result<FlagCollection<F>, D::Error> = deserialize_flags(d);
         match flags_vec_result {
             Ok(flags_vec) => {
-                if flags_vec.0.is_empty() {
+                if flags_vec.is_empty() {
                     Ok(FlagCollection::<F>::default())
                 } else {
                     Ok(flags_vec)
@@ -106,7 +84,7 @@ pub(crate) mod lgr_obj_flags {
         F: Serialize + IntoEnumIterator,
         S: Serializer,
     {
-        if !flags.0.is_empty() {
+        if !flags.is_empty() {
             serialize_flag(flags, s)
         } else {
             s.serialize_u32(0)
@@ -122,15 +100,45 @@ pub(crate) mod lgr_obj_flags {
     }
 }
 
+/// Deserialize the fields of the model `name`, tagged with its name,
+/// `{"Name": {...}}`, as rippled returns them, or bare, `{...}`, as
+/// models are often written by hand. `B` reads the bare fields and
+/// should deny unknown fields, so that a wrong wrapper is not read as
+/// a model without fields.
+#[doc(hidden)]
+pub fn deserialize_with_tag<'de, D, T, B>(deserializer: D, name: &str) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+    B: DeserializeOwned + Into<T>,
+{
+    let mut map = Map::<String, Value>::deserialize(deserializer)?;
+    let tagged = if map.len() == 1 {
+        map.remove(name)
+    } else {
+        None
+    };
+    let fields = match tagged {
+        Some(fields) => serde_json::from_value(fields),
+        None => serde_json::from_value::<B>(Value::Object(map)).map(Into::into),
+    };
+
+    fields.map_err(|error| {
+        serde::de::Error::custom(format!(
+            "expected a `{name}` as `{{\"{name}\": {{...}}}}` or as its fields: {error}"
+        ))
+    })
+}
+
 /// A macro to tag a struct externally. With `serde` attributes, unfortunately it is not possible to
 /// serialize a struct to json with its name as `key` and its fields as `value`. Example:
 /// `{"Example":{"Field1":"hello","Field2":"world"}}`
 ///
 /// Several models need to be serialized in that format. This macro uses a helper to serialize and
-/// deserialize to/from that format.
+/// deserialize to/from that format. The fields are also read without the tag, as other libraries
+/// and hand-written JSON often give them, but always written with it.
 ///
 /// Resource: https://github.com/serde-rs/serde/issues/554#issuecomment-249211775
-// TODO: Find a way to `#[skip_serializing_none]`
 #[macro_export]
 macro_rules! serde_with_tag {
     (
@@ -155,9 +163,9 @@ macro_rules! serde_with_tag {
             where
                 S: ::serde::Serializer
             {
+                #[::serde_with::skip_serializing_none]
                 #[derive(::serde::Serialize)]
                 #[serde(rename_all = "PascalCase")]
-                #[::serde_with::skip_serializing_none]
                 struct Helper<$lt> {
                     $(
                         $field: $ty,
@@ -177,7 +185,7 @@ macro_rules! serde_with_tag {
             }
         }
 
-        impl<'de: $lt, $lt> ::serde::Deserialize<'de> for $name<$lt> {
+        impl<'de, $lt> ::serde::Deserialize<'de> for $name<$lt> {
             #[allow(non_snake_case)]
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
@@ -185,28 +193,68 @@ macro_rules! serde_with_tag {
             {
                 #[derive(::serde::Deserialize)]
                 #[serde(rename_all = "PascalCase")]
-                #[::serde_with::skip_serializing_none]
                 struct Helper<$lt> {
                     $(
                         $field: $ty,
                     )*
                 }
 
-                let hash_map: $crate::_serde::HashMap<&$lt str, Helper<$lt>> = $crate::_serde::HashMap::deserialize(deserializer)?;
-                let helper_result = hash_map.get(stringify!($name));
+                #[derive(::serde::Deserialize)]
+                #[serde(rename_all = "PascalCase", deny_unknown_fields)]
+                struct BareHelper<$lt> {
+                    $(
+                        $field: $ty,
+                    )*
+                }
 
-                match helper_result {
-                    Some(helper) => {
-                        Ok(Self {
+                impl<$lt> From<BareHelper<$lt>> for Helper<$lt> {
+                    fn from(helper: BareHelper<$lt>) -> Self {
+                        Self {
                             $(
-                                $field: helper.$field.clone().into(),
+                                $field: helper.$field,
                             )*
-                        })
-                    }
-                    None => {
-                        Err(::serde::de::Error::custom("SerdeIntermediateStepError: Unable to find model name as json key."))
+                        }
                     }
                 }
+
+                let helper: Helper<$lt> = $crate::_serde::deserialize_with_tag::<D, Helper<$lt>, BareHelper<$lt>>(
+                    deserializer,
+                    stringify!($name),
+                )?;
+
+                Ok(Self {
+                    $(
+                        $field: helper.$field,
+                    )*
+                })
+            }
+        }
+
+        #[cfg(feature = "json-schema")]
+        impl<$lt> ::schemars::JsonSchema for $name<$lt> {
+            fn schema_name() -> ::alloc::borrow::Cow<'static, str> {
+                stringify!($name).into()
+            }
+
+            fn json_schema(generator: &mut ::schemars::SchemaGenerator) -> ::schemars::Schema {
+                #[allow(dead_code)]
+                #[::serde_with::skip_serializing_none]
+                #[derive(::schemars::JsonSchema)]
+                #[schemars(rename_all = "PascalCase")]
+                struct Helper<$lt> {
+                    $(
+                        $(#[$doc])*
+                        $field: $ty,
+                    )*
+                }
+
+                ::schemars::json_schema!({
+                    "type": "object",
+                    "properties": {
+                        stringify!($name): <Helper as ::schemars::JsonSchema>::json_schema(generator),
+                    },
+                    "required": [stringify!($name)],
+                })
             }
         }
     };
@@ -232,9 +280,9 @@ macro_rules! serde_with_tag {
             where
                 S: ::serde::Serializer
             {
+                #[::serde_with::skip_serializing_none]
                 #[derive(::serde::Serialize)]
                 #[serde(rename_all = "PascalCase")]
-                #[::serde_with::skip_serializing_none]
                 struct Helper {
                     $(
                         $field: $ty,
@@ -262,28 +310,68 @@ macro_rules! serde_with_tag {
             {
                 #[derive(::serde::Deserialize)]
                 #[serde(rename_all = "PascalCase")]
-                #[::serde_with::skip_serializing_none]
                 struct Helper {
                     $(
                         $field: $ty,
                     )*
                 }
 
-                let hash_map: $crate::_serde::HashMap<&'de str, Helper> = $crate::_serde::HashMap::deserialize(deserializer)?;
-                let helper_result = hash_map.get(stringify!($name));
+                #[derive(::serde::Deserialize)]
+                #[serde(rename_all = "PascalCase", deny_unknown_fields)]
+                struct BareHelper {
+                    $(
+                        $field: $ty,
+                    )*
+                }
 
-                match helper_result {
-                    Some(helper) => {
-                        Ok(Self {
+                impl From<BareHelper> for Helper {
+                    fn from(helper: BareHelper) -> Self {
+                        Self {
                             $(
-                                $field: helper.$field.clone().into(),
+                                $field: helper.$field,
                             )*
-                        })
-                    }
-                    None => {
-                        Err(::serde::de::Error::custom("SerdeIntermediateStepError: Unable to find model name as json key."))
+                        }
                     }
                 }
+
+                let helper: Helper = $crate::_serde::deserialize_with_tag::<D, Helper, BareHelper>(
+                    deserializer,
+                    stringify!($name),
+                )?;
+
+                Ok(Self {
+                    $(
+                        $field: helper.$field,
+                    )*
+                })
+            }
+        }
+
+        #[cfg(feature = "json-schema")]
+        impl ::schemars::JsonSchema for $name {
+            fn schema_name() -> ::alloc::borrow::Cow<'static, str> {
+                stringify!($name).into()
+            }
+
+            fn json_schema(generator: &mut ::schemars::SchemaGenerator) -> ::schemars::Schema {
+                #[allow(dead_code)]
+                #[::serde_with::skip_serializing_none]
+                #[derive(::schemars::JsonSchema)]
+                #[schemars(rename_all = "PascalCase")]
+                struct Helper {
+                    $(
+                        $(#[$doc])*
+                        $field: $ty,
+                    )*
+                }
+
+                ::schemars::json_schema!({
+                    "type": "object",
+                    "properties": {
+                        stringify!($name): <Helper as ::schemars::JsonSchema>::json_schema(generator),
+                    },
+                    "required": [stringify!($name)],
+                })
             }
         }
     };
@@ -0,0 +1,325 @@
+//! Helpers for the `tracing` spans and events of the clients,
+//! autofilling and signing.
+
+use alloc::{format, string::String};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Payloads in events are cut after this many bytes.
+pub(crate) const MAX_PAYLOAD_LEN: usize = 1024;
+
+/// Fields that carry key material, e.g. in `channel_authorize`,
+/// `sign`, `wallet_propose` or `validation_create`. Their values are
+/// never logged.
+const SECRET_FIELDS: [&str; 10] = [
+    "secret",
+    "seed",
+    "seed_hex",
+    "passphrase",
+    "master_seed",
+    "master_seed_hex",
+    "master_key",
+    "private_key",
+    "validation_key",
+    "validation_seed",
+];
+
+const REDACTED: &str = "[redacted]";
+
+/// `value` as JSON with the values of [`SECRET_FIELDS`] redacted, cut
+/// after [`MAX_PAYLOAD_LEN`] bytes.
+pub(crate) fn payload(value: &impl Serialize) -> String {
+    let mut json = match serde_json::to_value(value) {
+        Ok(mut value) => {
+            redact(&mut value);
+            serde_json::to_string(&value).unwrap_or_default()
+        }
+        Err(_) => String::new(),
+    };
+    if json.len() > MAX_PAYLOAD_LEN {
+        let len = json.len();
+        let mut end = MAX_PAYLOAD_LEN;
+        while !json.is_char_boundary(end) {
+            end -= 1;
+        }
+        json.truncate(end);
+        json.push_str(&format!("... ({len} bytes)"));
+    }
+
+    json
+}
+
+/// Replace the values of [`SECRET_FIELDS`] at any depth, so raw
+/// requests nesting them are covered too.
+fn redact(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (field, value) in map.iter_mut() {
+                if SECRET_FIELDS.contains(&field.as_str()) {
+                    *value = Value::from(REDACTED);
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+/// The type of the key of `public_key`. Events name the key type,
+/// never the key.
+pub(crate) fn key_type(public_key: &str) -> &'static str {
+    if public_key.starts_with("ED") {
+        "ed25519"
+    } else {
+        "secp256k1"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_payload_is_truncated() {
+        let short = payload(&"ledger_current");
+        assert_eq!(short, "\"ledger_current\"");
+
+        let long = payload(&"A".repeat(2 * MAX_PAYLOAD_LEN));
+        assert!(long.starts_with("\"AAA"));
+        assert!(long.ends_with(&format!("... ({} bytes)", 2 * MAX_PAYLOAD_LEN + 2)));
+        assert!(long.len() < MAX_PAYLOAD_LEN + 32);
+    }
+
+    #[test]
+    fn test_payload_is_redacted() {
+        let redacted = payload(&serde_json::json!({
+            "command": "channel_authorize",
+            "secret": "snoPBrXtMeMyMHUVTgbuqAfg1SUTb",
+            "params": [{ "seed_hex": "DEDCE9CE67B451D852FD4E846FCDE31C" }],
+        }));
+
+        assert!(!redacted.contains("snoPBrXtMeMyMHUVTgbuqAfg1SUTb"));
+        assert!(!redacted.contains("DEDCE9CE67B451D852FD4E846FCDE31C"));
+        assert!(redacted.contains("\"secret\":\"[redacted]\""));
+        assert!(redacted.contains("\"command\":\"channel_authorize\""));
+    }
+}
+
+#[cfg(all(test, feature = "std", feature = "helpers"))]
+mod test_instrumentation {
+    use alloc::{
+        string::ToString,
+        sync::{Arc, Mutex},
+        vec::Vec,
+    };
+    use core::{
+        fmt::Debug,
+        sync::atomic::{AtomicU64, Ordering},
+    };
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        Event, Metadata, Subscriber,
+    };
+
+    use super::*;
+    use crate::{
+        asynch::clients::{MockClient, MockResponse, XRPLAsyncClient},
+        models::{
+            requests::{
+                channel_authorize::ChannelAuthorize, ledger_current::LedgerCurrent, raw::Raw,
+                RequestMethod,
+            },
+            transactions::payment::Payment,
+        },
+        wallet::Wallet,
+    };
+
+    /// The spans and events sent to a subscriber, as their name or
+    /// level followed by their fields.
+    #[derive(Default)]
+    struct Records {
+        next_id: AtomicU64,
+        spans: Mutex<Vec<String>>,
+        events: Mutex<Vec<String>>,
+    }
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Records>);
+
+    struct Fields<'r>(&'r mut String);
+
+    impl Visit for Fields<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0.push_str(&format!(" {}={:?}", field.name(), value));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut recorded = span.metadata().name().to_string();
+            span.record(&mut Fields(&mut recorded));
+            self.0.spans.lock().unwrap().push(recorded);
+
+            Id::from_u64(self.0.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+        }
+
+        fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut recorded = event.metadata().level().to_string();
+            event.record(&mut Fields(&mut recorded));
+            self.0.events.lock().unwrap().push(recorded);
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    fn record(run: impl FnOnce()) -> Arc<Records> {
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), run);
+
+        recorder.0
+    }
+
+    #[test]
+    fn test_request_spans() {
+        let client = MockClient::new()
+            .with_response(
+                RequestMethod::LedgerCurrent,
+                MockResponse::result(serde_json::json!({ "ledger_current_index": 6 })),
+            )
+            .with_response(
+                RequestMethod::Fee,
+                MockResponse::error("noNetwork", 17, Some("Not synced to the network.")),
+            );
+        let recorder = record(|| {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            runtime.block_on(async {
+                let mut request = LedgerCurrent::new(None);
+                request.common_fields.id = Some("7".into());
+                client.request(request.into()).await.unwrap();
+                client
+                    .request(crate::models::requests::fee::Fee::new(None).into())
+                    .await
+                    .unwrap();
+            });
+        });
+
+        assert_eq!(
+            *recorder.spans.lock().unwrap(),
+            [
+                "request method=ledger_current id=\"7\"",
+                "request method=fee",
+            ]
+        );
+        let events = recorder.events.lock().unwrap();
+        assert!(events[0].starts_with("DEBUG message=sending request request="));
+        assert!(events[1].starts_with("DEBUG message=received response response="));
+        assert!(events[1].contains("ledger_current_index"));
+        assert_eq!(
+            events.last().unwrap(),
+            "ERROR message=request failed error=\"noNetwork\" error_code=17 error_message=\"Not synced to the network.\""
+        );
+    }
+
+    #[test]
+    fn test_request_events_have_no_key_material() {
+        const SECRET: &str = "snoPBrXtMeMyMHUVTgbuqAfg1SUTb";
+        const PASSPHRASE: &str = "masterpassphrase";
+        let client = MockClient::new().with_response(
+            RequestMethod::Other,
+            MockResponse::result(serde_json::json!({
+                "account_id": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                "master_seed": SECRET,
+                "master_seed_hex": "DEDCE9CE67B451D852FD4E846FCDE31C",
+                "public_key": "aBQG8RQAzjs1eTKFEAQXr2gS4utcDiEC9wmi7pfUPTi27VCahwgw"
+            })),
+        );
+        let recorder = record(|| {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            runtime.block_on(async {
+                // Refused before it is sent, even when opted in.
+                let channel_authorize = ChannelAuthorize::new(
+                    None,
+                    "5DB01B7FFED6B67E6B0414DED11E051D2EE2B7619CE0EAA6286D67A3A4D5BDB3".into(),
+                    "1000000".into(),
+                    Some(SECRET.into()),
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .allow_insecure_remote_signing();
+                assert!(client.request(channel_authorize.into()).await.is_err());
+
+                let mut params = serde_json::Map::new();
+                params.insert("passphrase".into(), PASSPHRASE.into());
+                let wallet_propose = Raw::new(None, "wallet_propose".into(), params);
+                client.request(wallet_propose.into()).await.unwrap();
+            });
+        });
+
+        let events = recorder.events.lock().unwrap();
+        assert!(events
+            .iter()
+            .any(|event| event.starts_with("DEBUG message=sending request")
+                && event.contains("[redacted]")));
+        assert!(events
+            .iter()
+            .any(|event| event.starts_with("DEBUG message=received response")
+                && event.contains("[redacted]")));
+        for event in events.iter() {
+            assert!(!event.contains(SECRET), "{event}");
+            assert!(!event.contains(PASSPHRASE), "{event}");
+            assert!(
+                !event.contains("DEDCE9CE67B451D852FD4E846FCDE31C"),
+                "{event}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_signing_events_have_no_key_material() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let payment: Payment = serde_json::from_value(serde_json::json!({
+            "TransactionType": "Payment",
+            "Account": wallet.classic_address,
+            "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+            "Amount": "1000000",
+            "Fee": "12",
+            "Sequence": 1
+        }))
+        .unwrap();
+        let mut hash = String::new();
+        let recorder = record(|| {
+            hash = wallet.sign(payment).unwrap().hash().unwrap();
+        });
+
+        let events = recorder.events.lock().unwrap();
+        assert_eq!(
+            *events,
+            [format!(
+                "DEBUG message=signed transaction hash=\"{hash}\" key_type=\"ed25519\""
+            )]
+        );
+        assert!(!events[0].contains(&wallet.private_key));
+        assert!(!events[0].contains(&wallet.public_key));
+    }
+}
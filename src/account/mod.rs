@@ -1,19 +1,41 @@
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, vec::Vec};
+use bigdecimal::BigDecimal;
 use embassy_futures::block_on;
 
 use crate::{
     asynch::{
         account::{
+            check_account_delete_blockers as async_check_account_delete_blockers,
             does_account_exist as async_does_account_exist,
-            get_account_root as async_get_account_root,
+            find_payment_paths as async_find_payment_paths,
+            get_account_channels as async_get_account_channels,
+            get_account_nfts as async_get_account_nfts, get_account_root as async_get_account_root,
+            get_issuer_obligations as async_get_issuer_obligations,
             get_latest_transaction as async_get_latest_transaction,
             get_next_valid_seq_number as async_get_next_valid_seq_number,
-            get_xrp_balance as async_get_xrp_balance,
+            get_nft_offers as async_get_nft_offers,
+            get_owner_reserve_requirement as async_get_owner_reserve_requirement,
+            get_signer_list as async_get_signer_list, get_xrp_balance as async_get_xrp_balance,
+            is_deposit_authorized as async_is_deposit_authorized,
+            iter_account_transactions as async_iter_account_transactions,
+            verify_payment_channel_claim as async_verify_payment_channel_claim,
+            AccountDeleteBlocker, AccountTransactionStream, NftOffersBySide, OwnerReserve,
         },
         clients::XRPLClient,
         exceptions::XRPLHelperResult,
     },
-    models::{ledger::objects::AccountRoot, results::account_tx::AccountTx, XRPAmount},
+    models::{
+        ledger::objects::{signer_list::SignerList, AccountRoot},
+        results::{
+            account_channels::Channel, account_nfts::AccountNft, account_tx::AccountTx,
+            ripple_path_find::PathAlternative,
+        },
+        Amount, XRPAmount,
+    },
+};
+
+pub use crate::asynch::account::{
+    can_meet_quorum, AccountTransaction, AccountTransactionsCheckpoint, AccountTransactionsOptions,
 };
 
 pub fn does_account_exist<C>(
@@ -64,6 +86,18 @@ where
     block_on(async_get_account_root(address, client, ledger_index))
 }
 
+/// The signer list of `address`. See
+/// [`crate::asynch::account::get_signer_list`].
+pub fn get_signer_list<'a: 'b, 'b, C>(
+    address: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<Option<SignerList<'b>>>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_signer_list(address, client))
+}
+
 pub fn get_latest_transaction<'a: 'b, 'b, C>(
     address: Cow<'a, str>,
     client: &C,
@@ -73,3 +107,145 @@ where
 {
     block_on(async_get_latest_transaction(address, client))
 }
+
+/// Iterate over the validated transactions of an account. See
+/// [`crate::asynch::account::iter_account_transactions`].
+pub fn iter_account_transactions<'a, C>(
+    client: &'a C,
+    address: Cow<'a, str>,
+    options: AccountTransactionsOptions<'a>,
+) -> AccountTransactionIter<'a, C>
+where
+    C: XRPLClient,
+{
+    AccountTransactionIter(async_iter_account_transactions(client, address, options))
+}
+
+/// The transactions of an account, see [`iter_account_transactions`].
+pub struct AccountTransactionIter<'a, C>(AccountTransactionStream<'a, C>);
+
+impl<C> AccountTransactionIter<'_, C>
+where
+    C: XRPLClient,
+{
+    /// Where the iterator is, see
+    /// [`AccountTransactionStream::checkpoint`].
+    pub fn checkpoint(&self) -> AccountTransactionsCheckpoint {
+        self.0.checkpoint()
+    }
+}
+
+impl<C> Iterator for AccountTransactionIter<'_, C>
+where
+    C: XRPLClient,
+{
+    type Item = XRPLHelperResult<AccountTransaction<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        block_on(self.0.next())
+    }
+}
+
+pub fn get_account_nfts<'a: 'b, 'b, C>(
+    address: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<Vec<AccountNft<'b>>>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_account_nfts(address, client))
+}
+
+pub fn get_nft_offers<'a: 'b, 'b, C>(
+    nft_id: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<NftOffersBySide<'b>>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_nft_offers(nft_id, client))
+}
+
+pub fn check_account_delete_blockers<'a: 'b, 'b, C>(
+    address: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<Vec<AccountDeleteBlocker<'b>>>
+where
+    C: XRPLClient,
+{
+    block_on(async_check_account_delete_blockers(address, client))
+}
+
+pub fn get_owner_reserve_requirement<'a: 'b, 'b, C>(
+    address: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<OwnerReserve<'b>>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_owner_reserve_requirement(address, client))
+}
+
+pub fn find_payment_paths<'a: 'b, 'b, C>(
+    client: &C,
+    source: Cow<'a, str>,
+    destination: Cow<'a, str>,
+    amount: Amount<'a>,
+) -> XRPLHelperResult<Vec<PathAlternative<'b>>>
+where
+    C: XRPLClient,
+{
+    block_on(async_find_payment_paths(
+        client,
+        source,
+        destination,
+        amount,
+    ))
+}
+
+pub fn get_account_channels<'a: 'b, 'b, C>(
+    address: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<Vec<Channel<'b>>>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_account_channels(address, client))
+}
+
+pub fn verify_payment_channel_claim<'a, C>(
+    client: &C,
+    channel_id: Cow<'a, str>,
+    amount: XRPAmount<'a>,
+    signature: Cow<'a, str>,
+    public_key: Cow<'a, str>,
+) -> XRPLHelperResult<bool>
+where
+    C: XRPLClient,
+{
+    block_on(async_verify_payment_channel_claim(
+        client, channel_id, amount, signature, public_key,
+    ))
+}
+
+pub fn is_deposit_authorized<C>(
+    client: &C,
+    source: Cow<'_, str>,
+    destination: Cow<'_, str>,
+) -> XRPLHelperResult<bool>
+where
+    C: XRPLClient,
+{
+    block_on(async_is_deposit_authorized(client, source, destination))
+}
+
+pub fn get_issuer_obligations<'a: 'b, 'b, C>(
+    client: &C,
+    issuer: Cow<'a, str>,
+    hot_wallets: Vec<Cow<'a, str>>,
+) -> XRPLHelperResult<Vec<(Cow<'b, str>, BigDecimal)>>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_issuer_obligations(client, issuer, hot_wallets))
+}
@@ -0,0 +1,525 @@
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::{IntoIter, Vec},
+};
+use serde_json::Value;
+
+use crate::{
+    core::addresscodec::{is_valid_xaddress, xaddress_to_classic_address},
+    models::{
+        requests::account_tx::AccountTx,
+        results::account_tx::AccountTx as AccountTxResult,
+        transactions::{metadata::TransactionMetadata, TransactionType, XRPLTransaction},
+        XRPLModelException,
+    },
+};
+
+use super::super::{clients::XRPLAsyncClient, exceptions::XRPLHelperResult};
+
+/// What [`iter_account_transactions`] streams, and from where.
+///
+/// The direction, the ledger range and the page size are sent to the
+/// server; the type and destination filters are applied to the typed
+/// transactions as they come.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountTransactionsOptions<'a> {
+    /// Stream the oldest transactions first, instead of the newest.
+    pub forward: bool,
+    /// The earliest ledger to stream transactions from, by default
+    /// the earliest the server has.
+    pub ledger_index_min: Option<u32>,
+    /// The latest ledger to stream transactions from, by default the
+    /// latest validated one.
+    pub ledger_index_max: Option<u32>,
+    /// How many transactions to fetch per request. The server may
+    /// return fewer.
+    pub limit: Option<u16>,
+    /// Only stream transactions of these types, e.g. only payments.
+    pub transaction_types: Option<Vec<TransactionType>>,
+    /// Only stream transactions to this destination.
+    pub destination: Option<Cow<'a, str>>,
+    /// Stop after this many transactions.
+    pub max_results: Option<usize>,
+    /// Stop at the first transaction past this ledger: later than it
+    /// going forward, earlier going backward.
+    pub until_ledger: Option<u32>,
+    /// Where a previous stream stopped, to resume from, see
+    /// [`AccountTransactionStream::checkpoint`].
+    pub resume_from: Option<AccountTransactionsCheckpoint>,
+}
+
+/// Where an [`AccountTransactionStream`] is in the history of the
+/// account, to resume from later, e.g. after a restart.
+///
+/// It is the marker of the page being streamed and how many of its
+/// transactions were handled, so resume with the same direction,
+/// ledger range and `limit`: pages fetched with the same marker
+/// hold the same validated transactions. Once all pages are
+/// streamed, resuming streams the transactions validated since.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountTransactionsCheckpoint {
+    /// The marker the page was fetched with, `None` for the first
+    /// page.
+    pub marker: Option<Value>,
+    /// How many transactions of the page were handled.
+    pub position: usize,
+}
+
+/// A validated transaction of an account, see
+/// [`iter_account_transactions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountTransaction<'a> {
+    pub hash: String,
+    /// The ledger the transaction was validated in.
+    pub ledger_index: u32,
+    pub transaction: XRPLTransaction<'a>,
+    pub meta: TransactionMetadata<'a>,
+}
+
+/// Stream the validated transactions of an account, page by page, as
+/// typed transactions, filtered and bounded by `options`.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```no_run
+/// use xrpl::asynch::account::{iter_account_transactions, AccountTransactionsOptions};
+/// use xrpl::asynch::clients::{AsyncJsonRpcClient, Network};
+/// use xrpl::models::transactions::TransactionType;
+///
+/// # #[cfg(feature = "std")]
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let client = AsyncJsonRpcClient::connect_network(Network::testnet());
+/// // The payments to a hot wallet, oldest first, from a checkpoint.
+/// let options = AccountTransactionsOptions {
+///     forward: true,
+///     transaction_types: Some(vec![TransactionType::Payment]),
+///     destination: Some("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".into()),
+///     max_results: Some(100),
+///     ..Default::default()
+/// };
+/// let mut stream = iter_account_transactions(
+///     &client,
+///     "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".into(),
+///     options,
+/// );
+/// while let Some(transaction) = stream.next().await {
+///     let transaction = transaction.unwrap();
+///     println!("{} in ledger {}", transaction.hash, transaction.ledger_index);
+/// }
+/// let checkpoint = stream.checkpoint();
+/// # });
+/// ```
+pub fn iter_account_transactions<'a, C>(
+    client: &'a C,
+    address: Cow<'a, str>,
+    options: AccountTransactionsOptions<'a>,
+) -> AccountTransactionStream<'a, C>
+where
+    C: XRPLAsyncClient,
+{
+    let checkpoint = options.resume_from.clone().unwrap_or_default();
+
+    AccountTransactionStream {
+        client,
+        address,
+        options,
+        marker: checkpoint.marker,
+        skip: checkpoint.position,
+        position: 0,
+        next_marker: None,
+        page: Vec::new().into_iter(),
+        yielded: 0,
+        fetched: false,
+        done: false,
+    }
+}
+
+/// The transactions of an account, see [`iter_account_transactions`].
+pub struct AccountTransactionStream<'a, C> {
+    client: &'a C,
+    address: Cow<'a, str>,
+    options: AccountTransactionsOptions<'a>,
+    /// The marker the current page was fetched with.
+    marker: Option<Value>,
+    /// How many transactions of the next page fetched were already
+    /// handled before resuming.
+    skip: usize,
+    /// How many transactions of the current page were handled.
+    position: usize,
+    /// The marker of the page after the current one.
+    next_marker: Option<Value>,
+    page: IntoIter<Value>,
+    yielded: usize,
+    fetched: bool,
+    done: bool,
+}
+
+impl<C> AccountTransactionStream<'_, C>
+where
+    C: XRPLAsyncClient,
+{
+    /// The next transaction, `None` once the history, or a stop
+    /// condition, is reached. After a failed request, the stream ends.
+    pub async fn next(&mut self) -> Option<XRPLHelperResult<AccountTransaction<'static>>> {
+        loop {
+            if self.done
+                || self
+                    .options
+                    .max_results
+                    .is_some_and(|max_results| self.yielded >= max_results)
+            {
+                return None;
+            }
+            let Some(entry) = self.page.as_slice().first() else {
+                if self.fetched && self.next_marker.is_none() {
+                    self.done = true;
+                    return None;
+                }
+                if let Err(error) = self.fetch_page().await {
+                    self.done = true;
+                    return Some(Err(error));
+                }
+                continue;
+            };
+            let transaction = match parse_entry(entry) {
+                Ok(transaction) => transaction,
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(error));
+                }
+            };
+            if let Some(transaction) = &transaction {
+                if self.is_past_until_ledger(transaction.ledger_index) {
+                    self.done = true;
+                    return None;
+                }
+            }
+            self.page.next();
+            self.position += 1;
+            match transaction {
+                Some(transaction) if self.matches(&transaction) => {
+                    self.yielded += 1;
+                    return Some(Ok(transaction));
+                }
+                _ => continue,
+            }
+        }
+    }
+
+    /// Where the stream is, to resume from with
+    /// [`AccountTransactionsOptions::resume_from`].
+    pub fn checkpoint(&self) -> AccountTransactionsCheckpoint {
+        if self.fetched {
+            AccountTransactionsCheckpoint {
+                marker: self.marker.clone(),
+                position: self.position,
+            }
+        } else {
+            AccountTransactionsCheckpoint {
+                marker: self.marker.clone(),
+                position: self.skip,
+            }
+        }
+    }
+
+    fn matches(&self, transaction: &AccountTransaction<'_>) -> bool {
+        let transaction_type_matches = match &self.options.transaction_types {
+            Some(transaction_types) => transaction
+                .transaction
+                .transaction_type()
+                .is_some_and(|transaction_type| transaction_types.contains(&transaction_type)),
+            None => true,
+        };
+        let destination_matches = match &self.options.destination {
+            Some(destination) => transaction.transaction.destination() == Some(destination),
+            None => true,
+        };
+
+        transaction_type_matches && destination_matches
+    }
+
+    fn is_past_until_ledger(&self, ledger_index: u32) -> bool {
+        match self.options.until_ledger {
+            Some(until_ledger) if self.options.forward => ledger_index > until_ledger,
+            Some(until_ledger) => ledger_index < until_ledger,
+            None => false,
+        }
+    }
+
+    async fn fetch_page(&mut self) -> XRPLHelperResult<()> {
+        let mut address = self.address.clone();
+        if is_valid_xaddress(&address) {
+            address = xaddress_to_classic_address(&address)?.0.into();
+        }
+        if self.fetched {
+            self.marker = self.next_marker.take();
+        }
+        let request = AccountTx::new(
+            None,
+            address,
+            None,
+            None,
+            None,
+            Some(self.options.forward),
+            self.options.ledger_index_min,
+            self.options.ledger_index_max,
+            self.options.limit,
+            self.marker.clone(),
+        );
+        let response = self.client.request(request.into()).await?;
+        let page = response.try_into_result::<AccountTxResult<'_>>()?;
+        let mut transactions = page.transactions.into_iter();
+        // Skip what was handled before resuming.
+        self.position = transactions.by_ref().take(self.skip).count();
+        self.skip = 0;
+        self.page = transactions.collect::<Vec<_>>().into_iter();
+        self.next_marker = page.marker;
+        self.fetched = true;
+
+        Ok(())
+    }
+}
+
+/// The typed transaction of an entry of `account_tx`, `None` if it is
+/// not validated.
+fn parse_entry(entry: &Value) -> XRPLHelperResult<Option<AccountTransaction<'static>>> {
+    if entry["validated"] != Value::Bool(true) {
+        return Ok(None);
+    }
+    let tx = &entry["tx"];
+    let hash = tx["hash"]
+        .as_str()
+        .ok_or(XRPLModelException::MissingField("hash".to_string()))?;
+    let ledger_index = tx["ledger_index"]
+        .as_u64()
+        .ok_or(XRPLModelException::MissingField("ledger_index".to_string()))?;
+
+    Ok(Some(AccountTransaction {
+        hash: hash.to_string(),
+        ledger_index: ledger_index as u32,
+        transaction: serde_json::from_value(tx.clone())?,
+        meta: serde_json::from_value(entry["meta"].clone())?,
+    }))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use alloc::vec;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        asynch::clients::{MockClient, MockResponse},
+        models::requests::{RequestMethod, XRPLRequest},
+    };
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+    const DESTINATION: &str = "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX";
+    const OTHER_DESTINATION: &str = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe";
+
+    fn entry(ledger_index: u32, validated: bool, mut tx: Value) -> Value {
+        tx["Account"] = ACCOUNT.into();
+        tx["Fee"] = "12".into();
+        tx["Sequence"] = ledger_index.into();
+        tx["hash"] = alloc::format!("{:064X}", ledger_index).into();
+        tx["ledger_index"] = ledger_index.into();
+
+        json!({
+            "meta": {
+                "AffectedNodes": [],
+                "TransactionIndex": 0,
+                "TransactionResult": "tesSUCCESS"
+            },
+            "tx": tx,
+            "validated": validated
+        })
+    }
+
+    fn payment(ledger_index: u32, destination: &str) -> Value {
+        entry(
+            ledger_index,
+            true,
+            json!({
+                "TransactionType": "Payment",
+                "Amount": "1000000",
+                "Destination": destination
+            }),
+        )
+    }
+
+    /// Three pages of history, oldest first, answered by their
+    /// marker.
+    fn client() -> MockClient {
+        MockClient::new().with_handler(|request| {
+            let XRPLRequest::AccountTx(request) = request else {
+                return None;
+            };
+            let page = match request.marker.as_ref().and_then(|marker| marker["seq"].as_u64()) {
+                None => json!({
+                    "transactions": [
+                        payment(10, DESTINATION),
+                        entry(11, true, json!({
+                            "TransactionType": "OfferCreate",
+                            "TakerGets": "6000000",
+                            "TakerPays": "1000000"
+                        })),
+                        payment(12, OTHER_DESTINATION),
+                    ],
+                    "marker": {"ledger": 12, "seq": 1}
+                }),
+                Some(1) => json!({
+                    "transactions": [
+                        entry(13, true, json!({
+                            "TransactionType": "NFTokenMint",
+                            "NFTokenTaxon": 0
+                        })),
+                        entry(14, false, payment(14, DESTINATION)["tx"].clone()),
+                        payment(15, DESTINATION),
+                    ],
+                    "marker": {"ledger": 15, "seq": 2}
+                }),
+                _ => json!({
+                    "transactions": [
+                        entry(16, true, json!({
+                            "TransactionType": "NFTokenCreateOffer",
+                            "Amount": "1000000",
+                            "NFTokenID": "000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65",
+                            "Destination": DESTINATION,
+                            "Flags": 1
+                        })),
+                        payment(17, DESTINATION),
+                    ]
+                }),
+            };
+            let mut page = page;
+            page["account"] = ACCOUNT.into();
+            Some(MockResponse::result(page))
+        })
+    }
+
+    fn ledger_indexes(transactions: &[XRPLHelperResult<AccountTransaction<'static>>]) -> Vec<u32> {
+        transactions
+            .iter()
+            .map(|transaction| transaction.as_ref().unwrap().ledger_index)
+            .collect()
+    }
+
+    async fn collect<C: XRPLAsyncClient>(
+        stream: &mut AccountTransactionStream<'_, C>,
+    ) -> Vec<XRPLHelperResult<AccountTransaction<'static>>> {
+        let mut transactions = Vec::new();
+        while let Some(transaction) = stream.next().await {
+            transactions.push(transaction);
+        }
+        transactions
+    }
+
+    #[tokio::test]
+    async fn test_filters() {
+        let client = client();
+        let options = AccountTransactionsOptions {
+            forward: true,
+            transaction_types: Some(vec![TransactionType::Payment]),
+            destination: Some(DESTINATION.into()),
+            ..Default::default()
+        };
+        let mut stream = iter_account_transactions(&client, ACCOUNT.into(), options);
+
+        let transactions = collect(&mut stream).await;
+
+        // Not the payment to another destination, nor the one not
+        // validated, nor the NFTokenCreateOffer.
+        assert_eq!(ledger_indexes(&transactions), [10, 15, 17]);
+        let transaction = transactions[0].as_ref().unwrap();
+        assert_eq!(transaction.hash, alloc::format!("{:064X}", 10));
+        assert_eq!(transaction.meta.transaction_result, "tesSUCCESS");
+        assert_eq!(transaction.transaction.destination(), Some(DESTINATION));
+        let requests = client.requests_of(RequestMethod::AccountTx);
+        assert_eq!(requests.len(), 3);
+        let markers = requests
+            .iter()
+            .map(|request| match request {
+                XRPLRequest::AccountTx(request) => {
+                    assert_eq!(request.forward, Some(true));
+                    request.marker.clone()
+                }
+                request => panic!("unexpected request: {:?}", request),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            markers,
+            [
+                None,
+                Some(json!({"ledger": 12, "seq": 1})),
+                Some(json!({"ledger": 15, "seq": 2}))
+            ]
+        );
+
+        let options = AccountTransactionsOptions {
+            transaction_types: Some(vec![
+                TransactionType::NFTokenMint,
+                TransactionType::NFTokenCreateOffer,
+            ]),
+            ..Default::default()
+        };
+        let mut stream = iter_account_transactions(&client, ACCOUNT.into(), options);
+
+        assert_eq!(ledger_indexes(&collect(&mut stream).await), [13, 16]);
+    }
+
+    #[tokio::test]
+    async fn test_resume_from_checkpoint() {
+        let client = client();
+        let options = AccountTransactionsOptions {
+            forward: true,
+            transaction_types: Some(vec![TransactionType::NFTokenMint]),
+            max_results: Some(1),
+            ..Default::default()
+        };
+        let mut stream = iter_account_transactions(&client, ACCOUNT.into(), options);
+
+        assert_eq!(ledger_indexes(&collect(&mut stream).await), [13]);
+        let checkpoint = stream.checkpoint();
+        assert_eq!(
+            checkpoint,
+            AccountTransactionsCheckpoint {
+                marker: Some(json!({"ledger": 12, "seq": 1})),
+                position: 1,
+            }
+        );
+
+        // Resuming streams what comes after, up to the ledger asked.
+        let options = AccountTransactionsOptions {
+            forward: true,
+            until_ledger: Some(16),
+            resume_from: Some(checkpoint),
+            ..Default::default()
+        };
+        let mut stream = iter_account_transactions(&client, ACCOUNT.into(), options);
+
+        assert_eq!(ledger_indexes(&collect(&mut stream).await), [15, 16]);
+        assert_eq!(
+            stream.checkpoint(),
+            AccountTransactionsCheckpoint {
+                marker: Some(json!({"ledger": 15, "seq": 2})),
+                position: 1,
+            }
+        );
+        assert_eq!(client.requests_of(RequestMethod::AccountTx).len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_failed_request_ends_stream() {
+        let client = MockClient::new().with_response(
+            RequestMethod::AccountTx,
+            MockResponse::error("actNotFound", 19, None),
+        );
+        let mut stream = iter_account_transactions(&client, ACCOUNT.into(), Default::default());
+
+        assert!(matches!(stream.next().await, Some(Err(_))));
+        assert!(stream.next().await.is_none());
+    }
+}
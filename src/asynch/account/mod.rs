@@ -1,17 +1,46 @@
-use alloc::borrow::Cow;
+mod account_transactions;
+
+use alloc::{
+    borrow::Cow,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bigdecimal::BigDecimal;
+use core::{ops::Range, str::FromStr};
+use serde_json::Value;
 
 use crate::{
     core::addresscodec::{is_valid_xaddress, xaddress_to_classic_address},
     models::{
-        ledger::objects::AccountRoot,
-        requests::{account_info::AccountInfo, account_tx::AccountTx},
-        results::{self},
-        XRPAmount,
+        ledger::objects::{
+            signer_list::{SignerList, SignerListFlag},
+            AccountRoot, LedgerEntry, RippleStateFlag,
+        },
+        requests::{
+            account_channels::AccountChannels, account_info::AccountInfo,
+            account_nfts::AccountNfts, account_objects::AccountObjects, account_tx::AccountTx,
+            channel_verify::ChannelVerify, deposit_authorize::DepositAuthorized,
+            gateway_balances::GatewayBalances, nft_buy_offers::NftBuyOffers,
+            nft_sell_offers::NftSellOffers, ripple_path_find::RipplePathFind,
+            server_state::ServerState, XRPLRequest,
+        },
+        results::{
+            self, account_channels::Channel, account_info::QueueData, account_nfts::AccountNft,
+            error::XRPLErrorCode, exceptions::XRPLResultException, nft_offers::NFTOffer,
+            ripple_path_find::PathAlternative, XRPLOptionalResult,
+        },
+        Amount, XRPAmount, XRPLModelException,
     },
+    XRPLError,
 };
 
-use super::{clients::XRPLAsyncClient, exceptions::XRPLHelperResult};
+use super::{clients::XRPLAsyncClient, exceptions::XRPLHelperResult, ledger::get_reserves};
+
+pub use account_transactions::*;
 
+/// Whether an account exists in the ledger. Fails if the server
+/// could not tell, e.g. because it is not synced.
 pub async fn does_account_exist<C>(
     address: Cow<'_, str>,
     client: &C,
@@ -22,7 +51,8 @@ where
 {
     match get_account_root(address, client, ledger_index.unwrap_or("validated".into())).await {
         Ok(_) => Ok(true),
-        Err(_) => Ok(false),
+        Err(XRPLError::AccountNotFound(_)) => Ok(false),
+        Err(error) => Err(error),
     }
 }
 
@@ -52,11 +82,180 @@ where
     }
 }
 
+/// The AccountRoot of an account. Fails with
+/// [`XRPLError::AccountNotFound`] if the account does not exist in the
+/// ledger.
 pub async fn get_account_root<'a: 'b, 'b, C>(
     address: Cow<'a, str>,
     client: &C,
     ledger_index: Cow<'a, str>,
 ) -> XRPLHelperResult<AccountRoot<'b>>
+where
+    C: XRPLAsyncClient,
+{
+    match get_account_info(address.clone(), client, ledger_index, None, None).await {
+        Ok(account_info) => Ok(account_info.account_data),
+        Err(error)
+            if error.error_response().is_some_and(|error_response| {
+                error_response.error == XRPLErrorCode::ActNotFound
+            }) =>
+        {
+            Err(XRPLError::AccountNotFound(address.into_owned()))
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// The transactions of an account that are not validated yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingTransactions<'a> {
+    /// The account's next Sequence Number in the latest validated
+    /// ledger.
+    pub validated_sequence: u32,
+    /// The account's next Sequence Number in the open ledger.
+    pub current_sequence: u32,
+    /// The account's transactions waiting in the server's queue.
+    pub queue_data: Option<QueueData<'a>>,
+}
+
+impl PendingTransactions<'_> {
+    /// The Sequence Numbers of transactions applied to the open
+    /// ledger that are not validated yet.
+    pub fn unvalidated_sequences(&self) -> Range<u32> {
+        self.validated_sequence..self.current_sequence.max(self.validated_sequence)
+    }
+
+    /// The Sequence Number to use for the account's next
+    /// transaction, after the open ledger and the queue.
+    pub fn next_sequence(&self) -> u32 {
+        match self
+            .queue_data
+            .as_ref()
+            .and_then(|queue_data| queue_data.highest_sequence)
+        {
+            Some(highest_sequence) => self.current_sequence.max(highest_sequence + 1),
+            None => self.current_sequence,
+        }
+    }
+
+    /// The Sequence Numbers missing between the open ledger and the
+    /// queued transactions. Queued transactions after a gap can only
+    /// apply once transactions filling it are submitted.
+    pub fn sequence_gaps(&self) -> Vec<u32> {
+        let Some(queue_data) = &self.queue_data else {
+            return Vec::new();
+        };
+        let Some(highest_sequence) = queue_data.highest_sequence else {
+            return Vec::new();
+        };
+        match &queue_data.transactions {
+            Some(transactions) => (self.current_sequence..highest_sequence)
+                .filter(|sequence| {
+                    !transactions
+                        .iter()
+                        .any(|transaction| transaction.seq == Some(*sequence))
+                })
+                .collect(),
+            None => (self.current_sequence
+                ..queue_data.lowest_sequence.unwrap_or(self.current_sequence))
+                .collect(),
+        }
+    }
+}
+
+/// Get the transactions of an account that are not validated yet:
+/// those applied to the open ledger and those waiting in the
+/// server's queue.
+pub async fn get_pending_transactions<'a: 'b, 'b, C>(
+    address: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<PendingTransactions<'b>>
+where
+    C: XRPLAsyncClient,
+{
+    let validated =
+        get_account_info(address.clone(), client, "validated".into(), None, None).await?;
+    let current = get_account_info(address, client, "current".into(), Some(true), None).await?;
+
+    Ok(PendingTransactions {
+        validated_sequence: validated.account_data.sequence,
+        current_sequence: current.account_data.sequence,
+        queue_data: current.queue_data,
+    })
+}
+
+/// The signer list of `address` in the latest validated ledger, or
+/// `None` if the account cannot multi-sign.
+///
+/// Check with [`can_meet_quorum`] whether the signers at hand can
+/// sign for the account before collecting their signatures.
+pub async fn get_signer_list<'a: 'b, 'b, C>(
+    address: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<Option<SignerList<'b>>>
+where
+    C: XRPLAsyncClient,
+{
+    let account_info =
+        get_account_info(address, client, "validated".into(), None, Some(true)).await?;
+
+    Ok(account_info
+        .signer_lists
+        .and_then(|signer_lists| signer_lists.into_iter().next()))
+}
+
+/// Whether the signatures of `available_signers` are enough to sign
+/// for the owner of `signer_list`: their weights must add up to its
+/// quorum. Accounts not on the list add nothing, and each signer
+/// counts once.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::asynch::account::can_meet_quorum;
+/// use xrpl::models::ledger::objects::signer_list::SignerList;
+///
+/// let signer_list: SignerList = serde_json::from_value(serde_json::json!({
+///     "Flags": 65536,
+///     "LedgerEntryType": "SignerList",
+///     "OwnerNode": "0",
+///     "PreviousTxnID": "5904C0DC72C58A83AEFED2FFC5386356AA83FCA6A88C89D00646E51E687CDBE4",
+///     "PreviousTxnLgrSeq": 16061435,
+///     "SignerEntries": [
+///         { "SignerEntry": { "Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW", "SignerWeight": 2 } },
+///         { "SignerEntry": { "Account": "raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n", "SignerWeight": 1 } }
+///     ],
+///     "SignerListID": 0,
+///     "SignerQuorum": 3
+/// }))
+/// .unwrap();
+///
+/// assert!(!can_meet_quorum(&signer_list, &["rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW"]));
+/// assert!(can_meet_quorum(
+///     &signer_list,
+///     &["rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW", "raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n"],
+/// ));
+/// ```
+pub fn can_meet_quorum(signer_list: &SignerList<'_>, available_signers: &[&str]) -> bool {
+    let weight: u32 = signer_list
+        .signer_entries
+        .iter()
+        .filter(|entry| available_signers.contains(&entry.account.as_str()))
+        .map(|entry| u32::from(entry.signer_weight))
+        .sum();
+
+    weight >= signer_list.signer_quorum
+}
+
+async fn get_account_info<'a: 'b, 'b, C>(
+    address: Cow<'a, str>,
+    client: &C,
+    ledger_index: Cow<'a, str>,
+    queue: Option<bool>,
+    signer_lists: Option<bool>,
+) -> XRPLHelperResult<results::account_info::AccountInfo<'b>>
 where
     C: XRPLAsyncClient,
 {
@@ -70,15 +269,13 @@ where
         None,
         Some(ledger_index),
         None,
-        None,
-        None,
+        queue,
+        signer_lists,
     )
     .into();
     let account_info = client.request(request).await?;
 
-    Ok(account_info
-        .try_into_result::<results::account_info::AccountInfo<'_>>()?
-        .account_data)
+    Ok(account_info.try_into_result::<results::account_info::AccountInfo<'_>>()?)
 }
 
 pub async fn get_latest_transaction<'a: 'b, 'b, C>(
@@ -107,3 +304,1697 @@ where
 
     Ok(response.try_into_result::<results::account_tx::AccountTx<'_>>()?)
 }
+
+/// Get all NFTokens an account owns in the latest validated
+/// ledger, following the pagination markers to the last page.
+pub async fn get_account_nfts<'a: 'b, 'b, C>(
+    mut address: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<Vec<AccountNft<'b>>>
+where
+    C: XRPLAsyncClient,
+{
+    if is_valid_xaddress(&address) {
+        address = xaddress_to_classic_address(&address)?.0.into();
+    }
+    let mut account_nfts = Vec::new();
+    let mut marker = None;
+    loop {
+        let request = AccountNfts::new(
+            None,
+            address.clone(),
+            None,
+            Some("validated".into()),
+            None,
+            marker.take(),
+        );
+        let response = client.request(request.into()).await?;
+        let page = response.try_into_result::<results::account_nfts::AccountNfts<'_>>()?;
+        account_nfts.extend(page.account_nfts);
+        match page.marker {
+            Some(next_marker) => marker = Some(next_marker),
+            None => return Ok(account_nfts),
+        }
+    }
+}
+
+/// The open offers for an NFToken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NftOffersBySide<'a> {
+    pub buy_offers: Vec<NFTOffer<'a>>,
+    pub sell_offers: Vec<NFTOffer<'a>>,
+}
+
+/// Get all buy and sell offers for an NFToken in the latest
+/// validated ledger, following the pagination markers to the last
+/// page. An NFToken without offers has empty lists.
+pub async fn get_nft_offers<'a: 'b, 'b, C>(
+    nft_id: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<NftOffersBySide<'b>>
+where
+    C: XRPLAsyncClient,
+{
+    let buy_offers = get_all_nft_offers(client, |marker| {
+        NftBuyOffers::new(
+            None,
+            nft_id.clone(),
+            None,
+            Some("validated".into()),
+            None,
+            marker,
+        )
+        .into()
+    })
+    .await?;
+    let sell_offers = get_all_nft_offers(client, |marker| {
+        NftSellOffers::new(
+            None,
+            nft_id.clone(),
+            None,
+            Some("validated".into()),
+            None,
+            marker,
+        )
+        .into()
+    })
+    .await?;
+
+    Ok(NftOffersBySide {
+        buy_offers,
+        sell_offers,
+    })
+}
+
+async fn get_all_nft_offers<'a: 'b, 'b, C>(
+    client: &C,
+    request: impl Fn(Option<Value>) -> XRPLRequest<'a>,
+) -> XRPLHelperResult<Vec<NFTOffer<'b>>>
+where
+    C: XRPLAsyncClient,
+{
+    let mut offers = Vec::new();
+    let mut marker = None;
+    loop {
+        let response = client.request(request(marker.take())).await?;
+        // The server answers objectNotFound rather than an empty list
+        // when there are no offers.
+        if response
+            .error_response()
+            .is_some_and(|error_response| error_response.error == XRPLErrorCode::ObjectNotFound)
+        {
+            return Ok(offers);
+        }
+        let page = response.try_into_result::<results::nft_offers::NftOffers<'_>>()?;
+        offers.extend(page.offers);
+        match page.marker {
+            Some(next_marker) => marker = Some(next_marker),
+            None => return Ok(offers),
+        }
+    }
+}
+
+/// Get all payment channels an account is the source of in the
+/// latest validated ledger, following the pagination markers to the
+/// last page.
+pub async fn get_account_channels<'a: 'b, 'b, C>(
+    mut address: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<Vec<Channel<'b>>>
+where
+    C: XRPLAsyncClient,
+{
+    if is_valid_xaddress(&address) {
+        address = xaddress_to_classic_address(&address)?.0.into();
+    }
+    let mut channels = Vec::new();
+    let mut marker = None;
+    loop {
+        let request = AccountChannels::new(
+            None,
+            address.clone(),
+            None,
+            None,
+            Some("validated".into()),
+            None,
+            marker.take(),
+        );
+        let response = client.request(request.into()).await?;
+        let page = response.try_into_result::<results::account_channels::AccountChannels<'_>>()?;
+        channels.extend(page.channels);
+        match page.marker {
+            Some(next_marker) => marker = Some(next_marker),
+            None => return Ok(channels),
+        }
+    }
+}
+
+/// Whether `signature` authorizes claiming `amount` from the payment
+/// channel `channel_id`, as checked by the server with
+/// `channel_verify`. `public_key` is the key of the channel, in hex
+/// or base58.
+///
+/// To check a claim without a server, see
+/// [`crate::transaction::is_valid_payment_channel_claim`].
+pub async fn verify_payment_channel_claim<'a, C>(
+    client: &C,
+    channel_id: Cow<'a, str>,
+    amount: XRPAmount<'a>,
+    signature: Cow<'a, str>,
+    public_key: Cow<'a, str>,
+) -> XRPLHelperResult<bool>
+where
+    C: XRPLAsyncClient,
+{
+    let request = ChannelVerify::new(None, amount, channel_id, public_key, signature);
+    let response = client.request(request.into()).await?;
+
+    Ok(response
+        .try_into_result::<results::channel_verify::ChannelVerify>()?
+        .signature_verified)
+}
+
+/// Whether `source` may send payments directly to `destination` in
+/// the latest validated ledger, i.e. `destination` does not require
+/// Deposit Authorization or has preauthorized `source`.
+///
+/// See Deposit Authorization:
+/// `<https://xrpl.org/depositauth.html#deposit-authorization>`
+pub async fn is_deposit_authorized<C>(
+    client: &C,
+    source: Cow<'_, str>,
+    destination: Cow<'_, str>,
+) -> XRPLHelperResult<bool>
+where
+    C: XRPLAsyncClient,
+{
+    let request = DepositAuthorized::new(
+        None,
+        destination,
+        source,
+        None,
+        None,
+        Some("validated".into()),
+    );
+    let response = client.request(request.into()).await?;
+
+    Ok(response
+        .try_into_result::<results::deposit_authorized::DepositAuthorized<'_>>()?
+        .deposit_authorized)
+}
+
+/// The amounts `issuer` has issued of each of its currencies in the
+/// latest validated ledger, not counting what its `hot_wallets`
+/// hold, sorted by currency code.
+///
+/// See Gateway Balances:
+/// `<https://xrpl.org/gateway_balances.html>`
+pub async fn get_issuer_obligations<'a: 'b, 'b, C>(
+    client: &C,
+    mut issuer: Cow<'a, str>,
+    hot_wallets: Vec<Cow<'a, str>>,
+) -> XRPLHelperResult<Vec<(Cow<'b, str>, BigDecimal)>>
+where
+    C: XRPLAsyncClient,
+{
+    if is_valid_xaddress(&issuer) {
+        issuer = xaddress_to_classic_address(&issuer)?.0.into();
+    }
+    let hot_wallets = (!hot_wallets.is_empty()).then_some(hot_wallets);
+    let request = GatewayBalances::new(
+        None,
+        issuer,
+        hot_wallets,
+        None,
+        Some("validated".into()),
+        Some(true),
+    );
+    let response = client.request(request.into()).await?;
+    let obligations = match response
+        .try_into_opt_result::<results::gateway_balances::GatewayBalances<'_>>()?
+    {
+        XRPLOptionalResult::Result(gateway_balances) => {
+            gateway_balances.obligations.unwrap_or_default()
+        }
+        // Nothing issued and nothing held, only the account is
+        // listed.
+        XRPLOptionalResult::Other(other) if other.get("account").is_some() => Default::default(),
+        XRPLOptionalResult::Other(other) => {
+            return Err(XRPLModelException::from(XRPLResultException::ExpectedResult(other)).into())
+        }
+    };
+
+    let mut obligations = obligations
+        .into_iter()
+        .map(|(currency, value)| {
+            let value = BigDecimal::from_str(&value).map_err(XRPLModelException::from)?;
+            Ok((currency.into_owned().into(), value))
+        })
+        .collect::<XRPLHelperResult<Vec<(Cow<'b, str>, BigDecimal)>>>()?;
+    obligations.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    Ok(obligations)
+}
+
+/// Find the ways to pay `amount` from `source` to `destination` in
+/// the current ledger, with `ripple_path_find`. The `paths_computed`
+/// of an alternative are the `paths` of the Payment, and its
+/// `source_amount` its `send_max`. An empty list means no path was
+/// found.
+pub async fn find_payment_paths<'a: 'b, 'b, C>(
+    client: &C,
+    mut source: Cow<'a, str>,
+    mut destination: Cow<'a, str>,
+    amount: Amount<'a>,
+) -> XRPLHelperResult<Vec<PathAlternative<'b>>>
+where
+    C: XRPLAsyncClient,
+{
+    if is_valid_xaddress(&source) {
+        source = xaddress_to_classic_address(&source)?.0.into();
+    }
+    if is_valid_xaddress(&destination) {
+        destination = xaddress_to_classic_address(&destination)?.0.into();
+    }
+    let request = RipplePathFind::new(None, destination, amount, source, None, None, None, None);
+    let response = client.request(request.into()).await?;
+
+    Ok(response
+        .try_into_result::<results::ripple_path_find::RipplePathFind<'_>>()?
+        .alternatives)
+}
+
+/// How many ledgers must pass after an account's Sequence before
+/// the account can be deleted.
+const ACCOUNT_DELETE_LEDGER_DELAY: u32 = 256;
+
+/// Something that keeps an account from being deleted with an
+/// AccountDelete transaction.
+///
+/// See Deletion of Accounts:
+/// `<https://xrpl.org/deleting-accounts.html>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountDeleteBlocker<'a> {
+    /// The account's Sequence plus 256 is not below the latest
+    /// validated ledger index yet.
+    SequenceTooRecent { sequence: u32, ledger_index: u32 },
+    /// The account owns `count` objects of `ledger_entry_type` that
+    /// are not deleted with it, e.g. trust lines or escrows.
+    OwnedObjects {
+        ledger_entry_type: String,
+        count: usize,
+    },
+    /// The balance does not cover the fee of an AccountDelete
+    /// transaction, which is one owner reserve.
+    InsufficientBalance {
+        balance: XRPAmount<'a>,
+        fee: XRPAmount<'a>,
+    },
+}
+
+/// Check whether an account can be deleted in the latest validated
+/// ledger. An empty list means nothing is in the way.
+pub async fn check_account_delete_blockers<'a: 'b, 'b, C>(
+    mut address: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<Vec<AccountDeleteBlocker<'b>>>
+where
+    C: XRPLAsyncClient,
+{
+    if is_valid_xaddress(&address) {
+        address = xaddress_to_classic_address(&address)?.0.into();
+    }
+    let account_root = get_account_root(address.clone(), client, "validated".into()).await?;
+    let server_state_response = client.request(ServerState::new(None).into()).await?;
+    let validated_ledger = server_state_response
+        .try_into_result::<results::server_state::ServerState<'_>>()?
+        .state
+        .validated_ledger
+        .ok_or(XRPLModelException::MissingField(
+            "validated_ledger".to_string(),
+        ))?;
+
+    let mut blockers = Vec::new();
+    if account_root
+        .sequence
+        .saturating_add(ACCOUNT_DELETE_LEDGER_DELAY)
+        >= validated_ledger.seq
+    {
+        blockers.push(AccountDeleteBlocker::SequenceTooRecent {
+            sequence: account_root.sequence,
+            ledger_index: validated_ledger.seq,
+        });
+    }
+    for (ledger_entry_type, count) in count_deletion_blockers(address, client).await? {
+        blockers.push(AccountDeleteBlocker::OwnedObjects {
+            ledger_entry_type,
+            count,
+        });
+    }
+    let balance = XRPAmount::from(account_root.balance.unwrap_or_default().0.into_owned());
+    let fee = XRPAmount::from(validated_ledger.reserve_inc.0.into_owned());
+    if balance < fee {
+        blockers.push(AccountDeleteBlocker::InsufficientBalance { balance, fee });
+    }
+
+    Ok(blockers)
+}
+
+/// Count the objects keeping an account from being deleted by
+/// ledger entry type.
+async fn count_deletion_blockers<C>(
+    address: Cow<'_, str>,
+    client: &C,
+) -> XRPLHelperResult<BTreeMap<String, usize>>
+where
+    C: XRPLAsyncClient,
+{
+    let mut counts = BTreeMap::new();
+    for object in get_all_account_objects(address, Some(true), client).await? {
+        let ledger_entry_type = object["LedgerEntryType"].as_str().unwrap_or_default();
+        *counts.entry(ledger_entry_type.to_string()).or_default() += 1;
+    }
+
+    Ok(counts)
+}
+
+/// The objects an account owns in the latest validated ledger,
+/// following the pagination markers to the last page.
+async fn get_all_account_objects<C>(
+    address: Cow<'_, str>,
+    deletion_blockers_only: Option<bool>,
+    client: &C,
+) -> XRPLHelperResult<Vec<Value>>
+where
+    C: XRPLAsyncClient,
+{
+    let mut objects = Vec::new();
+    let mut marker = None;
+    loop {
+        let request = AccountObjects::new(
+            None,
+            address.clone(),
+            None,
+            Some("validated".into()),
+            None,
+            deletion_blockers_only,
+            None,
+            marker.take(),
+        );
+        let response = client.request(request.into()).await?;
+        let page = response.try_into_result::<results::account_objects::AccountObjects<'_>>()?;
+        objects.extend(page.account_objects);
+        match page.marker {
+            Some(next_marker) => marker = Some(next_marker),
+            None => return Ok(objects),
+        }
+    }
+}
+
+/// The owner reserve an account must hold for the objects it owns,
+/// on top of the base reserve.
+///
+/// See Reserves:
+/// `<https://xrpl.org/reserves.html#owner-reserves>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnerReserve<'a> {
+    /// How many owner reserves the objects of each ledger entry type
+    /// take, see [`count_owner_reserves`].
+    pub owner_reserves: BTreeMap<String, u32>,
+    /// The reserve of one object, from the latest validated ledger.
+    pub reserve_increment: XRPAmount<'a>,
+    /// The owner reserve of all the objects.
+    pub total: XRPAmount<'a>,
+}
+
+/// The owner reserve `address` must hold for the objects it owns in
+/// the latest validated ledger, per ledger entry type and in total.
+pub async fn get_owner_reserve_requirement<'a: 'b, 'b, C>(
+    mut address: Cow<'a, str>,
+    client: &C,
+) -> XRPLHelperResult<OwnerReserve<'b>>
+where
+    C: XRPLAsyncClient,
+{
+    if is_valid_xaddress(&address) {
+        address = xaddress_to_classic_address(&address)?.0.into();
+    }
+    let objects = get_all_account_objects(address.clone(), None, client)
+        .await?
+        .into_iter()
+        .map(|object| Ok(serde_json::from_value(object)?))
+        .collect::<XRPLHelperResult<Vec<LedgerEntry<'_>>>>()?;
+    let owner_reserves = count_owner_reserves(&address, &objects);
+    let (_, reserve_increment) = get_reserves(client).await?;
+    let count: u64 = owner_reserves.values().map(|&count| u64::from(count)).sum();
+    let increment: u64 = reserve_increment.clone().try_into()?;
+
+    Ok(OwnerReserve {
+        owner_reserves,
+        reserve_increment,
+        total: (count * increment).to_string().into(),
+    })
+}
+
+/// Count the owner reserves the objects of `account` take, per
+/// ledger entry type. Each object takes one, except:
+///
+/// - a trust line only takes one from the side whose reserve flag is
+///   set, `lsfLowReserve` or `lsfHighReserve`;
+/// - a signer list from before the MultiSignReserve amendment, without
+///   `lsfOneOwnerCount`, takes two plus one per signer;
+/// - directories and AMMs take none.
+pub fn count_owner_reserves(account: &str, objects: &[LedgerEntry<'_>]) -> BTreeMap<String, u32> {
+    let mut counts = BTreeMap::new();
+    for object in objects {
+        let count = match object {
+            LedgerEntry::RippleState(trust_line) => {
+                let is_side = |limit: &Amount| match limit {
+                    Amount::IssuedCurrencyAmount(limit) => limit.issuer == account,
+                    Amount::XRPAmount(_) => false,
+                };
+                let flags = &trust_line.common_fields.flags;
+                let low = is_side(&trust_line.low_limit)
+                    && flags.contains(&RippleStateFlag::LsfLowReserve);
+                let high = is_side(&trust_line.high_limit)
+                    && flags.contains(&RippleStateFlag::LsfHighReserve);
+                u32::from(low || high)
+            }
+            LedgerEntry::SignerList(signer_list) => {
+                if signer_list
+                    .common_fields
+                    .flags
+                    .contains(&SignerListFlag::LsfOneOwnerCount)
+                {
+                    1
+                } else {
+                    2 + signer_list.signer_entries.len() as u32
+                }
+            }
+            LedgerEntry::DirectoryNode(_) | LedgerEntry::AMM(_) => 0,
+            _ => 1,
+        };
+        if count > 0 {
+            let ledger_entry_type = match object {
+                LedgerEntry::Other(fields) => fields["LedgerEntryType"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+                _ => object
+                    .get_ledger_entry_type()
+                    .map(|ledger_entry_type| ledger_entry_type.to_string())
+                    .unwrap_or_default(),
+            };
+            *counts.entry(ledger_entry_type).or_default() += count;
+        }
+    }
+
+    counts
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_pending_transactions {
+    use alloc::vec;
+    use url::Url;
+
+    use super::*;
+    use crate::{
+        asynch::clients::{exceptions::XRPLClientResult, XRPLClient},
+        models::{requests::XRPLRequest, results::XRPLResponse},
+    };
+
+    const ACCOUNT: &str = "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn";
+
+    /// Answers `account_info` with sequence 5 in the validated ledger,
+    /// 7 in the open ledger and three queued transactions.
+    struct MockClient;
+
+    impl XRPLClient for MockClient {
+        async fn request_impl<'a: 'b, 'b>(
+            &self,
+            request: XRPLRequest<'a>,
+        ) -> XRPLClientResult<XRPLResponse<'b>> {
+            let XRPLRequest::AccountInfo(account_info) = &request else {
+                panic!("unexpected request: {request:?}");
+            };
+            let current = account_info.ledger_index.as_deref() == Some("current");
+            let mut result = serde_json::json!({
+                "account_data": {
+                    "Account": ACCOUNT,
+                    "Balance": "999999999960",
+                    "Flags": 0,
+                    "LedgerEntryType": "AccountRoot",
+                    "OwnerCount": 0,
+                    "PreviousTxnID": "4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42",
+                    "PreviousTxnLgrSeq": 3,
+                    "Sequence": if current { 7 } else { 5 },
+                    "index": "92FA6A9FC8EA6018D5D16532D7795C91BFB0831355BDFDA177E86C8BF997985F"
+                },
+                "status": "success",
+                "validated": !current
+            });
+            if current && account_info.queue == Some(true) {
+                result["queue_data"] = serde_json::json!({
+                    "auth_change_queued": true,
+                    "highest_sequence": 10,
+                    "lowest_sequence": 7,
+                    "max_spend_drops_total": "500",
+                    "transactions": [
+                        {
+                            "auth_change": false,
+                            "fee": "100",
+                            "fee_level": "2560",
+                            "max_spend_drops": "100",
+                            "seq": 7
+                        },
+                        {
+                            "auth_change": false,
+                            "fee": "100",
+                            "fee_level": "2560",
+                            "max_spend_drops": "100",
+                            "seq": 8
+                        },
+                        {
+                            "LastLedgerSequence": 300,
+                            "auth_change": true,
+                            "fee": "100",
+                            "fee_level": "2560",
+                            "max_spend_drops": "300",
+                            "seq": 10
+                        }
+                    ],
+                    "txn_count": 3
+                });
+            }
+
+            Ok(serde_json::from_value(serde_json::json!({
+                "result": result,
+                "status": "success",
+                "type": "response"
+            }))?)
+        }
+
+        fn get_host(&self) -> Url {
+            Url::parse("wss://s.altnet.rippletest.net:51233").unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_pending_transactions() {
+        let pending = get_pending_transactions(ACCOUNT.into(), &MockClient)
+            .await
+            .unwrap();
+
+        assert_eq!(pending.validated_sequence, 5);
+        assert_eq!(pending.current_sequence, 7);
+        assert_eq!(pending.unvalidated_sequences(), 5..7);
+        assert_eq!(pending.next_sequence(), 11);
+        assert_eq!(pending.sequence_gaps(), vec![9]);
+
+        let queue_data = pending.queue_data.unwrap();
+        assert_eq!(queue_data.txn_count, 3);
+        assert_eq!(queue_data.auth_change_queued, Some(true));
+        assert_eq!(queue_data.max_spend_drops_total, Some("500".into()));
+        let transactions = queue_data.transactions.unwrap();
+        assert_eq!(transactions.len(), 3);
+        assert_eq!(transactions[2].last_ledger_sequence, Some(300));
+        assert!(transactions[2].auth_change);
+    }
+
+    #[test]
+    fn test_pending_transactions_without_queue() {
+        let pending = PendingTransactions {
+            validated_sequence: 5,
+            current_sequence: 5,
+            queue_data: None,
+        };
+
+        assert!(pending.unvalidated_sequences().is_empty());
+        assert_eq!(pending.next_sequence(), 5);
+        assert!(pending.sequence_gaps().is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_does_account_exist {
+    use url::Url;
+
+    use super::*;
+    use crate::{
+        asynch::clients::{exceptions::XRPLClientResult, XRPLClient},
+        models::{requests::XRPLRequest, results::XRPLResponse},
+    };
+
+    /// Answers every request with a WebSocket error response.
+    struct ErrorClient(&'static str);
+
+    impl XRPLClient for ErrorClient {
+        async fn request_impl<'a: 'b, 'b>(
+            &self,
+            _request: XRPLRequest<'a>,
+        ) -> XRPLClientResult<XRPLResponse<'b>> {
+            Ok(serde_json::from_value(serde_json::json!({
+                "error": self.0,
+                "status": "error",
+                "type": "response"
+            }))?)
+        }
+
+        fn get_host(&self) -> Url {
+            Url::parse("wss://s.altnet.rippletest.net:51233").unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_only_act_not_found_means_missing() {
+        let address = "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn";
+
+        let exists = does_account_exist(address.into(), &ErrorClient("actNotFound"), None).await;
+        assert!(!exists.unwrap());
+
+        let error = get_account_root(
+            address.into(),
+            &ErrorClient("actNotFound"),
+            "validated".into(),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(error, XRPLError::AccountNotFound(account) if account == address));
+
+        let error = does_account_exist(address.into(), &ErrorClient("tooBusy"), None)
+            .await
+            .unwrap_err();
+        assert!(error.error_response().unwrap().is_retriable());
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_nfts {
+    use url::Url;
+
+    use super::*;
+    use crate::{
+        asynch::clients::{exceptions::XRPLClientResult, XRPLClient},
+        models::results::XRPLResponse,
+    };
+
+    const ACCOUNT: &str = "rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx";
+    const NFT_ID: &str = "000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65";
+
+    /// Answers `account_nfts` and `nft_buy_offers` in two pages, and
+    /// `nft_sell_offers` as if there were none.
+    struct MockClient;
+
+    fn page(marker: Option<&Value>, items: Value) -> (Value, Option<&'static str>) {
+        match marker {
+            None => (serde_json::json!([items.clone(), items]), Some("page-2")),
+            Some(marker) => {
+                assert_eq!(marker, "page-2");
+                (serde_json::json!([items]), None)
+            }
+        }
+    }
+
+    impl XRPLClient for MockClient {
+        async fn request_impl<'a: 'b, 'b>(
+            &self,
+            request: XRPLRequest<'a>,
+        ) -> XRPLClientResult<XRPLResponse<'b>> {
+            let result = match &request {
+                XRPLRequest::AccountNfts(account_nfts) => {
+                    let (nfts, marker) = page(
+                        account_nfts.marker.as_ref(),
+                        serde_json::json!({
+                            "Flags": 8,
+                            "Issuer": ACCOUNT,
+                            "NFTokenID": NFT_ID,
+                            "NFTokenTaxon": 0,
+                            "nft_serial": 3429
+                        }),
+                    );
+                    serde_json::json!({
+                        "account": ACCOUNT,
+                        "account_nfts": nfts,
+                        "ledger_index": 17905,
+                        "marker": marker,
+                        "validated": true
+                    })
+                }
+                XRPLRequest::NftBuyOffers(nft_buy_offers) => {
+                    let (offers, marker) = page(
+                        nft_buy_offers.marker.as_ref(),
+                        serde_json::json!({
+                            "amount": "1500",
+                            "flags": 0,
+                            "nft_offer_index": "9E28E366573187F8E5B85CE301F229E061A619EE5A589EF740088F8843BF10A1",
+                            "owner": "rLpSRZ1E8JHyNDZeHYsQs1R5cwDCB3uuZt"
+                        }),
+                    );
+                    serde_json::json!({
+                        "nft_id": NFT_ID,
+                        "offers": offers,
+                        "marker": marker
+                    })
+                }
+                XRPLRequest::NftSellOffers(_) => {
+                    return Ok(serde_json::from_value(serde_json::json!({
+                        "error": "objectNotFound",
+                        "error_code": 92,
+                        "error_message": "The requested object was not found.",
+                        "status": "error",
+                        "type": "response"
+                    }))?)
+                }
+                request => panic!("unexpected request: {request:?}"),
+            };
+
+            Ok(serde_json::from_value(serde_json::json!({
+                "result": result,
+                "status": "success",
+                "type": "response"
+            }))?)
+        }
+
+        fn get_host(&self) -> Url {
+            Url::parse("wss://s.altnet.rippletest.net:51233").unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_account_nfts_follows_markers() {
+        let account_nfts = get_account_nfts(ACCOUNT.into(), &MockClient).await.unwrap();
+
+        assert_eq!(account_nfts.len(), 3);
+        assert!(account_nfts.iter().all(|nft| nft.nft_id == NFT_ID));
+    }
+
+    #[tokio::test]
+    async fn test_get_nft_offers() {
+        let nft_offers = get_nft_offers(NFT_ID.into(), &MockClient).await.unwrap();
+
+        assert_eq!(nft_offers.buy_offers.len(), 3);
+        assert!(nft_offers.sell_offers.is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_account_delete_blockers {
+    use super::*;
+    use crate::{
+        asynch::clients::{MockClient, MockResponse},
+        models::requests::RequestMethod,
+    };
+
+    const ACCOUNT: &str = "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn";
+
+    fn account_info(sequence: u32, balance: &str) -> MockResponse {
+        MockResponse::result(serde_json::json!({
+            "account_data": {
+                "Account": ACCOUNT,
+                "Balance": balance,
+                "Flags": 0,
+                "LedgerEntryType": "AccountRoot",
+                "OwnerCount": 3,
+                "PreviousTxnID": "4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42",
+                "PreviousTxnLgrSeq": 3,
+                "Sequence": sequence,
+                "index": "92FA6A9FC8EA6018D5D16532D7795C91BFB0831355BDFDA177E86C8BF997985F"
+            },
+            "status": "success",
+            "validated": true
+        }))
+    }
+
+    fn server_state(seq: u32) -> MockResponse {
+        MockResponse::result(serde_json::json!({
+            "state": {
+                "build_version": "2.3.0",
+                "server_state": "full",
+                "validated_ledger": {
+                    "base_fee": 10,
+                    "close_time": 787654321,
+                    "hash": "AD2B7B9F22E4E9FE3B7AC6B22A3B6D5C0F9A9DB2D4D68A0D21B0F4E6B1DAD4D1",
+                    "reserve_base": 1000000,
+                    "reserve_inc": 200000,
+                    "seq": seq
+                }
+            },
+            "status": "success"
+        }))
+    }
+
+    fn account_objects(objects: Value, marker: Option<&str>) -> MockResponse {
+        MockResponse::result(serde_json::json!({
+            "account": ACCOUNT,
+            "account_objects": objects,
+            "ledger_index": 300,
+            "marker": marker,
+            "validated": true
+        }))
+    }
+
+    fn trust_line() -> Value {
+        serde_json::json!({
+            "Balance": {
+                "currency": "USD",
+                "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji",
+                "value": "0"
+            },
+            "Flags": 65536,
+            "HighLimit": {
+                "currency": "USD",
+                "issuer": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+                "value": "0"
+            },
+            "HighNode": "0000000000000000",
+            "LedgerEntryType": "RippleState",
+            "LowLimit": {
+                "currency": "USD",
+                "issuer": ACCOUNT,
+                "value": "100"
+            },
+            "LowNode": "0000000000000000",
+            "PreviousTxnID": "BDBDD6CCF2F8211B41072BC37E934D2270F58EA5D5F44F7CA8483CF348B9377B",
+            "PreviousTxnLgrSeq": 230,
+            "index": "9CA88CDEDFF9252B3DE183CE35B038F57282BC9503CDFA1923EF9A95DF0D6F7B"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_blockers() {
+        let escrow = serde_json::json!({
+            "Account": ACCOUNT,
+            "Amount": "10000",
+            "Destination": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+            "FinishAfter": 787654400,
+            "Flags": 0,
+            "LedgerEntryType": "Escrow",
+            "OwnerNode": "0000000000000000",
+            "PreviousTxnID": "C44F2EB84196B9AD820313DBEBA6316A15C9A2D35787579ED172B87A30131DA7",
+            "PreviousTxnLgrSeq": 240,
+            "index": "DC5F3851D8A1AB622F957761E5963BC5BD439D5C24AC6AD7AC4523F0640244AC"
+        });
+        let client = MockClient::new()
+            .with_response(RequestMethod::AccountInfo, account_info(100, "150000"))
+            .with_response(RequestMethod::ServerState, server_state(300))
+            .with_response(
+                RequestMethod::AccountObjects,
+                account_objects(serde_json::json!([trust_line(), escrow]), Some("page-2")),
+            )
+            .with_response(
+                RequestMethod::AccountObjects,
+                account_objects(serde_json::json!([trust_line()]), None),
+            );
+
+        let blockers = check_account_delete_blockers(ACCOUNT.into(), &client)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            blockers,
+            [
+                AccountDeleteBlocker::SequenceTooRecent {
+                    sequence: 100,
+                    ledger_index: 300,
+                },
+                AccountDeleteBlocker::OwnedObjects {
+                    ledger_entry_type: "Escrow".into(),
+                    count: 1,
+                },
+                AccountDeleteBlocker::OwnedObjects {
+                    ledger_entry_type: "RippleState".into(),
+                    count: 2,
+                },
+                AccountDeleteBlocker::InsufficientBalance {
+                    balance: "150000".into(),
+                    fee: "200000".into(),
+                },
+            ]
+        );
+        let requests = client.requests_of(RequestMethod::AccountObjects);
+        assert_eq!(requests.len(), 2);
+        let XRPLRequest::AccountObjects(second_page) = &requests[1] else {
+            panic!("unexpected request: {:?}", requests[1]);
+        };
+        assert_eq!(second_page.deletion_blockers_only, Some(true));
+        assert_eq!(second_page.marker, Some("page-2".into()));
+    }
+
+    #[tokio::test]
+    async fn test_no_blockers() {
+        let client = MockClient::new()
+            .with_response(RequestMethod::AccountInfo, account_info(100, "10000000"))
+            .with_response(RequestMethod::ServerState, server_state(357))
+            .with_response(
+                RequestMethod::AccountObjects,
+                account_objects(serde_json::json!([]), None),
+            );
+
+        let blockers = check_account_delete_blockers(ACCOUNT.into(), &client)
+            .await
+            .unwrap();
+
+        assert!(blockers.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sequence_near_max_is_too_recent() {
+        let client = MockClient::new()
+            .with_response(
+                RequestMethod::AccountInfo,
+                account_info(u32::MAX - 1, "10000000"),
+            )
+            .with_response(RequestMethod::ServerState, server_state(357))
+            .with_response(
+                RequestMethod::AccountObjects,
+                account_objects(serde_json::json!([]), None),
+            );
+
+        let blockers = check_account_delete_blockers(ACCOUNT.into(), &client)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            blockers,
+            [AccountDeleteBlocker::SequenceTooRecent {
+                sequence: u32::MAX - 1,
+                ledger_index: 357
+            }]
+        );
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_find_payment_paths {
+    use super::*;
+    use crate::{
+        asynch::{
+            clients::{MockClient, MockResponse},
+            transaction::sign,
+        },
+        core::binarycodec::{decode, encode},
+        models::{
+            requests::RequestMethod,
+            transactions::{payment::Payment, CommonFieldsBuilder},
+            IssuedCurrencyAmount,
+        },
+        wallet::Wallet,
+    };
+
+    const DESTINATION: &str = "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59";
+    const GATEWAY: &str = "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B";
+
+    fn usd() -> Amount<'static> {
+        IssuedCurrencyAmount::new("USD".into(), GATEWAY.into(), "5".into()).into()
+    }
+
+    fn ripple_path_find(source: &str) -> MockResponse {
+        MockResponse::result(serde_json::json!({
+            "alternatives": [{
+                "paths_computed": [
+                    [{
+                        "currency": "USD",
+                        "issuer": GATEWAY,
+                        "type": 48,
+                        "type_hex": "0000000000000030"
+                    }],
+                    [{
+                        "currency": "EUR",
+                        "issuer": GATEWAY,
+                        "type": 48,
+                        "type_hex": "0000000000000030"
+                    }, {
+                        "currency": "USD",
+                        "issuer": GATEWAY,
+                        "type": 48,
+                        "type_hex": "0000000000000030"
+                    }]
+                ],
+                "source_amount": "5050505"
+            }],
+            "destination_account": DESTINATION,
+            "destination_currencies": ["USD", "XRP"],
+            "full_reply": true,
+            "ledger_current_index": 8201,
+            "source_account": source,
+            "validated": false
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_cross_currency_payment() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let client = MockClient::new().with_response(
+            RequestMethod::RipplePathFind,
+            ripple_path_find(&wallet.classic_address),
+        );
+
+        let alternatives = find_payment_paths(
+            &client,
+            wallet.classic_address.as_str().into(),
+            DESTINATION.into(),
+            usd(),
+        )
+        .await
+        .unwrap();
+
+        let requests = client.requests_of(RequestMethod::RipplePathFind);
+        let XRPLRequest::RipplePathFind(request) = &requests[0] else {
+            panic!("expected ripple_path_find");
+        };
+        assert_eq!(request.source_account, wallet.classic_address);
+        assert_eq!(request.destination_amount, usd());
+        assert_eq!(alternatives.len(), 1);
+        let alternative = alternatives.into_iter().next().unwrap();
+        assert_eq!(alternative.paths_computed.len(), 2);
+
+        let mut payment =
+            Payment::builder(wallet.classic_address.as_str(), usd(), DESTINATION.into())
+                .paths(alternative.paths_computed)
+                .send_max(alternative.source_amount)
+                .fee("12")
+                .sequence(1)
+                .build()
+                .unwrap();
+        sign(&mut payment, &wallet, false).unwrap();
+        let decoded = decode(&encode(&payment).unwrap()).unwrap();
+
+        assert_eq!(decoded["SendMax"], "5050505");
+        assert_eq!(
+            decoded["Paths"],
+            serde_json::json!([
+                [{ "currency": "USD", "issuer": GATEWAY }],
+                [
+                    { "currency": "EUR", "issuer": GATEWAY },
+                    { "currency": "USD", "issuer": GATEWAY }
+                ]
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_no_paths() {
+        let client = MockClient::new().with_response(
+            RequestMethod::RipplePathFind,
+            MockResponse::result(serde_json::json!({
+                "alternatives": [],
+                "destination_account": DESTINATION,
+                "destination_currencies": ["XRP"]
+            })),
+        );
+
+        let alternatives = find_payment_paths(
+            &client,
+            "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn".into(),
+            DESTINATION.into(),
+            usd(),
+        )
+        .await
+        .unwrap();
+
+        assert!(alternatives.is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_is_deposit_authorized {
+    use super::*;
+    use crate::{
+        asynch::clients::{MockClient, MockResponse},
+        models::requests::RequestMethod,
+    };
+
+    const SOURCE: &str = "rEhxGqkqPPSxQ3P25J66ft5TwpzV14k2de";
+    const DESTINATION: &str = "rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8";
+
+    fn deposit_authorized(authorized: bool) -> MockResponse {
+        MockResponse::result(serde_json::json!({
+            "deposit_authorized": authorized,
+            "destination_account": DESTINATION,
+            "ledger_index": 8,
+            "source_account": SOURCE,
+            "validated": true
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_is_deposit_authorized() {
+        let client = MockClient::new()
+            .with_response(RequestMethod::DepositAuthorized, deposit_authorized(true))
+            .with_response(RequestMethod::DepositAuthorized, deposit_authorized(false));
+
+        assert!(
+            is_deposit_authorized(&client, SOURCE.into(), DESTINATION.into())
+                .await
+                .unwrap()
+        );
+        assert!(
+            !is_deposit_authorized(&client, SOURCE.into(), DESTINATION.into())
+                .await
+                .unwrap()
+        );
+
+        let requests = client.requests_of(RequestMethod::DepositAuthorized);
+        let XRPLRequest::DepositAuthorized(request) = &requests[0] else {
+            panic!("expected deposit_authorized");
+        };
+        assert_eq!(request.source_account, SOURCE);
+        assert_eq!(request.destination_account, DESTINATION);
+        assert_eq!(request.ledger_index.as_deref(), Some("validated"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_account() {
+        let client = MockClient::new().with_response(
+            RequestMethod::DepositAuthorized,
+            MockResponse::error("dstActNotFound", 18, Some("Destination account not found.")),
+        );
+
+        assert!(
+            is_deposit_authorized(&client, SOURCE.into(), DESTINATION.into())
+                .await
+                .is_err()
+        );
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_payment_channels {
+    use super::*;
+    use crate::{
+        asynch::clients::{MockClient, MockResponse},
+        models::requests::RequestMethod,
+    };
+
+    const ACCOUNT: &str = "rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH";
+    const CHANNEL_ID: &str = "5DB01B7FFED6B67E6B0414DED11E051D2EE2B7619CE0EAA6286D67A3A4D5BDB3";
+    const PUBLIC_KEY: &str = "aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3";
+    const SIGNATURE: &str = "304402204EF0AFB78AC23ED1C472E74F4299C0C21F1B21D07EFC0A3838A420F76D783A400220154FB11B6F54320666E4C36CA7F686C16A3A0456800BBC43746F34AF50290064";
+
+    fn account_channels(channel_ids: &[&str], marker: Option<&str>) -> MockResponse {
+        let channels: Vec<Value> = channel_ids
+            .iter()
+            .map(|channel_id| {
+                serde_json::json!({
+                    "account": ACCOUNT,
+                    "amount": "100000000",
+                    "balance": "1000000",
+                    "channel_id": channel_id,
+                    "destination_account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                    "public_key": PUBLIC_KEY,
+                    "public_key_hex": "023693F15967AE357D0327974AD46FE3C127113B1110D6044FD41E723689F81CC6",
+                    "settle_delay": 86400,
+                    "expiration": 787654400
+                })
+            })
+            .collect();
+
+        MockResponse::result(serde_json::json!({
+            "account": ACCOUNT,
+            "channels": channels,
+            "ledger_index": 300,
+            "marker": marker,
+            "validated": true
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_get_account_channels_follows_markers() {
+        let second_channel_id = "C7F634794B79DB40E87179A9D1BF05D05797AE7E92DF8E93FD6656E8C4BE3AE7";
+        let client = MockClient::new()
+            .with_response(
+                RequestMethod::AccountChannels,
+                account_channels(&[CHANNEL_ID], Some("page-2")),
+            )
+            .with_response(
+                RequestMethod::AccountChannels,
+                account_channels(&[second_channel_id], None),
+            );
+
+        let channels = get_account_channels(ACCOUNT.into(), &client).await.unwrap();
+
+        assert_eq!(
+            channels
+                .iter()
+                .map(|channel| channel.channel_id.as_ref())
+                .collect::<Vec<_>>(),
+            [CHANNEL_ID, second_channel_id]
+        );
+        assert_eq!(channels[0].balance, XRPAmount::from("1000000"));
+        assert_eq!(channels[0].settle_delay, 86400);
+        assert_eq!(channels[0].expiration, Some(787654400));
+        assert_eq!(channels[0].cancel_after, None);
+
+        let requests = client.requests_of(RequestMethod::AccountChannels);
+        let markers: Vec<_> = requests
+            .iter()
+            .map(|request| {
+                let XRPLRequest::AccountChannels(request) = request else {
+                    panic!("expected account_channels");
+                };
+                request.marker.clone()
+            })
+            .collect();
+        assert_eq!(markers, [None, Some("page-2".into())]);
+    }
+
+    #[tokio::test]
+    async fn test_verify_payment_channel_claim() {
+        let client = MockClient::new().with_response(
+            RequestMethod::ChannelVerify,
+            MockResponse::result(serde_json::json!({
+                "signature_verified": true,
+                "status": "success"
+            })),
+        );
+
+        assert!(verify_payment_channel_claim(
+            &client,
+            CHANNEL_ID.into(),
+            "1000000".into(),
+            SIGNATURE.into(),
+            PUBLIC_KEY.into(),
+        )
+        .await
+        .unwrap());
+
+        let requests = client.requests_of(RequestMethod::ChannelVerify);
+        let XRPLRequest::ChannelVerify(request) = &requests[0] else {
+            panic!("expected channel_verify");
+        };
+        assert_eq!(request.channel_id, CHANNEL_ID);
+        assert_eq!(request.amount, XRPAmount::from("1000000"));
+        assert_eq!(request.signature, SIGNATURE);
+        assert_eq!(request.public_key, PUBLIC_KEY);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_issuer_obligations {
+    use super::*;
+    use crate::{
+        asynch::clients::{MockClient, MockResponse},
+        models::requests::RequestMethod,
+    };
+    use alloc::vec;
+
+    const ISSUER: &str = "rMwjYedjc7qqtKYVLiAccJSmCwih4LnE2q";
+    const HOT_WALLET: &str = "rKm4uWpg9tfwbVSeATv4KxDe6mpE9yPkgJ";
+
+    #[tokio::test]
+    async fn test_get_issuer_obligations() {
+        let client = MockClient::new().with_response(
+            RequestMethod::GatewayBalances,
+            MockResponse::result(serde_json::json!({
+                "account": ISSUER,
+                "balances": {
+                    HOT_WALLET: [{"currency": "EUR", "value": "29826.1965999999"}]
+                },
+                "ledger_hash": "980FECF48CA4BFDEC896692C31A50D484BDFE865EC101B00259C2E8A8B03BB1C",
+                "ledger_index": 14483212,
+                "obligations": {
+                    "USD": "1997134.20229482",
+                    "BTC": "5908.324927635318",
+                    "EUR": "992471.7419793958",
+                    "015841551A748AD2C1F76FF6ECB0CCCD00000000": "1e-3"
+                },
+                "validated": true
+            })),
+        );
+
+        let obligations = get_issuer_obligations(&client, ISSUER.into(), vec![HOT_WALLET.into()])
+            .await
+            .unwrap();
+        assert_eq!(
+            obligations,
+            [
+                (
+                    "015841551A748AD2C1F76FF6ECB0CCCD00000000".into(),
+                    BigDecimal::from_str("0.001").unwrap()
+                ),
+                (
+                    "BTC".into(),
+                    BigDecimal::from_str("5908.324927635318").unwrap()
+                ),
+                (
+                    "EUR".into(),
+                    BigDecimal::from_str("992471.7419793958").unwrap()
+                ),
+                (
+                    "USD".into(),
+                    BigDecimal::from_str("1997134.20229482").unwrap()
+                ),
+            ]
+        );
+
+        let requests = client.requests_of(RequestMethod::GatewayBalances);
+        let XRPLRequest::GatewayBalances(request) = &requests[0] else {
+            panic!("expected gateway_balances");
+        };
+        assert_eq!(request.account, ISSUER);
+        assert_eq!(request.hotwallet, Some(vec![HOT_WALLET.into()]));
+        assert_eq!(request.ledger_index.as_deref(), Some("validated"));
+    }
+
+    #[tokio::test]
+    async fn test_nothing_issued() {
+        let client = MockClient::new().with_response(
+            RequestMethod::GatewayBalances,
+            MockResponse::result(serde_json::json!({
+                "account": ISSUER,
+                "ledger_index": 14483212,
+                "validated": true
+            })),
+        );
+
+        let obligations = get_issuer_obligations(&client, ISSUER.into(), Vec::new())
+            .await
+            .unwrap();
+        assert!(obligations.is_empty());
+
+        let requests = client.requests_of(RequestMethod::GatewayBalances);
+        let XRPLRequest::GatewayBalances(request) = &requests[0] else {
+            panic!("expected gateway_balances");
+        };
+        assert_eq!(request.hotwallet, None);
+    }
+
+    #[tokio::test]
+    async fn test_unexpected_result() {
+        // Not a gateway_balances result, e.g. from a misbehaving proxy.
+        let client = MockClient::new().with_response(
+            RequestMethod::GatewayBalances,
+            MockResponse::result(serde_json::json!({
+                "ledger_index": 14483212,
+                "validated": true
+            })),
+        );
+
+        assert!(get_issuer_obligations(&client, ISSUER.into(), Vec::new())
+            .await
+            .is_err());
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_owner_reserve {
+    use super::*;
+    use crate::{
+        asynch::clients::{MockClient, MockResponse},
+        models::requests::RequestMethod,
+    };
+
+    const ACCOUNT: &str = "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn";
+    const PREVIOUS_TXN_ID: &str =
+        "4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42";
+
+    fn trust_line(low: &str, high: &str, flags: u32) -> Value {
+        serde_json::json!({
+            "Balance": {"currency": "USD", "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji", "value": "0"},
+            "Flags": flags,
+            "HighLimit": {"currency": "USD", "issuer": high, "value": "0"},
+            "HighNode": "0000000000000000",
+            "LedgerEntryType": "RippleState",
+            "LowLimit": {"currency": "USD", "issuer": low, "value": "100"},
+            "LowNode": "0000000000000000",
+            "PreviousTxnID": PREVIOUS_TXN_ID,
+            "PreviousTxnLgrSeq": 230
+        })
+    }
+
+    fn ticket(ticket_sequence: u32) -> Value {
+        serde_json::json!({
+            "Account": ACCOUNT,
+            "Flags": 0,
+            "LedgerEntryType": "Ticket",
+            "OwnerNode": "0000000000000000",
+            "PreviousTxnID": PREVIOUS_TXN_ID,
+            "PreviousTxnLgrSeq": 4,
+            "TicketSequence": ticket_sequence
+        })
+    }
+
+    /// One object of each kind the owner reserve counts differently.
+    fn account_objects() -> Vec<Value> {
+        alloc::vec![
+            serde_json::json!({
+                "Account": ACCOUNT,
+                "BookDirectory": "DFA3B6DDAB58C7E8E5D944E736DA4B7046C30E4F460FD9DE4C124AF94ED1781B",
+                "BookNode": "0000000000000000",
+                "Flags": 0,
+                "LedgerEntryType": "Offer",
+                "OwnerNode": "0000000000000000",
+                "PreviousTxnID": PREVIOUS_TXN_ID,
+                "PreviousTxnLgrSeq": 14524914,
+                "Sequence": 866,
+                "TakerGets": "2000000",
+                "TakerPays": {"currency": "USD", "issuer": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys", "value": "1"}
+            }),
+            // The account holds the reserve of the low side.
+            trust_line(ACCOUNT, "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys", 0x00010000),
+            // The other account holds the reserve of this one.
+            trust_line("rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys", ACCOUNT, 0x00010000),
+            serde_json::json!({
+                "Account": ACCOUNT,
+                "Amount": "10000",
+                "Destination": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+                "Flags": 0,
+                "LedgerEntryType": "Escrow",
+                "OwnerNode": "0000000000000000",
+                "PreviousTxnID": PREVIOUS_TXN_ID,
+                "PreviousTxnLgrSeq": 240
+            }),
+            serde_json::json!({
+                "Account": ACCOUNT,
+                "Amount": "4325800",
+                "Balance": "2323423",
+                "Destination": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+                "Flags": 0,
+                "LedgerEntryType": "PayChannel",
+                "OwnerNode": "0000000000000000",
+                "PreviousTxnID": PREVIOUS_TXN_ID,
+                "PreviousTxnLgrSeq": 14524914,
+                "PublicKey": "32D2471DB72B27E3310F355BB33E339BF26F8392D5A93D3BC0FC3B566612DA0F0A",
+                "SettleDelay": 3600
+            }),
+            // From before MultiSignReserve: two plus one per signer.
+            serde_json::json!({
+                "Flags": 0,
+                "LedgerEntryType": "SignerList",
+                "OwnerNode": "0000000000000000",
+                "PreviousTxnID": PREVIOUS_TXN_ID,
+                "PreviousTxnLgrSeq": 7,
+                "SignerEntries": [
+                    {"SignerEntry": {"Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW", "SignerWeight": 2}},
+                    {"SignerEntry": {"Account": "raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n", "SignerWeight": 1}},
+                    {"SignerEntry": {"Account": "rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v", "SignerWeight": 1}}
+                ],
+                "SignerListID": 0,
+                "SignerQuorum": 3
+            }),
+            serde_json::json!({
+                "Flags": 0,
+                "LedgerEntryType": "NFTokenPage",
+                "NFTokens": [{
+                    "NFToken": {
+                        "NFTokenID": "000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65"
+                    }
+                }]
+            }),
+            ticket(3),
+            ticket(4),
+            serde_json::json!({
+                "Flags": 0,
+                "Indexes": [],
+                "LedgerEntryType": "DirectoryNode",
+                "Owner": ACCOUNT,
+                "RootIndex": "4B6C4E7E8F7D6BDA27EC1B5A3D6DC4D6AA1DF0C8C2B6E6B1C4F79C1C5A83C05B"
+            }),
+            // A type without model takes one reserve.
+            serde_json::json!({
+                "Account": ACCOUNT,
+                "Flags": 0,
+                "LedgerEntryType": "DID",
+                "OwnerNode": "0000000000000000"
+            }),
+        ]
+    }
+
+    fn expected_owner_reserves() -> BTreeMap<String, u32> {
+        [
+            ("DID", 1),
+            ("Escrow", 1),
+            ("NFTokenPage", 1),
+            ("Offer", 1),
+            ("PayChannel", 1),
+            ("RippleState", 1),
+            ("SignerList", 5),
+            ("Ticket", 2),
+        ]
+        .into_iter()
+        .map(|(ledger_entry_type, count)| (ledger_entry_type.to_string(), count))
+        .collect()
+    }
+
+    #[test]
+    fn test_count_owner_reserves() {
+        let objects = account_objects()
+            .into_iter()
+            .map(|object| serde_json::from_value(object).unwrap())
+            .collect::<Vec<LedgerEntry>>();
+        assert_eq!(
+            objects
+                .iter()
+                .filter(|object| matches!(object, LedgerEntry::Other(_)))
+                .count(),
+            1
+        );
+
+        assert_eq!(
+            count_owner_reserves(ACCOUNT, &objects),
+            expected_owner_reserves()
+        );
+
+        // Since MultiSignReserve, a signer list takes one reserve.
+        let mut signer_list = account_objects()[5].clone();
+        signer_list["Flags"] = 0x00010000.into();
+        let objects = [serde_json::from_value(signer_list).unwrap()];
+        assert_eq!(
+            count_owner_reserves(ACCOUNT, &objects),
+            BTreeMap::from([("SignerList".to_string(), 1)])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_owner_reserve_requirement() {
+        let objects = account_objects();
+        let account_objects = |objects: &[Value], marker: Option<&str>| {
+            MockResponse::result(serde_json::json!({
+                "account": ACCOUNT,
+                "account_objects": objects,
+                "ledger_index": 300,
+                "marker": marker,
+                "validated": true
+            }))
+        };
+        let client = MockClient::new()
+            .with_response(
+                RequestMethod::AccountObjects,
+                account_objects(&objects[..6], Some("page-2")),
+            )
+            .with_response(
+                RequestMethod::AccountObjects,
+                account_objects(&objects[6..], None),
+            )
+            .with_response(
+                RequestMethod::ServerState,
+                MockResponse::result(serde_json::json!({
+                    "state": {
+                        "build_version": "2.3.0",
+                        "server_state": "full",
+                        "validated_ledger": {
+                            "base_fee": 10,
+                            "close_time": 787654321,
+                            "hash": "AD2B7B9F22E4E9FE3B7AC6B22A3B6D5C0F9A9DB2D4D68A0D21B0F4E6B1DAD4D1",
+                            "reserve_base": 1000000,
+                            "reserve_inc": 200000,
+                            "seq": 300
+                        }
+                    },
+                    "status": "success"
+                })),
+            );
+
+        let owner_reserve = get_owner_reserve_requirement(ACCOUNT.into(), &client)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            owner_reserve,
+            OwnerReserve {
+                owner_reserves: expected_owner_reserves(),
+                reserve_increment: "200000".into(),
+                // 13 reserves of 0.2 XRP.
+                total: "2600000".into(),
+            }
+        );
+        let requests = client.requests_of(RequestMethod::AccountObjects);
+        assert_eq!(requests.len(), 2);
+        let XRPLRequest::AccountObjects(first_page) = &requests[0] else {
+            panic!("unexpected request: {:?}", requests[0]);
+        };
+        assert_eq!(first_page.deletion_blockers_only, None);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_signer_list {
+    use super::*;
+    use crate::{
+        asynch::clients::{MockClient, MockResponse},
+        models::{
+            requests::RequestMethod, results::account_info::test::account_info_with_signer_list,
+        },
+    };
+
+    const ACCOUNT: &str = "rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8";
+
+    #[tokio::test]
+    async fn test_get_signer_list() {
+        let mut without_signer_list = account_info_with_signer_list();
+        without_signer_list["signer_lists"] = serde_json::json!([]);
+        let client = MockClient::new()
+            .with_response(
+                RequestMethod::AccountInfo,
+                MockResponse::result(account_info_with_signer_list()),
+            )
+            .with_response(
+                RequestMethod::AccountInfo,
+                MockResponse::result(without_signer_list),
+            );
+
+        let signer_list = get_signer_list(ACCOUNT.into(), &client)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(signer_list.signer_quorum, 3);
+        assert_eq!(signer_list.signer_entries.len(), 3);
+        assert!(get_signer_list(ACCOUNT.into(), &client)
+            .await
+            .unwrap()
+            .is_none());
+
+        let requests = client.requests_of(RequestMethod::AccountInfo);
+        let XRPLRequest::AccountInfo(request) = &requests[0] else {
+            panic!("expected account_info");
+        };
+        assert_eq!(request.account, ACCOUNT);
+        assert_eq!(request.signer_lists, Some(true));
+        assert_eq!(request.ledger_index.as_deref(), Some("validated"));
+    }
+
+    #[tokio::test]
+    async fn test_can_meet_quorum() {
+        let client = MockClient::new().with_response(
+            RequestMethod::AccountInfo,
+            MockResponse::result(account_info_with_signer_list()),
+        );
+        let signer_list = get_signer_list(ACCOUNT.into(), &client)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Weights 2, 1 and 1 with a quorum of 3.
+        let heavy = "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW";
+        let light = "raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n";
+        let other_light = "rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v";
+        assert!(can_meet_quorum(&signer_list, &[heavy, light]));
+        assert!(can_meet_quorum(&signer_list, &[light, heavy, other_light]));
+        assert!(!can_meet_quorum(&signer_list, &[heavy]));
+        assert!(!can_meet_quorum(&signer_list, &[light, other_light]));
+        // Signing twice or with accounts not on the list does not help.
+        assert!(!can_meet_quorum(&signer_list, &[light, light, other_light]));
+        assert!(!can_meet_quorum(&signer_list, &[heavy, ACCOUNT]));
+        assert!(!can_meet_quorum(&signer_list, &[]));
+    }
+}
@@ -1,16 +1,71 @@
-use super::{client::XRPLClient, exceptions::XRPLClientResult, CommonFields};
-use crate::models::{
-    requests::{server_state::ServerState, XRPLRequest},
-    results::{server_state::ServerState as ServerStateResult, XRPLResponse},
+use alloc::{boxed::Box, string::ToString};
+use core::{future::Future, pin::Pin, time::Duration};
+use serde_json::Value;
+use url::Url;
+
+use super::{
+    client::XRPLClient,
+    exceptions::{XRPLClientException, XRPLClientResult},
+    CommonFields, Network,
+};
+use crate::{
+    asynch::with_timeout,
+    models::{
+        requests::{raw::Raw, server_state::ServerState, RequestMethod, XRPLRequest},
+        results::{server_state::ServerState as ServerStateResult, XRPLResponse, XRPLResult},
+        XRPLModelException,
+    },
+    XRPLSerdeJsonError,
 };
 
 #[allow(async_fn_in_trait)]
 pub trait XRPLAsyncClient: XRPLClient {
+    /// Send a request and wait for its response, at most for the
+    /// client's timeout.
+    ///
+    /// Requests that send a secret key to the server are refused, even
+    /// if they were opted in with `allow_insecure_remote_signing()`.
+    /// Admin and standalone setups that really need them can go through
+    /// `request_impl` directly.
+    ///
+    /// Requests that do not set an API version are sent with the
+    /// client's, see `get_api_version`.
     async fn request<'a: 'b, 'b>(
         &self,
-        request: XRPLRequest<'a>,
+        mut request: XRPLRequest<'a>,
+    ) -> XRPLClientResult<XRPLResponse<'b>> {
+        self.set_api_version(&mut request);
+        send(self, request, self.get_timeout()).await
+    }
+
+    /// Like `request`, but wait at most `timeout` instead of the
+    /// client's timeout. Dropping the returned future cancels the
+    /// request.
+    async fn request_with_timeout<'a: 'b, 'b>(
+        &self,
+        mut request: XRPLRequest<'a>,
+        timeout: Duration,
+    ) -> XRPLClientResult<XRPLResponse<'b>> {
+        self.set_api_version(&mut request);
+        send(self, request, Some(timeout)).await
+    }
+
+    /// Send a request given as its method name and JSON `params`, for
+    /// methods the request models do not cover yet. The `result` of the
+    /// response is left as JSON; lift it into a typed result with
+    /// `try_into_result` when its shape is known. A result that already
+    /// has the shape of a typed result keeps only the typed fields.
+    ///
+    /// Like `request`, this waits at most for the client's timeout and
+    /// refuses to send secret keys.
+    async fn request_raw<'b>(
+        &self,
+        method: &str,
+        params: Value,
     ) -> XRPLClientResult<XRPLResponse<'b>> {
-        self.request_impl(request).await
+        let response = self.request(raw_request(method, params)?).await?;
+
+        into_raw_response(response)
     }
 
     async fn get_common_fields(&self) -> XRPLClientResult<CommonFields<'_>> {
@@ -28,3 +83,172 @@ pub trait XRPLAsyncClient: XRPLClient {
 }
 
 impl<T: XRPLClient> XRPLAsyncClient for T {}
+
+/// The future returned by [`DynClient::request_dyn`].
+pub type DynClientFuture<'c, T> = Pin<Box<dyn Future<Output = XRPLClientResult<T>> + 'c>>;
+
+/// An object safe view of a client, for storing clients picked at
+/// runtime as `Box<dyn DynClient>`, e.g. a WebSocket or a JSON-RPC
+/// client depending on configuration.
+///
+/// Every [`XRPLAsyncClient`] is a `DynClient`. The returned futures
+/// are not `Send`, as the futures of the clients they wrap need not
+/// be either.
+///
+/// # Examples
+///
+/// ```no_run
+/// use xrpl::asynch::clients::{AsyncJsonRpcClient, AsyncWebSocketClient, DynClient, SingleExecutorMutex};
+/// use xrpl::models::requests::ledger_current::LedgerCurrent;
+///
+/// # #[cfg(feature = "std")]
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let url = std::env::var("XRPL_URL").unwrap().parse().unwrap();
+/// let client: Box<dyn DynClient> = if std::env::var("XRPL_USE_WEBSOCKET").is_ok() {
+///     Box::new(AsyncWebSocketClient::<SingleExecutorMutex, _>::open(url).await.unwrap())
+/// } else {
+///     Box::new(AsyncJsonRpcClient::connect(url))
+/// };
+///
+/// let response = client
+///     .request_dyn(LedgerCurrent::new(None).into())
+///     .await
+///     .unwrap();
+/// # });
+/// ```
+pub trait DynClient {
+    /// Send a request like [`XRPLAsyncClient::request`].
+    fn request_dyn<'a: 'b, 'b: 'c, 'c>(
+        &'c self,
+        request: XRPLRequest<'a>,
+    ) -> DynClientFuture<'c, XRPLResponse<'b>>;
+
+    /// Like [`XRPLClient::get_host`].
+    fn host(&self) -> Url;
+
+    /// Like [`XRPLClient::get_network`].
+    fn network(&self) -> Option<&Network>;
+}
+
+impl<T: XRPLAsyncClient> DynClient for T {
+    fn request_dyn<'a: 'b, 'b: 'c, 'c>(
+        &'c self,
+        request: XRPLRequest<'a>,
+    ) -> DynClientFuture<'c, XRPLResponse<'b>> {
+        Box::pin(self.request(request))
+    }
+
+    fn host(&self) -> Url {
+        self.get_host()
+    }
+
+    fn network(&self) -> Option<&Network> {
+        self.get_network()
+    }
+}
+
+/// Send a request unless it sends a secret key, waiting at most
+/// `timeout` for the response.
+async fn send<'a: 'b, 'b, C>(
+    client: &C,
+    request: XRPLRequest<'a>,
+    timeout: Option<Duration>,
+) -> XRPLClientResult<XRPLResponse<'b>>
+where
+    C: XRPLClient + ?Sized,
+{
+    #[cfg(feature = "tracing")]
+    let span = tracing::debug_span!(
+        "request",
+        method = %request.method_name(),
+        id = crate::models::requests::Request::get_common_fields(&request)
+            .id
+            .as_deref(),
+    );
+    let response = async {
+        refuse_remote_signing(&request)?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(request = %crate::_tracing::payload(&request), "sending request");
+        match timeout {
+            Some(timeout) => match with_timeout(client.request_impl(request), timeout).await? {
+                Some(response) => response,
+                None => Err(XRPLClientException::RequestTimeout(timeout)),
+            },
+            None => client.request_impl(request).await,
+        }
+    };
+    #[cfg(feature = "tracing")]
+    let response = tracing::Instrument::instrument(response, span.clone());
+    let response = response.await;
+    #[cfg(feature = "tracing")]
+    span.in_scope(|| trace_response(&response));
+
+    response
+}
+
+/// Record the response of a request, and errors with the rippled
+/// error code if there is one.
+#[cfg(feature = "tracing")]
+fn trace_response(response: &XRPLClientResult<XRPLResponse<'_>>) {
+    match response {
+        Ok(response) if response.error.is_none() => {
+            tracing::debug!(response = %crate::_tracing::payload(response), "received response");
+        }
+        Ok(response) => tracing::error!(
+            error = response.error.as_deref(),
+            error_code = response.error_code,
+            error_message = response.error_message.as_deref(),
+            "request failed",
+        ),
+        Err(error) => tracing::error!(%error, "request failed"),
+    }
+}
+
+pub(crate) fn refuse_remote_signing(request: &XRPLRequest<'_>) -> XRPLClientResult<()> {
+    if request.sends_secret() {
+        // Raw `sign` and `sign_for` requests may send one too.
+        let (request, local_alternative) = match request.method() {
+            RequestMethod::Sign => ("sign", "xrpl::transaction::sign"),
+            RequestMethod::SignFor => ("sign_for", "xrpl::transaction::sign"),
+            _ => ("channel_authorize", "xrpl::core::keypairs::sign"),
+        };
+        return Err(XRPLModelException::InsecureRemoteSigning {
+            request,
+            local_alternative,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Build the request of `request_raw`. The parameters must be an
+/// object, or null for none.
+pub(crate) fn raw_request(method: &str, params: Value) -> XRPLClientResult<XRPLRequest<'static>> {
+    let params = match params {
+        Value::Object(params) => params,
+        Value::Null => Default::default(),
+        found => {
+            return Err(XRPLSerdeJsonError::UnexpectedValueType {
+                expected: "Object".into(),
+                found,
+            }
+            .into())
+        }
+    };
+
+    Ok(Raw::new(None, method.to_string().into(), params).into())
+}
+
+/// Leave the result of a `request_raw` response as JSON, even if it
+/// has the shape of a typed result.
+pub(crate) fn into_raw_response(
+    mut response: XRPLResponse<'_>,
+) -> XRPLClientResult<XRPLResponse<'_>> {
+    if let Some(result) = response.result.take() {
+        let result: Value = result.try_into()?;
+        response.result = Some(XRPLResult::from(result));
+    }
+
+    Ok(response)
+}
@@ -0,0 +1,64 @@
+#[cfg(feature = "std")]
+use bigdecimal::{BigDecimal, FromPrimitive, RoundingMode, ToPrimitive};
+use core::time::Duration;
+
+use crate::utils::Ratio;
+
+/// Exponentially growing delays with jitter, shared by the retries
+/// of an [`AsyncRateLimitedClient`](super::AsyncRateLimitedClient)
+/// and the reconnects of a websocket client.
+///
+/// Try `n` waits `base_delay * 2^(n - 1)`, capped at `max_delay`
+/// and shortened by up to `jitter` of itself, so many clients
+/// backing off at once don't try again at the same time. Without
+/// the `std` feature there is no randomness and the full delay is
+/// waited.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExponentialBackoff {
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    /// The share of each delay that is randomized, from none to
+    /// all of it. Ratios outside are clamped.
+    pub jitter: Ratio,
+}
+
+impl ExponentialBackoff {
+    pub fn new(base_delay: Duration, max_delay: Duration, jitter: Ratio) -> Self {
+        Self {
+            base_delay,
+            max_delay,
+            jitter: clamp(jitter),
+        }
+    }
+
+    /// The delay before try `n`, counting from 1.
+    pub fn delay(&self, n: u32) -> Duration {
+        let exponent = n.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        #[cfg(feature = "std")]
+        let delay = {
+            let nanos = BigDecimal::from_u128(delay.as_nanos()).unwrap_or_default();
+            let random = BigDecimal::from_f64(rand::random::<f64>()).unwrap_or_default();
+            // Clamped, as the field can be set to anything.
+            let shortening = &nanos * &clamp(self.jitter.clone()) * random;
+            (nanos - shortening)
+                .with_scale_round(0, RoundingMode::Down)
+                .to_u64()
+                .map_or(delay, Duration::from_nanos)
+        };
+
+        delay
+    }
+}
+
+/// `jitter` between no and full jitter.
+fn clamp(jitter: Ratio) -> Ratio {
+    jitter.clamp(
+        Ratio::from_basis_points(0),
+        Ratio::from_basis_points(10_000),
+    )
+}
@@ -0,0 +1,459 @@
+#[cfg(feature = "std")]
+use alloc::time::Instant;
+use alloc::{format, string::String};
+use core::cell::RefCell;
+use core::time::Duration;
+use embassy_sync::blocking_mutex::{raw::RawMutex, Mutex};
+use fnv::FnvBuildHasher;
+use indexmap::IndexMap;
+use serde_json::Value;
+use url::Url;
+
+use crate::models::{
+    requests::{Request, XRPLRequest},
+    results::XRPLResponse,
+};
+
+use super::{
+    client::{ApiVersion, XRPLClient},
+    exceptions::XRPLClientResult,
+    Network, SingleExecutorMutex,
+};
+
+/// Hit and miss counters of the immutable query cache.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// How many responses are currently cached.
+    pub entries: usize,
+}
+
+struct CacheEntry {
+    response: XRPLResponse<'static>,
+    /// Negative results only stay valid for a while; the rest never expire.
+    #[cfg(feature = "std")]
+    expires_at: Option<Instant>,
+}
+
+struct ImmutableCache {
+    capacity: usize,
+    /// Ordered from least to most recently used.
+    entries: IndexMap<String, CacheEntry, FnvBuildHasher>,
+    hits: u64,
+    misses: u64,
+}
+
+impl ImmutableCache {
+    fn get(&mut self, key: &str) -> Option<XRPLResponse<'static>> {
+        let index = self.entries.get_index_of(key)?;
+        #[cfg(feature = "std")]
+        if self.entries[index]
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= Instant::now())
+        {
+            self.entries.shift_remove_index(index);
+            return None;
+        }
+        let last = self.entries.len() - 1;
+        self.entries.move_index(index, last);
+
+        Some(self.entries[last].response.clone())
+    }
+
+    fn insert(&mut self, key: String, entry: CacheEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            self.entries.shift_remove_index(0);
+        }
+        self.entries.insert(key, entry);
+    }
+}
+
+/// A client that caches the answers to queries that can never change:
+/// validated transactions and validated ledgers looked up by hash. Cached responses are kept in a least recently used cache of
+/// a fixed capacity; every other request goes straight to the wrapped
+/// client.
+pub struct AsyncCachingClient<C, M = SingleExecutorMutex>
+where
+    C: XRPLClient,
+    M: RawMutex,
+{
+    client: C,
+    cache: Mutex<M, RefCell<ImmutableCache>>,
+    #[cfg(feature = "std")]
+    negative_ttl: Option<Duration>,
+}
+
+impl<C, M> AsyncCachingClient<C, M>
+where
+    C: XRPLClient,
+    M: RawMutex,
+{
+    /// Wrap `client`, caching up to `capacity` immutable responses.
+    pub fn new(client: C, capacity: usize) -> Self {
+        Self {
+            client,
+            cache: Mutex::new(RefCell::new(ImmutableCache {
+                capacity,
+                entries: IndexMap::default(),
+                hits: 0,
+                misses: 0,
+            })),
+            #[cfg(feature = "std")]
+            negative_ttl: None,
+        }
+    }
+
+    /// Also cache `txnNotFound` answers for `ttl`, when the server
+    /// searched the whole ledger range given by `min_ledger` and
+    /// `max_ledger`.
+    #[cfg(feature = "std")]
+    pub fn with_negative_ttl(mut self, ttl: Duration) -> Self {
+        self.negative_ttl = Some(ttl);
+        self
+    }
+
+    pub fn get_ref(&self) -> &C {
+        &self.client
+    }
+
+    pub fn into_inner(self) -> C {
+        self.client
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.cache.lock(|cache| {
+            let cache = cache.borrow();
+            CacheStats {
+                hits: cache.hits,
+                misses: cache.misses,
+                entries: cache.entries.len(),
+            }
+        })
+    }
+
+    pub fn clear(&self) {
+        self.cache.lock(|cache| cache.borrow_mut().entries.clear());
+    }
+
+    fn cache_entry(
+        &self,
+        request: &XRPLRequest<'_>,
+        response: &XRPLResponse<'_>,
+    ) -> Option<CacheEntry> {
+        if is_validated(response) {
+            return Some(CacheEntry {
+                response: to_owned_response(response).ok()?,
+                #[cfg(feature = "std")]
+                expires_at: None,
+            });
+        }
+        #[cfg(feature = "std")]
+        if is_not_found_in_closed_range(request, response) {
+            return Some(CacheEntry {
+                response: to_owned_response(response).ok()?,
+                expires_at: Some(Instant::now() + self.negative_ttl?),
+            });
+        }
+        #[cfg(not(feature = "std"))]
+        let _ = request;
+
+        None
+    }
+}
+
+impl<C, M> XRPLClient for AsyncCachingClient<C, M>
+where
+    C: XRPLClient,
+    M: RawMutex,
+{
+    async fn request_impl<'a: 'b, 'b>(
+        &self,
+        request: XRPLRequest<'a>,
+    ) -> XRPLClientResult<XRPLResponse<'b>> {
+        let Some(key) = cache_key(&request)? else {
+            return self.client.request_impl(request).await;
+        };
+        let cached = self.cache.lock(|cache| {
+            let mut cache = cache.borrow_mut();
+            let cached = cache.get(&key);
+            if cached.is_some() {
+                cache.hits += 1;
+            } else {
+                cache.misses += 1;
+            }
+            cached
+        });
+        if let Some(mut response) = cached {
+            response.id = request
+                .get_common_fields()
+                .id
+                .clone()
+                .map(|id| id.into_owned().into());
+            return Ok(response);
+        }
+
+        let response = self.client.request_impl(request.clone()).await?;
+        if let Some(entry) = self.cache_entry(&request, &response) {
+            self.cache
+                .lock(|cache| cache.borrow_mut().insert(key, entry));
+        }
+
+        Ok(response)
+    }
+
+    fn get_host(&self) -> Url {
+        self.client.get_host()
+    }
+
+    fn get_timeout(&self) -> Option<Duration> {
+        self.client.get_timeout()
+    }
+
+    fn get_api_version(&self) -> Option<ApiVersion> {
+        self.client.get_api_version()
+    }
+
+    fn get_network(&self) -> Option<&Network> {
+        self.client.get_network()
+    }
+}
+
+/// Only transactions and ledgers looked up by hash, and transactions
+/// looked up by CTID, are cacheable. The key holds every parameter of
+/// the request, as options like `binary` and `expand` change the shape
+/// of the answer.
+fn cache_key(request: &XRPLRequest<'_>) -> XRPLClientResult<Option<String>> {
+    let by_id = match request {
+        XRPLRequest::Tx(tx) => tx.transaction.is_some() || tx.ctid.is_some(),
+        XRPLRequest::Ledger(ledger) => ledger.ledger_hash.is_some(),
+        _ => false,
+    };
+    if !by_id {
+        return Ok(None);
+    }
+
+    Ok(Some(format!(
+        "{}:{}",
+        request.method(),
+        serde_json::to_string(request)?
+    )))
+}
+
+fn result_value(response: &XRPLResponse<'_>) -> Option<Value> {
+    serde_json::to_value(response.result.as_ref()?).ok()
+}
+
+fn is_validated(response: &XRPLResponse<'_>) -> bool {
+    response.is_success()
+        && result_value(response)
+            .is_some_and(|result| result.get("validated") == Some(&Value::Bool(true)))
+}
+
+/// A transaction that could not be found in a closed ledger range stays
+/// missing from that range, unless the server has gaps in its history.
+#[cfg(feature = "std")]
+fn is_not_found_in_closed_range(request: &XRPLRequest<'_>, response: &XRPLResponse<'_>) -> bool {
+    let XRPLRequest::Tx(tx) = request else {
+        return false;
+    };
+    if tx.min_ledger.is_none() || tx.max_ledger.is_none() {
+        return false;
+    }
+    // JSON-RPC nests the error in the result, WebSocket does not.
+    let result = result_value(response).unwrap_or_default();
+    let error = response
+        .error
+        .as_deref()
+        .or_else(|| result.get("error").and_then(Value::as_str));
+    let searched_all = response
+        .searched_all
+        .or_else(|| result.get("searched_all").and_then(Value::as_bool));
+
+    error == Some("txnNotFound") && searched_all == Some(true)
+}
+
+/// Copy a response into one that owns all of its data.
+fn to_owned_response(response: &XRPLResponse<'_>) -> XRPLClientResult<XRPLResponse<'static>> {
+    let mut value = serde_json::to_value(response)?;
+    if let Value::Object(map) = &mut value {
+        map.retain(|_, field| !field.is_null());
+    }
+
+    Ok(serde_json::from_value(value)?)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+    use crate::models::requests::tx::Tx;
+
+    const VALIDATED_TX_HASH: &str =
+        "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9";
+    const PENDING_TX_HASH: &str =
+        "E08D6E9754025BA2534A78707605E0601F03ACE063687A0CA1BDDACFCD1698C7";
+    const MISSING_TX_HASH: &str =
+        "0000000000000000000000000000000000000000000000000000000000000000";
+
+    /// Answers `tx` requests from fixtures and counts how often it is asked.
+    struct MockClient {
+        calls: Cell<usize>,
+    }
+
+    impl XRPLClient for MockClient {
+        async fn request_impl<'a: 'b, 'b>(
+            &self,
+            request: XRPLRequest<'a>,
+        ) -> XRPLClientResult<XRPLResponse<'b>> {
+            self.calls.set(self.calls.get() + 1);
+            let XRPLRequest::Tx(tx) = &request else {
+                panic!("unexpected request: {request:?}");
+            };
+            let hash = tx.transaction.as_deref().unwrap();
+            let response = if hash == MISSING_TX_HASH {
+                serde_json::json!({
+                    "error": "txnNotFound",
+                    "error_code": 29,
+                    "searched_all": true,
+                    "status": "error",
+                    "type": "response"
+                })
+            } else {
+                serde_json::json!({
+                    "id": tx.common_fields.id,
+                    "result": {
+                        "Account": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                        "TransactionType": "AccountSet",
+                        "ctid": "C005523E00000000",
+                        "date": 750086110,
+                        "hash": hash,
+                        "ledger_index": 348734,
+                        "meta": { "TransactionResult": "tesSUCCESS" },
+                        "validated": hash != PENDING_TX_HASH
+                    },
+                    "status": "success",
+                    "type": "response"
+                })
+            };
+
+            Ok(serde_json::from_value(response)?)
+        }
+
+        fn get_host(&self) -> Url {
+            Url::parse("wss://s.altnet.rippletest.net:51233").unwrap()
+        }
+    }
+
+    fn caching_client(capacity: usize) -> AsyncCachingClient<MockClient> {
+        AsyncCachingClient::new(
+            MockClient {
+                calls: Cell::new(0),
+            },
+            capacity,
+        )
+    }
+
+    fn tx_request(id: &'static str, hash: &'static str) -> XRPLRequest<'static> {
+        Tx::new(Some(id.into()), None, None, None, None, Some(hash.into())).into()
+    }
+
+    #[tokio::test]
+    async fn test_validated_tx_is_fetched_once() {
+        let client = caching_client(8);
+
+        let first = client
+            .request_impl(tx_request("1", VALIDATED_TX_HASH))
+            .await
+            .unwrap();
+        let second = client
+            .request_impl(tx_request("2", VALIDATED_TX_HASH))
+            .await
+            .unwrap();
+
+        assert_eq!(client.get_ref().calls.get(), 1);
+        assert_eq!(second.id.as_deref(), Some("2"));
+        assert_eq!(first.result, second.result);
+        assert_eq!(
+            client.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 1,
+                entries: 1
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unvalidated_tx_is_not_cached() {
+        let client = caching_client(8);
+
+        for id in ["1", "2"] {
+            client
+                .request_impl(tx_request(id, PENDING_TX_HASH))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(client.get_ref().calls.get(), 2);
+        assert_eq!(client.stats().entries, 0);
+    }
+
+    #[tokio::test]
+    async fn test_least_recently_used_is_evicted() {
+        let client = caching_client(2);
+        let hashes = [
+            "1111111111111111111111111111111111111111111111111111111111111111",
+            "2222222222222222222222222222222222222222222222222222222222222222",
+            "3333333333333333333333333333333333333333333333333333333333333333",
+        ];
+
+        // The second lookup of the first hash makes the second one the
+        // least recently used, so it is evicted by the third.
+        for hash in [hashes[0], hashes[1], hashes[0], hashes[2], hashes[0]] {
+            client.request_impl(tx_request("1", hash)).await.unwrap();
+        }
+        assert_eq!(client.get_ref().calls.get(), 3);
+
+        client
+            .request_impl(tx_request("1", hashes[1]))
+            .await
+            .unwrap();
+        assert_eq!(client.get_ref().calls.get(), 4);
+        assert_eq!(client.stats().entries, 2);
+    }
+
+    #[tokio::test]
+    async fn test_not_found_in_closed_range_is_cached_for_ttl() {
+        let not_found = || -> XRPLRequest<'static> {
+            Tx::new(
+                None,
+                None,
+                None,
+                Some(200),
+                Some(100),
+                Some(MISSING_TX_HASH.into()),
+            )
+            .into()
+        };
+
+        let client = caching_client(8);
+        client.request_impl(not_found()).await.unwrap();
+        client.request_impl(not_found()).await.unwrap();
+        assert_eq!(client.get_ref().calls.get(), 2);
+
+        let client = caching_client(8).with_negative_ttl(Duration::from_secs(60));
+        client.request_impl(not_found()).await.unwrap();
+        client.request_impl(not_found()).await.unwrap();
+        assert_eq!(client.get_ref().calls.get(), 1);
+
+        let client = caching_client(8).with_negative_ttl(Duration::ZERO);
+        client.request_impl(not_found()).await.unwrap();
+        client.request_impl(not_found()).await.unwrap();
+        assert_eq!(client.get_ref().calls.get(), 2);
+    }
+}
@@ -3,9 +3,36 @@ use crate::models::{
     results::XRPLResponse,
 };
 use alloc::borrow::Cow;
+use core::time::Duration;
 use url::Url;
 
-use super::exceptions::XRPLClientResult;
+use super::{exceptions::XRPLClientResult, Network};
+
+/// The version of the server's API a client asks for. Servers answer
+/// with version 1 to requests that do not set one.
+///
+/// Version 2 changes the shape of some results, e.g. `tx` puts the
+/// transaction's fields in `tx_json`. The typed results of `tx`,
+/// `account_tx`, `submit` and `ledger` read both shapes into the same
+/// model.
+///
+/// See API Versioning:
+/// `<https://xrpl.org/request-formatting.html#api-versioning>`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ApiVersion {
+    #[default]
+    V1,
+    V2,
+}
+
+impl From<ApiVersion> for u8 {
+    fn from(api_version: ApiVersion) -> Self {
+        match api_version {
+            ApiVersion::V1 => 1,
+            ApiVersion::V2 => 2,
+        }
+    }
+}
 
 #[allow(async_fn_in_trait)]
 pub trait XRPLClient {
@@ -16,6 +43,35 @@ pub trait XRPLClient {
 
     fn get_host(&self) -> Url;
 
+    /// The network the client was connected to with its settings, if
+    /// any.
+    fn get_network(&self) -> Option<&Network> {
+        None
+    }
+
+    /// How long `request` waits for a response before giving up.
+    /// `None` waits forever.
+    fn get_timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// The API version requests are sent with, unless they set one.
+    /// `None` leaves it to the server.
+    fn get_api_version(&self) -> Option<ApiVersion> {
+        None
+    }
+
+    /// Set the client's API version on a request that does not set
+    /// one.
+    fn set_api_version(&self, request: &mut XRPLRequest<'_>) {
+        if let Some(api_version) = self.get_api_version() {
+            request
+                .get_common_fields_mut()
+                .api_version
+                .get_or_insert(api_version.into());
+        }
+    }
+
     fn set_request_id(&self, request: &mut XRPLRequest<'_>) {
         let common_fields = request.get_common_fields_mut();
         common_fields.id = match &common_fields.id {
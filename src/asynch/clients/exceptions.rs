@@ -1,3 +1,5 @@
+use alloc::string::String;
+use core::time::Duration;
 use thiserror_no_std::Error;
 
 #[cfg(feature = "helpers")]
@@ -27,6 +29,16 @@ pub enum XRPLClientException {
     #[cfg(feature = "json-rpc")]
     #[error("XRPL JSON-RPC error: {0}")]
     XRPLJsonRpcError(#[from] XRPLJsonRpcException),
+    #[error("Received a non-JSON response (status: {status:?}, content type: {content_type:?}): {body_preview}")]
+    NonJsonResponse {
+        status: Option<u16>,
+        content_type: Option<String>,
+        body_preview: String,
+    },
+    #[error("Request timed out after {0:?}")]
+    RequestTimeout(Duration),
+    #[error("Waiting needs a timer; enable a runtime feature like `tokio-rt` or `embassy-rt`")]
+    NoTimer,
     #[error("URL parse error: {0}")]
     UrlParseError(#[from] url::ParseError),
     #[cfg(feature = "std")]
@@ -34,6 +46,35 @@ pub enum XRPLClientException {
     IoError(#[from] alloc::io::Error),
 }
 
+impl XRPLClientException {
+    /// Whether the request that failed can be sent again as is.
+    pub fn is_retriable(&self) -> bool {
+        match self {
+            XRPLClientException::RequestTimeout(_) => true,
+            #[cfg(feature = "websocket")]
+            XRPLClientException::XRPLWebSocketError(error) => error.is_retriable(),
+            _ => false,
+        }
+    }
+
+    /// Whether the request failed because the server could not be
+    /// reached or did not answer, so another server may still answer
+    /// it.
+    pub fn is_connection_error(&self) -> bool {
+        match self {
+            XRPLClientException::RequestTimeout(_) => true,
+            XRPLClientException::NonJsonResponse { .. } => true,
+            #[cfg(feature = "websocket")]
+            XRPLClientException::XRPLWebSocketError(_) => true,
+            #[cfg(feature = "json-rpc")]
+            XRPLClientException::XRPLJsonRpcError(_) => true,
+            #[cfg(feature = "std")]
+            XRPLClientException::IoError(_) => true,
+            _ => false,
+        }
+    }
+}
+
 impl From<serde_json::Error> for XRPLClientException {
     fn from(error: serde_json::Error) -> Self {
         XRPLClientException::XRPLSerdeJsonError(XRPLSerdeJsonError::from(error))
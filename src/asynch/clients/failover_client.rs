@@ -0,0 +1,210 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::cell::Cell;
+use url::Url;
+
+use crate::models::{requests::XRPLRequest, results::XRPLResponse};
+
+use super::{async_client::DynClient, client::XRPLClient, exceptions::XRPLClientResult, Network};
+
+/// A client sending requests to the first of several clients that
+/// can be reached, e.g. a list of public servers.
+///
+/// A request that fails with a connection error, see
+/// [`is_connection_error`](super::exceptions::XRPLClientException::is_connection_error),
+/// is sent again with the next client. Requests go to the client that
+/// answered last first. Every other error, and error responses of the
+/// server, are returned as they are.
+///
+/// # Examples
+///
+/// ```no_run
+/// use xrpl::asynch::clients::{AsyncJsonRpcClient, DynClient, FailoverClient, XRPLAsyncClient};
+/// use xrpl::models::requests::ledger_current::LedgerCurrent;
+///
+/// # #[cfg(feature = "std")]
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let clients: Vec<Box<dyn DynClient>> = vec![
+///     Box::new(AsyncJsonRpcClient::connect("https://xrplcluster.com".parse().unwrap())),
+///     Box::new(AsyncJsonRpcClient::connect("https://s2.ripple.com:51234".parse().unwrap())),
+/// ];
+/// let client = FailoverClient::new(clients);
+///
+/// let response = client.request(LedgerCurrent::new(None).into()).await.unwrap();
+/// # });
+/// ```
+pub struct FailoverClient {
+    clients: Vec<Box<dyn DynClient>>,
+    /// The index of the client that answered last.
+    current: Cell<usize>,
+}
+
+impl FailoverClient {
+    /// Fail over between `clients`, trying them in order.
+    ///
+    /// # Panics
+    ///
+    /// If `clients` is empty.
+    pub fn new(clients: Vec<Box<dyn DynClient>>) -> Self {
+        assert!(
+            !clients.is_empty(),
+            "FailoverClient needs at least one client"
+        );
+
+        Self {
+            clients,
+            current: Cell::new(0),
+        }
+    }
+
+    pub fn clients(&self) -> &[Box<dyn DynClient>] {
+        &self.clients
+    }
+
+    /// The client requests are sent with first.
+    pub fn current(&self) -> &dyn DynClient {
+        self.clients[self.current.get()].as_ref()
+    }
+}
+
+impl XRPLClient for FailoverClient {
+    async fn request_impl<'a: 'b, 'b>(
+        &self,
+        request: XRPLRequest<'a>,
+    ) -> XRPLClientResult<XRPLResponse<'b>> {
+        let first = self.current.get();
+        let mut index = first;
+        loop {
+            match self.clients[index].request_dyn(request.clone()).await {
+                Err(error) if error.is_connection_error() => {
+                    index = (index + 1) % self.clients.len();
+                    if index == first {
+                        return Err(error);
+                    }
+                }
+                response => {
+                    self.current.set(index);
+                    return response;
+                }
+            }
+        }
+    }
+
+    fn get_host(&self) -> Url {
+        self.current().host()
+    }
+
+    fn get_network(&self) -> Option<&Network> {
+        self.current().network()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::{rc::Rc, vec};
+    use core::time::Duration;
+
+    use super::*;
+    use crate::asynch::clients::{
+        exceptions::XRPLClientException, MockClient, MockResponse, XRPLAsyncClient,
+    };
+    use crate::models::requests::{ledger_current::LedgerCurrent, RequestMethod};
+
+    /// A client answering `ledger_current` with `response` and
+    /// counting the requests it was sent.
+    fn counting_client(host: &str, response: MockResponse) -> (MockClient, Rc<Cell<usize>>) {
+        let sent = Rc::new(Cell::new(0));
+        let counter = sent.clone();
+        let client = MockClient::new()
+            .with_host(Url::parse(host).unwrap())
+            .with_handler(move |_| {
+                counter.set(counter.get() + 1);
+                Some(response.clone())
+            });
+
+        (client, sent)
+    }
+
+    fn ledger_current(index: u32) -> MockResponse {
+        MockResponse::result(serde_json::json!({ "ledger_current_index": index }))
+    }
+
+    fn timeout() -> MockResponse {
+        MockResponse::client_error(|| XRPLClientException::RequestTimeout(Duration::from_secs(1)))
+    }
+
+    #[tokio::test]
+    async fn test_fails_over_on_connection_error() {
+        let (down, down_sent) = counting_client("http://down.example", timeout());
+        let (up, up_sent) = counting_client("http://up.example", ledger_current(6));
+        let client = FailoverClient::new(vec![Box::new(down), Box::new(up)]);
+
+        let response = client
+            .request(LedgerCurrent::new(None).into())
+            .await
+            .unwrap();
+        assert_eq!(
+            response.result_as_value().unwrap()["ledger_current_index"],
+            6
+        );
+        assert_eq!((down_sent.get(), up_sent.get()), (1, 1));
+        assert_eq!(client.get_host().as_str(), "http://up.example/");
+
+        // The client that answered is asked first from now on.
+        client
+            .request(LedgerCurrent::new(None).into())
+            .await
+            .unwrap();
+        assert_eq!((down_sent.get(), up_sent.get()), (1, 2));
+    }
+
+    #[tokio::test]
+    async fn test_returns_last_error_when_all_fail() {
+        let (first, first_sent) = counting_client("http://first.example", timeout());
+        let (second, second_sent) = counting_client("http://second.example", timeout());
+        let client = FailoverClient::new(vec![Box::new(first), Box::new(second)]);
+
+        let error = client
+            .request(LedgerCurrent::new(None).into())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, XRPLClientException::RequestTimeout(_)));
+        assert_eq!((first_sent.get(), second_sent.get()), (1, 1));
+    }
+
+    #[tokio::test]
+    async fn test_does_not_fail_over_on_error_response() {
+        let (first, _) = counting_client(
+            "http://first.example",
+            MockResponse::error("noCurrent", 17, None),
+        );
+        let (second, second_sent) = counting_client("http://second.example", ledger_current(6));
+        let client = FailoverClient::new(vec![Box::new(first), Box::new(second)]);
+
+        let response = client
+            .request(LedgerCurrent::new(None).into())
+            .await
+            .unwrap();
+        assert!(!response.is_success());
+        assert_eq!(second_sent.get(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_dyn_clients_of_different_types() {
+        let clients: Vec<Box<dyn DynClient>> = vec![
+            Box::new(MockClient::new().with_response(RequestMethod::LedgerCurrent, timeout())),
+            Box::new(FailoverClient::new(vec![Box::new(
+                MockClient::new().with_response(RequestMethod::LedgerCurrent, ledger_current(7)),
+            )])),
+        ];
+        let client = FailoverClient::new(clients);
+
+        let response = client
+            .request(LedgerCurrent::new(None).into())
+            .await
+            .unwrap();
+        assert_eq!(
+            response.result_as_value().unwrap()["ledger_current_index"],
+            7
+        );
+    }
+}
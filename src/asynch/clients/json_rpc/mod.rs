@@ -1,63 +1,85 @@
 use alloc::{string::ToString, vec};
-use serde::Serialize;
 use serde_json::{Map, Value};
 
-use crate::{models::results::XRPLResponse, XRPLSerdeJsonError};
+use crate::{
+    models::{requests::XRPLRequest, results::XRPLResponse},
+    XRPLSerdeJsonError,
+};
 
 mod exceptions;
 pub use exceptions::XRPLJsonRpcException;
 
-use super::{client::XRPLClient, exceptions::XRPLClientResult};
+use super::{check_json_response, client::XRPLClient, exceptions::XRPLClientResult};
 
-/// Renames the requests field `command` to `method` for JSON-RPC.
-fn request_to_json_rpc(request: &impl Serialize) -> XRPLClientResult<Value> {
-    let mut json_rpc_request = Map::new();
-    // let mut request = match serde_json::to_value(request) {
-    //     Ok(request) => match request.as_object().cloned() {
-    //         Some(request) => request,
-    //         None => todo!("Handle non-object requests"),
-    //     },
-    //     Err(error) => return Err!(error),
-    // };
-    let request_value = serde_json::to_value(request)?;
-    let mut request = request_value
-        .clone()
-        .as_object()
-        .ok_or(XRPLSerdeJsonError::UnexpectedValueType {
+/// Build the JSON-RPC envelope of a request: the `method` with the
+/// request's parameters wrapped in `params`.
+fn request_to_json_rpc(request: &XRPLRequest<'_>) -> XRPLClientResult<Value> {
+    let params = serde_json::to_value(request)?;
+    if !params.is_object() {
+        return Err(XRPLSerdeJsonError::UnexpectedValueType {
             expected: "Object".to_string(),
-            found: request_value,
-        })?
-        .clone();
-    if let Some(command) = request.remove("command") {
-        json_rpc_request.insert("method".to_string(), command);
-        json_rpc_request.insert(
-            "params".to_string(),
-            serde_json::Value::Array(vec![Value::Object(request)]),
-        );
+            found: params,
+        }
+        .into());
     }
+    let mut json_rpc_request = Map::new();
+    json_rpc_request.insert("method".to_string(), Value::from(request.method_name()));
+    json_rpc_request.insert("params".to_string(), Value::Array(vec![params]));
 
     Ok(Value::Object(json_rpc_request))
 }
 
 #[cfg(all(feature = "json-rpc", feature = "std"))]
 mod _std {
-    use crate::models::requests::XRPLRequest;
     #[cfg(feature = "helpers")]
     use crate::{asynch::clients::XRPLFaucet, models::requests::FundFaucet};
     #[cfg(feature = "helpers")]
     use alloc::string::ToString;
 
     use super::*;
-    use reqwest::Client as HttpClient;
+    use crate::asynch::clients::{ApiVersion, Network, INITIAL_REQUEST_TIMEOUT};
+    use core::time::Duration;
+    use reqwest::{header::CONTENT_TYPE, Client as HttpClient};
     use url::Url;
 
     pub struct AsyncJsonRpcClient {
         url: Url,
+        timeout: Option<Duration>,
+        api_version: Option<ApiVersion>,
+        network: Option<Network>,
     }
 
     impl AsyncJsonRpcClient {
         pub fn connect(url: Url) -> Self {
-            Self { url }
+            Self {
+                url,
+                timeout: INITIAL_REQUEST_TIMEOUT,
+                api_version: None,
+                network: None,
+            }
+        }
+
+        /// Connect to the JSON-RPC server of `network`, e.g.
+        /// [`Network::testnet`].
+        pub fn connect_network(network: Network) -> Self {
+            Self {
+                network: Some(network.clone()),
+                ..Self::connect(network.json_rpc_url)
+            }
+        }
+
+        /// Set how long requests wait for a response. `None` waits
+        /// forever.
+        pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+            self.timeout = timeout;
+            self
+        }
+
+        /// Set the API version requests are sent with, unless they
+        /// set one.
+        pub fn with_api_version(mut self, api_version: ApiVersion) -> Self {
+            self.api_version = Some(api_version);
+            self
         }
     }
 
@@ -74,12 +96,18 @@ mod _std {
                 .send()
                 .await;
             match response {
-                Ok(response) => match response.text().await {
-                    Ok(response) => {
-                        Ok(serde_json::from_str::<XRPLResponse<'b>>(&response).unwrap())
-                    }
-                    Err(error) => Err(error.into()),
-                },
+                Ok(response) => {
+                    let status = response.status().as_u16();
+                    let content_type = response
+                        .headers()
+                        .get(CONTENT_TYPE)
+                        .and_then(|content_type| content_type.to_str().ok())
+                        .map(ToString::to_string);
+                    let body = response.bytes().await?;
+                    check_json_response(Some(status), content_type.as_deref(), &body)?;
+
+                    Ok(serde_json::from_slice::<XRPLResponse<'b>>(&body)?)
+                }
                 Err(error) => Err(error.into()),
             }
         }
@@ -87,6 +115,18 @@ mod _std {
         fn get_host(&self) -> Url {
             self.url.clone()
         }
+
+        fn get_timeout(&self) -> Option<Duration> {
+            self.timeout
+        }
+
+        fn get_api_version(&self) -> Option<ApiVersion> {
+            self.api_version
+        }
+
+        fn get_network(&self) -> Option<&Network> {
+            self.network.as_ref()
+        }
     }
 
     #[cfg(feature = "helpers")]
@@ -120,12 +160,13 @@ mod _std {
 
 #[cfg(all(feature = "json-rpc", not(feature = "std")))]
 mod _no_std {
-    use crate::{asynch::clients::SingleExecutorMutex, models::requests::XRPLRequest};
+    use crate::asynch::clients::{ApiVersion, SingleExecutorMutex, INITIAL_REQUEST_TIMEOUT};
     #[cfg(feature = "helpers")]
     use crate::{asynch::clients::XRPLFaucet, models::requests::FundFaucet};
 
     use super::*;
     use alloc::sync::Arc;
+    use core::time::Duration;
     use embassy_sync::{blocking_mutex::raw::RawMutex, mutex::Mutex};
     use embedded_nal_async::{Dns, TcpConnect};
     use reqwless::{
@@ -143,6 +184,8 @@ mod _no_std {
     {
         url: Url,
         client: Arc<Mutex<M, HttpClient<'a, T, D>>>,
+        timeout: Option<Duration>,
+        api_version: Option<ApiVersion>,
     }
 
     impl<'a, const BUF: usize, T, D, M> AsyncJsonRpcClient<'a, BUF, T, D, M>
@@ -155,6 +198,8 @@ mod _no_std {
             Self {
                 url,
                 client: Arc::new(Mutex::new(HttpClient::new(tcp, dns))),
+                timeout: INITIAL_REQUEST_TIMEOUT,
+                api_version: None,
             }
         }
 
@@ -162,8 +207,24 @@ mod _no_std {
             Self {
                 url,
                 client: Arc::new(Mutex::new(HttpClient::new_with_tls(tcp, dns, tls))),
+                timeout: INITIAL_REQUEST_TIMEOUT,
+                api_version: None,
             }
         }
+
+        /// Set how long requests wait for a response. `None` waits
+        /// forever.
+        pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+            self.timeout = timeout;
+            self
+        }
+
+        /// Set the API version requests are sent with, unless they
+        /// set one.
+        pub fn with_api_version(mut self, api_version: ApiVersion) -> Self {
+            self.api_version = Some(api_version);
+            self
+        }
     }
 
     impl<const BUF: usize, T, D, M> XRPLClient for AsyncJsonRpcClient<'_, BUF, T, D, M>
@@ -183,15 +244,24 @@ mod _no_std {
             let mut client = self.client.lock().await;
             let response = match client.request(Method::POST, self.url.as_str()).await {
                 Ok(client) => {
-                    if let Err(error) = client
+                    match client
                         .body(request_buf)
                         .content_type(ContentType::ApplicationJson)
                         .send(&mut rx_buffer)
                         .await
                     {
-                        Err(error.into())
-                    } else {
-                        Ok(serde_json::from_slice::<XRPLResponse<'_>>(&rx_buffer)?)
+                        Ok(response) => {
+                            let status = response.status.0;
+                            let content_type = response
+                                .content_type
+                                .as_ref()
+                                .map(|content_type| content_type.as_str().to_string());
+                            let body = response.body().read_to_end().await?;
+                            check_json_response(Some(status), content_type.as_deref(), body)?;
+
+                            Ok(serde_json::from_slice::<XRPLResponse<'_>>(body)?)
+                        }
+                        Err(error) => Err(error.into()),
                     }
                 }
                 Err(error) => Err(error.into()),
@@ -203,6 +273,14 @@ mod _no_std {
         fn get_host(&self) -> Url {
             self.url.clone()
         }
+
+        fn get_timeout(&self) -> Option<Duration> {
+            self.timeout
+        }
+
+        fn get_api_version(&self) -> Option<ApiVersion> {
+            self.api_version
+        }
     }
 
     #[cfg(feature = "helpers")]
@@ -254,3 +332,55 @@ mod _no_std {
 pub use _no_std::AsyncJsonRpcClient;
 #[cfg(all(feature = "json-rpc", feature = "std"))]
 pub use _std::AsyncJsonRpcClient;
+
+#[cfg(all(test, feature = "json-rpc", feature = "std"))]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::{request_to_json_rpc, AsyncJsonRpcClient};
+    use crate::asynch::clients::{
+        exceptions::XRPLClientException,
+        tests::{envelope_test_requests, serve_forbidden_html, FORBIDDEN_HTML},
+        XRPLAsyncClient,
+    };
+    use crate::models::requests::server_info::ServerInfo;
+
+    #[test]
+    fn test_json_rpc_envelope() {
+        let envelopes = envelope_test_requests()
+            .map(|request| request_to_json_rpc(&request).unwrap().to_string());
+
+        assert_eq!(
+            envelopes,
+            [
+                r#"{"method":"account_info","params":[{"account":"rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn","ledger_index":"validated","strict":true}]}"#,
+                r#"{"method":"ledger","params":[{"ledger_index":"closed","transactions":true}]}"#,
+                r#"{"method":"server_info","params":[{"api_version":2}]}"#,
+                r#"{"method":"vault_info","params":[{"vault_id":"A3F0"}]}"#,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_html_response_is_reported_as_non_json() {
+        let client = AsyncJsonRpcClient::connect(serve_forbidden_html().await);
+        let error = client
+            .request(ServerInfo::new(None).into())
+            .await
+            .unwrap_err();
+
+        match &error {
+            XRPLClientException::NonJsonResponse {
+                status,
+                content_type,
+                body_preview,
+            } => {
+                assert_eq!(*status, Some(403));
+                assert_eq!(content_type.as_deref(), Some("text/html"));
+                assert_eq!(body_preview, FORBIDDEN_HTML);
+            }
+            error => panic!("expected NonJsonResponse, got {error:?}"),
+        }
+        assert!(!error.to_string().contains("serde"));
+    }
+}
@@ -0,0 +1,605 @@
+//! A client answering requests with canned responses, to unit test
+//! code that talks to the XRP Ledger without a network connection.
+//!
+//! # Examples
+//!
+//! ## Mocking `account_info`
+//!
+//! ```
+//! use xrpl::asynch::account::get_xrp_balance;
+//! use xrpl::asynch::clients::{MockClient, MockResponse, XRPLAsyncClient};
+//! use xrpl::asynch::exceptions::XRPLHelperResult;
+//! use xrpl::models::requests::{RequestMethod, XRPLRequest};
+//! use xrpl::models::XRPAmount;
+//!
+//! /// The service under test: can `account` pay `amount`?
+//! async fn can_pay(
+//!     client: &impl XRPLAsyncClient,
+//!     account: &str,
+//!     amount: XRPAmount<'_>,
+//! ) -> XRPLHelperResult<bool> {
+//!     Ok(get_xrp_balance(account.into(), client, None).await? >= amount)
+//! }
+//!
+//! # tokio::runtime::Runtime::new().unwrap().block_on(async {
+//! let client = MockClient::new().with_response(
+//!     RequestMethod::AccountInfo,
+//!     MockResponse::result(serde_json::json!({
+//!         "account_data": {
+//!             "Account": "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn",
+//!             "Balance": "25000000",
+//!             "Flags": 0,
+//!             "LedgerEntryType": "AccountRoot",
+//!             "OwnerCount": 0,
+//!             "PreviousTxnID": "4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42",
+//!             "PreviousTxnLgrSeq": 3,
+//!             "Sequence": 5,
+//!             "index": "92FA6A9FC8EA6018D5D16532D7795C91BFB0831355BDFDA177E86C8BF997985F"
+//!         },
+//!         "ledger_index": 3,
+//!         "validated": true
+//!     })),
+//! );
+//!
+//! let account = "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn";
+//! assert!(can_pay(&client, account, XRPAmount::from("20000000")).await.unwrap());
+//! assert!(!can_pay(&client, account, XRPAmount::from("30000000")).await.unwrap());
+//!
+//! // Every request is recorded.
+//! let requests = client.requests();
+//! assert_eq!(requests.len(), 2);
+//! let XRPLRequest::AccountInfo(account_info) = &requests[0] else {
+//!     panic!("expected account_info");
+//! };
+//! assert_eq!(account_info.account, account);
+//! # });
+//! ```
+
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, VecDeque},
+    rc::Rc,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{cell::RefCell, time::Duration};
+use serde_json::Value;
+use url::Url;
+
+#[cfg(feature = "websocket")]
+use super::XRPLAsyncWebsocketIO;
+use super::{
+    exceptions::{XRPLClientException, XRPLClientResult},
+    ApiVersion, Network, XRPLClient,
+};
+use crate::{
+    asynch::wait,
+    models::{
+        requests::{Request, RequestMethod, XRPLRequest},
+        results::XRPLResponse,
+    },
+};
+#[cfg(feature = "helpers")]
+use crate::{
+    asynch::{clients::XRPLFaucet, wallet::exceptions::XRPLFaucetException},
+    models::requests::FundFaucet,
+};
+
+const MOCK_HOST: &str = "ws://localhost:6006";
+
+type MockHandler = Box<dyn Fn(&XRPLRequest<'_>) -> Option<MockResponse>>;
+
+/// A canned answer of a [`MockClient`].
+#[derive(Clone)]
+pub struct MockResponse {
+    kind: MockResponseKind,
+    delay: Option<Duration>,
+}
+
+#[derive(Clone)]
+enum MockResponseKind {
+    Result(Value),
+    Error {
+        error: String,
+        error_code: i32,
+        error_message: Option<String>,
+    },
+    ClientError(Rc<dyn Fn() -> XRPLClientException>),
+}
+
+impl MockResponse {
+    /// A successful response with `result` as its `result`.
+    pub fn result(result: Value) -> Self {
+        Self {
+            kind: MockResponseKind::Result(result),
+            delay: None,
+        }
+    }
+
+    /// An error response, e.g. `actNotFound` with code 19.
+    pub fn error(error: &str, error_code: i32, error_message: Option<&str>) -> Self {
+        Self {
+            kind: MockResponseKind::Error {
+                error: error.into(),
+                error_code,
+                error_message: error_message.map(Into::into),
+            },
+            delay: None,
+        }
+    }
+
+    /// Fail the request itself, e.g. with a timeout or a dropped
+    /// connection.
+    pub fn client_error(error: impl Fn() -> XRPLClientException + 'static) -> Self {
+        Self {
+            kind: MockResponseKind::ClientError(Rc::new(error)),
+            delay: None,
+        }
+    }
+
+    /// Answer only after `delay`. Requests sent concurrently with
+    /// different delays are answered out of order.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    fn into_response<'b>(self, request: &XRPLRequest<'_>) -> XRPLClientResult<XRPLResponse<'b>> {
+        let id = request.get_common_fields().id.clone();
+        let response = match self.kind {
+            MockResponseKind::Result(result) => serde_json::json!({
+                "id": id,
+                "result": result,
+                "status": "success",
+                "type": "response"
+            }),
+            MockResponseKind::Error {
+                error,
+                error_code,
+                error_message,
+            } => serde_json::json!({
+                "error": error,
+                "error_code": error_code,
+                "error_message": error_message,
+                "id": id,
+                "request": recorded_request_json(request)?,
+                "status": "error",
+                "type": "response"
+            }),
+            MockResponseKind::ClientError(error) => return Err(error()),
+        };
+
+        Ok(serde_json::from_value(response)?)
+    }
+}
+
+impl core::fmt::Debug for MockResponse {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut debug = f.debug_struct("MockResponse");
+        match &self.kind {
+            MockResponseKind::Result(result) => debug.field("result", result),
+            MockResponseKind::Error { error, .. } => debug.field("error", error),
+            MockResponseKind::ClientError(_) => debug.field("client_error", &".."),
+        };
+
+        debug.field("delay", &self.delay).finish()
+    }
+}
+
+/// A client answering requests with canned responses and recording
+/// them for later assertions.
+///
+/// Responses are looked up by the request's method. Several responses
+/// queued for a method are used in order, and the last one answers
+/// every further request, e.g. for helpers that poll. Handlers added
+/// with [`MockClient::with_handler`] are asked first. A request
+/// nothing answers panics.
+pub struct MockClient {
+    host: Url,
+    network: Option<Network>,
+    api_version: Option<ApiVersion>,
+    responses: RefCell<BTreeMap<String, VecDeque<MockResponse>>>,
+    handlers: Vec<MockHandler>,
+    requests: RefCell<Vec<XRPLRequest<'static>>>,
+    funded_accounts: RefCell<Vec<String>>,
+}
+
+impl MockClient {
+    pub fn new() -> Self {
+        Self {
+            host: Url::parse(MOCK_HOST).expect("valid mock host"),
+            network: None,
+            api_version: None,
+            responses: RefCell::new(BTreeMap::new()),
+            handlers: Vec::new(),
+            requests: RefCell::new(Vec::new()),
+            funded_accounts: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Set the host reported by `get_host`, e.g. a testnet URL for
+    /// code that picks its faucet from it.
+    pub fn with_host(mut self, host: Url) -> Self {
+        self.host = host;
+        self
+    }
+
+    /// Act as connected to `network`, e.g. to test code depending on
+    /// its ID or faucet.
+    pub fn with_network(mut self, network: Network) -> Self {
+        self.host = network.json_rpc_url.clone();
+        self.network = Some(network);
+        self
+    }
+
+    /// Send requests with `api_version`, like a client configured
+    /// with it.
+    pub fn with_api_version(mut self, api_version: ApiVersion) -> Self {
+        self.api_version = Some(api_version);
+        self
+    }
+
+    /// Queue `response` for requests of `method`.
+    pub fn with_response(self, method: RequestMethod, response: MockResponse) -> Self {
+        self.push_response(method, response);
+        self
+    }
+
+    /// Answer the requests `handler` returns a response for, before
+    /// looking at the queued responses.
+    pub fn with_handler(
+        mut self,
+        handler: impl Fn(&XRPLRequest<'_>) -> Option<MockResponse> + 'static,
+    ) -> Self {
+        self.handlers.push(Box::new(handler));
+        self
+    }
+
+    /// Queue `response` for requests of `method`, e.g. halfway through
+    /// a test.
+    pub fn push_response(&self, method: RequestMethod, response: MockResponse) {
+        self.responses
+            .borrow_mut()
+            .entry(method.to_string())
+            .or_default()
+            .push_back(response);
+    }
+
+    /// The requests sent so far, oldest first.
+    pub fn requests(&self) -> Vec<XRPLRequest<'static>> {
+        self.requests.borrow().clone()
+    }
+
+    /// The requests of `method` sent so far, oldest first.
+    pub fn requests_of(&self, method: RequestMethod) -> Vec<XRPLRequest<'static>> {
+        self.requests
+            .borrow()
+            .iter()
+            .filter(|request| request.method() == method)
+            .cloned()
+            .collect()
+    }
+
+    /// The accounts funded with `request_funding`.
+    pub fn funded_accounts(&self) -> Vec<String> {
+        self.funded_accounts.borrow().clone()
+    }
+
+    fn find_response(&self, request: &XRPLRequest<'_>) -> Option<MockResponse> {
+        if let Some(response) = self.handlers.iter().find_map(|handler| handler(request)) {
+            return Some(response);
+        }
+        let mut responses = self.responses.borrow_mut();
+        let queue = responses.get_mut(&request.method().to_string())?;
+        if queue.len() > 1 {
+            queue.pop_front()
+        } else {
+            queue.front().cloned()
+        }
+    }
+}
+
+impl Default for MockClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XRPLClient for MockClient {
+    async fn request_impl<'a: 'b, 'b>(
+        &self,
+        request: XRPLRequest<'a>,
+    ) -> XRPLClientResult<XRPLResponse<'b>> {
+        self.requests
+            .borrow_mut()
+            .push(serde_json::from_value(recorded_request_json(&request)?)?);
+        let response = self.find_response(&request).unwrap_or_else(|| {
+            panic!(
+                "MockClient has no response for `{}`: {request:?}",
+                request.method()
+            )
+        });
+        if let Some(delay) = response.delay {
+            wait(delay).await?;
+        }
+
+        response.into_response(&request)
+    }
+
+    fn get_host(&self) -> Url {
+        self.host.clone()
+    }
+
+    fn get_network(&self) -> Option<&Network> {
+        self.network.as_ref()
+    }
+
+    fn get_api_version(&self) -> Option<ApiVersion> {
+        self.api_version
+    }
+}
+
+#[cfg(feature = "helpers")]
+impl XRPLFaucet for MockClient {
+    fn get_faucet_url(&self, url: Option<Url>) -> XRPLClientResult<Url> {
+        match (url, &self.network) {
+            (Some(url), _) => Ok(url),
+            (None, Some(network)) => match &network.faucet_url {
+                Some(faucet_url) => Ok(faucet_url.clone()),
+                None => {
+                    Err(XRPLFaucetException::NoFaucet(network.name.clone().into_owned()).into())
+                }
+            },
+            (None, None) => Ok(self.host.join("accounts")?),
+        }
+    }
+
+    async fn request_funding(
+        &self,
+        _url: Option<Url>,
+        request: FundFaucet<'_>,
+    ) -> XRPLClientResult<()> {
+        self.funded_accounts
+            .borrow_mut()
+            .push(request.destination.into_owned());
+
+        Ok(())
+    }
+}
+
+/// A websocket answering every request with a canned result, then
+/// sending the queued stream messages, to unit test code consuming
+/// subscription streams.
+///
+/// The messages are sent in order after the answers to the requests
+/// sent so far. Once all are received, the stream ends.
+#[cfg(feature = "websocket")]
+#[derive(Debug, Default)]
+pub struct MockWebsocket {
+    result: Value,
+    messages: VecDeque<Value>,
+    requests: Vec<XRPLRequest<'static>>,
+}
+
+#[cfg(feature = "websocket")]
+impl MockWebsocket {
+    /// Answer requests with an empty result.
+    pub fn new() -> Self {
+        Self {
+            result: Value::Object(Default::default()),
+            ..Default::default()
+        }
+    }
+
+    /// Answer requests with `result`, e.g. the ledger or server state
+    /// `subscribe` returns.
+    pub fn with_result(mut self, result: Value) -> Self {
+        self.result = result;
+        self
+    }
+
+    /// Queue `messages` to be sent as stream messages.
+    pub fn with_messages(mut self, messages: impl IntoIterator<Item = Value>) -> Self {
+        self.messages.extend(messages);
+        self
+    }
+
+    /// The requests sent so far, oldest first.
+    pub fn requests(&self) -> &[XRPLRequest<'static>] {
+        &self.requests
+    }
+
+    /// The number of queued messages not received yet.
+    pub fn pending_messages(&self) -> usize {
+        self.messages.len()
+    }
+}
+
+#[cfg(feature = "websocket")]
+impl XRPLAsyncWebsocketIO for MockWebsocket {
+    async fn xrpl_send(&mut self, message: XRPLRequest<'_>) -> XRPLClientResult<()> {
+        let request = recorded_request_json(&message)?;
+        self.messages.push_front(serde_json::json!({
+            "id": request.get("id"),
+            "result": self.result,
+            "status": "success",
+            "type": "response"
+        }));
+        self.requests.push(serde_json::from_value(request)?);
+
+        Ok(())
+    }
+
+    async fn xrpl_receive(&mut self) -> XRPLClientResult<Option<XRPLResponse<'_>>> {
+        match self.messages.pop_front() {
+            Some(message) => Ok(Some(serde_json::from_value(message)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// The request as the server would echo it, with its `command`.
+fn recorded_request_json(request: &XRPLRequest<'_>) -> XRPLClientResult<Value> {
+    let mut json = serde_json::to_value(request)?;
+    if let Some(fields) = json.as_object_mut() {
+        fields.insert("command".into(), request.method_name().into());
+        if let Some(id) = &request.get_common_fields().id {
+            fields.insert("id".into(), id.to_string().into());
+        }
+    }
+
+    Ok(json)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::{
+        asynch::clients::XRPLAsyncClient,
+        models::{
+            requests::{fee::Fee, ledger_current::LedgerCurrent, ping::Ping},
+            results::XRPLOtherResult,
+        },
+    };
+
+    fn ledger_current_index(response: XRPLResponse<'_>) -> u32 {
+        response
+            .try_into_result::<XRPLOtherResult>()
+            .unwrap()
+            .try_get_typed("ledger_current_index")
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_queued_responses() {
+        let client = MockClient::new()
+            .with_response(
+                RequestMethod::LedgerCurrent,
+                MockResponse::result(serde_json::json!({ "ledger_current_index": 1 })),
+            )
+            .with_response(
+                RequestMethod::LedgerCurrent,
+                MockResponse::result(serde_json::json!({ "ledger_current_index": 2 })),
+            );
+
+        let mut indexes = vec![];
+        for _ in 0..3 {
+            let response = client
+                .request(LedgerCurrent::new(Some("1".into())).into())
+                .await
+                .unwrap();
+            assert_eq!(response.id.as_deref(), Some("1"));
+            indexes.push(ledger_current_index(response));
+        }
+
+        // The last response is repeated.
+        assert_eq!(indexes, [1, 2, 2]);
+        assert_eq!(client.requests_of(RequestMethod::LedgerCurrent).len(), 3);
+        assert!(client.requests_of(RequestMethod::Fee).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_errors() {
+        let client = MockClient::new()
+            .with_response(
+                RequestMethod::Fee,
+                MockResponse::error(
+                    "tooBusy",
+                    9,
+                    Some("The server is too busy to help you now."),
+                ),
+            )
+            .with_response(
+                RequestMethod::Ping,
+                MockResponse::client_error(|| {
+                    XRPLClientException::RequestTimeout(Duration::from_secs(20))
+                }),
+            );
+
+        let response = client.request(Fee::new(None).into()).await.unwrap();
+        assert!(!response.is_success());
+        assert!(response.error_response().unwrap().is_retriable());
+
+        let error = client.request(Ping::new(None).into()).await.unwrap_err();
+        assert!(matches!(error, XRPLClientException::RequestTimeout(_)));
+    }
+
+    #[tokio::test]
+    async fn test_handler_and_out_of_order_responses() {
+        let client = MockClient::new().with_handler(|request| {
+            let XRPLRequest::LedgerCurrent(ledger_current) = request else {
+                return None;
+            };
+            // The first request is answered last.
+            let (index, delay) = match ledger_current.common_fields.id.as_deref() {
+                Some("first") => (1, 50),
+                _ => (2, 0),
+            };
+
+            Some(
+                MockResponse::result(serde_json::json!({ "ledger_current_index": index }))
+                    .with_delay(Duration::from_millis(delay)),
+            )
+        });
+
+        let order = RefCell::new(vec![]);
+        let request = |id: &'static str| {
+            let (client, order) = (&client, &order);
+            async move {
+                let response = client
+                    .request(LedgerCurrent::new(Some(id.into())).into())
+                    .await
+                    .unwrap();
+                order.borrow_mut().push(ledger_current_index(response));
+            }
+        };
+        tokio::join!(request("first"), request("second"));
+
+        assert_eq!(*order.borrow(), [2, 1]);
+        assert_eq!(client.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_api_version() {
+        let response = || MockResponse::result(serde_json::json!({ "ledger_current_index": 1 }));
+        let api_versions = |client: &MockClient| {
+            client
+                .requests()
+                .iter()
+                .map(|request| request.get_common_fields().api_version)
+                .collect::<Vec<_>>()
+        };
+
+        let client = MockClient::new().with_response(RequestMethod::LedgerCurrent, response());
+        client
+            .request(LedgerCurrent::new(None).into())
+            .await
+            .unwrap();
+        // Left to the server.
+        assert_eq!(api_versions(&client), [None]);
+
+        let client = MockClient::new()
+            .with_api_version(ApiVersion::V2)
+            .with_response(RequestMethod::LedgerCurrent, response());
+        client
+            .request(LedgerCurrent::new(None).into())
+            .await
+            .unwrap();
+        let mut request = LedgerCurrent::new(None);
+        request.common_fields.api_version = Some(1);
+        client
+            .request_with_timeout(request.into(), Duration::from_secs(1))
+            .await
+            .unwrap();
+        // A request's own version wins.
+        assert_eq!(api_versions(&client), [Some(2), Some(1)]);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MockClient has no response for `fee`")]
+    async fn test_unexpected_request() {
+        let _ = MockClient::new().request(Fee::new(None).into()).await;
+    }
+}
@@ -1,12 +1,20 @@
 pub mod async_client;
+mod backoff;
+pub mod caching_client;
 pub mod client;
 pub mod exceptions;
+mod failover_client;
 #[cfg(feature = "json-rpc")]
 mod json_rpc;
+#[cfg(any(test, feature = "test-utils"))]
+mod mock_client;
+mod network;
+mod rate_limited_client;
 #[cfg(feature = "websocket")]
 mod websocket;
 
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, string::String};
+use core::time::Duration;
 use embassy_sync::blocking_mutex::raw::{CriticalSectionRawMutex, NoopRawMutex};
 use serde::{Deserialize, Serialize};
 
@@ -16,15 +24,39 @@ use exceptions::XRPLClientResult;
 use url::Url;
 
 pub use async_client::*;
+pub use backoff::*;
+pub use caching_client::*;
 pub use client::*;
+pub use failover_client::*;
 #[cfg(feature = "json-rpc")]
 pub use json_rpc::*;
+#[cfg(any(test, feature = "test-utils"))]
+pub use mock_client::*;
+pub use network::*;
+pub use rate_limited_client::*;
 #[cfg(feature = "websocket")]
 pub use websocket::*;
 
 pub type MultiExecutorMutex = CriticalSectionRawMutex;
 pub type SingleExecutorMutex = NoopRawMutex;
 
+/// How long clients wait for a response unless configured otherwise.
+/// Without a runtime feature there is no timer: clients then wait
+/// forever by default, and fail with
+/// [`XRPLClientException::NoTimer`](exceptions::XRPLClientException::NoTimer)
+/// when given a timeout.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// The timeout clients start with, see [`DEFAULT_REQUEST_TIMEOUT`].
+const INITIAL_REQUEST_TIMEOUT: Option<Duration> = if super::HAS_TIMER {
+    Some(DEFAULT_REQUEST_TIMEOUT)
+} else {
+    None
+};
+
+/// How many characters of a non-JSON response body are kept for the error.
+const NON_JSON_BODY_PREVIEW_LENGTH: usize = 200;
+
 const TEST_FAUCET_URL: &str = "https://faucet.altnet.rippletest.net/accounts";
 const DEV_FAUCET_URL: &str = "https://faucet.devnet.rippletest.net/accounts";
 
@@ -40,6 +72,13 @@ pub trait XRPLFaucet: XRPLClient {
     {
         if let Some(url) = url {
             Ok(url)
+        } else if let Some(network) = self.get_network() {
+            match &network.faucet_url {
+                Some(faucet_url) => Ok(faucet_url.clone()),
+                None => {
+                    Err(XRPLFaucetException::NoFaucet(network.name.clone().into_owned()).into())
+                }
+            }
         } else {
             let host = self.get_host();
             let host_str = host.host_str().unwrap();
@@ -62,8 +101,316 @@ pub trait XRPLFaucet: XRPLClient {
     ) -> XRPLClientResult<()>;
 }
 
+/// Reject bodies that are clearly not JSON, like the HTML error pages served
+/// by proxies and captive portals, before they reach serde.
+pub(crate) fn check_json_response(
+    status: Option<u16>,
+    content_type: Option<&str>,
+    body: &[u8],
+) -> exceptions::XRPLClientResult<()> {
+    let is_html = content_type.is_some_and(|content_type| content_type.contains("html"));
+    let starts_like_json = matches!(
+        body.iter().find(|byte| !byte.is_ascii_whitespace()),
+        Some(b'{') | Some(b'[')
+    );
+
+    if !is_html && starts_like_json {
+        Ok(())
+    } else {
+        Err(exceptions::XRPLClientException::NonJsonResponse {
+            status,
+            content_type: content_type.map(Into::into),
+            body_preview: body_preview(body),
+        })
+    }
+}
+
+pub(crate) fn body_preview(body: &[u8]) -> String {
+    String::from_utf8_lossy(body)
+        .trim()
+        .chars()
+        .take(NON_JSON_BODY_PREVIEW_LENGTH)
+        .collect()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommonFields<'a> {
     pub build_version: Option<Cow<'a, str>>,
     pub network_id: Option<u32>,
 }
+
+#[cfg(all(test, feature = "std"))]
+pub(crate) mod tests {
+    use alloc::{format, string::ToString};
+    use serde_json::Value;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use url::Url;
+
+    use super::*;
+    use crate::models::{
+        requests::{
+            account_info::AccountInfo, ledger::Ledger, raw::Raw, server_info::ServerInfo,
+            RequestMethod, XRPLRequest,
+        },
+        results::{fee::Fee, XRPLOtherResult, XRPLResponse, XRPLResult},
+        XRPLModelException,
+    };
+
+    /// Requests used for the envelope snapshot tests of both transports.
+    pub(crate) fn envelope_test_requests() -> [XRPLRequest<'static>; 4] {
+        [
+            AccountInfo::new(
+                Some("1".into()),
+                "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn".into(),
+                None,
+                Some("validated".into()),
+                Some(true),
+                None,
+                None,
+            )
+            .into(),
+            Ledger::new(
+                Some("2".into()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some("closed".into()),
+                None,
+                None,
+                Some(true),
+            )
+            .into(),
+            {
+                let mut server_info = ServerInfo::new(None);
+                server_info.common_fields.api_version = Some(2);
+                server_info.into()
+            },
+            Raw::new(
+                Some("4".into()),
+                "vault_info".into(),
+                serde_json::json!({ "vault_id": "A3F0" })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+            )
+            .into(),
+        ]
+    }
+
+    pub(crate) const FORBIDDEN_HTML: &str =
+        "<html><head><title>403 Forbidden</title></head><body>Access denied by proxy</body></html>";
+
+    /// Serve a single HTML 403 page, the way an intercepting proxy would,
+    /// and return the address it listens on.
+    pub(crate) async fn serve_forbidden_html() -> Url {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = [0; 4096];
+            let _ = stream.read(&mut request).await.unwrap();
+            let response = format!(
+                "HTTP/1.1 403 Forbidden\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                FORBIDDEN_HTML.len(),
+                FORBIDDEN_HTML
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+            stream.shutdown().await.unwrap();
+        });
+
+        Url::parse(&format!("http://{address}")).unwrap()
+    }
+
+    #[test]
+    fn test_check_json_response() {
+        assert!(
+            check_json_response(Some(200), Some("application/json"), b" {\"result\":{}}").is_ok()
+        );
+        assert!(check_json_response(None, None, b"[]").is_ok());
+        assert!(matches!(
+            check_json_response(Some(200), Some("text/html"), b"{}"),
+            Err(exceptions::XRPLClientException::NonJsonResponse { .. })
+        ));
+        assert!(matches!(
+            check_json_response(Some(502), None, b""),
+            Err(exceptions::XRPLClientException::NonJsonResponse { .. })
+        ));
+    }
+
+    /// A client whose requests are never answered.
+    struct SilentClient(Option<Duration>);
+
+    impl XRPLClient for SilentClient {
+        async fn request_impl<'a: 'b, 'b>(
+            &self,
+            _request: XRPLRequest<'a>,
+        ) -> XRPLClientResult<XRPLResponse<'b>> {
+            core::future::pending().await
+        }
+
+        fn get_host(&self) -> Url {
+            Url::parse("ws://localhost").unwrap()
+        }
+
+        fn get_timeout(&self) -> Option<Duration> {
+            self.0
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_times_out() {
+        let timeout = Duration::from_millis(20);
+        let error = SilentClient(Some(timeout))
+            .request(ServerInfo::new(None).into())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            exceptions::XRPLClientException::RequestTimeout(after) if after == timeout
+        ));
+        assert!(error.is_retriable());
+
+        // A per-request timeout overrides waiting forever.
+        let error = SilentClient(None)
+            .request_with_timeout(ServerInfo::new(None).into(), timeout)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            exceptions::XRPLClientException::RequestTimeout(_)
+        ));
+    }
+
+    #[test]
+    fn test_body_preview_is_truncated() {
+        let body = "a".repeat(500);
+
+        assert_eq!(
+            body_preview(body.as_bytes()).len(),
+            NON_JSON_BODY_PREVIEW_LENGTH
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_raw() {
+        let client = MockClient::new()
+            .with_response(
+                RequestMethod::LedgerCurrent,
+                MockResponse::result(serde_json::json!({
+                    "ledger_current_index": 6,
+                    "status": "success"
+                })),
+            )
+            .with_response(
+                RequestMethod::Fee,
+                MockResponse::result(serde_json::json!({
+                    "current_ledger_size": "0",
+                    "current_queue_size": "0",
+                    "drops": {
+                        "base_fee": "10",
+                        "median_fee": "5000",
+                        "minimum_fee": "10",
+                        "open_ledger_fee": "10"
+                    },
+                    "expected_ledger_size": "32",
+                    "ledger_current_index": 6,
+                    "levels": {
+                        "median_level": "128000",
+                        "minimum_level": "256",
+                        "open_ledger_level": "256",
+                        "reference_level": "256"
+                    },
+                    "max_queue_size": "640",
+                    "status": "success"
+                })),
+            );
+
+        let response = client
+            .request_raw("ledger_current", Value::Null)
+            .await
+            .unwrap();
+        let Some(XRPLResult::Other(result)) = &response.result else {
+            panic!("expected a raw result, got {:?}", response.result);
+        };
+        assert_eq!(result.get("ledger_current_index"), Some(&6.into()));
+        assert_eq!(
+            serde_json::to_value(&client.requests_of(RequestMethod::LedgerCurrent)[0]).unwrap(),
+            serde_json::json!({})
+        );
+
+        // The result stays JSON even with the shape of a typed result,
+        // until it is asked for.
+        let response = client
+            .request_raw("fee", serde_json::json!({}))
+            .await
+            .unwrap();
+        assert!(matches!(response.result, Some(XRPLResult::Other(_))));
+        let fee = response.try_into_result::<Fee>().unwrap();
+        assert_eq!(fee.drops.base_fee, "10".into());
+
+        assert!(client
+            .request_raw("fee", serde_json::json!(["not", "an", "object"]))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_request_raw_error() {
+        let client = MockClient::new().with_handler(|request| match request {
+            XRPLRequest::Raw(raw) if raw.method == "vault_info" => Some(MockResponse::error(
+                "unknownCmd",
+                32,
+                Some("Unknown method."),
+            )),
+            _ => None,
+        });
+
+        let response = client
+            .request_raw("vault_info", serde_json::json!({ "vault_id": "A3F0" }))
+            .await
+            .unwrap();
+        let error = response.try_into_result::<XRPLOtherResult>().unwrap_err();
+        let XRPLModelException::XRPLResultError(error) = error else {
+            panic!("expected an error response, got {error:?}");
+        };
+        assert_eq!(
+            error.error_response().unwrap().error,
+            crate::models::results::error::XRPLErrorCode::UnknownCmd
+        );
+        let XRPLRequest::Raw(request) = &client.requests()[0] else {
+            panic!("expected the raw request to be recorded");
+        };
+        assert_eq!(request.params["vault_id"], "A3F0");
+
+        // Raw requests may not send secret keys either.
+        let error = client
+            .request_raw(
+                "sign",
+                serde_json::json!({ "secret": "snoPBrXtMeMyMHUVTgbuqAfg1SUTb" }),
+            )
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("`sign` sends a secret key"));
+        assert_eq!(client.requests().len(), 1);
+
+        // JSON-RPC nests the error in the result.
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "error": "unknownCmd",
+                "error_code": 32,
+                "error_message": "Unknown method.",
+                "status": "error"
+            }
+        }))
+        .unwrap();
+        let response = async_client::into_raw_response(response).unwrap();
+        assert!(response
+            .try_into_result::<XRPLOtherResult>()
+            .unwrap_err()
+            .to_string()
+            .contains("unknownCmd"));
+    }
+}
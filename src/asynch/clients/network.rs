@@ -0,0 +1,159 @@
+use alloc::borrow::Cow;
+use url::Url;
+
+use super::{DEV_FAUCET_URL, TEST_FAUCET_URL};
+
+const XAHAU_TEST_FAUCET_URL: &str = "https://xahau-test.net/accounts";
+
+/// The servers and settings of an XRP Ledger network, to connect
+/// clients to it.
+///
+/// Clients connected to a network know its ID and faucet, so
+/// `autofill` does not ask the server for the ID and the faucet
+/// helpers do not guess the faucet from the server URL.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use url::Url;
+/// use xrpl::asynch::clients::Network;
+///
+/// let testnet = Network::testnet();
+/// assert_eq!(testnet.network_id, Some(1));
+/// assert!(testnet.is_test);
+///
+/// // A private network.
+/// let network = Network::new(
+///     "private".into(),
+///     Url::parse("ws://localhost:6006").unwrap(),
+///     Url::parse("http://localhost:5005").unwrap(),
+///     Some(1234),
+///     None,
+///     true,
+/// );
+/// assert_eq!(network.faucet_url, None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Network {
+    pub name: Cow<'static, str>,
+    pub websocket_url: Url,
+    pub json_rpc_url: Url,
+    /// The ID transactions on networks above 1024 must carry as
+    /// `NetworkID`. `None` if unknown, to ask the server.
+    pub network_id: Option<u32>,
+    /// The faucet funding new accounts, on test networks.
+    pub faucet_url: Option<Url>,
+    /// Whether the network's XRP has no value.
+    pub is_test: bool,
+}
+
+impl Network {
+    pub fn new(
+        name: Cow<'static, str>,
+        websocket_url: Url,
+        json_rpc_url: Url,
+        network_id: Option<u32>,
+        faucet_url: Option<Url>,
+        is_test: bool,
+    ) -> Self {
+        Self {
+            name,
+            websocket_url,
+            json_rpc_url,
+            network_id,
+            faucet_url,
+            is_test,
+        }
+    }
+
+    /// The XRP Ledger Mainnet.
+    pub fn mainnet() -> Self {
+        Self::preset(
+            "mainnet",
+            "wss://xrplcluster.com/",
+            "https://xrplcluster.com/",
+            0,
+            None,
+            false,
+        )
+    }
+
+    /// The XRP Ledger Testnet.
+    pub fn testnet() -> Self {
+        Self::preset(
+            "testnet",
+            "wss://s.altnet.rippletest.net:51233/",
+            "https://s.altnet.rippletest.net:51234/",
+            1,
+            Some(TEST_FAUCET_URL),
+            true,
+        )
+    }
+
+    /// The XRP Ledger Devnet.
+    pub fn devnet() -> Self {
+        Self::preset(
+            "devnet",
+            "wss://s.devnet.rippletest.net:51233/",
+            "https://s.devnet.rippletest.net:51234/",
+            2,
+            Some(DEV_FAUCET_URL),
+            true,
+        )
+    }
+
+    /// The Xahau Testnet.
+    pub fn xahau_testnet() -> Self {
+        Self::preset(
+            "xahau_testnet",
+            "wss://xahau-test.net/",
+            "https://xahau-test.net/",
+            21338,
+            Some(XAHAU_TEST_FAUCET_URL),
+            true,
+        )
+    }
+
+    fn preset(
+        name: &'static str,
+        websocket_url: &str,
+        json_rpc_url: &str,
+        network_id: u32,
+        faucet_url: Option<&str>,
+        is_test: bool,
+    ) -> Self {
+        let parse = |url| Url::parse(url).expect("valid preset URL");
+
+        Self::new(
+            name.into(),
+            parse(websocket_url),
+            parse(json_rpc_url),
+            Some(network_id),
+            faucet_url.map(parse),
+            is_test,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        for (network, network_id) in [
+            (Network::mainnet(), 0),
+            (Network::testnet(), 1),
+            (Network::devnet(), 2),
+            (Network::xahau_testnet(), 21338),
+        ] {
+            assert_eq!(network.network_id, Some(network_id));
+            assert_eq!(network.websocket_url.scheme(), "wss");
+            assert_eq!(network.json_rpc_url.scheme(), "https");
+            // Only test networks have a faucet.
+            assert_eq!(network.faucet_url.is_some(), network.is_test);
+        }
+    }
+}
@@ -0,0 +1,658 @@
+#[cfg(feature = "std")]
+use alloc::time::Instant;
+use core::cell::RefCell;
+use core::time::Duration;
+use embassy_sync::{
+    blocking_mutex::{raw::RawMutex, Mutex},
+    semaphore::{GreedySemaphore, Semaphore},
+};
+use url::Url;
+
+use crate::{
+    models::{
+        requests::{RequestMethod, XRPLRequest},
+        results::{error::XRPLErrorCode, XRPLResponse},
+    },
+    utils::Ratio,
+};
+
+use super::{
+    backoff::ExponentialBackoff,
+    client::{ApiVersion, XRPLClient},
+    exceptions::{XRPLClientException, XRPLClientResult},
+    Network, SingleExecutorMutex,
+};
+
+/// The time source of an [`AsyncRateLimitedClient`].
+#[allow(async_fn_in_trait)]
+pub trait Clock {
+    /// The time passed since some fixed point.
+    fn now(&self) -> Duration;
+
+    async fn sleep(&self, duration: Duration);
+}
+
+/// Reads the time from the system, or from `embassy-time` without the
+/// `std` feature, and sleeps with the runtime enabled by the `-rt`
+/// features. Without either the time stands still and nothing is
+/// waited for.
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "std"), derive(Default))]
+pub struct RuntimeClock {
+    #[cfg(feature = "std")]
+    origin: Instant,
+}
+
+#[cfg(feature = "std")]
+impl Default for RuntimeClock {
+    fn default() -> Self {
+        Self {
+            origin: Instant::now(),
+        }
+    }
+}
+
+impl Clock for RuntimeClock {
+    #[allow(clippy::needless_return)]
+    fn now(&self) -> Duration {
+        #[cfg(feature = "std")]
+        {
+            return self.origin.elapsed();
+        }
+        #[cfg(all(not(feature = "std"), feature = "embassy-rt"))]
+        {
+            return Duration::from_micros(embassy_time::Instant::now().as_micros());
+        }
+        #[cfg(not(any(feature = "std", feature = "embassy-rt")))]
+        Duration::ZERO
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        // Without a timer nothing is waited for, see above.
+        let _ = crate::asynch::wait(duration).await;
+    }
+}
+
+/// How requests the server was too busy for are sent again, after
+/// the delays of an [`ExponentialBackoff`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackoffPolicy {
+    /// How often to retry a request before returning the busy
+    /// answer. `0` disables retrying.
+    pub max_retries: u32,
+    pub backoff: ExponentialBackoff,
+    /// Also retry `submit` and `submit_multisigned`. A busy server may
+    /// still have relayed the transaction, so this is off by default.
+    pub retry_submissions: bool,
+}
+
+impl Default for BackoffPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            backoff: ExponentialBackoff::new(
+                Duration::from_millis(500),
+                Duration::from_secs(10),
+                Ratio::from_basis_points(2000),
+            ),
+            retry_submissions: false,
+        }
+    }
+}
+
+impl BackoffPolicy {
+    pub fn new(max_retries: u32, base_delay: Duration, max_delay: Duration, jitter: Ratio) -> Self {
+        Self {
+            max_retries,
+            backoff: ExponentialBackoff::new(base_delay, max_delay, jitter),
+            retry_submissions: false,
+        }
+    }
+
+    /// Never retry; busy answers are returned as they are.
+    pub fn disabled() -> Self {
+        Self {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_retry_submissions(mut self, retry_submissions: bool) -> Self {
+        self.retry_submissions = retry_submissions;
+        self
+    }
+
+    /// The delay before retry `retry`, counting from 1.
+    pub fn delay(&self, retry: u32) -> Duration {
+        self.backoff.delay(retry)
+    }
+
+    fn retries(&self, request: &XRPLRequest<'_>) -> bool {
+        self.retry_submissions
+            || !matches!(
+                request.method(),
+                RequestMethod::Submit | RequestMethod::SubmitMultisigned
+            )
+    }
+}
+
+/// Counters of an [`AsyncRateLimitedClient`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStats {
+    /// How many requests were sent to the wrapped client, retries
+    /// included.
+    pub requests: u64,
+    /// How many of them had to wait for the rate limit.
+    pub throttled: u64,
+    /// How many of them were retries after a busy answer.
+    pub retries: u64,
+    /// How long was waited for the rate limit and between retries.
+    pub waited: Duration,
+}
+
+/// A rate of `burst` requests at once, refilled at one request per
+/// `interval`.
+struct Rate {
+    interval: Duration,
+    burst: u32,
+}
+
+#[derive(Default)]
+struct LimiterState {
+    /// When the rate limit would be reached if no request was sent in
+    /// between, see the generic cell rate algorithm.
+    theoretical_arrival: Duration,
+    stats: RateLimitStats,
+}
+
+/// A client that limits how many requests per second, and how many at
+/// once, are sent with the wrapped client. Requests over the limit wait
+/// for their turn instead of failing, so helpers sending many requests,
+/// like `autofill` or paging through account objects, stay under the
+/// limits of public servers.
+///
+/// Requests the server answers with `tooBusy` or `slowDown`, or a
+/// JSON-RPC `503 Service Unavailable`, are sent again after a growing
+/// delay, see [`BackoffPolicy`]. Submissions are not retried unless
+/// opted in.
+///
+/// # Examples
+///
+/// ```no_run
+/// use xrpl::asynch::clients::{AsyncJsonRpcClient, AsyncRateLimitedClient, XRPLAsyncClient};
+/// use xrpl::models::requests::ledger_current::LedgerCurrent;
+///
+/// # #[cfg(feature = "std")]
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let client: AsyncRateLimitedClient<_> =
+///     AsyncRateLimitedClient::new(AsyncJsonRpcClient::connect(
+///         "https://xrplcluster.com".parse().unwrap(),
+///     ))
+///     .with_rate(10, 20)
+///     .with_max_concurrent(4);
+///
+/// let response = client.request(LedgerCurrent::new(None).into()).await.unwrap();
+/// # });
+/// ```
+pub struct AsyncRateLimitedClient<C, M = SingleExecutorMutex, K = RuntimeClock>
+where
+    C: XRPLClient,
+    M: RawMutex,
+    K: Clock,
+{
+    client: C,
+    clock: K,
+    rate: Option<Rate>,
+    concurrency: Option<GreedySemaphore<M>>,
+    backoff: BackoffPolicy,
+    state: Mutex<M, RefCell<LimiterState>>,
+}
+
+impl<C, M> AsyncRateLimitedClient<C, M>
+where
+    C: XRPLClient,
+    M: RawMutex,
+{
+    /// Wrap `client` without limits, retrying busy answers with the
+    /// default [`BackoffPolicy`].
+    pub fn new(client: C) -> Self {
+        Self {
+            client,
+            clock: RuntimeClock::default(),
+            rate: None,
+            concurrency: None,
+            backoff: BackoffPolicy::default(),
+            state: Mutex::new(RefCell::new(LimiterState::default())),
+        }
+    }
+}
+
+impl<C, M, K> AsyncRateLimitedClient<C, M, K>
+where
+    C: XRPLClient,
+    M: RawMutex,
+    K: Clock,
+{
+    /// Send at most `requests_per_second` requests per second, after
+    /// an initial `burst` of requests sent at once.
+    ///
+    /// # Panics
+    ///
+    /// If `requests_per_second` is `0`.
+    pub fn with_rate(mut self, requests_per_second: u32, burst: u32) -> Self {
+        assert!(
+            requests_per_second > 0,
+            "the rate limit needs at least one request per second"
+        );
+        self.rate = Some(Rate {
+            interval: Duration::from_secs(1) / requests_per_second,
+            burst: burst.max(1),
+        });
+        self
+    }
+
+    /// Send at most `max_concurrent` requests at once.
+    ///
+    /// # Panics
+    ///
+    /// If `max_concurrent` is `0`.
+    pub fn with_max_concurrent(mut self, max_concurrent: usize) -> Self {
+        assert!(
+            max_concurrent > 0,
+            "the concurrency limit needs at least one request"
+        );
+        self.concurrency = Some(GreedySemaphore::new(max_concurrent));
+        self
+    }
+
+    pub fn with_backoff(mut self, backoff: BackoffPolicy) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Read the time from and sleep with `clock`, e.g. a fake clock in
+    /// tests.
+    pub fn with_clock<L: Clock>(self, clock: L) -> AsyncRateLimitedClient<C, M, L> {
+        AsyncRateLimitedClient {
+            client: self.client,
+            clock,
+            rate: self.rate,
+            concurrency: self.concurrency,
+            backoff: self.backoff,
+            state: self.state,
+        }
+    }
+
+    pub fn get_ref(&self) -> &C {
+        &self.client
+    }
+
+    pub fn clock(&self) -> &K {
+        &self.clock
+    }
+
+    pub fn into_inner(self) -> C {
+        self.client
+    }
+
+    pub fn stats(&self) -> RateLimitStats {
+        self.state.lock(|state| state.borrow().stats)
+    }
+
+    /// Reserve the next slot of the rate limit and wait for it.
+    async fn throttle(&self) {
+        let delay = self.state.lock(|state| {
+            let mut state = state.borrow_mut();
+            state.stats.requests += 1;
+            let Some(rate) = &self.rate else {
+                return Duration::ZERO;
+            };
+            let now = self.clock.now();
+            let arrival = state.theoretical_arrival.max(now);
+            let allowed_at = arrival.saturating_sub(rate.interval * (rate.burst - 1));
+            state.theoretical_arrival = arrival + rate.interval;
+
+            let delay = allowed_at.saturating_sub(now);
+            if !delay.is_zero() {
+                state.stats.throttled += 1;
+                state.stats.waited += delay;
+            }
+            delay
+        });
+        if !delay.is_zero() {
+            self.clock.sleep(delay).await;
+        }
+    }
+
+    async fn send<'a: 'b, 'b>(
+        &self,
+        request: XRPLRequest<'a>,
+    ) -> XRPLClientResult<XRPLResponse<'b>> {
+        self.throttle().await;
+        let _permit = match &self.concurrency {
+            Some(semaphore) => Some(
+                semaphore
+                    .acquire(1)
+                    .await
+                    .unwrap_or_else(|never| match never {}),
+            ),
+            None => None,
+        };
+
+        self.client.request_impl(request).await
+    }
+}
+
+impl<C, M, K> XRPLClient for AsyncRateLimitedClient<C, M, K>
+where
+    C: XRPLClient,
+    M: RawMutex,
+    K: Clock,
+{
+    async fn request_impl<'a: 'b, 'b>(
+        &self,
+        request: XRPLRequest<'a>,
+    ) -> XRPLClientResult<XRPLResponse<'b>> {
+        if self.backoff.max_retries == 0 || !self.backoff.retries(&request) {
+            return self.send(request).await;
+        }
+
+        let mut retry = 0;
+        loop {
+            let response = self.send(request.clone()).await;
+            if retry == self.backoff.max_retries || !is_busy(&response) {
+                return response;
+            }
+            retry += 1;
+            let delay = self.backoff.delay(retry);
+            self.state.lock(|state| {
+                let mut state = state.borrow_mut();
+                state.stats.retries += 1;
+                state.stats.waited += delay;
+            });
+            self.clock.sleep(delay).await;
+        }
+    }
+
+    fn get_host(&self) -> Url {
+        self.client.get_host()
+    }
+
+    fn get_timeout(&self) -> Option<Duration> {
+        self.client.get_timeout()
+    }
+
+    fn get_api_version(&self) -> Option<ApiVersion> {
+        self.client.get_api_version()
+    }
+
+    fn get_network(&self) -> Option<&Network> {
+        self.client.get_network()
+    }
+}
+
+/// Whether the server asked to send the request again later.
+fn is_busy(response: &XRPLClientResult<XRPLResponse<'_>>) -> bool {
+    match response {
+        Ok(response) => {
+            !response.is_success()
+                && response.error_response().is_some_and(|error| {
+                    matches!(
+                        error.error,
+                        XRPLErrorCode::TooBusy | XRPLErrorCode::SlowDown
+                    )
+                })
+        }
+        Err(XRPLClientException::NonJsonResponse { status, .. }) => *status == Some(503),
+        Err(_) => false,
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use core::cell::Cell;
+
+    use super::*;
+    use crate::asynch::clients::{MockClient, MockResponse, XRPLAsyncClient};
+    use crate::models::requests::{ledger_current::LedgerCurrent, submit::Submit};
+
+    /// A clock whose time only moves on when slept with.
+    #[derive(Default)]
+    struct MockClock {
+        now: Cell<Duration>,
+        sleeps: RefCell<Vec<Duration>>,
+    }
+
+    impl MockClock {
+        fn sleeps(&self) -> Vec<Duration> {
+            self.sleeps.borrow().clone()
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> Duration {
+            self.now.get()
+        }
+
+        async fn sleep(&self, duration: Duration) {
+            self.sleeps.borrow_mut().push(duration);
+            self.now.set(self.now.get() + duration);
+        }
+    }
+
+    fn ledger_current() -> MockResponse {
+        MockResponse::result(serde_json::json!({ "ledger_current_index": 6 }))
+    }
+
+    fn too_busy() -> MockResponse {
+        MockResponse::error(
+            "tooBusy",
+            9,
+            Some("The server is too busy to help you now."),
+        )
+    }
+
+    fn no_jitter() -> BackoffPolicy {
+        BackoffPolicy::new(
+            3,
+            Duration::from_millis(100),
+            Duration::from_millis(250),
+            Ratio::from_basis_points(0),
+        )
+    }
+
+    #[test]
+    fn test_backoff_clamps_the_jitter() {
+        // Set directly, the jitter is clamped like in `new`.
+        let mut backoff = no_jitter();
+        for jitter in [2.0, -1.0] {
+            backoff.backoff.jitter = Ratio::from_fraction(jitter).unwrap();
+            assert!(backoff.delay(1) <= Duration::from_millis(100));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_throttles_after_burst() {
+        let client: AsyncRateLimitedClient<_> = AsyncRateLimitedClient::new(
+            MockClient::new().with_response(RequestMethod::LedgerCurrent, ledger_current()),
+        );
+        let client = client.with_rate(4, 2).with_clock(MockClock::default());
+
+        for _ in 0..5 {
+            client
+                .request(LedgerCurrent::new(None).into())
+                .await
+                .unwrap();
+        }
+
+        let slot = Duration::from_millis(250);
+        assert_eq!(client.clock().sleeps(), vec![slot; 3]);
+        assert_eq!(
+            client.stats(),
+            RateLimitStats {
+                requests: 5,
+                throttled: 3,
+                retries: 0,
+                waited: slot * 3,
+            }
+        );
+
+        // Idle time refills the burst.
+        client.clock().now.set(Duration::from_secs(10));
+        for _ in 0..2 {
+            client
+                .request(LedgerCurrent::new(None).into())
+                .await
+                .unwrap();
+        }
+        assert_eq!(client.stats().throttled, 3);
+    }
+
+    #[tokio::test]
+    async fn test_retries_busy_answers_with_backoff() {
+        let client: AsyncRateLimitedClient<_> = AsyncRateLimitedClient::new(
+            MockClient::new()
+                .with_response(RequestMethod::LedgerCurrent, too_busy())
+                .with_response(
+                    RequestMethod::LedgerCurrent,
+                    MockResponse::error("slowDown", 10, None),
+                )
+                .with_response(RequestMethod::LedgerCurrent, too_busy())
+                .with_response(RequestMethod::LedgerCurrent, ledger_current()),
+        );
+        let client = client
+            .with_backoff(no_jitter())
+            .with_clock(MockClock::default());
+
+        let response = client
+            .request(LedgerCurrent::new(None).into())
+            .await
+            .unwrap();
+        assert!(response.is_success());
+        assert_eq!(client.get_ref().requests().len(), 4);
+        assert_eq!(
+            client.clock().sleeps(),
+            vec![
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+                Duration::from_millis(250),
+            ]
+        );
+        assert_eq!(client.stats().retries, 3);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_retries() {
+        let client: AsyncRateLimitedClient<_> = AsyncRateLimitedClient::new(
+            MockClient::new().with_response(RequestMethod::LedgerCurrent, too_busy()),
+        );
+        let client = client
+            .with_backoff(no_jitter())
+            .with_clock(MockClock::default());
+
+        let response = client
+            .request(LedgerCurrent::new(None).into())
+            .await
+            .unwrap();
+        assert_eq!(
+            response.error_response().unwrap().error,
+            XRPLErrorCode::TooBusy
+        );
+        assert_eq!(client.get_ref().requests().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_retries_service_unavailable() {
+        let client: AsyncRateLimitedClient<_> = AsyncRateLimitedClient::new(
+            MockClient::new()
+                .with_response(
+                    RequestMethod::LedgerCurrent,
+                    MockResponse::client_error(|| XRPLClientException::NonJsonResponse {
+                        status: Some(503),
+                        content_type: Some("text/html".into()),
+                        body_preview: "<html>503 Service Unavailable</html>".into(),
+                    }),
+                )
+                .with_response(RequestMethod::LedgerCurrent, ledger_current()),
+        );
+        let client = client
+            .with_backoff(no_jitter())
+            .with_clock(MockClock::default());
+
+        assert!(client
+            .request(LedgerCurrent::new(None).into())
+            .await
+            .unwrap()
+            .is_success());
+        assert_eq!(client.stats().retries, 1);
+    }
+
+    #[tokio::test]
+    async fn test_does_not_retry_submissions_by_default() {
+        let submit = || -> XRPLRequest<'static> {
+            Submit::new(None, "1200002280000000".into(), None).into()
+        };
+        let mock = || MockClient::new().with_response(RequestMethod::Submit, too_busy());
+
+        let client: AsyncRateLimitedClient<_> = AsyncRateLimitedClient::new(mock());
+        let client = client
+            .with_backoff(no_jitter())
+            .with_clock(MockClock::default());
+        let response = client.request(submit()).await.unwrap();
+        assert!(!response.is_success());
+        assert_eq!(client.get_ref().requests().len(), 1);
+        assert!(client.clock().sleeps().is_empty());
+
+        let client: AsyncRateLimitedClient<_> = AsyncRateLimitedClient::new(mock());
+        let client = client
+            .with_backoff(no_jitter().with_retry_submissions(true))
+            .with_clock(MockClock::default());
+        client.request(submit()).await.unwrap();
+        assert_eq!(client.get_ref().requests().len(), 4);
+    }
+
+    /// Counts how many of its requests are in flight at once.
+    #[derive(Default)]
+    struct InFlightClient {
+        in_flight: Cell<usize>,
+        max_in_flight: Cell<usize>,
+    }
+
+    impl XRPLClient for InFlightClient {
+        async fn request_impl<'a: 'b, 'b>(
+            &self,
+            request: XRPLRequest<'a>,
+        ) -> XRPLClientResult<XRPLResponse<'b>> {
+            self.in_flight.set(self.in_flight.get() + 1);
+            self.max_in_flight
+                .set(self.max_in_flight.get().max(self.in_flight.get()));
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            self.in_flight.set(self.in_flight.get() - 1);
+
+            MockClient::new()
+                .with_response(RequestMethod::LedgerCurrent, ledger_current())
+                .request_impl(request)
+                .await
+        }
+
+        fn get_host(&self) -> Url {
+            Url::parse("http://localhost").unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_limits_concurrent_requests() {
+        let client: AsyncRateLimitedClient<_> =
+            AsyncRateLimitedClient::new(InFlightClient::default());
+        let client = client.with_max_concurrent(2);
+        let request = || client.request(LedgerCurrent::new(None).into());
+
+        let (a, b, c, d) =
+            embassy_futures::join::join4(request(), request(), request(), request()).await;
+        for response in [a, b, c, d] {
+            assert!(response.unwrap().is_success());
+        }
+        assert_eq!(client.get_ref().max_in_flight.get(), 2);
+    }
+}
@@ -1,34 +1,54 @@
-use core::{marker::PhantomData, ops::DerefMut};
+use core::{
+    convert::TryFrom,
+    marker::PhantomData,
+    net::{IpAddr, SocketAddr},
+    ops::DerefMut,
+    time::Duration,
+};
 
 use alloc::{
+    boxed::Box,
+    format,
     string::{String, ToString},
     sync::Arc,
 };
 use embassy_sync::blocking_mutex::raw::RawMutex;
 use embassy_sync::mutex::Mutex;
-use embedded_io_async::{ErrorType, Read, Write};
+use embedded_io_async::{Error, ErrorType, Read, Write};
+use embedded_nal_async::{AddrType, Dns, TcpConnect};
+use embedded_tls::{Aes128GcmSha256, TlsConfig, TlsConnection, TlsContext, TlsError};
 use embedded_websocket_embedded_io::{
-    framer_async::{Framer, ReadResult},
-    Client, WebSocketClient, WebSocketOptions, WebSocketSendMessageType,
+    framer_async::Framer, Client, WebSocketClient, WebSocketOptions, WebSocketSendMessageType,
 };
-use rand::RngCore;
-use url::Url;
+use rand::{CryptoRng, RngCore};
+use url::{Host, Url};
 
-use super::{WebSocketClosed, WebSocketOpen};
+use super::{
+    message_reader::{Message, MessageReader, DEFAULT_MAX_MESSAGE_SIZE},
+    request_to_websocket, WebSocketClosed, WebSocketOpen, WebSocketUrl, XRPLAsyncWebsocketIO,
+};
 use crate::{
     asynch::clients::{
         client::XRPLClient as ClientTrait,
-        websocket::websocket_base::{MessageHandler, WebsocketBase},
+        websocket::websocket_base::{MessageHandler, PendingRequest, WebsocketBase},
     },
     models::results::XRPLResponse,
 };
 use crate::{
-    asynch::clients::{exceptions::XRPLClientResult, SingleExecutorMutex},
+    asynch::clients::{
+        exceptions::XRPLClientResult, ApiVersion, SingleExecutorMutex, INITIAL_REQUEST_TIMEOUT,
+    },
     models::requests::{Request, XRPLRequest},
 };
 
 use super::exceptions::XRPLWebSocketException;
 
+/// How [`AsyncWebSocketClient::connect`] checks the certificate of a
+/// `wss` server. [`NoVerify`] accepts any certificate, so anyone
+/// between the client and the server can read and forge its answers;
+/// only use it for servers reached over a trusted network.
+pub use embedded_tls::{Certificate, NoVerify, TlsVerifier};
+
 pub struct AsyncWebSocketClient<
     const BUF: usize,
     Tcp,
@@ -42,8 +62,15 @@ pub struct AsyncWebSocketClient<
     tcp: Arc<Mutex<M, Tcp>>,
     websocket: Arc<Mutex<M, Framer<Rng, Client>>>,
     tx_buffer: [u8; BUF],
+    /// Reads the messages of the server. The framer only writes, as
+    /// it cannot read messages larger than its buffer or fragmented
+    /// ones.
+    reader: Arc<Mutex<M, MessageReader>>,
+    max_message_size: usize,
     websocket_base: Arc<Mutex<M, WebsocketBase<M>>>,
     uri: Url,
+    timeout: Option<Duration>,
+    api_version: Option<ApiVersion>,
     status: PhantomData<Status>,
 }
 
@@ -59,30 +86,12 @@ where
         sub_protocols: Option<&[&str]>,
         additional_headers: Option<&[&str]>,
     ) -> XRPLClientResult<AsyncWebSocketClient<BUF, Tcp, Rng, M, WebSocketOpen>> {
-        // replace the scheme with http or https
-        let scheme = match url.scheme() {
-            "wss" => "https",
-            "ws" => "http",
-            _ => url.scheme(),
-        };
-        let port = match url.port() {
-            Some(port) => port,
-            None => match url.scheme() {
-                "wss" => 443,
-                "ws" => 80,
-                _ => 80,
-            },
-        }
-        .to_string();
-        let path = url.path();
-        let host = match url.host_str() {
-            Some(host) => host,
-            None => return Err(XRPLWebSocketException::Disconnected.into()),
-        };
-        let origin = scheme.to_string() + "://" + host + ":" + &port + path;
+        let websocket_url = WebSocketUrl::try_from(url.clone())?;
+        let host = websocket_url.host_header();
+        let origin = websocket_url.origin();
         let websocket_options = WebSocketOptions {
-            path,
-            host,
+            path: websocket_url.resource(),
+            host: &host,
             origin: &origin,
             sub_protocols,
             additional_headers,
@@ -100,25 +109,160 @@ where
             )
             .await
         {
-            match error {
-                // FramerError::WebSocket(embedded_websocket_embedded_io::Error::HttpResponseCodeInvalid(
-                //     Some(308),
-                // )) => (),
-                error => return Err(XRPLWebSocketException::from(error).into()),
-            }
+            #[cfg(feature = "tracing")]
+            tracing::error!(error = ?error, %url, "connection failed");
+            return Err(XRPLWebSocketException::from(error).into());
         }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(%url, "connected");
 
         Ok(AsyncWebSocketClient {
             tcp,
             websocket,
             tx_buffer: buffer,
+            reader: Arc::new(Mutex::new(MessageReader::new())),
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
             websocket_base: Arc::new(Mutex::new(WebsocketBase::new())),
             uri: url,
+            timeout: INITIAL_REQUEST_TIMEOUT,
+            api_version: None,
             status: PhantomData::<WebSocketOpen>,
         })
     }
 }
 
+/// The connection of a client opened with
+/// [`AsyncWebSocketClient::connect`]: TCP for a `ws` URL, TLS over TCP
+/// for a `wss` URL.
+pub enum WebSocketStream<'a, S>
+where
+    S: Read + Write + 'a,
+{
+    Plain(S),
+    Tls(Box<TlsConnection<'a, S, Aes128GcmSha256>>),
+}
+
+impl<'a, S> ErrorType for WebSocketStream<'a, S>
+where
+    S: Read + Write + 'a,
+{
+    type Error = XRPLWebSocketException;
+}
+
+impl<'a, S> Read for WebSocketStream<'a, S>
+where
+    S: Read + Write + 'a,
+{
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        match self {
+            WebSocketStream::Plain(stream) => stream
+                .read(buf)
+                .await
+                .map_err(|error| XRPLWebSocketException::EmbeddedIoError(error.kind())),
+            WebSocketStream::Tls(stream) => stream.read(buf).await.map_err(tls_error),
+        }
+    }
+}
+
+impl<'a, S> Write for WebSocketStream<'a, S>
+where
+    S: Read + Write + 'a,
+{
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        match self {
+            WebSocketStream::Plain(stream) => stream
+                .write(buf)
+                .await
+                .map_err(|error| XRPLWebSocketException::EmbeddedIoError(error.kind())),
+            WebSocketStream::Tls(stream) => stream.write(buf).await.map_err(tls_error),
+        }
+    }
+
+    async fn flush(&mut self) -> Result<(), Self::Error> {
+        match self {
+            WebSocketStream::Plain(stream) => stream
+                .flush()
+                .await
+                .map_err(|error| XRPLWebSocketException::EmbeddedIoError(error.kind())),
+            WebSocketStream::Tls(stream) => stream.flush().await.map_err(tls_error),
+        }
+    }
+}
+
+fn tls_error(error: TlsError) -> XRPLWebSocketException {
+    XRPLWebSocketException::Tls(format!("{:?}", error))
+}
+
+impl<'a, const BUF: usize, M, S, Rng>
+    AsyncWebSocketClient<BUF, WebSocketStream<'a, S>, Rng, M, WebSocketClosed>
+where
+    M: RawMutex,
+    S: Read + Write + Unpin + 'a,
+    Rng: RngCore + CryptoRng,
+{
+    /// Connect to a `ws://` or `wss://` URL: resolve its host, open a
+    /// TCP connection, do the TLS handshake for `wss` and upgrade the
+    /// connection to a websocket.
+    ///
+    /// The TLS buffers hold encrypted records and are only used for
+    /// `wss`. The read buffer should fit a whole record, 16640 bytes,
+    /// the write buffer can be smaller.
+    ///
+    /// The certificate of a `wss` server is checked by the verifier
+    /// `V`, against the certificate authority `ca` if given. With
+    /// [`NoVerify`] it is not checked at all: the connection is
+    /// encrypted, but the server is not authenticated, so a man in the
+    /// middle can forge ledger and account data.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn connect<V, T, D>(
+        url: &str,
+        tcp: &'a T,
+        dns: &D,
+        mut rng: Rng,
+        tls_read_buffer: &'a mut [u8],
+        tls_write_buffer: &'a mut [u8],
+        ca: Option<Certificate<'a>>,
+    ) -> XRPLClientResult<AsyncWebSocketClient<BUF, WebSocketStream<'a, S>, Rng, M, WebSocketOpen>>
+    where
+        V: for<'v> TlsVerifier<'v, Aes128GcmSha256>,
+        T: TcpConnect<Connection<'a> = S>,
+        D: Dns,
+    {
+        let url = WebSocketUrl::parse(url)?;
+        let address = match url.host() {
+            Host::Ipv4(address) => IpAddr::V4(address),
+            Host::Ipv6(address) => IpAddr::V6(address),
+            Host::Domain(domain) => dns
+                .get_host_by_name(domain, AddrType::Either)
+                .await
+                .map_err(|error| XRPLWebSocketException::Dns {
+                    host: domain.to_string(),
+                    error: format!("{:?}", error),
+                })?,
+        };
+        let connection = tcp
+            .connect(SocketAddr::new(address, url.port()))
+            .await
+            .map_err(|error| XRPLWebSocketException::Connect(format!("{:?}", error)))?;
+        let stream = if url.is_secure() {
+            let server_name = url.host_name();
+            let mut config = TlsConfig::new().with_server_name(&server_name);
+            if let Some(ca) = ca {
+                config = config.with_ca(ca);
+            }
+            let mut tls = TlsConnection::new(connection, tls_read_buffer, tls_write_buffer);
+            tls.open::<_, V>(TlsContext::new(&config, &mut rng))
+                .await
+                .map_err(tls_error)?;
+            WebSocketStream::Tls(Box::new(tls))
+        } else {
+            WebSocketStream::Plain(connection)
+        };
+
+        Self::open(stream, url.url().clone(), rng, None, None).await
+    }
+}
+
 impl<const BUF: usize, M, Tcp, Rng: RngCore> ErrorType
     for AsyncWebSocketClient<BUF, Tcp, Rng, M, WebSocketOpen>
 where
@@ -133,37 +277,90 @@ where
     M: RawMutex,
     Tcp: Read + Write + Unpin,
 {
+    /// Set how long requests wait for a response. `None` waits
+    /// forever.
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the API version requests are sent with, unless they set
+    /// one.
+    pub fn with_api_version(mut self, api_version: ApiVersion) -> Self {
+        self.api_version = Some(api_version);
+        self
+    }
+
+    /// Set the size of the largest message the client reads, 4 MiB by
+    /// default. The receive buffer grows up to it as large messages,
+    /// like full ledgers, come in; larger ones fail with
+    /// [`XRPLWebSocketException::MessageTooLarge`].
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
     async fn do_write(&self, buf: &[u8]) -> XRPLClientResult<usize, <Self as ErrorType>::Error> {
+        self.write_frame(WebSocketSendMessageType::Text, buf)
+            .await?;
+
+        Ok(buf.len())
+    }
+
+    async fn write_frame(
+        &self,
+        message_type: WebSocketSendMessageType,
+        payload: &[u8],
+    ) -> XRPLClientResult<(), <Self as ErrorType>::Error> {
         let mut inner = self.websocket.lock().await;
         let mut tcp = self.tcp.lock().await;
         let mut buffer = self.tx_buffer;
-        match inner
-            .write(
-                tcp.deref_mut(),
-                &mut buffer,
-                WebSocketSendMessageType::Text,
-                false,
-                buf,
-            )
+        inner
+            .write(tcp.deref_mut(), &mut buffer, message_type, true, payload)
             .await
-        {
-            Ok(()) => Ok(buf.len()),
-            Err(error) => Err(XRPLWebSocketException::from(error)),
+            .map_err(XRPLWebSocketException::from)
+    }
+
+    /// Read the next text message of the server, answering pings on
+    /// the way.
+    async fn read_message(&self) -> XRPLClientResult<String, <Self as ErrorType>::Error> {
+        loop {
+            let message = {
+                let mut reader = self.reader.lock().await;
+                let mut tcp = self.tcp.lock().await;
+                reader.read(tcp.deref_mut(), self.max_message_size).await?
+            };
+            match message {
+                Message::Text(text) => return Ok(text),
+                Message::Binary(binary) => {
+                    return String::from_utf8(binary)
+                        .map_err(|error| XRPLWebSocketException::Utf8(error.utf8_error()))
+                }
+                Message::Ping(payload) => {
+                    self.write_frame(WebSocketSendMessageType::Pong, &payload)
+                        .await?
+                }
+                Message::Pong(_) => {}
+                Message::Close(payload) => {
+                    // Complete the closing handshake; the connection is
+                    // gone either way.
+                    let _ = self
+                        .write_frame(WebSocketSendMessageType::CloseReply, &payload)
+                        .await;
+                    return Err(XRPLWebSocketException::Disconnected);
+                }
+            }
         }
     }
 
     async fn do_read(&self, buf: &mut [u8]) -> XRPLClientResult<usize, <Self as ErrorType>::Error> {
-        let mut inner = self.websocket.lock().await;
-        let mut tcp = self.tcp.lock().await;
-        match inner.read(tcp.deref_mut(), buf).await {
-            Some(Ok(ReadResult::Text(t))) => Ok(t.len()),
-            Some(Ok(ReadResult::Binary(b))) => Ok(b.len()),
-            Some(Ok(ReadResult::Ping(_))) => Ok(0),
-            Some(Ok(ReadResult::Pong(_))) => Ok(0),
-            Some(Ok(ReadResult::Close(_))) => Err(XRPLWebSocketException::Disconnected.into()),
-            Some(Err(error)) => Err(XRPLWebSocketException::from(error).into()),
-            None => Err(XRPLWebSocketException::Disconnected.into()),
-        }
+        let message = self.read_message().await?;
+        let Some(buf) = buf.get_mut(..message.len()) else {
+            return Err(XRPLWebSocketException::RxBufferTooSmall(message.len()));
+        };
+        buf.copy_from_slice(message.as_bytes());
+
+        Ok(message.len())
     }
 }
 
@@ -189,6 +386,28 @@ where
     }
 }
 
+impl<const BUF: usize, M, Tcp, Rng: RngCore> XRPLAsyncWebsocketIO
+    for AsyncWebSocketClient<BUF, Tcp, Rng, M, WebSocketOpen>
+where
+    M: RawMutex,
+    Tcp: Read + Write + Unpin,
+{
+    async fn xrpl_send(&mut self, message: XRPLRequest<'_>) -> XRPLClientResult<()> {
+        let message = request_to_websocket(&message)?;
+        self.do_write(message.as_bytes()).await?;
+
+        Ok(())
+    }
+
+    async fn xrpl_receive(&mut self) -> XRPLClientResult<Option<XRPLResponse<'_>>> {
+        let message = self.read_message().await?;
+        self.handle_message(message).await?;
+        let message = self.pop_message().await;
+
+        Ok(serde_json::from_str(&message)?)
+    }
+}
+
 impl<const BUF: usize, M, Tcp, Rng: RngCore> MessageHandler
     for AsyncWebSocketClient<BUF, Tcp, Rng, M, WebSocketOpen>
 where
@@ -227,6 +446,14 @@ where
         self.uri.clone()
     }
 
+    fn get_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    fn get_api_version(&self) -> Option<ApiVersion> {
+        self.api_version
+    }
+
     async fn request_impl<'a: 'b, 'b>(
         &self,
         mut request: XRPLRequest<'a>,
@@ -235,37 +462,21 @@ where
         self.set_request_id(&mut request);
         let request_id = request.get_common_fields().id.as_ref().unwrap();
         let mut websocket_base = self.websocket_base.lock().await;
-        websocket_base
-            .setup_request_future(request_id.to_string())
-            .await;
+        // Cancels the request if this future is dropped, e.g. on a timeout.
+        let mut websocket_base = PendingRequest::new(&mut websocket_base, request_id).await;
         // send request
-        let request_string = serde_json::to_string(&request)?;
+        let request_string = request_to_websocket(&request)?;
         self.do_write(request_string.as_bytes()).await?;
         // wait for response
         loop {
-            let mut rx_buffer = [0; 1024];
-            match self.do_read(&mut rx_buffer).await {
-                Ok(u_size) => {
-                    // If the buffer is empty, continue to the next iteration.
-                    if u_size == 0 {
-                        continue;
-                    }
-                    let message_str = match core::str::from_utf8(&rx_buffer[..u_size]) {
-                        Ok(response_str) => response_str,
-                        Err(error) => return Err(XRPLWebSocketException::Utf8(error).into()),
-                    };
-                    websocket_base
-                        .handle_message(message_str.to_string())
-                        .await?;
-                    let message_opt = websocket_base
-                        .try_recv_request(request_id.to_string())
-                        .await?;
-                    if let Some(message) = message_opt {
-                        let response = serde_json::from_str(&message)?;
-                        return Ok(response);
-                    }
-                }
-                Err(error) => return Err(error.into()),
+            let message = self.read_message().await?;
+            websocket_base.handle_message(message).await?;
+            let message_opt = websocket_base
+                .try_recv_request(request_id.to_string())
+                .await?;
+            if let Some(message) = message_opt {
+                let response = serde_json::from_str(&message)?;
+                return Ok(response);
             }
         }
     }
@@ -1,21 +1,29 @@
 use super::exceptions::XRPLWebSocketException;
-use super::{WebSocketClosed, WebSocketOpen};
+use super::reconnect::{ActiveSubscriptions, ConnectionState, ReconnectPolicy};
+use super::{request_to_websocket, WebSocketClosed, WebSocketOpen};
 use crate::asynch::clients::client::XRPLClient;
 use crate::asynch::clients::exceptions::{XRPLClientException, XRPLClientResult};
-use crate::asynch::clients::websocket::websocket_base::{MessageHandler, WebsocketBase};
-use crate::asynch::clients::SingleExecutorMutex;
+use crate::asynch::clients::websocket::websocket_base::{
+    MessageHandler, PendingRequest, WebsocketBase,
+};
+use crate::asynch::clients::{
+    body_preview, ApiVersion, Network, SingleExecutorMutex, INITIAL_REQUEST_TIMEOUT,
+};
+use crate::asynch::wait;
 use crate::models::requests::{Request, XRPLRequest};
 use crate::models::results::XRPLResponse;
 
 use alloc::string::{String, ToString};
 use alloc::sync::Arc;
 use core::marker::PhantomData;
+use core::time::Duration;
 use core::{pin::Pin, task::Poll};
 use embassy_futures::block_on;
 use embassy_sync::blocking_mutex::raw::RawMutex;
 use embassy_sync::mutex::Mutex;
 use futures::{Sink, SinkExt, Stream, StreamExt};
 use tokio::net::TcpStream;
+use tokio::sync::watch;
 use tokio_tungstenite::{tungstenite, MaybeTlsStream, WebSocketStream};
 use url::Url;
 
@@ -30,6 +38,12 @@ where
     websocket: Arc<Mutex<M, TokioTungsteniteMaybeTlsStream>>,
     websocket_base: Arc<Mutex<M, WebsocketBase<M>>>,
     uri: Url,
+    reconnect_policy: ReconnectPolicy,
+    connection_state: watch::Sender<ConnectionState>,
+    subscriptions: Arc<Mutex<M, ActiveSubscriptions>>,
+    timeout: Option<Duration>,
+    api_version: Option<ApiVersion>,
+    network: Option<Network>,
     status: PhantomData<Status>,
 }
 
@@ -112,15 +126,22 @@ where
                         Poll::Ready(Some(Ok(response_string)))
                     }
                     tungstenite::Message::Close(_) => {
+                        self.mark_disconnected();
                         Poll::Ready(Some(Err(XRPLWebSocketException::Disconnected.into())))
                     }
                     _ => Poll::Ready(Some(Err(
                         XRPLWebSocketException::UnexpectedMessageType.into()
                     ))),
                 },
-                Err(error) => Poll::Ready(Some(Err(error.into()))),
+                Err(error) => {
+                    if is_connection_error(&error) {
+                        self.mark_disconnected();
+                    }
+                    Poll::Ready(Some(Err(error.into())))
+                }
             },
             Poll::Ready(None) => {
+                self.mark_disconnected();
                 Poll::Ready(Some(Err(XRPLWebSocketException::Disconnected.into())))
             }
             Poll::Pending => Poll::Pending,
@@ -133,32 +154,250 @@ where
     M: RawMutex,
 {
     pub async fn open(uri: Url) -> XRPLClientResult<AsyncWebSocketClient<M, WebSocketOpen>> {
-        let stream = match tokio_tungstenite_connect_async(uri.to_string()).await {
-            Ok((stream, _)) => stream,
-            Err(error) => return Err(error.into()),
-        };
+        let stream = connect(&uri).await?;
         Ok(AsyncWebSocketClient {
             websocket: Arc::new(Mutex::new(stream)),
             websocket_base: Arc::new(Mutex::new(WebsocketBase::new())),
             uri,
+            reconnect_policy: ReconnectPolicy::default(),
+            connection_state: watch::Sender::new(ConnectionState::Connected),
+            subscriptions: Arc::new(Mutex::new(ActiveSubscriptions::default())),
+            timeout: INITIAL_REQUEST_TIMEOUT,
+            api_version: None,
+            network: None,
             status: PhantomData::<WebSocketOpen>,
         })
     }
+
+    /// Connect to the websocket server of `network`, e.g.
+    /// [`Network::testnet`].
+    pub async fn connect_network(
+        network: Network,
+    ) -> XRPLClientResult<AsyncWebSocketClient<M, WebSocketOpen>> {
+        let mut client = Self::open(network.websocket_url.clone()).await?;
+        client.network = Some(network);
+
+        Ok(client)
+    }
+}
+
+async fn connect(uri: &Url) -> XRPLClientResult<TokioTungsteniteMaybeTlsStream> {
+    let stream = async {
+        match tokio_tungstenite_connect_async(uri.to_string()).await {
+            Ok((stream, _)) => Ok(stream),
+            Err(tungstenite::Error::Http(response)) => {
+                // Surface what the server (or a proxy in front of it) actually said.
+                let content_type = response
+                    .headers()
+                    .get(tungstenite::http::header::CONTENT_TYPE)
+                    .and_then(|content_type| content_type.to_str().ok())
+                    .map(ToString::to_string);
+                Err(XRPLWebSocketException::UpgradeRejected {
+                    status: response.status().as_u16(),
+                    content_type,
+                    body_preview: body_preview(response.body().as_deref().unwrap_or_default()),
+                }
+                .into())
+            }
+            Err(error) => Err(error.into()),
+        }
+    };
+    #[cfg(feature = "tracing")]
+    let stream =
+        tracing::Instrument::instrument(stream, tracing::debug_span!("connect", url = %uri));
+    let stream = stream.await;
+    #[cfg(feature = "tracing")]
+    if let Err(error) = &stream {
+        tracing::error!(%error, url = %uri, "connection failed");
+    }
+
+    stream
+}
+
+/// Whether an error means the connection is gone.
+fn is_connection_error(error: &tungstenite::Error) -> bool {
+    matches!(
+        error,
+        tungstenite::Error::ConnectionClosed
+            | tungstenite::Error::AlreadyClosed
+            | tungstenite::Error::Io(_)
+            | tungstenite::Error::Protocol(_)
+    )
 }
 
 impl<M> AsyncWebSocketClient<M, WebSocketOpen>
 where
     M: RawMutex,
 {
+    /// Set how the client reconnects after its connection drops.
+    /// By default it tries [`ReconnectPolicy::default`].
+    ///
+    /// The client reconnects lazily, on the next request after the
+    /// drop. A client only read as a stream sees the drop as an
+    /// error and has to call [`reconnect`](Self::reconnect) itself.
+    pub fn with_reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+        self
+    }
+
+    /// Set how long requests wait for a response. `None` waits
+    /// forever.
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the API version requests are sent with, unless they
+    /// set one.
+    pub fn with_api_version(mut self, api_version: ApiVersion) -> Self {
+        self.api_version = Some(api_version);
+        self
+    }
+
+    /// Observe the state of the connection.
+    pub fn connection_state(&self) -> watch::Receiver<ConnectionState> {
+        self.connection_state.subscribe()
+    }
+
     pub async fn close(&self) -> XRPLClientResult<()> {
         let mut websocket = self.websocket.lock().await;
         let mut websocket_base = self.websocket_base.lock().await;
         websocket_base.close();
+        self.connection_state.send_replace(ConnectionState::Closed);
         match websocket.close(None).await {
             Ok(()) => Ok(()),
             Err(error) => Err(error.into()),
         }
     }
+
+    /// Reconnect following the reconnect policy, then re-issue the
+    /// active subscriptions. Requests reconnect on their own; call
+    /// this when only reading from the client as a stream, once it
+    /// returned a disconnection error.
+    pub async fn reconnect(&self) -> XRPLClientResult<()> {
+        let mut websocket_base = self.websocket_base.lock().await;
+        // Nothing answers the requests sent on the old connection.
+        websocket_base.close();
+        let max_attempts = self.reconnect_policy.max_attempts;
+        for attempt in 1..=max_attempts {
+            self.connection_state
+                .send_replace(ConnectionState::Reconnecting { attempt });
+            wait(self.reconnect_policy.delay(attempt)).await?;
+            let Ok(stream) = connect(&self.uri).await else {
+                continue;
+            };
+            let mut websocket = self.websocket.lock().await;
+            *websocket = stream;
+            self.connection_state
+                .send_replace(ConnectionState::Connected);
+            let subscriptions = self.subscriptions.lock().await;
+            for (id, envelope) in subscriptions.envelopes() {
+                let envelope = serde_json::to_string(&envelope)?;
+                self.send_and_receive(&mut websocket, &mut websocket_base, envelope, &id)
+                    .await?;
+            }
+
+            return Ok(());
+        }
+        self.connection_state.send_replace(ConnectionState::Closed);
+
+        Err(XRPLWebSocketException::ReconnectFailed {
+            attempts: max_attempts,
+        }
+        .into())
+    }
+
+    fn mark_disconnected(&self) {
+        let state = if self.reconnect_policy.max_attempts == 0 {
+            ConnectionState::Closed
+        } else {
+            ConnectionState::Dropped
+        };
+        self.connection_state.send_if_modified(|current| {
+            let modified = *current == ConnectionState::Connected;
+            if modified {
+                *current = state;
+            }
+            modified
+        });
+    }
+
+    /// Send a request envelope and wait for the response with `id`.
+    /// Fails with [`XRPLWebSocketException::ConnectionLost`] if the
+    /// connection drops in between.
+    async fn send_and_receive<'b>(
+        &self,
+        websocket: &mut TokioTungsteniteMaybeTlsStream,
+        websocket_base: &mut WebsocketBase<M>,
+        request_string: String,
+        id: &str,
+    ) -> XRPLClientResult<XRPLResponse<'b>> {
+        // Cancels the request if this future is dropped, e.g. on a timeout.
+        let mut websocket_base = PendingRequest::new(websocket_base, id).await;
+        if let Err(error) = websocket
+            .send(tungstenite::Message::Text(request_string))
+            .await
+        {
+            return Err(self.connection_error(&mut websocket_base, error));
+        }
+        loop {
+            let message = websocket.next().await;
+            match message {
+                Some(Ok(tungstenite::Message::Text(message))) => {
+                    websocket_base.handle_message(message).await?;
+                    let message_opt = websocket_base.try_recv_request(id.to_string()).await?;
+                    if let Some(message) = message_opt {
+                        let response = match serde_json::from_str(&message) {
+                            Ok(response) => response,
+                            Err(error) => return Err(error.into()),
+                        };
+                        return Ok(response);
+                    }
+                }
+                Some(Ok(tungstenite::Message::Binary(response))) => {
+                    let message = match String::from_utf8(response) {
+                        Ok(string) => string,
+                        Err(error) => {
+                            return Err(XRPLWebSocketException::Utf8(error.utf8_error()).into());
+                        }
+                    };
+                    match serde_json::from_str(&message) {
+                        Ok(response) => return Ok(response),
+                        Err(error) => return Err(error.into()),
+                    }
+                }
+                Some(Ok(tungstenite::Message::Close(_))) | None => {
+                    return Err(self.connection_error(
+                        &mut websocket_base,
+                        tungstenite::Error::ConnectionClosed,
+                    ));
+                }
+                Some(Ok(_)) => {
+                    return Err(XRPLWebSocketException::UnexpectedMessageType.into());
+                }
+                Some(Err(error)) => return Err(self.connection_error(&mut websocket_base, error)),
+            }
+        }
+    }
+
+    /// Turn a failed read or write into the error to return, failing
+    /// the pending requests fast if the connection is gone.
+    fn connection_error(
+        &self,
+        websocket_base: &mut WebsocketBase<M>,
+        error: tungstenite::Error,
+    ) -> XRPLClientException {
+        if !is_connection_error(&error) {
+            return error.into();
+        }
+        websocket_base.close();
+        self.mark_disconnected();
+        if self.reconnect_policy.max_attempts == 0 {
+            XRPLWebSocketException::Disconnected.into()
+        } else {
+            XRPLWebSocketException::ConnectionLost.into()
+        }
+    }
 }
 
 impl<M, Status> AsyncWebSocketClient<M, Status>
@@ -203,67 +442,221 @@ where
         self.uri.clone()
     }
 
+    fn get_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    fn get_api_version(&self) -> Option<ApiVersion> {
+        self.api_version
+    }
+
+    fn get_network(&self) -> Option<&Network> {
+        self.network.as_ref()
+    }
+
     async fn request_impl<'a: 'b, 'b>(
         &self,
         mut request: XRPLRequest<'a>,
     ) -> XRPLClientResult<XRPLResponse<'b>> {
-        // setup request future
+        // Copy the state out, the borrow blocks updating it.
+        let connection_state = *self.connection_state.borrow();
+        match connection_state {
+            ConnectionState::Connected => {}
+            ConnectionState::Dropped | ConnectionState::Reconnecting { .. } => {
+                self.reconnect().await?
+            }
+            ConnectionState::Closed => return Err(XRPLWebSocketException::Disconnected.into()),
+        }
         self.set_request_id(&mut request);
         let request_id = request.get_common_fields().id.as_ref().unwrap();
+        let request_string = request_to_websocket(&request)?;
         let mut websocket_base = self.websocket_base.lock().await;
-        websocket_base
-            .setup_request_future(request_id.to_string())
-            .await;
-        // send request
         let mut websocket = self.websocket.lock().await;
-        let request_string = match serde_json::to_string(&request) {
-            Ok(request_string) => request_string,
-            Err(error) => return Err(error.into()),
-        };
-        if let Err(error) = websocket
-            .send(tungstenite::Message::Text(request_string))
-            .await
-        {
-            return Err(error.into());
+        let response = self
+            .send_and_receive(
+                &mut websocket,
+                &mut websocket_base,
+                request_string,
+                request_id,
+            )
+            .await?;
+        if response.is_success() {
+            self.subscriptions.lock().await.record(&request);
         }
-        // wait for response
-        loop {
-            let message = websocket.next().await;
-            match message {
-                Some(Ok(tungstenite::Message::Text(message))) => {
-                    websocket_base.handle_message(message).await?;
-                    let message_opt = websocket_base
-                        .try_recv_request(request_id.to_string())
-                        .await?;
-                    if let Some(message) = message_opt {
-                        let response = match serde_json::from_str(&message) {
-                            Ok(response) => response,
-                            Err(error) => return Err(error.into()),
-                        };
-                        return Ok(response);
-                    }
-                }
-                Some(Ok(tungstenite::Message::Binary(response))) => {
-                    let message = match String::from_utf8(response) {
-                        Ok(string) => string,
-                        Err(error) => {
-                            return Err(XRPLWebSocketException::Utf8(error.utf8_error()).into());
-                        }
-                    };
-                    match serde_json::from_str(&message) {
-                        Ok(response) => return Ok(response),
-                        Err(error) => return Err(error.into()),
-                    }
-                }
-                Some(Ok(tungstenite::Message::Close(_))) => {
-                    return Err(XRPLWebSocketException::Disconnected.into());
-                }
-                Some(Ok(_)) => {
-                    return Err(XRPLWebSocketException::UnexpectedMessageType.into());
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString, vec, vec::Vec};
+    use serde_json::Value;
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::asynch::clients::tests::{serve_forbidden_html, FORBIDDEN_HTML};
+    use crate::asynch::clients::XRPLAsyncClient;
+    use crate::models::requests::{
+        server_info::ServerInfo,
+        subscribe::{StreamParameter, Subscribe},
+    };
+    use crate::utils::Ratio;
+
+    async fn receive_request(websocket: &mut WebSocketStream<TcpStream>) -> Value {
+        match websocket.next().await {
+            Some(Ok(tungstenite::Message::Text(request))) => {
+                serde_json::from_str(&request).unwrap()
+            }
+            message => panic!("expected a request, got {message:?}"),
+        }
+    }
+
+    async fn respond(websocket: &mut WebSocketStream<TcpStream>, request: &Value) {
+        let response = serde_json::json!({
+            "id": request["id"],
+            "result": {},
+            "status": "success",
+            "type": "response"
+        });
+        websocket
+            .send(tungstenite::Message::Text(response.to_string()))
+            .await
+            .unwrap();
+    }
+
+    /// Serve a websocket that drops the first connection after its
+    /// second request, and expects the subscription to be re-issued
+    /// on the next one. Returns the commands it received.
+    async fn serve_dropping_once() -> (Url, tokio::task::JoinHandle<Vec<Value>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let mut commands = Vec::new();
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let subscribe = receive_request(&mut websocket).await;
+            respond(&mut websocket, &subscribe).await;
+            let server_info = receive_request(&mut websocket).await;
+            commands.extend([subscribe["command"].clone(), server_info["command"].clone()]);
+            drop(websocket);
+
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            for _ in 0..2 {
+                let request = receive_request(&mut websocket).await;
+                respond(&mut websocket, &request).await;
+                if request["command"] == "subscribe" {
+                    assert_eq!(request["streams"], serde_json::json!(["ledger"]));
                 }
-                Some(Err(error)) => return Err(error.into()),
-                None => continue,
+                commands.push(request["command"].clone());
+            }
+
+            commands
+        });
+
+        (Url::parse(&format!("ws://{address}")).unwrap(), server)
+    }
+
+    #[tokio::test]
+    async fn test_reconnects_and_resubscribes_after_drop() {
+        let (uri, server) = serve_dropping_once().await;
+        let client = AsyncWebSocketClient::<SingleExecutorMutex, _>::open(uri)
+            .await
+            .unwrap()
+            .with_reconnect_policy(ReconnectPolicy::new(
+                3,
+                Duration::from_millis(10),
+                Duration::from_millis(100),
+                Ratio::from_basis_points(0),
+            ));
+        let connection_state = client.connection_state();
+
+        let subscribe = Subscribe::new(
+            None,
+            None,
+            None,
+            None,
+            Some(vec![StreamParameter::Ledger]),
+            None,
+            None,
+            None,
+        );
+        client.request(subscribe.into()).await.unwrap();
+
+        // The server drops the connection instead of answering.
+        let error = client
+            .request(ServerInfo::new(None).into())
+            .await
+            .unwrap_err();
+        assert!(error.is_retriable(), "{error:?}");
+        assert_eq!(*connection_state.borrow(), ConnectionState::Dropped);
+
+        client.request(ServerInfo::new(None).into()).await.unwrap();
+        assert_eq!(*connection_state.borrow(), ConnectionState::Connected);
+        assert_eq!(
+            server.await.unwrap(),
+            ["subscribe", "server_info", "subscribe", "server_info"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_timed_out_request_is_cancelled() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            // Answer the first request only once the client gave up on it.
+            let late = receive_request(&mut websocket).await;
+            let next = receive_request(&mut websocket).await;
+            respond(&mut websocket, &late).await;
+            respond(&mut websocket, &next).await;
+        });
+        let client = AsyncWebSocketClient::<SingleExecutorMutex, _>::open(
+            Url::parse(&format!("ws://{address}")).unwrap(),
+        )
+        .await
+        .unwrap()
+        .with_timeout(Some(Duration::from_millis(50)));
+
+        let error = client
+            .request(ServerInfo::new(None).into())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, XRPLClientException::RequestTimeout(_)));
+        assert!(!client.websocket_base.lock().await.has_pending_requests());
+
+        // The late response is dropped, not mistaken for this one's.
+        let response = client
+            .request(ServerInfo::new(Some("next".into())).into())
+            .await
+            .unwrap();
+        assert_eq!(response.id.as_deref(), Some("next"));
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rejected_upgrade_surfaces_status_and_body() {
+        let mut uri = serve_forbidden_html().await;
+        uri.set_scheme("ws").unwrap();
+        let error = match AsyncWebSocketClient::<SingleExecutorMutex, _>::open(uri).await {
+            Ok(_) => panic!("expected the upgrade to be rejected"),
+            Err(error) => error,
+        };
+
+        match &error {
+            XRPLClientException::XRPLWebSocketError(XRPLWebSocketException::UpgradeRejected {
+                status,
+                content_type,
+                body_preview,
+            }) => {
+                assert_eq!(*status, 403);
+                assert_eq!(content_type.as_deref(), Some("text/html"));
+                assert_eq!(body_preview, FORBIDDEN_HTML);
             }
+            error => panic!("expected UpgradeRejected, got {error:?}"),
         }
+        assert!(!error.to_string().contains("serde"));
     }
 }
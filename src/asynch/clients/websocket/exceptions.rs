@@ -20,13 +20,39 @@ pub enum XRPLWebSocketException {
     Utf8(Utf8Error),
     #[error("Invalid HTTP header")]
     HttpHeader,
+    #[error("WebSocket upgrade rejected with HTTP status {status} (content type: {content_type:?}): {body_preview}")]
+    UpgradeRejected {
+        status: u16,
+        content_type: Option<String>,
+        body_preview: String,
+    },
     #[cfg(all(feature = "websocket", not(feature = "std")))]
     #[error("Websocket error: {0:?}")]
     WebSocket(embedded_websocket_embedded_io::Error),
+    #[error("Invalid websocket URL {url:?}: {error}")]
+    InvalidUrl { url: String, error: String },
+    #[error("Unsupported websocket URL scheme {0:?}, expected ws or wss")]
+    UnsupportedScheme(String),
+    #[error("Failed to resolve {host:?}: {error}")]
+    Dns { host: String, error: String },
+    #[error("Failed to connect: {0}")]
+    Connect(String),
+    #[error("TLS error: {0}")]
+    Tls(String),
     #[error("Disconnected")]
     Disconnected,
-    #[error("Read buffer is too small (size: {0:?})")]
+    /// The connection dropped while a request was in flight. The
+    /// client reconnects on its next request, so it can be retried.
+    #[error("Connection lost, the request can be retried")]
+    ConnectionLost,
+    #[error("Failed to reconnect after {attempts} attempts")]
+    ReconnectFailed { attempts: u32 },
+    #[error("Read buffer is too small, {0} bytes are needed")]
     RxBufferTooSmall(usize),
+    #[error("Message of {size} bytes exceeds the maximum of {max_size} bytes")]
+    MessageTooLarge { size: usize, max_size: usize },
+    #[error("Invalid websocket frame: {0}")]
+    InvalidFrame(String),
     #[error("Unexpected message type")]
     UnexpectedMessageType,
     #[cfg(all(feature = "websocket", not(feature = "std")))]
@@ -42,9 +68,24 @@ pub enum XRPLWebSocketException {
     MessageChannelError(String),
     #[error("Failed to receive message through channel: {0:?}")]
     Canceled(#[from] Canceled),
+    /// Boxed, as it is larger than every other error.
     #[cfg(feature = "std")]
     #[error("Tungstenite error: {0:?}")]
-    TungsteniteError(#[from] tokio_tungstenite::tungstenite::Error),
+    TungsteniteError(alloc::boxed::Box<tokio_tungstenite::tungstenite::Error>),
+}
+
+#[cfg(feature = "std")]
+impl From<tokio_tungstenite::tungstenite::Error> for XRPLWebSocketException {
+    fn from(error: tokio_tungstenite::tungstenite::Error) -> Self {
+        XRPLWebSocketException::TungsteniteError(alloc::boxed::Box::new(error))
+    }
+}
+
+impl XRPLWebSocketException {
+    /// Whether the request that failed can be sent again as is.
+    pub fn is_retriable(&self) -> bool {
+        matches!(self, XRPLWebSocketException::ConnectionLost)
+    }
 }
 
 #[cfg(all(feature = "websocket", not(feature = "std")))]
@@ -57,6 +98,13 @@ impl<E: Debug> From<FramerError<E>> for XRPLWebSocketException {
             FramerError::FrameTooLarge(e) => XRPLWebSocketException::FrameTooLarge(e),
             FramerError::Utf8(e) => XRPLWebSocketException::Utf8(e),
             FramerError::HttpHeader(_) => XRPLWebSocketException::HttpHeader,
+            FramerError::WebSocket(
+                embedded_websocket_embedded_io::Error::HttpResponseCodeInvalid(Some(status)),
+            ) => XRPLWebSocketException::UpgradeRejected {
+                status,
+                content_type: None,
+                body_preview: String::new(),
+            },
             FramerError::WebSocket(e) => XRPLWebSocketException::WebSocket(e),
             FramerError::Disconnected => XRPLWebSocketException::Disconnected,
             FramerError::RxBufferTooSmall(e) => XRPLWebSocketException::RxBufferTooSmall(e),
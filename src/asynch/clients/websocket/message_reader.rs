@@ -0,0 +1,382 @@
+use alloc::{format, string::String, vec::Vec};
+use embedded_io_async::Read;
+
+use super::exceptions::XRPLWebSocketException;
+
+/// How much is read from the connection at once. The receive buffer
+/// grows by this much until it fits the frame being received.
+const READ_CHUNK_SIZE: usize = 4096;
+/// The largest message read by default, e.g. a ledger with its
+/// transactions or a page of `account_tx`.
+pub(crate) const DEFAULT_MAX_MESSAGE_SIZE: usize = 4 * 1024 * 1024;
+
+const OP_CONTINUATION: u8 = 0x0;
+const OP_TEXT: u8 = 0x1;
+const OP_BINARY: u8 = 0x2;
+const OP_CLOSE: u8 = 0x8;
+const OP_PING: u8 = 0x9;
+const OP_PONG: u8 = 0xA;
+
+/// A message of the server, put back together from its frames.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Message {
+    Text(String),
+    Binary(Vec<u8>),
+    /// To be answered with a pong with the same payload.
+    Ping(Vec<u8>),
+    Pong(Vec<u8>),
+    /// The close code and reason, to be echoed back.
+    Close(Vec<u8>),
+}
+
+/// Reads the messages of the server from a websocket connection,
+/// reassembling fragmented messages.
+///
+/// The receive buffer grows with the frame being received, so a
+/// message only has to fit the maximum message size, not a buffer
+/// given up front. Control frames may arrive between the fragments of a
+/// message, as the websocket protocol allows.
+pub(crate) struct MessageReader {
+    /// Bytes read from the connection, not decoded yet.
+    buffer: Vec<u8>,
+    /// The fragments of the message being received so far.
+    fragments: Vec<u8>,
+    /// The opcode of the message being received, while its last
+    /// fragment is missing.
+    fragmented: Option<u8>,
+}
+
+/// The header of a frame.
+struct FrameHeader {
+    fin: bool,
+    opcode: u8,
+    /// The length of the header itself.
+    header_len: usize,
+    payload_len: usize,
+}
+
+impl MessageReader {
+    pub(crate) fn new() -> Self {
+        Self {
+            buffer: Vec::new(),
+            fragments: Vec::new(),
+            fragmented: None,
+        }
+    }
+
+    /// Read the next whole message or control frame from `stream`,
+    /// failing if the message is larger than `max_message_size`.
+    pub(crate) async fn read<S: Read>(
+        &mut self,
+        stream: &mut S,
+        max_message_size: usize,
+    ) -> Result<Message, XRPLWebSocketException> {
+        loop {
+            if let Some(message) = self.decode(max_message_size)? {
+                return Ok(message);
+            }
+            let len = self.buffer.len();
+            self.buffer.resize(len + READ_CHUNK_SIZE, 0);
+            let read = stream
+                .read(&mut self.buffer[len..])
+                .await
+                .map_err(|error| XRPLWebSocketException::Io(format!("{:?}", error)))?;
+            self.buffer.truncate(len + read);
+            if read == 0 {
+                return Err(XRPLWebSocketException::Disconnected);
+            }
+        }
+    }
+
+    /// Decode the frames in the buffer until a message is complete.
+    /// `None` if more bytes are needed.
+    fn decode(
+        &mut self,
+        max_message_size: usize,
+    ) -> Result<Option<Message>, XRPLWebSocketException> {
+        loop {
+            let Some(header) = parse_header(&self.buffer)? else {
+                return Ok(None);
+            };
+            let is_control = header.opcode & 0x8 != 0;
+            let message_size = if is_control {
+                header.payload_len
+            } else {
+                self.fragments.len().saturating_add(header.payload_len)
+            };
+            // Checked before the payload is buffered, so a frame too
+            // large is refused before it fills the memory.
+            if message_size > max_message_size {
+                return Err(XRPLWebSocketException::MessageTooLarge {
+                    size: message_size,
+                    max_size: max_message_size,
+                });
+            }
+            let frame_len = header.header_len + header.payload_len;
+            if self.buffer.len() < frame_len {
+                return Ok(None);
+            }
+            let payload = self.buffer[header.header_len..frame_len].to_vec();
+            self.buffer.drain(..frame_len);
+
+            match header.opcode {
+                OP_PING => return Ok(Some(Message::Ping(payload))),
+                OP_PONG => return Ok(Some(Message::Pong(payload))),
+                OP_CLOSE => return Ok(Some(Message::Close(payload))),
+                OP_TEXT | OP_BINARY if self.fragmented.is_none() => {
+                    self.fragmented = Some(header.opcode);
+                    self.fragments = payload;
+                }
+                OP_CONTINUATION if self.fragmented.is_some() => {
+                    self.fragments.extend_from_slice(&payload);
+                }
+                OP_TEXT | OP_BINARY => {
+                    return Err(invalid_frame("new message before the last fragment"))
+                }
+                OP_CONTINUATION => return Err(invalid_frame("continuation of no message")),
+                _ => return Err(invalid_frame("unknown opcode")),
+            }
+            if header.fin {
+                let message = core::mem::take(&mut self.fragments);
+                return match self.fragmented.take() {
+                    Some(OP_TEXT) => String::from_utf8(message)
+                        .map(|text| Some(Message::Text(text)))
+                        .map_err(|error| XRPLWebSocketException::Utf8(error.utf8_error())),
+                    _ => Ok(Some(Message::Binary(message))),
+                };
+            }
+        }
+    }
+}
+
+/// The header of the frame at the start of `buffer`, `None` if it is
+/// not all there yet.
+fn parse_header(buffer: &[u8]) -> Result<Option<FrameHeader>, XRPLWebSocketException> {
+    let [first, second, ..] = *buffer else {
+        return Ok(None);
+    };
+    if first & 0x70 != 0 {
+        return Err(invalid_frame("reserved bits set without an extension"));
+    }
+    // Only frames of the client are masked.
+    if second & 0x80 != 0 {
+        return Err(invalid_frame("masked frame from the server"));
+    }
+    let fin = first & 0x80 != 0;
+    let opcode = first & 0x0F;
+    let (header_len, payload_len) = match second & 0x7F {
+        126 => {
+            let Some(len) = buffer.get(2..4) else {
+                return Ok(None);
+            };
+            (4, u16::from_be_bytes([len[0], len[1]]) as usize)
+        }
+        127 => {
+            let Some(len) = buffer.get(2..10) else {
+                return Ok(None);
+            };
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(len);
+            (
+                10,
+                usize::try_from(u64::from_be_bytes(bytes)).unwrap_or(usize::MAX),
+            )
+        }
+        len => (2, len as usize),
+    };
+    if opcode & 0x8 != 0 && (!fin || payload_len > 125) {
+        return Err(invalid_frame("fragmented or oversized control frame"));
+    }
+
+    Ok(Some(FrameHeader {
+        fin,
+        opcode,
+        header_len,
+        payload_len,
+    }))
+}
+
+fn invalid_frame(reason: &str) -> XRPLWebSocketException {
+    XRPLWebSocketException::InvalidFrame(reason.into())
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{collections::VecDeque, vec};
+    use core::convert::Infallible;
+
+    use embedded_io_async::ErrorType;
+
+    use super::*;
+
+    /// A connection delivering `data` at most `chunk_size` bytes per
+    /// read, like a TCP stream.
+    struct MockStream {
+        data: VecDeque<u8>,
+        chunk_size: usize,
+    }
+
+    impl ErrorType for MockStream {
+        type Error = Infallible;
+    }
+
+    impl Read for MockStream {
+        async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            let len = buf.len().min(self.chunk_size).min(self.data.len());
+            for (byte, data) in buf.iter_mut().zip(self.data.drain(..len)) {
+                *byte = data;
+            }
+
+            Ok(len)
+        }
+    }
+
+    fn frame(fin: bool, opcode: u8, payload: &[u8]) -> Vec<u8> {
+        let mut frame = vec![(fin as u8) << 7 | opcode];
+        match payload.len() {
+            len @ 0..=125 => frame.push(len as u8),
+            len @ 126..=0xFFFF => {
+                frame.push(126);
+                frame.extend_from_slice(&(len as u16).to_be_bytes());
+            }
+            len => {
+                frame.push(127);
+                frame.extend_from_slice(&(len as u64).to_be_bytes());
+            }
+        }
+        frame.extend_from_slice(payload);
+        frame
+    }
+
+    fn mock_stream(frames: &[Vec<u8>], chunk_size: usize) -> MockStream {
+        MockStream {
+            data: frames.concat().into(),
+            chunk_size,
+        }
+    }
+
+    /// A response of about 300 KB, like a page of `account_tx`.
+    fn large_message() -> String {
+        let transactions =
+            "{\"hash\":\"E08D6E9754025BA2534A78707605E0601F03ACE063687A0CA1BDDACFCD1698C7\"},"
+                .repeat(4200);
+
+        format!(
+            "{{\"id\":\"1\",\"result\":{{\"transactions\":[{}]}},\"status\":\"success\"}}",
+            transactions.trim_end_matches(',')
+        )
+    }
+
+    #[tokio::test]
+    async fn test_read_fragmented_message() {
+        let message = large_message();
+        assert!(message.len() > 300_000);
+        let (first, rest) = message.as_bytes().split_at(100_000);
+        let (second, third) = rest.split_at(150_000);
+        let mut stream = mock_stream(
+            &[
+                frame(true, OP_TEXT, b"{\"type\":\"ledgerClosed\"}"),
+                frame(false, OP_TEXT, first),
+                frame(false, OP_CONTINUATION, second),
+                // Control frames may come between fragments.
+                frame(true, OP_PING, b"ping"),
+                frame(true, OP_CONTINUATION, third),
+                frame(true, OP_BINARY, &[1, 2, 3]),
+            ],
+            1500,
+        );
+        let mut reader = MessageReader::new();
+
+        assert_eq!(
+            reader
+                .read(&mut stream, DEFAULT_MAX_MESSAGE_SIZE)
+                .await
+                .unwrap(),
+            Message::Text("{\"type\":\"ledgerClosed\"}".into())
+        );
+        assert_eq!(
+            reader
+                .read(&mut stream, DEFAULT_MAX_MESSAGE_SIZE)
+                .await
+                .unwrap(),
+            Message::Ping(b"ping".to_vec())
+        );
+        assert_eq!(
+            reader
+                .read(&mut stream, DEFAULT_MAX_MESSAGE_SIZE)
+                .await
+                .unwrap(),
+            Message::Text(message)
+        );
+        assert_eq!(
+            reader
+                .read(&mut stream, DEFAULT_MAX_MESSAGE_SIZE)
+                .await
+                .unwrap(),
+            Message::Binary(vec![1, 2, 3])
+        );
+        assert!(matches!(
+            reader.read(&mut stream, DEFAULT_MAX_MESSAGE_SIZE).await,
+            Err(XRPLWebSocketException::Disconnected)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_read_message_too_large() {
+        let message = large_message();
+        let (first, second) = message.as_bytes().split_at(200_000);
+        let mut stream = mock_stream(
+            &[
+                frame(false, OP_TEXT, first),
+                frame(true, OP_CONTINUATION, second),
+            ],
+            READ_CHUNK_SIZE,
+        );
+        let mut reader = MessageReader::new();
+
+        let error = reader.read(&mut stream, 256 * 1024).await.unwrap_err();
+        let XRPLWebSocketException::MessageTooLarge { size, max_size } = error else {
+            panic!("unexpected error: {error:?}");
+        };
+        assert_eq!(size, message.len());
+        assert_eq!(max_size, 256 * 1024);
+        // Only the frames of the message so far were buffered.
+        assert!(reader.buffer.len() < READ_CHUNK_SIZE + 16);
+
+        let mut stream = mock_stream(&[frame(true, OP_TEXT, message.as_bytes())], READ_CHUNK_SIZE);
+        let mut reader = MessageReader::new();
+        // A message of exactly the maximum size is read.
+        assert_eq!(
+            reader.read(&mut stream, message.len()).await.unwrap(),
+            Message::Text(message)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_invalid_frames() {
+        let mut reader = MessageReader::new();
+        let mut continuation = mock_stream(&[frame(true, OP_CONTINUATION, b"{}")], 1024);
+        assert!(matches!(
+            reader
+                .read(&mut continuation, DEFAULT_MAX_MESSAGE_SIZE)
+                .await,
+            Err(XRPLWebSocketException::InvalidFrame(_))
+        ));
+
+        let mut reader = MessageReader::new();
+        let mut masked = mock_stream(&[vec![0x81, 0x82, 0, 0, 0, 0, b'{', b'}']], 1024);
+        assert!(matches!(
+            reader.read(&mut masked, DEFAULT_MAX_MESSAGE_SIZE).await,
+            Err(XRPLWebSocketException::InvalidFrame(_))
+        ));
+
+        let mut reader = MessageReader::new();
+        let mut invalid_utf8 = mock_stream(&[frame(true, OP_TEXT, &[0xFF, 0xFE])], 1024);
+        assert!(matches!(
+            reader
+                .read(&mut invalid_utf8, DEFAULT_MAX_MESSAGE_SIZE)
+                .await,
+            Err(XRPLWebSocketException::Utf8(_))
+        ));
+    }
+}
@@ -1,24 +1,26 @@
 use crate::models::{requests::XRPLRequest, results::XRPLResponse};
-#[cfg(feature = "std")]
-use alloc::string::String;
-#[cfg(not(feature = "std"))]
-use alloc::string::ToString;
-#[cfg(not(feature = "std"))]
-use embedded_io_async::Error;
-#[cfg(not(feature = "std"))]
-use embedded_io_async::{Read as EmbeddedIoRead, Write as EmbeddedIoWrite};
+use alloc::string::{String, ToString};
 #[cfg(feature = "std")]
 use futures::{Sink, SinkExt, Stream, StreamExt};
 
 mod websocket_base;
+#[cfg(feature = "std")]
 use websocket_base::MessageHandler;
 
 #[cfg(all(feature = "websocket", not(feature = "std")))]
 mod _no_std;
 mod exceptions;
 pub use exceptions::*;
+#[cfg(any(not(feature = "std"), test))]
+mod message_reader;
+mod websocket_url;
+pub use websocket_url::WebSocketUrl;
 #[cfg(all(feature = "websocket", feature = "std"))]
 mod _std;
+#[cfg(all(feature = "websocket", feature = "std"))]
+mod reconnect;
+#[cfg(all(feature = "websocket", feature = "std"))]
+pub use reconnect::{ConnectionState, ReconnectPolicy};
 
 #[cfg(all(feature = "websocket", not(feature = "std")))]
 pub use _no_std::*;
@@ -26,6 +28,31 @@ pub use _no_std::*;
 pub use _std::*;
 
 use super::exceptions::{XRPLClientException, XRPLClientResult};
+use crate::models::requests::Request;
+use crate::XRPLSerdeJsonError;
+use serde_json::Value;
+
+/// Build the websocket envelope of a request: its parameters with the
+/// `command` and `id` next to them.
+pub(crate) fn request_to_websocket(request: &XRPLRequest<'_>) -> XRPLClientResult<String> {
+    let request_value = serde_json::to_value(request)?;
+    let mut envelope = match request_value {
+        Value::Object(params) => params,
+        found => {
+            return Err(XRPLSerdeJsonError::UnexpectedValueType {
+                expected: "Object".into(),
+                found,
+            }
+            .into())
+        }
+    };
+    envelope.insert("command".into(), Value::from(request.method_name()));
+    if let Some(id) = &request.get_common_fields().id {
+        envelope.insert("id".into(), Value::String(id.to_string()));
+    }
+
+    Ok(serde_json::to_string(&envelope)?)
+}
 
 pub struct WebSocketOpen;
 pub struct WebSocketClosed;
@@ -37,42 +64,6 @@ pub trait XRPLAsyncWebsocketIO {
     async fn xrpl_receive(&mut self) -> XRPLClientResult<Option<XRPLResponse<'_>>>;
 }
 
-#[cfg(not(feature = "std"))]
-impl<T: EmbeddedIoRead + EmbeddedIoWrite + MessageHandler> XRPLAsyncWebsocketIO for T {
-    async fn xrpl_send(&mut self, message: XRPLRequest<'_>) -> XRPLClientResult<()> {
-        let message = serde_json::to_string(&message)?;
-        let message_buffer = message.as_bytes();
-        self.write(message_buffer)
-            .await
-            .map_err(|e| XRPLWebSocketException::EmbeddedIoError(e.kind()))?;
-
-        Ok(())
-    }
-
-    async fn xrpl_receive(&mut self) -> XRPLClientResult<Option<XRPLResponse<'_>>> {
-        let mut buffer = [0; 1024];
-        loop {
-            match self.read(&mut buffer).await {
-                Ok(u_size) => {
-                    // If the buffer is empty, continue to the next iteration.
-                    if u_size == 0 {
-                        continue;
-                    }
-                    let response_str = core::str::from_utf8(&buffer[..u_size])
-                        .map_err(|e| XRPLWebSocketException::Utf8(e))?;
-                    self.handle_message(response_str.to_string()).await?;
-                    let message = self.pop_message().await;
-
-                    return Ok(serde_json::from_str(&message)?);
-                }
-                Err(error) => {
-                    return Err(XRPLWebSocketException::EmbeddedIoError(error.kind()).into())
-                }
-            }
-        }
-    }
-}
-
 #[cfg(feature = "std")]
 impl<T: ?Sized> XRPLAsyncWebsocketIO for T
 where
@@ -82,7 +73,7 @@ where
         + Unpin,
 {
     async fn xrpl_send(&mut self, message: XRPLRequest<'_>) -> XRPLClientResult<()> {
-        let message = serde_json::to_string(&message)?;
+        let message = request_to_websocket(&message)?;
 
         self.send(message).await
     }
@@ -100,3 +91,25 @@ where
         }
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::request_to_websocket;
+    use crate::asynch::clients::tests::envelope_test_requests;
+
+    #[test]
+    fn test_websocket_envelope() {
+        let envelopes =
+            envelope_test_requests().map(|request| request_to_websocket(&request).unwrap());
+
+        assert_eq!(
+            envelopes,
+            [
+                r#"{"account":"rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn","command":"account_info","id":"1","ledger_index":"validated","strict":true}"#,
+                r#"{"command":"ledger","id":"2","ledger_index":"closed","transactions":true}"#,
+                r#"{"api_version":2,"command":"server_info"}"#,
+                r#"{"command":"vault_info","id":"4","vault_id":"A3F0"}"#,
+            ]
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use alloc::{string::String, vec::Vec};
+use core::time::Duration;
+use serde_json::{Map, Value};
+
+use crate::{asynch::clients::ExponentialBackoff, models::requests::XRPLRequest, utils::Ratio};
+
+/// How a websocket client reconnects after its connection drops,
+/// after the delays of an [`ExponentialBackoff`]. Reconnecting is
+/// lazy, see [`ConnectionState::Dropped`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconnectPolicy {
+    /// How often to try reconnecting before giving up. `0` disables
+    /// reconnecting.
+    pub max_attempts: u32,
+    pub backoff: ExponentialBackoff,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            backoff: ExponentialBackoff::new(
+                Duration::from_millis(500),
+                Duration::from_secs(30),
+                Ratio::from_basis_points(2000),
+            ),
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    pub fn new(
+        max_attempts: u32,
+        base_delay: Duration,
+        max_delay: Duration,
+        jitter: Ratio,
+    ) -> Self {
+        Self {
+            max_attempts,
+            backoff: ExponentialBackoff::new(base_delay, max_delay, jitter),
+        }
+    }
+
+    /// Never reconnect; a dropped connection closes the client.
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 0,
+            ..Default::default()
+        }
+    }
+
+    /// The delay before reconnect attempt `attempt`, counting from 1.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        self.backoff.delay(attempt)
+    }
+}
+
+/// The state of a websocket client's connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    Connected,
+    /// The connection dropped. Nothing reconnects in the background:
+    /// the client reconnects on its next request, or when
+    /// `reconnect` is called.
+    Dropped,
+    /// The client is reconnecting, `attempt` counts the tries so far.
+    Reconnecting {
+        attempt: u32,
+    },
+    /// Closed by the user, or given up on after the last attempt.
+    Closed,
+}
+
+/// The subscriptions of a websocket connection, to re-issue them
+/// after reconnecting.
+#[derive(Debug, Default)]
+pub(crate) struct ActiveSubscriptions {
+    /// The parameters of each `subscribe` request still in effect.
+    subscribes: Vec<Map<String, Value>>,
+}
+
+impl ActiveSubscriptions {
+    /// Record a request the server accepted.
+    pub(crate) fn record(&mut self, request: &XRPLRequest<'_>) {
+        let Ok(Value::Object(mut params)) = serde_json::to_value(request) else {
+            return;
+        };
+        params.remove("id");
+        match request {
+            XRPLRequest::Subscribe(_) => self.subscribes.push(params),
+            XRPLRequest::Unsubscribe(_) => self.remove(&params),
+            _ => {}
+        }
+    }
+
+    fn remove(&mut self, unsubscribe: &Map<String, Value>) {
+        for subscribe in &mut self.subscribes {
+            for field in ["streams", "accounts", "accounts_proposed"] {
+                let (Some(Value::Array(subscribed)), Some(Value::Array(unsubscribed))) =
+                    (subscribe.get_mut(field), unsubscribe.get(field))
+                else {
+                    continue;
+                };
+                subscribed.retain(|item| !unsubscribed.contains(item));
+            }
+            if let (Some(Value::Array(books)), Some(Value::Array(unsubscribed))) =
+                (subscribe.get_mut("books"), unsubscribe.get("books"))
+            {
+                books.retain(|book| {
+                    !unsubscribed.iter().any(|unsubscribed| {
+                        book["taker_gets"] == unsubscribed["taker_gets"]
+                            && book["taker_pays"] == unsubscribed["taker_pays"]
+                    })
+                });
+            }
+            subscribe.retain(|_, value| !matches!(value, Value::Array(items) if items.is_empty()));
+        }
+        self.subscribes.retain(|subscribe| {
+            ["streams", "accounts", "accounts_proposed", "books"]
+                .iter()
+                .any(|field| subscribe.contains_key(*field))
+        });
+    }
+
+    /// The websocket envelopes re-issuing the subscriptions.
+    pub(crate) fn envelopes(&self) -> impl Iterator<Item = (String, Map<String, Value>)> + '_ {
+        self.subscribes.iter().enumerate().map(|(index, params)| {
+            let id = alloc::format!("resubscribe-{index}");
+            let mut envelope = params.clone();
+            envelope.insert("command".into(), "subscribe".into());
+            envelope.insert("id".into(), Value::String(id.clone()));
+
+            (id, envelope)
+        })
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.subscribes.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::requests::{
+        subscribe::{StreamParameter, Subscribe},
+        unsubscribe::Unsubscribe,
+    };
+
+    #[test]
+    fn test_delay_backs_off_exponentially() {
+        let policy = ReconnectPolicy::new(
+            10,
+            Duration::from_millis(100),
+            Duration::from_secs(1),
+            Ratio::from_basis_points(0),
+        );
+
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(3), Duration::from_millis(400));
+        assert_eq!(policy.delay(10), Duration::from_secs(1));
+
+        let jittered = ReconnectPolicy::new(
+            10,
+            Duration::from_millis(100),
+            Duration::from_secs(1),
+            Ratio::from_percent(50.0).unwrap(),
+        );
+        for _ in 0..100 {
+            let delay = jittered.delay(1);
+            assert!(delay > Duration::from_millis(50) && delay <= Duration::from_millis(100));
+        }
+
+        // Set directly, the jitter is clamped too.
+        let mut out_of_range = policy.clone();
+        for jitter in [2.0, -1.0] {
+            out_of_range.backoff.jitter = Ratio::from_fraction(jitter).unwrap();
+            assert!(out_of_range.delay(1) <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn test_unsubscribe_removes_subscriptions() {
+        let mut subscriptions = ActiveSubscriptions::default();
+        subscriptions.record(
+            &Subscribe::new(
+                Some("1".into()),
+                Some(alloc::vec!["rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn".into()]),
+                None,
+                None,
+                Some(alloc::vec![StreamParameter::Ledger]),
+                None,
+                None,
+                None,
+            )
+            .into(),
+        );
+        subscriptions.record(
+            &Unsubscribe::new(
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(alloc::vec![StreamParameter::Ledger]),
+            )
+            .into(),
+        );
+
+        let envelopes: Vec<_> = subscriptions.envelopes().collect();
+        assert_eq!(envelopes.len(), 1);
+        assert_eq!(
+            Value::Object(envelopes[0].1.clone()),
+            serde_json::json!({
+                "accounts": ["rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn"],
+                "command": "subscribe",
+                "id": "resubscribe-0"
+            })
+        );
+
+        subscriptions.record(
+            &Unsubscribe::new(
+                None,
+                Some(alloc::vec!["rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn".into()]),
+                None,
+                None,
+                None,
+                None,
+            )
+            .into(),
+        );
+        assert!(subscriptions.is_empty());
+    }
+}
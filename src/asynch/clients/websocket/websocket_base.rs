@@ -1,4 +1,8 @@
-use alloc::string::{String, ToString};
+use alloc::{
+    collections::VecDeque,
+    string::{String, ToString},
+};
+use core::ops::{Deref, DerefMut};
 use embassy_sync::{blocking_mutex::raw::RawMutex, channel::Channel};
 use futures::channel::oneshot::{self, Receiver, Sender};
 use hashbrown::HashMap;
@@ -9,6 +13,10 @@ use crate::asynch::clients::exceptions::XRPLClientResult;
 use super::exceptions::XRPLWebSocketException;
 
 const _MAX_CHANNEL_MSG_CNT: usize = 10;
+/// How many cancelled requests are remembered. The server may never
+/// answer some of them, so the oldest are forgotten; a late response
+/// to one of those is queued as a message.
+const MAX_CANCELLED_REQUESTS: usize = 64;
 
 /// A struct that handles futures of websocket messages.
 pub struct WebsocketBase<M>
@@ -18,6 +26,10 @@ where
     /// The messages the user requests, which means he is waiting for a specific `id`.
     pending_requests: HashMap<String, Receiver<String>>,
     request_senders: HashMap<String, Sender<String>>,
+    /// The requests given up on before their response arrived, oldest
+    /// first. Their responses are dropped instead of queued as
+    /// messages.
+    cancelled: VecDeque<String>,
     /// The messages the user waits for when sending and receiving normally.
     messages: Channel<M, String, _MAX_CHANNEL_MSG_CNT>,
 }
@@ -30,6 +42,7 @@ where
         Self {
             pending_requests: HashMap::new(),
             request_senders: HashMap::new(),
+            cancelled: VecDeque::new(),
             messages: Channel::new(),
         }
     }
@@ -37,8 +50,76 @@ where
     pub fn close(&mut self) {
         self.pending_requests.clear();
         self.request_senders.clear();
+        self.cancelled.clear();
         self.messages.clear();
     }
+
+    /// Stop waiting for the response to a request.
+    pub fn cancel_request(&mut self, id: &str) {
+        self.pending_requests.remove(id);
+        if self.request_senders.remove(id).is_some() {
+            if self.cancelled.len() == MAX_CANCELLED_REQUESTS {
+                self.cancelled.pop_front();
+            }
+            self.cancelled.push_back(id.to_string());
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn has_pending_requests(&self) -> bool {
+        !self.pending_requests.is_empty() || !self.request_senders.is_empty()
+    }
+}
+
+/// A request waiting for its response. Dropping it before the
+/// response arrived, e.g. because the request timed out, cancels
+/// the request.
+pub(crate) struct PendingRequest<'a, M>
+where
+    M: RawMutex,
+{
+    websocket_base: &'a mut WebsocketBase<M>,
+    id: &'a str,
+}
+
+impl<'a, M> PendingRequest<'a, M>
+where
+    M: RawMutex,
+{
+    pub(crate) async fn new(websocket_base: &'a mut WebsocketBase<M>, id: &'a str) -> Self {
+        websocket_base.setup_request_future(id.to_string()).await;
+
+        Self { websocket_base, id }
+    }
+}
+
+impl<M> Deref for PendingRequest<'_, M>
+where
+    M: RawMutex,
+{
+    type Target = WebsocketBase<M>;
+
+    fn deref(&self) -> &Self::Target {
+        self.websocket_base
+    }
+}
+
+impl<M> DerefMut for PendingRequest<'_, M>
+where
+    M: RawMutex,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.websocket_base
+    }
+}
+
+impl<M> Drop for PendingRequest<'_, M>
+where
+    M: RawMutex,
+{
+    fn drop(&mut self) {
+        self.websocket_base.cancel_request(self.id);
+    }
 }
 
 #[allow(async_fn_in_trait)]
@@ -72,6 +153,10 @@ where
             },
             None => String::new(),
         };
+        if let Some(index) = self.cancelled.iter().position(|cancelled| *cancelled == id) {
+            self.cancelled.remove(index);
+            return Ok(());
+        }
         if let Some(_receiver) = self.pending_requests.get(&id) {
             let sender = match self.request_senders.remove(&id) {
                 Some(sender) => sender,
@@ -79,7 +164,7 @@ where
             };
             sender
                 .send(message)
-                .map_err(|e| XRPLWebSocketException::MessageChannelError(e))?;
+                .map_err(XRPLWebSocketException::MessageChannelError)?;
         } else {
             self.messages.send(message).await;
         }
@@ -106,3 +191,34 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use alloc::format;
+    use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_cancelled_requests_are_capped() {
+        let mut websocket_base = WebsocketBase::<NoopRawMutex>::new();
+        for index in 0..=MAX_CANCELLED_REQUESTS {
+            let id = format!("{index}");
+            websocket_base.setup_request_future(id.clone()).await;
+            websocket_base.cancel_request(&id);
+        }
+        assert_eq!(websocket_base.cancelled.len(), MAX_CANCELLED_REQUESTS);
+
+        // The response to the latest cancelled request is dropped, the
+        // one to the forgotten oldest is queued.
+        let last = MAX_CANCELLED_REQUESTS.to_string();
+        for id in [last.as_str(), "0"] {
+            websocket_base
+                .handle_message(format!(r#"{{"id":"{id}"}}"#))
+                .await
+                .unwrap();
+        }
+        assert_eq!(websocket_base.pop_message().await, r#"{"id":"0"}"#);
+        assert_eq!(websocket_base.cancelled.len(), MAX_CANCELLED_REQUESTS - 1);
+    }
+}
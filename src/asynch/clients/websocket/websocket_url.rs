@@ -0,0 +1,177 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::convert::TryFrom;
+use url::{Host, Url};
+
+use super::exceptions::XRPLWebSocketException;
+
+/// A `ws://` or `wss://` URL, with what the websocket handshake needs
+/// from it.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::asynch::clients::WebSocketUrl;
+///
+/// let url = WebSocketUrl::parse("wss://s.altnet.rippletest.net:51233").unwrap();
+/// assert!(url.is_secure());
+/// assert_eq!(url.port(), 51233);
+/// assert_eq!(url.host_header(), "s.altnet.rippletest.net:51233");
+/// assert_eq!(url.origin(), "https://s.altnet.rippletest.net:51233");
+///
+/// assert!(WebSocketUrl::parse("https://s.altnet.rippletest.net").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebSocketUrl {
+    url: Url,
+    secure: bool,
+    port: u16,
+}
+
+impl WebSocketUrl {
+    pub fn parse(url: &str) -> Result<Self, XRPLWebSocketException> {
+        let url = Url::parse(url).map_err(|error| XRPLWebSocketException::InvalidUrl {
+            url: url.to_string(),
+            error: error.to_string(),
+        })?;
+
+        Self::try_from(url)
+    }
+
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Whether the connection goes through TLS, i.e. the scheme is
+    /// `wss`.
+    pub fn is_secure(&self) -> bool {
+        self.secure
+    }
+
+    pub fn host(&self) -> Host<&str> {
+        // A `ws` or `wss` URL always has a host.
+        self.url.host().unwrap_or(Host::Domain(""))
+    }
+
+    /// The host, as a domain name or an IP address without the
+    /// brackets of an IPv6 address.
+    pub fn host_name(&self) -> String {
+        match self.host() {
+            Host::Domain(domain) => domain.to_string(),
+            Host::Ipv4(address) => address.to_string(),
+            Host::Ipv6(address) => address.to_string(),
+        }
+    }
+
+    /// The port, or the default port of the scheme.
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// The path and query to request.
+    pub fn resource(&self) -> &str {
+        &self.url[url::Position::BeforePath..url::Position::AfterQuery]
+    }
+
+    /// The `Host` header of the upgrade request, with the port only if
+    /// it is not the default one.
+    pub fn host_header(&self) -> String {
+        let host = self.url.host_str().unwrap_or_default();
+        match self.url.port() {
+            Some(port) => format!("{}:{}", host, port),
+            None => host.to_string(),
+        }
+    }
+
+    /// The `Origin` header of the upgrade request.
+    pub fn origin(&self) -> String {
+        let scheme = if self.secure { "https" } else { "http" };
+
+        format!("{}://{}", scheme, self.host_header())
+    }
+}
+
+impl TryFrom<Url> for WebSocketUrl {
+    type Error = XRPLWebSocketException;
+
+    fn try_from(url: Url) -> Result<Self, Self::Error> {
+        let secure = match url.scheme() {
+            "wss" => true,
+            "ws" => false,
+            scheme => {
+                return Err(XRPLWebSocketException::UnsupportedScheme(
+                    scheme.to_string(),
+                ))
+            }
+        };
+        if url.host_str().is_none_or(str::is_empty) {
+            return Err(XRPLWebSocketException::InvalidUrl {
+                url: url.to_string(),
+                error: "missing host".to_string(),
+            });
+        }
+        let port = url
+            .port_or_known_default()
+            .unwrap_or(if secure { 443 } else { 80 });
+
+        Ok(Self { url, secure, port })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_default_ports() {
+        let url = WebSocketUrl::parse("wss://xrplcluster.com").unwrap();
+        assert!(url.is_secure());
+        assert_eq!(url.port(), 443);
+        assert_eq!(url.host_name(), "xrplcluster.com");
+        assert_eq!(url.resource(), "/");
+        assert_eq!(url.host_header(), "xrplcluster.com");
+        assert_eq!(url.origin(), "https://xrplcluster.com");
+
+        // An explicit default port is dropped by the URL parser.
+        let url = WebSocketUrl::parse("ws://localhost:80").unwrap();
+        assert!(!url.is_secure());
+        assert_eq!(url.port(), 80);
+        assert_eq!(url.host_header(), "localhost");
+        assert_eq!(url.origin(), "http://localhost");
+    }
+
+    #[test]
+    fn test_parse_port_and_resource() {
+        let url = WebSocketUrl::parse("ws://127.0.0.1:6006/ws/path?key=value").unwrap();
+        assert_eq!(url.port(), 6006);
+        assert_eq!(url.host(), Host::<&str>::Ipv4([127, 0, 0, 1].into()));
+        assert_eq!(url.resource(), "/ws/path?key=value");
+        assert_eq!(url.host_header(), "127.0.0.1:6006");
+        // The origin has no path.
+        assert_eq!(url.origin(), "http://127.0.0.1:6006");
+
+        let url = WebSocketUrl::parse("wss://[::1]:51233").unwrap();
+        assert_eq!(url.host_name(), "::1");
+        assert_eq!(url.host_header(), "[::1]:51233");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(matches!(
+            WebSocketUrl::parse("https://xrplcluster.com"),
+            Err(XRPLWebSocketException::UnsupportedScheme(scheme)) if scheme == "https"
+        ));
+        assert!(matches!(
+            WebSocketUrl::parse("xrplcluster.com"),
+            Err(XRPLWebSocketException::InvalidUrl { .. })
+        ));
+        assert!(matches!(
+            WebSocketUrl::parse("wss://"),
+            Err(XRPLWebSocketException::InvalidUrl { .. })
+        ));
+    }
+}
@@ -0,0 +1,717 @@
+use core::{cell::RefCell, cmp::max, convert::TryInto, time::Duration};
+
+#[cfg(feature = "websocket")]
+use alloc::vec;
+use alloc::{collections::VecDeque, string::ToString, vec::Vec};
+use serde_json::Value;
+
+#[cfg(feature = "websocket")]
+use crate::{
+    asynch::clients::{
+        exceptions::XRPLClientException, XRPLAsyncWebsocketIO, XRPLWebSocketException,
+    },
+    models::requests::subscribe::{StreamParameter, Subscribe},
+};
+use crate::{
+    asynch::{
+        clients::XRPLAsyncClient,
+        exceptions::{XRPLError, XRPLHelperResult},
+        wait, with_timeout,
+    },
+    models::{requests::fee::Fee, results::fee::Fee as FeeResult, XRPAmount},
+};
+
+use super::FeeStrategy;
+
+/// How often [`FeeMonitor::wait_until_fee_below`] asks for the fees.
+/// They change at most once per ledger, every 3 to 5 seconds.
+const FEE_POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+/// How urgently a transaction should make it into a ledger, see
+/// [`FeeMonitor::current_recommended_fee`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeePriority {
+    /// The minimum fee. The transaction may wait in the queue for a
+    /// few ledgers while the open ledger is full.
+    Low,
+    /// The open ledger fee, to make it into the current open ledger.
+    Medium,
+    /// The highest open ledger fee of the monitored window, to make it
+    /// into a ledger even if the fee keeps escalating.
+    High,
+}
+
+/// The fees of the network at one point, in drops.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FeeSnapshot {
+    /// The open ledger the fees apply to, if known.
+    pub ledger_index: Option<u32>,
+    /// The reference cost of a transaction, without load or
+    /// escalation.
+    pub base_fee: u64,
+    /// The cost of a transaction to be queued, or to be applied while
+    /// the open ledger is not full.
+    pub minimum_fee: u64,
+    /// The cost of a transaction to be applied to the open ledger
+    /// right away.
+    pub open_ledger_fee: u64,
+    /// How many times the base fee the server's load makes the fee.
+    /// Only the server stream reports it, not `fee`.
+    pub load_factor: Option<f64>,
+}
+
+impl FeeSnapshot {
+    /// How many times the base fee the open ledger fee is, 1 while
+    /// the open ledger is not full and the server not loaded.
+    pub fn escalation(&self) -> f64 {
+        self.open_ledger_fee as f64 / max(self.base_fee, 1) as f64
+    }
+
+    fn from_base_fee(base_fee: u64) -> Self {
+        Self {
+            ledger_index: None,
+            base_fee,
+            minimum_fee: base_fee,
+            open_ledger_fee: base_fee,
+            load_factor: None,
+        }
+    }
+
+    fn from_fee_result(fee: FeeResult<'_>) -> XRPLHelperResult<Self> {
+        Ok(Self {
+            ledger_index: fee.ledger_current_index,
+            base_fee: fee.drops.base_fee.try_into()?,
+            minimum_fee: fee.drops.minimum_fee.try_into()?,
+            open_ledger_fee: fee.drops.open_ledger_fee.try_into()?,
+            load_factor: None,
+        })
+    }
+
+    /// The snapshot once the base fee changed to `base_fee`, e.g. by a
+    /// fee vote, with the same load and escalation.
+    fn with_base_fee(self, base_fee: u64) -> Self {
+        let rebase = |fee: u64| scale(base_fee, fee, max(self.base_fee, 1));
+
+        Self {
+            minimum_fee: rebase(self.minimum_fee),
+            open_ledger_fee: rebase(self.open_ledger_fee),
+            base_fee,
+            ..self
+        }
+    }
+}
+
+/// Watches the fees of the network, to adapt how much, and when,
+/// transactions pay.
+///
+/// The monitor keeps the last fee updates it got, either by polling
+/// `fee` with [`FeeMonitor::update`], or from the `server` and
+/// `ledger` streams of a WebSocket connection with
+/// [`FeeMonitor::update_streamed`] or
+/// [`FeeMonitor::update_from_stream`]. Plug it into
+/// [`autofill_with_fee_strategy`](crate::asynch::transaction::autofill_with_fee_strategy)
+/// with [`FeeMonitor::fee_strategy`].
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```no_run
+/// use core::time::Duration;
+///
+/// use xrpl::asynch::clients::{AsyncJsonRpcClient, Network};
+/// use xrpl::asynch::ledger::{FeeMonitor, FeePriority};
+/// use xrpl::asynch::transaction::autofill_with_fee_strategy;
+/// use xrpl::models::transactions::account_set::AccountSet;
+/// use xrpl::models::XRPAmount;
+///
+/// # #[cfg(feature = "std")]
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let client = AsyncJsonRpcClient::connect_network(Network::testnet());
+/// let monitor = FeeMonitor::new(10);
+/// // Wait out a fee spike, then pay whatever makes it into the next
+/// // ledger.
+/// monitor
+///     .wait_until_fee_below(&client, XRPAmount::from("1000"), Duration::from_secs(60))
+///     .await
+///     .unwrap();
+/// let mut account_set: AccountSet = serde_json::from_value(serde_json::json!({
+///     "TransactionType": "AccountSet",
+///     "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"
+/// }))
+/// .unwrap();
+/// autofill_with_fee_strategy(
+///     &mut account_set,
+///     &client,
+///     None,
+///     &monitor.fee_strategy(FeePriority::High),
+/// )
+/// .await
+/// .unwrap();
+/// # });
+/// ```
+pub struct FeeMonitor {
+    window: usize,
+    /// Oldest first.
+    snapshots: RefCell<VecDeque<FeeSnapshot>>,
+}
+
+impl FeeMonitor {
+    /// A monitor keeping the last `window` fee updates, at least one.
+    pub fn new(window: usize) -> Self {
+        let window = max(window, 1);
+
+        Self {
+            window,
+            snapshots: RefCell::new(VecDeque::with_capacity(window)),
+        }
+    }
+
+    /// The fees of the latest update, if any.
+    pub fn latest(&self) -> Option<FeeSnapshot> {
+        self.snapshots.borrow().back().copied()
+    }
+
+    /// The fees of the monitored window, oldest first.
+    pub fn snapshots(&self) -> Vec<FeeSnapshot> {
+        self.snapshots.borrow().iter().copied().collect()
+    }
+
+    /// The fee a transaction should pay for `priority`, before the
+    /// cost of its type, or `None` before the first update.
+    pub fn current_recommended_fee(&self, priority: FeePriority) -> Option<XRPAmount<'static>> {
+        let snapshots = self.snapshots.borrow();
+        let latest = snapshots.back()?;
+        let drops = match priority {
+            FeePriority::Low => latest.minimum_fee,
+            FeePriority::Medium => latest.open_ledger_fee,
+            FeePriority::High => snapshots
+                .iter()
+                .map(|snapshot| snapshot.open_ledger_fee)
+                .fold(latest.open_ledger_fee, max),
+        };
+
+        Some(XRPAmount::from(drops.to_string()))
+    }
+
+    /// The monitor as the fee source of
+    /// [`autofill_with_fee_strategy`](crate::asynch::transaction::autofill_with_fee_strategy),
+    /// paying for `priority`. It polls `fee` once if nothing updated
+    /// the monitor yet.
+    pub fn fee_strategy(&self, priority: FeePriority) -> MonitoredFee<'_> {
+        MonitoredFee {
+            monitor: self,
+            priority,
+        }
+    }
+
+    /// Poll the fees with `fee`.
+    pub async fn update(&self, client: &impl XRPLAsyncClient) -> XRPLHelperResult<FeeSnapshot> {
+        let response = client.request(Fee::new(None).into()).await?;
+        let snapshot = FeeSnapshot::from_fee_result(response.try_into_result::<FeeResult<'_>>()?)?;
+        self.record(snapshot);
+
+        Ok(snapshot)
+    }
+
+    /// Update the fees from a message of the `server` or `ledger`
+    /// stream, or from the result of subscribing to them, and return
+    /// them. Other messages are ignored.
+    ///
+    /// Use it to feed the monitor from a connection read elsewhere;
+    /// [`FeeMonitor::update_streamed`] reads the connection itself.
+    pub fn update_from_stream(&self, message: &Value) -> Option<FeeSnapshot> {
+        let previous = self.latest();
+        let mut snapshot = None;
+        // A `ledgerClosed` message, or the ledger part of the result of
+        // subscribing: the base fee of the new open ledger.
+        if let Some(base_fee) = message["fee_base"].as_u64() {
+            let mut updated = match previous {
+                Some(previous) => previous.with_base_fee(base_fee),
+                None => FeeSnapshot::from_base_fee(base_fee),
+            };
+            if let Some(ledger_index) = message["ledger_index"].as_u64() {
+                updated.ledger_index = Some(ledger_index as u32 + 1);
+            }
+            snapshot = Some(updated);
+        }
+        // A `serverStatus` message, or the server part of the result of
+        // subscribing: the load and the fee escalation.
+        if let (Some(load_base), Some(load_factor)) = (
+            message["load_base"].as_u64(),
+            message["load_factor"].as_u64(),
+        ) {
+            let latest = snapshot.or(previous);
+            let base_fee = message["base_fee"]
+                .as_u64()
+                .or(latest.map(|latest| latest.base_fee));
+            if let Some(base_fee) = base_fee {
+                let mut updated = latest.unwrap_or(FeeSnapshot::from_base_fee(base_fee));
+                updated.base_fee = base_fee;
+                updated.load_factor = Some(load_factor as f64 / max(load_base, 1) as f64);
+                let server_load = message["load_factor_server"]
+                    .as_u64()
+                    .unwrap_or(load_factor);
+                let server_fee = scale(base_fee, server_load, load_base);
+                let fee_level = |field: &str| {
+                    let level = message[field].as_u64()?;
+                    let reference = message["load_factor_fee_reference"].as_u64()?;
+                    Some(max(server_fee, scale(base_fee, level, reference)))
+                };
+                let fee = scale(base_fee, load_factor, load_base);
+                updated.minimum_fee = fee_level("load_factor_fee_queue").unwrap_or(fee);
+                updated.open_ledger_fee = fee_level("load_factor_fee_escalation").unwrap_or(fee);
+                snapshot = Some(updated);
+            }
+        }
+        let snapshot = snapshot?;
+        self.record(snapshot);
+
+        Some(snapshot)
+    }
+
+    /// Subscribe to the `server` and `ledger` streams, whose messages
+    /// [`FeeMonitor::update_streamed`] reads.
+    #[cfg(feature = "websocket")]
+    pub async fn subscribe(&self, client: &mut impl XRPLAsyncWebsocketIO) -> XRPLHelperResult<()> {
+        let subscribe = Subscribe::new(
+            None,
+            None,
+            None,
+            None,
+            Some(vec![StreamParameter::Server, StreamParameter::Ledger]),
+            None,
+            None,
+            None,
+        );
+        client.xrpl_send(subscribe.into()).await?;
+
+        Ok(())
+    }
+
+    /// Read the next message of a connection subscribed with
+    /// [`FeeMonitor::subscribe`], and update the fees if it is about
+    /// them.
+    #[cfg(feature = "websocket")]
+    pub async fn update_streamed(
+        &self,
+        client: &mut impl XRPLAsyncWebsocketIO,
+    ) -> XRPLHelperResult<Option<FeeSnapshot>> {
+        let message = client
+            .xrpl_receive()
+            .await?
+            .ok_or(XRPLClientException::from(
+                XRPLWebSocketException::Disconnected,
+            ))?;
+        let Some(result) = message.result else {
+            return Ok(None);
+        };
+        let result: Value = result.try_into()?;
+
+        Ok(self.update_from_stream(&result))
+    }
+
+    /// Wait until the open ledger fee is below `threshold`, polling
+    /// `fee`, and return it.
+    ///
+    /// With a WebSocket client,
+    /// [`FeeMonitor::wait_until_fee_below_streamed`] waits for the
+    /// `server` and `ledger` streams instead of polling.
+    pub async fn wait_until_fee_below(
+        &self,
+        client: &impl XRPLAsyncClient,
+        threshold: XRPAmount<'_>,
+        timeout: Duration,
+    ) -> XRPLHelperResult<XRPAmount<'static>> {
+        self.poll_until_fee_below(client, threshold, timeout, FEE_POLL_INTERVAL)
+            .await
+    }
+
+    async fn poll_until_fee_below(
+        &self,
+        client: &impl XRPLAsyncClient,
+        threshold: XRPAmount<'_>,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> XRPLHelperResult<XRPAmount<'static>> {
+        let threshold: u64 = threshold.try_into()?;
+        let wait_for_fee = async {
+            loop {
+                let snapshot = self.update(client).await?;
+                if snapshot.open_ledger_fee < threshold {
+                    return Ok(XRPAmount::from(snapshot.open_ledger_fee.to_string()));
+                }
+                wait(poll_interval).await?;
+            }
+        };
+
+        match with_timeout(wait_for_fee, timeout).await? {
+            Some(result) => result,
+            None => Err(XRPLError::FeeWaitTimeout { threshold, timeout }),
+        }
+    }
+
+    /// Wait until the open ledger fee is below `threshold`,
+    /// subscribing to the `server` and `ledger` streams, and return
+    /// it. The client stays subscribed to the streams.
+    #[cfg(feature = "websocket")]
+    pub async fn wait_until_fee_below_streamed(
+        &self,
+        client: &mut impl XRPLAsyncWebsocketIO,
+        threshold: XRPAmount<'_>,
+        timeout: Duration,
+    ) -> XRPLHelperResult<XRPAmount<'static>> {
+        let threshold: u64 = threshold.try_into()?;
+        self.subscribe(client).await?;
+        let wait_for_fee = async {
+            loop {
+                let Some(snapshot) = self.update_streamed(client).await? else {
+                    continue;
+                };
+                if snapshot.open_ledger_fee < threshold {
+                    return Ok(XRPAmount::from(snapshot.open_ledger_fee.to_string()));
+                }
+            }
+        };
+
+        match with_timeout(wait_for_fee, timeout).await? {
+            Some(result) => result,
+            None => Err(XRPLError::FeeWaitTimeout { threshold, timeout }),
+        }
+    }
+
+    fn record(&self, snapshot: FeeSnapshot) {
+        let mut snapshots = self.snapshots.borrow_mut();
+        if snapshots.len() == self.window {
+            snapshots.pop_front();
+        }
+        snapshots.push_back(snapshot);
+    }
+}
+
+/// A [`FeeMonitor`] as a [`FeeStrategy`], see
+/// [`FeeMonitor::fee_strategy`].
+pub struct MonitoredFee<'m> {
+    monitor: &'m FeeMonitor,
+    priority: FeePriority,
+}
+
+impl FeeStrategy for MonitoredFee<'_> {
+    async fn get_net_fee(
+        &self,
+        client: &impl XRPLAsyncClient,
+    ) -> XRPLHelperResult<XRPAmount<'static>> {
+        if self.monitor.latest().is_none() {
+            self.monitor.update(client).await?;
+        }
+
+        Ok(self
+            .monitor
+            .current_recommended_fee(self.priority)
+            .expect("the monitor was just updated"))
+    }
+}
+
+/// `fee` times `level / reference`, rounded up, as the server scales
+/// fees.
+fn scale(fee: u64, level: u64, reference: u64) -> u64 {
+    let scaled = (fee as u128 * level as u128).div_ceil(max(reference, 1) as u128);
+
+    scaled.try_into().unwrap_or(u64::MAX)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+    #[cfg(feature = "websocket")]
+    use crate::{asynch::clients::MockWebsocket, models::requests::XRPLRequest};
+    use crate::{
+        asynch::{
+            clients::{MockClient, MockResponse},
+            transaction::{autofill_with_fee_strategy, test_autofill::mock_client},
+        },
+        models::{
+            requests::RequestMethod,
+            transactions::{account_set::AccountSet, Transaction},
+        },
+    };
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+
+    fn fee(ledger_current_index: u32, minimum_fee: u64, open_ledger_fee: u64) -> MockResponse {
+        MockResponse::result(json!({
+            "current_ledger_size": "14",
+            "current_queue_size": "0",
+            "drops": {
+                "base_fee": "10",
+                "median_fee": "5000",
+                "minimum_fee": minimum_fee.to_string(),
+                "open_ledger_fee": open_ledger_fee.to_string()
+            },
+            "expected_ledger_size": "24",
+            "ledger_current_index": ledger_current_index,
+            "max_queue_size": "480"
+        }))
+    }
+
+    fn server_status(load_factor: u64, escalation: u64, queue: u64) -> Value {
+        json!({
+            "base_fee": 10,
+            "load_base": 256,
+            "load_factor": load_factor,
+            "load_factor_fee_escalation": escalation,
+            "load_factor_fee_queue": queue,
+            "load_factor_fee_reference": 256,
+            "load_factor_server": 256,
+            "server_status": "full",
+            "type": "serverStatus"
+        })
+    }
+
+    fn ledger_closed(ledger_index: u32, fee_base: u64) -> Value {
+        json!({
+            "fee_base": fee_base,
+            "ledger_hash": "687F604EF6B2F67319E8DCC8C66EF49D84D18A1E18F948421FC24D2C7C3DB464",
+            "ledger_index": ledger_index,
+            "ledger_time": 561514690,
+            "reserve_base": 10000000,
+            "reserve_inc": 2000000,
+            "txn_count": 0,
+            "type": "ledgerClosed",
+            "validated_ledgers": "1-10"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_update_keeps_the_window() {
+        let client = MockClient::new()
+            .with_response(RequestMethod::Fee, fee(11, 10, 10))
+            .with_response(RequestMethod::Fee, fee(12, 10, 4000))
+            .with_response(RequestMethod::Fee, fee(13, 12, 26));
+        let monitor = FeeMonitor::new(2);
+        assert_eq!(monitor.current_recommended_fee(FeePriority::Medium), None);
+
+        for _ in 0..3 {
+            monitor.update(&client).await.unwrap();
+        }
+
+        let snapshots = monitor.snapshots();
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[0].ledger_index, Some(12));
+        assert_eq!(snapshots[0].escalation(), 400.0);
+        assert_eq!(
+            monitor.latest(),
+            Some(FeeSnapshot {
+                ledger_index: Some(13),
+                base_fee: 10,
+                minimum_fee: 12,
+                open_ledger_fee: 26,
+                load_factor: None,
+            })
+        );
+        assert_eq!(
+            monitor.current_recommended_fee(FeePriority::Low),
+            Some(XRPAmount::from("12"))
+        );
+        assert_eq!(
+            monitor.current_recommended_fee(FeePriority::Medium),
+            Some(XRPAmount::from("26"))
+        );
+        // The spike of the previous ledger is still in the window.
+        assert_eq!(
+            monitor.current_recommended_fee(FeePriority::High),
+            Some(XRPAmount::from("4000"))
+        );
+    }
+
+    #[test]
+    fn test_update_from_stream() {
+        let monitor = FeeMonitor::new(10);
+
+        // The result of subscribing to the server and ledger streams.
+        let snapshot = monitor
+            .update_from_stream(&json!({
+                "fee_base": 10,
+                "ledger_index": 10,
+                "load_base": 256,
+                "load_factor": 256,
+                "server_status": "full",
+                "validated_ledgers": "1-10"
+            }))
+            .unwrap();
+        assert_eq!(
+            snapshot,
+            FeeSnapshot {
+                ledger_index: Some(11),
+                base_fee: 10,
+                minimum_fee: 10,
+                open_ledger_fee: 10,
+                load_factor: Some(1.0),
+            }
+        );
+
+        // The open ledger fills up: the fee escalates.
+        let snapshot = monitor
+            .update_from_stream(&server_status(256 * 50, 256 * 50, 256))
+            .unwrap();
+        assert_eq!(snapshot.ledger_index, Some(11));
+        assert_eq!(snapshot.minimum_fee, 10);
+        assert_eq!(snapshot.open_ledger_fee, 500);
+        assert_eq!(snapshot.load_factor, Some(50.0));
+        assert_eq!(snapshot.escalation(), 50.0);
+
+        // A fee vote raises the base fee of the next ledger.
+        let snapshot = monitor.update_from_stream(&ledger_closed(11, 12)).unwrap();
+        assert_eq!(snapshot.ledger_index, Some(12));
+        assert_eq!(snapshot.base_fee, 12);
+        assert_eq!(snapshot.open_ledger_fee, 600);
+
+        // The server is loaded: even queued transactions cost more.
+        let mut status = server_status(512, 256, 256);
+        status["base_fee"] = 12.into();
+        status["load_factor_server"] = 512.into();
+        let snapshot = monitor.update_from_stream(&status).unwrap();
+        assert_eq!(snapshot.minimum_fee, 24);
+        assert_eq!(snapshot.open_ledger_fee, 24);
+
+        // Other messages are ignored.
+        assert_eq!(
+            monitor.update_from_stream(&json!({ "type": "transaction" })),
+            None
+        );
+        assert_eq!(monitor.snapshots().len(), 4);
+        assert_eq!(
+            monitor.current_recommended_fee(FeePriority::High),
+            Some(XRPAmount::from("600"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_fee_below() {
+        let client = MockClient::new()
+            .with_response(RequestMethod::Fee, fee(11, 10, 5000))
+            .with_response(RequestMethod::Fee, fee(12, 10, 800))
+            .with_response(RequestMethod::Fee, fee(13, 10, 10));
+        let monitor = FeeMonitor::new(10);
+        let open_ledger_fee = monitor
+            .poll_until_fee_below(
+                &client,
+                XRPAmount::from("100"),
+                Duration::from_secs(5),
+                Duration::from_millis(1),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(open_ledger_fee, XRPAmount::from("10"));
+        assert_eq!(client.requests_of(RequestMethod::Fee).len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_fee_below_times_out() {
+        let client = MockClient::new().with_response(RequestMethod::Fee, fee(11, 10, 5000));
+        let monitor = FeeMonitor::new(10);
+        let error = monitor
+            .poll_until_fee_below(
+                &client,
+                XRPAmount::from("100"),
+                Duration::from_millis(50),
+                Duration::from_millis(20),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            XRPLError::FeeWaitTimeout { threshold: 100, .. }
+        ));
+    }
+
+    #[cfg(feature = "websocket")]
+    #[tokio::test]
+    async fn test_wait_until_fee_below_streamed() {
+        let mut websocket = MockWebsocket::new()
+            .with_result(json!({
+                "fee_base": 10,
+                "ledger_index": 10,
+                "load_base": 256,
+                "load_factor": 256 * 300,
+                "server_status": "full"
+            }))
+            .with_messages([
+                server_status(256 * 30, 256 * 30, 256),
+                json!({ "type": "transaction" }),
+                ledger_closed(11, 10),
+                server_status(256, 256, 256),
+                ledger_closed(12, 10),
+            ]);
+        let monitor = FeeMonitor::new(10);
+        let open_ledger_fee = monitor
+            .wait_until_fee_below_streamed(
+                &mut websocket,
+                XRPAmount::from("100"),
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(open_ledger_fee, XRPAmount::from("10"));
+        let XRPLRequest::Subscribe(subscribe) = &websocket.requests()[0] else {
+            panic!("expected subscribe");
+        };
+        assert_eq!(
+            subscribe.streams,
+            Some(vec![StreamParameter::Server, StreamParameter::Ledger])
+        );
+        assert_eq!(websocket.pending_messages(), 1);
+        assert_eq!(monitor.snapshots().len(), 4);
+        assert_eq!(monitor.latest().unwrap().ledger_index, Some(12));
+    }
+
+    #[tokio::test]
+    async fn test_autofill_with_fee_monitor() {
+        let client = mock_client(ACCOUNT);
+        let monitor = FeeMonitor::new(10);
+        monitor.update_from_stream(&ledger_closed(10, 10));
+        monitor.update_from_stream(&server_status(256 * 40, 256 * 40, 256));
+        let mut account_set: AccountSet = serde_json::from_value(json!({
+            "TransactionType": "AccountSet",
+            "Account": ACCOUNT
+        }))
+        .unwrap();
+
+        autofill_with_fee_strategy(
+            &mut account_set,
+            &client,
+            None,
+            &monitor.fee_strategy(FeePriority::Medium),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(account_set.get_fee(), Some(&XRPAmount::from("400")));
+        // The monitor already knew the fees.
+        assert!(client.requests_of(RequestMethod::Fee).is_empty());
+
+        // An empty monitor asks the server first.
+        let monitor = FeeMonitor::new(10);
+        let mut account_set: AccountSet = serde_json::from_value(json!({
+            "TransactionType": "AccountSet",
+            "Account": ACCOUNT
+        }))
+        .unwrap();
+        autofill_with_fee_strategy(
+            &mut account_set,
+            &client,
+            Some(2),
+            &monitor.fee_strategy(FeePriority::Low),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(account_set.get_fee(), Some(&XRPAmount::from("40")));
+        assert_eq!(client.requests_of(RequestMethod::Fee).len(), 1);
+    }
+}
@@ -1,14 +1,56 @@
-use core::{cmp::min, convert::TryInto};
+mod fee_monitor;
 
-use alloc::string::ToString;
+use core::{cmp::min, convert::TryInto, time::Duration};
+
+#[cfg(feature = "websocket")]
+use alloc::vec;
+use alloc::{
+    string::{String, ToString},
+    vec::{IntoIter, Vec},
+};
+use serde_json::Value;
 
 use crate::models::{
-    requests::{fee::Fee, ledger::Ledger},
-    results::{fee::Drops, fee::Fee as FeeResult, ledger::Ledger as LedgerResult},
-    XRPAmount,
+    ledger::objects::LedgerEntry,
+    requests::{
+        fee::Fee,
+        ledger::Ledger,
+        ledger_accept::LedgerAccept,
+        ledger_current::LedgerCurrent,
+        ledger_data::{LedgerData, LedgerDataType},
+        server_info::ServerInfo,
+        server_state::ServerState,
+    },
+    results::{
+        fee::Drops,
+        fee::Fee as FeeResult,
+        ledger::Ledger as LedgerResult,
+        ledger_data::{LedgerData as LedgerDataResult, LedgerDataEntry},
+        server_info::ServerInfo as ServerInfoResult,
+        server_state::ServerState as ServerStateResult,
+        XRPLOtherResult,
+    },
+    XRPAmount, XRPLModelException,
+};
+#[cfg(feature = "websocket")]
+use crate::{
+    asynch::clients::{
+        exceptions::XRPLClientException, XRPLAsyncWebsocketIO, XRPLWebSocketException,
+    },
+    models::requests::subscribe::{StreamParameter, Subscribe},
 };
 
-use super::{clients::XRPLAsyncClient, exceptions::XRPLHelperResult};
+use super::{
+    clients::XRPLAsyncClient,
+    exceptions::{XRPLError, XRPLHelperResult},
+    wait, with_timeout,
+};
+
+pub use fee_monitor::*;
+
+/// How often [`wait_for_ledger_close`] asks for the current ledger.
+/// Ledgers close every 3 to 5 seconds.
+const LEDGER_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 pub async fn get_latest_validated_ledger_sequence(
     client: &impl XRPLAsyncClient,
@@ -62,12 +104,225 @@ pub async fn get_latest_open_ledger_sequence(
         .ledger_index)
 }
 
+/// A client [`wait_for_ledger_close`] can wait with.
+///
+/// A shared client polls `ledger_current`. A websocket client
+/// borrowed mutably waits for the ledger stream instead, see
+/// [`wait_for_ledger_close_streamed`].
+#[allow(async_fn_in_trait)]
+pub trait LedgerCloseWaiter {
+    async fn wait_for_ledger_close(
+        self,
+        ledger_index: u32,
+        timeout: Duration,
+    ) -> XRPLHelperResult<u32>;
+}
+
+impl<C: XRPLAsyncClient> LedgerCloseWaiter for &C {
+    async fn wait_for_ledger_close(
+        self,
+        ledger_index: u32,
+        timeout: Duration,
+    ) -> XRPLHelperResult<u32> {
+        poll_for_ledger_close(self, ledger_index, timeout, LEDGER_POLL_INTERVAL).await
+    }
+}
+
+#[cfg(feature = "websocket")]
+impl<C: XRPLAsyncWebsocketIO> LedgerCloseWaiter for &mut C {
+    async fn wait_for_ledger_close(
+        self,
+        ledger_index: u32,
+        timeout: Duration,
+    ) -> XRPLHelperResult<u32> {
+        wait_for_ledger_close_streamed(self, ledger_index, timeout).await
+    }
+}
+
+/// Wait until the ledger `ledger_index` has closed and return the
+/// index of the latest closed ledger. The ledger is closed, not
+/// necessarily validated yet.
+///
+/// Given a websocket client as `&mut`, this waits for the ledger
+/// stream and the client stays subscribed to it. Otherwise it polls
+/// `ledger_current`.
+pub async fn wait_for_ledger_close(
+    client: impl LedgerCloseWaiter,
+    ledger_index: u32,
+    timeout: Duration,
+) -> XRPLHelperResult<u32> {
+    client.wait_for_ledger_close(ledger_index, timeout).await
+}
+
+async fn poll_for_ledger_close(
+    client: &impl XRPLAsyncClient,
+    ledger_index: u32,
+    timeout: Duration,
+    poll_interval: Duration,
+) -> XRPLHelperResult<u32> {
+    let wait_for_close = async {
+        loop {
+            // The open ledger follows the latest closed one.
+            let closed_ledger_index = get_ledger_current_index(client).await?.checked_sub(1);
+            if let Some(closed_ledger_index) =
+                closed_ledger_index.filter(|closed| *closed >= ledger_index)
+            {
+                return Ok(closed_ledger_index);
+            }
+            wait(poll_interval).await?;
+        }
+    };
+
+    match with_timeout(wait_for_close, timeout).await? {
+        Some(result) => result,
+        None => Err(XRPLError::LedgerCloseTimeout {
+            ledger_index,
+            timeout,
+        }),
+    }
+}
+
+/// Wait until the ledger `ledger_index` has closed, subscribing to
+/// the ledger stream, and return the index of the latest closed
+/// ledger. The client stays subscribed to the ledger stream.
+#[cfg(feature = "websocket")]
+pub async fn wait_for_ledger_close_streamed(
+    client: &mut impl XRPLAsyncWebsocketIO,
+    ledger_index: u32,
+    timeout: Duration,
+) -> XRPLHelperResult<u32> {
+    const SUBSCRIBE_ID: &str = "wait_for_ledger_close";
+
+    let subscribe = Subscribe::new(
+        Some(SUBSCRIBE_ID.into()),
+        None,
+        None,
+        None,
+        Some(vec![StreamParameter::Ledger]),
+        None,
+        None,
+        None,
+    );
+    client.xrpl_send(subscribe.into()).await?;
+    let wait_for_close = async {
+        loop {
+            let message = client
+                .xrpl_receive()
+                .await?
+                .ok_or(XRPLClientException::from(
+                    XRPLWebSocketException::Disconnected,
+                ))?;
+            // The answer to `subscribe` holds the latest closed ledger,
+            // each `ledgerClosed` message the one that just closed.
+            let is_subscribe_response = message.id.as_deref() == Some(SUBSCRIBE_ID);
+            let Some(result) = message.result else {
+                continue;
+            };
+            let result: Value = result.try_into()?;
+            if !is_subscribe_response && result["type"] != "ledgerClosed" {
+                continue;
+            }
+            if let Some(closed_ledger_index) = result["ledger_index"].as_u64() {
+                if closed_ledger_index >= ledger_index.into() {
+                    return Ok(closed_ledger_index as u32);
+                }
+            }
+        }
+    };
+
+    match with_timeout(wait_for_close, timeout).await? {
+        Some(result) => result,
+        None => Err(XRPLError::LedgerCloseTimeout {
+            ledger_index,
+            timeout,
+        }),
+    }
+}
+
+/// Close the current open ledger and return the index of the new
+/// open ledger.
+///
+/// `ledger_accept` is an admin method of a server in stand-alone
+/// mode, meant for tests. Other servers refuse it.
+pub async fn ledger_accept(client: &impl XRPLAsyncClient) -> XRPLHelperResult<u32> {
+    let response = client.request(LedgerAccept::new(None).into()).await?;
+
+    Ok(response
+        .try_into_result::<XRPLOtherResult>()?
+        .try_get_typed("ledger_current_index")?)
+}
+
+async fn get_ledger_current_index(client: &impl XRPLAsyncClient) -> XRPLHelperResult<u32> {
+    let response = client.request(LedgerCurrent::new(None).into()).await?;
+
+    Ok(response
+        .try_into_result::<XRPLOtherResult>()?
+        .try_get_typed("ledger_current_index")?)
+}
+
+/// Get the network ID reported by the server, if any. Mainnet,
+/// Testnet and Devnet servers may omit it.
+pub async fn get_network_id(client: &impl XRPLAsyncClient) -> XRPLHelperResult<Option<u32>> {
+    let server_info_response = client.request(ServerInfo::new(None).into()).await?;
+
+    Ok(server_info_response
+        .try_into_result::<ServerInfoResult<'_>>()?
+        .info
+        .network_id)
+}
+
+/// Get the base reserve and the owner reserve (the reserve
+/// increment per owned object) of the latest validated ledger.
+pub async fn get_reserves<'a>(
+    client: &impl XRPLAsyncClient,
+) -> XRPLHelperResult<(XRPAmount<'a>, XRPAmount<'a>)> {
+    let server_state_response = client.request(ServerState::new(None).into()).await?;
+    let validated_ledger = server_state_response
+        .try_into_result::<ServerStateResult<'_>>()?
+        .state
+        .validated_ledger
+        .ok_or(XRPLModelException::MissingField(
+            "validated_ledger".to_string(),
+        ))?;
+
+    Ok((
+        XRPAmount::from(validated_ledger.reserve_base.0.into_owned()),
+        XRPAmount::from(validated_ledger.reserve_inc.0.into_owned()),
+    ))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FeeType {
     Open,
     Minimum,
     Dynamic,
 }
 
+/// Where [`autofill_with_fee_strategy`](crate::asynch::transaction::autofill_with_fee_strategy)
+/// gets the network fee from, the fee of a transaction before the
+/// cost of its type and signatures.
+///
+/// [`FeeType`] asks the server with [`get_fee`] every time; a
+/// [`FeeMonitor`] answers from the fees it watches.
+#[allow(async_fn_in_trait)]
+pub trait FeeStrategy {
+    async fn get_net_fee(
+        &self,
+        client: &impl XRPLAsyncClient,
+    ) -> XRPLHelperResult<XRPAmount<'static>>;
+}
+
+impl FeeStrategy for FeeType {
+    async fn get_net_fee(
+        &self,
+        client: &impl XRPLAsyncClient,
+    ) -> XRPLHelperResult<XRPAmount<'static>> {
+        let fee = get_fee(client, None, Some(*self)).await?;
+
+        Ok(XRPAmount::from(fee.0.into_owned()))
+    }
+}
+
 pub async fn get_fee(
     client: &impl XRPLAsyncClient,
     max_fee: Option<u32>,
@@ -85,6 +340,121 @@ pub async fn get_fee(
     }
 }
 
+/// Stream the whole state of the latest validated ledger, one
+/// ledger entry at a time, optionally only the entries of type
+/// `filter`.
+///
+/// Pages are fetched in binary, the most compact format, as the
+/// stream goes, and each entry is decoded only when yielded. All
+/// pages come from the ledger of the first one, so the stream is a
+/// consistent snapshot.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```no_run
+/// use xrpl::asynch::clients::{AsyncJsonRpcClient, Network};
+/// use xrpl::asynch::ledger::stream_ledger_data;
+/// use xrpl::models::ledger::objects::LedgerEntry;
+/// use xrpl::models::requests::ledger_data::LedgerDataType;
+///
+/// # #[cfg(feature = "std")]
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let client = AsyncJsonRpcClient::connect_network(Network::testnet());
+/// let mut trust_lines = 0;
+/// let mut stream = stream_ledger_data(&client, Some(LedgerDataType::State));
+/// while let Some(entry) = stream.next().await {
+///     if let LedgerEntry::RippleState(_) = entry.unwrap() {
+///         trust_lines += 1;
+///     }
+/// }
+/// # });
+/// ```
+pub fn stream_ledger_data<C>(client: &C, filter: Option<LedgerDataType>) -> LedgerDataStream<'_, C>
+where
+    C: XRPLAsyncClient,
+{
+    LedgerDataStream {
+        client,
+        filter,
+        ledger_hash: None,
+        marker: None,
+        page: Vec::new().into_iter(),
+        buffer: Vec::new(),
+        done: false,
+    }
+}
+
+/// The ledger entries of a ledger, see [`stream_ledger_data`].
+pub struct LedgerDataStream<'c, C> {
+    client: &'c C,
+    filter: Option<LedgerDataType>,
+    /// The ledger of the first page, to fetch the others from.
+    ledger_hash: Option<String>,
+    marker: Option<Value>,
+    page: IntoIter<LedgerDataEntry<'static>>,
+    /// Where binary entries are hex decoded, reused across entries.
+    buffer: Vec<u8>,
+    done: bool,
+}
+
+impl<C> LedgerDataStream<'_, C>
+where
+    C: XRPLAsyncClient,
+{
+    /// The next ledger entry, `None` once all are streamed. After a
+    /// failed request, the stream ends.
+    pub async fn next(&mut self) -> Option<XRPLHelperResult<LedgerEntry<'static>>> {
+        loop {
+            if let Some(entry) = self.page.next() {
+                return Some(
+                    entry
+                        .decode_with_buffer(&mut self.buffer)
+                        .map_err(Into::into),
+                );
+            }
+            if self.done {
+                return None;
+            }
+            if let Err(error) = self.fetch_page().await {
+                self.done = true;
+                return Some(Err(error));
+            }
+        }
+    }
+
+    /// The hash of the streamed ledger, once the first page is
+    /// fetched.
+    pub fn ledger_hash(&self) -> Option<&str> {
+        self.ledger_hash.as_deref()
+    }
+
+    async fn fetch_page(&mut self) -> XRPLHelperResult<()> {
+        let ledger_index = match self.ledger_hash {
+            Some(_) => None,
+            None => Some("validated".into()),
+        };
+        let request = LedgerData::new(
+            None,
+            Some(true),
+            self.ledger_hash.clone().map(Into::into),
+            ledger_index,
+            None,
+            self.marker.take(),
+            self.filter.clone(),
+        );
+        let response = self.client.request(request.into()).await?;
+        let page = response.try_into_result::<LedgerDataResult<'static>>()?;
+        self.ledger_hash = Some(page.ledger_hash.into_owned());
+        self.done = page.marker.is_none();
+        self.marker = page.marker;
+        self.page = page.state.into_iter();
+
+        Ok(())
+    }
+}
+
 fn match_fee_type(fee_type: Option<FeeType>, drops: Drops<'_>) -> XRPLHelperResult<u32> {
     match fee_type {
         None | Some(FeeType::Open) => Ok(drops.open_ledger_fee.try_into()?),
@@ -92,3 +462,305 @@ fn match_fee_type(fee_type: Option<FeeType>, drops: Drops<'_>) -> XRPLHelperResu
         Some(FeeType::Dynamic) => unimplemented!("Dynamic fee calculation not yet implemented"),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use core::cell::Cell;
+
+    use url::Url;
+
+    use super::*;
+    #[cfg(feature = "websocket")]
+    use crate::asynch::clients::MockWebsocket;
+    use crate::{
+        asynch::clients::{exceptions::XRPLClientResult, MockResponse, XRPLClient},
+        models::{
+            requests::{RequestMethod, XRPLRequest},
+            results::XRPLResponse,
+        },
+    };
+
+    /// A stand-alone server whose open ledger advances with each
+    /// `ledger_current`, or with `ledger_accept`.
+    struct MockClient {
+        ledger_current_index: Cell<u32>,
+    }
+
+    impl MockClient {
+        fn new(ledger_current_index: u32) -> Self {
+            Self {
+                ledger_current_index: Cell::new(ledger_current_index),
+            }
+        }
+    }
+
+    impl XRPLClient for MockClient {
+        async fn request_impl<'a: 'b, 'b>(
+            &self,
+            request: XRPLRequest<'a>,
+        ) -> XRPLClientResult<XRPLResponse<'b>> {
+            let ledger_current_index = self.ledger_current_index.get();
+            match request {
+                XRPLRequest::LedgerCurrent(_) | XRPLRequest::LedgerAccept(_) => {
+                    self.ledger_current_index.set(ledger_current_index + 1)
+                }
+                request => panic!("unexpected request: {request:?}"),
+            }
+
+            Ok(serde_json::from_value(serde_json::json!({
+                "result": {
+                    "ledger_current_index": self.ledger_current_index.get(),
+                    "status": "success"
+                },
+                "status": "success",
+                "type": "response"
+            }))?)
+        }
+
+        fn get_host(&self) -> Url {
+            Url::parse("http://localhost:5005").unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_ledger_close() {
+        let client = MockClient::new(10);
+        let closed_ledger_index = poll_for_ledger_close(
+            &client,
+            13,
+            Duration::from_secs(5),
+            Duration::from_millis(1),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(closed_ledger_index, 13);
+        assert_eq!(client.ledger_current_index.get(), 14);
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_ledger_close_times_out() {
+        // The open ledger only advances once per poll.
+        let client = MockClient::new(10);
+        let error = poll_for_ledger_close(
+            &client,
+            1000,
+            Duration::from_millis(50),
+            Duration::from_millis(20),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(
+            error,
+            XRPLError::LedgerCloseTimeout {
+                ledger_index: 1000,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_ledger_close_before_any_closed() {
+        // No ledger closed before the open ledger 0.
+        let client = crate::asynch::clients::MockClient::new().with_response(
+            RequestMethod::LedgerCurrent,
+            MockResponse::result(serde_json::json!({ "ledger_current_index": 0 })),
+        );
+        let error = poll_for_ledger_close(
+            &client,
+            0,
+            Duration::from_millis(50),
+            Duration::from_millis(10),
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(
+            error,
+            XRPLError::LedgerCloseTimeout {
+                ledger_index: 0,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_ledger_accept() {
+        let client = MockClient::new(10);
+
+        assert_eq!(ledger_accept(&client).await.unwrap(), 11);
+    }
+
+    /// Answers `subscribe` with ledger 10 as the last closed one.
+    #[cfg(feature = "websocket")]
+    fn mock_websocket() -> MockWebsocket {
+        MockWebsocket::new().with_result(serde_json::json!({
+            "fee_base": 10,
+            "ledger_hash": "7CD50477F23FF158B430772D8E82A961376A7B40E13C695AA849811EDF66C5C0",
+            "ledger_index": 10,
+            "ledger_time": 561514690,
+            "validated_ledgers": "1-10"
+        }))
+    }
+
+    #[cfg(feature = "websocket")]
+    fn ledger_closed(ledger_index: u32) -> Value {
+        serde_json::json!({
+            "fee_base": 10,
+            "ledger_hash": "687F604EF6B2F67319E8DCC8C66EF49D84D18A1E18F948421FC24D2C7C3DB464",
+            "ledger_index": ledger_index,
+            "ledger_time": 561514690,
+            "txn_count": 0,
+            "type": "ledgerClosed",
+            "validated_ledgers": "1-10"
+        })
+    }
+
+    #[cfg(feature = "websocket")]
+    #[tokio::test]
+    async fn test_wait_for_ledger_close_streamed() {
+        let mut websocket = mock_websocket().with_messages([
+            // Not a ledger stream message.
+            serde_json::json!({
+                "ledger_index": 20,
+                "type": "transaction"
+            }),
+            ledger_closed(11),
+            ledger_closed(12),
+            ledger_closed(13),
+        ]);
+        let closed_ledger_index =
+            wait_for_ledger_close_streamed(&mut websocket, 12, Duration::from_secs(5))
+                .await
+                .unwrap();
+
+        assert_eq!(closed_ledger_index, 12);
+        let XRPLRequest::Subscribe(subscribe) = &websocket.requests()[0] else {
+            panic!("expected subscribe");
+        };
+        assert_eq!(subscribe.streams, Some(vec![StreamParameter::Ledger]));
+        assert_eq!(websocket.pending_messages(), 1);
+
+        // The ledger already closed. Given as `&mut`, the websocket
+        // is waited on with the ledger stream.
+        let mut websocket = mock_websocket();
+        let closed_ledger_index = wait_for_ledger_close(&mut websocket, 9, Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(closed_ledger_index, 10);
+        assert!(matches!(websocket.requests()[0], XRPLRequest::Subscribe(_)));
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_stream_ledger_data {
+    use super::stream_ledger_data;
+    use crate::{
+        asynch::clients::{MockClient, MockResponse},
+        models::{
+            ledger::objects::LedgerEntry,
+            requests::{ledger_data::LedgerDataType, RequestMethod, XRPLRequest},
+            Amount, IssuedCurrencyAmount,
+        },
+    };
+
+    const LEDGER_HASH: &str = "842B57C1CC0613299A686D3E9F310EC0422C84D3911E5056389AA7E5808A93C8";
+    const ACCOUNT_ROOT: &str = "1100612200000000240000000125000022C52D00000000558D7F42ED0621FBCFAE55CC6F2A9403A2AFB205708CCBA3109BB61DB8DDA261B46240000000160DC0808114712B799C79D1EEE3094B59EF9920C7FEB3CE4499";
+    const RIPPLE_STATE: &str = "110072220002000025000000EF37000000000000000038000000000000000055C6A2521BBCCF13282C4FFEBC00D47BBA18C6CE5F5E4E0EFC3E3FCE364BAFC6B862800000000000000000000000000000000000000055534400000000000000000000000000000000000000000000000001668000000000000000000000000000000000000000555344000000000036D16F18B3AAC1868C1E3E8FA8EB7DDFD8ECCCAC67D4C38D7EA4C680000000000000000000000000005553440000000000E14829DB4C6419A8EFCAC1EC21D891A1A4339871";
+    const ACCOUNT_ROOT_INDEX: &str =
+        "2B6AC232AA4C4BE41BF49D2459FA4A0347E1B543A4C92FCEE0821C0201E2E9A8";
+    const RIPPLE_STATE_INDEX: &str =
+        "7E1247F78EFC74FA9C0FE39F37E8C82EB3B0C2D5EB93F1E8B0A06FF3FEB38FB6";
+
+    /// A ledger of two pages: an AccountRoot, then a RippleState.
+    fn mock_client() -> MockClient {
+        MockClient::new()
+            .with_response(
+                RequestMethod::LedgerData,
+                MockResponse::result(serde_json::json!({
+                    "ledger": {
+                        "closed": true,
+                        "ledger_data": "0000000100000000"
+                    },
+                    "ledger_hash": LEDGER_HASH,
+                    "ledger_index": 6885842,
+                    "marker": RIPPLE_STATE_INDEX,
+                    "state": [{ "data": ACCOUNT_ROOT, "index": ACCOUNT_ROOT_INDEX }],
+                    "validated": true
+                })),
+            )
+            .with_response(
+                RequestMethod::LedgerData,
+                MockResponse::result(serde_json::json!({
+                    "ledger_hash": LEDGER_HASH,
+                    "ledger_index": 6885842,
+                    "state": [{ "data": RIPPLE_STATE, "index": RIPPLE_STATE_INDEX }],
+                    "validated": true
+                })),
+            )
+    }
+
+    #[tokio::test]
+    async fn test_stream_two_pages() {
+        let client = mock_client();
+        let mut stream = stream_ledger_data(&client, Some(LedgerDataType::State));
+
+        let Some(Ok(LedgerEntry::AccountRoot(account_root))) = stream.next().await else {
+            panic!("expected the AccountRoot first");
+        };
+        assert_eq!(account_root.account, "rBKPS4oLSaV2KVVuHH8EpQqMGgGefGFQs7");
+        assert_eq!(account_root.balance.unwrap().0, "370000000");
+        assert_eq!(
+            account_root.common_fields.index.as_deref(),
+            Some(ACCOUNT_ROOT_INDEX)
+        );
+        assert_eq!(stream.ledger_hash(), Some(LEDGER_HASH));
+
+        let Some(Ok(LedgerEntry::RippleState(ripple_state))) = stream.next().await else {
+            panic!("expected the RippleState second");
+        };
+        assert_eq!(
+            ripple_state.high_limit,
+            Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rMYBVwiY95QyUnCeuBQA1D47kXA9zuoBui".into(),
+                "10".into()
+            ))
+        );
+        assert_eq!(
+            ripple_state.common_fields.index.as_deref(),
+            Some(RIPPLE_STATE_INDEX)
+        );
+        assert!(stream.next().await.is_none());
+        assert!(stream.next().await.is_none());
+
+        let requests = client.requests_of(RequestMethod::LedgerData);
+        assert_eq!(requests.len(), 2);
+        let XRPLRequest::LedgerData(first_page) = &requests[0] else {
+            panic!("unexpected request: {:?}", requests[0]);
+        };
+        assert_eq!(first_page.binary, Some(true));
+        assert_eq!(first_page.ledger_index.as_deref(), Some("validated"));
+        assert_eq!(first_page.r#type, Some(LedgerDataType::State));
+        // The next page comes from the same ledger.
+        let XRPLRequest::LedgerData(second_page) = &requests[1] else {
+            panic!("unexpected request: {:?}", requests[1]);
+        };
+        assert_eq!(second_page.ledger_hash.as_deref(), Some(LEDGER_HASH));
+        assert_eq!(second_page.ledger_index, None);
+        assert_eq!(second_page.marker, Some(RIPPLE_STATE_INDEX.into()));
+    }
+
+    #[tokio::test]
+    async fn test_stream_ends_after_error() {
+        let client = MockClient::new().with_response(
+            RequestMethod::LedgerData,
+            MockResponse::error("lgrNotFound", 21, Some("ledgerNotFound")),
+        );
+        let mut stream = stream_ledger_data(&client, None);
+
+        assert!(stream.next().await.unwrap().is_err());
+        assert!(stream.next().await.is_none());
+    }
+}
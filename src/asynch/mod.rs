@@ -7,43 +7,95 @@ pub mod clients;
 #[cfg(feature = "helpers")]
 pub mod ledger;
 #[cfg(feature = "helpers")]
+pub mod nft;
+#[cfg(feature = "helpers")]
+pub mod orderbook;
+#[cfg(feature = "helpers")]
 pub mod transaction;
 #[cfg(feature = "helpers")]
+pub mod trustline;
+#[cfg(feature = "helpers")]
 pub mod wallet;
 
-#[allow(unused_imports)]
-#[allow(clippy::needless_return)]
-async fn wait_seconds(seconds: u64) {
-    use core::time::Duration;
+/// Whether a runtime feature provides a timer for [`wait`].
+#[cfg(any(feature = "websocket", feature = "json-rpc"))]
+const HAS_TIMER: bool = cfg!(any(
+    feature = "tokio-rt",
+    feature = "embassy-rt",
+    feature = "actix-rt",
+    feature = "async-std-rt",
+    feature = "futures-rt",
+    feature = "smol-rt"
+));
 
+/// Run `future`, giving up with `None` after `timeout`. Without a
+/// runtime feature there is no timer, so this fails instead of
+/// waiting for `future` forever.
+#[cfg(any(feature = "websocket", feature = "json-rpc"))]
+async fn with_timeout<F: core::future::Future>(
+    future: F,
+    timeout: core::time::Duration,
+) -> clients::exceptions::XRPLClientResult<Option<F::Output>> {
+    if !HAS_TIMER {
+        return Err(clients::exceptions::XRPLClientException::NoTimer);
+    }
+    match embassy_futures::select::select(future, wait(timeout)).await {
+        embassy_futures::select::Either::First(output) => Ok(Some(output)),
+        embassy_futures::select::Either::Second(_) => Ok(None),
+    }
+}
+
+#[cfg(any(feature = "websocket", feature = "json-rpc"))]
+async fn wait_seconds(seconds: u64) -> clients::exceptions::XRPLClientResult<()> {
+    wait(core::time::Duration::from_secs(seconds)).await
+}
+
+/// Wait for `duration`. Without a runtime feature there is no timer,
+/// so this fails instead of returning at once and letting a polling
+/// loop spin.
+#[cfg(any(feature = "websocket", feature = "json-rpc"))]
+#[allow(clippy::needless_return)]
+async fn wait(duration: core::time::Duration) -> clients::exceptions::XRPLClientResult<()> {
     #[cfg(feature = "tokio-rt")]
     {
-        tokio::time::sleep(tokio::time::Duration::from_secs(seconds)).await;
-        return;
+        tokio::time::sleep(duration).await;
+        return Ok(());
     }
     #[cfg(feature = "embassy-rt")]
     {
-        embassy_time::Timer::after_secs(1).await;
-        return;
+        embassy_time::Timer::after_micros(duration.as_micros() as u64).await;
+        return Ok(());
     }
     #[cfg(feature = "actix-rt")]
     {
-        actix_rt::time::sleep(Duration::from_secs(seconds)).await;
-        return;
+        actix_rt::time::sleep(duration).await;
+        return Ok(());
     }
     #[cfg(feature = "async-std-rt")]
     {
-        async_std::task::sleep(Duration::from_secs(seconds)).await;
-        return;
+        async_std::task::sleep(duration).await;
+        return Ok(());
     }
     #[cfg(feature = "futures-rt")]
     {
-        futures_timer::Delay::new(Duration::from_secs(seconds)).await;
-        return;
+        futures_timer::Delay::new(duration).await;
+        return Ok(());
     }
     #[cfg(feature = "smol-rt")]
     {
-        smol::Timer::after(Duration::from_secs(seconds)).await;
-        return;
+        smol::Timer::after(duration).await;
+        return Ok(());
+    }
+    #[cfg(not(any(
+        feature = "tokio-rt",
+        feature = "embassy-rt",
+        feature = "actix-rt",
+        feature = "async-std-rt",
+        feature = "futures-rt",
+        feature = "smol-rt"
+    )))]
+    {
+        let _ = duration;
+        Err(clients::exceptions::XRPLClientException::NoTimer)
     }
 }
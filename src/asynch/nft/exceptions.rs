@@ -0,0 +1,17 @@
+use alloc::string::String;
+use thiserror_no_std::Error;
+
+use crate::models::transactions::TransactionType;
+
+#[derive(Error, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum XRPLNftException {
+    #[error("{transaction_type} failed with {result}")]
+    TransactionFailed {
+        transaction_type: TransactionType,
+        /// The `tec` code of the transaction, e.g. `tecNO_PERMISSION`.
+        result: String,
+    },
+    #[error("Expected the {0} in the transaction metadata")]
+    ExpectedIdInMeta(&'static str),
+}
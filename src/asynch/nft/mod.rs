@@ -0,0 +1,404 @@
+pub mod exceptions;
+
+use core::fmt::Debug;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::{
+    asynch::{
+        clients::XRPLAsyncClient,
+        exceptions::{XRPLError, XRPLHelperResult},
+        transaction::{
+            exceptions::{XRPLSubmitAndWaitException, XRPLTransactionHelperException},
+            submit_and_wait,
+        },
+    },
+    models::{
+        transactions::{
+            metadata::{AffectedNode, TransactionMetadata},
+            nftoken_accept_offer::NFTokenAcceptOffer,
+            nftoken_burn::NFTokenBurn,
+            nftoken_create_offer::{NFTokenCreateOffer, NFTokenCreateOfferFlag},
+            nftoken_mint::{NFTokenMint, NFTokenMintFlag},
+            Transaction,
+        },
+        Amount, FlagCollection, Model,
+    },
+    utils::txn_parser::get_minted_nftoken_ids,
+    wallet::Wallet,
+};
+
+use exceptions::XRPLNftException;
+
+/// Mint an NFToken with `wallet` as its issuer, wait for the
+/// transaction to be validated and return the ID of the NFToken.
+///
+/// `uri` is the plain URI of the token's data, encoded to hex for the
+/// transaction. `transfer_fee` is in units of 1/100,000, up to 50000
+/// for 50%, and requires the `TfTransferable` flag.
+pub async fn mint_nft<C>(
+    client: &C,
+    wallet: &Wallet,
+    uri: Option<&str>,
+    taxon: u32,
+    flags: Option<FlagCollection<NFTokenMintFlag>>,
+    transfer_fee: Option<u32>,
+) -> XRPLHelperResult<String>
+where
+    C: XRPLAsyncClient,
+{
+    let mut transaction = NFTokenMint::new(
+        wallet.classic_address.as_str().into(),
+        None,
+        None,
+        flags,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        taxon,
+        None,
+        transfer_fee,
+        uri.map(|uri| hex::encode_upper(uri).into()),
+    );
+    let (_, meta) = submit_nft_transaction(&mut transaction, client, wallet).await?;
+
+    meta.nftoken_id
+        .as_deref()
+        .map(ToString::to_string)
+        .or_else(|| get_minted_nftoken_ids(&meta).into_iter().next())
+        .ok_or(XRPLNftException::ExpectedIdInMeta("NFToken ID").into())
+}
+
+/// Offer the NFToken `nftoken_id` of `wallet` for sale at `amount`,
+/// optionally only to `destination` or until `expiration` (seconds
+/// since the Ripple Epoch), wait for the offer to be validated and
+/// return its ID.
+pub async fn create_nft_sell_offer<C>(
+    client: &C,
+    wallet: &Wallet,
+    nftoken_id: &str,
+    amount: Amount<'_>,
+    destination: Option<&str>,
+    expiration: Option<u32>,
+) -> XRPLHelperResult<String>
+where
+    C: XRPLAsyncClient,
+{
+    let mut transaction = NFTokenCreateOffer::new(
+        wallet.classic_address.as_str().into(),
+        None,
+        None,
+        Some(vec![NFTokenCreateOfferFlag::TfSellOffer].into()),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        amount,
+        nftoken_id.into(),
+        destination.map(Into::into),
+        expiration,
+        None,
+    );
+    let (_, meta) = submit_nft_transaction(&mut transaction, client, wallet).await?;
+
+    get_created_offer_id(&meta).ok_or(XRPLNftException::ExpectedIdInMeta("offer ID").into())
+}
+
+/// Accept an NFToken offer with `wallet`, wait for the transaction to
+/// be validated and return its hash.
+///
+/// Pass one of `sell_offer` or `buy_offer` to trade directly, or both
+/// to broker the trade, keeping up to `broker_fee`.
+pub async fn accept_nft_offer<C>(
+    client: &C,
+    wallet: &Wallet,
+    sell_offer: Option<&str>,
+    buy_offer: Option<&str>,
+    broker_fee: Option<Amount<'_>>,
+) -> XRPLHelperResult<String>
+where
+    C: XRPLAsyncClient,
+{
+    let mut transaction = NFTokenAcceptOffer::new(
+        wallet.classic_address.as_str().into(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        sell_offer.map(Into::into),
+        buy_offer.map(Into::into),
+        broker_fee,
+    );
+    let (hash, _) = submit_nft_transaction(&mut transaction, client, wallet).await?;
+
+    Ok(hash)
+}
+
+/// Burn the NFToken `nftoken_id` with `wallet`, wait for the
+/// transaction to be validated and return its hash.
+///
+/// `owner` is the holder of the token, if `wallet` is its issuer
+/// burning it with the `TfBurnable` flag.
+pub async fn burn_nft<C>(
+    client: &C,
+    wallet: &Wallet,
+    nftoken_id: &str,
+    owner: Option<&str>,
+) -> XRPLHelperResult<String>
+where
+    C: XRPLAsyncClient,
+{
+    let mut transaction = NFTokenBurn::new(
+        wallet.classic_address.as_str().into(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        nftoken_id.into(),
+        owner.map(Into::into),
+    );
+    let (hash, _) = submit_nft_transaction(&mut transaction, client, wallet).await?;
+
+    Ok(hash)
+}
+
+/// Autofill, sign, submit and wait for `transaction`, returning its
+/// hash and metadata. A `tec` result fails with
+/// [`XRPLNftException::TransactionFailed`].
+async fn submit_nft_transaction<'a, T, F, C>(
+    transaction: &mut T,
+    client: &C,
+    wallet: &Wallet,
+) -> XRPLHelperResult<(String, TransactionMetadata<'static>)>
+where
+    T: Transaction<'a, F> + Model + Clone + DeserializeOwned + Debug,
+    F: IntoEnumIterator + Serialize + Debug + PartialEq + Clone + 'a,
+    C: XRPLAsyncClient,
+{
+    let transaction_type = transaction.get_transaction_type();
+    match submit_and_wait(transaction, client, Some(wallet), Some(true), Some(true)).await {
+        Ok(tx) => Ok((tx.hash.to_string(), serde_json::from_value(tx.meta)?)),
+        Err(XRPLError::XRPLTransactionHelperError(
+            XRPLTransactionHelperException::XRPLSubmitAndWaitError(
+                XRPLSubmitAndWaitException::SubmissionFailed(result),
+            ),
+        )) if result.starts_with("tec") => Err(XRPLNftException::TransactionFailed {
+            transaction_type,
+            result,
+        }
+        .into()),
+        Err(error) => Err(error),
+    }
+}
+
+/// The ID of the NFTokenOffer an NFTokenCreateOffer created.
+fn get_created_offer_id(meta: &TransactionMetadata<'_>) -> Option<String> {
+    if let Some(offer_id) = &meta.offer_id {
+        return Some(offer_id.to_string());
+    }
+
+    meta.affected_nodes.iter().find_map(|node| match node {
+        AffectedNode::CreatedNode {
+            ledger_entry_type,
+            ledger_index,
+            ..
+        } if ledger_entry_type == "NFTokenOffer" => Some(ledger_index.to_string()),
+        _ => None,
+    })
+}
+
+#[cfg(all(test, feature = "std", feature = "tokio-rt"))]
+mod test {
+    use super::*;
+    use crate::{
+        asynch::{
+            clients::{MockClient, MockResponse},
+            transaction::test_autofill::mock_client,
+        },
+        models::{
+            requests::{RequestMethod, XRPLRequest},
+            transactions::TransactionType,
+            XRPAmount,
+        },
+    };
+
+    const NFTOKEN_ID: &str = "000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65";
+    const OFFER_ID: &str = "68CD1F6F906494EA08C9CB5CAFA64DFA90D4E834B7151899B73231DE5A0C3B77";
+
+    /// A server accepting every submitted transaction, which ends up
+    /// validated with `meta`.
+    fn nft_client(wallet: &Wallet, meta: serde_json::Value) -> MockClient {
+        mock_client(&wallet.classic_address)
+            .with_handler(|request| {
+                let XRPLRequest::Submit(submit) = request else {
+                    return None;
+                };
+
+                Some(MockResponse::result(serde_json::json!({
+                    "engine_result": "tesSUCCESS",
+                    "engine_result_code": 0,
+                    "engine_result_message": "The transaction was applied. Only final in a validated ledger.",
+                    "status": "success",
+                    "tx_blob": submit.tx_blob,
+                    "tx_json": {}
+                })))
+            })
+            .with_response(
+                RequestMethod::Tx,
+                MockResponse::result(serde_json::json!({
+                    "Account": wallet.classic_address,
+                    "date": 638329271,
+                    "hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB13FD",
+                    "ledger_index": 4,
+                    "meta": meta,
+                    "status": "success",
+                    "validated": true
+                })),
+            )
+    }
+
+    fn submitted(client: &MockClient) -> serde_json::Value {
+        let XRPLRequest::Submit(submit) = &client.requests_of(RequestMethod::Submit)[0] else {
+            unreachable!()
+        };
+
+        crate::core::binarycodec::decode(&submit.tx_blob).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_mint_nft() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let client = nft_client(
+            &wallet,
+            serde_json::json!({
+                "AffectedNodes": [],
+                "TransactionIndex": 0,
+                "TransactionResult": "tesSUCCESS",
+                "nftoken_id": NFTOKEN_ID
+            }),
+        );
+
+        let nftoken_id = mint_nft(
+            &client,
+            &wallet,
+            Some("ipfs://example"),
+            7,
+            Some(vec![NFTokenMintFlag::TfTransferable].into()),
+            Some(5000),
+        )
+        .await
+        .unwrap();
+        assert_eq!(nftoken_id, NFTOKEN_ID);
+
+        let tx = submitted(&client);
+        assert_eq!(tx["TransactionType"], "NFTokenMint");
+        assert_eq!(tx["NFTokenTaxon"], 7);
+        assert_eq!(tx["TransferFee"], 5000);
+        assert_eq!(tx["URI"], hex::encode_upper("ipfs://example"));
+    }
+
+    #[tokio::test]
+    async fn test_create_nft_sell_offer() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        // The offer ID from the created ledger entry, for servers that
+        // do not add `offer_id`.
+        let client = nft_client(
+            &wallet,
+            serde_json::json!({
+                "AffectedNodes": [{
+                    "CreatedNode": {
+                        "LedgerEntryType": "NFTokenOffer",
+                        "LedgerIndex": OFFER_ID,
+                        "NewFields": {
+                            "Amount": "1000000",
+                            "Flags": 1,
+                            "NFTokenID": NFTOKEN_ID,
+                            "Owner": wallet.classic_address
+                        }
+                    }
+                }],
+                "TransactionIndex": 0,
+                "TransactionResult": "tesSUCCESS"
+            }),
+        );
+
+        let offer_id = create_nft_sell_offer(
+            &client,
+            &wallet,
+            NFTOKEN_ID,
+            XRPAmount::from("1000000").into(),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(offer_id, OFFER_ID);
+
+        let tx = submitted(&client);
+        assert_eq!(tx["TransactionType"], "NFTokenCreateOffer");
+        assert_eq!(tx["Flags"], NFTokenCreateOfferFlag::TfSellOffer as u32);
+        assert_eq!(tx["NFTokenID"], NFTOKEN_ID);
+    }
+
+    #[tokio::test]
+    async fn test_accept_nft_offer_no_permission() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let client = nft_client(
+            &wallet,
+            serde_json::json!({
+                "AffectedNodes": [],
+                "TransactionIndex": 0,
+                "TransactionResult": "tecNO_PERMISSION"
+            }),
+        );
+
+        let error = accept_nft_offer(&client, &wallet, Some(OFFER_ID), None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            XRPLError::XRPLNftError(XRPLNftException::TransactionFailed {
+                transaction_type: TransactionType::NFTokenAcceptOffer,
+                ref result,
+            }) if result == "tecNO_PERMISSION"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_burn_nft() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let client = nft_client(
+            &wallet,
+            serde_json::json!({
+                "AffectedNodes": [],
+                "TransactionIndex": 0,
+                "TransactionResult": "tesSUCCESS"
+            }),
+        );
+
+        let hash = burn_nft(&client, &wallet, NFTOKEN_ID, None).await.unwrap();
+        assert_eq!(
+            hash,
+            "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB13FD"
+        );
+        assert_eq!(submitted(&client)["TransactionType"], "NFTokenBurn");
+    }
+}
@@ -0,0 +1,1109 @@
+use core::{convert::TryInto, str::FromStr};
+
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+#[cfg(feature = "websocket")]
+use alloc::{
+    string::{String, ToString},
+    vec,
+};
+use bigdecimal::{BigDecimal, One, Zero};
+#[cfg(feature = "websocket")]
+use serde_json::Value;
+
+#[cfg(feature = "websocket")]
+use crate::{
+    asynch::clients::{
+        exceptions::XRPLClientException, XRPLAsyncWebsocketIO, XRPLWebSocketException,
+    },
+    models::requests::subscribe::{Subscribe, SubscribeBook},
+};
+use crate::{
+    models::{
+        requests::book_offers::BookOffers,
+        results::book_offers::{BookOffer, BookOffers as BookOffersResult},
+        transactions::{
+            offer_create::{OfferCreate, OfferCreateFlag},
+            Transaction,
+        },
+        Amount, Currency, XRPLModelException,
+    },
+    utils::{transfer_rate_to_ratio, Ratio, MAX_IOU_PRECISION, XRP_DROPS},
+};
+
+use super::{account::get_account_root, clients::XRPLAsyncClient, exceptions::XRPLHelperResult};
+
+/// Which side of an [`OrderBook`] an offer is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderBookSide {
+    /// An offer to buy the base currency.
+    Bid,
+    /// An offer to sell the base currency.
+    Ask,
+}
+
+/// An offer of an [`OrderBook`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderBookEntry<'a> {
+    /// The price of the base currency, in the quote currency, for
+    /// the account taking the offer, transfer fees included. XRP is
+    /// counted in XRP, not drops.
+    pub price: BigDecimal,
+    /// How much of the base currency the owner can fund.
+    pub amount: Amount<'a>,
+    /// The owner of the offer.
+    pub account: Cow<'a, str>,
+    /// The Sequence of the offer.
+    pub sequence: u32,
+}
+
+impl<'a> OrderBookEntry<'a> {
+    /// The entry of an offer of `side`. `transfer_fee` is the fee the
+    /// issuer of what the taker pays charges, if any.
+    ///
+    /// Returns `None` for an offer its owner cannot fund.
+    pub fn from_offer(
+        offer: &BookOffer<'a>,
+        side: OrderBookSide,
+        transfer_fee: &Ratio,
+    ) -> XRPLHelperResult<Option<Self>> {
+        let base_amount = match side {
+            OrderBookSide::Ask => offer
+                .taker_gets_funded
+                .as_ref()
+                .unwrap_or(&offer.offer.taker_gets),
+            OrderBookSide::Bid => offer
+                .taker_pays_funded
+                .as_ref()
+                .unwrap_or(&offer.offer.taker_pays),
+        };
+        let taker_gets = amount_value(&offer.offer.taker_gets)?;
+        let mut taker_pays = amount_value(&offer.offer.taker_pays)?;
+        if amount_value(base_amount)?.is_zero() || taker_gets.is_zero() || taker_pays.is_zero() {
+            return Ok(None);
+        }
+        // Paying the issuer itself is free of transfer fees.
+        let pays_issuer = matches!(
+            &offer.offer.taker_pays,
+            Amount::IssuedCurrencyAmount(amount) if amount.issuer == offer.offer.account
+        );
+        if !pays_issuer {
+            taker_pays = transfer_fee.add_to(&taker_pays);
+        }
+        let quality = taker_pays / taker_gets;
+        let price = match side {
+            OrderBookSide::Ask => quality,
+            OrderBookSide::Bid => BigDecimal::one() / quality,
+        };
+
+        Ok(Some(Self {
+            price: price.with_prec(MAX_IOU_PRECISION.into()).normalized(),
+            amount: base_amount.clone(),
+            account: offer.offer.account.clone(),
+            sequence: offer.offer.sequence,
+        }))
+    }
+}
+
+/// Both sides of the order book of a currency pair.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderBook<'a> {
+    /// The currency bought and sold.
+    pub base: Currency<'a>,
+    /// The currency prices are in.
+    pub quote: Currency<'a>,
+    /// The offers to buy the base currency, highest price first.
+    pub bids: Vec<OrderBookEntry<'a>>,
+    /// The offers to sell the base currency, lowest price first.
+    pub asks: Vec<OrderBookEntry<'a>>,
+}
+
+impl<'a> OrderBook<'a> {
+    /// The order book of `base` against `quote`, from the offers
+    /// selling `base` (`asks`) and those buying it (`bids`), as
+    /// listed by `book_offers`. The transfer fees are those of the
+    /// issuers of `base` and `quote`, if any.
+    pub fn from_offers(
+        base: Currency<'a>,
+        quote: Currency<'a>,
+        asks: &[BookOffer<'a>],
+        bids: &[BookOffer<'a>],
+        base_transfer_fee: &Ratio,
+        quote_transfer_fee: &Ratio,
+    ) -> XRPLHelperResult<Self> {
+        let mut order_book = Self {
+            base,
+            quote,
+            bids: entries(bids, OrderBookSide::Bid, base_transfer_fee)?,
+            asks: entries(asks, OrderBookSide::Ask, quote_transfer_fee)?,
+        };
+        order_book
+            .bids
+            .sort_by(|bid, other| other.price.cmp(&bid.price));
+        order_book
+            .asks
+            .sort_by(|ask, other| ask.price.cmp(&other.price));
+
+        Ok(order_book)
+    }
+
+    /// The difference between the lowest ask and the highest bid, if
+    /// both sides have offers.
+    pub fn spread(&self) -> Option<BigDecimal> {
+        Some(&self.asks.first()?.price - &self.bids.first()?.price)
+    }
+}
+
+fn entries<'a>(
+    offers: &[BookOffer<'a>],
+    side: OrderBookSide,
+    transfer_fee: &Ratio,
+) -> XRPLHelperResult<Vec<OrderBookEntry<'a>>> {
+    let mut entries = Vec::new();
+    for offer in offers {
+        if let Some(entry) = OrderBookEntry::from_offer(offer, side, transfer_fee)? {
+            entries.push(entry);
+        }
+    }
+
+    Ok(entries)
+}
+
+/// The value of an amount, with XRP in XRP rather than drops.
+fn amount_value(amount: &Amount<'_>) -> XRPLHelperResult<BigDecimal> {
+    let value: BigDecimal = amount.clone().try_into()?;
+    if amount.is_xrp() {
+        Ok(value / BigDecimal::from(XRP_DROPS))
+    } else {
+        Ok(value)
+    }
+}
+
+/// What an OfferCreate would trade right away against the order
+/// book, before any of it rests in the ledger. XRP is counted in XRP,
+/// not drops.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OfferCrossingEstimate {
+    /// How much of its TakerPays the offer would get.
+    pub taker_pays_crossed: BigDecimal,
+    /// How much of its TakerGets the offer would spend for it,
+    /// transfer fees included.
+    pub taker_gets_crossed: BigDecimal,
+    /// The TakerGets spent per TakerPays got, like the quality of
+    /// the offer. `None` if nothing would cross.
+    pub average_rate: Option<BigDecimal>,
+    /// Whether part of the offer would remain in the ledger as an
+    /// Offer.
+    pub remains: bool,
+}
+
+impl OfferCrossingEstimate {
+    /// Estimate how `offer` would cross `book`, the offers selling
+    /// its TakerPays for its TakerGets, as listed by `book_offers`.
+    ///
+    /// The offers are taken best rate first while their rate is at
+    /// most the rate of `offer`, each limited by what its owner can
+    /// fund. `taker_pays_transfer_fee` is the fee of the issuer of
+    /// the TakerPays of `offer`, which the owners of `book` pay on
+    /// what they deliver; `taker_gets_transfer_fee` is the fee of the
+    /// issuer of its TakerGets, which the account of `offer` pays.
+    ///
+    /// The account of `offer` does not take its own offers, which it
+    /// would cancel instead.
+    pub fn from_book(
+        offer: &OfferCreate<'_>,
+        book: &[BookOffer<'_>],
+        taker_pays_transfer_fee: &Ratio,
+        taker_gets_transfer_fee: &Ratio,
+    ) -> XRPLHelperResult<Self> {
+        let account = offer.common_fields.account.as_ref();
+        let sell = offer.has_flag(&OfferCreateFlag::TfSell);
+        let limit_rate = amount_value(&offer.taker_gets)? / amount_value(&offer.taker_pays)?;
+        let mut wanted = amount_value(&offer.taker_pays)?;
+        let mut budget = amount_value(&offer.taker_gets)?;
+
+        let mut crossable = Vec::new();
+        for book_offer in book {
+            let owner = book_offer.offer.account.as_ref();
+            let taker_gets = amount_value(&book_offer.offer.taker_gets)?;
+            if owner == account || taker_gets.is_zero() {
+                continue;
+            }
+            let mut rate = amount_value(&book_offer.offer.taker_pays)? / taker_gets;
+            if charges_transfer_fee(&offer.taker_gets, account, owner) {
+                rate = taker_gets_transfer_fee.add_to(&rate);
+            }
+            // A passive offer does not take offers at its own rate.
+            let crosses = if offer.has_flag(&OfferCreateFlag::TfPassive) {
+                rate < limit_rate
+            } else {
+                rate <= limit_rate
+            };
+            if crosses {
+                crossable.push((rate, book_offer));
+            }
+        }
+        crossable.sort_by(|(rate, _), (other, _)| rate.cmp(other));
+
+        let mut owner_funds = BTreeMap::new();
+        let mut taker_pays_crossed = BigDecimal::zero();
+        let mut taker_gets_crossed = BigDecimal::zero();
+        for (rate, book_offer) in crossable {
+            if budget <= BigDecimal::zero() || (!sell && wanted <= BigDecimal::zero()) {
+                break;
+            }
+            let owner = book_offer.offer.account.as_ref();
+            let delivered = &book_offer.offer.taker_gets;
+            // The owner pays the transfer fee on what it delivers out
+            // of its funds.
+            let fee = if charges_transfer_fee(delivered, owner, account) {
+                taker_pays_transfer_fee.add_to(&BigDecimal::one())
+            } else {
+                BigDecimal::one()
+            };
+            if let Some(funds) = &book_offer.owner_funds {
+                let mut funds = BigDecimal::from_str(funds).map_err(XRPLModelException::from)?;
+                if delivered.is_xrp() {
+                    funds = funds / BigDecimal::from(XRP_DROPS);
+                }
+                owner_funds.entry(owner).or_insert(funds);
+            }
+            let mut available =
+                amount_value(book_offer.taker_gets_funded.as_ref().unwrap_or(delivered))?;
+            if let Some(funds) = owner_funds.get(owner) {
+                available = available.min(funds / &fee);
+            }
+
+            let mut crossed = available;
+            if !sell && crossed > wanted {
+                crossed = wanted.clone();
+            }
+            let mut cost = &crossed * &rate;
+            if cost > budget {
+                cost = budget.clone();
+                crossed = &cost / &rate;
+            }
+            if let Some(funds) = owner_funds.get_mut(owner) {
+                *funds -= &crossed * &fee;
+            }
+            wanted -= &crossed;
+            budget -= &cost;
+            taker_pays_crossed += crossed;
+            taker_gets_crossed += cost;
+        }
+
+        let filled = if sell {
+            budget <= BigDecimal::zero()
+        } else {
+            wanted <= BigDecimal::zero()
+        };
+        // A Fill or Kill offer that cannot be filled trades nothing.
+        if !filled && offer.has_flag(&OfferCreateFlag::TfFillOrKill) {
+            return Ok(Self {
+                taker_pays_crossed: BigDecimal::zero(),
+                taker_gets_crossed: BigDecimal::zero(),
+                average_rate: None,
+                remains: false,
+            });
+        }
+        let average_rate = (!taker_pays_crossed.is_zero()).then(|| {
+            (&taker_gets_crossed / &taker_pays_crossed)
+                .with_prec(MAX_IOU_PRECISION.into())
+                .normalized()
+        });
+
+        Ok(Self {
+            taker_pays_crossed: round(taker_pays_crossed),
+            taker_gets_crossed: round(taker_gets_crossed),
+            average_rate,
+            remains: !filled && !offer.has_flag(&OfferCreateFlag::TfImmediateOrCancel),
+        })
+    }
+}
+
+/// Whether moving `amount` from `from` to `to` pays a transfer fee:
+/// it is issued by someone else than both of them.
+fn charges_transfer_fee(amount: &Amount<'_>, from: &str, to: &str) -> bool {
+    matches!(
+        amount,
+        Amount::IssuedCurrencyAmount(amount) if amount.issuer != from && amount.issuer != to
+    )
+}
+
+fn round(value: BigDecimal) -> BigDecimal {
+    value.with_prec(MAX_IOU_PRECISION.into()).normalized()
+}
+
+fn amount_currency<'a>(amount: &Amount<'a>) -> Currency<'a> {
+    match amount {
+        Amount::XRPAmount(amount) => amount.into(),
+        Amount::IssuedCurrencyAmount(amount) => amount.into(),
+    }
+}
+
+/// Get the order book of `taker_gets` against `taker_pays` in the
+/// latest validated ledger, with up to `depth` offers on each side.
+///
+/// The asks sell `taker_gets` for `taker_pays`, the bids buy it, and
+/// prices are in `taker_pays` per `taker_gets`. They include the
+/// transfer fees of the issuers, looked up with `account_info`.
+pub async fn get_order_book<'a: 'b, 'b, C>(
+    client: &C,
+    taker_gets: Currency<'a>,
+    taker_pays: Currency<'a>,
+    depth: u16,
+) -> XRPLHelperResult<OrderBook<'b>>
+where
+    C: XRPLAsyncClient,
+{
+    let asks = get_book_offers(client, taker_gets.clone(), taker_pays.clone(), depth).await?;
+    let bids = get_book_offers(client, taker_pays.clone(), taker_gets.clone(), depth).await?;
+    let base_transfer_fee = get_transfer_fee(client, &taker_gets).await?;
+    let quote_transfer_fee = get_transfer_fee(client, &taker_pays).await?;
+    let mut order_book = OrderBook::from_offers(
+        taker_gets,
+        taker_pays,
+        &asks,
+        &bids,
+        &base_transfer_fee,
+        &quote_transfer_fee,
+    )?;
+    order_book.bids.truncate(depth.into());
+    order_book.asks.truncate(depth.into());
+
+    Ok(order_book)
+}
+
+/// The number of offers of the opposing book [`estimate_offer_crossing`]
+/// looks at.
+const CROSSING_DEPTH: u16 = 100;
+
+/// Estimate what `offer` would trade right away against the opposing
+/// order book in the latest validated ledger, see
+/// [`OfferCrossingEstimate::from_book`]. The transfer fees of the
+/// issuers are looked up with `account_info`.
+///
+/// This is an estimate: the book can change before `offer` is
+/// applied, and only the best offers of the book are looked at.
+pub async fn estimate_offer_crossing<C>(
+    client: &C,
+    offer: &OfferCreate<'_>,
+) -> XRPLHelperResult<OfferCrossingEstimate>
+where
+    C: XRPLAsyncClient,
+{
+    let taker_pays = amount_currency(&offer.taker_pays);
+    let taker_gets = amount_currency(&offer.taker_gets);
+    let book = get_book_offers(
+        client,
+        taker_pays.clone(),
+        taker_gets.clone(),
+        CROSSING_DEPTH,
+    )
+    .await?;
+    let taker_pays_transfer_fee = get_transfer_fee(client, &taker_pays).await?;
+    let taker_gets_transfer_fee = get_transfer_fee(client, &taker_gets).await?;
+
+    OfferCrossingEstimate::from_book(
+        offer,
+        &book,
+        &taker_pays_transfer_fee,
+        &taker_gets_transfer_fee,
+    )
+}
+
+async fn get_book_offers<'a, C>(
+    client: &C,
+    taker_gets: Currency<'a>,
+    taker_pays: Currency<'a>,
+    depth: u16,
+) -> XRPLHelperResult<Vec<BookOffer<'a>>>
+where
+    C: XRPLAsyncClient,
+{
+    let request = BookOffers::new(
+        None,
+        taker_gets,
+        taker_pays,
+        None,
+        Some("validated".into()),
+        Some(depth),
+        None,
+    );
+    let response = client.request(request.into()).await?;
+
+    Ok(response.try_into_result::<BookOffersResult<'_>>()?.offers)
+}
+
+/// The transfer fee the issuer of `currency` charges. XRP has none.
+async fn get_transfer_fee<C>(client: &C, currency: &Currency<'_>) -> XRPLHelperResult<Ratio>
+where
+    C: XRPLAsyncClient,
+{
+    match currency {
+        Currency::XRP(_) => Ok(Ratio::from_basis_points(0)),
+        Currency::IssuedCurrency(issued_currency) => {
+            let issuer =
+                get_account_root(issued_currency.issuer.clone(), client, "validated".into())
+                    .await?;
+
+            Ok(transfer_rate_to_ratio(issuer.transfer_rate.unwrap_or(0))?)
+        }
+    }
+}
+
+/// The id of the `subscribe` request of [`subscribe_order_book`].
+#[cfg(feature = "websocket")]
+const SUBSCRIBE_ID: &str = "subscribe_order_book";
+
+/// Subscribe to both sides of the order book of `taker_gets` against
+/// `taker_pays`, as seen by `taker`, to keep an [`OrderBook`] live:
+/// call [`get_order_book`] again after each
+/// [`wait_for_order_book_update`].
+#[cfg(feature = "websocket")]
+pub async fn subscribe_order_book<'a>(
+    client: &mut impl XRPLAsyncWebsocketIO,
+    taker_gets: Currency<'a>,
+    taker_pays: Currency<'a>,
+    taker: Cow<'a, str>,
+) -> XRPLHelperResult<()> {
+    let book = SubscribeBook::new(taker, taker_gets, taker_pays, Some(true), Some(false));
+    let subscribe = Subscribe::new(
+        Some(SUBSCRIBE_ID.into()),
+        None,
+        None,
+        Some(vec![book]),
+        None,
+        None,
+        None,
+        None,
+    );
+    client.xrpl_send(subscribe.into()).await?;
+
+    Ok(())
+}
+
+/// Wait for the next transaction changing an order book the client
+/// subscribed to with [`subscribe_order_book`], and return its hash.
+#[cfg(feature = "websocket")]
+pub async fn wait_for_order_book_update(
+    client: &mut impl XRPLAsyncWebsocketIO,
+) -> XRPLHelperResult<String> {
+    loop {
+        let message = client
+            .xrpl_receive()
+            .await?
+            .ok_or(XRPLClientException::from(
+                XRPLWebSocketException::Disconnected,
+            ))?;
+        let Some(result) = message.result else {
+            continue;
+        };
+        let result: Value = result.try_into()?;
+        if result["type"] != "transaction" {
+            continue;
+        }
+        // API v2 moves the hash out of the transaction.
+        if let Some(hash) = result["transaction"]["hash"]
+            .as_str()
+            .or(result["hash"].as_str())
+        {
+            return Ok(hash.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use serde_json::json;
+
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::models::{IssuedCurrency, IssuedCurrencyAmount};
+
+    const USD_ISSUER: &str = "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B";
+    const EUR_ISSUER: &str = "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq";
+
+    fn usd() -> Currency<'static> {
+        IssuedCurrency::new("USD".into(), USD_ISSUER.into()).into()
+    }
+
+    fn eur() -> Currency<'static> {
+        IssuedCurrency::new("EUR".into(), EUR_ISSUER.into()).into()
+    }
+
+    fn book_offer(
+        account: &str,
+        sequence: u32,
+        taker_gets: serde_json::Value,
+        taker_pays: serde_json::Value,
+        funded: Option<(serde_json::Value, serde_json::Value)>,
+    ) -> BookOffer<'static> {
+        let mut offer = json!({
+            "Account": account,
+            "BookDirectory": "7E5F614417C2D0A7CEFEB73C4AA773ED5B078DE2B5771F6D55055E4C405218EB",
+            "BookNode": "0000000000000000",
+            "Flags": 0,
+            "LedgerEntryType": "Offer",
+            "OwnerNode": "0000000000000000",
+            "PreviousTxnID": "6956221794397C25A53647182E5C78A439766D600724074C99D78982E37599F1",
+            "PreviousTxnLgrSeq": 7022646,
+            "Sequence": sequence,
+            "TakerGets": taker_gets,
+            "TakerPays": taker_pays
+        });
+        if let Some((taker_gets_funded, taker_pays_funded)) = funded {
+            offer["taker_gets_funded"] = taker_gets_funded;
+            offer["taker_pays_funded"] = taker_pays_funded;
+        }
+
+        serde_json::from_value(offer).unwrap()
+    }
+
+    fn iou(currency: &str, issuer: &str, value: &str) -> serde_json::Value {
+        json!({ "currency": currency, "issuer": issuer, "value": value })
+    }
+
+    fn decimal(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_xrp_book_prices() {
+        // Asks sell XRP for USD, bids buy XRP with USD.
+        let asks = [
+            book_offer(
+                "rM3X3QSr8icjTGpaF52dozhbT2BZSXJQYM",
+                5,
+                json!("20000000"),
+                iou("USD", USD_ISSUER, "11"),
+                None,
+            ),
+            book_offer(
+                "rpXhhWmCvDwkzNtRbm7mmD1vZqdfatQNEe",
+                7,
+                json!("10000000"),
+                iou("USD", USD_ISSUER, "5"),
+                None,
+            ),
+        ];
+        let bids = [
+            // Only 2 of the 4 USD are funded.
+            book_offer(
+                "rM3X3QSr8icjTGpaF52dozhbT2BZSXJQYM",
+                6,
+                iou("USD", USD_ISSUER, "4"),
+                json!("10000000"),
+                Some((iou("USD", USD_ISSUER, "2"), json!("5000000"))),
+            ),
+            book_offer(
+                "rpXhhWmCvDwkzNtRbm7mmD1vZqdfatQNEe",
+                8,
+                iou("USD", USD_ISSUER, "9"),
+                json!("20000000"),
+                None,
+            ),
+        ];
+        let order_book = OrderBook::from_offers(
+            Currency::default(),
+            usd(),
+            &asks,
+            &bids,
+            &Ratio::from_basis_points(0),
+            &Ratio::from_basis_points(0),
+        )
+        .unwrap();
+
+        // 5 USD for 10 XRP, then 11 USD for 20 XRP.
+        assert_eq!(order_book.asks[0].price, decimal("0.5"));
+        assert_eq!(order_book.asks[0].sequence, 7);
+        assert_eq!(order_book.asks[1].price, decimal("0.55"));
+        assert_eq!(
+            order_book.asks[1].amount,
+            Amount::XRPAmount("20000000".into())
+        );
+        // 9 USD for 20 XRP, then 4 USD for 10 XRP.
+        assert_eq!(order_book.bids[0].price, decimal("0.45"));
+        assert_eq!(
+            order_book.bids[0].account,
+            "rpXhhWmCvDwkzNtRbm7mmD1vZqdfatQNEe"
+        );
+        assert_eq!(order_book.bids[1].price, decimal("0.4"));
+        assert_eq!(
+            order_book.bids[1].amount,
+            Amount::XRPAmount("5000000".into())
+        );
+        assert_eq!(order_book.spread(), Some(decimal("0.05")));
+    }
+
+    #[test]
+    fn test_iou_book_prices_with_transfer_fees() {
+        // Asks sell EUR for USD, bids buy EUR with USD. USD has a
+        // transfer fee of 0.2%, EUR of 0.5%.
+        let asks = [
+            book_offer(
+                "rM3X3QSr8icjTGpaF52dozhbT2BZSXJQYM",
+                1,
+                iou("EUR", EUR_ISSUER, "10"),
+                iou("USD", USD_ISSUER, "12"),
+                None,
+            ),
+            // Asks more USD, but the taker pays the issuer of USD
+            // itself, without the fee.
+            book_offer(
+                USD_ISSUER,
+                2,
+                iou("EUR", EUR_ISSUER, "10"),
+                iou("USD", USD_ISSUER, "12.02"),
+                None,
+            ),
+            // Unfunded.
+            book_offer(
+                "rpXhhWmCvDwkzNtRbm7mmD1vZqdfatQNEe",
+                3,
+                iou("EUR", EUR_ISSUER, "10"),
+                iou("USD", USD_ISSUER, "11"),
+                Some((iou("EUR", EUR_ISSUER, "0"), iou("USD", USD_ISSUER, "0"))),
+            ),
+        ];
+        let bids = [book_offer(
+            "rpXhhWmCvDwkzNtRbm7mmD1vZqdfatQNEe",
+            4,
+            iou("USD", USD_ISSUER, "11"),
+            iou("EUR", EUR_ISSUER, "10"),
+            None,
+        )];
+        let order_book = OrderBook::from_offers(
+            eur(),
+            usd(),
+            &asks,
+            &bids,
+            &Ratio::from_basis_points(50),
+            &Ratio::from_basis_points(20),
+        )
+        .unwrap();
+
+        assert_eq!(order_book.asks.len(), 2);
+        // 12.02 / 10
+        assert_eq!(order_book.asks[0].price, decimal("1.202"));
+        assert_eq!(order_book.asks[0].sequence, 2);
+        // 12 * 1.002 / 10
+        assert_eq!(order_book.asks[1].price, decimal("1.2024"));
+        assert_eq!(order_book.asks[1].sequence, 1);
+        // 11 / (10 * 1.005), rounded to 16 significant digits.
+        assert_eq!(order_book.bids[0].price, decimal("1.094527363184080"));
+        assert_eq!(
+            order_book.bids[0].amount,
+            Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "EUR".into(),
+                EUR_ISSUER.into(),
+                "10".into()
+            ))
+        );
+    }
+
+    const TRADER: &str = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf";
+
+    fn offer_create(
+        taker_gets: serde_json::Value,
+        taker_pays: serde_json::Value,
+        flags: u32,
+    ) -> OfferCreate<'static> {
+        serde_json::from_value(json!({
+            "Account": TRADER,
+            "Flags": flags,
+            "TakerGets": taker_gets,
+            "TakerPays": taker_pays,
+            "TransactionType": "OfferCreate"
+        }))
+        .unwrap()
+    }
+
+    fn owner_funds(mut offer: BookOffer<'static>, funds: &str) -> BookOffer<'static> {
+        offer.owner_funds = Some(funds.to_string().into());
+        offer
+    }
+
+    #[test]
+    fn test_estimate_crossing_partially_funded() {
+        // Offers selling XRP for USD, against an offer of 10 USD for
+        // 20 XRP, so at most 0.5 USD per XRP.
+        let book = [
+            // The trader's own offer is not taken.
+            book_offer(
+                TRADER,
+                1,
+                json!("10000000"),
+                iou("USD", USD_ISSUER, "1"),
+                None,
+            ),
+            book_offer(
+                "rM3X3QSr8icjTGpaF52dozhbT2BZSXJQYM",
+                5,
+                json!("10000000"),
+                iou("USD", USD_ISSUER, "4"),
+                None,
+            ),
+            // Only 6 of the 20 XRP are funded.
+            owner_funds(
+                book_offer(
+                    "rpXhhWmCvDwkzNtRbm7mmD1vZqdfatQNEe",
+                    7,
+                    json!("20000000"),
+                    iou("USD", USD_ISSUER, "10"),
+                    Some((json!("6000000"), iou("USD", USD_ISSUER, "3"))),
+                ),
+                "6000000",
+            ),
+            // 0.6 USD per XRP, too expensive.
+            book_offer(
+                "rM3X3QSr8icjTGpaF52dozhbT2BZSXJQYM",
+                8,
+                json!("10000000"),
+                iou("USD", USD_ISSUER, "6"),
+                None,
+            ),
+        ];
+        let no_fee = Ratio::from_basis_points(0);
+        let estimate = |flags: u32| {
+            let offer = offer_create(iou("USD", USD_ISSUER, "10"), json!("20000000"), flags);
+            OfferCrossingEstimate::from_book(&offer, &book, &no_fee, &no_fee).unwrap()
+        };
+
+        assert_eq!(
+            estimate(0),
+            OfferCrossingEstimate {
+                taker_pays_crossed: decimal("16"),
+                taker_gets_crossed: decimal("7"),
+                average_rate: Some(decimal("0.4375")),
+                remains: true,
+            }
+        );
+        assert!(!estimate(OfferCreateFlag::TfImmediateOrCancel as u32).remains);
+        assert_eq!(
+            estimate(OfferCreateFlag::TfFillOrKill as u32),
+            OfferCrossingEstimate {
+                taker_pays_crossed: BigDecimal::zero(),
+                taker_gets_crossed: BigDecimal::zero(),
+                average_rate: None,
+                remains: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_estimate_crossing_with_transfer_fees() {
+        // Offers selling EUR for USD. USD has a transfer fee of 1%,
+        // EUR of 0.5%.
+        let book = [
+            // 5.025 EUR fund 5 EUR with the fee of the issuer.
+            owner_funds(
+                book_offer(
+                    "rM3X3QSr8icjTGpaF52dozhbT2BZSXJQYM",
+                    1,
+                    iou("EUR", EUR_ISSUER, "10"),
+                    iou("USD", USD_ISSUER, "10"),
+                    None,
+                ),
+                "5.025",
+            ),
+            // The first offer used up the funds of the owner.
+            book_offer(
+                "rM3X3QSr8icjTGpaF52dozhbT2BZSXJQYM",
+                2,
+                iou("EUR", EUR_ISSUER, "10"),
+                iou("USD", USD_ISSUER, "10"),
+                None,
+            ),
+            // Asks more USD, but the trader pays the issuer of USD
+            // itself, without the fee.
+            owner_funds(
+                book_offer(
+                    USD_ISSUER,
+                    3,
+                    iou("EUR", EUR_ISSUER, "10"),
+                    iou("USD", USD_ISSUER, "10.1"),
+                    None,
+                ),
+                "100",
+            ),
+            // 10.05 * 1.01 / 10 USD per EUR, too expensive.
+            book_offer(
+                "rpXhhWmCvDwkzNtRbm7mmD1vZqdfatQNEe",
+                4,
+                iou("EUR", EUR_ISSUER, "10"),
+                iou("USD", USD_ISSUER, "10.05"),
+                None,
+            ),
+        ];
+        let offer = offer_create(
+            iou("USD", USD_ISSUER, "20.2"),
+            iou("EUR", EUR_ISSUER, "20"),
+            0,
+        );
+
+        let estimate = OfferCrossingEstimate::from_book(
+            &offer,
+            &book,
+            &Ratio::from_basis_points(50),
+            &Ratio::from_basis_points(100),
+        )
+        .unwrap();
+        assert_eq!(
+            estimate,
+            OfferCrossingEstimate {
+                taker_pays_crossed: decimal("15"),
+                taker_gets_crossed: decimal("15.15"),
+                average_rate: Some(decimal("1.01")),
+                remains: true,
+            }
+        );
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_get_order_book {
+    use core::str::FromStr;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        asynch::clients::{MockClient, MockResponse},
+        models::{
+            requests::{RequestMethod, XRPLRequest},
+            IssuedCurrency,
+        },
+    };
+
+    #[tokio::test]
+    async fn test_get_order_book() {
+        let offer = |sequence: u32, taker_gets: Value, taker_pays: Value| {
+            json!({
+                "Account": "rM3X3QSr8icjTGpaF52dozhbT2BZSXJQYM",
+                "BookDirectory": "7E5F614417C2D0A7CEFEB73C4AA773ED5B078DE2B5771F6D55055E4C405218EB",
+                "BookNode": "0000000000000000",
+                "Flags": 0,
+                "LedgerEntryType": "Offer",
+                "OwnerNode": "0000000000000000",
+                "PreviousTxnID": "6956221794397C25A53647182E5C78A439766D600724074C99D78982E37599F1",
+                "PreviousTxnLgrSeq": 7022646,
+                "Sequence": sequence,
+                "TakerGets": taker_gets,
+                "TakerPays": taker_pays
+            })
+        };
+        let usd = |value: &str| {
+            json!({
+                "currency": "USD",
+                "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                "value": value
+            })
+        };
+        let client = MockClient::new()
+            .with_response(
+                RequestMethod::BookOffers,
+                MockResponse::result(json!({
+                    "ledger_index": 100,
+                    "offers": [
+                        offer(1, json!("10000000"), usd("5")),
+                        offer(2, json!("10000000"), usd("6"))
+                    ],
+                    "validated": true
+                })),
+            )
+            .with_response(
+                RequestMethod::BookOffers,
+                MockResponse::result(json!({
+                    "ledger_index": 100,
+                    "offers": [offer(3, usd("4"), json!("10000000"))],
+                    "validated": true
+                })),
+            )
+            .with_response(
+                RequestMethod::AccountInfo,
+                MockResponse::result(json!({
+                    "account_data": {
+                        "Account": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                        "Balance": "1000000000",
+                        "Flags": 0,
+                        "LedgerEntryType": "AccountRoot",
+                        "OwnerCount": 0,
+                        "PreviousTxnID": "6956221794397C25A53647182E5C78A439766D600724074C99D78982E37599F1",
+                        "PreviousTxnLgrSeq": 7022646,
+                        "Sequence": 1,
+                        "TransferRate": 1010000000,
+                        "index": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8"
+                    },
+                    "ledger_index": 100,
+                    "validated": true
+                })),
+            );
+
+        let order_book = get_order_book(
+            &client,
+            Currency::default(),
+            IssuedCurrency::new("USD".into(), "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into()).into(),
+            1,
+        )
+        .await
+        .unwrap();
+
+        let requests = client.requests_of(RequestMethod::BookOffers);
+        let XRPLRequest::BookOffers(asks_request) = &requests[0] else {
+            panic!("unexpected request: {:?}", requests[0]);
+        };
+        assert_eq!(asks_request.taker_gets, Currency::default());
+        assert_eq!(asks_request.limit, Some(1));
+        // The 1% transfer fee of USD is paid on asks, not on bids,
+        // which pay XRP.
+        assert_eq!(order_book.asks.len(), 1);
+        assert_eq!(
+            order_book.asks[0].price,
+            BigDecimal::from_str("0.505").unwrap()
+        );
+        assert_eq!(
+            order_book.bids[0].price,
+            BigDecimal::from_str("0.4").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_estimate_offer_crossing() {
+        let client = MockClient::new()
+            .with_response(
+                RequestMethod::BookOffers,
+                MockResponse::result(json!({
+                    "ledger_index": 100,
+                    "offers": [{
+                        "Account": "rM3X3QSr8icjTGpaF52dozhbT2BZSXJQYM",
+                        "BookDirectory": "7E5F614417C2D0A7CEFEB73C4AA773ED5B078DE2B5771F6D55055E4C405218EB",
+                        "BookNode": "0000000000000000",
+                        "Flags": 0,
+                        "LedgerEntryType": "Offer",
+                        "OwnerNode": "0000000000000000",
+                        "PreviousTxnID": "6956221794397C25A53647182E5C78A439766D600724074C99D78982E37599F1",
+                        "PreviousTxnLgrSeq": 7022646,
+                        "Sequence": 1,
+                        "TakerGets": "10000000",
+                        "TakerPays": {
+                            "currency": "USD",
+                            "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                            "value": "5"
+                        }
+                    }],
+                    "validated": true
+                })),
+            )
+            .with_response(
+                RequestMethod::AccountInfo,
+                MockResponse::result(json!({
+                    "account_data": {
+                        "Account": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                        "Balance": "1000000000",
+                        "Flags": 0,
+                        "LedgerEntryType": "AccountRoot",
+                        "OwnerCount": 0,
+                        "PreviousTxnID": "6956221794397C25A53647182E5C78A439766D600724074C99D78982E37599F1",
+                        "PreviousTxnLgrSeq": 7022646,
+                        "Sequence": 1,
+                        "TransferRate": 1010000000,
+                        "index": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8"
+                    },
+                    "ledger_index": 100,
+                    "validated": true
+                })),
+            );
+        // 5.05 USD for 10 XRP pays for the 5 USD of the offer and the
+        // 1% transfer fee.
+        let offer: OfferCreate = serde_json::from_value(json!({
+            "Account": "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+            "TakerGets": {
+                "currency": "USD",
+                "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                "value": "5.05"
+            },
+            "TakerPays": "10000000",
+            "TransactionType": "OfferCreate"
+        }))
+        .unwrap();
+
+        let estimate = estimate_offer_crossing(&client, &offer).await.unwrap();
+
+        let XRPLRequest::BookOffers(request) = &client.requests_of(RequestMethod::BookOffers)[0]
+        else {
+            unreachable!()
+        };
+        assert_eq!(request.taker_gets, Currency::default());
+        assert_eq!(estimate.taker_pays_crossed, BigDecimal::from(10));
+        assert_eq!(
+            estimate.taker_gets_crossed,
+            BigDecimal::from_str("5.05").unwrap()
+        );
+        assert!(!estimate.remains);
+    }
+}
+
+#[cfg(all(test, feature = "websocket"))]
+mod test_subscribe_order_book {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        asynch::clients::MockWebsocket,
+        models::{requests::XRPLRequest, IssuedCurrency},
+    };
+
+    #[tokio::test]
+    async fn test_subscribe_order_book() {
+        let mut websocket = MockWebsocket::new().with_messages([
+            json!({
+                "ledger_index": 11,
+                "type": "ledgerClosed"
+            }),
+            json!({
+                "engine_result": "tesSUCCESS",
+                "ledger_index": 12,
+                "meta": { "TransactionResult": "tesSUCCESS" },
+                "transaction": {
+                    "Account": "rM3X3QSr8icjTGpaF52dozhbT2BZSXJQYM",
+                    "Fee": "12",
+                    "Sequence": 9,
+                    "TakerGets": "10000000",
+                    "TakerPays": {
+                        "currency": "USD",
+                        "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                        "value": "5"
+                    },
+                    "TransactionType": "OfferCreate",
+                    "hash": "E08D6E9754025BA2534A78707605E0601F03ACE063687A0CA1BDDACFCD1698C7"
+                },
+                "type": "transaction",
+                "validated": true
+            }),
+        ]);
+        subscribe_order_book(
+            &mut websocket,
+            Currency::default(),
+            IssuedCurrency::new("USD".into(), "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into()).into(),
+            "rM3X3QSr8icjTGpaF52dozhbT2BZSXJQYM".into(),
+        )
+        .await
+        .unwrap();
+
+        let XRPLRequest::Subscribe(subscribe) = &websocket.requests()[0] else {
+            panic!("expected subscribe");
+        };
+        let books = &serde_json::to_value(subscribe).unwrap()["books"];
+        assert_eq!(books[0]["taker"], "rM3X3QSr8icjTGpaF52dozhbT2BZSXJQYM");
+        assert_eq!(books[0]["taker_gets"], json!({ "currency": "XRP" }));
+        assert_eq!(books[0]["both"], true);
+        assert_eq!(
+            wait_for_order_book_update(&mut websocket).await.unwrap(),
+            "E08D6E9754025BA2534A78707605E0601F03ACE063687A0CA1BDDACFCD1698C7"
+        );
+        assert_eq!(websocket.pending_messages(), 0);
+    }
+}
@@ -12,6 +12,10 @@ pub enum XRPLTransactionHelperException {
     ParseRippledVersionError(ParseIntError),
     #[error("Invalid rippled version: {0}")]
     InvalidRippledVersion(String),
+    #[error("The server does not support simulating transactions")]
+    SimulateNotSupported,
+    #[error("Only unsigned transactions can be simulated")]
+    SimulateSignedTransaction,
     #[error("XRPL Sign Transaction error: {0}")]
     XRPLSignTransactionError(#[from] XRPLSignTransactionException),
     #[error("XRPL Submit and Wait error: {0}")]
@@ -21,12 +25,16 @@ pub enum XRPLTransactionHelperException {
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
 #[non_exhaustive]
 pub enum XRPLSignTransactionException {
+    /// No longer returned: signing reports
+    /// [`XRPLTransactionFieldException::TagFieldMismatch`](crate::models::transactions::exceptions::XRPLTransactionFieldException::TagFieldMismatch).
     #[error("{0:?} value does not match X-Address tag")]
     TagFieldMismatch(String),
-    #[error("Fee value of {0:?} is likely entered incorrectly, since it is much larger than the typical XRP transaction cost. If this is intentional, use `check_fee=Some(false)`.")]
-    FeeTooHigh(String),
+    #[error("Fee of {fee} drops is above the limit of {limit} drops, so it is likely entered incorrectly. If this is intentional, do not check the fee.")]
+    FeeTooHigh { fee: String, limit: String },
     #[error("Wallet is required to sign transaction")]
     WalletRequired,
+    #[error("Fee is required to sign a transaction without a client")]
+    FeeRequired,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
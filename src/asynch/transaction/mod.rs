@@ -1,31 +1,28 @@
 pub mod exceptions;
+mod signer;
 mod submit_and_wait;
 
 use bigdecimal::{BigDecimal, RoundingMode};
+use signer::sign_payload;
+pub use signer::Signer;
 pub use submit_and_wait::*;
 
 use crate::{
     asynch::{
         account::get_next_valid_seq_number,
         clients::{CommonFields, XRPLAsyncClient},
-        ledger::{get_fee, get_latest_validated_ledger_sequence},
+        ledger::{get_latest_validated_ledger_sequence, get_reserves, FeeStrategy, FeeType},
         transaction::exceptions::XRPLSignTransactionException,
     },
-    core::{
-        addresscodec::{is_valid_xaddress, xaddress_to_classic_address},
-        binarycodec::{encode, encode_for_multisigning, encode_for_signing},
-        keypairs::sign as keypairs_sign,
-    },
+    core::{binarycodec::encode_for_multisigning, keypairs::SigningKey},
     models::{
-        requests::{server_state::ServerState, submit::Submit},
-        results::{server_state::ServerState as ServerStateResult, submit::Submit as SubmitResult},
-        transactions::{
-            exceptions::XRPLTransactionFieldException, Signer, Transaction, TransactionType,
+        requests::{simulate::Simulate, submit::Submit},
+        results::{
+            error::XRPLErrorCode, simulate::Simulate as SimulateResult,
+            submit::Submit as SubmitResult,
         },
-        Model, XRPAmount, XRPLModelException,
-    },
-    utils::transactions::{
-        get_transaction_field_value, set_transaction_field_value, validate_transaction_has_field,
+        transactions::{PreparedTransaction, Signer as SignerEntry, Transaction, TransactionType},
+        Model, XRPAmount,
     },
     wallet::Wallet,
 };
@@ -37,46 +34,172 @@ use alloc::{borrow::Cow, vec};
 use core::convert::TryInto;
 use core::fmt::Debug;
 use exceptions::XRPLTransactionHelperException;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
-use serde::{de::DeserializeOwned, Deserialize};
 use strum::IntoEnumIterator;
 
 use super::exceptions::XRPLHelperResult;
 
 const OWNER_RESERVE: &str = "2000000"; // 2 XRP
+/// The fee [`check_fee`] lets through whatever the network costs, in
+/// drops: 2 XRP, as in xrpl.js.
+pub const DEFAULT_MAX_FEE: &str = "2000000";
 const RESTRICTED_NETWORKS: u16 = 1024;
 const REQUIRED_NETWORKID_VERSION: &str = "1.11.0";
 const LEDGER_OFFSET: u8 = 20;
 
+/// Sign the transaction with the wallet's key. If `multisign` is
+/// true, the signature is added to its `Signers` as the wallet's
+/// account, to be combined with the other signers' signatures;
+/// otherwise it is set as the `TxnSignature`.
+///
+/// The universal `tfFullyCanonicalSig` flag is left as it is on the
+/// transaction: since the RequireFullyCanonicalSig amendment every
+/// signature must be fully canonical, so the flag has no effect. Use
+/// [`sign_fully_canonical`] for tooling that still expects it.
 pub fn sign<'a, T, F>(transaction: &mut T, wallet: &Wallet, multisign: bool) -> XRPLHelperResult<()>
 where
     F: IntoEnumIterator + Serialize + Debug + PartialEq,
     T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone + Debug,
 {
+    sign_with_key(transaction, wallet, &wallet.signing_key()?, multisign)
+}
+
+/// Like [`sign`], setting the universal `tfFullyCanonicalSig` flag
+/// first. The flag is part of what is signed: when multi-signing, it
+/// must be set the same way for every signer.
+pub fn sign_fully_canonical<'a, T, F>(
+    transaction: &mut T,
+    wallet: &Wallet,
+    multisign: bool,
+) -> XRPLHelperResult<()>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone + Debug,
+{
+    transaction
+        .get_mut_common_fields()
+        .flags
+        .set_fully_canonical_sig(true);
+    sign(transaction, wallet, multisign)
+}
+
+/// Like [`sign`], with the wallet's key already parsed.
+pub(crate) fn sign_with_key<'a, T, F>(
+    transaction: &mut T,
+    wallet: &Wallet,
+    signing_key: &SigningKey,
+    multisign: bool,
+) -> XRPLHelperResult<()>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone + Debug,
+{
+    if !multisign {
+        let signed =
+            PreparedTransaction::new(transaction.clone(), wallet.public_key.clone().into())
+                .sign_with_key(signing_key)?;
+        *transaction = signed.into_transaction();
+        return Ok(());
+    }
+
+    let payload = signing_payload(
+        transaction,
+        &wallet.public_key,
+        &wallet.classic_address,
+        true,
+    )?;
+    let signature = signing_key.sign(&payload)?;
+    apply_signature(
+        transaction,
+        signature,
+        &wallet.public_key,
+        &wallet.classic_address,
+        true,
+    );
+
+    Ok(())
+}
+
+/// Like [`sign`], with a [`Signer`] holding the key instead of a
+/// [`Wallet`], e.g. a hardware security module. The transaction is
+/// prepared and encoded the same way, only the signature is made by
+/// the signer.
+pub async fn sign_with<'a, T, F>(
+    transaction: &mut T,
+    signer: &impl Signer,
+    multisign: bool,
+) -> XRPLHelperResult<()>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone + Debug,
+{
+    let public_key = signer.public_key();
+    let account = signer.classic_address()?;
+    let payload = signing_payload(transaction, &public_key, &account, multisign)?;
+    let signature = sign_payload(signer, &payload).await?;
+    apply_signature(transaction, signature, &public_key, &account, multisign);
+
+    Ok(())
+}
+
+/// Prepare the transaction for `public_key` to sign, as `account` if
+/// multi-signing, and return what it signs.
+fn signing_payload<'a, T, F>(
+    transaction: &mut T,
+    public_key: &str,
+    account: &str,
+    multisign: bool,
+) -> XRPLHelperResult<Vec<u8>>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone + Debug,
+{
+    if !multisign {
+        let mut prepared =
+            PreparedTransaction::new(transaction.clone(), public_key.to_string().into());
+        let payload = prepared.signing_payload()?;
+        *transaction = prepared.transaction;
+        return Ok(payload);
+    }
+
     transaction.validate()?;
+    // Multi-signed transactions have an empty SigningPubKey, which
+    // is part of what each signer signs.
+    transaction.set_signing_pub_key("".into());
+
+    Ok(hex::decode(encode_for_multisigning(
+        transaction,
+        account.into(),
+    )?)?)
+}
 
+fn apply_signature<'a, T, F>(
+    transaction: &mut T,
+    signature: String,
+    public_key: &str,
+    account: &str,
+    multisign: bool,
+) where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Serialize + DeserializeOwned + Clone + Debug,
+{
     if multisign {
-        let serialized_for_signing =
-            encode_for_multisigning(transaction, wallet.classic_address.clone().into())?;
-        let serialized_bytes = hex::decode(serialized_for_signing)?;
-        let signature = keypairs_sign(&serialized_bytes, &wallet.private_key)?;
-        let signer = Signer::new(
-            wallet.classic_address.clone().into(),
-            signature.into(),
-            wallet.public_key.clone().into(),
-        );
+        let signer = SignerEntry::new(account.to_string(), signature, public_key.to_string());
         transaction.get_mut_common_fields().signers = Some(vec![signer]);
-
-        Ok(())
     } else {
-        prepare_transaction(transaction, wallet)?;
-        let serialized_for_signing = encode_for_signing(transaction)?;
-        let serialized_bytes = hex::decode(serialized_for_signing)?;
-        let signature = keypairs_sign(&serialized_bytes, &wallet.private_key)?;
-        transaction.get_mut_common_fields().txn_signature = Some(signature.into());
-
-        Ok(())
+        transaction.set_txn_signature(signature.into());
     }
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        hash = (!multisign)
+            .then(|| transaction.get_hash().ok())
+            .flatten()
+            .as_deref(),
+        key_type = crate::_tracing::key_type(public_key),
+        multisign,
+        "signed transaction",
+    );
 }
 
 pub async fn sign_and_submit<'a, 'b, T, F, C>(
@@ -95,7 +218,7 @@ where
         autofill_and_sign(transaction, client, wallet, check_fee).await?;
     } else {
         if check_fee {
-            check_txn_fee(transaction, client).await?;
+            self::check_fee(transaction, client).await?;
         }
         sign(transaction, wallet, false)?;
     }
@@ -112,24 +235,72 @@ where
     F: IntoEnumIterator + Serialize + Debug + PartialEq,
     C: XRPLAsyncClient,
 {
-    let txn = transaction.clone();
-    let txn_common_fields = transaction.get_mut_common_fields();
-    let common_fields = client.get_common_fields().await?;
-    if txn_common_fields.network_id.is_none() && txn_needs_network_id(common_fields.clone())? {
-        txn_common_fields.network_id = common_fields.network_id;
+    autofill_with_fee_strategy(transaction, client, signers_count, &FeeType::Open).await
+}
+
+/// Like [`autofill`], with the network fee from `fee_strategy`, e.g.
+/// a [`FeeMonitor`](crate::asynch::ledger::FeeMonitor), instead of
+/// the open ledger fee the server reports.
+pub async fn autofill_with_fee_strategy<'a, 'b, F, T, C, S>(
+    transaction: &mut T,
+    client: &'b C,
+    signers_count: Option<u8>,
+    fee_strategy: &S,
+) -> XRPLHelperResult<()>
+where
+    T: Transaction<'a, F> + Model + Clone,
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    C: XRPLAsyncClient,
+    S: FeeStrategy,
+{
+    transaction.validate()?;
+    if transaction.get_network_id().is_none() {
+        if let Some(network_id) = client.get_network().and_then(|network| network.network_id) {
+            // The client knows the network, no need to ask the server.
+            if network_id > RESTRICTED_NETWORKS as u32 {
+                transaction.set_network_id(network_id);
+            }
+        } else {
+            // One request gives both the network ID and the server
+            // version, which decide whether the field is needed.
+            let common_fields = client.get_common_fields().await?;
+            if let Some(network_id) = common_fields.network_id {
+                if txn_needs_network_id(common_fields)? {
+                    transaction.set_network_id(network_id);
+                }
+            }
+        }
     }
-    if txn_common_fields.sequence.is_none() {
-        txn_common_fields.sequence =
-            Some(get_next_valid_seq_number(txn_common_fields.account.clone(), client, None).await?);
+    if transaction.get_sequence().is_none() {
+        if transaction.get_ticket_sequence().is_some() {
+            // A ticket takes the place of the sequence.
+            transaction.set_sequence(0);
+        } else {
+            let account = transaction.get_common_fields().account.clone();
+            let sequence = get_next_valid_seq_number(account, client, None).await?;
+            transaction.set_sequence(sequence);
+        }
     }
-    if txn_common_fields.fee.is_none() {
-        txn_common_fields.fee =
-            Some(calculate_fee_per_transaction_type(&txn, Some(client), signers_count).await?);
+    if transaction.get_fee().is_none() {
+        let fee =
+            calculate_fee_with_strategy(&*transaction, Some(client), signers_count, fee_strategy)
+                .await?;
+        transaction.set_fee(fee);
     }
-    if txn_common_fields.last_ledger_sequence.is_none() {
+    if transaction.get_last_ledger_sequence().is_none() {
         let ledger_sequence = get_latest_validated_ledger_sequence(client).await?;
-        txn_common_fields.last_ledger_sequence = Some(ledger_sequence + LEDGER_OFFSET as u32);
+        transaction.set_last_ledger_sequence(ledger_sequence + LEDGER_OFFSET as u32);
     }
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        fee = transaction.get_fee().map(|fee| fee.0.as_ref()),
+        sequence = transaction.get_sequence(),
+        last_ledger_sequence = transaction.get_last_ledger_sequence(),
+        network_id = transaction.get_network_id(),
+        "autofilled transaction",
+    );
+    // Check the filled in fields agree with the ones that were set.
+    transaction.validate()?;
 
     Ok(())
 }
@@ -146,7 +317,7 @@ where
     C: XRPLAsyncClient,
 {
     if check_fee {
-        check_txn_fee(transaction, client).await?;
+        self::check_fee(transaction, client).await?;
     }
     autofill(transaction, client, None).await?;
     sign(transaction, wallet, false)?;
@@ -161,13 +332,46 @@ where
     C: XRPLAsyncClient,
 {
     transaction.validate()?;
-    let txn_blob = encode(transaction)?;
+    let txn_blob = transaction.to_blob()?;
     let req = Submit::new(None, txn_blob.into(), None);
     let res = client.request(req.into()).await?;
 
     Ok(res.try_into_result::<SubmitResult<'_>>()?)
 }
 
+/// Dry-run `transaction` on the server's current ledger, to preview
+/// its result, fee and metadata before signing and submitting it.
+///
+/// The transaction must not be signed. The server fills in missing
+/// auto-fillable fields, like `Fee` and `Sequence`, in the returned
+/// `tx_json`. Servers without the simulate method (before rippled
+/// 2.4) fail with [`XRPLTransactionHelperException::SimulateNotSupported`].
+pub async fn simulate<'a, T, F, C>(
+    client: &C,
+    transaction: &T,
+) -> XRPLHelperResult<SimulateResult<'a>>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Model + Serialize + Clone + Debug,
+    C: XRPLAsyncClient,
+{
+    transaction.validate()?;
+    let common_fields = transaction.get_common_fields();
+    if common_fields.txn_signature.is_some() || common_fields.signers.is_some() {
+        return Err(XRPLTransactionHelperException::SimulateSignedTransaction.into());
+    }
+    let req = Simulate::new(None, Some(serde_json::to_value(transaction)?), None, None);
+    let res = client.request(req.into()).await?;
+    if res
+        .error_response()
+        .is_some_and(|error_response| error_response.error == XRPLErrorCode::UnknownCmd)
+    {
+        return Err(XRPLTransactionHelperException::SimulateNotSupported.into());
+    }
+
+    Ok(res.try_into_result::<SimulateResult<'_>>()?)
+}
+
 pub async fn calculate_fee_per_transaction_type<'a, 'b, 'c, T, F, C>(
     transaction: &T,
     client: Option<&'b C>,
@@ -177,17 +381,32 @@ where
     T: Transaction<'a, F>,
     F: IntoEnumIterator + Serialize + Debug + PartialEq,
     C: XRPLAsyncClient,
+{
+    calculate_fee_with_strategy(transaction, client, signers_count, &FeeType::Open).await
+}
+
+async fn calculate_fee_with_strategy<'a, 'b, 'c, T, F, C, S>(
+    transaction: &T,
+    client: Option<&'b C>,
+    signers_count: Option<u8>,
+    fee_strategy: &S,
+) -> XRPLHelperResult<XRPAmount<'c>>
+where
+    T: Transaction<'a, F>,
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    C: XRPLAsyncClient,
+    S: FeeStrategy,
 {
     let mut net_fee = XRPAmount::from("10");
     let base_fee;
     if let Some(client) = client {
-        net_fee = get_fee(client, None, None).await?;
+        net_fee = fee_strategy.get_net_fee(client).await?;
         base_fee = match transaction.get_transaction_type() {
             TransactionType::EscrowFinish => calculate_base_fee_for_escrow_finish(
                 net_fee.clone(),
                 transaction
-                    .get_field_value("fulfillment")?
-                    .map(|fulfillment| fulfillment.into()),
+                    .get_field_value::<String>("Fulfillment")?
+                    .map(Cow::from),
             )?,
             // TODO: same for TransactionType::AMMCreate
             TransactionType::AccountDelete => get_owner_reserve_from_response(client).await?,
@@ -198,8 +417,8 @@ where
             TransactionType::EscrowFinish => calculate_base_fee_for_escrow_finish(
                 net_fee.clone(),
                 transaction
-                    .get_field_value("fulfillment")?
-                    .map(|fulfillment| fulfillment.into()),
+                    .get_field_value::<String>("Fulfillment")?
+                    .map(Cow::from),
             )?,
             // TODO: same for TransactionType::AMMCreate
             TransactionType::AccountDelete => XRPAmount::from(OWNER_RESERVE),
@@ -221,15 +440,9 @@ where
 async fn get_owner_reserve_from_response(
     client: &impl XRPLAsyncClient,
 ) -> XRPLHelperResult<XRPAmount<'_>> {
-    let owner_reserve_response = client.request(ServerState::new(None).into()).await?;
-    match owner_reserve_response
-        .try_into_result::<ServerStateResult<'_>>()?
-        .state
-        .validated_ledger
-    {
-        Some(validated_ledger) => Ok(validated_ledger.reserve_base),
-        None => Err(XRPLModelException::MissingField("validated_ledger".to_string()).into()),
-    }
+    let (_, owner_reserve) = get_reserves(client).await?;
+
+    Ok(owner_reserve)
 }
 
 fn calculate_base_fee_for_escrow_finish<'a: 'b, 'b>(
@@ -271,14 +484,14 @@ fn txn_needs_network_id(common_fields: CommonFields<'_>) -> XRPLHelperResult<boo
             Ok(is_not_later_rippled_version) => {
                 Ok(is_higher_restricted_networks && is_not_later_rippled_version)
             }
-            Err(e) => Err(e.into()),
+            Err(e) => Err(e),
         }
     } else {
         Ok(false)
     }
 }
 
-fn is_not_later_rippled_version<'a>(source: String, target: String) -> XRPLHelperResult<bool> {
+fn is_not_later_rippled_version(source: String, target: String) -> XRPLHelperResult<bool> {
     if source == target {
         Ok(true)
     } else {
@@ -352,150 +565,137 @@ fn is_not_later_rippled_version<'a>(source: String, target: String) -> XRPLHelpe
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
-enum AccountFieldType {
-    Account,
-    Destination,
-}
-
-async fn check_txn_fee<'a, 'b, T, F, C>(transaction: &mut T, client: &'b C) -> XRPLHelperResult<()>
+/// Fail with [`XRPLSignTransactionException::FeeTooHigh`] if the fee
+/// of `transaction` is above both [`DEFAULT_MAX_FEE`] and what its
+/// type and signers cost on the network now, as
+/// [`calculate_fee_per_transaction_type`] estimates it.
+///
+/// This catches a typo like a 100 XRP fee before signing, while the
+/// higher costs of an EscrowFinish with a fulfillment, an
+/// AccountDelete or a multi-signed transaction are let through.
+pub async fn check_fee<'a, T, F, C>(transaction: &T, client: &C) -> XRPLHelperResult<()>
 where
     F: IntoEnumIterator + Serialize + Debug + PartialEq,
-    T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone,
+    T: Transaction<'a, F>,
     C: XRPLAsyncClient,
 {
-    // max of xrp_to_drops(0.1) and calculate_fee_per_transaction_type
-    let expected_fee = XRPAmount::from("100000")
-        .max(calculate_fee_per_transaction_type(transaction, Some(client), None).await?);
-    let transaction_fee = transaction
-        .get_common_fields()
-        .fee
-        .clone()
-        .unwrap_or(XRPAmount::from("0"));
-    if transaction_fee > expected_fee {
-        Err(XRPLSignTransactionException::FeeTooHigh(transaction_fee.to_string()).into())
-    } else {
-        Ok(())
-    }
-}
-
-fn prepare_transaction<'a, T, F>(transaction: &mut T, wallet: &Wallet) -> XRPLHelperResult<()>
-where
-    F: IntoEnumIterator + Serialize + Debug + PartialEq,
-    T: Transaction<'a, F> + Serialize + DeserializeOwned + Clone,
-{
-    let commond_fields = transaction.get_mut_common_fields();
-    commond_fields.signing_pub_key = Some(wallet.public_key.clone().into());
-
-    validate_account_xaddress(transaction, AccountFieldType::Account)?;
-    if validate_transaction_has_field(transaction, "Destination").is_ok() {
-        validate_account_xaddress(transaction, AccountFieldType::Destination)?;
-    }
-
-    let _ = convert_to_classic_address(transaction, "Unauthorize");
-    let _ = convert_to_classic_address(transaction, "Authorize");
-    // EscrowCancel, EscrowFinish
-    let _ = convert_to_classic_address(transaction, "Owner");
-    // SetRegularKey
-
-    let _ = convert_to_classic_address(transaction, "RegularKey");
-
-    Ok(())
-}
-
-fn validate_account_xaddress<'a, T, F>(
-    prepared_transaction: &mut T,
-    account_field: AccountFieldType,
-) -> XRPLHelperResult<()>
-where
-    F: IntoEnumIterator + Serialize + Debug + PartialEq,
-    T: Transaction<'a, F> + Serialize + DeserializeOwned + Clone,
-{
-    let (account_field_name, tag_field_name) = match serde_json::to_string(&account_field) {
-        Ok(name) => {
-            let name_str = name.as_str().trim();
-            if name_str == "\"Account\"" {
-                ("Account", "SourceTag")
-            } else if name_str == "\"Destination\"" {
-                ("Destination", "DestinationTag")
-            } else {
-                return Err(XRPLTransactionFieldException::UnknownAccountField(
-                    name_str.to_string(),
-                )
-                .into());
-            }
-        }
-        Err(error) => return Err(error.into()),
+    let Some(fee) = transaction.get_fee() else {
+        return Ok(());
     };
-    let account_address = match account_field {
-        AccountFieldType::Account => prepared_transaction.get_common_fields().account.clone(),
-        AccountFieldType::Destination => {
-            get_transaction_field_value(prepared_transaction, "Destination")?
-        }
-    };
-
-    if is_valid_xaddress(&account_address) {
-        let (address, tag, _) = match xaddress_to_classic_address(&account_address) {
-            Ok(t) => t,
-            Err(error) => return Err(error.into()),
-        };
-        validate_transaction_has_field(prepared_transaction, account_field_name)?;
-        set_transaction_field_value(prepared_transaction, account_field_name, address)?;
-
-        if validate_transaction_has_field(prepared_transaction, tag_field_name).is_ok()
-            && get_transaction_field_value(prepared_transaction, tag_field_name).unwrap_or(Some(0))
-                != tag
-        {
-            Err(XRPLSignTransactionException::TagFieldMismatch(tag_field_name.to_string()).into())
-        } else {
-            set_transaction_field_value(prepared_transaction, tag_field_name, tag)?;
-
-            Ok(())
+    let signers_count = transaction
+        .get_common_fields()
+        .signers
+        .as_ref()
+        .map(|signers| signers.len() as u8);
+    let limit = XRPAmount::from(DEFAULT_MAX_FEE)
+        .max(calculate_fee_per_transaction_type(transaction, Some(client), signers_count).await?);
+    if *fee > limit {
+        Err(XRPLSignTransactionException::FeeTooHigh {
+            fee: fee.to_string(),
+            limit: limit.to_string(),
         }
+        .into())
     } else {
         Ok(())
     }
 }
 
-fn convert_to_classic_address<'a, T, F>(
-    transaction: &mut T,
-    field_name: &str,
-) -> XRPLHelperResult<()>
-where
-    F: IntoEnumIterator + Serialize + Debug + PartialEq,
-    T: Transaction<'a, F> + Serialize + DeserializeOwned + Clone,
-{
-    let address = get_transaction_field_value::<F, _, String>(transaction, field_name)?;
-    if is_valid_xaddress(&address) {
-        let classic_address = match xaddress_to_classic_address(&address) {
-            Ok(t) => t.0,
-            Err(error) => return Err(error.into()),
-        };
-        Ok(set_transaction_field_value(
-            transaction,
-            field_name,
-            classic_address,
-        )?)
-    } else {
-        Ok(())
-    }
-}
-
-#[cfg(all(feature = "websocket", feature = "std"))]
-#[cfg(test)]
-mod test_autofill {
-    use super::autofill;
+#[cfg(all(test, feature = "std"))]
+pub(crate) mod test_autofill {
+    use super::{autofill, autofill_and_sign};
     use crate::{
         asynch::{
-            clients::{AsyncWebSocketClient, SingleExecutorMutex},
+            clients::{MockClient, MockResponse, Network},
             exceptions::XRPLHelperResult,
         },
         models::{
+            requests::RequestMethod,
             transactions::{offer_create::OfferCreate, Transaction},
             IssuedCurrencyAmount, XRPAmount,
         },
+        wallet::Wallet,
     };
 
+    /// A testnet server answering the requests of `autofill` and
+    /// `submit_and_wait` for `account`.
+    pub(crate) fn mock_client(account: &str) -> MockClient {
+        MockClient::new()
+            .with_response(
+                RequestMethod::ServerState,
+                MockResponse::result(serde_json::json!({
+                    "state": {
+                        "build_version": "2.3.0",
+                        "server_state": "full"
+                    },
+                    "status": "success"
+                })),
+            )
+            .with_response(
+                RequestMethod::AccountInfo,
+                MockResponse::result(serde_json::json!({
+                    "account_data": {
+                        "Account": account,
+                        "Balance": "100000000",
+                        "Flags": 0,
+                        "LedgerEntryType": "AccountRoot",
+                        "OwnerCount": 0,
+                        "PreviousTxnID": "4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42",
+                        "PreviousTxnLgrSeq": 3,
+                        "Sequence": 227234,
+                        "index": "92FA6A9FC8EA6018D5D16532D7795C91BFB0831355BDFDA177E86C8BF997985F"
+                    },
+                    "ledger_current_index": 4,
+                    "status": "success",
+                    "validated": false
+                })),
+            )
+            .with_response(
+                RequestMethod::Fee,
+                MockResponse::result(serde_json::json!({
+                    "current_ledger_size": "0",
+                    "current_queue_size": "0",
+                    "drops": {
+                        "base_fee": "10",
+                        "median_fee": "5000",
+                        "minimum_fee": "10",
+                        "open_ledger_fee": "10"
+                    },
+                    "expected_ledger_size": "32",
+                    "ledger_current_index": 4,
+                    "levels": {
+                        "median_level": "128000",
+                        "minimum_level": "256",
+                        "open_ledger_level": "256",
+                        "reference_level": "256"
+                    },
+                    "max_queue_size": "640",
+                    "status": "success"
+                })),
+            )
+            .with_response(
+                RequestMethod::Ledger,
+                MockResponse::result(serde_json::json!({
+                    "ledger": {
+                        "account_hash": "B258A8BB4743FB74CBBD6E9F67E4A56C4432EA09E5805E4CC2DA26F2DBE8F3D1",
+                        "close_flags": 0,
+                        "close_time": 638329271,
+                        "close_time_resolution": 10,
+                        "closed": true,
+                        "ledger_hash": "3652D7FD0576BC452C0D2E9B747BDD733075971D1A9A1D98125055DEF428721A",
+                        "ledger_index": "3",
+                        "parent_close_time": 638329270,
+                        "parent_hash": "AE996778246BC81F85D5AF051241DAA577C23BCA04C034A7074F93700194520D",
+                        "total_coins": "99999999999999980",
+                        "transaction_hash": "FC6FFCB71B2527DDD630EE5409D38913B4D4C026AA6C3B14A3E9D4ED45CFE30D"
+                    },
+                    "ledger_hash": "3652D7FD0576BC452C0D2E9B747BDD733075971D1A9A1D98125055DEF428721A",
+                    "ledger_index": 3,
+                    "status": "success",
+                    "validated": true
+                })),
+            )
+    }
+
     #[tokio::test]
     async fn test_autofill_txn() -> XRPLHelperResult<()> {
         let mut txn = OfferCreate::new(
@@ -519,37 +719,166 @@ mod test_autofill {
             None,
             None,
         );
-        let client = AsyncWebSocketClient::<SingleExecutorMutex, _>::open(
-            "wss://testnet.xrpl-labs.com/".parse().unwrap(),
+        let client = mock_client("r9mhdWo1NXVZr2pDnCtC1xwxE85kFtSzYR");
+        autofill(&mut txn, &client, None).await?;
+
+        assert!(txn.get_network_id().is_none());
+        assert_eq!(txn.get_sequence(), Some(227234));
+        // The network ID and the server version come from one request.
+        assert_eq!(client.requests_of(RequestMethod::ServerState).len(), 1);
+        assert!(client.requests_of(RequestMethod::ServerInfo).is_empty());
+        assert_eq!(txn.get_fee(), Some(&XRPAmount::from("10")));
+        assert_eq!(txn.get_last_ledger_sequence(), Some(23));
+        // The next sequence is read from the current ledger.
+        let account_info = &client.requests_of(RequestMethod::AccountInfo)[0];
+        assert_eq!(
+            serde_json::to_value(account_info).unwrap()["ledger_index"],
+            "current"
+        );
+
+        Ok(())
+    }
+
+    pub(crate) fn offer_create() -> OfferCreate<'static> {
+        OfferCreate::new(
+            "r9mhdWo1NXVZr2pDnCtC1xwxE85kFtSzYR".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            XRPAmount::from("1000000").into(),
+            IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq".into(),
+                "0.3".into(),
+            )
+            .into(),
+            None,
+            None,
         )
-        .await
-        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_autofill_network_id_from_network() -> XRPLHelperResult<()> {
+        let mut txn = offer_create();
+        let client =
+            mock_client("r9mhdWo1NXVZr2pDnCtC1xwxE85kFtSzYR").with_network(Network::mainnet());
         autofill(&mut txn, &client, None).await?;
+        assert!(txn.get_network_id().is_none());
 
-        assert!(txn.get_common_fields().network_id.is_none());
-        assert!(txn.get_common_fields().sequence.is_some());
-        assert!(txn.get_common_fields().fee.is_some());
-        assert!(txn.get_common_fields().last_ledger_sequence.is_some());
+        let mut txn = offer_create();
+        let client = mock_client("r9mhdWo1NXVZr2pDnCtC1xwxE85kFtSzYR")
+            .with_network(Network::xahau_testnet());
+        autofill(&mut txn, &client, None).await?;
+        assert_eq!(txn.get_network_id(), Some(21338));
+        // The network ID is not asked from the server.
+        assert!(client.requests_of(RequestMethod::ServerInfo).is_empty());
+        assert!(client.requests_of(RequestMethod::ServerState).is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_autofill_validates_common_fields() {
+        let mut txn = offer_create();
+        txn.common_fields.sequence = Some(3);
+        txn.common_fields.ticket_sequence = Some(5);
+        let client = mock_client("r9mhdWo1NXVZr2pDnCtC1xwxE85kFtSzYR");
+
+        assert!(autofill(&mut txn, &client, None).await.is_err());
+        // Nothing is asked from the server for an invalid transaction.
+        assert!(client.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_autofill_and_sign_with_ticket() -> XRPLHelperResult<()> {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let mut txn = offer_create();
+        txn.common_fields.account = wallet.classic_address.clone().into();
+        txn.common_fields.ticket_sequence = Some(5);
+        let client = mock_client(&wallet.classic_address);
+        autofill_and_sign(&mut txn, &client, &wallet, true).await?;
+
+        assert_eq!(txn.get_sequence(), Some(0));
+        assert_eq!(txn.get_ticket_sequence(), Some(5));
+        assert!(txn.is_signed());
+        // The sequence of the account is not needed.
+        assert!(client.requests_of(RequestMethod::AccountInfo).is_empty());
 
         Ok(())
     }
 }
 
-#[cfg(all(feature = "json-rpc", feature = "std"))]
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod test_sign {
-    use alloc::borrow::Cow;
+    use alloc::{
+        borrow::Cow,
+        string::{String, ToString},
+        vec::Vec,
+    };
 
     use crate::{
         asynch::{
-            clients::AsyncJsonRpcClient,
-            transaction::{autofill_and_sign, sign},
-            wallet::generate_faucet_wallet,
+            exceptions::XRPLHelperResult,
+            transaction::{
+                autofill_and_sign, sign, sign_fully_canonical, sign_with,
+                test_autofill::{mock_client, offer_create},
+                Signer,
+            },
         },
-        models::transactions::{account_set::AccountSet, Transaction},
+        core::{
+            binarycodec::{decode, encode},
+            keypairs::SigningKey,
+        },
+        models::transactions::{account_set::AccountSet, PreparedTransaction, Transaction},
+        transaction::verify_and_decode_blob,
         wallet::Wallet,
     };
 
+    /// A signer holding only the key, as an HSM would.
+    struct InMemorySigner {
+        public_key: String,
+        signing_key: SigningKey,
+    }
+
+    impl Signer for InMemorySigner {
+        fn public_key(&self) -> Cow<'_, str> {
+            self.public_key.as_str().into()
+        }
+
+        async fn sign_digest(&self, digest: &[u8]) -> XRPLHelperResult<Vec<u8>> {
+            Ok(self.signing_key.sign_digest(digest)?)
+        }
+    }
+
+    fn account_set(account: &str) -> AccountSet<'static> {
+        AccountSet::new(
+            account.to_string().into(),
+            None,
+            Some("10".into()),
+            None,
+            None,
+            None,
+            Some(227234),
+            None,
+            None,
+            None,
+            None,
+            Some("6578616d706c652e636f6d".into()), // "example.com"
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
     #[tokio::test]
     async fn test_sign() {
         let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
@@ -582,12 +911,43 @@ mod test_sign {
         assert_eq!(expected_signature, *actual_signature);
     }
 
+    #[test]
+    fn test_sign_offer_create_in_place_and_prepared() {
+        for seed in [
+            "sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5",
+            "sp5fghtJtpUorTwvof1NpDXAzNwf5",
+        ] {
+            let wallet = Wallet::new(seed, 0).unwrap();
+            let mut offer = offer_create();
+            offer.common_fields.account = wallet.classic_address.clone().into();
+            offer.common_fields.fee = Some("10".into());
+            offer.common_fields.sequence = Some(227234);
+
+            let signed = PreparedTransaction::new(offer.clone(), wallet.public_key.clone().into())
+                .sign(&wallet)
+                .unwrap();
+            assert!(signed.verify().unwrap());
+            assert_eq!(wallet.sign(offer.clone()).unwrap(), signed);
+
+            let mut in_place = offer;
+            sign(&mut in_place, &wallet, false).unwrap();
+            let blob = encode(&in_place).unwrap();
+            assert_eq!(signed.blob().unwrap(), blob);
+            assert_eq!(signed.hash().unwrap(), in_place.get_hash().unwrap());
+            assert_eq!(signed.into_transaction(), in_place);
+
+            // A signature by another key does not verify.
+            let other = Wallet::new("snoPBrXtMeMyMHUVTgbuqAfg1SUTb", 0).unwrap();
+            let mut forged = wallet.sign(offer_create()).unwrap();
+            forged.txn_signature = other.sign(offer_create()).unwrap().txn_signature;
+            assert!(!forged.verify().unwrap());
+        }
+    }
+
     #[tokio::test]
     async fn test_autofill_and_sign() {
-        let client = AsyncJsonRpcClient::connect("https://testnet.xrpl-labs.com/".parse().unwrap());
-        let wallet = generate_faucet_wallet(&client, None, None, None, None)
-            .await
-            .unwrap();
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let client = mock_client(&wallet.classic_address);
         let mut tx = AccountSet::new(
             Cow::from(wallet.classic_address.clone()),
             None,
@@ -614,4 +974,334 @@ mod test_sign {
         assert!(tx.get_common_fields().sequence.is_some());
         assert!(tx.get_common_fields().txn_signature.is_some());
     }
+
+    #[tokio::test]
+    async fn test_sign_with() {
+        for seed in [
+            "sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5",
+            "sp5fghtJtpUorTwvof1NpDXAzNwf5",
+        ] {
+            let wallet = Wallet::new(seed, 0).unwrap();
+            let signer = InMemorySigner {
+                public_key: wallet.public_key.clone(),
+                signing_key: wallet.signing_key().unwrap(),
+            };
+            for multisign in [false, true] {
+                let mut expected = account_set(&wallet.classic_address);
+                sign(&mut expected, &wallet, multisign).unwrap();
+
+                let mut tx = account_set(&wallet.classic_address);
+                sign_with(&mut tx, &signer, multisign).await.unwrap();
+                assert_eq!(tx, expected);
+
+                let mut tx = account_set(&wallet.classic_address);
+                sign_with(&mut tx, &wallet, multisign).await.unwrap();
+                assert_eq!(tx, expected);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sign_fully_canonical() {
+        let wallet = Wallet::new("sp5fghtJtpUorTwvof1NpDXAzNwf5", 0).unwrap();
+        let mut tx = account_set(&wallet.classic_address);
+        sign(&mut tx, &wallet, false).unwrap();
+        let mut fully_canonical = account_set(&wallet.classic_address);
+        sign_fully_canonical(&mut fully_canonical, &wallet, false).unwrap();
+
+        let blob = encode(&tx).unwrap();
+        let fully_canonical_blob = encode(&fully_canonical).unwrap();
+        assert!(blob.contains("2200000000"));
+        assert!(fully_canonical_blob.contains("2280000000"));
+        assert_ne!(tx.get_hash().unwrap(), fully_canonical.get_hash().unwrap());
+
+        let decoded: AccountSet =
+            serde_json::from_value(decode(&fully_canonical_blob).unwrap()).unwrap();
+        assert!(decoded.get_common_fields().flags.has_fully_canonical_sig());
+        assert!(verify_and_decode_blob::<AccountSet>(&fully_canonical_blob).is_ok());
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_simulate {
+    use super::{exceptions::XRPLTransactionHelperException, simulate};
+    use crate::{
+        asynch::{
+            clients::{MockClient, MockResponse},
+            exceptions::XRPLError,
+        },
+        models::{
+            requests::{RequestMethod, XRPLRequest},
+            transactions::payment::Payment,
+            Amount, XRPAmount,
+        },
+        utils::txn_parser::get_delivered_amount,
+    };
+
+    const ACCOUNT: &str = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf";
+    const DESTINATION: &str = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe";
+
+    fn payment() -> Payment<'static> {
+        serde_json::from_value(serde_json::json!({
+            "TransactionType": "Payment",
+            "Account": ACCOUNT,
+            "Destination": DESTINATION,
+            "Amount": "1000000"
+        }))
+        .unwrap()
+    }
+
+    fn simulated(engine_result: &str, engine_result_code: i32) -> serde_json::Value {
+        serde_json::json!({
+            "applied": false,
+            "engine_result": engine_result,
+            "engine_result_code": engine_result_code,
+            "engine_result_message": "The simulated transaction would have been applied.",
+            "ledger_index": 3,
+            "meta": {
+                "AffectedNodes": [{
+                    "ModifiedNode": {
+                        "FinalFields": {
+                            "Account": ACCOUNT,
+                            "Balance": "98999988",
+                            "Flags": 0,
+                            "OwnerCount": 0,
+                            "Sequence": 3
+                        },
+                        "LedgerEntryType": "AccountRoot",
+                        "LedgerIndex": "92FA6A9FC8EA6018D5D16532D7795C91BFB0831355BDFDA177E86C8BF997985F",
+                        "PreviousFields": {
+                            "Balance": "100000000",
+                            "Sequence": 2
+                        }
+                    }
+                }],
+                "TransactionIndex": 0,
+                "TransactionResult": engine_result,
+                "delivered_amount": "1000000"
+            },
+            "tx_json": {
+                "TransactionType": "Payment",
+                "Account": ACCOUNT,
+                "Destination": DESTINATION,
+                "Amount": "1000000",
+                "Fee": "12",
+                "Flags": 0,
+                "Sequence": 2,
+                "SigningPubKey": ""
+            },
+            "status": "success"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_simulate_success() {
+        let client = MockClient::new().with_response(
+            RequestMethod::Simulate,
+            MockResponse::result(simulated("tesSUCCESS", 0)),
+        );
+        let tx = payment();
+
+        let result = simulate(&client, &tx).await.unwrap();
+        assert!(result.is_success());
+        assert!(!result.applied);
+        assert_eq!(result.ledger_index, Some(3));
+        assert_eq!(result.tx_json.as_ref().unwrap()["Fee"], "12");
+        let meta = result.meta.unwrap();
+        assert_eq!(meta.affected_nodes.len(), 1);
+        assert_eq!(
+            get_delivered_amount(&meta, &tx),
+            Some(Amount::XRPAmount(XRPAmount::from("1000000")))
+        );
+
+        let requests = client.requests_of(RequestMethod::Simulate);
+        assert_eq!(requests.len(), 1);
+        let XRPLRequest::Simulate(request) = &requests[0] else {
+            panic!("unexpected request: {:?}", requests[0]);
+        };
+        assert_eq!(
+            request.tx_json.as_ref().unwrap()["Destination"],
+            DESTINATION
+        );
+        assert_eq!(request.tx_blob, None);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_failure() {
+        let mut response = simulated("tecNO_DST_INSUF_XRP", 125);
+        response["engine_result_message"] =
+            "Destination does not exist. Too little XRP sent to create it.".into();
+        response["meta"]["AffectedNodes"][0]["ModifiedNode"]["FinalFields"]["Balance"] =
+            "99999988".into();
+        response["meta"]["delivered_amount"] = serde_json::Value::Null;
+        let client = MockClient::new()
+            .with_response(RequestMethod::Simulate, MockResponse::result(response));
+
+        let result = simulate(&client, &payment()).await.unwrap();
+        assert!(!result.is_success());
+        assert_eq!(result.engine_result, "tecNO_DST_INSUF_XRP");
+        assert_eq!(result.engine_result_code, 125);
+        assert_eq!(
+            result.meta.unwrap().transaction_result,
+            "tecNO_DST_INSUF_XRP"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simulate_not_supported() {
+        let client = MockClient::new().with_response(
+            RequestMethod::Simulate,
+            MockResponse::error("unknownCmd", 32, Some("Unknown method.")),
+        );
+
+        assert!(matches!(
+            simulate(&client, &payment()).await,
+            Err(XRPLError::XRPLTransactionHelperError(
+                XRPLTransactionHelperException::SimulateNotSupported
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_simulate_signed_transaction() {
+        let client = MockClient::new();
+        let mut tx = payment();
+        tx.common_fields.txn_signature = Some("DEADBEEF".into());
+
+        assert!(matches!(
+            simulate(&client, &tx).await,
+            Err(XRPLError::XRPLTransactionHelperError(
+                XRPLTransactionHelperException::SimulateSignedTransaction
+            ))
+        ));
+        assert!(client.requests().is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_check_fee {
+    use super::{check_fee, exceptions::XRPLSignTransactionException, test_autofill::mock_client};
+    use crate::{
+        asynch::{
+            clients::{MockClient, MockResponse},
+            exceptions::XRPLError,
+            transaction::exceptions::XRPLTransactionHelperException,
+        },
+        models::{
+            requests::{RequestMethod, XRPLRequest},
+            transactions::{account_delete::AccountDelete, payment::Payment, Signer},
+        },
+    };
+
+    const ACCOUNT: &str = "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf";
+    const DESTINATION: &str = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe";
+
+    fn payment(fee: &str) -> Payment<'static> {
+        serde_json::from_value(serde_json::json!({
+            "TransactionType": "Payment",
+            "Account": ACCOUNT,
+            "Destination": DESTINATION,
+            "Amount": "1000000",
+            "Fee": fee
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fee_typo() {
+        let client = mock_client(ACCOUNT);
+
+        assert!(check_fee(&payment("12"), &client).await.is_ok());
+        assert!(check_fee(&payment("2000000"), &client).await.is_ok());
+        // 100 XRP instead of 100 drops.
+        let result = check_fee(&payment("100000000"), &client).await;
+        assert!(matches!(
+            result,
+            Err(XRPLError::XRPLTransactionHelperError(
+                XRPLTransactionHelperException::XRPLSignTransactionError(
+                    XRPLSignTransactionException::FeeTooHigh { fee, limit }
+                )
+            )) if fee == "100000000" && limit == "2000000"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_account_delete_fee() {
+        // An AccountDelete costs the owner reserve, here above the
+        // default limit.
+        // Answered before the server state queued by `mock_client`.
+        let client = mock_client(ACCOUNT).with_handler(|request| {
+            matches!(request, XRPLRequest::ServerState(_)).then(|| {
+                MockResponse::result(serde_json::json!({
+                "state": {
+                    "build_version": "2.3.0",
+                    "complete_ledgers": "1-3",
+                    "io_latency_ms": 1,
+                    "load_base": 256,
+                    "load_factor": 256,
+                    "peers": 0,
+                    "server_state": "full",
+                    "validated_ledger": {
+                        "base_fee": 10,
+                        "close_time": 787654321,
+                        "hash": "AD2B7B9F22E4E9FE3B7AC6B22A3B6D5C0F9A9DB2D4D68A0D21B0F4E6B1DAD4D1",
+                        "reserve_base": 10000000,
+                        "reserve_inc": 5000000,
+                        "seq": 3
+                    },
+                    "validation_quorum": 1
+                },
+                "status": "success"
+                }))
+            })
+        });
+        let mut account_delete: AccountDelete = serde_json::from_value(serde_json::json!({
+            "TransactionType": "AccountDelete",
+            "Account": ACCOUNT,
+            "Destination": DESTINATION,
+            "Fee": "5000000"
+        }))
+        .unwrap();
+
+        assert!(check_fee(&account_delete, &client).await.is_ok());
+
+        account_delete.common_fields.fee = Some("5000001".into());
+        assert!(check_fee(&account_delete, &client).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_multisigned_fee() {
+        // A busy network, where a transaction costs 0.6 XRP.
+        let client = MockClient::new().with_response(
+            RequestMethod::Fee,
+            MockResponse::result(serde_json::json!({
+                "current_ledger_size": "0",
+                "current_queue_size": "0",
+                "drops": {
+                    "base_fee": "10",
+                    "median_fee": "5000",
+                    "minimum_fee": "10",
+                    "open_ledger_fee": "600000"
+                },
+                "expected_ledger_size": "32",
+                "ledger_current_index": 4,
+                "levels": {
+                    "median_level": "128000",
+                    "minimum_level": "256",
+                    "open_ledger_level": "15360000",
+                    "reference_level": "256"
+                },
+                "max_queue_size": "640",
+                "status": "success"
+            })),
+        );
+        let mut payment = payment("3000000");
+
+        assert!(check_fee(&payment, &client).await.is_err());
+
+        // Each of the 3 signers adds to the cost.
+        payment.common_fields.signers =
+            Some((0..3).map(|_| Signer::new(ACCOUNT, "", "")).collect());
+        assert!(check_fee(&payment, &client).await.is_ok());
+    }
 }
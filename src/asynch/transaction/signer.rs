@@ -0,0 +1,100 @@
+use alloc::{borrow::Cow, string::String, vec::Vec};
+
+use crate::{
+    asynch::exceptions::XRPLHelperResult,
+    core::keypairs::{derive_classic_address, signing_digest},
+    wallet::Wallet,
+};
+
+/// Something holding a private key that signs transactions with it,
+/// e.g. a hardware security module or a key management service,
+/// for [`sign_with`](super::sign_with).
+///
+/// The transaction is prepared, encoded and hashed by the caller;
+/// the signer only signs the digest it gets.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use xrpl::asynch::exceptions::XRPLHelperResult;
+/// use xrpl::asynch::transaction::{sign_with, Signer};
+/// use xrpl::core::keypairs::SigningKey;
+/// use xrpl::models::transactions::account_set::AccountSet;
+/// use xrpl::wallet::Wallet;
+///
+/// /// A signer whose key never leaves it.
+/// struct Enclave {
+///     public_key: String,
+///     signing_key: SigningKey,
+/// }
+///
+/// impl Signer for Enclave {
+///     fn public_key(&self) -> Cow<'_, str> {
+///         self.public_key.as_str().into()
+///     }
+///
+///     async fn sign_digest(&self, digest: &[u8]) -> XRPLHelperResult<Vec<u8>> {
+///         Ok(self.signing_key.sign_digest(digest)?)
+///     }
+/// }
+///
+/// let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+/// let enclave = Enclave {
+///     public_key: wallet.public_key.clone(),
+///     signing_key: wallet.signing_key().unwrap(),
+/// };
+/// let mut tx: AccountSet = serde_json::from_value(serde_json::json!({
+///     "TransactionType": "AccountSet",
+///     "Account": wallet.classic_address,
+///     "Fee": "12",
+///     "Sequence": 1
+/// }))
+/// .unwrap();
+///
+/// embassy_futures::block_on(sign_with(&mut tx, &enclave, false)).unwrap();
+/// ```
+#[allow(async_fn_in_trait)]
+pub trait Signer {
+    /// The hex encoded public key of the signing key.
+    fn public_key(&self) -> Cow<'_, str>;
+
+    /// The address the signer signs as when multi-signing, by default
+    /// the address of its public key.
+    fn classic_address(&self) -> XRPLHelperResult<Cow<'_, str>> {
+        Ok(derive_classic_address(&self.public_key())?.into())
+    }
+
+    /// Sign `digest` and return the raw signature, DER encoded for
+    /// SECP256K1 keys.
+    ///
+    /// The digest is what
+    /// [`signing_digest`](crate::core::keypairs::signing_digest)
+    /// returns: the signing payload itself for ED25519 keys, its
+    /// SHA-512 half for SECP256K1 keys.
+    async fn sign_digest(&self, digest: &[u8]) -> XRPLHelperResult<Vec<u8>>;
+}
+
+impl Signer for Wallet {
+    fn public_key(&self) -> Cow<'_, str> {
+        self.public_key.as_str().into()
+    }
+
+    fn classic_address(&self) -> XRPLHelperResult<Cow<'_, str>> {
+        Ok(self.classic_address.as_str().into())
+    }
+
+    async fn sign_digest(&self, digest: &[u8]) -> XRPLHelperResult<Vec<u8>> {
+        Ok(self.signing_key()?.sign_digest(digest)?)
+    }
+}
+
+/// The hex encoded signature `signer` makes of `payload`.
+pub(super) async fn sign_payload(signer: &impl Signer, payload: &[u8]) -> XRPLHelperResult<String> {
+    let digest = signing_digest(payload, &signer.public_key());
+
+    Ok(hex::encode_upper(signer.sign_digest(&digest).await?))
+}
@@ -11,7 +11,7 @@ use crate::{
         exceptions::XRPLHelperResult,
         ledger::get_latest_validated_ledger_sequence,
         transaction::{
-            autofill, check_txn_fee,
+            autofill, check_fee,
             exceptions::{XRPLSignTransactionException, XRPLSubmitAndWaitException},
             sign, submit,
         },
@@ -85,19 +85,22 @@ where
         }
         validated_ledger_sequence = get_latest_validated_ledger_sequence(client).await?;
         // sleep for 1 second
-        wait_seconds(1).await;
+        wait_seconds(1).await?;
         let response = client
-            .request(requests::tx::Tx::new(None, None, None, None, Some(tx_hash.clone())).into())
+            .request(
+                requests::tx::Tx::new(None, None, None, None, None, Some(tx_hash.clone())).into(),
+            )
             .await?;
         if response.is_success() {
             if let Some(error) = response.error {
                 if error == "txnNotFound" {
                     continue;
                 } else {
-                    return Err(XRPLSubmitAndWaitException::SubmissionFailed(
-                        format!("{}: {}", error, response.error_message.unwrap_or("".into()))
-                            .into(),
-                    )
+                    return Err(XRPLSubmitAndWaitException::SubmissionFailed(format!(
+                        "{}: {}",
+                        error,
+                        response.error_message.unwrap_or("".into())
+                    ))
                     .into());
                 }
             } else {
@@ -148,10 +151,8 @@ where
         return Ok(());
     }
     if let Some(wallet) = wallet {
-        if let Some(check_fee) = do_check_fee {
-            if check_fee {
-                check_txn_fee(transaction, client).await?;
-            }
+        if do_check_fee == Some(true) {
+            check_fee(transaction, client).await?;
         }
         if let Some(do_autofill) = do_autofill {
             if do_autofill {
@@ -168,27 +169,57 @@ where
     }
 }
 
-#[cfg(all(
-    feature = "std",
-    feature = "json-rpc",
-    feature = "helpers",
-    feature = "models",
-    feature = "tokio-rt"
-))]
-#[cfg(test)]
+#[cfg(all(test, feature = "std", feature = "tokio-rt"))]
 mod test_submit_and_wait {
     use super::*;
     use crate::{
-        asynch::{clients::AsyncJsonRpcClient, wallet::generate_faucet_wallet},
-        models::transactions::account_set::AccountSet,
+        asynch::{clients::MockResponse, transaction::test_autofill::mock_client},
+        models::{
+            requests::{RequestMethod, XRPLRequest},
+            transactions::account_set::AccountSet,
+        },
     };
 
     #[tokio::test]
     async fn test_submit_and_wait() {
-        let client = AsyncJsonRpcClient::connect("https://testnet.xrpl-labs.com/".parse().unwrap());
-        let wallet = generate_faucet_wallet(&client, None, None, None, None)
-            .await
-            .unwrap();
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let client = mock_client(&wallet.classic_address)
+            .with_handler(|request| {
+                let XRPLRequest::Submit(submit) = request else {
+                    return None;
+                };
+
+                Some(MockResponse::result(serde_json::json!({
+                    "accepted": true,
+                    "applied": true,
+                    "broadcast": true,
+                    "engine_result": "tesSUCCESS",
+                    "engine_result_code": 0,
+                    "engine_result_message": "The transaction was applied. Only final in a validated ledger.",
+                    "kept": true,
+                    "queued": false,
+                    "status": "success",
+                    "tx_blob": submit.tx_blob,
+                    "tx_json": {}
+                })))
+            })
+            .with_response(
+                RequestMethod::Tx,
+                MockResponse::result(serde_json::json!({
+                    "Account": wallet.classic_address,
+                    "TransactionType": "AccountSet",
+                    "ctid": "C000000400000000",
+                    "date": 638329271,
+                    "hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB13FD",
+                    "ledger_index": 4,
+                    "meta": {
+                        "TransactionIndex": 0,
+                        "TransactionResult": "tesSUCCESS"
+                    },
+                    "status": "success",
+                    "validated": true
+                })),
+            );
         let mut tx = AccountSet::new(
             Cow::from(wallet.classic_address.clone()),
             None,
@@ -209,8 +240,17 @@ mod test_submit_and_wait {
             None,
             None,
         );
-        submit_and_wait(&mut tx, &client, Some(&wallet), Some(true), Some(true))
+        let result = submit_and_wait(&mut tx, &client, Some(&wallet), Some(true), Some(true))
             .await
             .unwrap();
+        assert_eq!(result.meta["TransactionResult"], "tesSUCCESS");
+
+        // The submitted transaction is looked up by its hash.
+        let tx_hash = tx.get_hash().unwrap();
+        let XRPLRequest::Tx(tx_request) = &client.requests_of(RequestMethod::Tx)[0] else {
+            unreachable!()
+        };
+        assert_eq!(tx_request.transaction.as_deref(), Some(&*tx_hash));
+        assert_eq!(client.requests_of(RequestMethod::Submit).len(), 1);
     }
 }
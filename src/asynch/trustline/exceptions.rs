@@ -0,0 +1,11 @@
+use alloc::string::String;
+use thiserror_no_std::Error;
+
+#[derive(Error, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum XRPLTrustlineException {
+    #[error("Cannot create a trust line from {0} to itself")]
+    TrustlineToSelf(String),
+    #[error("The limit of a trust line cannot be negative: {0}")]
+    NegativeLimit(String),
+}
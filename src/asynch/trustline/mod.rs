@@ -0,0 +1,451 @@
+pub mod exceptions;
+
+use core::str::FromStr;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+};
+
+use crate::{
+    asynch::{
+        clients::XRPLAsyncClient, exceptions::XRPLHelperResult, transaction::submit_and_wait,
+    },
+    models::{
+        requests::account_lines::AccountLines,
+        results::account_lines::{AccountLines as AccountLinesResult, TrustLine},
+        transactions::trust_set::{TrustSet, TrustSetFlag},
+        FlagCollection, IssuedCurrencyAmount,
+    },
+    utils::IOUValue,
+    wallet::Wallet,
+};
+
+use exceptions::XRPLTrustlineException;
+
+/// What [`create_trustline`] did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrustlineChange {
+    /// The trust line did not exist. Holds the hash of the TrustSet
+    /// creating it.
+    Created(String),
+    /// The trust line existed with another limit or without
+    /// `no_ripple`. Holds the hash of the TrustSet updating it.
+    Updated(String),
+    /// The trust line already had the limit and flags, so nothing was
+    /// submitted.
+    AlreadyExists(TrustLine<'static>),
+}
+
+/// Get the trust line of `account` with `peer` for `currency` in the
+/// latest validated ledger, from the perspective of `account`, or
+/// `None` if there is none.
+pub async fn get_trustline<C>(
+    client: &C,
+    account: &str,
+    peer: &str,
+    currency: &str,
+) -> XRPLHelperResult<Option<TrustLine<'static>>>
+where
+    C: XRPLAsyncClient,
+{
+    let mut marker = None;
+    loop {
+        let request = AccountLines::new(
+            None,
+            account.into(),
+            None,
+            Some("validated".into()),
+            None,
+            Some(peer.into()),
+            marker.take(),
+        );
+        let response = client.request(request.into()).await?;
+        let page = response.try_into_result::<AccountLinesResult<'_>>()?;
+        if let Some(line) = page
+            .lines
+            .into_iter()
+            .find(|line| line.currency == currency)
+        {
+            return Ok(Some(into_owned(line)));
+        }
+        match page.marker {
+            Some(next_marker) => marker = Some(next_marker),
+            None => return Ok(None),
+        }
+    }
+}
+
+/// Trust `issuer` to hold up to `limit` of its `currency` with
+/// `wallet`, and wait for the TrustSet to be validated.
+///
+/// If the trust line already exists with the same limit, and with
+/// the No Ripple flag if `no_ripple` asks for it, nothing is
+/// submitted. `no_ripple` sets the No Ripple flag on the line, which
+/// most accounts that do not issue currencies want; it never clears
+/// it.
+pub async fn create_trustline<C>(
+    client: &C,
+    wallet: &Wallet,
+    currency: &str,
+    issuer: &str,
+    limit: &str,
+    no_ripple: bool,
+) -> XRPLHelperResult<TrustlineChange>
+where
+    C: XRPLAsyncClient,
+{
+    if issuer == wallet.classic_address {
+        return Err(XRPLTrustlineException::TrustlineToSelf(issuer.to_string()).into());
+    }
+    let limit_value = IOUValue::from_str(limit)?;
+    if limit_value.is_negative() {
+        return Err(XRPLTrustlineException::NegativeLimit(limit.to_string()).into());
+    }
+
+    let existing = get_trustline(client, &wallet.classic_address, issuer, currency).await?;
+    if let Some(line) = existing.as_ref() {
+        let has_limit = IOUValue::from_str(&line.limit).is_ok_and(|value| value == limit_value);
+        if has_limit && (!no_ripple || line.no_ripple == Some(true)) {
+            return Ok(TrustlineChange::AlreadyExists(line.clone()));
+        }
+    }
+
+    let flags = no_ripple.then(|| vec![TrustSetFlag::TfSetNoRipple].into());
+    let hash = submit_trust_set(client, wallet, currency, issuer, limit, flags).await?;
+
+    match existing {
+        Some(_) => Ok(TrustlineChange::Updated(hash)),
+        None => Ok(TrustlineChange::Created(hash)),
+    }
+}
+
+/// Set `flag` on the trust line of `wallet` with `peer` for
+/// `currency`, e.g. [`TrustSetFlag::TfSetFreeze`] to freeze it or
+/// [`TrustSetFlag::TfSetAuth`] to authorize `peer` to hold the
+/// currency of `wallet`, wait for the TrustSet to be validated and
+/// return its hash.
+///
+/// The limit of `wallet` on the line is kept, or is 0 if the line
+/// does not exist yet.
+pub async fn set_trustline_flag<C>(
+    client: &C,
+    wallet: &Wallet,
+    currency: &str,
+    peer: &str,
+    flag: TrustSetFlag,
+) -> XRPLHelperResult<String>
+where
+    C: XRPLAsyncClient,
+{
+    if peer == wallet.classic_address {
+        return Err(XRPLTrustlineException::TrustlineToSelf(peer.to_string()).into());
+    }
+    let limit = get_trustline(client, &wallet.classic_address, peer, currency)
+        .await?
+        .map_or_else(|| "0".to_string(), |line| line.limit.into_owned());
+
+    submit_trust_set(
+        client,
+        wallet,
+        currency,
+        peer,
+        &limit,
+        Some(vec![flag].into()),
+    )
+    .await
+}
+
+async fn submit_trust_set<C>(
+    client: &C,
+    wallet: &Wallet,
+    currency: &str,
+    peer: &str,
+    limit: &str,
+    flags: Option<FlagCollection<TrustSetFlag>>,
+) -> XRPLHelperResult<String>
+where
+    C: XRPLAsyncClient,
+{
+    let mut transaction = TrustSet::new(
+        wallet.classic_address.as_str().into(),
+        None,
+        None,
+        flags,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        IssuedCurrencyAmount::new(currency.into(), peer.into(), limit.into()),
+        None,
+        None,
+    );
+    let tx = submit_and_wait(
+        &mut transaction,
+        client,
+        Some(wallet),
+        Some(true),
+        Some(true),
+    )
+    .await?;
+
+    Ok(tx.hash.to_string())
+}
+
+fn into_owned(line: TrustLine<'_>) -> TrustLine<'static> {
+    TrustLine {
+        account: line.account.into_owned().into(),
+        balance: line.balance.into_owned().into(),
+        currency: line.currency.into_owned().into(),
+        limit: line.limit.into_owned().into(),
+        limit_peer: line.limit_peer.into_owned().into(),
+        quality_in: line.quality_in,
+        quality_out: line.quality_out,
+        no_ripple: line.no_ripple,
+        no_ripple_peer: line.no_ripple_peer,
+        authorized: line.authorized,
+        peer_authorized: line.peer_authorized,
+        freeze: line.freeze,
+        freeze_peer: line.freeze_peer,
+    }
+}
+
+#[cfg(all(test, feature = "std", feature = "tokio-rt"))]
+mod test {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::{
+        asynch::{
+            clients::{MockClient, MockResponse},
+            exceptions::XRPLError,
+            transaction::test_autofill::mock_client,
+        },
+        models::requests::{RequestMethod, XRPLRequest},
+        utils::exceptions::{XRPLUtilsException, XRPRangeException},
+    };
+
+    const ISSUER: &str = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe";
+    const HASH: &str = "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB13FD";
+
+    /// A server listing `lines` for the wallet and accepting every
+    /// submitted transaction.
+    fn trustline_client(wallet: &Wallet, lines: serde_json::Value) -> MockClient {
+        mock_client(&wallet.classic_address)
+            .with_response(
+                RequestMethod::AccountLines,
+                MockResponse::result(serde_json::json!({
+                    "account": wallet.classic_address,
+                    "ledger_index": 4,
+                    "lines": lines,
+                    "validated": true
+                })),
+            )
+            .with_handler(|request| {
+                let XRPLRequest::Submit(submit) = request else {
+                    return None;
+                };
+
+                Some(MockResponse::result(serde_json::json!({
+                    "engine_result": "tesSUCCESS",
+                    "engine_result_code": 0,
+                    "engine_result_message": "The transaction was applied. Only final in a validated ledger.",
+                    "status": "success",
+                    "tx_blob": submit.tx_blob,
+                    "tx_json": {}
+                })))
+            })
+            .with_response(
+                RequestMethod::Tx,
+                MockResponse::result(serde_json::json!({
+                    "Account": wallet.classic_address,
+                    "date": 638329271,
+                    "hash": HASH,
+                    "ledger_index": 4,
+                    "meta": {
+                        "AffectedNodes": [],
+                        "TransactionIndex": 0,
+                        "TransactionResult": "tesSUCCESS"
+                    },
+                    "status": "success",
+                    "validated": true
+                })),
+            )
+    }
+
+    fn usd_line(limit: &str, no_ripple: bool) -> serde_json::Value {
+        serde_json::json!({
+            "account": ISSUER,
+            "balance": "0",
+            "currency": "USD",
+            "limit": limit,
+            "limit_peer": "0",
+            "no_ripple": no_ripple,
+            "quality_in": 0,
+            "quality_out": 0
+        })
+    }
+
+    fn submitted(client: &MockClient) -> Vec<serde_json::Value> {
+        client
+            .requests_of(RequestMethod::Submit)
+            .iter()
+            .map(|request| {
+                let XRPLRequest::Submit(submit) = request else {
+                    unreachable!()
+                };
+                crate::core::binarycodec::decode(&submit.tx_blob).unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_get_trustline() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let client = trustline_client(&wallet, serde_json::json!([usd_line("100", true)]));
+
+        let line = get_trustline(&client, &wallet.classic_address, ISSUER, "USD")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(line.limit, "100");
+        assert_eq!(line.no_ripple, Some(true));
+        assert_eq!(
+            get_trustline(&client, &wallet.classic_address, ISSUER, "EUR")
+                .await
+                .unwrap(),
+            None
+        );
+
+        let XRPLRequest::AccountLines(request) =
+            &client.requests_of(RequestMethod::AccountLines)[0]
+        else {
+            unreachable!()
+        };
+        assert_eq!(request.peer.as_deref(), Some(ISSUER));
+    }
+
+    #[tokio::test]
+    async fn test_create_trustline_created() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let client = trustline_client(&wallet, serde_json::json!([]));
+
+        let change = create_trustline(&client, &wallet, "USD", ISSUER, "100", true)
+            .await
+            .unwrap();
+        assert_eq!(change, TrustlineChange::Created(HASH.into()));
+
+        let tx = &submitted(&client)[0];
+        assert_eq!(tx["TransactionType"], "TrustSet");
+        assert_eq!(tx["Flags"], TrustSetFlag::TfSetNoRipple as u32);
+        assert_eq!(
+            tx["LimitAmount"],
+            serde_json::json!({"currency": "USD", "issuer": ISSUER, "value": "100"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_trustline_updated() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let client = trustline_client(&wallet, serde_json::json!([usd_line("100", false)]));
+
+        // The same limit, but without the No Ripple flag asked for.
+        let change = create_trustline(&client, &wallet, "USD", ISSUER, "100", true)
+            .await
+            .unwrap();
+        assert_eq!(change, TrustlineChange::Updated(HASH.into()));
+        assert_eq!(
+            submitted(&client)[0]["Flags"],
+            TrustSetFlag::TfSetNoRipple as u32
+        );
+
+        let client = trustline_client(&wallet, serde_json::json!([usd_line("100", false)]));
+        let change = create_trustline(&client, &wallet, "USD", ISSUER, "250", false)
+            .await
+            .unwrap();
+        assert_eq!(change, TrustlineChange::Updated(HASH.into()));
+        assert_eq!(submitted(&client)[0]["LimitAmount"]["value"], "250");
+    }
+
+    #[tokio::test]
+    async fn test_create_trustline_already_exists() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let client = trustline_client(&wallet, serde_json::json!([usd_line("100", true)]));
+
+        // Limits are compared by value.
+        let change = create_trustline(&client, &wallet, "USD", ISSUER, "1e2", false)
+            .await
+            .unwrap();
+        let TrustlineChange::AlreadyExists(line) = change else {
+            panic!("expected the existing line, found {change:?}");
+        };
+        assert_eq!(line.limit, "100");
+        assert!(client.requests_of(RequestMethod::Submit).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_create_trustline_invalid() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let client = trustline_client(&wallet, serde_json::json!([]));
+
+        let error = create_trustline(
+            &client,
+            &wallet,
+            "USD",
+            &wallet.classic_address,
+            "100",
+            true,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error,
+            XRPLError::XRPLTrustlineError(XRPLTrustlineException::TrustlineToSelf(_))
+        ));
+        let error = create_trustline(
+            &client,
+            &wallet,
+            "USD",
+            ISSUER,
+            "1.234567890123456789",
+            true,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error,
+            XRPLError::XRPLUtilsError(XRPLUtilsException::XRPRangeError(
+                XRPRangeException::InvalidICPrecision { max: 16, found: 19 }
+            ))
+        ));
+        let error = create_trustline(&client, &wallet, "USD", ISSUER, "-1", true)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            XRPLError::XRPLTrustlineError(XRPLTrustlineException::NegativeLimit(_))
+        ));
+        assert!(client.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_set_trustline_flag() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let client = trustline_client(&wallet, serde_json::json!([usd_line("5", false)]));
+
+        let hash = set_trustline_flag(&client, &wallet, "USD", ISSUER, TrustSetFlag::TfSetFreeze)
+            .await
+            .unwrap();
+        assert_eq!(hash, HASH);
+
+        // The limit of the wallet on the line is kept.
+        let tx = &submitted(&client)[0];
+        assert_eq!(tx["Flags"], TrustSetFlag::TfSetFreeze as u32);
+        assert_eq!(
+            tx["LimitAmount"],
+            serde_json::json!({"currency": "USD", "issuer": ISSUER, "value": "5"})
+        );
+    }
+}
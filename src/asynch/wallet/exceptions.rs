@@ -1,3 +1,4 @@
+use alloc::string::String;
 use thiserror_no_std::Error;
 
 #[derive(Error, PartialEq, Debug)]
@@ -9,6 +10,8 @@ pub enum XRPLFaucetException {
     CannotFundSidechainAccount,
     #[error("Cannot derive a faucet URL from the client host.")]
     CannotDeriveFaucetUrl,
+    #[error("The network {0} has no faucet.")]
+    NoFaucet(String),
     #[error("Funding request timed out.")]
     FundingTimeout,
 }
@@ -47,7 +47,7 @@ where
     let mut is_funded = false;
     for _ in 0..TIMEOUT_SECS {
         // wait 1 second
-        wait_seconds(1).await;
+        wait_seconds(1).await?;
         if !is_funded {
             let balance = check_balance(client, address.into()).await;
             if balance > starting_balance {
@@ -103,23 +103,66 @@ where
     Ok(())
 }
 
-#[cfg(all(feature = "json-rpc", feature = "std"))]
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod test_faucet_wallet_generation {
     use super::*;
-    use crate::asynch::clients::AsyncJsonRpcClient;
-    use url::Url;
+    use crate::{
+        asynch::clients::{MockClient, MockResponse, Network},
+        models::requests::RequestMethod,
+    };
 
     #[tokio::test]
     async fn test_generate_faucet_wallet() {
-        let client =
-            AsyncJsonRpcClient::connect(Url::parse("https://testnet.xrpl-labs.com/").unwrap());
-        let wallet = generate_faucet_wallet(&client, None, None, None, None)
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let funded = MockResponse::result(serde_json::json!({
+            "account_data": {
+                "Account": wallet.classic_address,
+                "Balance": "100000000",
+                "Flags": 0,
+                "LedgerEntryType": "AccountRoot",
+                "OwnerCount": 0,
+                "PreviousTxnID": "4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42",
+                "PreviousTxnLgrSeq": 3,
+                "Sequence": 3,
+                "index": "92FA6A9FC8EA6018D5D16532D7795C91BFB0831355BDFDA177E86C8BF997985F"
+            },
+            "status": "success",
+            "validated": true
+        }));
+        // The account only exists once the faucet funded it.
+        let client = MockClient::new()
+            .with_host(Url::parse("https://s.altnet.rippletest.net:51234").unwrap())
+            .with_response(
+                RequestMethod::AccountInfo,
+                MockResponse::error("actNotFound", 19, Some("Account not found.")),
+            )
+            .with_response(RequestMethod::AccountInfo, funded);
+
+        let wallet = generate_faucet_wallet(&client, Some(wallet), None, None, None)
             .await
             .unwrap();
         let balance = get_xrp_balance(wallet.classic_address.clone().into(), &client, None)
             .await
             .unwrap();
         assert!(balance > 0.into());
+        assert_eq!(client.funded_accounts(), [wallet.classic_address.as_str()]);
+    }
+
+    #[test]
+    fn test_faucet_url_from_network() {
+        let client = MockClient::new().with_network(Network::devnet());
+        assert_eq!(
+            get_faucet_url(&client, None).unwrap().as_str(),
+            "https://faucet.devnet.rippletest.net/accounts"
+        );
+
+        let client = MockClient::new().with_network(Network::mainnet());
+        assert!(get_faucet_url(&client, None).is_err());
+        // An explicit faucet still wins.
+        let faucet = Url::parse("http://localhost:8080/accounts").unwrap();
+        assert_eq!(
+            get_faucet_url(&client, Some(faucet.clone())).unwrap(),
+            faucet
+        );
     }
 }
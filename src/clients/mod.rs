@@ -1,5 +1,11 @@
+use serde_json::Value;
+
 use crate::{
-    asynch::clients::{exceptions::XRPLClientResult, CommonFields, XRPLClient},
+    asynch::clients::{
+        async_client::{into_raw_response, raw_request},
+        exceptions::XRPLClientResult,
+        CommonFields, XRPLClient,
+    },
     models::{requests::XRPLRequest, results::XRPLResponse},
 };
 
@@ -8,11 +14,18 @@ pub use crate::asynch::clients::SingleExecutorMutex;
 pub trait XRPLSyncClient: XRPLClient {
     fn request<'a: 'b, 'b>(&self, request: XRPLRequest<'a>) -> XRPLClientResult<XRPLResponse<'b>>;
 
+    /// Send a request given as its method name and JSON `params`, see
+    /// [`XRPLAsyncClient::request_raw`](crate::asynch::clients::XRPLAsyncClient::request_raw).
+    fn request_raw<'b>(&self, method: &str, params: Value) -> XRPLClientResult<XRPLResponse<'b>> {
+        into_raw_response(self.request(raw_request(method, params)?)?)
+    }
+
     fn get_common_fields(&self) -> XRPLClientResult<CommonFields<'_>>;
 }
 
 #[cfg(all(feature = "json-rpc", feature = "std"))]
 pub mod json_rpc {
+    use core::time::Duration;
     use tokio::runtime::Runtime;
     use url::Url;
 
@@ -20,8 +33,8 @@ pub mod json_rpc {
     use crate::{asynch::clients::XRPLFaucet, models::requests::FundFaucet};
     use crate::{
         asynch::clients::{
-            exceptions::XRPLClientResult, AsyncJsonRpcClient, CommonFields, XRPLAsyncClient,
-            XRPLClient,
+            exceptions::XRPLClientResult, ApiVersion, AsyncJsonRpcClient, CommonFields, Network,
+            XRPLAsyncClient, XRPLClient,
         },
         models::{requests::XRPLRequest, results::XRPLResponse},
     };
@@ -33,6 +46,23 @@ pub mod json_rpc {
         pub fn connect(url: Url) -> Self {
             Self(AsyncJsonRpcClient::connect(url))
         }
+
+        /// Connect to the JSON-RPC server of `network`.
+        pub fn connect_network(network: Network) -> Self {
+            Self(AsyncJsonRpcClient::connect_network(network))
+        }
+
+        /// Set how long requests wait for a response. `None` waits
+        /// forever.
+        pub fn with_timeout(self, timeout: Option<Duration>) -> Self {
+            Self(self.0.with_timeout(timeout))
+        }
+
+        /// Set the API version requests are sent with, unless they
+        /// set one.
+        pub fn with_api_version(self, api_version: ApiVersion) -> Self {
+            Self(self.0.with_api_version(api_version))
+        }
     }
 
     impl XRPLClient for JsonRpcClient {
@@ -47,6 +77,18 @@ pub mod json_rpc {
             self.0.get_host()
         }
 
+        fn get_timeout(&self) -> Option<Duration> {
+            self.0.get_timeout()
+        }
+
+        fn get_api_version(&self) -> Option<ApiVersion> {
+            self.0.get_api_version()
+        }
+
+        fn get_network(&self) -> Option<&Network> {
+            self.0.get_network()
+        }
+
         fn get_random_id<'a>(&self) -> alloc::borrow::Cow<'a, str> {
             self.0.get_random_id()
         }
@@ -58,7 +100,7 @@ pub mod json_rpc {
             request: XRPLRequest<'a>,
         ) -> XRPLClientResult<XRPLResponse<'b>> {
             match Runtime::new() {
-                Ok(rt) => rt.block_on(self.0.request_impl(request)),
+                Ok(rt) => rt.block_on(self.0.request(request)),
                 Err(e) => Err(e.into()),
             }
         }
@@ -85,6 +127,7 @@ pub mod json_rpc {
 
 #[cfg(all(feature = "json-rpc", not(feature = "std")))]
 pub mod json_rpc {
+    use core::time::Duration;
     use embassy_sync::blocking_mutex::raw::RawMutex;
     use embedded_nal_async::{Dns, TcpConnect};
     use url::Url;
@@ -92,7 +135,9 @@ pub mod json_rpc {
     #[cfg(feature = "helpers")]
     use crate::{asynch::clients::XRPLFaucet, models::requests::FundFaucet};
     use crate::{
-        asynch::clients::{exceptions::XRPLClientResult, AsyncJsonRpcClient, XRPLClient},
+        asynch::clients::{
+            exceptions::XRPLClientResult, ApiVersion, AsyncJsonRpcClient, XRPLClient,
+        },
         models::{requests::XRPLRequest, results::XRPLResponse},
     };
 
@@ -113,6 +158,18 @@ pub mod json_rpc {
         pub fn connect(url: Url, tcp: &'a T, dns: &'a D) -> Self {
             Self(AsyncJsonRpcClient::connect(url, tcp, dns))
         }
+
+        /// Set how long requests wait for a response. `None` waits
+        /// forever.
+        pub fn with_timeout(self, timeout: Option<Duration>) -> Self {
+            Self(self.0.with_timeout(timeout))
+        }
+
+        /// Set the API version requests are sent with, unless they
+        /// set one.
+        pub fn with_api_version(self, api_version: ApiVersion) -> Self {
+            Self(self.0.with_api_version(api_version))
+        }
     }
 
     impl<const BUF: usize, T, D, M> XRPLClient for JsonRpcClient<'_, BUF, T, D, M>
@@ -131,6 +188,14 @@ pub mod json_rpc {
         fn get_host(&self) -> Url {
             self.0.get_host()
         }
+
+        fn get_timeout(&self) -> Option<Duration> {
+            self.0.get_timeout()
+        }
+
+        fn get_api_version(&self) -> Option<ApiVersion> {
+            self.0.get_api_version()
+        }
     }
 
     #[cfg(feature = "helpers")]
@@ -158,6 +223,7 @@ pub trait XRPLSyncWebsocketIO {
 
 #[cfg(all(feature = "websocket", feature = "std"))]
 pub mod websocket {
+    use core::time::Duration;
     use embassy_sync::blocking_mutex::raw::RawMutex;
     use tokio::runtime::Runtime;
     use url::Url;
@@ -165,8 +231,8 @@ pub mod websocket {
     use super::{XRPLSyncClient, XRPLSyncWebsocketIO};
     use crate::{
         asynch::clients::{
-            exceptions::XRPLClientResult, AsyncWebSocketClient, CommonFields, XRPLAsyncClient,
-            XRPLAsyncWebsocketIO, XRPLClient,
+            exceptions::XRPLClientResult, ApiVersion, AsyncWebSocketClient, CommonFields, Network,
+            XRPLAsyncClient, XRPLAsyncWebsocketIO, XRPLClient,
         },
         models::{requests::XRPLRequest, results::XRPLResponse},
     };
@@ -190,6 +256,41 @@ pub mod websocket {
                 Err(e) => Err(e.into()),
             }
         }
+
+        /// Connect to the websocket server of `network`.
+        pub fn connect_network(
+            network: Network,
+        ) -> XRPLClientResult<WebSocketClient<M, WebSocketOpen>> {
+            match Runtime::new() {
+                Ok(rt) => {
+                    let client: AsyncWebSocketClient<M, WebSocketOpen> =
+                        rt.block_on(AsyncWebSocketClient::connect_network(network))?;
+
+                    Ok(WebSocketClient { inner: client, rt })
+                }
+                Err(e) => Err(e.into()),
+            }
+        }
+    }
+
+    impl<M: RawMutex> WebSocketClient<M, WebSocketOpen> {
+        /// Set how long requests wait for a response. `None` waits
+        /// forever.
+        pub fn with_timeout(self, timeout: Option<Duration>) -> Self {
+            Self {
+                inner: self.inner.with_timeout(timeout),
+                rt: self.rt,
+            }
+        }
+
+        /// Set the API version requests are sent with, unless they
+        /// set one.
+        pub fn with_api_version(self, api_version: ApiVersion) -> Self {
+            Self {
+                inner: self.inner.with_api_version(api_version),
+                rt: self.rt,
+            }
+        }
     }
 
     impl<M> XRPLClient for WebSocketClient<M, WebSocketOpen>
@@ -200,6 +301,18 @@ pub mod websocket {
             self.inner.get_host()
         }
 
+        fn get_timeout(&self) -> Option<Duration> {
+            self.inner.get_timeout()
+        }
+
+        fn get_api_version(&self) -> Option<ApiVersion> {
+            self.inner.get_api_version()
+        }
+
+        fn get_network(&self) -> Option<&Network> {
+            self.inner.get_network()
+        }
+
         async fn request_impl<'a: 'b, 'b>(
             &self,
             request: XRPLRequest<'a>,
@@ -219,7 +332,7 @@ pub mod websocket {
             &self,
             request: XRPLRequest<'a>,
         ) -> XRPLClientResult<XRPLResponse<'b>> {
-            self.rt.block_on(self.inner.request_impl(request))
+            self.rt.block_on(self.inner.request(request))
         }
 
         fn get_common_fields(&self) -> XRPLClientResult<CommonFields<'_>> {
@@ -244,6 +357,7 @@ pub mod websocket {
 #[cfg(all(feature = "websocket", not(feature = "std")))]
 pub mod websocket {
     use super::XRPLSyncWebsocketIO;
+    use core::time::Duration;
     use embassy_futures::block_on;
     use embassy_sync::blocking_mutex::raw::RawMutex;
     use embedded_io_async::{Read, Write};
@@ -252,7 +366,7 @@ pub mod websocket {
 
     use crate::{
         asynch::clients::{
-            exceptions::XRPLClientResult, AsyncWebSocketClient, WebSocketOpen,
+            exceptions::XRPLClientResult, ApiVersion, AsyncWebSocketClient, WebSocketOpen,
             XRPLAsyncWebsocketIO, XRPLClient,
         },
         models::{requests::XRPLRequest, results::XRPLResponse},
@@ -276,6 +390,14 @@ pub mod websocket {
             self.0.get_host()
         }
 
+        fn get_timeout(&self) -> Option<Duration> {
+            self.0.get_timeout()
+        }
+
+        fn get_api_version(&self) -> Option<ApiVersion> {
+            self.0.get_api_version()
+        }
+
         async fn request_impl<'a: 'b, 'b>(
             &self,
             request: XRPLRequest<'a>,
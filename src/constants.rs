@@ -25,8 +25,14 @@ pub const MAX_URI_LENGTH: usize = 512;
 
 pub const MAX_DOMAIN_LENGTH: usize = 256;
 
+/// Maximum number of entries in a signer list (ExpandedSignerList).
+pub const MAX_SIGNER_ENTRIES: usize = 32;
+/// Length of a signer entry's wallet locator in hex characters.
+pub const WALLET_LOCATOR_LENGTH: usize = 64;
+
 /// Represents the supported cryptography algorithms.
 #[derive(Debug, PartialEq, Eq, Clone, EnumIter, Display, Deserialize, Serialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
 pub enum CryptoAlgorithm {
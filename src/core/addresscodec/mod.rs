@@ -13,6 +13,7 @@ use alloc::string::String;
 use alloc::vec::Vec;
 use core::convert::TryInto;
 use strum::IntoEnumIterator;
+use zeroize::Zeroize;
 
 use super::exceptions::XRPLCoreResult;
 
@@ -37,23 +38,20 @@ fn _is_test_address(prefix: &[u8]) -> XRPLCoreResult<bool> {
 
 /// Returns the destination tag extracted from the suffix
 /// of the X-Address.
-fn _get_tag_from_buffer(buffer: &[u8]) -> XRPLCoreResult<Option<u64>> {
-    let flag = &buffer[0];
+fn _get_tag_from_buffer(buffer: &[u8]) -> XRPLCoreResult<Option<u32>> {
+    let flag = buffer[0];
 
-    if flag >= &2 {
+    if flag >= 2 {
         Err(XRPLAddressCodecException::UnsupportedXAddress.into())
-    } else if flag == &1 {
-        // Little-endian to big-endian
-        Ok(Some(
-            buffer[1] as u64
-                + buffer[2] as u64 * 0x100
-                + buffer[3] as u64 * 0x10000
-                + buffer[4] as u64 * 0x1000000,
-        ))
+    } else if buffer[5..9] != [0; 4] {
+        // 64-bit tags are reserved and not supported
+        Err(XRPLAddressCodecException::UnsupportedXAddress.into())
+    } else if flag == 1 {
         // inverse of what happens in encode
-    } else if flag != &0 {
-        Err(XRPLAddressCodecException::InvalidXAddressZeroNoTag.into())
-    } else if hex::decode("0000000000000000")? != buffer[1..9] {
+        Ok(Some(u32::from_le_bytes([
+            buffer[1], buffer[2], buffer[3], buffer[4],
+        ])))
+    } else if buffer[1..5] != [0; 4] {
         Err(XRPLAddressCodecException::InvalidXAddressZeroRemain.into())
     } else {
         Ok(None)
@@ -149,13 +147,77 @@ pub fn decode_seed(seed: &str) -> XRPLCoreResult<([u8; SEED_LENGTH], CryptoAlgor
         Some(Ok(val)) => {
             let decoded: [u8; SEED_LENGTH] = val
                 .try_into()
-                .map_err(|err| XRPLAddressCodecException::VecResizeError(err))?;
+                .map_err(XRPLAddressCodecException::VecResizeError)?;
             Ok((decoded, algo.expect("decode_seed")))
         }
         Some(Err(_)) | None => Err(XRPLAddressCodecException::UnknownSeedEncoding.into()),
     }
 }
 
+/// Returns the seed encoding 16 bytes of hex-encoded
+/// entropy, for wallets that export raw entropy rather
+/// than a seed.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::addresscodec::seed_from_entropy_hex;
+/// use xrpl::constants::CryptoAlgorithm;
+///
+/// let seed = seed_from_entropy_hex(
+///     "CF2DE378FBDD7E2EE87D486DFB5A7BFF",
+///     CryptoAlgorithm::SECP256K1,
+/// );
+///
+/// assert_eq!(Ok("sn259rEFXrQrWyx3Q7XneWcwV6dfL".to_string()), seed);
+/// ```
+pub fn seed_from_entropy_hex(
+    entropy_hex: &str,
+    algorithm: CryptoAlgorithm,
+) -> XRPLCoreResult<String> {
+    let mut entropy = hex::decode(entropy_hex)?;
+    let result = match <[u8; SEED_LENGTH]>::try_from(entropy.as_slice()) {
+        Ok(bytes) => encode_seed(bytes, algorithm),
+        Err(_) => Err(XRPLAddressCodecException::UnexpectedPayloadLength {
+            expected: SEED_LENGTH,
+            found: entropy.len(),
+        }
+        .into()),
+    };
+    entropy.zeroize();
+
+    result
+}
+
+/// Returns the 16 bytes of entropy of a seed as an
+/// uppercase hex string, along with the algorithm the
+/// seed is meant for.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::addresscodec::entropy_hex_from_seed;
+/// use xrpl::constants::CryptoAlgorithm;
+///
+/// let entropy = entropy_hex_from_seed("sEdTM1uX8pu2do5XvTnutH6HsouMaM2");
+///
+/// assert_eq!(
+///     Ok(("4C3A1D213FBDFB14C7C28D609469B341".to_string(), CryptoAlgorithm::ED25519)),
+///     entropy
+/// );
+/// ```
+pub fn entropy_hex_from_seed(seed: &str) -> XRPLCoreResult<(String, CryptoAlgorithm)> {
+    let (mut entropy, algorithm) = decode_seed(seed)?;
+    let entropy_hex = hex::encode_upper(entropy);
+    entropy.zeroize();
+
+    Ok((entropy_hex, algorithm))
+}
+
 /// Returns the X-Address representation of the data.
 ///
 /// # Examples
@@ -168,7 +230,7 @@ pub fn decode_seed(seed: &str) -> XRPLCoreResult<([u8; SEED_LENGTH], CryptoAlgor
 /// use xrpl::core::exceptions::XRPLCoreException;
 ///
 /// let classic_address: &str = "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59".into();
-/// let tag: Option<u64> = None;
+/// let tag: Option<u32> = None;
 /// let is_test_network: bool = false;
 /// let xaddress: String = "X7AcgcsBL6XDcUb289X4mJ8djcdyKaB5hJDWMArnXr61cqZ".into();
 ///
@@ -195,27 +257,17 @@ pub fn decode_seed(seed: &str) -> XRPLCoreResult<([u8; SEED_LENGTH], CryptoAlgor
 /// ```
 pub fn classic_address_to_xaddress(
     classic_address: &str,
-    tag: Option<u64>,
+    tag: Option<u32>,
     is_test_network: bool,
 ) -> XRPLCoreResult<String> {
     let classic_address_bytes = decode_classic_address(classic_address)?;
-    let flag: bool = tag.is_some();
-    let tag_val: u64;
 
     if classic_address_bytes.len() != CLASSIC_ADDRESS_ID_LENGTH {
         Err(XRPLAddressCodecException::InvalidCAddressIdLength {
             length: CLASSIC_ADDRESS_ID_LENGTH,
         }
         .into())
-    } else if tag.is_some() && tag > Some(u32::MAX.into()) {
-        Err(XRPLAddressCodecException::InvalidCAddressTag.into())
     } else {
-        if let Some(tval) = tag {
-            tag_val = tval;
-        } else {
-            tag_val = 0;
-        }
-
         let mut bytestring = match is_test_network {
             true => ADDRESS_PREFIX_BYTES_TEST,
             false => ADDRESS_PREFIX_BYTES_MAIN,
@@ -224,12 +276,14 @@ pub fn classic_address_to_xaddress(
 
         bytestring.extend_from_slice(&classic_address_bytes);
 
+        // A tag of 0 is still flagged, so it stays distinct from no tag.
+        let tag_bytes = tag.unwrap_or(0).to_le_bytes();
         let encoded_tag = [
-            flag as u8,
-            (tag_val & 0xFF) as u8,
-            (tag_val >> 8 & 0xFF) as u8,
-            (tag_val >> 16 & 0xFF) as u8,
-            (tag_val >> 24 & 0xFF) as u8,
+            tag.is_some() as u8,
+            tag_bytes[0],
+            tag_bytes[1],
+            tag_bytes[2],
+            tag_bytes[3],
             0,
             0,
             0,
@@ -259,13 +313,13 @@ pub fn classic_address_to_xaddress(
 /// use xrpl::core::exceptions::XRPLCoreException;
 ///
 /// let xaddress: &str = "X7AcgcsBL6XDcUb289X4mJ8djcdyKaB5hJDWMArnXr61cqZ";
-/// let classic: (String, Option<u64>, bool) = (
+/// let classic: (String, Option<u32>, bool) = (
 ///     "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59".into(),
 ///     None,
 ///     false,
 /// );
 ///
-/// let conversion: Option<(String, Option<u64>, bool)> = match xaddress_to_classic_address(xaddress) {
+/// let conversion: Option<(String, Option<u32>, bool)> = match xaddress_to_classic_address(xaddress) {
 ///     Ok((address, tag, is_test_network)) => Some((address, tag, is_test_network)),
 ///     Err(e) => match e {
 ///         XRPLCoreException::XRPLAddressCodecError(XRPLAddressCodecException::InvalidXAddressPrefix) => None,
@@ -282,13 +336,21 @@ pub fn classic_address_to_xaddress(
 ///
 /// assert_eq!(Some(classic), conversion);
 /// ```
-pub fn xaddress_to_classic_address(xaddress: &str) -> XRPLCoreResult<(String, Option<u64>, bool)> {
+pub fn xaddress_to_classic_address(xaddress: &str) -> XRPLCoreResult<(String, Option<u32>, bool)> {
     // Convert b58 to bytes
     let decoded = bs58::decode(xaddress)
         .with_alphabet(&XRPL_ALPHABET)
         .with_check(None)
         .into_vec()?;
 
+    if decoded.len() != XADDRESS_PAYLOAD_LENGTH {
+        return Err(XRPLAddressCodecException::UnexpectedPayloadLength {
+            expected: XADDRESS_PAYLOAD_LENGTH,
+            found: decoded.len(),
+        }
+        .into());
+    }
+
     let is_test_network = _is_test_address(&decoded[..2])?;
     let classic_address_bytes = &decoded[2..22];
     // extracts the destination tag
@@ -643,6 +705,86 @@ mod test {
         }
     }
 
+    /// Encodes an X-Address from a raw tag suffix, bypassing the
+    /// checks of `classic_address_to_xaddress`.
+    fn encode_raw_xaddress(tag_suffix: [u8; 9]) -> String {
+        let mut bytestring = ADDRESS_PREFIX_BYTES_MAIN.to_vec();
+        bytestring.extend_from_slice(
+            &decode_classic_address("r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59").unwrap(),
+        );
+        bytestring.extend_from_slice(&tag_suffix);
+
+        bs58::encode(bytestring)
+            .with_alphabet(&XRPL_ALPHABET)
+            .with_check()
+            .into_string()
+    }
+
+    #[test]
+    fn test_xaddress_tag_round_trip() {
+        let address = "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59";
+
+        for tag in [None, Some(0), Some(1), Some(u32::MAX)] {
+            for is_test_network in [true, false] {
+                let xaddress = classic_address_to_xaddress(address, tag, is_test_network).unwrap();
+                let prefix = if is_test_network { "T" } else { "X" };
+                assert!(xaddress.starts_with(prefix));
+                assert_eq!(
+                    xaddress_to_classic_address(&xaddress),
+                    Ok((address.to_string(), tag, is_test_network)),
+                );
+            }
+        }
+
+        assert_ne!(
+            classic_address_to_xaddress(address, None, false),
+            classic_address_to_xaddress(address, Some(0), false),
+        );
+    }
+
+    #[test]
+    fn test_xaddress_to_classic_address_invalid_tag() {
+        // 64-bit tags are not supported
+        let xaddress = encode_raw_xaddress([1, 1, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(
+            xaddress_to_classic_address(&xaddress),
+            Err(XRPLAddressCodecException::UnsupportedXAddress.into()),
+        );
+
+        // a tag without the tag flag
+        let xaddress = encode_raw_xaddress([0, 1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            xaddress_to_classic_address(&xaddress),
+            Err(XRPLAddressCodecException::InvalidXAddressZeroRemain.into()),
+        );
+
+        let xaddress = encode_raw_xaddress([2, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            xaddress_to_classic_address(&xaddress),
+            Err(XRPLAddressCodecException::UnsupportedXAddress.into()),
+        );
+    }
+
+    #[test]
+    fn test_xaddress_to_classic_address_invalid_length() {
+        let mut bytestring = ADDRESS_PREFIX_BYTES_MAIN.to_vec();
+        bytestring.extend_from_slice(&[0; 20]);
+        let xaddress = bs58::encode(bytestring)
+            .with_alphabet(&XRPL_ALPHABET)
+            .with_check()
+            .into_string();
+
+        assert_eq!(
+            xaddress_to_classic_address(&xaddress),
+            Err(XRPLAddressCodecException::UnexpectedPayloadLength {
+                expected: XADDRESS_PAYLOAD_LENGTH,
+                found: 22,
+            }
+            .into()),
+        );
+        assert!(!is_valid_xaddress(&xaddress));
+    }
+
     #[test]
     fn test_encode_node_public_key() {
         let bytes = hex::decode(NODE_PUBLIC_KEY_HEX_TEST).expect("");
@@ -749,4 +891,62 @@ mod test {
         assert_eq!(decode_result, bytes);
         assert_eq!(encoding_type, CryptoAlgorithm::ED25519);
     }
+
+    #[test]
+    fn test_entropy_hex_seed_round_trip() {
+        for (entropy_hex, algorithm, seed) in [
+            (
+                SECP256K1_HEX_TEST,
+                CryptoAlgorithm::SECP256K1,
+                SECP256K1_ENCODED_SEED_TEST,
+            ),
+            (
+                ED25519_HEX_TEST,
+                CryptoAlgorithm::ED25519,
+                ED25519_ENCODED_SEED_TEST,
+            ),
+        ] {
+            assert_eq!(
+                seed_from_entropy_hex(entropy_hex, algorithm.clone()),
+                Ok(seed.to_string())
+            );
+            assert_eq!(
+                entropy_hex_from_seed(seed),
+                Ok((entropy_hex.to_string(), algorithm))
+            );
+        }
+
+        // Every entropy survives the round trip through both algorithms.
+        for entropy_hex in [
+            "00000000000000000000000000000000",
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+        ] {
+            for algorithm in CryptoAlgorithm::iter() {
+                let seed = seed_from_entropy_hex(entropy_hex, algorithm.clone()).unwrap();
+                assert_eq!(
+                    entropy_hex_from_seed(&seed),
+                    Ok((entropy_hex.to_string(), algorithm))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_seed_from_entropy_hex_rejects_wrong_length() {
+        for entropy_hex in [
+            "",
+            "CF2DE378FBDD7E2EE87D486DFB5A7B",
+            "CF2DE378FBDD7E2EE87D486DFB5A7BFF00",
+        ] {
+            assert_eq!(
+                seed_from_entropy_hex(entropy_hex, CryptoAlgorithm::ED25519),
+                Err(XRPLAddressCodecException::UnexpectedPayloadLength {
+                    expected: SEED_LENGTH,
+                    found: entropy_hex.len() / 2,
+                }
+                .into())
+            );
+        }
+        assert!(seed_from_entropy_hex("not hex", CryptoAlgorithm::ED25519).is_err());
+    }
 }
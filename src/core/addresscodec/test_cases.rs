@@ -1,6 +1,6 @@
 pub struct TestCase<'a> {
     pub address: &'a str,
-    pub tag: Option<u64>,
+    pub tag: Option<u32>,
     pub main_xaddress: &'a str,
     pub test_xaddress: &'a str,
 }
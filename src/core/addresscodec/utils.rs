@@ -20,6 +20,8 @@ pub(crate) const ADDRESS_PREFIX_BYTES_TEST: [u8; 2] = [0x04, 0x93];
 pub(crate) const CLASSIC_ADDRESS_ID_LENGTH: usize = 20;
 /// Classic address length
 pub(crate) const CLASSIC_ADDRESS_LENGTH: u8 = 20;
+/// Length of a decoded X-Address: prefix, account ID and tag.
+pub(crate) const XADDRESS_PAYLOAD_LENGTH: usize = 31;
 /// base58 encodings: https://xrpl.org/base58-encodings.html
 /// Account address (20 bytes)
 pub(crate) const CLASSIC_ADDRESS_PREFIX: [u8; 1] = [0x0];
@@ -4,9 +4,6 @@ use crate::core::binarycodec::exceptions::XRPLBinaryCodecException;
 use crate::core::binarycodec::utils::*;
 use crate::core::exceptions::XRPLCoreException;
 use crate::core::exceptions::XRPLCoreResult;
-use crate::utils::ToBytes;
-use alloc::borrow::ToOwned;
-use alloc::vec;
 use alloc::vec::Vec;
 use core::convert::TryFrom;
 use core::convert::TryInto;
@@ -32,7 +29,13 @@ pub type BinarySerializer = Vec<u8>;
 /// assert_eq!(binary_parser, test_bytes[..]);
 /// ```
 #[derive(Debug, Clone)]
-pub struct BinaryParser(Vec<u8>);
+pub struct BinaryParser {
+    buffer: Vec<u8>,
+    /// How many bytes of `buffer` were consumed. Reading moves it
+    /// instead of shrinking the buffer, so parsing a large object
+    /// stays linear in its size.
+    position: usize,
+}
 
 /// Helper function for length-prefixed fields including
 /// Blob types and some AccountID types. Calculates the
@@ -46,11 +49,10 @@ pub struct BinaryParser(Vec<u8>);
 ///
 /// See Length Prefixing:
 /// `<https://xrpl.org/serialization.html#length-prefixing>`
-fn _encode_variable_length_prefix(length: &usize) -> XRPLCoreResult<Vec<u8>> {
+fn _encode_variable_length_prefix(length: &usize) -> XRPLCoreResult<([u8; 3], usize)> {
     if length <= &MAX_SINGLE_BYTE_LENGTH {
-        Ok([*length as u8].to_vec())
+        Ok(([*length as u8, 0, 0], 1))
     } else if length < &MAX_DOUBLE_BYTE_LENGTH {
-        let mut bytes = vec![];
         let b_length = *length - (MAX_SINGLE_BYTE_LENGTH + 1);
         let val_a: u8 = ((b_length >> 8) + (MAX_SINGLE_BYTE_LENGTH + 1))
             .try_into()
@@ -59,12 +61,8 @@ fn _encode_variable_length_prefix(length: &usize) -> XRPLCoreResult<Vec<u8>> {
             .try_into()
             .map_err(XRPLBinaryCodecException::TryFromIntError)?;
 
-        bytes.extend_from_slice(&[val_a]);
-        bytes.extend_from_slice(&[val_b]);
-
-        Ok(bytes)
+        Ok(([val_a, val_b, 0], 2))
     } else if length <= &MAX_LENGTH_VALUE {
-        let mut bytes = vec![];
         let b_length = *length - MAX_DOUBLE_BYTE_LENGTH;
         let val_a: u8 = ((MAX_SECOND_BYTE_VALUE + 1) + (b_length >> 16))
             .try_into()
@@ -76,11 +74,7 @@ fn _encode_variable_length_prefix(length: &usize) -> XRPLCoreResult<Vec<u8>> {
             .try_into()
             .map_err(XRPLBinaryCodecException::TryFromIntError)?;
 
-        bytes.extend_from_slice(&[val_a]);
-        bytes.extend_from_slice(&[val_b]);
-        bytes.extend_from_slice(&[val_c]);
-
-        Ok(bytes)
+        Ok(([val_a, val_b, val_c], 3))
     } else {
         Err(XRPLBinaryCodecException::InvalidVariableLengthTooLarge {
             max: MAX_LENGTH_VALUE,
@@ -329,7 +323,7 @@ pub trait Parser {
     /// BinaryParser and return a FieldInstance object
     /// representing information about the field
     /// containedin the following bytes.
-    fn read_field(&mut self) -> XRPLCoreResult<FieldInstance>;
+    fn read_field(&mut self) -> XRPLCoreResult<&'static FieldInstance>;
 
     /// Read next bytes from BinaryParser as the given type.
     fn read_type<T: TryFromParser>(&mut self) -> XRPLCoreResult<T, T::Error>;
@@ -414,12 +408,12 @@ pub trait Serialization {
     /// let test_bytes: Vec<u8> = [0, 17, 34].to_vec();
     /// let mut serializer: BinarySerializer = BinarySerializer::new();
     ///
-    /// serializer.write_field_and_value(field_instance, &test_bytes, false);
+    /// serializer.write_field_and_value(&field_instance, &test_bytes, false);
     /// assert_eq!(expected, serializer);
     /// ```
     fn write_field_and_value(
         &mut self,
-        field: FieldInstance,
+        field: &FieldInstance,
         value: &[u8],
         is_unl_modify_workaround: bool,
     ) -> &Self;
@@ -432,27 +426,26 @@ impl Serialization for BinarySerializer {
     }
 
     fn write_length_encoded(&mut self, value: &[u8], encode_value: bool) -> &Self {
-        let mut byte_object: Vec<u8> = Vec::new();
-        if encode_value {
-            // write value to byte_object
-            byte_object.extend_from_slice(value);
-        }
+        // Without the value, only a zero length is written.
+        let length = if encode_value { value.len() } else { 0 };
         // TODO Handle unwrap better
-        let length_prefix = _encode_variable_length_prefix(&byte_object.len()).unwrap();
+        let (length_prefix, prefix_length) = _encode_variable_length_prefix(&length).unwrap();
 
-        self.extend_from_slice(&length_prefix);
-        self.extend_from_slice(&byte_object);
+        self.extend_from_slice(&length_prefix[..prefix_length]);
+        if encode_value {
+            self.extend_from_slice(value);
+        }
 
         self
     }
 
     fn write_field_and_value(
         &mut self,
-        field: FieldInstance,
+        field: &FieldInstance,
         value: &[u8],
         is_unl_modify_workaround: bool,
     ) -> &Self {
-        self.extend_from_slice(&field.header.to_bytes());
+        self.extend_from_slice(field.header_bytes());
 
         if field.is_vl_encoded {
             self.write_length_encoded(value, !is_unl_modify_workaround);
@@ -467,31 +460,19 @@ impl Serialization for BinarySerializer {
 /// Peek the first byte of the BinaryParser.
 impl Parser for BinaryParser {
     fn peek(&self) -> Option<[u8; 1]> {
-        if !self.0.is_empty() {
-            Some(self.0[0].to_be_bytes())
-        } else {
-            None
-        }
+        self.remaining().first().map(|byte| byte.to_be_bytes())
     }
 
     fn skip_bytes(&mut self, n: usize) -> XRPLCoreResult<&Self> {
-        if n > self.0.len() {
-            Err(XRPLBinaryCodecException::UnexpectedParserSkipOverflow {
-                max: self.0.len(),
-                found: n,
-            }
-            .into())
-        } else {
-            self.0 = self.0[n..].to_vec();
-            Ok(self)
-        }
+        self.advance(n)?;
+        Ok(self)
     }
 
     fn read(&mut self, n: usize) -> XRPLCoreResult<Vec<u8>> {
-        let first_n_bytes = self.0[..n].to_owned();
+        let start = self.position;
+        self.advance(n)?;
 
-        self.skip_bytes(n)?;
-        Ok(first_n_bytes)
+        Ok(self.buffer[start..self.position].to_vec())
     }
 
     fn read_uint8(&mut self) -> XRPLCoreResult<u8> {
@@ -517,9 +498,9 @@ impl Parser for BinaryParser {
 
     fn is_end(&self, custom_end: Option<usize>) -> bool {
         if let Some(end) = custom_end {
-            self.0.len() <= end
+            self.len() <= end
         } else {
-            self.0.is_empty()
+            self.remaining().is_empty()
         }
     }
 
@@ -591,17 +572,11 @@ impl Parser for BinaryParser {
         })
     }
 
-    fn read_field(&mut self) -> XRPLCoreResult<FieldInstance> {
+    fn read_field(&mut self) -> XRPLCoreResult<&'static FieldInstance> {
         let field_header = self.read_field_header()?;
-        let field_name = get_field_name_from_header(&field_header);
-
-        if let Some(name) = field_name {
-            if let Some(instance) = get_field_instance(name) {
-                return Ok(instance);
-            };
-        };
 
-        Err(XRPLBinaryCodecException::UnknownFieldName.into())
+        get_field_instance_from_header(&field_header)
+            .ok_or_else(|| XRPLBinaryCodecException::UnknownFieldName.into())
     }
 
     fn read_type<T: TryFromParser>(&mut self) -> XRPLCoreResult<T, T::Error> {
@@ -624,15 +599,72 @@ impl Parser for BinaryParser {
     }
 }
 
+impl BinaryParser {
+    /// Read `reader` to its end and parse what it returned, e.g. a
+    /// large binary file, without going through a hex string.
+    ///
+    /// This is not streaming: all of `reader` is read into memory
+    /// before parsing starts.
+    ///
+    /// # Examples
+    ///
+    /// ## Basic usage
+    ///
+    /// ```
+    /// use xrpl::core::binarycodec::BinaryParser;
+    /// use xrpl::core::Parser;
+    ///
+    /// let test_bytes: &[u8] = &[0, 17, 34, 51, 68, 85, 102];
+    /// let mut binary_parser = BinaryParser::from_reader_to_end(test_bytes).unwrap();
+    ///
+    /// assert_eq!(binary_parser.read_uint16().unwrap(), 17);
+    /// assert_eq!(binary_parser, test_bytes[2..]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_reader_to_end(mut reader: impl alloc::io::Read) -> alloc::io::Result<Self> {
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer)?;
+
+        Ok(BinaryParser::from(buffer))
+    }
+
+    /// The bytes that were not read yet.
+    pub fn remaining(&self) -> &[u8] {
+        &self.buffer[self.position..]
+    }
+
+    /// How many bytes were read so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    fn advance(&mut self, n: usize) -> XRPLCoreResult<()> {
+        let remaining = self.len();
+        if n > remaining {
+            return Err(XRPLBinaryCodecException::UnexpectedParserSkipOverflow {
+                max: remaining,
+                found: n,
+            }
+            .into());
+        }
+        self.position += n;
+
+        Ok(())
+    }
+}
+
 impl From<&[u8]> for BinaryParser {
     fn from(hex_bytes: &[u8]) -> Self {
-        BinaryParser(hex_bytes.to_vec())
+        BinaryParser::from(hex_bytes.to_vec())
     }
 }
 
 impl From<Vec<u8>> for BinaryParser {
     fn from(hex_bytes: Vec<u8>) -> Self {
-        BinaryParser(hex_bytes)
+        BinaryParser {
+            buffer: hex_bytes,
+            position: 0,
+        }
     }
 }
 
@@ -640,25 +672,25 @@ impl TryFrom<&str> for BinaryParser {
     type Error = XRPLCoreException;
 
     fn try_from(hex_bytes: &str) -> XRPLCoreResult<Self, Self::Error> {
-        Ok(BinaryParser(hex::decode(hex_bytes)?))
+        Ok(BinaryParser::from(hex::decode(hex_bytes)?))
     }
 }
 
 impl PartialEq<[u8]> for BinaryParser {
     fn eq(&self, bytes: &[u8]) -> bool {
-        self.0 == bytes
+        self.remaining() == bytes
     }
 }
 
 impl PartialEq<Vec<u8>> for BinaryParser {
     fn eq(&self, bytes: &Vec<u8>) -> bool {
-        &self.0 == bytes
+        self.remaining() == bytes.as_slice()
     }
 }
 
 impl ExactSizeIterator for BinaryParser {
     fn len(&self) -> usize {
-        self.0.len()
+        self.buffer.len() - self.position
     }
 }
 
@@ -678,6 +710,7 @@ impl Iterator for BinaryParser {
 mod test {
     use super::*;
     use crate::alloc::string::ToString;
+    use alloc::borrow::ToOwned;
     use alloc::string::String;
 
     const TEST_HEX: &str = "00112233445566";
@@ -771,9 +804,75 @@ mod test {
         assert_eq!(result, Ok(0));
     }
 
-    // TODO Finish tests
     #[test]
-    fn test_read_field_header() {}
+    fn test_read_out_of_range() {
+        let test_bytes: Vec<u8> = hex::decode(TEST_HEX).expect("");
+        let mut binary_parser = BinaryParser::from(test_bytes.as_ref());
+
+        assert_eq!(
+            binary_parser.read(8),
+            Err(XRPLBinaryCodecException::UnexpectedParserSkipOverflow { max: 7, found: 8 }.into())
+        );
+        // A failed read consumes nothing.
+        assert_eq!(binary_parser, test_bytes[..]);
+        assert!(binary_parser.skip_bytes(6).is_ok());
+        assert!(binary_parser.read_uint16().is_err());
+        assert!(binary_parser.read_uint32().is_err());
+        assert_eq!(binary_parser.read_uint8(), Ok(0x66));
+        assert!(binary_parser.read_uint8().is_err());
+        assert_eq!(binary_parser.peek(), None);
+    }
+
+    #[test]
+    fn test_read_field_header() {
+        for (hex_header, type_code, field_code) in [
+            ("24", 2, 4),
+            ("2019", 2, 25),
+            ("0110", 16, 1),
+            ("001011", 16, 17),
+        ] {
+            let mut binary_parser = BinaryParser::try_from(hex_header).unwrap();
+            assert_eq!(
+                binary_parser.read_field_header(),
+                Ok(FieldHeader {
+                    type_code,
+                    field_code
+                })
+            );
+            assert!(binary_parser.is_end(None));
+        }
+
+        // Truncated headers and codes that should have fit in one byte.
+        for hex_header in ["", "00", "20", "0010", "01", "2001", "0001"] {
+            let mut binary_parser = BinaryParser::try_from(hex_header).unwrap();
+            assert!(binary_parser.read_field_header().is_err(), "{hex_header}");
+        }
+    }
+
+    #[test]
+    fn test_read_keeps_position() {
+        let test_bytes: Vec<u8> = hex::decode(TEST_HEX).expect("");
+        let mut binary_parser = BinaryParser::from(test_bytes.as_ref());
+
+        assert_eq!(binary_parser.read(2), Ok(test_bytes[..2].to_vec()));
+        assert!(binary_parser.skip_bytes(3).is_ok());
+        assert_eq!(binary_parser.position(), 5);
+        assert_eq!(binary_parser.remaining(), &test_bytes[5..]);
+        assert_eq!(binary_parser.len(), 2);
+        assert!(binary_parser.is_end(Some(2)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_reader_to_end() {
+        let test_bytes: Vec<u8> = hex::decode(TEST_HEX).expect("");
+        let mut binary_parser =
+            BinaryParser::from_reader_to_end(alloc::io::Cursor::new(test_bytes.clone())).unwrap();
+
+        assert_eq!(binary_parser, test_bytes);
+        assert_eq!(binary_parser.read_uint32(), Ok(0x00112233));
+        assert_eq!(binary_parser.collect::<Vec<u8>>(), test_bytes[4..]);
+    }
 
     #[test]
     fn test_read_field_value() {}
@@ -819,10 +918,88 @@ mod test {
         let test_bytes: Vec<u8> = [0, 17, 34].to_vec();
         let mut serializer: BinarySerializer = BinarySerializer::new();
 
-        serializer.write_field_and_value(field_instance, &test_bytes, false);
+        serializer.write_field_and_value(&field_instance, &test_bytes, false);
         assert_eq!(expected, serializer);
     }
 
+    /// Read every field of the buffer, as far as the types with a
+    /// parser allow.
+    fn read_fields(binary_parser: &mut BinaryParser) -> XRPLCoreResult<()> {
+        use crate::core::binarycodec::types::{AccountId, Amount, Hash128, Hash256, Issue};
+
+        while !binary_parser.is_end(None) {
+            let field = binary_parser.read_field()?;
+            match field.associated_type.as_str() {
+                "UInt8" => binary_parser.read_uint8().map(|_| ())?,
+                "UInt16" => binary_parser.read_uint16().map(|_| ())?,
+                "UInt32" => binary_parser.read_uint32().map(|_| ())?,
+                "Hash128" => binary_parser
+                    .read_field_value::<Hash128>(field)
+                    .map(|_| ())?,
+                "Hash256" => binary_parser
+                    .read_field_value::<Hash256>(field)
+                    .map(|_| ())?,
+                "AccountID" => binary_parser
+                    .read_field_value::<AccountId>(field)
+                    .map(|_| ())?,
+                "Amount" => binary_parser
+                    .read_field_value::<Amount>(field)
+                    .map(|_| ())?,
+                "Issue" => binary_parser.read_field_value::<Issue>(field).map(|_| ())?,
+                "Blob" => {
+                    let length = binary_parser.read_length_prefix()?;
+                    binary_parser.read(length).map(|_| ())?
+                }
+                _ => return Ok(()),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_truncated_and_garbage_buffers() {
+        let payment = crate::core::binarycodec::encode(&serde_json::json!({
+            "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+            "Amount": {
+                "currency": "USD",
+                "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "value": "1234.5678"
+            },
+            "Destination": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            "Fee": "12",
+            "Flags": 2147483648u32,
+            "Sequence": 359,
+            "SigningPubKey": "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020",
+            "TransactionType": "Payment",
+        }))
+        .unwrap();
+        let payment = hex::decode(payment).unwrap();
+
+        assert_eq!(
+            read_fields(&mut BinaryParser::from(payment.as_ref())),
+            Ok(())
+        );
+        // Cutting the last byte off always leaves a field incomplete.
+        for end in 0..payment.len() {
+            let _ = read_fields(&mut BinaryParser::from(&payment[..end]));
+        }
+        assert!(read_fields(&mut BinaryParser::from(&payment[..payment.len() - 1])).is_err());
+
+        // A fixed linear congruential generator keeps the garbage reproducible.
+        let mut seed: u32 = 0x2545F491;
+        for _ in 0..2000 {
+            let length = (seed % 64) as usize;
+            let garbage: Vec<u8> = (0..length)
+                .map(|_| {
+                    seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                    (seed >> 24) as u8
+                })
+                .collect();
+            let _ = read_fields(&mut BinaryParser::from(garbage));
+        }
+    }
+
     /// This is currently a sanity check for private
     /// [`_encode_variable_length_prefix`], which is called by
     /// BinarySerializer.write_length_encoded.
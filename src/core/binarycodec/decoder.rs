@@ -0,0 +1,529 @@
+//! Decoding of the XRP Ledger's canonical binary format into JSON.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::Range;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::core::{
+    binarycodec::{
+        definitions::{
+            get_ledger_entry_type_name, get_transaction_result_name, get_transaction_type_name,
+            FieldInstance,
+        },
+        types::{
+            exceptions::XRPLTypeException, AccountId, Amount, Currency, Hash128, Hash160, Hash256,
+            PathSet, TryFromParser, Vector256, XRPLType,
+        },
+        BinaryParser, Parser,
+    },
+    exceptions::XRPLCoreResult,
+};
+use crate::XRPLSerdeJsonError;
+
+const OBJECT_END_MARKER: &str = "ObjectEndMarker";
+const ARRAY_END_MARKER: &str = "ArrayEndMarker";
+const XCHAIN_BRIDGE_FIELDS: [(&str, &str); 4] = [
+    ("LockingChainDoor", "AccountID"),
+    ("LockingChainIssue", "Issue"),
+    ("IssuingChainDoor", "AccountID"),
+    ("IssuingChainIssue", "Issue"),
+];
+
+/// Decode a serialized object, e.g. a signed transaction blob, into
+/// its JSON representation.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::{decode, encode};
+/// use serde_json::json;
+///
+/// let tx = json!({
+///     "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+///     "Fee": "12",
+///     "Sequence": 1,
+///     "TransactionType": "AccountSet"
+/// });
+/// let blob = encode(&tx).unwrap();
+///
+/// assert_eq!(decode(&blob).unwrap(), tx);
+/// ```
+pub fn decode(blob: &str) -> XRPLCoreResult<Value> {
+    decode_bytes(&hex::decode(blob)?)
+}
+
+/// Like [`decode`], hex decoding `blob` into `buffer` rather than a
+/// new allocation, to decode many objects in a row, e.g. the ledger
+/// entries of a `ledger_data` page.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::{decode_with_buffer, encode};
+/// use serde_json::json;
+///
+/// let mut buffer = Vec::new();
+/// for sequence in 1..=3 {
+///     let tx = json!({
+///         "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+///         "Fee": "12",
+///         "Sequence": sequence,
+///         "TransactionType": "AccountSet"
+///     });
+///     let blob = encode(&tx).unwrap();
+///
+///     assert_eq!(decode_with_buffer(&blob, &mut buffer).unwrap(), tx);
+/// }
+/// ```
+pub fn decode_with_buffer(blob: &str, buffer: &mut Vec<u8>) -> XRPLCoreResult<Value> {
+    buffer.clear();
+    buffer.resize(blob.len() / 2, 0);
+    hex::decode_to_slice(blob, buffer)?;
+
+    decode_bytes(buffer)
+}
+
+fn decode_bytes(bytes: &[u8]) -> XRPLCoreResult<Value> {
+    let fields = decode_fields(bytes)?;
+
+    Ok(Value::Object(
+        fields
+            .into_iter()
+            .map(|decoded| (decoded.field.name.clone(), decoded.value))
+            .collect(),
+    ))
+}
+
+/// A top-level field of a decoded object.
+pub(crate) struct DecodedField {
+    pub(crate) field: &'static FieldInstance,
+    /// The bytes of the field, header included.
+    pub(crate) span: Range<usize>,
+    pub(crate) value: Value,
+}
+
+/// Decode the top-level fields of a serialized object, keeping
+/// where each of them is in `bytes`.
+pub(crate) fn decode_fields(bytes: &[u8]) -> XRPLCoreResult<Vec<DecodedField>> {
+    let mut parser = BinaryParser::from(bytes);
+    let mut fields = Vec::new();
+    while !parser.is_end(None) {
+        let start = parser.position();
+        let field = parser.read_field()?;
+        let value = decode_field_value(&mut parser, field)?;
+        fields.push(DecodedField {
+            field,
+            span: start..parser.position(),
+            value,
+        });
+    }
+
+    Ok(fields)
+}
+
+fn decode_object(parser: &mut BinaryParser) -> XRPLCoreResult<Map<String, Value>> {
+    let mut object = Map::new();
+    loop {
+        let field = parser.read_field()?;
+        if field.name == OBJECT_END_MARKER {
+            return Ok(object);
+        }
+        let value = decode_field_value(parser, field)?;
+        object.insert(field.name.clone(), value);
+    }
+}
+
+fn decode_array(parser: &mut BinaryParser) -> XRPLCoreResult<Vec<Value>> {
+    let mut array = Vec::new();
+    loop {
+        let field = parser.read_field()?;
+        if field.name == ARRAY_END_MARKER {
+            return Ok(array);
+        }
+        // Array members are objects wrapped in their field name,
+        // e.g. `{"Signer": {...}}`.
+        let mut member = Map::new();
+        member.insert(field.name.clone(), Value::Object(decode_object(parser)?));
+        array.push(Value::Object(member));
+    }
+}
+
+fn decode_field_value(parser: &mut BinaryParser, field: &FieldInstance) -> XRPLCoreResult<Value> {
+    let length = if field.is_vl_encoded {
+        Some(parser.read_length_prefix()?)
+    } else {
+        None
+    };
+
+    match field.associated_type.as_str() {
+        "STObject" => Ok(Value::Object(decode_object(parser)?)),
+        "STArray" => Ok(Value::Array(decode_array(parser)?)),
+        // UNLModify pseudo-transactions encode their zero `Account`
+        // with a length of zero.
+        "AccountID" if length == Some(0) => to_value(AccountId::new(None)?),
+        "AccountID" => to_value(AccountId::from_parser(parser, length)?),
+        "Amount" => to_value(Amount::from_parser(parser, None)?),
+        "Blob" => Ok(Value::String(hex::encode_upper(
+            parser.read(length.unwrap_or_default())?,
+        ))),
+        "Currency" => to_value(Currency::from_parser(parser, None)?),
+        "Hash128" => to_value(Hash128::from_parser(parser, None)?),
+        "Hash160" => to_value(Hash160::from_parser(parser, None)?),
+        "Hash256" => to_value(Hash256::from_parser(parser, None)?),
+        "PathSet" => to_value(PathSet::from_parser(parser, None)?),
+        "Vector256" => to_value(Vector256::from_parser(parser, length)?),
+        "Issue" => decode_issue(parser),
+        "XChainBridge" => decode_xchain_bridge(parser),
+        "UInt8" => {
+            let value = parser.read_uint8()?;
+            match field.name.as_str() {
+                "TransactionResult" => Ok(get_transaction_result_name(&(value as i16))
+                    .map_or(Value::from(value), |name| Value::from(name.as_str()))),
+                _ => Ok(Value::from(value)),
+            }
+        }
+        "UInt16" => {
+            let value = parser.read_uint16()?;
+            let code = value as i16;
+            let name = match field.name.as_str() {
+                "TransactionType" => get_transaction_type_name(&code),
+                "LedgerEntryType" => get_ledger_entry_type_name(&code),
+                _ => None,
+            };
+            Ok(name.map_or(Value::from(value), |name| Value::from(name.as_str())))
+        }
+        "UInt32" => Ok(Value::from(parser.read_uint32()?)),
+        // UInt64 fields are hex strings in JSON, as they exceed the
+        // safe integer range of JavaScript.
+        "UInt64" => Ok(Value::String(hex::encode_upper(parser.read(8)?))),
+        _ => Err(XRPLTypeException::UnknownXRPLType.into()),
+    }
+}
+
+fn decode_issue(parser: &mut BinaryParser) -> XRPLCoreResult<Value> {
+    let currency = Currency::from_parser(parser, None)?;
+    let mut issue = Map::new();
+    let is_xrp = currency.to_string() == "XRP";
+    issue.insert("currency".into(), to_value(currency)?);
+    if !is_xrp {
+        issue.insert(
+            "issuer".into(),
+            to_value(AccountId::from_parser(parser, None)?)?,
+        );
+    }
+
+    Ok(Value::Object(issue))
+}
+
+fn decode_xchain_bridge(parser: &mut BinaryParser) -> XRPLCoreResult<Value> {
+    let mut bridge = Map::new();
+    for (name, field_type) in XCHAIN_BRIDGE_FIELDS {
+        let value = match field_type {
+            "AccountID" => {
+                let length = parser.read_length_prefix()?;
+                to_value(AccountId::from_parser(parser, Some(length))?)?
+            }
+            _ => decode_issue(parser)?,
+        };
+        bridge.insert(name.into(), value);
+    }
+
+    Ok(Value::Object(bridge))
+}
+
+fn to_value<T: Serialize>(value: T) -> XRPLCoreResult<Value> {
+    Ok(serde_json::to_value(value).map_err(XRPLSerdeJsonError::from)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::binarycodec::encode;
+
+    const CODEC_TEST_FIXTURES: &str = include_str!("./test_data/codec-fixtures.json");
+
+    #[test]
+    fn test_decode_codec_fixtures() {
+        let fixtures: Value = serde_json::from_str(CODEC_TEST_FIXTURES).unwrap();
+        for fixture in fixtures["transactions"].as_array().unwrap() {
+            let binary = fixture["binary"].as_str().unwrap();
+
+            assert_eq!(decode(binary).unwrap(), fixture["json"], "{binary}");
+        }
+    }
+
+    #[test]
+    fn test_decode_with_buffer() {
+        let fixtures: Value = serde_json::from_str(CODEC_TEST_FIXTURES).unwrap();
+        let mut buffer = Vec::new();
+        for fixture in fixtures["accountState"].as_array().unwrap() {
+            let binary = fixture["binary"].as_str().unwrap();
+
+            assert_eq!(
+                decode_with_buffer(binary, &mut buffer).unwrap(),
+                fixture["json"],
+                "{binary}"
+            );
+        }
+        assert!(decode_with_buffer("ABC", &mut buffer).is_err());
+    }
+
+    #[test]
+    fn test_decode_round_trip() {
+        let tx = serde_json::json!({
+            "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+            "Amount": {
+                "currency": "USD",
+                "issuer": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+                "value": "1.5"
+            },
+            "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+            "Fee": "12",
+            "Flags": 131072,
+            "Memos": [
+                {
+                    "Memo": {
+                        "MemoData": "72656E74",
+                        "MemoType": "687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963"
+                    }
+                }
+            ],
+            "Sequence": 2,
+            "TransactionType": "Payment"
+        });
+        let blob = encode(&tx).unwrap();
+
+        assert_eq!(decode(&blob).unwrap(), tx);
+    }
+
+    #[test]
+    fn test_pseudo_transactions() {
+        let unl_modify = serde_json::json!({
+            "Account": "rrrrrrrrrrrrrrrrrrrrrhoLvTp",
+            "Fee": "0",
+            "LedgerSequence": 1600000,
+            "Sequence": 0,
+            "SigningPubKey": "",
+            "TransactionType": "UNLModify",
+            "UNLModifyDisabling": 1,
+            "UNLModifyValidator": "ED6629D456285AE3613B285F65BBFF168D695BA3921F309949AFCD2CA7AFEC16FE"
+        });
+        let unl_modify_blob = concat!(
+            "120066",
+            "2400000000",
+            "2600186A00",
+            "684000000000000000",
+            "7300",
+            "701321ED6629D456285AE3613B285F65BBFF168D695BA3921F309949AFCD2CA7AFEC16FE",
+            // The zero account, with a length of zero and no value.
+            "8100",
+            "00101101",
+        );
+        let set_fee = serde_json::json!({
+            "Account": "rrrrrrrrrrrrrrrrrrrrrhoLvTp",
+            "BaseFee": "000000000000000A",
+            "Fee": "0",
+            "ReferenceFeeUnits": 10,
+            "ReserveBase": 20000000,
+            "ReserveIncrement": 5000000,
+            "Sequence": 0,
+            "SigningPubKey": "",
+            "TransactionType": "SetFee"
+        });
+        let set_fee_blob = concat!(
+            "120065",
+            "2400000000",
+            "201E0000000A",
+            "201F01312D00",
+            "2020004C4B40",
+            "35000000000000000A",
+            "684000000000000000",
+            "7300",
+            "81140000000000000000000000000000000000000000",
+        );
+        let set_fee_drops = serde_json::json!({
+            "Account": "rrrrrrrrrrrrrrrrrrrrrhoLvTp",
+            "BaseFeeDrops": "10",
+            "Fee": "0",
+            "LedgerSequence": 92090368,
+            "ReserveBaseDrops": "1000000",
+            "ReserveIncrementDrops": "200000",
+            "Sequence": 0,
+            "SigningPubKey": "",
+            "TransactionType": "SetFee"
+        });
+        let set_fee_drops_blob = concat!(
+            "120065",
+            "2400000000",
+            "26057D3000",
+            "684000000000000000",
+            "6016400000000000000A",
+            "601740000000000F4240",
+            "60184000000000030D40",
+            "7300",
+            "81140000000000000000000000000000000000000000",
+        );
+        let enable_amendment = serde_json::json!({
+            "Account": "rrrrrrrrrrrrrrrrrrrrrhoLvTp",
+            "Amendment": "42426C4D4F1009EE67080A9B7965B44656D7714D104A72F9B4369F97ABF044EE",
+            "Fee": "0",
+            "Flags": 65536,
+            "LedgerSequence": 21225473,
+            "Sequence": 0,
+            "SigningPubKey": "",
+            "TransactionType": "EnableAmendment"
+        });
+        let enable_amendment_blob = concat!(
+            "120064",
+            "2200010000",
+            "2400000000",
+            "260143E001",
+            "501342426C4D4F1009EE67080A9B7965B44656D7714D104A72F9B4369F97ABF044EE",
+            "684000000000000000",
+            "7300",
+            "81140000000000000000000000000000000000000000",
+        );
+
+        for (json, blob) in [
+            (unl_modify, unl_modify_blob),
+            (set_fee, set_fee_blob),
+            (set_fee_drops, set_fee_drops_blob),
+            (enable_amendment, enable_amendment_blob),
+        ] {
+            assert_eq!(encode(&json).unwrap(), blob);
+            assert_eq!(decode(blob).unwrap(), json, "{blob}");
+        }
+    }
+
+    #[test]
+    fn test_account_state_fixtures() {
+        let fixtures: Value = serde_json::from_str(CODEC_TEST_FIXTURES).unwrap();
+        for fixture in fixtures["accountState"].as_array().unwrap() {
+            let binary = fixture["binary"].as_str().unwrap();
+
+            assert_eq!(encode(&fixture["json"]).unwrap(), binary);
+            assert_eq!(decode(binary).unwrap(), fixture["json"], "{binary}");
+        }
+    }
+
+    #[test]
+    fn test_uint64_hex_string_and_number() {
+        let offer = |owner_node: Value| {
+            serde_json::json!({
+                "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+                "Flags": 0,
+                "LedgerEntryType": "Offer",
+                "OwnerNode": owner_node,
+                "Sequence": 1
+            })
+        };
+
+        let blob = encode(&offer("0000000000000000".into())).unwrap();
+        assert!(blob.contains("340000000000000000"));
+        assert_eq!(decode(&blob).unwrap(), offer("0000000000000000".into()));
+        assert_eq!(encode(&offer(0.into())).unwrap(), blob);
+
+        // A number is encoded as the same value as the hex string it
+        // decodes to.
+        let blob = encode(&offer(4_294_967_296_u64.into())).unwrap();
+        assert!(blob.contains("340000000100000000"));
+        assert_eq!(decode(&blob).unwrap(), offer("0000000100000000".into()));
+        assert_eq!(encode(&offer("100000000".into())).unwrap(), blob);
+
+        assert!(encode(&offer("00000000000000000".into())).is_err());
+        assert!(encode(&offer("not hex".into())).is_err());
+    }
+
+    #[test]
+    fn test_decode_truncated() {
+        let fixtures: Value = serde_json::from_str(CODEC_TEST_FIXTURES).unwrap();
+        let binary = fixtures["transactions"][0]["binary"].as_str().unwrap();
+
+        assert!(decode(&binary[..binary.len() - 2]).is_err());
+        assert!(decode("ZZ").is_err());
+    }
+
+    #[test]
+    fn test_decode_fields_spans() {
+        let fixtures: Value = serde_json::from_str(CODEC_TEST_FIXTURES).unwrap();
+        let bytes = hex::decode(fixtures["transactions"][0]["binary"].as_str().unwrap()).unwrap();
+        let fields = decode_fields(&bytes).unwrap();
+
+        assert_eq!(fields[0].field.name, "TransactionType");
+        assert_eq!(fields[0].span, 0..3);
+        assert_eq!(fields.last().unwrap().span.end, bytes.len());
+        for window in fields.windows(2) {
+            assert_eq!(window[0].span.end, window[1].span.start);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "models")]
+    fn test_vector256_fields() {
+        use crate::models::{
+            ledger::objects::LedgerEntry, transactions::nftoken_cancel_offer::NFTokenCancelOffer,
+        };
+
+        let directory_node_blob = concat!(
+            "1100642200000000",
+            "310000000000000002",
+            "320000000000000001",
+            "58D0CAC45692858D395B16D52A0B44ADCB7EF178617C05BAE3C36FF5574BA012C3",
+            "8214E14829DB4C6419A8EFCAC1EC21D891A1A4339871",
+            "011340",
+            "A95EB2892EA15C8B7BCDAF6D1A8F1F21791192586EBD66B7DCBEC582BFAAA198",
+            "52733E959FD0D25A72E188A26BC406768D91285883108AED061121408DAD4AF0",
+        );
+        let LedgerEntry::DirectoryNode(directory_node) =
+            serde_json::from_value(decode(directory_node_blob).unwrap()).unwrap()
+        else {
+            panic!("expected a DirectoryNode");
+        };
+        assert_eq!(
+            directory_node.indexes,
+            [
+                "A95EB2892EA15C8B7BCDAF6D1A8F1F21791192586EBD66B7DCBEC582BFAAA198",
+                "52733E959FD0D25A72E188A26BC406768D91285883108AED061121408DAD4AF0"
+            ]
+        );
+        assert_eq!(
+            directory_node.index_next.as_deref(),
+            Some("0000000000000002")
+        );
+        assert_eq!(encode(&directory_node).unwrap(), directory_node_blob);
+
+        let nftoken_cancel_offer = NFTokenCancelOffer::new(
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh".into(),
+            None,
+            Some("10".into()),
+            None,
+            None,
+            Some(1),
+            None,
+            None,
+            None,
+            alloc::vec![
+                "9C92E061381C1EF37A8CDE0E8FC35188BFC30B1883825042A64309AC09F4C36D".into(),
+                "E3AE23D7B62A3E52A8D6A0E5C1F6A2A81A0D8F5AD16E36D1BC28CB1D6F6E9A0F".into(),
+            ],
+        );
+        let blob = encode(&nftoken_cancel_offer).unwrap();
+        assert!(blob.contains(concat!(
+            "041340",
+            "9C92E061381C1EF37A8CDE0E8FC35188BFC30B1883825042A64309AC09F4C36D",
+            "E3AE23D7B62A3E52A8D6A0E5C1F6A2A81A0D8F5AD16E36D1BC28CB1D6F6E9A0F",
+        )));
+        let decoded: NFTokenCancelOffer = serde_json::from_value(decode(&blob).unwrap()).unwrap();
+        assert_eq!(decoded, nftoken_cancel_offer);
+
+        let mut invalid = nftoken_cancel_offer;
+        invalid.nftoken_offers[1] = "E3AE23D7".into();
+        assert!(encode(&invalid).is_err());
+    }
+}
@@ -10,12 +10,13 @@ pub use self::types::*;
 use crate::utils::ToBytes;
 use alloc::string::String;
 use alloc::string::ToString;
-use alloc::vec;
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
 pub const CODE_MIN_VALUE: i16 = 1;
 pub const CODE_MAX_VALUE: i16 = u8::MAX as i16;
+/// The maximum number of bytes of an encoded FieldHeader.
+pub const FIELD_HEADER_MAX_LENGTH: usize = 3;
 
 /// A container class for simultaneous storage of a field's
 /// type code and field code.
@@ -32,7 +33,7 @@ pub const CODE_MAX_VALUE: i16 = u8::MAX as i16;
 ///     field_code: 0,
 /// };
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FieldHeader {
     pub type_code: i16,
     pub field_code: i16,
@@ -76,6 +77,7 @@ pub struct FieldInstance {
     pub name: String,
     pub header: FieldHeader,
     pub ordinal: i32,
+    encoded_header: ([u8; FIELD_HEADER_MAX_LENGTH], usize),
 }
 
 ///Model object for field info metadata from the
@@ -127,43 +129,55 @@ impl FieldInstance {
             is_signing: field_info.is_signing_field,
             name: field_name.to_string(),
             ordinal: &(field_header.type_code as i32) << 16 | field_info.nth as i32,
+            encoded_header: field_header.encode(),
             header: field_header,
             associated_type: field_info.r#type.to_string(),
         }
     }
-}
 
-impl Display for FieldHeader {
-    /// Convert the FieldHeader to a String.
-    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        write!(f, "{}_{}", self.type_code, self.field_code)
+    /// Get the binary encoding of this field's header,
+    /// computed once when the FieldInstance is created.
+    pub fn header_bytes(&self) -> &[u8] {
+        let (bytes, length) = &self.encoded_header;
+        &bytes[..*length]
     }
 }
 
-impl ToBytes for FieldHeader {
-    /// Convert the FieldHeader to a Vec<u8>.
-    fn to_bytes(&self) -> Vec<u8> {
-        let mut header_bytes = vec![];
+impl FieldHeader {
+    /// Encode the FieldHeader without allocating. Returns
+    /// a buffer and the number of bytes of it in use.
+    ///
+    /// See Field IDs:
+    /// `<https://xrpl.org/serialization.html#field-ids>`
+    pub fn encode(&self) -> ([u8; FIELD_HEADER_MAX_LENGTH], usize) {
+        let type_code = self.type_code as u8;
+        let field_code = self.field_code as u8;
 
         if self.type_code < 16 {
             if self.field_code < 16 {
-                let shift = (self.type_code << 4 | self.field_code) as u8;
-                header_bytes.extend_from_slice(&shift.to_be_bytes());
+                ([type_code << 4 | field_code, 0, 0], 1)
             } else {
-                let shift = (self.type_code << 4) as u8;
-
-                header_bytes.extend_from_slice(&shift.to_be_bytes());
-                header_bytes.extend_from_slice(&(self.field_code as u8).to_be_bytes());
+                ([type_code << 4, field_code, 0], 2)
             }
         } else if self.field_code < 16 {
-            header_bytes.extend_from_slice(&(self.field_code as u8).to_be_bytes());
-            header_bytes.extend_from_slice(&(self.type_code as u8).to_be_bytes());
+            ([field_code, type_code, 0], 2)
         } else {
-            header_bytes.extend_from_slice(&[0]);
-            header_bytes.extend_from_slice(&(self.type_code as u8).to_be_bytes());
-            header_bytes.extend_from_slice(&(self.field_code as u8).to_be_bytes());
+            ([0, type_code, field_code], 3)
         }
+    }
+}
 
-        header_bytes
+impl Display for FieldHeader {
+    /// Convert the FieldHeader to a String.
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}_{}", self.type_code, self.field_code)
+    }
+}
+
+impl ToBytes for FieldHeader {
+    /// Convert the FieldHeader to a Vec<u8>.
+    fn to_bytes(&self) -> Vec<u8> {
+        let (bytes, length) = self.encode();
+        bytes[..length].to_vec()
     }
 }
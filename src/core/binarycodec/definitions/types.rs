@@ -4,18 +4,19 @@
 use super::FieldHeader;
 use super::FieldInfo;
 use super::FieldInstance;
+use crate::core::binarycodec::IndexMap;
 use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
 use alloc::string::String;
-use alloc::string::ToString;
 use alloc::vec::Vec;
-use indexmap::IndexMap;
-use lazy_static::lazy_static;
+use once_cell::race::OnceBox;
 use serde::{Deserialize, Serialize};
 
 type FieldInfoMap = IndexMap<String, FieldInfo>;
 type TypeValueMap = IndexMap<String, i16>;
 type TypeNameMap = IndexMap<i16, String>;
-type FieldHeaderNameMap = IndexMap<String, String>;
+type FieldHeaderNameMap = IndexMap<FieldHeader, String>;
+type FieldInstanceMap = IndexMap<String, FieldInstance>;
 type TransactionTypeValueMap = IndexMap<String, i16>;
 type TransactionTypeNameMap = IndexMap<i16, String>;
 type TransactionResultValueMap = IndexMap<String, i16>;
@@ -357,6 +358,30 @@ pub struct TransactionTypes {
     pub deposit_preauth: i16,
     pub trust_set: i16,
     pub account_delete: i16,
+    pub set_hook: i16,
+    #[serde(rename = "NFTokenMint")]
+    pub nftoken_mint: i16,
+    #[serde(rename = "NFTokenBurn")]
+    pub nftoken_burn: i16,
+    #[serde(rename = "NFTokenCreateOffer")]
+    pub nftoken_create_offer: i16,
+    #[serde(rename = "NFTokenCancelOffer")]
+    pub nftoken_cancel_offer: i16,
+    #[serde(rename = "NFTokenAcceptOffer")]
+    pub nftoken_accept_offer: i16,
+    pub clawback: i16,
+    #[serde(rename = "AMMCreate")]
+    pub amm_create: i16,
+    #[serde(rename = "AMMDeposit")]
+    pub amm_deposit: i16,
+    #[serde(rename = "AMMWithdraw")]
+    pub amm_withdraw: i16,
+    #[serde(rename = "AMMVote")]
+    pub amm_vote: i16,
+    #[serde(rename = "AMMBid")]
+    pub amm_bid: i16,
+    #[serde(rename = "AMMDelete")]
+    pub amm_delete: i16,
     #[serde(rename = "XChainAccountCreateCommit")]
     pub xchain_account_create_commit: i16,
     #[serde(rename = "XChainAddAccountCreateAttestation")]
@@ -373,6 +398,12 @@ pub struct TransactionTypes {
     pub xchain_create_claim_id: i16,
     #[serde(rename = "XChainModifyBridge")]
     pub xchain_modify_bridge: i16,
+    #[serde(rename = "DIDSet")]
+    pub did_set: i16,
+    #[serde(rename = "DIDDelete")]
+    pub did_delete: i16,
+    pub oracle_set: i16,
+    pub oracle_delete: i16,
 
     pub enable_amendment: i16,
     pub set_fee: i16,
@@ -406,6 +437,7 @@ pub struct DefinitionMap {
     type_value_map: TypeValueMap,
     type_name_map: TypeNameMap,
     field_header_name_map: FieldHeaderNameMap,
+    field_instance_map: FieldInstanceMap,
     transaction_type_value_map: TransactionTypeValueMap,
     transaction_type_name_map: TransactionTypeNameMap,
     transaction_result_value_map: TransactionResultValueMap,
@@ -444,7 +476,10 @@ pub trait DefinitionHandler {
     /// FieldHeader object.
     fn get_field_name_from_header(&self, field_header: &FieldHeader) -> Option<&String>;
     /// Return a FieldInstance object for the given field name.
-    fn get_field_instance(&self, field_name: &str) -> Option<FieldInstance>;
+    fn get_field_instance(&self, field_name: &str) -> Option<&FieldInstance>;
+    /// Return the FieldInstance object described by the given
+    /// FieldHeader object.
+    fn get_field_instance_from_header(&self, field_header: &FieldHeader) -> Option<&FieldInstance>;
     /// Return an integer representing the given
     /// transaction type string in an enum.
     fn get_transaction_type_code(&self, transaction_type: &str) -> Option<&i16>;
@@ -471,6 +506,10 @@ trait DefinitionMaker {
         fields: &[Field],
         types: &TypeValueMap,
     ) -> (FieldInfoMap, FieldHeaderNameMap);
+    fn _make_field_instance_map(
+        field_info_map: &FieldInfoMap,
+        types: &TypeValueMap,
+    ) -> FieldInstanceMap;
     fn _make_transaction_type_maps(
         transaction_types: &TransactionTypes,
     ) -> (TransactionTypeValueMap, TransactionTypeNameMap);
@@ -510,12 +549,35 @@ impl DefinitionMaker for DefinitionMap {
             };
 
             field_info_map.insert(field_name.to_owned(), field_info);
-            field_header_name_map.insert(field_header.to_string(), field_name.to_owned());
+            field_header_name_map.insert(field_header, field_name.to_owned());
         }
 
         (field_info_map, field_header_name_map)
     }
 
+    fn _make_field_instance_map(
+        field_info_map: &FieldInfoMap,
+        types: &TypeValueMap,
+    ) -> FieldInstanceMap {
+        let mut field_instance_map = FieldInstanceMap::default();
+
+        for (field_name, field_info) in field_info_map {
+            let field_header = FieldHeader {
+                type_code: *types
+                    .get(&field_info.r#type)
+                    .expect("_make_field_instance_map"),
+                field_code: field_info.nth,
+            };
+
+            field_instance_map.insert(
+                field_name.to_owned(),
+                FieldInstance::new(field_info, field_name, field_header),
+            );
+        }
+
+        field_instance_map
+    }
+
     fn _make_transaction_type_maps(
         transaction_types: &TransactionTypes,
     ) -> (TransactionTypeValueMap, TransactionTypeNameMap) {
@@ -569,6 +631,8 @@ impl DefinitionHandler for DefinitionMap {
         let (type_value_map, type_name_map) = DefinitionMap::_make_type_maps(&definitions.types);
         let (field_info_map, field_header_name_map) =
             DefinitionMap::_make_field_info_map(&definitions.fields, &type_value_map);
+        let field_instance_map =
+            DefinitionMap::_make_field_instance_map(&field_info_map, &type_value_map);
         let (transaction_type_value_map, transaction_type_name_map) =
             DefinitionMap::_make_transaction_type_maps(&definitions.transaction_types);
         let (transaction_result_value_map, transaction_result_name_map) =
@@ -579,6 +643,7 @@ impl DefinitionHandler for DefinitionMap {
         DefinitionMap {
             field_info_map,
             field_header_name_map,
+            field_instance_map,
             type_value_map,
             type_name_map,
             transaction_type_value_map,
@@ -633,19 +698,16 @@ impl DefinitionHandler for DefinitionMap {
     }
 
     fn get_field_name_from_header(&self, field_header: &FieldHeader) -> Option<&String> {
-        self.field_header_name_map.get(&field_header.to_string())
+        self.field_header_name_map.get(field_header)
     }
 
-    fn get_field_instance<'a>(&self, field_name: &str) -> Option<FieldInstance> {
-        let field_info_wrap = self.field_info_map.get(field_name);
-        let field_header_wrap = self.get_field_header_from_name(field_name);
+    fn get_field_instance(&self, field_name: &str) -> Option<&FieldInstance> {
+        self.field_instance_map.get(field_name)
+    }
 
-        match (field_info_wrap, field_header_wrap) {
-            (Some(field_info), Some(field_header)) => {
-                Some(FieldInstance::new(field_info, field_name, field_header))
-            }
-            _ => None,
-        }
+    fn get_field_instance_from_header(&self, field_header: &FieldHeader) -> Option<&FieldInstance> {
+        let field_name = self.get_field_name_from_header(field_header)?;
+        self.get_field_instance(field_name)
     }
 
     fn get_transaction_type_code(&self, transaction_type: &str) -> Option<&i16> {
@@ -675,17 +737,14 @@ impl DefinitionHandler for DefinitionMap {
 
 fn _load_definitions() -> &'static Option<(Definitions, DefinitionMap)> {
     static JSON: &str = include_str!("definitions.json");
+    static DEFINITIONS: OnceBox<Option<(Definitions, DefinitionMap)>> = OnceBox::new();
 
-    lazy_static! {
-        static ref DEFINITIONS: Option<(Definitions, DefinitionMap)> = {
-            let definitions: Definitions = serde_json::from_str(JSON).expect("_load_definitions");
-            let definition_map: DefinitionMap = DefinitionMap::new(&definitions);
+    DEFINITIONS.get_or_init(|| {
+        let definitions: Definitions = serde_json::from_str(JSON).expect("_load_definitions");
+        let definition_map: DefinitionMap = DefinitionMap::new(&definitions);
 
-            Some((definitions, definition_map))
-        };
-    }
-
-    &DEFINITIONS
+        Box::new(Some((definitions, definition_map)))
+    })
 }
 
 /// Retrieve the definition map.
@@ -739,13 +798,23 @@ pub fn get_field_name_from_header(field_header: &FieldHeader) -> Option<&String>
 }
 
 /// Return a FieldInstance object for the given
-/// field name.
-pub fn get_field_instance(field_name: &str) -> Option<FieldInstance> {
+/// field name. The instance is shared, not copied.
+pub fn get_field_instance(field_name: &str) -> Option<&'static FieldInstance> {
     let definition_map: &DefinitionMap = load_definition_map();
 
     definition_map.get_field_instance(field_name)
 }
 
+/// Return the FieldInstance object described by
+/// the given FieldHeader object.
+pub fn get_field_instance_from_header(
+    field_header: &FieldHeader,
+) -> Option<&'static FieldInstance> {
+    let definition_map: &DefinitionMap = load_definition_map();
+
+    definition_map.get_field_instance_from_header(field_header)
+}
+
 /// Return an integer representing the given
 /// transaction type string in an enum.
 pub fn get_transaction_type_code(transaction_type: &str) -> Option<&i16> {
@@ -791,6 +860,8 @@ pub fn get_ledger_entry_type_name(ledger_entry_type: &i16) -> Option<&String> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::utils::ToBytes;
+    use alloc::string::ToString;
 
     #[test]
     fn test_load_definitions() {
@@ -840,6 +911,37 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_get_field_instance_from_header() {
+        let field_instance = get_field_instance("OfferSequence").unwrap();
+        let from_header = get_field_instance_from_header(&field_instance.header).unwrap();
+
+        // Both lookups hand out the same shared instance.
+        assert!(core::ptr::eq(field_instance, from_header));
+        assert!(get_field_instance_from_header(&FieldHeader {
+            type_code: 2,
+            field_code: 250,
+        })
+        .is_none());
+    }
+
+    #[test]
+    fn test_field_header_encode() {
+        let cases: [(&str, &[u8]); 4] = [
+            ("Account", &[0x81]),
+            ("OfferSequence", &[0x20, 0x19]),
+            ("CloseResolution", &[0x01, 0x10]),
+            ("TickSize", &[0x00, 0x10, 0x10]),
+        ];
+
+        for (field_name, expected) in cases {
+            let field_instance = get_field_instance(field_name).unwrap();
+
+            assert_eq!(expected, field_instance.header_bytes());
+            assert_eq!(expected, field_instance.header.to_bytes());
+        }
+    }
+
     #[test]
     fn test_get_field_instance() {
         let field_header = FieldHeader {
@@ -872,6 +974,11 @@ mod test {
     fn test_get_transaction_type_code() {
         assert_eq!(get_transaction_type_code("Invalid"), Some(&-1));
         assert_eq!(get_transaction_type_code("OfferCancel"), Some(&8));
+        assert_eq!(get_transaction_type_code("NFTokenMint"), Some(&25));
+        assert_eq!(get_transaction_type_code("Clawback"), Some(&30));
+        assert_eq!(get_transaction_type_code("AMMBid"), Some(&39));
+        assert_eq!(get_transaction_type_code("DIDSet"), Some(&49));
+        assert_eq!(get_transaction_type_code("OracleDelete"), Some(&52));
         assert!(get_transaction_type_code("Nonexistent").is_none());
     }
 
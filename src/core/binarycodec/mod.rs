@@ -4,26 +4,37 @@
 pub mod definitions;
 pub mod types;
 
-use types::{AccountId, STObject};
+use types::{
+    exceptions::XRPLSerializeMapException, serialized_size_hint, AccountId, Hash256, STObject,
+};
 
 use alloc::{borrow::Cow, string::String, vec::Vec};
 use core::convert::TryFrom;
 use hex::ToHex;
 use serde::Serialize;
+use serde_json::Value;
 
 pub mod binary_wrappers;
+pub(crate) mod decoder;
 pub mod exceptions;
 pub(crate) mod test_cases;
 pub mod utils;
 
 pub use binary_wrappers::*;
+pub use decoder::{decode, decode_with_buffer};
 
 use crate::XRPLSerdeJsonError;
 
-use super::exceptions::XRPLCoreResult;
+/// An insertion-ordered map, hashed with FNV as `std`'s default
+/// hasher is not available without `std`.
+pub(crate) type IndexMap<K, V> = indexmap::IndexMap<K, V, fnv::FnvBuildHasher>;
 
-const TRANSACTION_SIGNATURE_PREFIX: i32 = 0x53545800;
-const TRANSACTION_MULTISIG_PREFIX: i32 = 0x534D5400;
+use super::{
+    exceptions::XRPLCoreResult,
+    hashing::{
+        PAYMENT_CHANNEL_CLAIM_PREFIX, TRANSACTION_MULTISIG_PREFIX, TRANSACTION_SIGNATURE_PREFIX,
+    },
+};
 
 pub fn encode<T>(signed_transaction: &T) -> XRPLCoreResult<String>
 where
@@ -32,6 +43,39 @@ where
     serialize_json(signed_transaction, None, None, false)
 }
 
+/// Encode a transaction, appending its binary form to `buffer`
+/// instead of returning it as hex. Clearing and reusing the same
+/// buffer avoids allocating one for every transaction.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::{encode, encode_into};
+/// use serde_json::json;
+///
+/// let mut buffer = Vec::new();
+/// for sequence in 1..=3 {
+///     let tx = json!({
+///         "TransactionType": "AccountSet",
+///         "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+///         "Fee": "12",
+///         "Sequence": sequence
+///     });
+///     buffer.clear();
+///     encode_into(&tx, &mut buffer).unwrap();
+///
+///     assert_eq!(hex::encode_upper(&buffer), encode(&tx).unwrap());
+/// }
+/// ```
+pub fn encode_into<T>(signed_transaction: &T, buffer: &mut Vec<u8>) -> XRPLCoreResult<()>
+where
+    T: Serialize,
+{
+    serialize_json_into(signed_transaction, None, None, false, buffer)
+}
+
 pub fn encode_for_signing<T>(prepared_transaction: &T) -> XRPLCoreResult<String>
 where
     T: Serialize,
@@ -61,6 +105,93 @@ where
     )
 }
 
+/// Encode a claim of `amount` drops from the payment channel
+/// `channel_id`, as the message the owner of the channel signs to
+/// authorize it.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::encode_for_signing_claim;
+///
+/// let claim = encode_for_signing_claim(
+///     "43904CBFCDCEC530B4037871F86EE90BF799DF8D2E0EA564BC8A3F332E4F5FB1",
+///     1000,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     claim,
+///     "434C4D0043904CBFCDCEC530B4037871F86EE90BF799DF8D2E0EA564BC8A3F332E4F5FB100000000000003E8"
+/// );
+/// ```
+pub fn encode_for_signing_claim(channel_id: &str, amount: u64) -> XRPLCoreResult<String> {
+    let channel_id = Hash256::try_from(channel_id)?;
+    let mut buffer = Vec::with_capacity(4 + 32 + 8);
+    buffer.extend_from_slice(&PAYMENT_CHANNEL_CLAIM_PREFIX.to_be_bytes());
+    buffer.extend_from_slice(channel_id.as_ref());
+    buffer.extend_from_slice(&amount.to_be_bytes());
+
+    Ok(buffer.encode_hex_upper::<String>())
+}
+
+/// Fields rippled adds to ledger entries in its JSON responses,
+/// e.g. `book_offers` annotating each offer with its funding, which
+/// are not part of the entry itself.
+const LEDGER_ENTRY_API_FIELDS: [&str; 5] = [
+    "index",
+    "owner_funds",
+    "quality",
+    "taker_gets_funded",
+    "taker_pays_funded",
+];
+
+/// Encode a ledger entry, e.g. the `node` of a `ledger_entry`
+/// response, into its binary form as the ledger stores it. The
+/// fields rippled annotates entries with, like `index`, are left out.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::{decode, encode_ledger_object};
+/// use serde_json::json;
+///
+/// let ticket = json!({
+///     "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+///     "Flags": 0,
+///     "LedgerEntryType": "Ticket",
+///     "OwnerNode": "0000000000000000",
+///     "PreviousTxnID": "F19AD4577212D3BEACA0F75FE1BA1644F2E854D46E8D62E9C95D18E9708CBFB1",
+///     "PreviousTxnLgrSeq": 3,
+///     "TicketSequence": 3,
+///     "index": "7458B6FD22827B3C141CDC88F1F0C72658C9B5D2E40961E45AF6CD31DECC0C29"
+/// });
+/// let blob = encode_ledger_object(ticket.clone()).unwrap();
+///
+/// let mut decoded = decode(&blob).unwrap();
+/// decoded["index"] = ticket["index"].clone();
+/// assert_eq!(decoded, ticket);
+/// ```
+pub fn encode_ledger_object(ledger_object: Value) -> XRPLCoreResult<String> {
+    let mut ledger_object = match ledger_object {
+        Value::Object(map) => map,
+        _ => return Err(XRPLSerializeMapException::ExpectedObject.into()),
+    };
+    for field in LEDGER_ENTRY_API_FIELDS {
+        ledger_object.remove(field);
+    }
+    let ledger_object = Value::Object(ledger_object);
+    let mut buffer = Vec::with_capacity(serialized_size_hint(&ledger_object));
+    // Unlike a transaction being signed, every field is kept.
+    STObject::write_value(ledger_object, false, &mut buffer)?;
+
+    Ok(buffer.encode_hex_upper::<String>())
+}
+
 fn serialize_json<T>(
     prepared_transaction: &T,
     prefix: Option<&[u8]>,
@@ -71,19 +202,43 @@ where
     T: Serialize,
 {
     let mut buffer = Vec::new();
-    if let Some(p) = prefix {
-        buffer.extend(p);
-    }
+    serialize_json_into(
+        prepared_transaction,
+        prefix,
+        suffix,
+        signing_only,
+        &mut buffer,
+    )?;
+    let hex_string = buffer.encode_hex_upper::<String>();
 
+    Ok(hex_string)
+}
+
+fn serialize_json_into<T>(
+    prepared_transaction: &T,
+    prefix: Option<&[u8]>,
+    suffix: Option<&[u8]>,
+    signing_only: bool,
+    buffer: &mut Vec<u8>,
+) -> XRPLCoreResult<()>
+where
+    T: Serialize,
+{
     let json_value =
         serde_json::to_value(prepared_transaction).map_err(XRPLSerdeJsonError::from)?;
-    let st_object = STObject::try_from_value(json_value, signing_only)?;
-    buffer.extend(st_object.as_ref());
+    buffer.reserve(
+        prefix.map_or(0, <[u8]>::len)
+            + serialized_size_hint(&json_value)
+            + suffix.map_or(0, <[u8]>::len),
+    );
 
+    if let Some(p) = prefix {
+        buffer.extend_from_slice(p);
+    }
+    STObject::write_value(json_value, signing_only, buffer)?;
     if let Some(s) = suffix {
-        buffer.extend(s);
+        buffer.extend_from_slice(s);
     }
-    let hex_string = buffer.encode_hex_upper::<String>();
 
-    Ok(hex_string)
+    Ok(())
 }
@@ -1,8 +1,9 @@
 //! Test cases
 
+use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec::Vec;
-use lazy_static::lazy_static;
+use once_cell::race::OnceBox;
 use serde::{Deserialize, Serialize};
 use serde_json::value::Value;
 
@@ -51,12 +52,13 @@ fn _load_tests() -> &'static Option<TestDefinitions> {
     pub const CODEC_TEST_FIXTURES: &str = include_str!("./test_data/codec-fixtures.json");
     pub const X_CODEC_TEST_FIXTURES: &str = include_str!("./test_data/x-codec-fixtures.json");
 
-    lazy_static! {
-        static ref TEST_CASES: Option<TestDefinitions> =
-            Some(serde_json::from_str(DATA_DRIVEN_TESTS).expect("_load_tests"));
-    }
+    static TEST_CASES: OnceBox<Option<TestDefinitions>> = OnceBox::new();
 
-    &TEST_CASES
+    TEST_CASES.get_or_init(|| {
+        Box::new(Some(
+            serde_json::from_str(DATA_DRIVEN_TESTS).expect("_load_tests"),
+        ))
+    })
 }
 
 /// Retrieve the field tests.
@@ -11,9 +11,10 @@ use crate::core::exceptions::XRPLCoreException;
 use crate::core::exceptions::XRPLCoreResult;
 use crate::core::BinaryParser;
 use crate::utils::is_hex_address;
+use alloc::vec::Vec;
 use core::convert::TryFrom;
 use core::fmt::Display;
-use serde::ser::Error;
+use core::str::FromStr;
 use serde::Serializer;
 use serde::{Deserialize, Serialize};
 
@@ -57,13 +58,7 @@ impl Serialize for AccountId {
     where
         S: Serializer,
     {
-        let result = &encode_classic_address(self.as_ref());
-
-        if let Ok(data) = result {
-            serializer.serialize_str(data)
-        } else {
-            Err(S::Error::custom(result.as_ref().unwrap_err()))
-        }
+        serializer.collect_str(self)
     }
 }
 
@@ -88,14 +83,21 @@ impl TryFrom<&str> for AccountId {
     }
 }
 
+impl FromStr for AccountId {
+    type Err = XRPLCoreException;
+
+    /// Construct an AccountId from a hex string, a base58
+    /// r-Address or an X-Address.
+    fn from_str(value: &str) -> XRPLCoreResult<Self, Self::Err> {
+        AccountId::try_from(value)
+    }
+}
+
 impl Display for AccountId {
     /// Return the classic address of the AccountId bytes.
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        write!(
-            f,
-            "{}",
-            encode_classic_address(self.as_ref()).expect("Could not encode address to string")
-        )
+        let address = encode_classic_address(self.as_ref()).map_err(|_| core::fmt::Error)?;
+        f.write_str(&address)
     }
 }
 
@@ -106,6 +108,12 @@ impl AsRef<[u8]> for AccountId {
     }
 }
 
+impl From<AccountId> for Vec<u8> {
+    fn from(account_id: AccountId) -> Self {
+        account_id.0.into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -127,6 +135,15 @@ mod test {
         assert_eq!(HEX_ENCODING, hex::encode_upper(account));
     }
 
+    #[test]
+    fn test_accountid_from_str_round_trip() {
+        let account: AccountId = BASE58_ENCODING.parse().unwrap();
+        assert_eq!(BASE58_ENCODING, account.to_string());
+
+        let account = AccountId::from_str(HEX_ENCODING).unwrap();
+        assert_eq!(BASE58_ENCODING, account.to_string());
+    }
+
     #[test]
     fn accept_accountid_serde_encode_decode() {
         let account: AccountId = AccountId::try_from(BASE58_ENCODING).unwrap();
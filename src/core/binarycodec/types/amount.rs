@@ -20,22 +20,18 @@ use alloc::string::String;
 use alloc::string::ToString;
 use alloc::vec;
 use alloc::vec::Vec;
-use bigdecimal::{BigDecimal, Signed, Zero};
+use bigdecimal::{BigDecimal, ToPrimitive};
 use core::convert::TryFrom;
 use core::convert::TryInto;
 use core::fmt::Display;
 use core::str::FromStr;
-use rust_decimal::prelude::ToPrimitive;
 use serde::ser::Error;
 use serde::ser::SerializeMap;
 use serde::Serializer;
 use serde::{Deserialize, Serialize};
 
-const _MIN_MANTISSA: u128 = u128::pow(10, 15);
-const _MAX_MANTISSA: u128 = u128::pow(10, 16) - 1;
-
 const _NOT_XRP_BIT_MASK: u8 = 0x80;
-const _POS_SIGN_BIT_MASK: i64 = 0x4000000000000000;
+const _POS_SIGN_BIT_MASK: u64 = 0x4000000000000000;
 const _ZERO_CURRENCY_AMOUNT_HEX: u64 = 0x8000000000000000;
 const _NATIVE_AMOUNT_BYTE_LENGTH: u8 = 8;
 const _CURRENCY_AMOUNT_BYTE_LENGTH: u8 = 48;
@@ -76,78 +72,50 @@ fn _contains_decimal(string: &str) -> bool {
 /// Serializes the value field of an issued currency amount
 /// to its bytes representation.
 fn _serialize_issued_currency_value(decimal: BigDecimal) -> XRPLCoreResult<[u8; 8]> {
-    verify_valid_ic_value(&decimal.to_scientific_notation())
+    let value = IOUValue::try_from(&decimal)
         .map_err(|e| XRPLCoreException::XRPLUtilsError(e.to_string()))?;
 
-    if decimal.is_zero() {
+    if value.is_zero() {
         return Ok((_ZERO_CURRENCY_AMOUNT_HEX).to_be_bytes());
     };
 
-    let is_positive: bool = decimal.is_positive();
-    let (mantissa_str, scale) = decimal.normalized().as_bigint_and_exponent();
-    let mut exp: i32 = -(scale as i32);
-    let mut mantissa: u128 = mantissa_str.abs().to_u128().unwrap();
-
-    while mantissa < _MIN_MANTISSA && exp > MIN_IOU_EXPONENT {
-        mantissa *= 10;
-        exp -= 1;
-    }
-
-    while mantissa > _MAX_MANTISSA {
-        if exp >= MAX_IOU_EXPONENT {
-            return Err(XRPLBinaryCodecException::from(
-                XRPRangeException::UnexpectedICAmountOverflow {
-                    max: MAX_IOU_EXPONENT as usize,
-                    found: exp as usize,
-                },
-            )
-            .into());
-        } else {
-            mantissa /= 10;
-            exp += 1;
-        }
-    }
+    // "Not XRP" bit set
+    let mut serial: u64 = _ZERO_CURRENCY_AMOUNT_HEX;
 
-    if exp < MIN_IOU_EXPONENT || mantissa < _MIN_MANTISSA {
-        // Round to zero
-        Ok((_ZERO_CURRENCY_AMOUNT_HEX).to_be_bytes())
-    } else if exp > MAX_IOU_EXPONENT || mantissa > _MAX_MANTISSA {
-        Err(
-            XRPLBinaryCodecException::from(XRPRangeException::UnexpectedICAmountOverflow {
-                max: MAX_IOU_EXPONENT as usize,
-                found: exp as usize,
-            })
-            .into(),
-        )
-    } else {
-        // "Not XRP" bit set
-        let mut serial: i128 = _ZERO_CURRENCY_AMOUNT_HEX as i128;
-
-        // "Is positive" bit set
-        if is_positive {
-            serial |= _POS_SIGN_BIT_MASK as i128;
-        };
+    // "Is positive" bit set
+    if !value.is_negative() {
+        serial |= _POS_SIGN_BIT_MASK;
+    };
 
-        // next 8 bits are exponents
-        serial |= ((exp as i64 + 97) << 54) as i128;
-        // last 54 bits are mantissa
-        serial |= mantissa as i128;
+    // next 8 bits are exponents
+    serial |= ((value.exponent() + 97) as u64) << 54;
+    // last 54 bits are mantissa
+    serial |= value.mantissa().unsigned_abs();
 
-        Ok((serial as u64).to_be_bytes())
-    }
+    Ok(serial.to_be_bytes())
 }
 
-/// Serializes an XRP amount.
+/// Serializes an XRP amount. A negative amount, e.g. a balance
+/// change, is serialized with the sign bit cleared.
 fn _serialize_xrp_amount(value: &str) -> XRPLCoreResult<[u8; 8]> {
-    verify_valid_xrp_value(value).map_err(|e| XRPLCoreException::XRPLUtilsError(e.to_string()))?;
+    let (is_negative, magnitude) = match value.strip_prefix('-') {
+        Some(magnitude) => (true, magnitude),
+        None => (false, value),
+    };
+    verify_valid_xrp_value(magnitude)
+        .map_err(|e| XRPLCoreException::XRPLUtilsError(e.to_string()))?;
 
-    let decimal = bigdecimal::BigDecimal::from_str(value)
+    let decimal = bigdecimal::BigDecimal::from_str(magnitude)
         .map_err(XRPLTypeException::BigDecimalError)?
         .normalized();
 
-    if let Some(result) = decimal.to_i64() {
-        let value_with_pos_bit = result | _POS_SIGN_BIT_MASK;
-        Ok(value_with_pos_bit.to_be_bytes())
+    if let Some(result) = decimal.to_u64() {
+        let serial = if is_negative && result != 0 {
+            result
+        } else {
+            result | _POS_SIGN_BIT_MASK
+        };
+        Ok(serial.to_be_bytes())
     } else {
         // Safety, should never occur
         Err(XRPLCoreException::XRPLUtilsError(
@@ -186,7 +154,12 @@ impl Amount {
         let mut sized: [u8; 8] = Default::default();
 
         sized.copy_from_slice(&self.as_ref()[..8]);
-        (u64::from_be_bytes(sized) & 0x3FFFFFFFFFFFFFFF).to_string()
+        let drops = u64::from_be_bytes(sized) & 0x3FFFFFFFFFFFFFFF;
+        if self.is_positive() || drops == 0 {
+            drops.to_string()
+        } else {
+            alloc::format!("-{drops}")
+        }
     }
 
     /// Returns True if this amount is a native XRP amount.
@@ -197,7 +170,7 @@ impl Amount {
     /// Returns true if 2nd bit in 1st byte is set to 1
     /// (positive amount).
     pub fn is_positive(&self) -> bool {
-        self.0[1] & 0x40 > 0
+        self.0[0] & 0x40 > 0
     }
 }
 
@@ -206,36 +179,25 @@ impl IssuedCurrency {
     fn _deserialize_issued_currency_amount(
         parser: &mut BinaryParser,
     ) -> XRPLCoreResult<BigDecimal> {
-        let mut value: BigDecimal;
         let bytes = parser.read(8)?;
 
         // Some wizardry by Amie Corso
         let exp = ((bytes[0] as i32 & 0x3F) << 2) + ((bytes[1] as i32 & 0xFF) >> 6) - 97;
 
-        if exp < MIN_IOU_EXPONENT {
-            value = BigDecimal::from(0);
+        let value = if exp < MIN_IOU_EXPONENT {
+            IOUValue::ZERO
         } else {
-            let hex_mantissa = hex::encode([&[bytes[1] & 0x3F], &bytes[2..]].concat());
-            let int_mantissa = i128::from_str_radix(&hex_mantissa, 16)
-                .map_err(XRPLBinaryCodecException::ParseIntError)?;
-
-            // Adjust scale using the exponent
-            let scale = exp.unsigned_abs();
-            value = BigDecimal::new(int_mantissa.into(), scale as i64);
-
-            // Handle the sign
-            if bytes[0] & 0x40 > 0 {
-                // Set the value to positive (BigDecimal assumes positive by default)
-                value = value.abs();
-            } else {
-                // Set the value to negative
-                value = -value.abs();
-            }
-        }
-        verify_valid_ic_value(&value.to_string())
-            .map_err(|e| XRPLCoreException::XRPLUtilsError(e.to_string()))?;
+            let mut mantissa = [0; 8];
+            mantissa[1..].copy_from_slice(&bytes[1..]);
+            mantissa[1] &= 0x3F;
+            let mantissa = i64::from_be_bytes(mantissa);
+            let is_positive = bytes[0] & 0x40 > 0;
+
+            IOUValue::new(if is_positive { mantissa } else { -mantissa }, exp)
+                .map_err(|e| XRPLCoreException::XRPLUtilsError(e.to_string()))?
+        };
 
-        Ok(value)
+        Ok(value.into())
     }
 }
 
@@ -260,10 +222,10 @@ impl TryFromParser for Amount {
         parser: &mut BinaryParser,
         _length: Option<usize>,
     ) -> XRPLCoreResult<Amount, Self::Error> {
-        let parser_first_byte = parser.peek();
-        let num_bytes = match parser_first_byte {
-            None => _CURRENCY_AMOUNT_BYTE_LENGTH,
-            Some(_) => _NATIVE_AMOUNT_BYTE_LENGTH,
+        // The first bit is set for issued currency amounts.
+        let num_bytes = match parser.peek() {
+            Some([first_byte]) if first_byte & 0x80 != 0 => _CURRENCY_AMOUNT_BYTE_LENGTH,
+            _ => _NATIVE_AMOUNT_BYTE_LENGTH,
         };
 
         Ok(Amount(parser.read(num_bytes as usize)?))
@@ -344,13 +306,12 @@ impl TryFrom<serde_json::Value> for Amount {
         } else if value.is_object() {
             Ok(Self::try_from(IssuedCurrency::try_from(value)?)?)
         } else {
-            Err(
-                XRPLCoreException::SerdeJsonError(XRPLSerdeJsonError::UnexpectedValueType {
+            Err(XRPLCoreException::SerdeJsonError(
+                XRPLSerdeJsonError::UnexpectedValueType {
                     expected: "String/Object".into(),
                     found: value,
-                })
-                .into(),
-            )
+                },
+            ))
         }
     }
 }
@@ -398,6 +359,12 @@ impl AsRef<[u8]> for Amount {
     }
 }
 
+impl From<Amount> for Vec<u8> {
+    fn from(amount: Amount) -> Self {
+        amount.0
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -466,6 +433,22 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_negative_xrp_amount() {
+        let amount = Amount::try_from("-1000000").unwrap();
+        assert_eq!(amount.to_string(), "00000000000F4240");
+        assert!(amount.is_native());
+        assert!(!amount.is_positive());
+        assert_eq!(serde_json::to_value(&amount).unwrap(), "-1000000");
+
+        // There is no negative zero.
+        let zero = Amount::try_from("-0").unwrap();
+        assert_eq!(zero.to_string(), "4000000000000000");
+        assert_eq!(serde_json::to_value(&zero).unwrap(), "0");
+
+        assert!(Amount::try_from("-100000000000000001").is_err());
+    }
+
     #[test]
     fn accept_amount_value_tests() {
         let tests = load_data_tests(Some("Amount"));
@@ -480,4 +463,61 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_issued_currency_exponent_boundaries() {
+        let amount = |value: &str| {
+            Amount::try_from(serde_json::json!({
+                "currency": "USD",
+                "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji",
+                "value": value
+            }))
+        };
+
+        for (value, hex) in [
+            ("1e-81", "C0438D7EA4C68000"),
+            ("-1e-81", "80438D7EA4C68000"),
+            ("9999999999999999e80", "EC6386F26FC0FFFF"),
+            ("1000000e74", "E8838D7EA4C68000"),
+        ] {
+            let amount = amount(value).unwrap();
+            assert_eq!(amount.to_string()[..16], *hex, "{value}");
+            // Decoding gives back the value, e.g. not 1e-80 for 1e80.
+            let json = serde_json::to_value(&amount).unwrap();
+            assert_eq!(
+                IOUValue::from_str(json["value"].as_str().unwrap()).unwrap(),
+                IOUValue::from_str(value).unwrap()
+            );
+            assert_eq!(
+                Amount::try_from(json).unwrap().to_string(),
+                amount.to_string()
+            );
+        }
+
+        assert!(amount("9.9e-82").is_err());
+        assert!(amount("1e96").is_err());
+        assert!(amount("0.1234567890123456789").is_err());
+    }
+
+    #[test]
+    fn test_amount_from_parser() {
+        let json: Vec<IOUCase> = serde_json::from_str(IOU_TEST).expect("");
+        let iou = hex::decode(&json[0].1).expect("");
+        let xrp = hex::decode(TEST_XRP_CASES[0].1).expect("");
+        let mut parser = BinaryParser::from([iou.as_slice(), xrp.as_slice()].concat());
+
+        assert_eq!(
+            Amount::from_parser(&mut parser, None).unwrap().as_ref(),
+            iou
+        );
+        assert_eq!(
+            Amount::from_parser(&mut parser, None).unwrap().as_ref(),
+            xrp
+        );
+        assert!(Amount::from_parser(&mut parser, None).is_err());
+
+        let mut truncated = BinaryParser::from(&iou[..iou.len() - 1]);
+        assert!(Amount::from_parser(&mut truncated, None).is_err());
+        assert!(IssuedCurrency::from_parser(&mut BinaryParser::from(&iou[..20]), None).is_err());
+    }
 }
@@ -65,6 +65,12 @@ impl AsRef<[u8]> for Blob {
     }
 }
 
+impl From<Blob> for Vec<u8> {
+    fn from(blob: Blob) -> Self {
+        blob.0
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
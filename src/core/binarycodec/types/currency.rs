@@ -16,6 +16,7 @@ use alloc::vec::Vec;
 use core::convert::TryFrom;
 use core::convert::TryInto;
 use core::fmt::Display;
+use core::str::FromStr;
 use serde::Serializer;
 use serde::{Deserialize, Serialize};
 
@@ -29,7 +30,10 @@ pub const NATIVE_CODE: &str = "XRP";
 pub struct Currency(Hash160);
 
 fn _iso_code_from_hex(value: &[u8]) -> Result<Option<String>, ISOCodeException> {
-    let candidate_iso = alloc::str::from_utf8(&value[12..15])?;
+    let Some(candidate_iso) = value.get(12..15) else {
+        return Err(ISOCodeException::InvalidISOLength);
+    };
+    let candidate_iso = alloc::str::from_utf8(candidate_iso)?;
 
     if candidate_iso == NATIVE_CODE {
         Err(ISOCodeException::InvalidXRPBytes)
@@ -94,7 +98,7 @@ impl Serialize for Currency {
     where
         S: Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        serializer.collect_str(self)
     }
 }
 
@@ -116,6 +120,16 @@ impl TryFrom<&str> for Currency {
     }
 }
 
+impl FromStr for Currency {
+    type Err = XRPLCoreException;
+
+    /// Construct a Currency object from an ISO code
+    /// or a 40 character hex string.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Currency::try_from(value)
+    }
+}
+
 impl Display for Currency {
     /// Get the ISO or hex representation of the Currency bytes.
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
@@ -146,6 +160,12 @@ impl AsRef<[u8]> for Currency {
     }
 }
 
+impl From<Currency> for Vec<u8> {
+    fn from(currency: Currency) -> Self {
+        currency.0.into()
+    }
+}
+
 impl Currency {
     pub fn is_xrp(&self) -> bool {
         self.to_string() == NATIVE_CODE
@@ -198,6 +218,25 @@ mod test {
         assert_eq!(NONSTANDARD_HEX_CODE, hex::encode_upper(from_ns));
     }
 
+    #[test]
+    fn test_currency_from_str_round_trip() {
+        let xrp: Currency = NATIVE_CODE.parse().unwrap();
+        let usd: Currency = USD_ISO.parse().unwrap();
+        let usd_hex: Currency = USD_HEX_CODE.parse().unwrap();
+        let nonstandard: Currency = NONSTANDARD_HEX_CODE.parse().unwrap();
+
+        assert_eq!(NATIVE_CODE, xrp.to_string());
+        assert_eq!(USD_ISO, usd.to_string());
+        assert_eq!(USD_ISO, usd_hex.to_string());
+        assert_eq!(NONSTANDARD_HEX_CODE, nonstandard.to_string());
+        assert_eq!(
+            ILLEGAL_NATIVE_HEX_CODE,
+            Currency::from_str(ILLEGAL_NATIVE_HEX_CODE)
+                .unwrap()
+                .to_string()
+        );
+    }
+
     #[test]
     fn accept_currency_serde_encode_decode() {
         let currency = Currency::try_from(USD_HEX_CODE).unwrap();
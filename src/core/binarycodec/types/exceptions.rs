@@ -37,6 +37,8 @@ pub enum XRPLTypeException {
     MissingField(String),
     #[error("Parse int error: {0}")]
     ParseIntError(#[from] core::num::ParseIntError),
+    #[error("Invalid UInt64 hex string (expected at most 16 characters): {0}")]
+    InvalidUInt64Hex(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Error)]
@@ -65,6 +67,10 @@ pub enum XRPLSerializeMapException {
     UnknownTransactionResult(String),
     #[error("Unknown ledger entry type: {0}")]
     UnknownLedgerEntryType(String),
+    #[error("Unknown field: {0}")]
+    UnknownField(String),
+    #[error("Missing field: {0}")]
+    MissingField(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Error)]
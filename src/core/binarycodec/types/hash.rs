@@ -16,7 +16,8 @@ use crate::core::Parser;
 use alloc::vec::Vec;
 use core::convert::TryFrom;
 use core::fmt::Display;
-use serde::Deserialize;
+use core::str::FromStr;
+use serde::{Deserialize, Serialize, Serializer};
 
 /// Codec for serializing and deserializing a hash field
 /// with a width of 128 bits (16 bytes).
@@ -176,7 +177,7 @@ impl dyn Hash {
         length: Option<usize>,
     ) -> XRPLCoreResult<Vec<u8>> {
         let read_length = length.or_else(|| Some(T::get_length())).unwrap();
-        Ok(parser.read(read_length)?)
+        parser.read(read_length)
     }
 }
 
@@ -285,6 +286,15 @@ impl TryFrom<&str> for Hash256 {
     }
 }
 
+impl FromStr for Hash128 {
+    type Err = XRPLCoreException;
+
+    /// Construct a Hash128 from a hex string.
+    fn from_str(value: &str) -> XRPLCoreResult<Self, Self::Err> {
+        Hash128::try_from(value)
+    }
+}
+
 impl Display for Hash128 {
     /// Get the hex representation of the Hash128 bytes.
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
@@ -292,6 +302,15 @@ impl Display for Hash128 {
     }
 }
 
+impl FromStr for Hash160 {
+    type Err = XRPLCoreException;
+
+    /// Construct a Hash160 from a hex string.
+    fn from_str(value: &str) -> XRPLCoreResult<Self, Self::Err> {
+        Hash160::try_from(value)
+    }
+}
+
 impl Display for Hash160 {
     /// Get the hex representation of the Hash160 bytes.
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
@@ -299,6 +318,15 @@ impl Display for Hash160 {
     }
 }
 
+impl FromStr for Hash256 {
+    type Err = XRPLCoreException;
+
+    /// Construct a Hash256 from a hex string.
+    fn from_str(value: &str) -> XRPLCoreResult<Self, Self::Err> {
+        Hash256::try_from(value)
+    }
+}
+
 impl Display for Hash256 {
     /// Get the hex representation of the Hash256 bytes.
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
@@ -306,6 +334,36 @@ impl Display for Hash256 {
     }
 }
 
+impl Serialize for Hash128 {
+    /// Returns the hex representation of the Hash128.
+    fn serialize<S>(&self, serializer: S) -> XRPLCoreResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl Serialize for Hash160 {
+    /// Returns the hex representation of the Hash160.
+    fn serialize<S>(&self, serializer: S) -> XRPLCoreResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl Serialize for Hash256 {
+    /// Returns the hex representation of the Hash256.
+    fn serialize<S>(&self, serializer: S) -> XRPLCoreResult<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
 impl AsRef<[u8]> for Hash160 {
     /// Get a reference of the byte representation.
     fn as_ref(&self) -> &[u8] {
@@ -313,6 +371,12 @@ impl AsRef<[u8]> for Hash160 {
     }
 }
 
+impl From<Hash160> for Vec<u8> {
+    fn from(hash160: Hash160) -> Self {
+        hash160.0
+    }
+}
+
 impl AsRef<[u8]> for Hash128 {
     /// Get a reference of the byte representation.
     fn as_ref(&self) -> &[u8] {
@@ -320,6 +384,12 @@ impl AsRef<[u8]> for Hash128 {
     }
 }
 
+impl From<Hash128> for Vec<u8> {
+    fn from(hash128: Hash128) -> Self {
+        hash128.0
+    }
+}
+
 impl AsRef<[u8]> for Hash256 {
     /// Get a reference of the byte representation.
     fn as_ref(&self) -> &[u8] {
@@ -327,6 +397,12 @@ impl AsRef<[u8]> for Hash256 {
     }
 }
 
+impl From<Hash256> for Vec<u8> {
+    fn from(hash256: Hash256) -> Self {
+        hash256.0
+    }
+}
+
 #[cfg(test)]
 mod test {
     use alloc::string::ToString;
@@ -391,6 +467,33 @@ mod test {
         assert_eq!(HASH256_HEX_TEST, result.unwrap().to_string());
     }
 
+    #[test]
+    fn test_hash_from_str_round_trip() {
+        let hash128: Hash128 = HASH128_HEX_TEST.parse().unwrap();
+        let hash160: Hash160 = HASH160_HEX_TEST.parse().unwrap();
+        let hash256: Hash256 = HASH256_HEX_TEST.parse().unwrap();
+
+        assert_eq!(HASH128_HEX_TEST, hash128.to_string());
+        assert_eq!(HASH160_HEX_TEST, hash160.to_string());
+        assert_eq!(HASH256_HEX_TEST, hash256.to_string());
+        assert_eq!(
+            HASH256_HEX_TEST,
+            Hash256::from_str(&HASH256_HEX_TEST.to_lowercase())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn accept_hash_serde_encode_decode() {
+        let hash: Hash256 = HASH256_HEX_TEST.parse().unwrap();
+        let serialize = serde_json::to_string(&hash).unwrap();
+        let deserialize: Hash256 = serde_json::from_str(&serialize).unwrap();
+
+        assert_eq!(alloc::format!("\"{HASH256_HEX_TEST}\""), serialize);
+        assert_eq!(hash.to_string(), deserialize.to_string());
+    }
+
     #[test]
     fn accept_hash_invalid_length_errors() {
         let hash128 = Hash128::try_from("1000000000200000000030000000001234");
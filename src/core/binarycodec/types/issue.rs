@@ -1,4 +1,5 @@
 use alloc::string::ToString;
+use alloc::vec::Vec;
 use serde_json::Value;
 
 use crate::core::{
@@ -28,9 +29,9 @@ impl TryFromParser for Issue {
 
     fn from_parser(
         parser: &mut BinaryParser,
-        length: Option<usize>,
+        _length: Option<usize>,
     ) -> XRPLCoreResult<Self, Self::Error> {
-        let currency = Currency::from_parser(parser, length)?;
+        let currency = Currency::from_parser(parser, None)?;
         let mut currency_bytes = currency.as_ref().to_vec();
         if currency.to_string() == "XRP" {
             Ok(Issue(SerializedType::from(currency_bytes)))
@@ -74,3 +75,9 @@ impl AsRef<[u8]> for Issue {
         self.0.as_ref()
     }
 }
+
+impl From<Issue> for Vec<u8> {
+    fn from(issue: Issue) -> Self {
+        issue.0 .0
+    }
+}
@@ -36,9 +36,12 @@ pub use self::xchain_bridge::XChainBridge;
 
 use crate::core::binarycodec::binary_wrappers::Serialization;
 use crate::core::binarycodec::definitions::get_field_instance;
+use crate::core::binarycodec::definitions::get_ledger_entry_type_code;
 use crate::core::binarycodec::definitions::get_transaction_result_code;
 use crate::core::binarycodec::definitions::get_transaction_type_code;
 use crate::core::binarycodec::definitions::FieldInstance;
+use crate::core::binarycodec::definitions::FIELD_HEADER_MAX_LENGTH;
+use crate::core::binarycodec::IndexMap;
 use crate::core::exceptions::XRPLCoreResult;
 use crate::core::BinaryParser;
 use alloc::borrow::Cow;
@@ -61,8 +64,9 @@ const ACCOUNT: &str = "Account";
 const SOURCE_TAG: &str = "SourceTag";
 const DESTINATION: &str = "Destination";
 const DESTINATION_TAG: &str = "DestinationTag";
-const UNL_MODIFY_TX_TYPE: &str = "0066";
+const UNL_MODIFY_TX_TYPE: [u8; 2] = [0x00, 0x66];
 const ST_OBJECT: &str = "STObject";
+const ST_ARRAY: &str = "STArray";
 const OBJECT_END_MARKER_BYTES: [u8; 1] = [0xE1];
 const ARRAY_END_MARKER: [u8; 1] = [0xF1];
 
@@ -121,11 +125,16 @@ impl XRPLTypes {
                         .parse::<u32>()
                         .map_err(XRPLTypeException::ParseIntError)?,
                 )),
-                "UInt64" => Ok(XRPLTypes::UInt64(
-                    value
-                        .parse::<u64>()
-                        .map_err(XRPLTypeException::ParseIntError)?,
-                )),
+                // UInt64 values are hex strings in JSON, as they exceed
+                // the safe integer range of JavaScript.
+                "UInt64" => {
+                    if value.len() > 16 {
+                        return Err(XRPLTypeException::InvalidUInt64Hex(value.into()).into());
+                    }
+                    Ok(XRPLTypes::UInt64(
+                        u64::from_str_radix(value, 16).map_err(XRPLTypeException::ParseIntError)?,
+                    ))
+                }
                 _ => Err(exceptions::XRPLTypeException::UnknownXRPLType.into()),
             }
         } else if let Some(value) = value.as_u64() {
@@ -136,27 +145,35 @@ impl XRPLTypes {
                 "UInt64" => Ok(XRPLTypes::UInt64(value)),
                 _ => Err(exceptions::XRPLTypeException::UnknownXRPLType.into()),
             }
-        } else if let Some(value) = value.as_object() {
-            match name {
-                "Amount" => Ok(XRPLTypes::Amount(Self::amount_from_map(value.to_owned())?)),
-                "STObject" => Ok(XRPLTypes::STObject(STObject::try_from_value(
-                    Value::Object(value.to_owned()),
-                    false,
-                )?)),
-                "XChainBridge" => Ok(XRPLTypes::XChainBridge(XChainBridge::try_from(
-                    Value::Object(value.to_owned()),
-                )?)),
-                _ => Err(exceptions::XRPLTypeException::UnknownXRPLType.into()),
-            }
-        } else if let Some(value) = value.as_array() {
-            match name {
-                "STArray" => Ok(XRPLTypes::STArray(STArray::try_from_value(Value::Array(
-                    value.to_owned(),
-                ))?)),
+        } else {
+            match (name, value) {
+                ("Amount", Value::Object(value)) => {
+                    Ok(XRPLTypes::Amount(Self::amount_from_map(value)?))
+                }
+                ("STObject", value @ Value::Object(_)) => {
+                    Ok(XRPLTypes::STObject(STObject::try_from_value(value, false)?))
+                }
+                ("XChainBridge", value @ Value::Object(_)) => {
+                    Ok(XRPLTypes::XChainBridge(XChainBridge::try_from(value)?))
+                }
+                ("Issue", value @ Value::Object(_)) => {
+                    Ok(XRPLTypes::Issue(Issue::try_from(value)?))
+                }
+                ("Vector256", Value::Array(values)) => {
+                    let hashes = values
+                        .iter()
+                        .map(|value| value.as_str().ok_or(XRPLTypeException::UnexpectedJSONType))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok(XRPLTypes::Vector256(Vector256::try_from(hashes)?))
+                }
+                ("PathSet", Value::Array(paths)) => {
+                    Ok(XRPLTypes::PathSet(Self::path_set_from_array(paths)?))
+                }
+                ("STArray", value @ Value::Array(_)) => {
+                    Ok(XRPLTypes::STArray(STArray::try_from_value(value)?))
+                }
                 _ => Err(exceptions::XRPLTypeException::UnknownXRPLType.into()),
             }
-        } else {
-            Err(exceptions::XRPLTypeException::UnknownXRPLType.into())
         }
     }
 
@@ -170,6 +187,32 @@ impl XRPLTypes {
             .map_err(|_| XRPLTypeException::TryFromStrError.into())
     }
 
+    fn path_set_from_array(paths: Vec<Value>) -> XRPLCoreResult<PathSet> {
+        let paths = paths
+            .into_iter()
+            .map(|path| match path {
+                Value::Array(steps) => steps
+                    .into_iter()
+                    .map(|step| match step {
+                        // `type` and `type_hex`, as returned by the
+                        // server, are derived from the other fields.
+                        Value::Object(step) => Ok(step
+                            .into_iter()
+                            .filter_map(|(key, value)| match value {
+                                Value::String(value) if key != "type_hex" => Some((key, value)),
+                                _ => None,
+                            })
+                            .collect::<IndexMap<String, String>>()),
+                        _ => Err(XRPLTypeException::UnexpectedJSONType),
+                    })
+                    .collect::<Result<Vec<_>, _>>(),
+                _ => Err(XRPLTypeException::UnexpectedJSONType),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        PathSet::try_from(paths)
+    }
+
     fn amount_from_map<T>(value: Map<String, Value>) -> XRPLCoreResult<T>
     where
         T: TryFrom<IssuedCurrency>,
@@ -246,25 +289,27 @@ impl STArray {
     /// assert_eq!(actual_hex, expected_hex);
     /// ```
     pub fn try_from_value(value: Value) -> XRPLCoreResult<Self> {
-        if let Some(array) = value.as_array() {
+        let mut serializer = BinarySerializer::with_capacity(serialized_size_hint(&value));
+        Self::write_value(value, &mut serializer)?;
+
+        Ok(STArray(serializer.into()))
+    }
+
+    /// Serialize a serde_json::Value, appending it to the
+    /// given serializer.
+    pub(crate) fn write_value(
+        value: Value,
+        serializer: &mut BinarySerializer,
+    ) -> XRPLCoreResult<()> {
+        if let Value::Array(array) = value {
             if !array.is_empty() && array.iter().filter(|v| v.is_object()).count() != array.len() {
                 Err(exceptions::XRPLSerializeArrayException::ExpectedObjectArray.into())
             } else {
-                let mut serializer = BinarySerializer::new();
                 for object in array {
-                    let obj = match object {
-                        Value::Object(map) => map,
-                        _ => {
-                            return Err(
-                                exceptions::XRPLSerializeArrayException::ExpectedObjectArray.into(),
-                            )
-                        }
-                    };
-                    let transaction = STObject::try_from_value(Value::Object(obj.clone()), false)?;
-                    serializer.append(transaction.as_ref().to_vec().as_mut());
+                    STObject::write_value(object, false, serializer)?;
                 }
-                serializer.append(ARRAY_END_MARKER.to_vec().as_mut());
-                Ok(STArray(serializer.into()))
+                serializer.extend_from_slice(&ARRAY_END_MARKER);
+                Ok(())
             }
         } else {
             Err(exceptions::XRPLSerializeArrayException::ExpectedArray.into())
@@ -290,6 +335,12 @@ impl AsRef<[u8]> for STArray {
     }
 }
 
+impl From<STArray> for Vec<u8> {
+    fn from(st_array: STArray) -> Self {
+        st_array.0 .0
+    }
+}
+
 /// Class for serializing/deserializing Indexmaps of objects.
 ///
 /// See Object Fields:
@@ -333,18 +384,36 @@ impl STObject {
     /// assert_eq!(hex, buffer);
     /// ```
     pub fn try_from_value(value: Value, signing_only: bool) -> XRPLCoreResult<Self> {
+        let mut serializer = BinarySerializer::with_capacity(serialized_size_hint(&value));
+        Self::write_value(value, signing_only, &mut serializer)?;
+
+        Ok(STObject(serializer.into()))
+    }
+
+    /// Serialize a serde_json::Value, appending it to the
+    /// given serializer.
+    pub(crate) fn write_value(
+        value: Value,
+        signing_only: bool,
+        serializer: &mut BinarySerializer,
+    ) -> XRPLCoreResult<()> {
         let object = match value {
             Value::Object(map) => map,
             _ => return Err(exceptions::XRPLSerializeMapException::ExpectedObject.into()),
         };
-        let mut serializer = BinarySerializer::new();
-        let mut value_xaddress_handled = Map::new();
-        for (field, value) in &object {
-            if let Some(value) = value.as_str() {
-                if is_valid_xaddress(value) {
-                    let handled_xaddress = handle_xaddress(field.into(), value.into())?;
-                    if let Some(handled_tag) = handled_xaddress.get(SOURCE_TAG) {
-                        if let Some(object_tag) = object.get(SOURCE_TAG) {
+        // Needed to check the tags of X-addresses once the object is
+        // consumed.
+        let source_tag = object.get(SOURCE_TAG).cloned();
+        let destination_tag = object.get(DESTINATION_TAG).cloned();
+        let mut fields: Vec<(&'static FieldInstance, Value)> = Vec::with_capacity(object.len());
+        for (field, value) in object {
+            let value =
+                match value.as_str() {
+                    Some(value) if is_valid_xaddress(value) => {
+                        let handled_xaddress = handle_xaddress(field.into(), value.into())?;
+                        if let (Some(handled_tag), Some(object_tag)) =
+                            (handled_xaddress.get(SOURCE_TAG), &source_tag)
+                        {
                             if handled_tag != object_tag {
                                 return Err(
                                     exceptions::XRPLSerializeMapException::AccountMismatchingTags
@@ -352,36 +421,36 @@ impl STObject {
                                 );
                             }
                         }
-                    }
-                    if let Some(handled_tag) = handled_xaddress.get(DESTINATION_TAG) {
-                        if let Some(object_tag) = object.get(DESTINATION_TAG) {
+                        if let (Some(handled_tag), Some(object_tag)) =
+                            (handled_xaddress.get(DESTINATION_TAG), &destination_tag)
+                        {
                             if handled_tag != object_tag {
                                 return Err(
-                                    exceptions::XRPLSerializeMapException::DestinationMismatchingTags.into()
-                                );
+                                exceptions::XRPLSerializeMapException::DestinationMismatchingTags
+                                    .into(),
+                            );
                             }
                         }
-                    }
-                    value_xaddress_handled.extend(handled_xaddress);
-                } else if field == "TransactionType" {
-                    let transaction_type_code = match get_transaction_type_code(value) {
-                        Some(code) => code,
-                        None => {
-                            return Err(
-                                exceptions::XRPLSerializeMapException::UnknownTransactionType(
-                                    value.to_string(),
-                                )
-                                .into(),
-                            )
+                        for (field, value) in handled_xaddress {
+                            push_serialized_field(&mut fields, &field, value)?;
                         }
-                    };
-                    value_xaddress_handled.insert(
-                        field.to_owned(),
-                        Value::Number(transaction_type_code.to_owned().into()),
-                    );
-                } else if field == "TransactionResult" {
-                    let transaction_result_code =
-                        match get_transaction_result_code(value) {
+                        continue;
+                    }
+                    Some(value) if field == "TransactionType" => {
+                        let transaction_type_code =
+                            match get_transaction_type_code(value) {
+                                Some(code) => code,
+                                None => return Err(
+                                    exceptions::XRPLSerializeMapException::UnknownTransactionType(
+                                        value.to_string(),
+                                    )
+                                    .into(),
+                                ),
+                            };
+                        Value::Number(transaction_type_code.to_owned().into())
+                    }
+                    Some(value) if field == "TransactionResult" => {
+                        let transaction_result_code = match get_transaction_result_code(value) {
                             Some(code) => code,
                             None => return Err(
                                 exceptions::XRPLSerializeMapException::UnknownTransactionResult(
@@ -390,79 +459,70 @@ impl STObject {
                                 .into(),
                             ),
                         };
-                    value_xaddress_handled.insert(
-                        field.to_owned(),
-                        Value::Number(transaction_result_code.to_owned().into()),
-                    );
-                } else if field == "LedgerEntryType" {
-                    let ledger_entry_type_code = match get_transaction_type_code(value) {
-                        Some(code) => code,
-                        None => {
-                            return Err(
-                                exceptions::XRPLSerializeMapException::UnknownLedgerEntryType(
-                                    value.to_string(),
-                                )
-                                .into(),
-                            )
-                        }
-                    };
-                    value_xaddress_handled.insert(
-                        field.to_owned(),
-                        Value::Number(ledger_entry_type_code.to_owned().into()),
-                    );
-                } else {
-                    value_xaddress_handled
-                        .insert(field.to_owned(), Value::String(value.to_owned()));
-                }
-            } else {
-                value_xaddress_handled.insert(field.to_owned(), value.clone());
-            }
+                        Value::Number(transaction_result_code.to_owned().into())
+                    }
+                    Some(value) if field == "LedgerEntryType" => {
+                        let ledger_entry_type_code =
+                            match get_ledger_entry_type_code(value) {
+                                Some(code) => code,
+                                None => return Err(
+                                    exceptions::XRPLSerializeMapException::UnknownLedgerEntryType(
+                                        value.to_string(),
+                                    )
+                                    .into(),
+                                ),
+                            };
+                        Value::Number(ledger_entry_type_code.to_owned().into())
+                    }
+                    _ => value,
+                };
+            push_serialized_field(&mut fields, &field, value)?;
         }
 
-        let mut sorted_keys: Vec<FieldInstance> = Vec::new();
-        for (field, _) in &value_xaddress_handled {
-            let field_instance = get_field_instance(field);
-            if let Some(field_instance) = field_instance {
-                if value_xaddress_handled.contains_key(&field_instance.name)
-                    && field_instance.is_serialized
-                {
-                    sorted_keys.push(field_instance);
-                }
-            }
-        }
-        sorted_keys.sort_by_key(|k| k.ordinal);
+        fields.sort_by_key(|(k, _)| k.ordinal);
+        // An X-address tag may also be given as its own field, with
+        // the same value.
+        fields.dedup_by_key(|(k, _)| &k.header);
         if signing_only {
-            sorted_keys.retain(|k| k.is_signing);
+            fields.retain(|(k, _)| k.is_signing);
         }
         let mut is_unl_modify = false;
 
-        for field_instance in &sorted_keys {
-            let associated_value = value_xaddress_handled.get(&field_instance.name).ok_or(
-                exceptions::XRPLTypeException::MissingField(field_instance.name.clone()),
-            )?;
-            let associated_value = XRPLTypes::from_value(
-                &field_instance.associated_type,
-                associated_value.to_owned(),
-            )?;
-            let associated_value: SerializedType = associated_value.into();
-            if field_instance.name == "TransactionType"
-                && associated_value.to_string() == UNL_MODIFY_TX_TYPE
-            {
-                is_unl_modify = true;
-            }
-            let is_unl_modify_workaround = field_instance.name == "Account" && is_unl_modify;
-
-            serializer.write_field_and_value(
-                field_instance.to_owned(),
-                associated_value.as_ref(),
-                is_unl_modify_workaround,
-            );
-            if field_instance.associated_type == ST_OBJECT {
-                serializer.append(OBJECT_END_MARKER_BYTES.to_vec().as_mut());
+        for (field_instance, associated_value) in fields {
+            // Nested objects and arrays are written in place rather
+            // than serialized on their own first.
+            match (field_instance.associated_type.as_str(), associated_value) {
+                (ST_OBJECT, value @ Value::Object(_)) => {
+                    serializer.extend_from_slice(field_instance.header_bytes());
+                    Self::write_value(value, false, serializer)?;
+                    serializer.extend_from_slice(&OBJECT_END_MARKER_BYTES);
+                }
+                (ST_ARRAY, value @ Value::Array(_)) => {
+                    serializer.extend_from_slice(field_instance.header_bytes());
+                    STArray::write_value(value, serializer)?;
+                }
+                (associated_type, associated_value) => {
+                    let associated_value =
+                        XRPLTypes::from_value(associated_type, associated_value)?;
+                    let associated_value: SerializedType = associated_value.into();
+                    if field_instance.name == "TransactionType"
+                        && associated_value.as_ref() == UNL_MODIFY_TX_TYPE
+                    {
+                        is_unl_modify = true;
+                    }
+                    let is_unl_modify_workaround =
+                        field_instance.name == "Account" && is_unl_modify;
+
+                    serializer.write_field_and_value(
+                        field_instance,
+                        associated_value.as_ref(),
+                        is_unl_modify_workaround,
+                    );
+                }
             }
         }
 
-        Ok(STObject(serializer.into()))
+        Ok(())
     }
 }
 
@@ -484,6 +544,52 @@ impl AsRef<[u8]> for STObject {
     }
 }
 
+impl From<STObject> for Vec<u8> {
+    fn from(st_object: STObject) -> Self {
+        st_object.0 .0
+    }
+}
+
+/// Estimate the size of a serde_json::Value once serialized, to
+/// allocate the buffer for it once. Strings are mostly hex, which
+/// takes half the space, and each field has a header of up to 3
+/// bytes.
+pub(crate) fn serialized_size_hint(value: &Value) -> usize {
+    match value {
+        Value::Object(object) => {
+            object
+                .values()
+                .map(|value| FIELD_HEADER_MAX_LENGTH + serialized_size_hint(value))
+                .sum::<usize>()
+                + OBJECT_END_MARKER_BYTES.len()
+        }
+        Value::Array(array) => {
+            array.iter().map(serialized_size_hint).sum::<usize>() + ARRAY_END_MARKER.len()
+        }
+        Value::String(string) => string.len() / 2 + 1,
+        Value::Number(_) => 8,
+        Value::Bool(_) | Value::Null => 1,
+    }
+}
+
+/// Add a field to be serialized, unless it is not part of the
+/// binary format.
+fn push_serialized_field(
+    fields: &mut Vec<(&'static FieldInstance, Value)>,
+    field: &str,
+    value: Value,
+) -> XRPLCoreResult<()> {
+    // Skipping a field we know nothing about would still produce a
+    // valid looking, but wrong, signing payload.
+    let field_instance = get_field_instance(field)
+        .ok_or_else(|| exceptions::XRPLSerializeMapException::UnknownField(field.to_owned()))?;
+    if field_instance.is_serialized {
+        fields.push((field_instance, value));
+    }
+
+    Ok(())
+}
+
 fn handle_xaddress(field: Cow<str>, xaddress: Cow<str>) -> XRPLCoreResult<Map<String, Value>> {
     let (classic_address, tag, _is_test_net) = xaddress_to_classic_address(&xaddress)?;
     if let Some(tag) = tag {
@@ -609,10 +715,54 @@ impl AsRef<[u8]> for SerializedType {
 
 impl<T> From<T> for SerializedType
 where
-    T: XRPLType + AsRef<[u8]>,
+    T: XRPLType + Into<Vec<u8>>,
 {
-    /// Create a serialized type from an XRPLType.
+    /// Create a serialized type from an XRPLType, taking over its
+    /// buffer.
     fn from(instance: T) -> Self {
-        SerializedType(instance.as_ref().to_vec())
+        SerializedType(instance.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+    use serde_json::json;
+
+    #[test]
+    fn test_stobject_unknown_field() {
+        let result = STObject::try_from_value(
+            json!({
+                "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+                "NotAField": 1,
+            }),
+            false,
+        );
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .ends_with("Unknown field: NotAField"));
+    }
+
+    #[test]
+    fn test_stobject_field_order_with_same_type_code() {
+        // All UInt32, given in an order that is neither alphabetical
+        // nor canonical.
+        let object = STObject::try_from_value(
+            json!({
+                "LastLedgerSequence": 3,
+                "Sequence": 1,
+                "SourceTag": 2,
+            }),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            hex::encode_upper(object.as_ref()),
+            "23000000022400000001201B00000003"
+        );
     }
 }
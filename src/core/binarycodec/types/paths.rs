@@ -7,6 +7,7 @@ use crate::constants::ACCOUNT_ID_LENGTH;
 use crate::core::binarycodec::exceptions::XRPLBinaryCodecException;
 use crate::core::binarycodec::types::utils::CURRENCY_CODE_LENGTH;
 use crate::core::binarycodec::types::*;
+use crate::core::binarycodec::IndexMap;
 use crate::core::exceptions::XRPLCoreException;
 use crate::core::exceptions::XRPLCoreResult;
 use crate::core::BinaryParser;
@@ -18,7 +19,6 @@ use alloc::string::ToString;
 use alloc::vec;
 use alloc::vec::Vec;
 use core::convert::TryFrom;
-use indexmap::IndexMap;
 use serde::ser::{SerializeMap, SerializeSeq};
 use serde::{Deserialize, Serialize, Serializer};
 use serde_with::skip_serializing_none;
@@ -351,7 +351,7 @@ impl TryFrom<IndexMap<String, String>> for PathStep {
         buffer.extend_from_slice(&[data_type]);
         buffer.extend_from_slice(&value_bytes);
 
-        Ok(Self::new(Some(&buffer))?)
+        Self::new(Some(&buffer))
     }
 }
 
@@ -367,7 +367,7 @@ impl TryFrom<Vec<IndexMap<String, String>>> for Path {
             buffer.extend_from_slice(pathstep.as_ref());
         }
 
-        Ok(Path::new(Some(&buffer))?)
+        Path::new(Some(&buffer))
     }
 }
 
@@ -440,6 +440,12 @@ impl AsRef<[u8]> for PathStep {
     }
 }
 
+impl From<PathStep> for Vec<u8> {
+    fn from(path_step: PathStep) -> Self {
+        path_step.0
+    }
+}
+
 impl AsRef<[u8]> for Path {
     /// Get a reference of the byte representation.
     fn as_ref(&self) -> &[u8] {
@@ -447,6 +453,12 @@ impl AsRef<[u8]> for Path {
     }
 }
 
+impl From<Path> for Vec<u8> {
+    fn from(path: Path) -> Self {
+        path.0
+    }
+}
+
 impl AsRef<[u8]> for PathSet {
     /// Get a reference of the byte representation.
     fn as_ref(&self) -> &[u8] {
@@ -454,6 +466,12 @@ impl AsRef<[u8]> for PathSet {
     }
 }
 
+impl From<PathSet> for Vec<u8> {
+    fn from(path_set: PathSet) -> Self {
+        path_set.0
+    }
+}
+
 impl Iterator for PathStepData {
     type Item = (String, Option<String>);
 
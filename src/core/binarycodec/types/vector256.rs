@@ -7,9 +7,11 @@ use crate::core::binarycodec::types::*;
 use crate::core::exceptions::XRPLCoreException;
 use crate::core::exceptions::XRPLCoreResult;
 use crate::core::BinaryParser;
+use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 use core::convert::TryFrom;
+use core::str::FromStr;
 use serde::ser::Error;
 use serde::ser::SerializeSeq;
 use serde::Serializer;
@@ -20,7 +22,7 @@ const _HASH_LENGTH_BYTES: usize = 32;
 /// Codec for serializing and deserializing
 /// vectors of Hash256.
 #[derive(Debug, Deserialize, Clone)]
-#[serde(try_from = "Vec<&str>")]
+#[serde(try_from = "Vec<String>")]
 pub struct Vector256(Vec<u8>);
 
 impl XRPLType for Vector256 {
@@ -51,6 +53,9 @@ impl TryFromParser for Vector256 {
             parser.len()
         };
 
+        if !num_bytes.is_multiple_of(_HASH_LENGTH_BYTES) {
+            return Err(XRPLVectorException::InvalidVector256Bytes.into());
+        }
         let num_hashes: usize = num_bytes / _HASH_LENGTH_BYTES;
 
         for _ in 0..num_hashes {
@@ -66,14 +71,15 @@ impl Serialize for Vector256 {
     where
         S: Serializer,
     {
-        if self.0.len() % _HASH_LENGTH_BYTES != 0 {
+        if !self.0.len().is_multiple_of(_HASH_LENGTH_BYTES) {
             Err(S::Error::custom(XRPLVectorException::InvalidVector256Bytes))
         } else {
             let mut sequence = serializer.serialize_seq(None)?;
 
-            for i in (0..self.0.len()).step_by(_HASH_LENGTH_BYTES) {
-                let encoded = hex::encode_upper(&self.0[i..i + _HASH_LENGTH_BYTES]);
-                sequence.serialize_element(&encoded)?;
+            for chunk in self.0.chunks(_HASH_LENGTH_BYTES) {
+                // Each chunk is exactly one Hash256 after the length check above.
+                let hash = Hash256::new(Some(chunk)).map_err(S::Error::custom)?;
+                sequence.serialize_element(&hash)?;
             }
 
             sequence.end()
@@ -96,6 +102,31 @@ impl TryFrom<Vec<&str>> for Vector256 {
     }
 }
 
+impl TryFrom<Vec<String>> for Vector256 {
+    type Error = XRPLCoreException;
+
+    /// Construct a Vector256 from a list of strings.
+    fn try_from(value: Vec<String>) -> XRPLCoreResult<Self, Self::Error> {
+        Vector256::try_from(value.iter().map(String::as_str).collect::<Vec<_>>())
+    }
+}
+
+impl FromStr for Vector256 {
+    type Err = XRPLCoreException;
+
+    /// Construct a Vector256 from the concatenated hex
+    /// representation of its hashes.
+    fn from_str(value: &str) -> XRPLCoreResult<Self, Self::Err> {
+        let bytes = hex::decode(value)?;
+
+        if !bytes.len().is_multiple_of(_HASH_LENGTH_BYTES) {
+            Err(XRPLVectorException::InvalidVector256Bytes.into())
+        } else {
+            Ok(Vector256(bytes))
+        }
+    }
+}
+
 impl Display for Vector256 {
     /// Get the hex representation of the Vector256 bytes.
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
@@ -110,6 +141,12 @@ impl AsRef<[u8]> for Vector256 {
     }
 }
 
+impl From<Vector256> for Vec<u8> {
+    fn from(vector256: Vector256) -> Self {
+        vector256.0
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -133,6 +170,10 @@ mod test {
 
         assert!(result.is_ok());
         assert_eq!(SERIALIZED, result.unwrap().to_string());
+
+        // The length of the field must be a whole number of hashes.
+        let mut parser = BinaryParser::from(hex::decode(SERIALIZED).unwrap());
+        assert!(Vector256::from_parser(&mut parser, Some(33)).is_err());
     }
 
     #[test]
@@ -143,6 +184,13 @@ mod test {
         assert_eq!(SERIALIZED, result.unwrap().to_string());
     }
 
+    #[test]
+    fn test_vector256_from_str_round_trip() {
+        let vector: Vector256 = SERIALIZED.parse().unwrap();
+        assert_eq!(SERIALIZED, vector.to_string());
+        assert!(Vector256::from_str(HASH1.get(..62).unwrap()).is_err());
+    }
+
     #[test]
     fn accept_vector256_serde_encode_decode() {
         let vector = Vector256::try_from(vec![HASH1, HASH2]).unwrap();
@@ -151,5 +199,10 @@ mod test {
 
         assert_eq!(format!("[\"{HASH1}\",\"{HASH2}\"]"), serialize);
         assert_eq!(SERIALIZED, deserialize.to_string());
+
+        let deserialize: Vector256 =
+            serde_json::from_value(serde_json::json!([HASH1, HASH2])).unwrap();
+        assert_eq!(SERIALIZED, deserialize.to_string());
+        assert!(serde_json::from_value::<Vector256>(serde_json::json!([&HASH1[..62]])).is_err());
     }
 }
@@ -110,3 +110,9 @@ impl AsRef<[u8]> for XChainBridge {
         self.0.as_ref()
     }
 }
+
+impl From<XChainBridge> for Vec<u8> {
+    fn from(xchain_bridge: XChainBridge) -> Self {
+        xchain_bridge.0 .0
+    }
+}
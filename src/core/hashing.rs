@@ -0,0 +1,169 @@
+//! The hashes of the XRP Ledger, for signing flows that do not go
+//! through a [`Wallet`](crate::wallet::Wallet), e.g. with a hardware
+//! security module or a remote signer.
+//!
+//! Most objects are hashed with [`sha512_half`] after one of the
+//! 4-byte prefixes below, which keep hashes of different kinds of
+//! objects from colliding.
+//!
+//! See Hashes:
+//! `<https://xrpl.org/basic-data-types.html#hashes>`
+//!
+//! # Examples
+//!
+//! ## Basic usage
+//!
+//! Hash the payload a single signer signs, without a `Wallet`:
+//!
+//! ```
+//! use xrpl::core::binarycodec::encode_for_signing;
+//! use xrpl::core::hashing::{sha512_half, TRANSACTION_SIGNATURE_PREFIX};
+//! use serde_json::json;
+//!
+//! let tx = json!({
+//!     "TransactionType": "AccountSet",
+//!     "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+//!     "Fee": "12",
+//!     "Sequence": 1,
+//!     "SigningPubKey": "ED5F5AC8B98974A3CA843326D9B88CEBD0560177B973EE0B149F782CFAA06DC66A"
+//! });
+//! let payload = hex::decode(encode_for_signing(&tx).unwrap()).unwrap();
+//! assert_eq!(payload[..4], TRANSACTION_SIGNATURE_PREFIX.to_be_bytes());
+//!
+//! // What a SECP256K1 signer signs; ED25519 signs the payload itself.
+//! let digest: [u8; 32] = sha512_half(&payload);
+//! ```
+//!
+//! Derive an address from a public key:
+//!
+//! ```
+//! use xrpl::core::addresscodec::encode_classic_address;
+//! use xrpl::core::hashing::account_id_from_public_key;
+//!
+//! let account_id = account_id_from_public_key(
+//!     "ED01FA53FA5A7E77798F882ECE20B1ABC00BB358A9E55A202D0D0676BD0CE37A63",
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(
+//!     encode_classic_address(&account_id).unwrap(),
+//!     "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD",
+//! );
+//! ```
+
+use core::convert::TryInto;
+
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256, Sha512};
+
+use crate::constants::ACCOUNT_ID_LENGTH;
+
+use super::exceptions::XRPLCoreResult;
+
+/// Length of half a SHA-512 hash.
+pub const SHA512_HALF_LENGTH: usize = 32;
+
+/// Prefix of a signed transaction when hashing it into its
+/// transaction ID (`TXN\0`).
+pub const TRANSACTION_ID_HASH_PREFIX: u32 = 0x54584E00;
+/// Prefix of a transaction with metadata when hashing it as a
+/// leaf of the transaction tree (`SND\0`).
+pub const TRANSACTION_NODE_HASH_PREFIX: u32 = 0x534E4400;
+/// Prefix of a transaction when single-signing it (`STX\0`).
+pub const TRANSACTION_SIGNATURE_PREFIX: u32 = 0x53545800;
+/// Prefix of a transaction when multi-signing it (`SMT\0`).
+pub const TRANSACTION_MULTISIG_PREFIX: u32 = 0x534D5400;
+/// Prefix of a payment channel claim when signing it (`CLM\0`).
+pub const PAYMENT_CHANNEL_CLAIM_PREFIX: u32 = 0x434C4D00;
+/// Prefix of a ledger header when hashing it (`LWR\0`).
+pub const LEDGER_HASH_PREFIX: u32 = 0x4C575200;
+/// Prefix of an inner node of a ledger's state or transaction tree
+/// when hashing it (`MIN\0`).
+pub const INNER_NODE_HASH_PREFIX: u32 = 0x4D494E00;
+/// Prefix of a ledger entry when hashing it as a leaf of the state
+/// tree, after its index (`MLN\0`).
+pub const LEAF_NODE_HASH_PREFIX: u32 = 0x4D4C4E00;
+
+/// The first half of the SHA-512 hash of `data`, the hash most of
+/// the XRP Ledger uses.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::hashing::sha512_half;
+///
+/// assert_eq!(
+///     hex::encode_upper(sha512_half(b"test message")),
+///     "950B2A7EFFA78F51A63515EC45E03ECEBE50EF2F1C41E69629B50778F11BC080",
+/// );
+/// ```
+pub fn sha512_half(data: &[u8]) -> [u8; SHA512_HALF_LENGTH] {
+    let mut sha512 = Sha512::new();
+
+    sha512.update(data);
+    sha512.finalize()[..SHA512_HALF_LENGTH]
+        .try_into()
+        .expect("Invalid slice length")
+}
+
+/// The account ID of a public key: the RIPEMD-160 hash of its
+/// SHA-256 hash.
+///
+/// See Account ID and Address:
+/// `<https://xrpl.org/cryptographic-keys.html#account-id-and-address>`
+pub fn account_id(public_key: &[u8]) -> [u8; ACCOUNT_ID_LENGTH] {
+    let mut sha256 = Sha256::new();
+    let mut ripemd160 = Ripemd160::new();
+
+    sha256.update(public_key);
+    ripemd160.update(sha256.finalize());
+
+    ripemd160.finalize()[..ACCOUNT_ID_LENGTH]
+        .try_into()
+        .expect("Invalid slice length")
+}
+
+/// The account ID of a hex encoded public key.
+pub fn account_id_from_public_key(public_key: &str) -> XRPLCoreResult<[u8; ACCOUNT_ID_LENGTH]> {
+    Ok(account_id(&hex::decode(public_key)?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::keypairs::test_cases::*;
+
+    #[test]
+    fn test_sha512_half() {
+        assert_eq!(TEST_MESSAGE_SHA, sha512_half(TEST_MESSAGE.as_bytes()));
+    }
+
+    #[test]
+    fn test_account_id() {
+        assert_eq!(TEST_ACCOUNT_ID, account_id(TEST_MESSAGE.as_bytes()));
+        assert_eq!(
+            account_id_from_public_key(PUBLIC_SECP256K1).unwrap(),
+            account_id(&hex::decode(PUBLIC_SECP256K1).unwrap())
+        );
+        assert!(account_id_from_public_key("not hex").is_err());
+    }
+
+    #[test]
+    fn test_hash_prefixes() {
+        for (prefix, name) in [
+            (TRANSACTION_ID_HASH_PREFIX, b"TXN"),
+            (TRANSACTION_NODE_HASH_PREFIX, b"SND"),
+            (TRANSACTION_SIGNATURE_PREFIX, b"STX"),
+            (TRANSACTION_MULTISIG_PREFIX, b"SMT"),
+            (PAYMENT_CHANNEL_CLAIM_PREFIX, b"CLM"),
+            (LEDGER_HASH_PREFIX, b"LWR"),
+            (INNER_NODE_HASH_PREFIX, b"MIN"),
+            (LEAF_NODE_HASH_PREFIX, b"MLN"),
+        ] {
+            assert_eq!(prefix.to_be_bytes()[..3], name[..]);
+            assert_eq!(prefix.to_be_bytes()[3], 0);
+        }
+    }
+}
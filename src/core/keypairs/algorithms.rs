@@ -9,9 +9,11 @@
 
 use crate::constants::CryptoAlgorithm;
 use crate::core::exceptions::XRPLCoreResult;
+use crate::core::hashing::sha512_half;
 use crate::core::keypairs::exceptions::XRPLKeypairsException;
 use crate::core::keypairs::utils::*;
 use crate::core::keypairs::CryptoImplementation;
+use crate::core::keypairs::PrivateKey;
 use alloc::format;
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -21,9 +23,9 @@ use crypto_bigint::Encoding;
 use crypto_bigint::U256;
 use ed25519_dalek::ed25519::signature::SignerMut;
 use ed25519_dalek::Verifier;
-use ed25519_dalek::SECRET_KEY_LENGTH;
 use secp256k1::ecdsa;
 use secp256k1::Scalar;
+use zeroize::Zeroizing;
 
 /// Methods for using the ECDSA cryptographic system with
 /// the SECP256K1 elliptic curve.
@@ -33,9 +35,10 @@ pub struct Secp256k1;
 pub struct Ed25519;
 
 impl Secp256k1 {
-    /// Hex encode the private key.
-    fn _private_key_to_str(key: secp256k1::SecretKey) -> String {
-        hex::encode_upper(key.as_ref())
+    /// Parse a private key.
+    pub(crate) fn _secret_key(private_key: &PrivateKey) -> XRPLCoreResult<secp256k1::SecretKey> {
+        Ok(secp256k1::SecretKey::from_byte_array(private_key.secret())
+            .map_err(XRPLKeypairsException::SECP256K1Error)?)
     }
 
     /// Hex encode the public key.
@@ -43,26 +46,40 @@ impl Secp256k1 {
         hex::encode_upper(key.serialize())
     }
 
-    /// Format a provided key.
-    fn _format_key(keystr: &str) -> String {
-        format!("{keystr:0>SECP256K1_KEY_LENGTH$}")
+    /// Format a provided key as SECP256K1_KEY_LENGTH uppercase
+    /// hex characters, restoring the leading zeros of its bytes.
+    fn _format_key(keystr: &str) -> XRPLCoreResult<String> {
+        if keystr.len() > SECP256K1_KEY_LENGTH {
+            return Err(XRPLKeypairsException::InvalidKeyLength {
+                max: SECP256K1_KEY_LENGTH,
+                found: keystr.len(),
+            }
+            .into());
+        }
+
+        Ok(format!(
+            "{:0>width$}",
+            keystr.to_uppercase(),
+            width = SECP256K1_KEY_LENGTH
+        ))
     }
 
-    /// Format the public and private keys.
-    /// TODO Make function constant time
+    /// Format the public key and wrap the private key.
     fn _format_keys(
         public: secp256k1::PublicKey,
         private: secp256k1::SecretKey,
-    ) -> (String, String) {
-        (
-            Secp256k1::_format_key(&Secp256k1::_public_key_to_str(public)),
-            Secp256k1::_format_key(&Secp256k1::_private_key_to_str(private)),
-        )
+    ) -> XRPLCoreResult<(String, PrivateKey)> {
+        let secret = Zeroizing::new(private.secret_bytes());
+
+        Ok((
+            Secp256k1::_format_key(&Secp256k1::_public_key_to_str(public))?,
+            PrivateKey::new(CryptoAlgorithm::SECP256K1, &secret),
+        ))
     }
 
     /// Hash the message to prevent insecure signing.
-    fn _get_message(message: &[u8]) -> secp256k1::Message {
-        secp256k1::Message::from_digest(sha512_first_half(message))
+    pub(crate) fn _get_message(message: &[u8]) -> secp256k1::Message {
+        secp256k1::Message::from_digest(sha512_half(message))
     }
 
     /// Determing if the provided secret key is valid.
@@ -93,9 +110,9 @@ impl Secp256k1 {
         bytes: &[u8],
         phase: Secp256k1Phase,
     ) -> XRPLCoreResult<(secp256k1::PublicKey, secp256k1::SecretKey)> {
-        let raw_private = Self::_get_secret(bytes, &phase)?;
+        let raw_private = Zeroizing::new(Self::_get_secret(bytes, &phase)?);
         let secp = secp256k1::Secp256k1::new();
-        let wrapped_private = secp256k1::SecretKey::from_slice(&raw_private)
+        let wrapped_private = secp256k1::SecretKey::from_byte_array(&raw_private)
             .map_err(XRPLKeypairsException::SECP256K1Error)?;
         let wrapped_public = secp256k1::PublicKey::from_secret_key(&secp, &wrapped_private);
 
@@ -131,7 +148,7 @@ impl Secp256k1 {
     ) -> XRPLCoreResult<[u8; SHA512_HASH_LENGTH]> {
         for raw_root in 0..SECP256K1_SEQUENCE_MAX {
             let root = (raw_root as u32).to_be_bytes();
-            let candidate = sha512_first_half(&Self::_candidate_merger(input, &root, phase));
+            let candidate = sha512_half(&Self::_candidate_merger(input, &root, phase));
 
             if Self::_is_secret_valid(candidate) {
                 return Ok(candidate);
@@ -145,9 +162,9 @@ impl Secp256k1 {
 }
 
 impl Ed25519 {
-    /// Hex encode the private key.
-    fn _private_key_to_str(key: ed25519_dalek::SecretKey) -> String {
-        hex::encode(key)
+    /// Parse a private key, deriving its public key.
+    pub(crate) fn _signing_key(private_key: &PrivateKey) -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(private_key.secret())
     }
 
     /// Hex encode the public key.
@@ -161,14 +178,14 @@ impl Ed25519 {
         format!("{}{}", ED25519_PREFIX, keystr.to_uppercase())
     }
 
-    /// Format the public and private keys.
+    /// Format the public key and wrap the private key.
     fn _format_keys(
         public: ed25519_dalek::VerifyingKey,
-        private: ed25519_dalek::SecretKey,
-    ) -> (String, String) {
+        private: &ed25519_dalek::SecretKey,
+    ) -> (String, PrivateKey) {
         (
             Ed25519::_format_key(&Ed25519::_public_key_to_str(public)),
-            Ed25519::_format_key(&Ed25519::_private_key_to_str(private)),
+            PrivateKey::new(CryptoAlgorithm::ED25519, private),
         )
     }
 }
@@ -201,7 +218,7 @@ impl CryptoImplementation for Secp256k1 {
     ///     decoded_seed,
     ///     validator,
     /// ) {
-    ///     Ok((public, private)) => Some((public, private)),
+    ///     Ok((public, private)) => Some((public, private.to_hex())),
     ///     Err(e) => match e {
     ///         XRPLCoreException::XRPLKeypairsError(XRPLKeypairsException::InvalidSignature) => None,
     ///         XRPLCoreException::XRPLKeypairsError(XRPLKeypairsException::InvalidSecret) => None,
@@ -216,17 +233,17 @@ impl CryptoImplementation for Secp256k1 {
         &self,
         decoded_seed: &[u8],
         is_validator: bool,
-    ) -> XRPLCoreResult<(String, String)> {
+    ) -> XRPLCoreResult<(String, PrivateKey)> {
         let (root_public, root_secret) = Self::_derive_part(decoded_seed, Secp256k1Phase::Root)?;
         if is_validator {
-            Ok(Secp256k1::_format_keys(root_public, root_secret))
+            Secp256k1::_format_keys(root_public, root_secret)
         } else {
             let (mid_public, mid_secret) =
                 Self::_derive_part(&root_public.serialize(), Secp256k1Phase::Mid)?;
             let (final_public, final_secret) =
                 Self::_derive_final(root_public, root_secret, mid_public, mid_secret)?;
 
-            Ok(Secp256k1::_format_keys(final_public, final_secret))
+            Secp256k1::_format_keys(final_public, final_secret)
         }
     }
 
@@ -239,14 +256,15 @@ impl CryptoImplementation for Secp256k1 {
     /// ## Basic usage
     ///
     /// ```
-    /// use xrpl::core::keypairs::Secp256k1;
+    /// use xrpl::core::keypairs::{PrivateKey, Secp256k1};
     /// use xrpl::core::keypairs::exceptions::XRPLKeypairsException;
     /// use xrpl::core::keypairs::CryptoImplementation;
     /// use xrpl::core::exceptions::XRPLCoreException;
     ///
     /// let message: &[u8] = "test message".as_bytes();
-    /// let private_key: &str = "00D78B9735C3F26501C7337B8A5727FD5\
-    ///                          3A6EFDBC6AA55984F098488561F985E23";
+    /// let private_key = PrivateKey::from_hex(
+    ///     "00D78B9735C3F26501C7337B8A5727FD53A6EFDBC6AA55984F098488561F985E23",
+    /// ).unwrap();
     /// let signature: Vec<u8> = vec![
     ///     48, 68, 2, 32, 88, 58, 145, 201, 94, 84, 230, 166, 81, 196,
     ///     123, 236, 34, 116, 78, 11, 16, 30, 44, 64, 96, 231, 176, 143,
@@ -258,7 +276,7 @@ impl CryptoImplementation for Secp256k1 {
     ///
     /// let signing: Option<Vec<u8>> = match Secp256k1.sign(
     ///     message,
-    ///     private_key,
+    ///     &private_key,
     /// ) {
     ///     Ok(signature) => Some(signature),
     ///     Err(e) => match e {
@@ -269,12 +287,10 @@ impl CryptoImplementation for Secp256k1 {
     ///
     /// assert_eq!(Some(signature), signing);
     /// ```
-    fn sign(&self, message_bytes: &[u8], private_key: &str) -> XRPLCoreResult<Vec<u8>> {
+    fn sign(&self, message_bytes: &[u8], private_key: &PrivateKey) -> XRPLCoreResult<Vec<u8>> {
         let secp = secp256k1::Secp256k1::<secp256k1::SignOnly>::signing_only();
         let message = Self::_get_message(message_bytes);
-        let trimmed_key = private_key.trim_start_matches(SECP256K1_PREFIX);
-        let private = secp256k1::SecretKey::from_str(trimmed_key)
-            .map_err(XRPLKeypairsException::SECP256K1Error)?;
+        let private = Self::_secret_key(private_key)?;
         let signature = secp.sign_ecdsa(&message, &private);
 
         Ok(signature.serialize_der().to_vec())
@@ -314,8 +330,10 @@ impl CryptoImplementation for Secp256k1 {
             let sig = ecdsa::Signature::from_der(&value);
             let public = secp256k1::PublicKey::from_str(public_key);
 
-            if let (&Ok(s), &Ok(p)) = (&sig.as_ref(), &public.as_ref()) {
-                secp.verify_ecdsa(&msg, s, p).is_ok()
+            // High S values are not fully canonical and fail to verify,
+            // as under the RequireFullyCanonicalSig amendment.
+            if let (Ok(s), Ok(p)) = (sig, public) {
+                secp.verify_ecdsa(&msg, &s, &p).is_ok()
             } else {
                 false
             }
@@ -353,7 +371,7 @@ impl CryptoImplementation for Ed25519 {
     ///     decoded_seed,
     ///     validator,
     /// ) {
-    ///     Ok((public, private)) => Some((public, private)),
+    ///     Ok((public, private)) => Some((public, private.to_hex())),
     ///     Err(e) => match e {
     ///         XRPLCoreException::XRPLKeypairsError(XRPLKeypairsException::InvalidSignature) => None,
     ///         XRPLCoreException::XRPLKeypairsError(XRPLKeypairsException::ED25519Error) => None,
@@ -368,19 +386,17 @@ impl CryptoImplementation for Ed25519 {
         &self,
         decoded_seed: &[u8],
         is_validator: bool,
-    ) -> XRPLCoreResult<(String, String)> {
+    ) -> XRPLCoreResult<(String, PrivateKey)> {
         if is_validator {
             Err(XRPLKeypairsException::UnsupportedValidatorAlgorithm {
                 expected: CryptoAlgorithm::ED25519,
             }
             .into())
         } else {
-            let raw_private = sha512_first_half(decoded_seed);
-            let private: [u8; SECRET_KEY_LENGTH] = ed25519_dalek::SecretKey::from(raw_private);
-            let signing_key: ed25519_dalek::SigningKey = private.into();
-            let public = (&signing_key).into();
+            let private = Zeroizing::new(sha512_half(decoded_seed));
+            let signing_key = ed25519_dalek::SigningKey::from_bytes(&private);
 
-            Ok(Ed25519::_format_keys(public, private))
+            Ok(Ed25519::_format_keys(signing_key.verifying_key(), &private))
         }
     }
 
@@ -393,13 +409,14 @@ impl CryptoImplementation for Ed25519 {
     /// ## Basic usage
     ///
     /// ```
-    /// use xrpl::core::keypairs::Ed25519;
+    /// use xrpl::core::keypairs::{Ed25519, PrivateKey};
     /// use xrpl::core::keypairs::exceptions::XRPLKeypairsException;
     /// use xrpl::core::keypairs::CryptoImplementation;
     ///
     /// let message: &[u8] = "test message".as_bytes();
-    /// let private_key: &str = "EDB4C4E046826BD26190D09715FC31F4E\
-    ///                          6A728204EADD112905B08B14B7F15C4F3";
+    /// let private_key = PrivateKey::from_hex(
+    ///     "EDB4C4E046826BD26190D09715FC31F4E6A728204EADD112905B08B14B7F15C4F3",
+    /// ).unwrap();
     /// let signature: Vec<u8> = vec![
     ///     203, 25, 158, 27, 253, 78, 61, 170, 16, 94, 72, 50, 238, 223,
     ///     163, 100, 19, 225, 244, 66, 5,228, 239, 185, 226, 126, 130, 96,
@@ -410,19 +427,13 @@ impl CryptoImplementation for Ed25519 {
     ///
     /// let signing: Option<Vec<u8>> = Some(Ed25519.sign(
     ///     message,
-    ///     private_key,
+    ///     &private_key,
     /// ).unwrap());
     ///
     /// assert_eq!(Some(signature), signing);
     /// ```
-    fn sign(&self, message: &[u8], private_key: &str) -> XRPLCoreResult<Vec<u8>> {
-        let raw_private = hex::decode(&private_key[ED25519_PREFIX.len()..])?;
-        let raw_private_slice: &[u8; SECRET_KEY_LENGTH] = raw_private
-            .as_slice()
-            .try_into()
-            .map_err(|_| XRPLKeypairsException::InvalidSecret)?;
-        let private: ed25519_dalek::SecretKey = *raw_private_slice;
-        let mut signing_key: ed25519_dalek::SigningKey = private.into();
+    fn sign(&self, message: &[u8], private_key: &PrivateKey) -> XRPLCoreResult<Vec<u8>> {
+        let mut signing_key = Self::_signing_key(private_key);
         let signature = signing_key.sign(message);
 
         Ok(signature.to_bytes().to_vec())
@@ -496,16 +507,48 @@ mod test {
         let (public, private) = Secp256k1.derive_keypair(seed, false).unwrap();
 
         assert!(validator.is_ok());
-        assert_eq!(PRIVATE_SECP256K1, private);
+        assert_eq!(PRIVATE_SECP256K1, private.to_hex());
         assert_eq!(PUBLIC_SECP256K1, public);
     }
 
     #[test]
-    fn test_secp256k1_sign() {
-        let success = Secp256k1.sign(TEST_MESSAGE.as_bytes(), PRIVATE_SECP256K1);
-        let error = Secp256k1.sign(TEST_MESSAGE.as_bytes(), "abc123");
+    fn test_secp256k1_format_key() {
+        // A key whose first bytes are zero, hex encoded without them.
+        let key = "00000A0B0C0D0E0F101112131415161718191A1B1C1D1E1F202122232425262728";
+        let formatted = Secp256k1::_format_key(&key[5..].to_lowercase()).unwrap();
+
+        assert_eq!(formatted, key);
+        assert_eq!(Secp256k1::_format_key("").unwrap(), "0".repeat(66));
+        assert_eq!(
+            Secp256k1::_format_key(&"1".repeat(67)),
+            Err(XRPLKeypairsException::InvalidKeyLength { max: 66, found: 67 }.into())
+        );
+    }
+
+    #[test]
+    fn test_secp256k1_private_key_leading_zeros() {
+        let mut secret = [0x42; 32];
+        secret[..3].copy_from_slice(&[0, 0, 7]);
+        let private_key = PrivateKey::new(CryptoAlgorithm::SECP256K1, &secret);
+
+        assert_eq!(private_key.to_hex().len(), 66);
+        assert!(private_key.to_hex().starts_with("00000007"));
+        assert!(Secp256k1
+            .sign(TEST_MESSAGE.as_bytes(), &private_key)
+            .is_ok());
+    }
 
-        assert!(success.is_ok());
+    #[test]
+    fn test_secp256k1_sign() {
+        let private_key = PrivateKey::from_hex(PRIVATE_SECP256K1).unwrap();
+        let signature = Secp256k1
+            .sign(TEST_MESSAGE.as_bytes(), &private_key)
+            .unwrap();
+        // Out of the curve order.
+        let invalid_key = PrivateKey::new(CryptoAlgorithm::SECP256K1, &[0xFF; 32]);
+        let error = Secp256k1.sign(TEST_MESSAGE.as_bytes(), &invalid_key);
+
+        assert_eq!(signature, SIGNATURE_SECP256K1);
         assert!(error.is_err());
     }
 
@@ -517,6 +560,34 @@ mod test {
         assert!(Secp256k1.is_valid_message(message, signature, PUBLIC_SECP256K1));
     }
 
+    #[test]
+    fn test_secp256k1_rejects_high_s() {
+        const ORDER: [u8; 32] = [
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+            0xFF, 0xFE, 0xBA, 0xAE, 0xDC, 0xE6, 0xAF, 0x48, 0xA0, 0x3B, 0xBF, 0xD2, 0x5E, 0x8C,
+            0xD0, 0x36, 0x41, 0x41,
+        ];
+        let mut compact = ecdsa::Signature::from_der(&SIGNATURE_SECP256K1)
+            .unwrap()
+            .serialize_compact();
+        // The same signature with S replaced by ORDER - S.
+        let mut borrow = 0;
+        for i in (0..32).rev() {
+            let difference = ORDER[i] as i16 - compact[32 + i] as i16 - borrow;
+            borrow = (difference < 0) as i16;
+            compact[32 + i] = difference.rem_euclid(256) as u8;
+        }
+        let high_s = ecdsa::Signature::from_compact(&compact)
+            .unwrap()
+            .serialize_der();
+
+        assert!(!Secp256k1.is_valid_message(
+            TEST_MESSAGE.as_bytes(),
+            &hex::encode_upper(high_s),
+            PUBLIC_SECP256K1
+        ));
+    }
+
     #[test]
     fn test_ed25519_derive_keypair() {
         let seed: &[u8] = SEED_ED25519.as_bytes();
@@ -525,16 +596,15 @@ mod test {
 
         assert!(validator.is_err());
         assert_eq!(RAW_PRIVATE_ED25519, public);
-        assert_eq!(RAW_PUBLIC_ED25519, private);
+        assert_eq!(RAW_PUBLIC_ED25519, private.to_hex());
     }
 
     #[test]
     fn test_ed25519_sign() {
-        let success = Ed25519.sign(TEST_MESSAGE.as_bytes(), RAW_PRIVATE_ED25519);
-        let error = Ed25519.sign(TEST_MESSAGE.as_bytes(), "abc123");
+        let private_key = PrivateKey::from_hex(PRIVATE_ED25519).unwrap();
+        let signature = Ed25519.sign(TEST_MESSAGE.as_bytes(), &private_key).unwrap();
 
-        assert!(success.is_ok());
-        assert!(error.is_err());
+        assert_eq!(signature, SIGNATURE_ED25519);
     }
 
     #[test]
@@ -12,6 +12,10 @@ pub enum XRPLKeypairsException {
     InvalidSignature,
     #[error("Invalid secret")]
     InvalidSecret,
+    #[error("Invalid digest length (expected {expected}, found {found})")]
+    InvalidDigestLength { expected: usize, found: usize },
+    #[error("Invalid key length (max {max}, found {found})")]
+    InvalidKeyLength { max: usize, found: usize },
     #[error("Unsupported validator algorithm: {expected:?}")]
     UnsupportedValidatorAlgorithm { expected: CryptoAlgorithm },
     #[error("ed25519 error")]
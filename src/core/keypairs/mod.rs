@@ -2,21 +2,25 @@
 
 pub mod algorithms;
 pub mod exceptions;
+pub mod private_key;
 #[cfg(test)]
 pub(crate) mod test_cases;
 pub mod utils;
 
 pub use self::algorithms::Ed25519;
 pub use self::algorithms::Secp256k1;
+pub use self::private_key::PrivateKey;
 
 use crate::constants::CryptoAlgorithm;
 use crate::core::addresscodec::utils::SEED_LENGTH;
 use crate::core::addresscodec::*;
+use crate::core::hashing::{account_id_from_public_key, sha512_half};
 use crate::core::keypairs::exceptions::XRPLKeypairsException;
 use crate::core::keypairs::utils::*;
 use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::convert::TryInto;
 use rand::Rng;
 use rand::SeedableRng;
 
@@ -30,14 +34,6 @@ const fn _get_algorithm_sig_length(algo: CryptoAlgorithm) -> usize {
     }
 }
 
-/// Return the CryptoAlgorithm from a key.
-fn _get_algorithm_from_key(key: &str) -> CryptoAlgorithm {
-    match &key[..2] {
-        ED25519_PREFIX => CryptoAlgorithm::ED25519,
-        _ => CryptoAlgorithm::SECP256K1,
-    }
-}
-
 /// Return the trait implementation for the provided
 /// algorithm enum.
 fn _get_algorithm_engine(algo: CryptoAlgorithm) -> Box<dyn CryptoImplementation> {
@@ -150,10 +146,10 @@ pub fn derive_keypair(seed: &str, validator: bool) -> XRPLCoreResult<(String, St
     let (decoded_seed, algorithm) = decode_seed(seed)?;
     let module = _get_algorithm_engine(algorithm);
     let (public, private) = module.derive_keypair(&decoded_seed, validator)?;
-    let signature = sign(SIGNATURE_VERIFICATION_MESSAGE, &private)?;
+    let signature = hex::encode_upper(module.sign(SIGNATURE_VERIFICATION_MESSAGE, &private)?);
 
     if module.is_valid_message(SIGNATURE_VERIFICATION_MESSAGE, &signature, &public) {
-        Ok((public, private))
+        Ok((public, private.to_hex()))
     } else {
         Err(XRPLKeypairsException::InvalidSignature.into())
     }
@@ -192,7 +188,7 @@ pub fn derive_keypair(seed: &str, validator: bool) -> XRPLCoreResult<(String, St
 /// assert_eq!(Some(address), derivation);
 /// ```
 pub fn derive_classic_address(public_key: &str) -> XRPLCoreResult<String> {
-    let account_id = get_account_id(&hex::decode(public_key)?);
+    let account_id = account_id_from_public_key(public_key)?;
     encode_classic_address(&account_id)
 }
 
@@ -229,8 +225,149 @@ pub fn derive_classic_address(public_key: &str) -> XRPLCoreResult<String> {
 /// assert_eq!(Some(signature), signing);
 /// ```
 pub fn sign(message: &[u8], private_key: &str) -> XRPLCoreResult<String> {
-    let module = _get_algorithm_engine_from_key(private_key);
-    Ok(hex::encode_upper(module.sign(message, private_key)?))
+    let private_key = PrivateKey::from_hex(private_key)?;
+    let module = _get_algorithm_engine(private_key.algorithm());
+    Ok(hex::encode_upper(module.sign(message, &private_key)?))
+}
+
+/// What the key of `public_key` signs for a message: the message
+/// itself for ED25519 keys, its SHA-512 half for SECP256K1 keys.
+/// A signer holding the key elsewhere, e.g. in a hardware security
+/// module, signs this digest as is.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::hashing::sha512_half;
+/// use xrpl::core::keypairs::signing_digest;
+///
+/// let message: &[u8] = "test message".as_bytes();
+///
+/// assert_eq!(
+///     signing_digest(message, "ED01FA53FA5A7E77798F882ECE20B1ABC00BB358A9E55A202D0D0676BD0CE37A63"),
+///     message,
+/// );
+/// assert_eq!(
+///     signing_digest(message, "030D58EB48B4420B1F7B9DF55087E0E29FEF0E8468F9A6825B01CA2C361042D435"),
+///     sha512_half(message),
+/// );
+/// ```
+pub fn signing_digest(message: &[u8], public_key: &str) -> Vec<u8> {
+    if public_key
+        .get(..ED25519_PREFIX.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(ED25519_PREFIX))
+    {
+        message.to_vec()
+    } else {
+        sha512_half(message).to_vec()
+    }
+}
+
+/// A private key parsed for signing. [`sign`] parses the key, and
+/// for ED25519 derives its public key, on every call; this does it
+/// once for signing many messages.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::keypairs::{sign, SigningKey};
+///
+/// let message: &[u8] = "test message".as_bytes();
+/// let private_key: &str = "EDB4C4E046826BD26190D09715FC31F4E\
+///                          6A728204EADD112905B08B14B7F15C4F3";
+/// let signing_key = SigningKey::from_private_key(private_key).unwrap();
+///
+/// assert_eq!(
+///     signing_key.sign(message).unwrap(),
+///     sign(message, private_key).unwrap(),
+/// );
+/// ```
+pub struct SigningKey(SigningKeyInner);
+
+enum SigningKeyInner {
+    Ed25519(ed25519_dalek::SigningKey),
+    Secp256k1 {
+        secp: secp256k1::Secp256k1<secp256k1::SignOnly>,
+        key: secp256k1::SecretKey,
+    },
+}
+
+impl SigningKey {
+    /// Parse a hex encoded private key.
+    pub fn from_private_key(private_key: &str) -> XRPLCoreResult<Self> {
+        Self::new(&PrivateKey::from_hex(private_key)?)
+    }
+
+    /// Parse a private key.
+    pub fn new(private_key: &PrivateKey) -> XRPLCoreResult<Self> {
+        match private_key.algorithm() {
+            CryptoAlgorithm::ED25519 => Ok(Self(SigningKeyInner::Ed25519(Ed25519::_signing_key(
+                private_key,
+            )))),
+            CryptoAlgorithm::SECP256K1 => Ok(Self(SigningKeyInner::Secp256k1 {
+                secp: secp256k1::Secp256k1::signing_only(),
+                key: Secp256k1::_secret_key(private_key)?,
+            })),
+        }
+    }
+
+    /// Sign the digest [`signing_digest`] returns for a message,
+    /// returning the raw signature. The digest of a SECP256K1 key
+    /// must be 32 bytes long.
+    pub fn sign_digest(&self, digest: &[u8]) -> XRPLCoreResult<Vec<u8>> {
+        match &self.0 {
+            SigningKeyInner::Ed25519(signing_key) => {
+                use ed25519_dalek::Signer;
+
+                Ok(signing_key.sign(digest).to_bytes().to_vec())
+            }
+            SigningKeyInner::Secp256k1 { secp, key } => {
+                let digest: [u8; SHA512_HASH_LENGTH] =
+                    digest
+                        .try_into()
+                        .map_err(|_| XRPLKeypairsException::InvalidDigestLength {
+                            expected: SHA512_HASH_LENGTH,
+                            found: digest.len(),
+                        })?;
+                let message = secp256k1::Message::from_digest(digest);
+
+                Ok(secp.sign_ecdsa(&message, key).serialize_der().to_vec())
+            }
+        }
+    }
+
+    /// Sign a message, like [`sign`].
+    pub fn sign(&self, message: &[u8]) -> XRPLCoreResult<String> {
+        match &self.0 {
+            SigningKeyInner::Ed25519(signing_key) => {
+                use ed25519_dalek::Signer;
+
+                Ok(hex::encode_upper(signing_key.sign(message).to_bytes()))
+            }
+            SigningKeyInner::Secp256k1 { secp, key } => {
+                let message = Secp256k1::_get_message(message);
+
+                Ok(hex::encode_upper(
+                    secp.sign_ecdsa(&message, key).serialize_der(),
+                ))
+            }
+        }
+    }
+}
+
+impl core::fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let algorithm = match self.0 {
+            SigningKeyInner::Ed25519(_) => CryptoAlgorithm::ED25519,
+            SigningKeyInner::Secp256k1 { .. } => CryptoAlgorithm::SECP256K1,
+        };
+
+        f.debug_tuple("SigningKey").field(&algorithm).finish()
+    }
 }
 
 /// Verifies the signature on a given message.
@@ -270,12 +407,12 @@ pub trait CryptoImplementation {
         &self,
         decoded_seed: &[u8],
         is_validator: bool,
-    ) -> XRPLCoreResult<(String, String)>;
+    ) -> XRPLCoreResult<(String, PrivateKey)>;
 
     /// Signs a message using a given private key.
     /// * `message` - Text about foo.
     /// * `private_key` - Text about bar.
-    fn sign(&self, message: &[u8], private_key: &str) -> XRPLCoreResult<Vec<u8>>;
+    fn sign(&self, message: &[u8], private_key: &PrivateKey) -> XRPLCoreResult<Vec<u8>>;
 
     /// Verifies the signature on a given message.
     fn is_valid_message(&self, message: &[u8], signature: &str, public_key: &str) -> bool;
@@ -339,6 +476,34 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_sign_digest() {
+        let message = TEST_MESSAGE.as_bytes();
+
+        for (private_key, public_key, signature) in [
+            (PRIVATE_ED25519, PUBLIC_ED25519, SIGNATURE_ED25519.to_vec()),
+            (
+                PRIVATE_SECP256K1,
+                PUBLIC_SECP256K1,
+                SIGNATURE_SECP256K1.to_vec(),
+            ),
+        ] {
+            let signing_key = SigningKey::from_private_key(private_key).unwrap();
+            let digest = signing_digest(message, public_key);
+            assert_eq!(signing_key.sign_digest(&digest), Ok(signature));
+        }
+
+        let signing_key = SigningKey::from_private_key(PRIVATE_SECP256K1).unwrap();
+        assert_eq!(
+            signing_key.sign_digest(message),
+            Err(XRPLKeypairsException::InvalidDigestLength {
+                expected: 32,
+                found: message.len()
+            }
+            .into())
+        );
+    }
+
     #[test]
     fn test_is_valid_message() {
         let message: &[u8] = TEST_MESSAGE.as_bytes();
@@ -0,0 +1,151 @@
+//! Private keys kept as bytes, wiped from memory when dropped.
+
+use crate::constants::CryptoAlgorithm;
+use crate::core::exceptions::XRPLCoreResult;
+use crate::core::keypairs::exceptions::XRPLKeypairsException;
+use crate::core::keypairs::utils::*;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+/// Length of the secret of a private key.
+pub const PRIVATE_KEY_SECRET_LENGTH: usize = 32;
+
+/// The prefix byte of ED25519 private keys.
+const ED25519_PREFIX_BYTE: u8 = 0xED;
+/// The prefix byte of SECP256K1 private keys.
+const SECP256K1_PREFIX_BYTE: u8 = 0x00;
+
+/// A private key, as its prefix byte followed by its secret. The
+/// bytes are wiped when the key is dropped, compared in constant
+/// time and never printed.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::keypairs::PrivateKey;
+/// use xrpl::constants::CryptoAlgorithm;
+///
+/// let hex_key: &str = "EDB4C4E046826BD26190D09715FC31F4E\
+///                      6A728204EADD112905B08B14B7F15C4F3";
+/// let private_key = PrivateKey::from_hex(hex_key).unwrap();
+///
+/// assert_eq!(private_key.algorithm(), CryptoAlgorithm::ED25519);
+/// assert_eq!(private_key.to_hex(), hex_key);
+/// assert_eq!(format!("{:?}", private_key), "PrivateKey(ED25519)");
+/// ```
+#[derive(Clone)]
+pub struct PrivateKey(Zeroizing<Vec<u8>>);
+
+impl PrivateKey {
+    pub(crate) fn new(
+        algorithm: CryptoAlgorithm,
+        secret: &[u8; PRIVATE_KEY_SECRET_LENGTH],
+    ) -> Self {
+        let mut bytes = Zeroizing::new(Vec::with_capacity(PRIVATE_KEY_SECRET_LENGTH + 1));
+        bytes.push(match algorithm {
+            CryptoAlgorithm::ED25519 => ED25519_PREFIX_BYTE,
+            CryptoAlgorithm::SECP256K1 => SECP256K1_PREFIX_BYTE,
+        });
+        bytes.extend_from_slice(secret);
+
+        Self(bytes)
+    }
+
+    /// Parse a hex encoded private key. SECP256K1 keys may omit
+    /// their `00` prefix.
+    pub fn from_hex(private_key: &str) -> XRPLCoreResult<Self> {
+        let (algorithm, hex_secret) = match private_key.strip_prefix(ED25519_PREFIX) {
+            Some(hex_secret) => (CryptoAlgorithm::ED25519, hex_secret),
+            None if private_key.len() == 2 * (PRIVATE_KEY_SECRET_LENGTH + 1) => (
+                CryptoAlgorithm::SECP256K1,
+                private_key
+                    .strip_prefix("00")
+                    .ok_or(XRPLKeypairsException::InvalidSecret)?,
+            ),
+            None => (CryptoAlgorithm::SECP256K1, private_key),
+        };
+        let secret = Zeroizing::new(hex::decode(hex_secret)?);
+        let secret: &[u8; PRIVATE_KEY_SECRET_LENGTH] = secret
+            .as_slice()
+            .try_into()
+            .map_err(|_| XRPLKeypairsException::InvalidSecret)?;
+
+        Ok(Self::new(algorithm, secret))
+    }
+
+    /// Hex encode the private key, e.g. to store it. The caller is
+    /// responsible for wiping the result.
+    pub fn to_hex(&self) -> String {
+        hex::encode_upper(self.0.as_slice())
+    }
+
+    /// The algorithm the private key is used with.
+    pub fn algorithm(&self) -> CryptoAlgorithm {
+        match self.0[0] {
+            ED25519_PREFIX_BYTE => CryptoAlgorithm::ED25519,
+            _ => CryptoAlgorithm::SECP256K1,
+        }
+    }
+
+    /// The secret, without the prefix byte.
+    pub(crate) fn secret(&self) -> &[u8; PRIVATE_KEY_SECRET_LENGTH] {
+        self.0[1..].try_into().expect("Invalid slice length")
+    }
+}
+
+impl PartialEq for PrivateKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_slice().ct_eq(other.0.as_slice()).into()
+    }
+}
+
+impl Eq for PrivateKey {}
+
+impl core::fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("PrivateKey")
+            .field(&self.algorithm())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::keypairs::test_cases::*;
+    use alloc::format;
+
+    #[test]
+    fn test_from_hex() {
+        let ed25519 = PrivateKey::from_hex(PRIVATE_ED25519).unwrap();
+        let secp256k1 = PrivateKey::from_hex(PRIVATE_SECP256K1).unwrap();
+
+        assert_eq!(ed25519.algorithm(), CryptoAlgorithm::ED25519);
+        assert_eq!(ed25519.to_hex(), PRIVATE_ED25519);
+        assert_eq!(secp256k1.algorithm(), CryptoAlgorithm::SECP256K1);
+        assert_eq!(secp256k1.to_hex(), PRIVATE_SECP256K1);
+        assert_eq!(
+            PrivateKey::from_hex(&PRIVATE_SECP256K1[2..]).unwrap(),
+            secp256k1
+        );
+        assert_ne!(ed25519, secp256k1);
+
+        assert!(PrivateKey::from_hex("abc123").is_err());
+        assert!(PrivateKey::from_hex(&PRIVATE_ED25519[..64]).is_err());
+        assert!(PrivateKey::from_hex(&PRIVATE_SECP256K1.replacen("00", "01", 1)).is_err());
+    }
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let private_key = PrivateKey::from_hex(PRIVATE_SECP256K1).unwrap();
+        let debug = format!("{private_key:?}");
+
+        assert_eq!(debug, "PrivateKey(SECP256K1)");
+        assert!(!debug.contains(&PRIVATE_SECP256K1[2..]));
+    }
+}
@@ -0,0 +1,390 @@
+//! Ledger header parsing and the hashes the ledger is built from.
+//!
+//! These let a client check a `ledger` or `tx` response locally
+//! instead of trusting the server it came from.
+//!
+//! See Ledger Header:
+//! `<https://xrpl.org/ledger-header.html>`
+
+use alloc::{string::String, vec::Vec};
+use serde_json::Value;
+
+use super::{
+    binarycodec::{
+        encode_ledger_object, exceptions::XRPLBinaryCodecException,
+        types::exceptions::XRPLSerializeMapException, BinaryParser, BinarySerializer, Parser,
+        Serialization,
+    },
+    exceptions::XRPLCoreResult,
+    hashing::{sha512_half, SHA512_HALF_LENGTH},
+};
+
+pub use super::hashing::{
+    LEAF_NODE_HASH_PREFIX, LEDGER_HASH_PREFIX, TRANSACTION_ID_HASH_PREFIX,
+    TRANSACTION_NODE_HASH_PREFIX,
+};
+
+const HASH_LENGTH: usize = SHA512_HALF_LENGTH;
+
+/// The header of a closed ledger in its canonical binary layout.
+/// Hashes are uppercase hex strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LedgerHeader {
+    pub ledger_index: u32,
+    /// Total drops of XRP in existence.
+    pub total_coins: u64,
+    pub parent_hash: String,
+    /// Root of the transaction tree.
+    pub transaction_hash: String,
+    /// Root of the state tree.
+    pub account_hash: String,
+    /// Close time of the previous ledger, in seconds since the
+    /// Ripple Epoch.
+    pub parent_close_time: u32,
+    /// Close time of this ledger, in seconds since the Ripple Epoch.
+    pub close_time: u32,
+    pub close_time_resolution: u8,
+    pub close_flags: u8,
+}
+
+impl LedgerHeader {
+    /// Read a ledger header from its canonical binary layout.
+    ///
+    /// # Examples
+    ///
+    /// ## Basic usage
+    ///
+    /// ```
+    /// use xrpl::core::binarycodec::BinaryParser;
+    /// use xrpl::core::ledger::LedgerHeader;
+    ///
+    /// let mut parser = BinaryParser::from([0u8; 118].as_ref());
+    /// let header = LedgerHeader::from_parser(&mut parser).unwrap();
+    ///
+    /// assert_eq!(header.ledger_index, 0);
+    /// ```
+    pub fn from_parser(parser: &mut BinaryParser) -> XRPLCoreResult<Self> {
+        let ledger_index = parser.read_uint32()?;
+        let total_coins = u64::from_be_bytes(
+            parser
+                .read(8)?
+                .try_into()
+                .or(Err(XRPLBinaryCodecException::InvalidReadFromBytesValue))?,
+        );
+        let parent_hash = hex::encode_upper(parser.read(HASH_LENGTH)?);
+        let transaction_hash = hex::encode_upper(parser.read(HASH_LENGTH)?);
+        let account_hash = hex::encode_upper(parser.read(HASH_LENGTH)?);
+        let parent_close_time = parser.read_uint32()?;
+        let close_time = parser.read_uint32()?;
+        let close_time_resolution = parser.read_uint8()?;
+        let close_flags = parser.read_uint8()?;
+
+        Ok(Self {
+            ledger_index,
+            total_coins,
+            parent_hash,
+            transaction_hash,
+            account_hash,
+            parent_close_time,
+            close_time,
+            close_time_resolution,
+            close_flags,
+        })
+    }
+
+    /// Encode the header in its canonical binary layout.
+    pub fn to_bytes(&self) -> XRPLCoreResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        buffer.extend(self.ledger_index.to_be_bytes());
+        buffer.extend(self.total_coins.to_be_bytes());
+        for hash in [
+            &self.parent_hash,
+            &self.transaction_hash,
+            &self.account_hash,
+        ] {
+            buffer.extend(decode_hash(hash)?);
+        }
+        buffer.extend(self.parent_close_time.to_be_bytes());
+        buffer.extend(self.close_time.to_be_bytes());
+        buffer.push(self.close_time_resolution);
+        buffer.push(self.close_flags);
+
+        Ok(buffer)
+    }
+}
+
+/// Compute the hash of a ledger from its header.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::ledger::{hash_ledger_header, LedgerHeader};
+///
+/// let header = LedgerHeader {
+///     ledger_index: 15202439,
+///     total_coins: 99998831688050493,
+///     parent_hash: "12724A65B030C15A1573AA28B1BBB5DF3DA4589AA3623675A31CAE69B23B1C4E".into(),
+///     transaction_hash: "325EACC5271322539EEEC2D6A5292471EF1B3E72AE7180533EFC3B8F0AD435C8".into(),
+///     account_hash: "D9ABF622DA26EEEE48203085D4BC23B0F77DC6F8724AC33D975DA3CA492D2E44".into(),
+///     parent_close_time: 492656460,
+///     close_time: 492656470,
+///     close_time_resolution: 10,
+///     close_flags: 0,
+/// };
+///
+/// assert_eq!(
+///     hash_ledger_header(&header).unwrap(),
+///     "F4D865D83EB88C1A1911B9E90641919A1314F36E1B099F8E95FE3B7C77BE3349",
+/// );
+/// ```
+pub fn hash_ledger_header(header: &LedgerHeader) -> XRPLCoreResult<String> {
+    let mut buffer = LEDGER_HASH_PREFIX.to_be_bytes().to_vec();
+    buffer.extend(header.to_bytes()?);
+
+    Ok(hex::encode_upper(sha512_half(&buffer)))
+}
+
+/// Compute the hash (transaction ID) of a signed transaction
+/// from its hex `tx_blob`.
+pub fn hash_signed_tx(tx_blob: &str) -> XRPLCoreResult<String> {
+    let mut buffer = TRANSACTION_ID_HASH_PREFIX.to_be_bytes().to_vec();
+    buffer.extend(hex::decode(tx_blob)?);
+
+    Ok(hex::encode_upper(sha512_half(&buffer)))
+}
+
+/// Compute the hash of a transaction and its metadata as a leaf
+/// of a ledger's transaction tree. Both blobs are hex encoded.
+pub fn hash_tx_blob_with_meta(tx_blob: &str, meta_blob: &str) -> XRPLCoreResult<String> {
+    let tx_id = decode_hash(&hash_signed_tx(tx_blob)?)?;
+    let mut buffer = BinarySerializer::new();
+    buffer.extend(TRANSACTION_NODE_HASH_PREFIX.to_be_bytes());
+    buffer.write_length_encoded(&hex::decode(tx_blob)?, true);
+    buffer.write_length_encoded(&hex::decode(meta_blob)?, true);
+    buffer.extend(tx_id);
+
+    Ok(hex::encode_upper(sha512_half(&buffer)))
+}
+
+/// Compute the hash of a ledger entry as a leaf of a ledger's state
+/// tree, from its JSON with its `index`, e.g. the `node` of a
+/// `ledger_entry` response.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::ledger::hash_ledger_object;
+/// use serde_json::json;
+///
+/// let account_root = json!({
+///     "Account": "rBKPS4oLSaV2KVVuHH8EpQqMGgGefGFQs7",
+///     "Balance": "370000000",
+///     "Flags": 0,
+///     "LedgerEntryType": "AccountRoot",
+///     "OwnerCount": 0,
+///     "PreviousTxnID": "8D7F42ED0621FBCFAE55CC6F2A9403A2AFB205708CCBA3109BB61DB8DDA261B4",
+///     "PreviousTxnLgrSeq": 8901,
+///     "Sequence": 1,
+///     "index": "02CE52E3E46AD340B1C7900F86AFB959AE0C246916E3463905EDD61DE26FFFDD"
+/// });
+///
+/// assert_eq!(
+///     hash_ledger_object(account_root).unwrap(),
+///     "755ACEE97CA43148005F512F1F1DD1C9000D16830E3CA1127CB02D7205C49EDB",
+/// );
+/// ```
+pub fn hash_ledger_object(ledger_object: Value) -> XRPLCoreResult<String> {
+    let index = match ledger_object.get("index").and_then(Value::as_str) {
+        Some(index) => decode_hash(index)?,
+        None => return Err(XRPLSerializeMapException::MissingField("index".into()).into()),
+    };
+    let mut buffer = LEAF_NODE_HASH_PREFIX.to_be_bytes().to_vec();
+    buffer.extend(hex::decode(encode_ledger_object(ledger_object)?)?);
+    buffer.extend(index);
+
+    Ok(hex::encode_upper(sha512_half(&buffer)))
+}
+
+fn decode_hash(hash: &str) -> XRPLCoreResult<Vec<u8>> {
+    let bytes = hex::decode(hash)?;
+    if bytes.len() != HASH_LENGTH {
+        return Err(XRPLBinaryCodecException::InvalidHashLength {
+            expected: HASH_LENGTH,
+            found: bytes.len(),
+        }
+        .into());
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::binarycodec::{decode, encode};
+
+    /// Mainnet ledger 15202439.
+    fn mainnet_header() -> LedgerHeader {
+        LedgerHeader {
+            ledger_index: 15202439,
+            total_coins: 99998831688050493,
+            parent_hash: "12724A65B030C15A1573AA28B1BBB5DF3DA4589AA3623675A31CAE69B23B1C4E".into(),
+            transaction_hash: "325EACC5271322539EEEC2D6A5292471EF1B3E72AE7180533EFC3B8F0AD435C8"
+                .into(),
+            account_hash: "D9ABF622DA26EEEE48203085D4BC23B0F77DC6F8724AC33D975DA3CA492D2E44".into(),
+            parent_close_time: 492656460,
+            close_time: 492656470,
+            close_time_resolution: 10,
+            close_flags: 0,
+        }
+    }
+
+    #[test]
+    fn test_parse_and_hash_ledger_header() {
+        let header = mainnet_header();
+        let bytes = header.to_bytes().unwrap();
+        assert_eq!(bytes.len(), 118);
+
+        let mut parser = BinaryParser::from(bytes);
+        let parsed = LedgerHeader::from_parser(&mut parser).unwrap();
+        assert!(parser.is_end(None));
+        assert_eq!(parsed, header);
+        assert_eq!(
+            hash_ledger_header(&parsed).unwrap(),
+            "F4D865D83EB88C1A1911B9E90641919A1314F36E1B099F8E95FE3B7C77BE3349"
+        );
+    }
+
+    #[test]
+    fn test_truncated_ledger_header() {
+        let bytes = mainnet_header().to_bytes().unwrap();
+        let mut parser = BinaryParser::from(&bytes[..117]);
+
+        assert!(LedgerHeader::from_parser(&mut parser).is_err());
+    }
+
+    #[test]
+    fn test_invalid_hash_length() {
+        let mut header = mainnet_header();
+        header.account_hash = "D9ABF622".into();
+
+        assert!(hash_ledger_header(&header).is_err());
+    }
+
+    #[test]
+    fn test_hash_signed_tx() {
+        let tx_json = serde_json::json!({
+            "Account": "rEbY5Tr5B6AjyjuVRhajpnvCWLGkYk5z6",
+            "Domain": "6578616d706c652e636f6d",
+            "Fee": "10",
+            "Flags": 0,
+            "LastLedgerSequence": 596447,
+            "Sequence": 596427,
+            "SigningPubKey": "EDAF73A0E6745EA9C17A2F4EB7043134A055213116CFF6F7888BBFF557B002874F",
+            "TransactionType": "AccountSet",
+            "TxnSignature": "8666A7E6AF0D6A4B4F19F25D315FA1C31D132FB2E974686C415D5499D43710384FF851C75CCC4E57972DE5C5354289F574B2F604B6AF15E2DADA6BB9F1330A07"
+        });
+        let tx_blob = encode(&tx_json).unwrap();
+
+        assert_eq!(
+            hash_signed_tx(&tx_blob).unwrap(),
+            "5B765D6C6058CF54F5DBF6230A7F51E23295004FCC043660A77D73AA8537737B"
+        );
+    }
+
+    #[test]
+    fn test_hash_tx_blob_with_meta() {
+        let tx_blob = "120003220000000024000919CB201B000919DF68400000000000000A7321EDAF73A0E6745EA9C17A2F4EB7043134A055213116CFF6F7888BBFF557B002874F74408666A7E6AF0D6A4B4F19F25D315FA1C31D132FB2E974686C415D5499D43710384FF851C75CCC4E57972DE5C5354289F574B2F604B6AF15E2DADA6BB9F1330A07770B6578616D706C652E636F6D811402C2A9642B35EFD9EA92DEC22C95254CB06206A2";
+        // TransactionIndex 0, empty AffectedNodes, tesSUCCESS.
+        let meta_blob = "201C00000000F8F1031000";
+
+        assert_eq!(
+            hash_tx_blob_with_meta(tx_blob, meta_blob).unwrap(),
+            "81205D55702C057012127AA88E7BE6C107DFDA3B5006DDE70B52526A42F3C831"
+        );
+    }
+
+    /// Ledger entries from the binary codec fixtures, with the index
+    /// the ledger stores them at.
+    fn ledger_objects() -> [(Value, &'static str, &'static str); 2] {
+        [
+            (
+                serde_json::json!({
+                    "Account": "rBKPS4oLSaV2KVVuHH8EpQqMGgGefGFQs7",
+                    "Balance": "370000000",
+                    "Flags": 0,
+                    "LedgerEntryType": "AccountRoot",
+                    "OwnerCount": 0,
+                    "PreviousTxnID": "8D7F42ED0621FBCFAE55CC6F2A9403A2AFB205708CCBA3109BB61DB8DDA261B4",
+                    "PreviousTxnLgrSeq": 8901,
+                    "Sequence": 1,
+                    "index": "02CE52E3E46AD340B1C7900F86AFB959AE0C246916E3463905EDD61DE26FFFDD"
+                }),
+                "1100612200000000240000000125000022C52D00000000558D7F42ED0621FBCFAE55CC6F2A9403A2AFB205708CCBA3109BB61DB8DDA261B46240000000160DC0808114712B799C79D1EEE3094B59EF9920C7FEB3CE4499",
+                "755ACEE97CA43148005F512F1F1DD1C9000D16830E3CA1127CB02D7205C49EDB",
+            ),
+            (
+                serde_json::json!({
+                    "Account": "rwpRq4gQrb58N7PRJwYEQaoSui6Xd3FC7j",
+                    "BookDirectory": "2FB4904ACFB96228FC002335B1B5A4C5584D9D727BBE82145003BAF82D03A000",
+                    "BookNode": "0000000000000000",
+                    "Flags": 0,
+                    "LedgerEntryType": "Offer",
+                    "OwnerNode": "0000000000000000",
+                    "PreviousTxnID": "15955F0DCBF3237CE8F5ACAB92C81B4368857AF2E9BD2BC3D0C1D9CEA26F45BA",
+                    "PreviousTxnLgrSeq": 17826,
+                    "Sequence": 8,
+                    "TakerGets": "3000000",
+                    "TakerPays": {
+                        "currency": "USD",
+                        "issuer": "rhxbkK9jGqPVLZSWPvCEmmf15xHBfJfCEy",
+                        "value": "31.5"
+                    },
+                    "index": "5B7F148A8DDB4EB7386C9E75C4C1ED918DEDE5C52D5BA51B694D7271EF8BDB46"
+                }),
+                "11006F2200000000240000000825000045A23300000000000000003400000000000000005515955F0DCBF3237CE8F5ACAB92C81B4368857AF2E9BD2BC3D0C1D9CEA26F45BA50102FB4904ACFB96228FC002335B1B5A4C5584D9D727BBE82145003BAF82D03A00064D4CB30E8870AE00000000000000000000000000055534400000000002B6C42A95B3F7EE1971E4A10098E8F1B5F66AA086540000000002DC6C0811462FE474693228F7F9ED1C5EFADB3B6555FBEAFBE",
+                "214EBE170761AE367146D53E029B771EDAC132E75780105F0518557500025737",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_encode_ledger_object() {
+        for (ledger_object, blob, _) in ledger_objects() {
+            assert_eq!(encode_ledger_object(ledger_object.clone()).unwrap(), blob);
+
+            let mut decoded = decode(blob).unwrap();
+            decoded["index"] = ledger_object["index"].clone();
+            assert_eq!(decoded, ledger_object);
+        }
+
+        // As `book_offers` returns the offer.
+        let [_, (mut offer, blob, _)] = ledger_objects();
+        offer["owner_funds"] = "4000000".into();
+        offer["quality"] = "0.0000105".into();
+        offer["taker_gets_funded"] = "3000000".into();
+        assert_eq!(encode_ledger_object(offer).unwrap(), blob);
+
+        assert!(encode_ledger_object(Value::Null).is_err());
+    }
+
+    #[test]
+    fn test_hash_ledger_object() {
+        for (ledger_object, _, hash) in ledger_objects() {
+            assert_eq!(hash_ledger_object(ledger_object).unwrap(), hash);
+        }
+
+        let [(mut account_root, _, _), _] = ledger_objects();
+        account_root["index"] = "02CE52E3".into();
+        assert!(hash_ledger_object(account_root.clone()).is_err());
+        account_root.as_object_mut().unwrap().remove("index");
+        assert_eq!(
+            hash_ledger_object(account_root).unwrap_err(),
+            XRPLSerializeMapException::MissingField("index".into()).into()
+        );
+    }
+}
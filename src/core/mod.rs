@@ -3,7 +3,9 @@
 pub mod addresscodec;
 pub mod binarycodec;
 pub mod exceptions;
+pub mod hashing;
 pub mod keypairs;
+pub mod ledger;
 
 pub use self::binarycodec::binary_wrappers::BinaryParser;
 pub use self::binarycodec::binary_wrappers::BinarySerializer;
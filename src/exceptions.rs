@@ -0,0 +1,303 @@
+//! The error of the crate, wrapping the errors of its modules.
+
+use thiserror_no_std::Error;
+
+#[cfg(any(feature = "json-rpc", feature = "websocket"))]
+use crate::asynch::clients::exceptions::XRPLClientException;
+#[cfg(feature = "core")]
+use crate::core::{
+    addresscodec::exceptions::XRPLAddressCodecException,
+    binarycodec::exceptions::XRPLBinaryCodecException, exceptions::XRPLCoreException,
+    keypairs::exceptions::XRPLKeypairsException,
+};
+#[cfg(feature = "models")]
+use crate::models::{
+    results::{error::XRPLErrorResponse, exceptions::XRPLResultException},
+    transactions::exceptions::XRPLTransactionFieldException,
+    XRPLModelException,
+};
+#[cfg(feature = "utils")]
+use crate::utils::exceptions::XRPLUtilsException;
+#[cfg(feature = "wallet")]
+use crate::wallet::exceptions::XRPLWalletException;
+use crate::XRPLSerdeJsonError;
+#[cfg(feature = "helpers")]
+use crate::{
+    asynch::{
+        nft::exceptions::XRPLNftException,
+        transaction::exceptions::{
+            XRPLSignTransactionException, XRPLSubmitAndWaitException,
+            XRPLTransactionHelperException,
+        },
+        trustline::exceptions::XRPLTrustlineException,
+        wallet::exceptions::XRPLFaucetException,
+    },
+    transaction::exceptions::{
+        XRPLMultisignException, XRPLSigningPayloadException, XRPLVerifyBlobException,
+    },
+};
+
+/// Any error of this crate. The helpers return it, and the errors of
+/// each module convert into it, so one type covers them all.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::wallet::Wallet;
+/// use xrpl::XRPLError;
+///
+/// fn wallet(seed: &str) -> Result<Wallet, XRPLError> {
+///     let wallet = Wallet::new(seed, 0)?;
+///     // The errors of the modules convert with `?` too.
+///     wallet.get_xaddress(None, false)?;
+///
+///     Ok(wallet)
+/// }
+///
+/// let error = wallet("not a seed").unwrap_err();
+/// assert!(error.is_crypto_error());
+/// ```
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum XRPLError {
+    #[cfg(feature = "wallet")]
+    #[error("XRPL Wallet error: {0}")]
+    XRPLWalletError(#[from] XRPLWalletException),
+    #[cfg(feature = "helpers")]
+    #[error("XRPL Faucet error: {0}")]
+    XRPLFaucetError(#[from] XRPLFaucetException),
+    #[cfg(feature = "helpers")]
+    #[error("XRPL NFT error: {0}")]
+    XRPLNftError(#[from] XRPLNftException),
+    #[cfg(feature = "helpers")]
+    #[error("XRPL Trustline error: {0}")]
+    XRPLTrustlineError(#[from] XRPLTrustlineException),
+    #[cfg(feature = "helpers")]
+    #[error("XRPL Transaction Helper error: {0}")]
+    XRPLTransactionHelperError(#[from] XRPLTransactionHelperException),
+    #[cfg(feature = "models")]
+    #[error("XRPL Model error: {0}")]
+    XRPLModelError(#[from] XRPLModelException),
+    #[cfg(feature = "core")]
+    #[error("XRPL Core error: {0}")]
+    XRPLCoreError(#[from] XRPLCoreException),
+    #[cfg(feature = "models")]
+    #[error("XRPL Transaction Field error: {0}")]
+    XRPLTransactionFieldError(#[from] XRPLTransactionFieldException),
+    #[cfg(feature = "utils")]
+    #[error("XRPL Utils error: {0}")]
+    XRPLUtilsError(#[from] XRPLUtilsException),
+    #[cfg(feature = "helpers")]
+    #[error("XRPL MultiSign error: {0}")]
+    XRPLMultiSignError(#[from] XRPLMultisignException),
+    #[cfg(feature = "helpers")]
+    #[error("XRPL Verify Blob error: {0}")]
+    XRPLVerifyBlobError(#[from] XRPLVerifyBlobException),
+    #[cfg(feature = "helpers")]
+    #[error("XRPL Signing Payload error: {0}")]
+    XRPLSigningPayloadError(#[from] XRPLSigningPayloadException),
+    #[cfg(any(feature = "json-rpc", feature = "websocket"))]
+    #[error("XRPL Client error: {0}")]
+    XRPLClientError(#[from] XRPLClientException),
+    #[error("serde_json error: {0}")]
+    XRPLSerdeJsonError(#[from] XRPLSerdeJsonError),
+    #[error("From hex error: {0}")]
+    FromHexError(#[from] hex::FromHexError),
+    #[error("Account {0} does not exist in the ledger")]
+    AccountNotFound(alloc::string::String),
+    #[error("Ledger {ledger_index} did not close within {timeout:?}")]
+    LedgerCloseTimeout {
+        ledger_index: u32,
+        timeout: core::time::Duration,
+    },
+    #[error("The open ledger fee did not drop below {threshold} drops within {timeout:?}")]
+    FeeWaitTimeout {
+        threshold: u64,
+        timeout: core::time::Duration,
+    },
+}
+
+#[cfg(feature = "std")]
+impl alloc::error::Error for XRPLError {}
+
+impl XRPLError {
+    /// The error the server answered with, if that is what failed.
+    #[cfg(feature = "models")]
+    pub fn error_response(&self) -> Option<&XRPLErrorResponse> {
+        match self {
+            XRPLError::XRPLModelError(XRPLModelException::XRPLResultError(error)) => {
+                error.error_response()
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether a model, e.g. a transaction, has invalid or missing
+    /// fields.
+    pub fn is_validation_error(&self) -> bool {
+        match self {
+            #[cfg(feature = "models")]
+            XRPLError::XRPLModelError(error) => !matches!(
+                error,
+                XRPLModelException::FromHexError(_)
+                    | XRPLModelException::ParseIntError(_)
+                    | XRPLModelException::ParseFloatError(_)
+                    | XRPLModelException::SerdeJsonError(_)
+                    | XRPLModelException::BigDecimalError(_)
+                    | XRPLModelException::XRPLUtilsError(_)
+                    | XRPLModelException::XRPLResultError(_)
+            ),
+            #[cfg(feature = "models")]
+            XRPLError::XRPLTransactionFieldError(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the server could not be reached or did not answer in
+    /// time.
+    pub fn is_network_error(&self) -> bool {
+        match self {
+            #[cfg(any(feature = "json-rpc", feature = "websocket"))]
+            XRPLError::XRPLClientError(_) => true,
+            XRPLError::LedgerCloseTimeout { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Whether a seed, key, signature or keystore could not be used,
+    /// e.g. an invalid seed or a wrong keystore password.
+    pub fn is_crypto_error(&self) -> bool {
+        match self {
+            #[cfg(feature = "core")]
+            XRPLError::XRPLCoreError(error) => is_crypto_error(error),
+            #[cfg(feature = "wallet")]
+            XRPLError::XRPLWalletError(error) => match error {
+                XRPLWalletException::XRPLCoreError(error) => is_crypto_error(error),
+                error => matches!(
+                    error,
+                    XRPLWalletException::WrongKeystorePassword
+                        | XRPLWalletException::KeystoreTampered
+                        | XRPLWalletException::KeystoreEncryptionFailed
+                ),
+            },
+            _ => false,
+        }
+    }
+}
+
+#[cfg(feature = "core")]
+fn is_crypto_error(error: &XRPLCoreException) -> bool {
+    matches!(
+        error,
+        XRPLCoreException::XRPLKeypairsError(_)
+            | XRPLCoreException::XRPLAddressCodecError(
+                XRPLAddressCodecException::UnknownSeedEncoding
+                    | XRPLAddressCodecException::InvalidSeedPrefixEncodingType
+            )
+    )
+}
+
+impl From<serde_json::Error> for XRPLError {
+    fn from(error: serde_json::Error) -> Self {
+        XRPLError::XRPLSerdeJsonError(XRPLSerdeJsonError::SerdeJsonError(error))
+    }
+}
+
+#[cfg(feature = "core")]
+impl From<XRPLAddressCodecException> for XRPLError {
+    fn from(error: XRPLAddressCodecException) -> Self {
+        XRPLError::XRPLCoreError(error.into())
+    }
+}
+
+#[cfg(feature = "core")]
+impl From<XRPLBinaryCodecException> for XRPLError {
+    fn from(error: XRPLBinaryCodecException) -> Self {
+        XRPLError::XRPLCoreError(error.into())
+    }
+}
+
+#[cfg(feature = "core")]
+impl From<XRPLKeypairsException> for XRPLError {
+    fn from(error: XRPLKeypairsException) -> Self {
+        XRPLError::XRPLCoreError(error.into())
+    }
+}
+
+#[cfg(feature = "models")]
+impl From<XRPLResultException> for XRPLError {
+    fn from(error: XRPLResultException) -> Self {
+        XRPLError::XRPLModelError(error.into())
+    }
+}
+
+#[cfg(feature = "helpers")]
+impl From<XRPLSignTransactionException> for XRPLError {
+    fn from(error: XRPLSignTransactionException) -> Self {
+        XRPLError::XRPLTransactionHelperError(
+            XRPLTransactionHelperException::XRPLSignTransactionError(error),
+        )
+    }
+}
+
+#[cfg(feature = "helpers")]
+impl From<XRPLSubmitAndWaitException> for XRPLError {
+    fn from(error: XRPLSubmitAndWaitException) -> Self {
+        XRPLError::XRPLTransactionHelperError(
+            XRPLTransactionHelperException::XRPLSubmitAndWaitError(error),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "helpers"))]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::{asynch::exceptions::XRPLHelperResult, wallet::Wallet};
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_helper_exception_alias() {
+        use crate::asynch::exceptions::XRPLHelperException;
+
+        // Code written against the helper error keeps compiling.
+        fn old_helper() -> Result<(), XRPLHelperException> {
+            Err(XRPLHelperException::LedgerCloseTimeout {
+                ledger_index: 1,
+                timeout: core::time::Duration::from_secs(1),
+            })
+        }
+        let error: XRPLError = old_helper().unwrap_err();
+        assert!(error.is_network_error());
+        let result: XRPLHelperResult<()> = Err(error);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_conversions() {
+        let error = Wallet::new("not a seed", 0).unwrap_err();
+        assert!(error.to_string().starts_with("XRPL Core error: "));
+        assert!(error.is_crypto_error());
+        assert!(!error.is_network_error());
+
+        let error: XRPLError = XRPLKeypairsException::InvalidSignature.into();
+        assert!(error.is_crypto_error());
+        assert!(error
+            .to_string()
+            .ends_with(&XRPLKeypairsException::InvalidSignature.to_string()));
+
+        let error: XRPLError = XRPLModelException::MissingField("Account".into()).into();
+        assert!(error.is_validation_error());
+        assert!(!error.is_crypto_error());
+        assert_eq!(
+            error.to_string(),
+            "XRPL Model error: Expected field `Account` is missing"
+        );
+
+        let error: XRPLError = XRPLResultException::ExpectedResultOrError.into();
+        assert!(!error.is_validation_error());
+    }
+}
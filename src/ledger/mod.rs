@@ -1,3 +1,5 @@
+use core::time::Duration;
+
 use embassy_futures::block_on;
 
 use crate::{
@@ -8,12 +10,17 @@ use crate::{
             get_fee as async_get_fee,
             get_latest_open_ledger_sequence as async_get_latest_open_ledger_sequence,
             get_latest_validated_ledger_sequence as async_get_latest_validated_ledger_sequence,
+            get_network_id as async_get_network_id, get_reserves as async_get_reserves,
+            ledger_accept as async_ledger_accept, stream_ledger_data as async_stream_ledger_data,
+            wait_for_ledger_close as async_wait_for_ledger_close, LedgerDataStream,
         },
     },
-    models::XRPAmount,
+    models::{ledger::objects::LedgerEntry, requests::ledger_data::LedgerDataType, XRPAmount},
 };
 
-pub use crate::asynch::ledger::FeeType;
+pub use crate::asynch::ledger::{
+    FeeMonitor, FeePriority, FeeSnapshot, FeeStrategy, FeeType, MonitoredFee,
+};
 
 pub fn get_latest_validated_ledger_sequence<C>(client: &C) -> XRPLHelperResult<u32>
 where
@@ -29,6 +36,38 @@ where
     block_on(async_get_latest_open_ledger_sequence(client))
 }
 
+pub fn wait_for_ledger_close<C>(
+    client: &C,
+    ledger_index: u32,
+    timeout: Duration,
+) -> XRPLHelperResult<u32>
+where
+    C: XRPLClient,
+{
+    block_on(async_wait_for_ledger_close(client, ledger_index, timeout))
+}
+
+pub fn ledger_accept<C>(client: &C) -> XRPLHelperResult<u32>
+where
+    C: XRPLClient,
+{
+    block_on(async_ledger_accept(client))
+}
+
+pub fn get_network_id<C>(client: &C) -> XRPLHelperResult<Option<u32>>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_network_id(client))
+}
+
+pub fn get_reserves<'a, C>(client: &C) -> XRPLHelperResult<(XRPAmount<'a>, XRPAmount<'a>)>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_reserves(client))
+}
+
 pub fn get_fee<C>(
     client: &C,
     max_fee: Option<u32>,
@@ -39,3 +78,27 @@ where
 {
     block_on(async_get_fee(client, max_fee, fee_type))
 }
+
+/// Iterate over the whole state of the latest validated ledger, one
+/// ledger entry at a time, optionally only the entries of type
+/// `filter`. See [`crate::asynch::ledger::stream_ledger_data`].
+pub fn stream_ledger_data<C>(client: &C, filter: Option<LedgerDataType>) -> LedgerDataIter<'_, C>
+where
+    C: XRPLClient,
+{
+    LedgerDataIter(async_stream_ledger_data(client, filter))
+}
+
+/// The ledger entries of a ledger, see [`stream_ledger_data`].
+pub struct LedgerDataIter<'c, C>(LedgerDataStream<'c, C>);
+
+impl<C> Iterator for LedgerDataIter<'_, C>
+where
+    C: XRPLClient,
+{
+    type Item = XRPLHelperResult<LedgerEntry<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        block_on(self.0.next())
+    }
+}
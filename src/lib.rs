@@ -13,6 +13,32 @@
 //!
 //! TODO
 //!
+//! # Tracing
+//!
+//! With the `tracing` feature, the clients open a `request` span for
+//! each request, with its method and ID, and a `connect` span when
+//! connecting. Requests and responses are debug events, cut after
+//! 1024 bytes; failed requests are errors with the rippled error
+//! code. Autofilling records the fee, sequence and last ledger
+//! sequence it chose, and signing records the transaction hash and
+//! key type, never keys. Install a subscriber to see them:
+//!
+//! ```no_run
+//! use tracing_subscriber::filter::LevelFilter;
+//! use xrpl::asynch::clients::{AsyncJsonRpcClient, XRPLAsyncClient};
+//! use xrpl::models::requests::ledger_current::LedgerCurrent;
+//!
+//! tracing_subscriber::fmt()
+//!     .with_max_level(LevelFilter::DEBUG)
+//!     .init();
+//!
+//! # #[cfg(feature = "std")]
+//! # tokio::runtime::Runtime::new().unwrap().block_on(async {
+//! let client = AsyncJsonRpcClient::connect("https://s.altnet.rippletest.net:51234".parse().unwrap());
+//! client.request(LedgerCurrent::new(None).into()).await.unwrap();
+//! # });
+//! ```
+//!
 //! # The XRP Ledger
 //!
 //! For the user guide and further documentation, please read
@@ -39,13 +65,21 @@ pub mod clients;
 pub mod constants;
 #[cfg(feature = "core")]
 pub mod core;
+pub mod exceptions;
 #[cfg(feature = "helpers")]
 pub mod ledger;
 pub mod macros;
-#[cfg(any(feature = "models"))]
+#[cfg(feature = "models")]
 pub mod models;
 #[cfg(feature = "helpers")]
+pub mod nft;
+#[cfg(feature = "helpers")]
+pub mod orderbook;
+pub mod prelude;
+#[cfg(feature = "helpers")]
 pub mod transaction;
+#[cfg(feature = "helpers")]
+pub mod trustline;
 #[cfg(feature = "utils")]
 pub mod utils;
 #[cfg(feature = "wallet")]
@@ -53,8 +87,12 @@ pub mod wallet;
 
 pub extern crate serde_json;
 
-#[cfg(any(feature = "models"))]
+pub use exceptions::XRPLError;
+
+#[cfg(feature = "models")]
 mod _serde;
+#[cfg(feature = "tracing")]
+mod _tracing;
 
 #[cfg(all(
     feature = "helpers",
@@ -1,4 +1,5 @@
 use crate::models::{Model, XRPLModelException, XRPLModelResult};
+use crate::utils::IOUValue;
 use alloc::borrow::Cow;
 use bigdecimal::BigDecimal;
 use core::convert::TryInto;
@@ -6,13 +7,20 @@ use core::str::FromStr;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct IssuedCurrencyAmount<'a> {
     pub currency: Cow<'a, str>,
     pub issuer: Cow<'a, str>,
     pub value: Cow<'a, str>,
 }
 
-impl<'a> Model for IssuedCurrencyAmount<'a> {}
+impl<'a> Model for IssuedCurrencyAmount<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.iou_value()?;
+
+        Ok(())
+    }
+}
 
 impl<'a> IssuedCurrencyAmount<'a> {
     pub fn new(currency: Cow<'a, str>, issuer: Cow<'a, str>, value: Cow<'a, str>) -> Self {
@@ -22,6 +30,17 @@ impl<'a> IssuedCurrencyAmount<'a> {
             value,
         }
     }
+
+    /// The value, checked to be in the number domain of issued
+    /// currency amounts.
+    pub fn iou_value(&self) -> XRPLModelResult<IOUValue> {
+        Ok(IOUValue::from_str(&self.value)?)
+    }
+
+    /// Returns true if the value is below zero.
+    pub fn is_negative(&self) -> bool {
+        self.iou_value().is_ok_and(|value| value.is_negative())
+    }
 }
 
 impl<'a> TryInto<BigDecimal> for IssuedCurrencyAmount<'a> {
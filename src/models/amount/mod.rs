@@ -13,6 +13,7 @@ use strum_macros::Display;
 use super::{XRPLModelException, XRPLModelResult};
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, Display)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum Amount<'a> {
     IssuedCurrencyAmount(IssuedCurrencyAmount<'a>),
@@ -30,7 +31,14 @@ impl<'a> TryInto<BigDecimal> for Amount<'a> {
     }
 }
 
-impl<'a> Model for Amount<'a> {}
+impl<'a> Model for Amount<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        match self {
+            Amount::IssuedCurrencyAmount(amount) => amount.get_errors(),
+            Amount::XRPAmount(amount) => amount.get_errors(),
+        }
+    }
+}
 
 impl<'a> Default for Amount<'a> {
     fn default() -> Self {
@@ -49,6 +57,15 @@ impl<'a> Amount<'a> {
     pub fn is_issued_currency(&self) -> bool {
         !self.is_xrp()
     }
+
+    /// Returns true if the amount is below zero, e.g. a balance
+    /// change.
+    pub fn is_negative(&self) -> bool {
+        match self {
+            Amount::IssuedCurrencyAmount(amount) => amount.is_negative(),
+            Amount::XRPAmount(amount) => amount.is_negative(),
+        }
+    }
 }
 
 impl<'a> From<IssuedCurrencyAmount<'a>> for Amount<'a> {
@@ -1,6 +1,9 @@
 use crate::models::{Model, XRPLModelException, XRPLModelResult};
+use crate::utils::exceptions::{XRPLUtilsException, XRPRangeException};
+use crate::utils::{signed_drops_to_xrp, signed_xrp_to_drops, MAX_DROPS};
 use alloc::{
     borrow::Cow,
+    format,
     string::{String, ToString},
 };
 use bigdecimal::BigDecimal;
@@ -8,16 +11,97 @@ use core::str::FromStr;
 use core::{
     convert::{TryFrom, TryInto},
     fmt::Display,
+    ops::Neg,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 /// Represents an amount of XRP in Drops.
+///
+/// The amount may be negative, e.g. a balance change. Transactions
+/// reject negative amounts when they are validated.
 #[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct XRPAmount<'a>(pub Cow<'a, str>);
 
 impl<'a> Model for XRPAmount<'a> {}
 
+impl<'a> XRPAmount<'a> {
+    /// Create an amount from a decimal XRP value, e.g. `"1.5"` or
+    /// `"-1.5"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xrpl::models::XRPAmount;
+    ///
+    /// let amount = XRPAmount::from_xrp("1.5").unwrap();
+    /// assert_eq!(amount, XRPAmount::from("1500000"));
+    /// assert_eq!(amount.to_xrp().unwrap(), "1.5");
+    /// ```
+    pub fn from_xrp(xrp: &str) -> XRPLModelResult<Self> {
+        Ok(Self(signed_xrp_to_drops(xrp)?.into()))
+    }
+
+    /// Return the amount as a decimal XRP value.
+    pub fn to_xrp(&self) -> XRPLModelResult<String> {
+        Ok(signed_drops_to_xrp(&self.0)?)
+    }
+
+    /// Return the amount as a whole number of drops.
+    pub fn drops(&self) -> XRPLModelResult<i64> {
+        Ok(self.0.parse::<i64>()?)
+    }
+
+    /// Returns true if the amount is below zero.
+    pub fn is_negative(&self) -> bool {
+        BigDecimal::from_str(&self.0).is_ok_and(|value| value < 0)
+    }
+
+    /// Add two amounts, e.g. to total balance changes.
+    ///
+    /// # Errors
+    ///
+    /// If either amount is not a whole number of drops or the sum is
+    /// more than all XRP in existence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use xrpl::models::XRPAmount;
+    ///
+    /// let change = XRPAmount::from("-1000012").checked_add(&"12".into()).unwrap();
+    /// assert_eq!(change, XRPAmount::from("-1000000"));
+    /// ```
+    pub fn checked_add(&self, other: &XRPAmount<'_>) -> XRPLModelResult<XRPAmount<'static>> {
+        Self::from_sum(i128::from(self.drops()?) + i128::from(other.drops()?))
+    }
+
+    /// Subtract an amount, e.g. to compute a balance change.
+    ///
+    /// # Errors
+    ///
+    /// If either amount is not a whole number of drops or the
+    /// difference is more than all XRP in existence.
+    pub fn checked_sub(&self, other: &XRPAmount<'_>) -> XRPLModelResult<XRPAmount<'static>> {
+        Self::from_sum(i128::from(self.drops()?) - i128::from(other.drops()?))
+    }
+
+    fn from_sum(drops: i128) -> XRPLModelResult<XRPAmount<'static>> {
+        if drops.unsigned_abs() > u128::from(MAX_DROPS) {
+            return Err(
+                XRPLUtilsException::from(XRPRangeException::InvalidDropsAmountTooLarge {
+                    max: MAX_DROPS.to_string(),
+                    found: drops.to_string(),
+                })
+                .into(),
+            );
+        }
+
+        Ok(XRPAmount(drops.to_string().into()))
+    }
+}
+
 impl Default for XRPAmount<'_> {
     fn default() -> Self {
         Self("0".into())
@@ -77,6 +161,20 @@ impl<'a> From<u32> for XRPAmount<'a> {
     }
 }
 
+impl<'a> Neg for XRPAmount<'a> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        if let Some(magnitude) = self.0.strip_prefix('-') {
+            Self(String::from(magnitude).into())
+        } else if self.0.bytes().all(|byte| byte == b'0' || byte == b'.') {
+            self
+        } else {
+            Self(format!("-{}", self.0).into())
+        }
+    }
+}
+
 impl<'a> TryFrom<Value> for XRPAmount<'a> {
     type Error = XRPLModelException;
 
@@ -107,6 +205,22 @@ impl<'a> TryInto<u32> for XRPAmount<'a> {
     }
 }
 
+impl<'a> TryInto<u64> for XRPAmount<'a> {
+    type Error = XRPLModelException;
+
+    fn try_into(self) -> XRPLModelResult<u64, Self::Error> {
+        Ok(self.0.parse::<u64>()?)
+    }
+}
+
+impl<'a> TryInto<i64> for XRPAmount<'a> {
+    type Error = XRPLModelException;
+
+    fn try_into(self) -> XRPLModelResult<i64, Self::Error> {
+        self.drops()
+    }
+}
+
 impl<'a> TryInto<BigDecimal> for XRPAmount<'a> {
     type Error = XRPLModelException;
 
@@ -136,3 +250,53 @@ impl<'a> Ord for XRPAmount<'a> {
         self_decimal.cmp(&other_decimal)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::amount::Amount;
+
+    #[test]
+    fn test_negative_amounts() {
+        let change = XRPAmount::from("-1000012");
+        assert!(change.is_negative());
+        assert!(!XRPAmount::from("0").is_negative());
+        assert_eq!(change.drops().unwrap(), -1000012);
+        assert_eq!(change.to_xrp().unwrap(), "-1.000012");
+        assert_eq!(XRPAmount::from_xrp("-1.000012").unwrap(), change);
+        assert!(change < XRPAmount::from("0"));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let balance = XRPAmount::from("1000000");
+        let change = XRPAmount::from("25000000")
+            .checked_sub(&"26000012".into())
+            .unwrap();
+        assert_eq!(change, XRPAmount::from("-1000012"));
+        assert_eq!(
+            balance.checked_add(&change).unwrap(),
+            XRPAmount::from("-12")
+        );
+        assert_eq!(-change, XRPAmount::from("1000012"));
+        assert_eq!(-XRPAmount::from("12"), XRPAmount::from("-12"));
+        assert_eq!(-XRPAmount::from("0"), XRPAmount::from("0"));
+
+        let max = XRPAmount::from(alloc::format!("{MAX_DROPS}"));
+        assert!(max.checked_add(&"1".into()).is_err());
+        assert!((-max).checked_sub(&"1".into()).is_err());
+        assert!(balance.checked_add(&"1.5".into()).is_err());
+    }
+
+    #[test]
+    fn test_negative_deltas_serde() {
+        let change: XRPAmount = serde_json::from_str("\"-1000012\"").unwrap();
+        assert_eq!(change, XRPAmount::from("-1000012"));
+        assert_eq!(serde_json::to_string(&change).unwrap(), "\"-1000012\"");
+
+        let amount: Amount = serde_json::from_value(serde_json::json!("-12")).unwrap();
+        assert_eq!(amount, Amount::XRPAmount("-12".into()));
+        assert!(amount.is_negative());
+        assert_eq!(serde_json::to_value(&amount).unwrap(), "-12");
+    }
+}
@@ -4,7 +4,7 @@ pub mod xrp;
 use crate::models::Model;
 use alloc::borrow::Cow;
 pub use issued_currency::*;
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
 use strum_macros::Display;
 pub use xrp::*;
 
@@ -14,13 +14,42 @@ pub trait ToAmount<'a, A> {
     fn to_amount(&self, value: Cow<'a, str>) -> A;
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, Display)]
+/// A currency without an amount, as in an order book: `{"currency":
+/// "XRP"}` for XRP, the currency code and its issuer otherwise.
+///
+/// Deserializing fails if XRP has an issuer or another currency has
+/// none, rather than reading a currency without issuer as XRP.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Display)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum Currency<'a> {
     IssuedCurrency(IssuedCurrency<'a>),
     XRP(XRP<'a>),
 }
 
+impl<'a, 'de> Deserialize<'de> for Currency<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Currency<'a>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Fields<'a> {
+            currency: Cow<'a, str>,
+            issuer: Option<Cow<'a, str>>,
+        }
+
+        let Fields { currency, issuer } = Fields::deserialize(deserializer)?;
+        match (currency.as_ref(), issuer) {
+            ("XRP", None) => Ok(XRP { currency }.into()),
+            ("XRP", Some(_)) => Err(de::Error::custom("XRP has no issuer")),
+            (_, Some(issuer)) => Ok(IssuedCurrency::new(currency, issuer).into()),
+            (_, None) => Err(de::Error::custom(alloc::format!(
+                "missing field `issuer`: currency `{currency}` is not XRP"
+            ))),
+        }
+    }
+}
+
 impl<'a> Model for Currency<'a> {}
 
 impl<'a> Default for Currency<'a> {
@@ -64,3 +93,40 @@ impl<'a> From<&XRPAmount<'a>> for Currency<'a> {
         XRP::new().into()
     }
 }
+
+#[cfg(test)]
+mod test_serde {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let xrp: Currency = serde_json::from_str(r#"{"currency":"XRP"}"#).unwrap();
+        assert_eq!(xrp, XRP::new().into());
+
+        let issued_currency: Currency = serde_json::from_str(
+            r#"{"currency":"USD","issuer":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            issued_currency,
+            IssuedCurrency::new("USD".into(), "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into()).into()
+        );
+    }
+
+    #[test]
+    fn test_deserialize_errors() {
+        let error = serde_json::from_str::<Currency>(r#"{"currency":"USD"}"#).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "missing field `issuer`: currency `USD` is not XRP"
+        );
+
+        let error = serde_json::from_str::<Currency>(
+            r#"{"currency":"XRP","issuer":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"}"#,
+        )
+        .unwrap_err();
+        assert_eq!(error.to_string(), "XRP has no issuer");
+    }
+}
@@ -0,0 +1,379 @@
+//! Human-readable summaries of transactions and ledger objects, for
+//! command line tools and logs.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{Debug, Write};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use strum::IntoEnumIterator;
+
+use crate::{
+    core::{
+        binarycodec::{definitions::get_field_instance, encode},
+        ledger::hash_signed_tx,
+    },
+    utils::drops_to_xrp,
+};
+
+use super::{
+    ledger::objects::{
+        account_root::AccountRootFlag, nftoken_offer::NFTokenOfferFlag, offer::OfferFlag,
+        ripple_state::RippleStateFlag, signer_list::SignerListFlag,
+    },
+    transactions::{
+        amm_deposit::AMMDepositFlag, amm_withdraw::AMMWithdrawFlag,
+        nftoken_create_offer::NFTokenCreateOfferFlag, nftoken_mint::NFTokenMintFlag,
+        offer_create::OfferCreateFlag, payment::PaymentFlag,
+        payment_channel_claim::PaymentChannelClaimFlag,
+        pseudo_transactions::enable_amendment::EnableAmendmentFlag, trust_set::TrustSetFlag,
+    },
+    FlagCollection, NoFlags,
+};
+
+/// Describe a transaction in rippled's `tx_json` form, e.g. as
+/// returned by the `tx` method, without a typed model for it. See
+/// [`Transaction::describe`].
+///
+/// [`Transaction::describe`]: super::transactions::Transaction::describe
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::describe::describe_tx_json;
+///
+/// let tx_json = serde_json::json!({
+///     "TransactionType": "Payment",
+///     "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+///     "Destination": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+///     "Amount": "2500000",
+///     "Flags": 131072
+/// });
+/// let description = describe_tx_json(&tx_json);
+///
+/// assert!(description.contains("Amount: 2.5 XRP"));
+/// assert!(description.contains("Flags: TfPartialPayment"));
+/// ```
+pub fn describe_tx_json(tx_json: &Value) -> String {
+    let transaction_type = tx_json["TransactionType"].as_str().unwrap_or("Transaction");
+    let flags = tx_json["Flags"].as_u64().unwrap_or_default() as u32;
+    let flag_names = match transaction_type {
+        "AMMDeposit" => flag_names::<AMMDepositFlag>(flags),
+        "AMMWithdraw" => flag_names::<AMMWithdrawFlag>(flags),
+        "EnableAmendment" => flag_names::<EnableAmendmentFlag>(flags),
+        "NFTokenCreateOffer" => flag_names::<NFTokenCreateOfferFlag>(flags),
+        "NFTokenMint" => flag_names::<NFTokenMintFlag>(flags),
+        "OfferCreate" => flag_names::<OfferCreateFlag>(flags),
+        "Payment" => flag_names::<PaymentFlag>(flags),
+        "PaymentChannelClaim" => flag_names::<PaymentChannelClaimFlag>(flags),
+        "TrustSet" => flag_names::<TrustSetFlag>(flags),
+        _ => flag_names::<NoFlags>(flags),
+    };
+
+    describe_transaction(tx_json, flag_names)
+}
+
+/// Describe a ledger object, as returned by `ledger_entry`: its
+/// type, its fields with amounts in XRP and its flags by name.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::describe::describe_ledger_object;
+///
+/// let account_root = serde_json::json!({
+///     "LedgerEntryType": "AccountRoot",
+///     "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+///     "Balance": "148446663",
+///     "Flags": 8388608
+/// });
+/// let description = describe_ledger_object(&account_root);
+///
+/// assert!(description.starts_with("AccountRoot\n"));
+/// assert!(description.contains("Balance: 148.446663 XRP"));
+/// assert!(description.contains("Flags: LsfDefaultRipple"));
+/// ```
+pub fn describe_ledger_object(object: &Value) -> String {
+    let ledger_entry_type = object["LedgerEntryType"].as_str().unwrap_or("LedgerObject");
+    let flags = object["Flags"].as_u64().unwrap_or_default() as u32;
+    let flag_names = match ledger_entry_type {
+        "AccountRoot" => flag_names::<AccountRootFlag>(flags),
+        "NFTokenOffer" => flag_names::<NFTokenOfferFlag>(flags),
+        "Offer" => flag_names::<OfferFlag>(flags),
+        "RippleState" => flag_names::<RippleStateFlag>(flags),
+        "SignerList" => flag_names::<SignerListFlag>(flags),
+        _ => flag_names::<NoFlags>(flags),
+    };
+
+    let mut description = String::from(ledger_entry_type);
+    if let Some(fields) = object.as_object() {
+        describe_fields(&mut description, fields, "LedgerEntryType", &flag_names);
+    }
+
+    description
+}
+
+/// Describe a transaction in `tx_json` form, with its flags already
+/// named.
+pub(crate) fn describe_transaction(tx_json: &Value, flag_names: String) -> String {
+    let transaction_type = tx_json["TransactionType"].as_str().unwrap_or("Transaction");
+    let mut description = String::from(transaction_type);
+    let Some(fields) = tx_json.as_object() else {
+        return description;
+    };
+    describe_fields(&mut description, fields, "TransactionType", &flag_names);
+
+    let is_signed = (fields.contains_key("TxnSignature") && fields.contains_key("SigningPubKey"))
+        || fields.contains_key("Signers");
+    if !is_signed {
+        description.push_str("\n  Signed: no");
+        return description;
+    }
+    description.push_str("\n  Signed: yes");
+    // Hashing needs the transaction to encode, which a malformed one
+    // does not.
+    if let Some(hash) = encode(tx_json)
+        .ok()
+        .and_then(|tx_blob| hash_signed_tx(&tx_blob).ok())
+    {
+        let _ = write!(description, "\n  Hash: {hash}");
+    }
+
+    description
+}
+
+/// The names of the flags set in `flags`, followed by any bits
+/// unknown to `T` in hex.
+pub(crate) fn flag_names<T>(flags: u32) -> String
+where
+    T: IntoEnumIterator + Serialize + Debug,
+{
+    let flag_collection = FlagCollection::<T>::from_bits_truncate(flags);
+    let mut names = flag_collection.to_string();
+    let unknown = flags & !flag_collection.bits();
+    if unknown != 0 {
+        if !names.is_empty() {
+            names.push_str(", ");
+        }
+        let _ = write!(names, "{unknown:#010x}");
+    }
+
+    names
+}
+
+fn describe_fields(
+    description: &mut String,
+    fields: &Map<String, Value>,
+    type_field: &str,
+    flag_names: &str,
+) {
+    // In rippled's order, whatever the order of serde_json's objects.
+    let mut fields: Vec<(&String, &Value)> = fields.iter().collect();
+    fields.sort_unstable_by_key(|(name, _)| *name);
+    for (name, value) in fields {
+        match name.as_str() {
+            name if name == type_field => {}
+            // The signing status is described instead.
+            "TxnSignature" => {}
+            "Flags" => {
+                if !flag_names.is_empty() {
+                    let _ = write!(description, "\n  Flags: {flag_names}");
+                }
+            }
+            "Memos" => {
+                for memo in value.as_array().into_iter().flatten() {
+                    let _ = write!(description, "\n  Memo: {}", describe_memo(&memo["Memo"]));
+                }
+            }
+            "Signers" => {
+                let signers: Vec<&str> = value
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|signer| signer["Signer"]["Account"].as_str())
+                    .collect();
+                let _ = write!(description, "\n  Signers: {}", signers.join(", "));
+            }
+            name => {
+                let _ = write!(description, "\n  {name}: {}", describe_value(name, value));
+            }
+        }
+    }
+}
+
+fn describe_value(name: &str, value: &Value) -> String {
+    let is_amount = get_field_instance(name)
+        .is_some_and(|field_instance| field_instance.associated_type == "Amount");
+    match value {
+        Value::String(drops) if is_amount => match drops_to_xrp(drops) {
+            Ok(xrp) => format!("{xrp} XRP"),
+            Err(_) => format!("{drops} drops"),
+        },
+        Value::Object(amount) if is_amount => format!(
+            "{} {} ({})",
+            amount["value"].as_str().unwrap_or_default(),
+            amount["currency"].as_str().unwrap_or_default(),
+            amount["issuer"].as_str().unwrap_or_default()
+        ),
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// The memo's data, with its type and format when set. Each is
+/// decoded when it is valid UTF-8 and left in hex otherwise.
+fn describe_memo(memo: &Value) -> String {
+    let decoded = |field: &str| {
+        memo[field].as_str().map(|hex_value| {
+            hex::decode(hex_value)
+                .ok()
+                .and_then(|bytes| String::from_utf8(bytes).ok())
+                .unwrap_or_else(|| hex_value.to_string())
+        })
+    };
+    let mut description = decoded("MemoData").unwrap_or_default();
+    let details: Vec<String> = [("type", "MemoType"), ("format", "MemoFormat")]
+        .into_iter()
+        .filter_map(|(label, field)| decoded(field).map(|value| format!("{label}: {value}")))
+        .collect();
+    if !details.is_empty() {
+        let _ = write!(description, " ({})", details.join(", "));
+    }
+
+    description
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        asynch::transaction::sign,
+        models::transactions::{nftoken_mint::NFTokenMint, payment::Payment, Memo, Transaction},
+        wallet::Wallet,
+    };
+
+    #[test]
+    fn test_describe_payment() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let mut payment: Payment = serde_json::from_value(serde_json::json!({
+            "TransactionType": "Payment",
+            "Account": wallet.classic_address,
+            "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+            "Amount": {
+                "currency": "USD",
+                "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                "value": "10"
+            },
+            "SendMax": "1500000",
+            "Fee": "12",
+            "Flags": 131072,
+            "Sequence": 5
+        }))
+        .unwrap();
+        payment.common_fields.memos = Some(alloc::vec![
+            Memo::data(hex::encode("invoice 42")).with_type(hex::encode("text/plain"))
+        ]);
+
+        assert_eq!(
+            payment.describe(),
+            "Payment
+  Account: r9mhdWo1NXVZr2pDnCtC1xwxE85kFtSzYR
+  Amount: 10 USD (rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B)
+  Destination: rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe
+  Fee: 0.000012 XRP
+  Flags: TfPartialPayment
+  Memo: invoice 42 (type: text/plain)
+  SendMax: 1.5 XRP
+  Sequence: 5
+  Signed: no"
+        );
+
+        sign(&mut payment, &wallet, false).unwrap();
+        assert_eq!(
+            payment.describe(),
+            "Payment
+  Account: r9mhdWo1NXVZr2pDnCtC1xwxE85kFtSzYR
+  Amount: 10 USD (rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B)
+  Destination: rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe
+  Fee: 0.000012 XRP
+  Flags: TfPartialPayment
+  Memo: invoice 42 (type: text/plain)
+  SendMax: 1.5 XRP
+  Sequence: 5
+  SigningPubKey: ED954B07518437C13BF4718B07CBABA87C868FAC82D9C2D6A042B1D366B3722D86
+  Signed: yes
+  Hash: 2F5C2EE5919E16AC49B4C4E208C065A2F1966302B76AD3254D7C2A3C1C7407EB"
+        );
+        assert!(payment
+            .describe()
+            .ends_with(payment.get_hash().unwrap().as_ref()));
+    }
+
+    #[test]
+    fn test_describe_nftoken_mint() {
+        let tx_json = serde_json::json!({
+            "TransactionType": "NFTokenMint",
+            "Account": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+            "Fee": "10",
+            "Flags": 2147483656u32,
+            "NFTokenTaxon": 0,
+            "Sequence": 21,
+            "TransferFee": 314,
+            "URI": "697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C67747179353566627A6469"
+        });
+
+        assert_eq!(
+            describe_tx_json(&tx_json),
+            "NFTokenMint
+  Account: rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B
+  Fee: 0.00001 XRP
+  Flags: TfTransferable, TfFullyCanonicalSig
+  NFTokenTaxon: 0
+  Sequence: 21
+  TransferFee: 314
+  URI: 697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C67747179353566627A6469
+  Signed: no"
+        );
+        let nftoken_mint: NFTokenMint = serde_json::from_value(tx_json).unwrap();
+        assert!(nftoken_mint
+            .describe()
+            .contains("\n  Flags: TfTransferable, TfFullyCanonicalSig\n"));
+    }
+
+    #[test]
+    fn test_describe_ledger_object() {
+        let offer = serde_json::json!({
+            "LedgerEntryType": "Offer",
+            "Account": "rBqb89MRQJnMPq8wTwEbtz4kvxrEDfcYvt",
+            "BookDirectory": "ACC27DE91DBA86FC509069EAF4BC511D73128B780F2E54BF5E07A369E2446000",
+            "Flags": 131072 | 0x00400000,
+            "Sequence": 866,
+            "TakerGets": {
+                "currency": "XAG",
+                "issuer": "r9Dr5xwkeLegBeXq6ujinjSBLQzQ1zQGjH",
+                "value": "37"
+            },
+            "TakerPays": "79550000000",
+            "index": "96F76F27D8A327FC48753167EC04A46AA0E382E6F57F32FD12274144D00F1797"
+        });
+
+        assert_eq!(
+            describe_ledger_object(&offer),
+            "Offer
+  Account: rBqb89MRQJnMPq8wTwEbtz4kvxrEDfcYvt
+  BookDirectory: ACC27DE91DBA86FC509069EAF4BC511D73128B780F2E54BF5E07A369E2446000
+  Flags: LsfSell, 0x00400000
+  Sequence: 866
+  TakerGets: 37 XAG (r9Dr5xwkeLegBeXq6ujinjSBLQzQ1zQGjH)
+  TakerPays: 79550 XRP
+  index: 96F76F27D8A327FC48753167EC04A46AA0E382E6F57F32FD12274144D00F1797"
+        );
+    }
+}
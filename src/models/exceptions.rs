@@ -5,18 +5,22 @@ use core::num::{ParseFloatError, ParseIntError};
 use alloc::string::String;
 use thiserror_no_std::Error;
 
-use crate::XRPLSerdeJsonError;
+use crate::{utils::exceptions::XRPLUtilsException, XRPLSerdeJsonError};
 
 use super::{
     results::exceptions::XRPLResultException,
     transactions::exceptions::{
-        XRPLAccountSetException, XRPLNFTokenCancelOfferException, XRPLNFTokenCreateOfferException,
-        XRPLPaymentException, XRPLSignerListSetException, XRPLTransactionException,
-        XRPLXChainClaimException, XRPLXChainCreateBridgeException,
-        XRPLXChainCreateClaimIDException, XRPLXChainModifyBridgeException,
+        XRPLAccountDeleteException, XRPLAccountSetException, XRPLCommonFieldsException,
+        XRPLNFTokenCancelOfferException, XRPLNFTokenCreateOfferException, XRPLPaymentException,
+        XRPLSignerListSetException, XRPLTransactionException, XRPLXChainClaimException,
+        XRPLXChainCreateBridgeException, XRPLXChainCreateClaimIDException,
+        XRPLXChainModifyBridgeException,
     },
 };
 
+#[cfg(feature = "xahau")]
+use super::transactions::exceptions::XRPLSetHookException;
+
 pub type XRPLModelResult<T, E = XRPLModelException> = core::result::Result<T, E>;
 
 #[derive(Debug, PartialEq, Error)]
@@ -62,11 +66,21 @@ pub enum XRPLModelException {
     ValueEqualsValue { field1: String, field2: String },
     #[error("The value of the field `{0:?}` is not allowed to be zero")]
     ValueZero(String),
+    #[error("The value of the field `{0:?}` is not allowed to be negative")]
+    ValueNegative(String),
     #[error("If the field `{field1:?}` is defined, the field `{field2:?}` must also be defined")]
     FieldRequiresField { field1: String, field2: String },
 
+    #[error("The flags {0:#010x} are not defined for this model")]
+    UnknownFlags(u32),
+
     #[error("Expected field `{0}` is missing")]
     MissingField(String),
+    #[error("`{request}` sends a secret key to the server. Sign locally with `{local_alternative}` instead, or call `allow_insecure_remote_signing()` if the server is one you run and trust (see <https://xrpl.org/set-up-secure-signing.html>)")]
+    InsecureRemoteSigning {
+        request: &'static str,
+        local_alternative: &'static str,
+    },
 
     #[error("From hex error: {0}")]
     FromHexError(#[from] hex::FromHexError),
@@ -79,6 +93,8 @@ pub enum XRPLModelException {
     #[error("BigDecimal error: {0}")]
     BigDecimalError(#[from] bigdecimal::ParseBigDecimalError),
     #[error("{0}")]
+    XRPLUtilsError(#[from] XRPLUtilsException),
+    #[error("{0}")]
     XRPLResultError(#[from] XRPLResultException),
     #[error("{0}")]
     XRPLTransactionError(#[from] XRPLTransactionException),
@@ -93,6 +109,12 @@ impl From<serde_json::Error> for XRPLModelException {
     }
 }
 
+impl From<XRPLAccountDeleteException> for XRPLModelException {
+    fn from(error: XRPLAccountDeleteException) -> Self {
+        XRPLModelException::XRPLTransactionError(error.into())
+    }
+}
+
 impl From<XRPLAccountSetException> for XRPLModelException {
     fn from(error: XRPLAccountSetException) -> Self {
         XRPLModelException::XRPLTransactionError(error.into())
@@ -111,6 +133,12 @@ impl From<XRPLNFTokenCreateOfferException> for XRPLModelException {
     }
 }
 
+impl From<XRPLCommonFieldsException> for XRPLModelException {
+    fn from(error: XRPLCommonFieldsException) -> Self {
+        XRPLModelException::XRPLTransactionError(error.into())
+    }
+}
+
 impl From<XRPLPaymentException> for XRPLModelException {
     fn from(error: XRPLPaymentException) -> Self {
         XRPLModelException::XRPLTransactionError(error.into())
@@ -146,3 +174,10 @@ impl From<XRPLXChainModifyBridgeException> for XRPLModelException {
         XRPLModelException::XRPLTransactionError(error.into())
     }
 }
+
+#[cfg(feature = "xahau")]
+impl From<XRPLSetHookException> for XRPLModelException {
+    fn from(error: XRPLSetHookException) -> Self {
+        XRPLModelException::XRPLTransactionError(error.into())
+    }
+}
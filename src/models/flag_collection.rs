@@ -1,33 +1,33 @@
 use core::convert::TryFrom;
+use core::fmt::Debug;
 
 use alloc::vec::Vec;
-use derive_new::new;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use strum::IntoEnumIterator;
 use strum_macros::{AsRefStr, Display, EnumIter};
 
 use super::{XRPLModelException, XRPLModelResult};
 
+/// The universal `tfFullyCanonicalSig` flag. Transactions from
+/// before the RequireFullyCanonicalSig amendment may set it, whatever
+/// their type, so it is not part of the per-type flag enums.
+pub const TF_FULLY_CANONICAL_SIG: u32 = 0x80000000;
+
 /// Represents the type of flags when the XRPL model has no flags.
 #[derive(
     Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Display, AsRefStr, EnumIter, Copy,
 )]
 pub enum NoFlags {}
 
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, new)]
-pub struct FlagCollection<T>(pub(crate) Vec<T>)
-where
-    T: IntoEnumIterator;
-
-impl<T> Iterator for FlagCollection<T>
+/// The flags set on a transaction or ledger object. Serializes as
+/// the numeric `Flags` value.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FlagCollection<T>
 where
     T: IntoEnumIterator,
 {
-    type Item = T;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.pop()
-    }
+    pub(crate) flags: Vec<T>,
+    fully_canonical_sig: bool,
 }
 
 impl<T> Default for FlagCollection<T>
@@ -35,7 +35,7 @@ where
     T: IntoEnumIterator,
 {
     fn default() -> Self {
-        FlagCollection(Vec::new())
+        FlagCollection::new(Vec::new())
     }
 }
 
@@ -44,7 +44,40 @@ where
     T: IntoEnumIterator,
 {
     fn from(flags: Vec<T>) -> Self {
-        FlagCollection(flags)
+        FlagCollection::new(flags)
+    }
+}
+
+impl<T> FromIterator<T> for FlagCollection<T>
+where
+    T: IntoEnumIterator,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(flags: I) -> Self {
+        FlagCollection::new(flags.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for FlagCollection<T>
+where
+    T: IntoEnumIterator,
+{
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.flags.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a FlagCollection<T>
+where
+    T: IntoEnumIterator,
+{
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.flags.iter()
     }
 }
 
@@ -55,14 +88,7 @@ where
     type Error = XRPLModelException;
 
     fn try_from(flags: u32) -> XRPLModelResult<Self> {
-        let mut flag_collection = Vec::new();
-        for flag in T::iter() {
-            let flag_as_u32 = flag_to_u32(&flag)?;
-            if flags & flag_as_u32 == flag_as_u32 {
-                flag_collection.push(flag);
-            }
-        }
-        Ok(FlagCollection::new(flag_collection))
+        FlagCollection::try_from_bits(flags)
     }
 }
 
@@ -73,26 +99,189 @@ where
     type Error = XRPLModelException;
 
     fn try_from(flag_collection: FlagCollection<T>) -> XRPLModelResult<Self> {
-        let mut flags = 0;
-        for flag in flag_collection {
-            let flag_as_u32 = flag_to_u32(&flag)?;
-            flags |= flag_as_u32;
+        Ok(flag_collection.bits())
+    }
+}
+
+impl<T> FlagCollection<T>
+where
+    T: IntoEnumIterator,
+{
+    pub fn new(flags: Vec<T>) -> Self {
+        Self {
+            flags,
+            fully_canonical_sig: false,
         }
-        Ok(flags)
+    }
+
+    /// Also set the universal `tfFullyCanonicalSig` flag.
+    pub fn with_fully_canonical_sig(mut self) -> Self {
+        self.fully_canonical_sig = true;
+        self
+    }
+
+    /// Set or clear the universal `tfFullyCanonicalSig` flag.
+    pub fn set_fully_canonical_sig(&mut self, fully_canonical_sig: bool) {
+        self.fully_canonical_sig = fully_canonical_sig;
+    }
+
+    /// Whether the universal `tfFullyCanonicalSig` flag is set.
+    pub fn has_fully_canonical_sig(&self) -> bool {
+        self.fully_canonical_sig
+    }
+
+    pub fn contains(&self, flag: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.flags.contains(flag)
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.flags.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.flags.is_empty() && !self.fully_canonical_sig
     }
 }
 
-impl<T> core::fmt::Display for FlagCollection<T>
+impl<T> FlagCollection<T>
 where
     T: IntoEnumIterator + Serialize,
+{
+    /// Read the numeric `Flags` value of a transaction or ledger
+    /// object. Fails on bits that are not flags of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ## Basic usage
+    ///
+    /// ```
+    /// use xrpl::models::transactions::payment::PaymentFlag;
+    /// use xrpl::models::FlagCollection;
+    ///
+    /// let flags = FlagCollection::<PaymentFlag>::try_from_bits(0x00020000).unwrap();
+    ///
+    /// assert!(flags.contains(&PaymentFlag::TfPartialPayment));
+    /// assert_eq!(flags.bits(), 0x00020000);
+    /// assert!(FlagCollection::<PaymentFlag>::try_from_bits(0x00000001).is_err());
+    /// ```
+    pub fn try_from_bits(bits: u32) -> XRPLModelResult<Self> {
+        let flag_collection = Self::from_bits_truncate(bits);
+        let unknown_bits = bits & !flag_collection.bits();
+        if unknown_bits != 0 {
+            return Err(XRPLModelException::UnknownFlags(unknown_bits));
+        }
+
+        Ok(flag_collection)
+    }
+
+    /// Read the numeric `Flags` value of a transaction or ledger
+    /// object, ignoring bits that are not flags of `T`, e.g. flags
+    /// added by amendments after this release.
+    pub fn from_bits_truncate(bits: u32) -> Self {
+        let flag_collection: Self = T::iter()
+            .filter(|flag| flag_to_u32(flag).is_ok_and(|flag_bits| bits & flag_bits == flag_bits))
+            .collect();
+        // Ledger objects may use the same bit for a flag of their own,
+        // e.g. `lsfAllowTrustLineClawback`.
+        let unknown_bits = bits & !flag_collection.bits();
+
+        Self {
+            fully_canonical_sig: unknown_bits & TF_FULLY_CANONICAL_SIG != 0,
+            ..flag_collection
+        }
+    }
+
+    /// Fold the flags into the numeric `Flags` value of a transaction
+    /// or ledger object.
+    pub fn bits(&self) -> u32 {
+        let bits = self
+            .flags
+            .iter()
+            .filter_map(|flag| flag_to_u32(flag).ok())
+            .fold(0, |bits, flag_bits| bits | flag_bits);
+        if self.fully_canonical_sig {
+            bits | TF_FULLY_CANONICAL_SIG
+        } else {
+            bits
+        }
+    }
+}
+
+/// Lists the names of the flags set, e.g. `TfPartialPayment,
+/// TfLimitQuality`.
+impl<T> core::fmt::Display for FlagCollection<T>
+where
+    T: IntoEnumIterator + Debug,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut flags = 0;
-        for flag in &self.0 {
-            let flag_as_u32 = flag_to_u32(flag).unwrap();
-            flags |= flag_as_u32;
+        let mut names = self
+            .flags
+            .iter()
+            .map(|flag| alloc::format!("{flag:?}"))
+            .chain(
+                self.fully_canonical_sig
+                    .then(|| "TfFullyCanonicalSig".into()),
+            );
+        if let Some(name) = names.next() {
+            f.write_str(&name)?;
         }
-        write!(f, "{}", flags)
+        for name in names {
+            write!(f, ", {name}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Serialize for FlagCollection<T>
+where
+    T: IntoEnumIterator + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u32(self.bits())
+    }
+}
+
+/// Drops bits that are not flags of `T`, like
+/// [`FlagCollection::from_bits_truncate`], so ledger data with flags
+/// of newer amendments still parses. Use
+/// [`FlagCollection::try_from_bits`] to reject them.
+impl<'de, T> Deserialize<'de> for FlagCollection<T>
+where
+    T: IntoEnumIterator + Serialize,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bits = u32::deserialize(deserializer)?;
+
+        Ok(Self::from_bits_truncate(bits))
+    }
+}
+
+/// The numeric value, as it is on the wire.
+#[cfg(feature = "json-schema")]
+impl<T> schemars::JsonSchema for FlagCollection<T>
+where
+    T: IntoEnumIterator,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        "FlagCollection".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        u32::json_schema(generator)
     }
 }
 
@@ -102,3 +291,98 @@ where
 {
     Ok(serde_json::to_string(flag)?.parse::<u32>()?)
 }
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::models::transactions::{
+        account_set::AccountSetTfFlag, amm_deposit::AMMDepositFlag, amm_withdraw::AMMWithdrawFlag,
+        nftoken_create_offer::NFTokenCreateOfferFlag, nftoken_mint::NFTokenMintFlag,
+        offer_create::OfferCreateFlag, payment::PaymentFlag,
+        payment_channel_claim::PaymentChannelClaimFlag,
+        pseudo_transactions::enable_amendment::EnableAmendmentFlag, trust_set::TrustSetFlag,
+    };
+
+    fn assert_round_trips<T>()
+    where
+        T: IntoEnumIterator + Serialize + Debug + PartialEq,
+    {
+        let mut all_bits = 0;
+        for flag in T::iter() {
+            let bits = FlagCollection::from_iter([flag]).bits();
+            assert_eq!(bits.count_ones(), 1, "{}", core::any::type_name::<T>());
+            let flags = FlagCollection::<T>::try_from_bits(bits).unwrap();
+            assert_eq!(flags.bits(), bits);
+            all_bits |= bits;
+        }
+        let all_flags = FlagCollection::<T>::try_from_bits(all_bits).unwrap();
+        assert_eq!(all_flags.iter().count(), T::iter().count());
+        assert_eq!(all_flags.bits(), all_bits);
+
+        let with_fully_canonical_sig =
+            FlagCollection::<T>::try_from_bits(all_bits | TF_FULLY_CANONICAL_SIG).unwrap();
+        assert!(with_fully_canonical_sig.has_fully_canonical_sig());
+        assert_eq!(
+            with_fully_canonical_sig.bits(),
+            all_bits | TF_FULLY_CANONICAL_SIG
+        );
+    }
+
+    #[test]
+    fn test_transaction_flags_round_trip() {
+        // `AccountSetFlag` holds the values of `SetFlag` and
+        // `ClearFlag`, which are not bits.
+        assert_round_trips::<AccountSetTfFlag>();
+        assert_round_trips::<AMMDepositFlag>();
+        assert_round_trips::<AMMWithdrawFlag>();
+        assert_round_trips::<EnableAmendmentFlag>();
+        assert_round_trips::<NFTokenCreateOfferFlag>();
+        assert_round_trips::<NFTokenMintFlag>();
+        assert_round_trips::<OfferCreateFlag>();
+        assert_round_trips::<PaymentFlag>();
+        assert_round_trips::<PaymentChannelClaimFlag>();
+        assert_round_trips::<TrustSetFlag>();
+        assert_round_trips::<NoFlags>();
+    }
+
+    #[test]
+    fn test_unknown_bits() {
+        let bits = 0x00020000 | 0x00000100;
+
+        assert_eq!(
+            FlagCollection::<PaymentFlag>::try_from_bits(bits),
+            Err(XRPLModelException::UnknownFlags(0x00000100))
+        );
+        let truncated = FlagCollection::<PaymentFlag>::from_bits_truncate(bits);
+        assert_eq!(truncated.bits(), 0x00020000);
+
+        let deserialized =
+            serde_json::from_value::<FlagCollection<PaymentFlag>>(bits.into()).unwrap();
+        assert_eq!(deserialized, truncated);
+    }
+
+    #[test]
+    fn test_display_and_iteration() {
+        let flags: FlagCollection<PaymentFlag> =
+            [PaymentFlag::TfPartialPayment, PaymentFlag::TfLimitQuality]
+                .into_iter()
+                .collect();
+        assert_eq!(flags.to_string(), "TfPartialPayment, TfLimitQuality");
+        assert!(flags.contains(&PaymentFlag::TfLimitQuality));
+        assert!(!flags.contains(&PaymentFlag::TfNoDirectRipple));
+
+        let flags = flags.with_fully_canonical_sig();
+        assert_eq!(
+            flags.to_string(),
+            "TfPartialPayment, TfLimitQuality, TfFullyCanonicalSig"
+        );
+        assert_eq!(serde_json::to_string(&flags).unwrap(), "2147876864");
+        assert_eq!(
+            flags.into_iter().collect::<Vec<_>>(),
+            [PaymentFlag::TfPartialPayment, PaymentFlag::TfLimitQuality]
+        );
+        assert_eq!(FlagCollection::<PaymentFlag>::default().to_string(), "");
+    }
+}
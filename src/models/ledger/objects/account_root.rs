@@ -20,6 +20,9 @@ use super::{CommonFields, LedgerObject};
 )]
 #[repr(u32)]
 pub enum AccountRootFlag {
+    /// The issuer of this account's tokens can claw them back. Once enabled, cannot be
+    /// disabled.
+    LsfAllowTrustLineClawback = 0x80000000,
     /// This account is an Automated Market Maker instance.
     LsfAmm = 0x02000000,
     /// Enable rippling on this addresses's trust lines by default.
@@ -30,6 +33,14 @@ pub enum AccountRootFlag {
     LsfDepositAuth = 0x01000000,
     /// Disallows use of the master key to sign transactions for this account.
     LsfDisableMaster = 0x00100000,
+    /// This account does not accept incoming Checks.
+    LsfDisallowIncomingCheck = 0x08000000,
+    /// This account does not accept incoming NFToken offers.
+    LsfDisallowIncomingNFTokenOffer = 0x04000000,
+    /// This account does not accept incoming payment channels.
+    LsfDisallowIncomingPayChan = 0x10000000,
+    /// This account does not accept incoming trust lines.
+    LsfDisallowIncomingTrustline = 0x20000000,
     /// Client applications should not send XRP to this account. Not enforced by rippled.
     LsfDisallowXRP = 0x00080000,
     /// All assets issued by this address are frozen.
@@ -122,6 +133,10 @@ pub struct AccountRoot<'a> {
 impl<'a> Model for AccountRoot<'a> {}
 
 impl<'a> LedgerObject<AccountRootFlag> for AccountRoot<'a> {
+    fn has_flag(&self, flag: &AccountRootFlag) -> bool {
+        self.common_fields.has_flag(flag)
+    }
+
     fn get_ledger_entry_type(&self) -> LedgerEntryType {
         self.common_fields.get_ledger_entry_type()
     }
@@ -224,4 +239,49 @@ mod tests {
 
         assert_eq!(account_root, deserialized);
     }
+
+    #[test]
+    fn test_account_root_flags() {
+        let account_root: AccountRoot = serde_json::from_value(serde_json::json!({
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "Balance": "148446663",
+            "Flags": 0x80820000u32,
+            "LedgerEntryType": "AccountRoot",
+            "OwnerCount": 3,
+            "PreviousTxnID": "0D5FB50FA65C9FE1538FD7E398FFFE9D1908DFA4576D8D7A020040686F93C77D",
+            "PreviousTxnLgrSeq": 14091160,
+            "Sequence": 336,
+            "index": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8"
+        }))
+        .unwrap();
+
+        assert!(account_root.has_flag(&AccountRootFlag::LsfRequireDestTag));
+        assert!(account_root.has_flag(&AccountRootFlag::LsfDefaultRipple));
+        assert!(account_root.has_flag(&AccountRootFlag::LsfAllowTrustLineClawback));
+        assert!(!account_root.has_flag(&AccountRootFlag::LsfRequireAuth));
+        assert!(!account_root.common_fields.flags.has_fully_canonical_sig());
+
+        let serialized = serde_json::to_value(&account_root).unwrap();
+        assert_eq!(serialized["Flags"], 0x80820000u32);
+    }
+
+    #[test]
+    fn test_account_root_unknown_flags() {
+        // Flags of amendments newer than this release are dropped.
+        let account_root: AccountRoot = serde_json::from_value(serde_json::json!({
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "Balance": "148446663",
+            "Flags": 0x40020000u32,
+            "LedgerEntryType": "AccountRoot",
+            "OwnerCount": 3,
+            "PreviousTxnID": "0D5FB50FA65C9FE1538FD7E398FFFE9D1908DFA4576D8D7A020040686F93C77D",
+            "PreviousTxnLgrSeq": 14091160,
+            "Sequence": 336,
+            "index": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8"
+        }))
+        .unwrap();
+
+        assert!(account_root.has_flag(&AccountRootFlag::LsfRequireDestTag));
+        assert_eq!(account_root.common_fields.flags.bits(), 0x00020000);
+    }
 }
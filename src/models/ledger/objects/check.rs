@@ -1,4 +1,5 @@
 use crate::models::ledger::objects::LedgerEntryType;
+use crate::models::ripple_time::ripple_time_accessors;
 use crate::models::FlagCollection;
 use crate::models::NoFlags;
 use crate::models::{amount::Amount, Model};
@@ -110,6 +111,10 @@ impl<'a> Check<'a> {
     }
 }
 
+ripple_time_accessors!(Check {
+    expiration => expiration_datetime;
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
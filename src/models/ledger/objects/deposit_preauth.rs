@@ -1,7 +1,8 @@
 use crate::models::FlagCollection;
 use crate::models::Model;
-use crate::models::{ledger::objects::LedgerEntryType, NoFlags};
+use crate::models::{ledger::objects::LedgerEntryType, Credential, NoFlags};
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
 
 use serde::{Deserialize, Serialize};
 
@@ -29,8 +30,9 @@ pub struct DepositPreauth<'a> {
     // `<https://xrpl.org/depositpreauth-object.html#depositpreauth-fields>`
     /// The account that granted the preauthorization.
     pub account: Cow<'a, str>,
-    /// The account that received the preauthorization.
-    pub authorize: Cow<'a, str>,
+    /// The account that received the preauthorization. Absent when
+    /// credentials are preauthorized instead.
+    pub authorize: Option<Cow<'a, str>>,
     /// A hint indicating which page of the sender's owner directory links to this object, in case
     /// the directory consists of multiple pages.
     pub owner_node: Cow<'a, str>,
@@ -39,6 +41,9 @@ pub struct DepositPreauth<'a> {
     pub previous_txn_id: Cow<'a, str>,
     /// The index of the ledger that contains the transaction that most recently modified this object.
     pub previous_txn_lgr_seq: u32,
+    /// The credentials that received the preauthorization. Any
+    /// account holding all of them may send payments to `account`.
+    pub authorize_credentials: Option<Vec<Credential<'a>>>,
 }
 
 impl<'a> Model for DepositPreauth<'a> {}
@@ -54,10 +59,11 @@ impl<'a> DepositPreauth<'a> {
         index: Option<Cow<'a, str>>,
         ledger_index: Option<Cow<'a, str>>,
         account: Cow<'a, str>,
-        authorize: Cow<'a, str>,
+        authorize: Option<Cow<'a, str>>,
         owner_node: Cow<'a, str>,
         previous_txn_id: Cow<'a, str>,
         previous_txn_lgr_seq: u32,
+        authorize_credentials: Option<Vec<Credential<'a>>>,
     ) -> Self {
         Self {
             common_fields: CommonFields {
@@ -71,6 +77,7 @@ impl<'a> DepositPreauth<'a> {
             owner_node,
             previous_txn_id,
             previous_txn_lgr_seq,
+            authorize_credentials,
         }
     }
 }
@@ -87,10 +94,11 @@ mod tests {
             )),
             None,
             Cow::from("rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8"),
-            Cow::from("rEhxGqkqPPSxQ3P25J66ft5TwpzV14k2de"),
+            Some(Cow::from("rEhxGqkqPPSxQ3P25J66ft5TwpzV14k2de")),
             Cow::from("0000000000000000"),
             Cow::from("3E8964D5A86B3CD6B9ECB33310D4E073D64C865A5B866200AD2B7E29F8326702"),
             7,
+            None,
         );
         let serialized = serde_json::to_string(&deposit_preauth).unwrap();
 
@@ -98,4 +106,37 @@ mod tests {
 
         assert_eq!(deposit_preauth, deserialized);
     }
+
+    #[test]
+    fn test_deserialize_credentials() {
+        let json = serde_json::json!({
+            "Account": "rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8",
+            "AuthorizeCredentials": [{
+                "Credential": {
+                    "CredentialType": "4B5943",
+                    "Issuer": "rEhxGqkqPPSxQ3P25J66ft5TwpzV14k2de"
+                }
+            }],
+            "Flags": 0,
+            "LedgerEntryType": "DepositPreauth",
+            "OwnerNode": "0000000000000000",
+            "PreviousTxnID": "3E8964D5A86B3CD6B9ECB33310D4E073D64C865A5B866200AD2B7E29F8326702",
+            "PreviousTxnLgrSeq": 7,
+            "index": "4A255038CC3ADCC1A9C91509279B59908251728D0DAADB248FFE297D0F7E068C"
+        });
+        let deposit_preauth: DepositPreauth = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(deposit_preauth.authorize, None);
+        assert_eq!(
+            deposit_preauth.authorize_credentials,
+            Some(
+                [Credential::new(
+                    "rEhxGqkqPPSxQ3P25J66ft5TwpzV14k2de".into(),
+                    "4B5943".into()
+                )]
+                .to_vec()
+            )
+        );
+        assert_eq!(serde_json::to_value(&deposit_preauth).unwrap(), json);
+    }
 }
@@ -43,20 +43,20 @@ pub struct DirectoryNode<'a> {
     /// The ID of root object for this directory.
     pub root_index: Cow<'a, str>,
     /// If this `Directory` consists of multiple pages, this ID links to the next object in the chain,
-    /// wrapping around at the end.
-    pub index_next: Option<u64>,
+    /// wrapping around at the end. A UInt64, as hexadecimal.
+    pub index_next: Option<Cow<'a, str>>,
     /// If this `Directory` consists of multiple pages, this ID links to the previous object in the
-    /// chain, wrapping around at the beginning.
-    pub index_previous: Option<u64>,
+    /// chain, wrapping around at the beginning. A UInt64, as hexadecimal.
+    pub index_previous: Option<Cow<'a, str>>,
     /// (Owner Directories only) The address of the account that owns the objects in this directory.
     pub owner: Option<Cow<'a, str>>,
     /// (`Offer` `Directories` only) The currency code of the `TakerGets` amount from the offers in this
     /// directory.
     pub taker_gets_currency: Option<Cow<'a, str>>,
-    /// (`Offer` `Directories` only) The currency code of the `TakerPays` amount from the offers in this
     /// (`Offer` `Directories` only) The issuer of the `TakerGets` amount from the offers in this
     /// directory.
     pub taker_gets_issuer: Option<Cow<'a, str>>,
+    /// (`Offer` `Directories` only) The currency code of the `TakerPays` amount from the offers in this
     /// directory.
     pub taker_pays_currency: Option<Cow<'a, str>>,
     /// (`Offer` `Directories` only) The issuer of the `TakerPays` amount from the offers in this
@@ -79,8 +79,8 @@ impl<'a> DirectoryNode<'a> {
         indexes: Vec<Cow<'a, str>>,
         root_index: Cow<'a, str>,
         exchange_rate: Option<Cow<'a, str>>,
-        index_next: Option<u64>,
-        index_previous: Option<u64>,
+        index_next: Option<Cow<'a, str>>,
+        index_previous: Option<Cow<'a, str>>,
         owner: Option<Cow<'a, str>>,
         taker_gets_currency: Option<Cow<'a, str>>,
         taker_gets_issuer: Option<Cow<'a, str>>,
@@ -1,4 +1,5 @@
 use crate::models::ledger::objects::LedgerEntryType;
+use crate::models::ripple_time::ripple_time_accessors;
 use crate::models::FlagCollection;
 use crate::models::NoFlags;
 use crate::models::{amount::Amount, Model};
@@ -125,6 +126,11 @@ impl<'a> Escrow<'a> {
     }
 }
 
+ripple_time_accessors!(Escrow {
+    cancel_after => cancel_after_datetime;
+    finish_after => finish_after_datetime;
+});
+
 #[cfg(test)]
 mod test_serde {
     use super::*;
@@ -0,0 +1,114 @@
+use crate::models::transactions::set_hook;
+use crate::models::FlagCollection;
+use crate::models::Model;
+use crate::models::{ledger::objects::LedgerEntryType, NoFlags};
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+use serde_with::skip_serializing_none;
+
+use super::{CommonFields, LedgerObject};
+
+/// The `Hook` object type holds the Hooks installed on an account,
+/// in the order they run. Xahau only.
+///
+/// `<https://xrpl-hooks.readme.io/docs/hook>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct Hook<'a> {
+    /// The base fields for all ledger object models.
+    ///
+    /// See Ledger Object Common Fields:
+    /// `<https://xrpl.org/ledger-entry-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields<'a, NoFlags>,
+    // The custom fields for the Hook model.
+    //
+    // See Hook fields:
+    // `<https://xrpl-hooks.readme.io/docs/hook>`
+    /// The account the Hooks are installed on.
+    pub account: Cow<'a, str>,
+    /// The installed Hooks. Each refers to its `HookDefinition` by
+    /// `HookHash`, with the fields set at install time.
+    pub hooks: Vec<set_hook::Hook>,
+    /// A hint indicating which page of the owner directory links to this object, in case the
+    /// directory consists of multiple pages.
+    pub owner_node: Cow<'a, str>,
+    /// The identifying hash of the transaction that most recently modified this object.
+    #[serde(rename = "PreviousTxnID")]
+    pub previous_txn_id: Cow<'a, str>,
+    /// The index of the ledger that contains the transaction that most recently
+    /// modified this object.
+    pub previous_txn_lgr_seq: u32,
+}
+
+impl<'a> Model for Hook<'a> {}
+
+impl<'a> LedgerObject<NoFlags> for Hook<'a> {
+    fn get_ledger_entry_type(&self) -> LedgerEntryType {
+        self.common_fields.get_ledger_entry_type()
+    }
+}
+
+impl<'a> Hook<'a> {
+    pub fn new(
+        index: Option<Cow<'a, str>>,
+        ledger_index: Option<Cow<'a, str>>,
+        account: Cow<'a, str>,
+        hooks: Vec<set_hook::Hook>,
+        owner_node: Cow<'a, str>,
+        previous_txn_id: Cow<'a, str>,
+        previous_txn_lgr_seq: u32,
+    ) -> Self {
+        Self {
+            common_fields: CommonFields {
+                flags: FlagCollection::default(),
+                ledger_entry_type: LedgerEntryType::Hook,
+                index,
+                ledger_index,
+            },
+            account,
+            hooks,
+            owner_node,
+            previous_txn_id,
+            previous_txn_lgr_seq,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde() {
+        let json = serde_json::json!({
+            "Account": "rKxLZ6Y7Q8XX5YdC4zSCH1LK1kf1YM5tQb",
+            "Flags": 0,
+            "Hooks": [
+                {
+                    "Hook": {
+                        "HookHash": "A5B8D62154DA1C329BE13582086B52612476720CEBD097EB85CEE1455E1C70A6"
+                    }
+                }
+            ],
+            "LedgerEntryType": "Hook",
+            "OwnerNode": "0000000000000000",
+            "PreviousTxnID": "A94A9C9EE1BEB2C9B6F3DD0BB8B4F78FCAB3B6C9B3B9D2E7A0B0F8A0B6C1F2C3",
+            "PreviousTxnLgrSeq": 6716318,
+            "index": "469372BFCE6EDCD0C2BD82C1A3E8C1A24B82D5E8CE6D0C58A8BCFDDEF8B3C1F2"
+        });
+
+        let hook: Hook = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(hook.get_ledger_entry_type(), LedgerEntryType::Hook);
+        assert_eq!(
+            hook.hooks[0].hook_hash.as_deref(),
+            Some("A5B8D62154DA1C329BE13582086B52612476720CEBD097EB85CEE1455E1C70A6")
+        );
+
+        assert_eq!(serde_json::to_value(&hook).unwrap(), json);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::models::transactions::set_hook::{HookFlag, HookParameter};
+use crate::models::FlagCollection;
+use crate::models::Model;
+use crate::models::{amount::XRPAmount, ledger::objects::LedgerEntryType};
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+use serde_with::skip_serializing_none;
+
+use super::{CommonFields, LedgerObject};
+
+/// The `HookDefinition` object type holds the code of a Hook and its
+/// defaults, shared by every account that installed it. Xahau only.
+///
+/// `<https://xrpl-hooks.readme.io/docs/hookdefinition>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct HookDefinition<'a> {
+    /// The base fields for all ledger object models.
+    ///
+    /// See Ledger Object Common Fields:
+    /// `<https://xrpl.org/ledger-entry-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields<'a, HookFlag>,
+    // The custom fields for the HookDefinition model.
+    //
+    // See HookDefinition fields:
+    // `<https://xrpl-hooks.readme.io/docs/hookdefinition>`
+    /// The WebAssembly bytecode of the Hook, as hex.
+    pub create_code: Cow<'a, str>,
+    /// The fee to execute the Hook.
+    pub fee: XRPAmount<'a>,
+    pub hook_api_version: u16,
+    /// The hash of `CreateCode`.
+    pub hook_hash: Cow<'a, str>,
+    /// The default namespace of the Hook's state.
+    pub hook_namespace: Cow<'a, str>,
+    /// The default transaction types the Hook is triggered by.
+    pub hook_on: Cow<'a, str>,
+    /// The default parameters of the Hook.
+    pub hook_parameters: Option<Vec<HookParameter>>,
+    /// The transaction that installed the Hook first.
+    #[serde(rename = "HookSetTxnID")]
+    pub hook_set_txn_id: Cow<'a, str>,
+    /// The number of accounts the Hook is installed on, as hex. The
+    /// definition is deleted when it drops to zero.
+    pub reference_count: Cow<'a, str>,
+}
+
+impl<'a> Model for HookDefinition<'a> {}
+
+impl<'a> LedgerObject<HookFlag> for HookDefinition<'a> {
+    fn has_flag(&self, flag: &HookFlag) -> bool {
+        self.common_fields.has_flag(flag)
+    }
+
+    fn get_ledger_entry_type(&self) -> LedgerEntryType {
+        self.common_fields.get_ledger_entry_type()
+    }
+}
+
+impl<'a> HookDefinition<'a> {
+    pub fn new(
+        flags: FlagCollection<HookFlag>,
+        index: Option<Cow<'a, str>>,
+        ledger_index: Option<Cow<'a, str>>,
+        create_code: Cow<'a, str>,
+        fee: XRPAmount<'a>,
+        hook_api_version: u16,
+        hook_hash: Cow<'a, str>,
+        hook_namespace: Cow<'a, str>,
+        hook_on: Cow<'a, str>,
+        hook_parameters: Option<Vec<HookParameter>>,
+        hook_set_txn_id: Cow<'a, str>,
+        reference_count: Cow<'a, str>,
+    ) -> Self {
+        Self {
+            common_fields: CommonFields {
+                flags,
+                ledger_entry_type: LedgerEntryType::HookDefinition,
+                index,
+                ledger_index,
+            },
+            create_code,
+            fee,
+            hook_api_version,
+            hook_hash,
+            hook_namespace,
+            hook_on,
+            hook_parameters,
+            hook_set_txn_id,
+            reference_count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde() {
+        let json = serde_json::json!({
+            "CreateCode": "0061736D01000000011C0460057F7F7F7F7F017E60037F7F7E017E",
+            "Fee": "10",
+            "Flags": 0,
+            "HookApiVersion": 0,
+            "HookHash": "A5B8D62154DA1C329BE13582086B52612476720CEBD097EB85CEE1455E1C70A6",
+            "HookNamespace": "CAFECAFECAFECAFECAFECAFECAFECAFECAFECAFECAFECAFECAFECAFECAFECAFE",
+            "HookOn": "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFBFFFFE",
+            "HookParameters": [
+                {
+                    "HookParameter": {
+                        "HookParameterName": "414D4F554E54",
+                        "HookParameterValue": "00E1F50500000000"
+                    }
+                }
+            ],
+            "HookSetTxnID": "A94A9C9EE1BEB2C9B6F3DD0BB8B4F78FCAB3B6C9B3B9D2E7A0B0F8A0B6C1F2C3",
+            "LedgerEntryType": "HookDefinition",
+            "ReferenceCount": "1",
+            "index": "5AFF3D5E0F8F5A3C9D8F6A4AC4E7A1D1D8E6F0A1B2C3D4E5F60718293A4B5C6D"
+        });
+
+        let hook_definition: HookDefinition = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(hook_definition.fee, XRPAmount::from("10"));
+        assert!(!hook_definition.has_flag(&HookFlag::HsfCollect));
+
+        assert_eq!(serde_json::to_value(&hook_definition).unwrap(), json);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::models::FlagCollection;
+use crate::models::Model;
+use crate::models::{ledger::objects::LedgerEntryType, NoFlags};
+use alloc::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use serde_with::skip_serializing_none;
+
+use super::{CommonFields, LedgerObject};
+
+/// The `HookState` object type holds one key-value entry of a Hook's
+/// state, in the namespace of an account. Xahau only.
+///
+/// `<https://xrpl-hooks.readme.io/docs/hookstate>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct HookState<'a> {
+    /// The base fields for all ledger object models.
+    ///
+    /// See Ledger Object Common Fields:
+    /// `<https://xrpl.org/ledger-entry-common-fields.html>`
+    #[serde(flatten)]
+    pub common_fields: CommonFields<'a, NoFlags>,
+    // The custom fields for the HookState model.
+    //
+    // See HookState fields:
+    // `<https://xrpl-hooks.readme.io/docs/hookstate>`
+    /// The value, up to 256 bytes as hex.
+    pub hook_state_data: Cow<'a, str>,
+    /// The 256-bit key, as hex.
+    pub hook_state_key: Cow<'a, str>,
+    /// A hint indicating which page of the owner directory links to this object, in case the
+    /// directory consists of multiple pages.
+    pub owner_node: Cow<'a, str>,
+}
+
+impl<'a> Model for HookState<'a> {}
+
+impl<'a> LedgerObject<NoFlags> for HookState<'a> {
+    fn get_ledger_entry_type(&self) -> LedgerEntryType {
+        self.common_fields.get_ledger_entry_type()
+    }
+}
+
+impl<'a> HookState<'a> {
+    pub fn new(
+        index: Option<Cow<'a, str>>,
+        ledger_index: Option<Cow<'a, str>>,
+        hook_state_data: Cow<'a, str>,
+        hook_state_key: Cow<'a, str>,
+        owner_node: Cow<'a, str>,
+    ) -> Self {
+        Self {
+            common_fields: CommonFields {
+                flags: FlagCollection::default(),
+                ledger_entry_type: LedgerEntryType::HookState,
+                index,
+                ledger_index,
+            },
+            hook_state_data,
+            hook_state_key,
+            owner_node,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde() {
+        let hook_state = HookState::new(
+            Some(Cow::from(
+                "1D2A6E5E0C6A2C3D4E5F60718293A4B5C6D7E8F90A1B2C3D4E5F60718293A4B5",
+            )),
+            None,
+            Cow::from("0000000000000001"),
+            Cow::from("0000000000000000000000006AE5F5D8F1A8A1D2B9C1E8F7A2B3C4D5E6F70819"),
+            Cow::from("0000000000000000"),
+        );
+        let json = serde_json::json!({
+            "Flags": 0,
+            "HookStateData": "0000000000000001",
+            "HookStateKey": "0000000000000000000000006AE5F5D8F1A8A1D2B9C1E8F7A2B3C4D5E6F70819",
+            "LedgerEntryType": "HookState",
+            "OwnerNode": "0000000000000000",
+            "index": "1D2A6E5E0C6A2C3D4E5F60718293A4B5C6D7E8F90A1B2C3D4E5F60718293A4B5"
+        });
+
+        assert_eq!(serde_json::to_value(&hook_state).unwrap(), json);
+        let deserialized: HookState = serde_json::from_value(json).unwrap();
+        assert_eq!(hook_state, deserialized);
+    }
+}
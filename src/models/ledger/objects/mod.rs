@@ -7,6 +7,12 @@ pub mod deposit_preauth;
 pub mod directory_node;
 pub mod escrow;
 pub mod fee_settings;
+#[cfg(feature = "xahau")]
+pub mod hook;
+#[cfg(feature = "xahau")]
+pub mod hook_definition;
+#[cfg(feature = "xahau")]
+pub mod hook_state;
 pub mod ledger_hashes;
 pub mod negative_unl;
 pub mod nftoken_offer;
@@ -27,6 +33,12 @@ pub use deposit_preauth::*;
 pub use directory_node::*;
 pub use escrow::*;
 pub use fee_settings::*;
+#[cfg(feature = "xahau")]
+pub use hook::*;
+#[cfg(feature = "xahau")]
+pub use hook_definition::*;
+#[cfg(feature = "xahau")]
+pub use hook_state::*;
 pub use ledger_hashes::*;
 pub use negative_unl::*;
 pub use nftoken_offer::*;
@@ -40,7 +52,8 @@ use derive_new::new;
 use strum::IntoEnumIterator;
 
 use alloc::borrow::Cow;
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
 use strum_macros::Display;
 
@@ -58,6 +71,12 @@ pub enum LedgerEntryType {
     DirectoryNode = 0x0064,
     Escrow = 0x0075,
     FeeSettings = 0x0073,
+    #[cfg(feature = "xahau")]
+    Hook = 0x0048,
+    #[cfg(feature = "xahau")]
+    HookDefinition = 0x0044,
+    #[cfg(feature = "xahau")]
+    HookState = 0x0076,
     LedgerHashes = 0x0068,
     NegativeUNL = 0x004E,
     NFTokenOffer = 0x0037,
@@ -71,8 +90,162 @@ pub enum LedgerEntryType {
     XChainOwnedCreateAccountClaimID = 0x0074,
 }
 
+/// Any ledger entry, typed by its `LedgerEntryType`. Serializes to
+/// the entry itself; when deserializing, the `LedgerEntryType`
+/// field selects the variant.
+///
+/// Entries of types this library has no model for, or that fail to
+/// deserialize into theirs, are kept as JSON in `Other`.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::ledger::objects::LedgerEntry;
+///
+/// let entry: LedgerEntry = serde_json::from_value(serde_json::json!({
+///     "LedgerEntryType": "Ticket",
+///     "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+///     "Flags": 0,
+///     "OwnerNode": "0000000000000000",
+///     "PreviousTxnID": "F19AD4577212D3BEACA0F75FE1BA1644F2E854D46E8D62E9C95D18E9708CBFB1",
+///     "PreviousTxnLgrSeq": 4,
+///     "TicketSequence": 3
+/// }))
+/// .unwrap();
+///
+/// assert!(matches!(entry, LedgerEntry::Ticket(_)));
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum LedgerEntry<'a> {
+    AccountRoot(AccountRoot<'a>),
+    Amendments(Amendments<'a>),
+    AMM(AMM<'a>),
+    Bridge(bridge::Bridge<'a>),
+    Check(Check<'a>),
+    DepositPreauth(DepositPreauth<'a>),
+    DirectoryNode(DirectoryNode<'a>),
+    Escrow(Escrow<'a>),
+    FeeSettings(FeeSettings<'a>),
+    #[cfg(feature = "xahau")]
+    Hook(Hook<'a>),
+    #[cfg(feature = "xahau")]
+    HookDefinition(HookDefinition<'a>),
+    #[cfg(feature = "xahau")]
+    HookState(HookState<'a>),
+    LedgerHashes(LedgerHashes<'a>),
+    NegativeUNL(NegativeUNL<'a>),
+    NFTokenOffer(NFTokenOffer<'a>),
+    NFTokenPage(NFTokenPage<'a>),
+    Offer(Offer<'a>),
+    PayChannel(PayChannel<'a>),
+    RippleState(RippleState<'a>),
+    SignerList(signer_list::SignerList<'a>),
+    Ticket(Ticket<'a>),
+    XChainOwnedClaimID(xchain_owned_claim_id::XChainOwnedClaimID<'a>),
+    XChainOwnedCreateAccountClaimID(
+        xchain_owned_create_account_claim_id::XChainOwnedCreateAccountClaimID<'a>,
+    ),
+    Other(Value),
+}
+
+impl<'a, 'de> Deserialize<'de> for LedgerEntry<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<LedgerEntry<'a>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let ledger_entry_type = value
+            .get("LedgerEntryType")
+            .and_then(Value::as_str)
+            .ok_or_else(|| de::Error::missing_field("LedgerEntryType"))?;
+
+        let entry = match ledger_entry_type {
+            "AccountRoot" => serde_json::from_value(value.clone()).map(LedgerEntry::AccountRoot),
+            "Amendments" => serde_json::from_value(value.clone()).map(LedgerEntry::Amendments),
+            "AMM" => serde_json::from_value(value.clone()).map(LedgerEntry::AMM),
+            "Bridge" => serde_json::from_value(value.clone()).map(LedgerEntry::Bridge),
+            "Check" => serde_json::from_value(value.clone()).map(LedgerEntry::Check),
+            "DepositPreauth" => {
+                serde_json::from_value(value.clone()).map(LedgerEntry::DepositPreauth)
+            }
+            "DirectoryNode" => {
+                serde_json::from_value(value.clone()).map(LedgerEntry::DirectoryNode)
+            }
+            "Escrow" => serde_json::from_value(value.clone()).map(LedgerEntry::Escrow),
+            "FeeSettings" => serde_json::from_value(value.clone()).map(LedgerEntry::FeeSettings),
+            #[cfg(feature = "xahau")]
+            "Hook" => serde_json::from_value(value.clone()).map(LedgerEntry::Hook),
+            #[cfg(feature = "xahau")]
+            "HookDefinition" => {
+                serde_json::from_value(value.clone()).map(LedgerEntry::HookDefinition)
+            }
+            #[cfg(feature = "xahau")]
+            "HookState" => serde_json::from_value(value.clone()).map(LedgerEntry::HookState),
+            "LedgerHashes" => serde_json::from_value(value.clone()).map(LedgerEntry::LedgerHashes),
+            "NegativeUNL" => serde_json::from_value(value.clone()).map(LedgerEntry::NegativeUNL),
+            "NFTokenOffer" => serde_json::from_value(value.clone()).map(LedgerEntry::NFTokenOffer),
+            "NFTokenPage" => serde_json::from_value(value.clone()).map(LedgerEntry::NFTokenPage),
+            "Offer" => serde_json::from_value(value.clone()).map(LedgerEntry::Offer),
+            "PayChannel" => serde_json::from_value(value.clone()).map(LedgerEntry::PayChannel),
+            "RippleState" => serde_json::from_value(value.clone()).map(LedgerEntry::RippleState),
+            "SignerList" => serde_json::from_value(value.clone()).map(LedgerEntry::SignerList),
+            "Ticket" => serde_json::from_value(value.clone()).map(LedgerEntry::Ticket),
+            "XChainOwnedClaimID" => {
+                serde_json::from_value(value.clone()).map(LedgerEntry::XChainOwnedClaimID)
+            }
+            "XChainOwnedCreateAccountClaimID" => serde_json::from_value(value.clone())
+                .map(LedgerEntry::XChainOwnedCreateAccountClaimID),
+            _ => return Ok(LedgerEntry::Other(value)),
+        };
+
+        Ok(entry.unwrap_or(LedgerEntry::Other(value)))
+    }
+}
+
+impl<'a> LedgerEntry<'a> {
+    /// The type of the entry, `None` for entries kept as JSON.
+    pub fn get_ledger_entry_type(&self) -> Option<LedgerEntryType> {
+        let ledger_entry_type = match self {
+            LedgerEntry::AccountRoot(_) => LedgerEntryType::AccountRoot,
+            LedgerEntry::Amendments(_) => LedgerEntryType::Amendments,
+            LedgerEntry::AMM(_) => LedgerEntryType::AMM,
+            LedgerEntry::Bridge(_) => LedgerEntryType::Bridge,
+            LedgerEntry::Check(_) => LedgerEntryType::Check,
+            LedgerEntry::DepositPreauth(_) => LedgerEntryType::DepositPreauth,
+            LedgerEntry::DirectoryNode(_) => LedgerEntryType::DirectoryNode,
+            LedgerEntry::Escrow(_) => LedgerEntryType::Escrow,
+            LedgerEntry::FeeSettings(_) => LedgerEntryType::FeeSettings,
+            #[cfg(feature = "xahau")]
+            LedgerEntry::Hook(_) => LedgerEntryType::Hook,
+            #[cfg(feature = "xahau")]
+            LedgerEntry::HookDefinition(_) => LedgerEntryType::HookDefinition,
+            #[cfg(feature = "xahau")]
+            LedgerEntry::HookState(_) => LedgerEntryType::HookState,
+            LedgerEntry::LedgerHashes(_) => LedgerEntryType::LedgerHashes,
+            LedgerEntry::NegativeUNL(_) => LedgerEntryType::NegativeUNL,
+            LedgerEntry::NFTokenOffer(_) => LedgerEntryType::NFTokenOffer,
+            LedgerEntry::NFTokenPage(_) => LedgerEntryType::NFTokenPage,
+            LedgerEntry::Offer(_) => LedgerEntryType::Offer,
+            LedgerEntry::PayChannel(_) => LedgerEntryType::PayChannel,
+            LedgerEntry::RippleState(_) => LedgerEntryType::RippleState,
+            LedgerEntry::SignerList(_) => LedgerEntryType::SignerList,
+            LedgerEntry::Ticket(_) => LedgerEntryType::Ticket,
+            LedgerEntry::XChainOwnedClaimID(_) => LedgerEntryType::XChainOwnedClaimID,
+            LedgerEntry::XChainOwnedCreateAccountClaimID(_) => {
+                LedgerEntryType::XChainOwnedCreateAccountClaimID
+            }
+            LedgerEntry::Other(_) => return None,
+        };
+
+        Some(ledger_entry_type)
+    }
+}
+
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "PascalCase")]
 pub struct XChainClaimProofSig<'a> {
     pub amount: Amount<'a>,
@@ -113,7 +286,7 @@ where
     T: IntoEnumIterator + Serialize + PartialEq + core::fmt::Debug,
 {
     fn has_flag(&self, flag: &T) -> bool {
-        self.flags.0.contains(flag)
+        self.flags.contains(flag)
     }
 
     fn get_ledger_entry_type(&self) -> LedgerEntryType {
@@ -1,4 +1,5 @@
 use crate::models::ledger::objects::LedgerEntryType;
+use crate::models::ripple_time::ripple_time_accessors;
 use crate::models::FlagCollection;
 use crate::models::{amount::Amount, Model};
 use alloc::borrow::Cow;
@@ -74,6 +75,10 @@ pub struct NFTokenOffer<'a> {
 impl<'a> Model for NFTokenOffer<'a> {}
 
 impl<'a> LedgerObject<NFTokenOfferFlag> for NFTokenOffer<'a> {
+    fn has_flag(&self, flag: &NFTokenOfferFlag) -> bool {
+        self.common_fields.has_flag(flag)
+    }
+
     fn get_ledger_entry_type(&self) -> LedgerEntryType {
         self.common_fields.get_ledger_entry_type()
     }
@@ -114,6 +119,10 @@ impl<'a> NFTokenOffer<'a> {
     }
 }
 
+ripple_time_accessors!(NFTokenOffer {
+    expiration => expiration_datetime;
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
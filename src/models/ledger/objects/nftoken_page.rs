@@ -10,13 +10,44 @@ use serde_with::skip_serializing_none;
 
 use super::{CommonFields, LedgerObject};
 
+/// An NFToken held in an `NFTokenPage`. In the ledger, each one is
+/// wrapped in an object: `{"NFToken": {"NFTokenID": ..., "URI": ...}}`.
+///
+/// `<https://xrpl.org/nftokenpage.html#nftokens>`
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, new, Default)]
-#[serde(rename_all = "PascalCase")]
+#[serde(from = "WrappedNFToken<'a>", into = "WrappedNFToken<'a>")]
 pub struct NFToken<'a> {
-    #[serde(rename = "NFTokenID")]
-    nftoken_id: Cow<'a, str>,
-    #[serde(rename = "URI")]
-    uri: Cow<'a, str>,
+    /// The identifier of the NFToken.
+    pub nftoken_id: Cow<'a, str>,
+    /// The URI the NFToken points to, if it has one.
+    pub uri: Option<Cow<'a, str>>,
+}
+
+#[derive(Serialize, Deserialize)]
+enum WrappedNFToken<'a> {
+    NFToken {
+        #[serde(rename = "NFTokenID")]
+        nftoken_id: Cow<'a, str>,
+        #[serde(rename = "URI", skip_serializing_if = "Option::is_none")]
+        uri: Option<Cow<'a, str>>,
+    },
+}
+
+impl<'a> From<WrappedNFToken<'a>> for NFToken<'a> {
+    fn from(wrapped: WrappedNFToken<'a>) -> Self {
+        let WrappedNFToken::NFToken { nftoken_id, uri } = wrapped;
+
+        Self { nftoken_id, uri }
+    }
+}
+
+impl<'a> From<NFToken<'a>> for WrappedNFToken<'a> {
+    fn from(nftoken: NFToken<'a>) -> Self {
+        WrappedNFToken::NFToken {
+            nftoken_id: nftoken.nftoken_id,
+            uri: nftoken.uri,
+        }
+    }
 }
 
 /// The `NFTokenPage` object represents a collection of `NFToken` objects owned by the same account.
@@ -103,7 +134,7 @@ mod tests {
             Some(Cow::from("598EDFD7CF73460FB8C695d6a9397E9073781BA3B78198904F659AAA252A")),
             vec![NFToken::new(
                 Cow::from("000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65"),
-                Cow::from("697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C67747179353566627A6469")
+                Some(Cow::from("697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C67747179353566627A6469")),
             )],
             Some(Cow::from("598EDFD7CF73460FB8C695d6a9397E907378C8A841F7204C793DCBEF5406")),
             Some(Cow::from("95C8761B22894E328646F7A70035E9DFBECC90EDD83E43B7B973F626D21A0822")),
@@ -115,4 +146,39 @@ mod tests {
 
         assert_eq!(nftoken_page, deserialized);
     }
+
+    #[test]
+    fn test_deserialize_ledger_json() {
+        let nftoken_page: NFTokenPage = serde_json::from_value(serde_json::json!({
+            "Flags": 0,
+            "LedgerEntryType": "NFTokenPage",
+            "NFTokens": [
+                {
+                    "NFToken": {
+                        "NFTokenID": "000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65",
+                        "URI": "697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C67747179353566627A6469"
+                    }
+                },
+                {
+                    "NFToken": {
+                        "NFTokenID": "000B013A95F14B0044F78A264E41713C64B5F89242540EE2C3098E0000000D66"
+                    }
+                }
+            ],
+            "index": "598EDFD7CF73460FB8C695D6A9397E907378C8A841F7204C793DCBEF5406"
+        }))
+        .unwrap();
+
+        assert_eq!(nftoken_page.nftokens.len(), 2);
+        assert!(nftoken_page.nftokens[0].uri.is_some());
+        assert_eq!(nftoken_page.nftokens[1].uri, None);
+        assert_eq!(
+            serde_json::to_value(&nftoken_page.nftokens[1]).unwrap(),
+            serde_json::json!({
+                "NFToken": {
+                    "NFTokenID": "000B013A95F14B0044F78A264E41713C64B5F89242540EE2C3098E0000000D66"
+                }
+            })
+        );
+    }
 }
@@ -1,4 +1,5 @@
 use crate::models::ledger::objects::LedgerEntryType;
+use crate::models::ripple_time::ripple_time_accessors;
 use crate::models::FlagCollection;
 use crate::models::{amount::Amount, Model};
 use alloc::borrow::Cow;
@@ -72,6 +73,10 @@ pub struct Offer<'a> {
 impl<'a> Model for Offer<'a> {}
 
 impl<'a> LedgerObject<OfferFlag> for Offer<'a> {
+    fn has_flag(&self, flag: &OfferFlag) -> bool {
+        self.common_fields.has_flag(flag)
+    }
+
     fn get_ledger_entry_type(&self) -> LedgerEntryType {
         self.common_fields.get_ledger_entry_type()
     }
@@ -114,6 +119,10 @@ impl<'a> Offer<'a> {
     }
 }
 
+ripple_time_accessors!(Offer {
+    expiration => expiration_datetime;
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,5 @@
 use crate::models::ledger::objects::LedgerEntryType;
+use crate::models::ripple_time::ripple_time_accessors;
 use crate::models::FlagCollection;
 use crate::models::NoFlags;
 use crate::models::{amount::Amount, Model};
@@ -122,6 +123,11 @@ impl<'a> PayChannel<'a> {
     }
 }
 
+ripple_time_accessors!(PayChannel {
+    cancel_after => cancel_after_datetime;
+    expiration => expiration_datetime;
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -34,6 +34,14 @@ pub enum RippleStateFlag {
     /// The high account has frozen the trust line, preventing the low account from
     /// transferring the asset.
     LsfHighFreeze = 0x00800000,
+    /// This trust line holds the tokens of an Automated Market Maker.
+    LsfAmmNode = 0x01000000,
+    /// The low account has deep frozen the trust line, preventing the high account from
+    /// sending and receiving the asset.
+    LsfLowDeepFreeze = 0x02000000,
+    /// The high account has deep frozen the trust line, preventing the low account from
+    /// sending and receiving the asset.
+    LsfHighDeepFreeze = 0x04000000,
 }
 
 /// The RippleState object type connects two accounts in a single currency. Conceptually,
@@ -92,6 +100,10 @@ pub struct RippleState<'a> {
 impl<'a> Model for RippleState<'a> {}
 
 impl<'a> LedgerObject<RippleStateFlag> for RippleState<'a> {
+    fn has_flag(&self, flag: &RippleStateFlag) -> bool {
+        self.common_fields.has_flag(flag)
+    }
+
     fn get_ledger_entry_type(&self) -> LedgerEntryType {
         self.common_fields.get_ledger_entry_type()
     }
@@ -180,4 +192,41 @@ mod tests {
 
         assert_eq!(ripple_state, deserialized);
     }
+
+    #[test]
+    fn test_flags() {
+        let ripple_state: RippleState = serde_json::from_value(serde_json::json!({
+            "Balance": {
+                "currency": "USD",
+                "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji",
+                "value": "-10"
+            },
+            "Flags": 0x01030000,
+            "HighLimit": {
+                "currency": "USD",
+                "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "value": "110"
+            },
+            "HighNode": "0000000000000000",
+            "LedgerEntryType": "RippleState",
+            "LowLimit": {
+                "currency": "USD",
+                "issuer": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+                "value": "0"
+            },
+            "LowNode": "0000000000000000",
+            "PreviousTxnID": "E3FE6EA3D48F0C2B639448020EA4F03D4F4F8FFDB243A852A0F59177921B4879",
+            "PreviousTxnLgrSeq": 14090896,
+            "index": "9CA88CDEDFF9252B3DE183CE35B038F57282BC9503CDFA1923EF9A95DF0D6F7B"
+        }))
+        .unwrap();
+
+        assert!(ripple_state.has_flag(&RippleStateFlag::LsfLowReserve));
+        assert!(ripple_state.has_flag(&RippleStateFlag::LsfHighReserve));
+        assert!(ripple_state.has_flag(&RippleStateFlag::LsfAmmNode));
+        assert!(!ripple_state.has_flag(&RippleStateFlag::LsfLowFreeze));
+
+        let serialized = serde_json::to_value(&ripple_state).unwrap();
+        assert_eq!(serialized["Flags"], 0x01030000);
+    }
 }
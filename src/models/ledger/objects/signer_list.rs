@@ -81,6 +81,10 @@ pub struct SignerList<'a> {
 impl<'a> Model for SignerList<'a> {}
 
 impl<'a> LedgerObject<SignerListFlag> for SignerList<'a> {
+    fn has_flag(&self, flag: &SignerListFlag) -> bool {
+        self.common_fields.has_flag(flag)
+    }
+
     fn get_ledger_entry_type(&self) -> LedgerEntryType {
         self.common_fields.get_ledger_entry_type()
     }
@@ -146,4 +150,32 @@ mod tests {
 
         assert_eq!(signer_list, deserialized);
     }
+
+    #[test]
+    fn test_flags() {
+        let signer_list: SignerList = serde_json::from_value(serde_json::json!({
+            "Flags": 0x00010000,
+            "LedgerEntryType": "SignerList",
+            "OwnerNode": "0000000000000000",
+            "PreviousTxnID": "5904C0DC72C58A83AEFED2FFC5386356AA83FCA6A88C89D00646E51E687CDBE4",
+            "PreviousTxnLgrSeq": 16061435,
+            "SignerEntries": [
+                {
+                    "SignerEntry": {
+                        "Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+                        "SignerWeight": 2
+                    }
+                }
+            ],
+            "SignerListID": 0,
+            "SignerQuorum": 2,
+            "index": "A9C28A28B85CD533217F5C0A0C7767666B093FA58A0F2D80026FCC4CD932DDC7"
+        }))
+        .unwrap();
+
+        assert!(signer_list.has_flag(&SignerListFlag::LsfOneOwnerCount));
+
+        let serialized = serde_json::to_value(&signer_list).unwrap();
+        assert_eq!(serialized["Flags"], 0x00010000);
+    }
 }
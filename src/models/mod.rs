@@ -7,6 +7,8 @@
 //! 4. Required specific fields in alphabetical order
 //! 5. Optional specific fields in alphabetical order
 
+#[cfg(feature = "models")]
+pub mod describe;
 #[cfg(feature = "models")]
 #[allow(clippy::too_many_arguments)]
 pub mod ledger;
@@ -25,29 +27,51 @@ mod currency;
 mod exceptions;
 mod flag_collection;
 mod model;
+#[cfg(feature = "models")]
+mod ripple_time;
+#[cfg(feature = "json-schema")]
+mod schema;
 
 pub use amount::*;
 pub use currency::*;
 pub use exceptions::*;
 pub use flag_collection::*;
 pub use model::*;
+#[cfg(feature = "json-schema")]
+pub use schema::*;
 
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, vec::Vec};
 use derive_new::new;
-use serde::{Deserialize, Serialize};
+use serde::{ser::SerializeMap, Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 /// A PathStep represents an individual step along a Path.
+///
+/// See Paths:
+/// `<https://xrpl.org/paths.html#path-specifications>`
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, new)]
-#[serde(rename_all = "PascalCase")]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct PathStep<'a> {
-    account: Option<Cow<'a, str>>,
-    currency: Option<Cow<'a, str>>,
-    issuer: Option<Cow<'a, str>>,
-    r#type: Option<u8>,
-    type_hex: Option<Cow<'a, str>>,
+    /// Rippling through this account.
+    pub account: Option<Cow<'a, str>>,
+    /// Converting to this currency, through an order book.
+    pub currency: Option<Cow<'a, str>>,
+    /// The issuer of the currency converted to.
+    pub issuer: Option<Cow<'a, str>>,
+    /// Which of the fields above are set. Informational, as returned
+    /// by the server.
+    pub r#type: Option<u8>,
+    /// `type` as a hex string. Informational, as returned by the
+    /// server.
+    pub type_hex: Option<Cow<'a, str>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, derive_new::new)]
+/// A path a payment can take, as a list of steps.
+pub type Path<'a> = Vec<PathStep<'a>>;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, derive_new::new)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct XChainBridge<'a> {
     pub issuing_chain_door: Cow<'a, str>,
@@ -56,6 +80,20 @@ pub struct XChainBridge<'a> {
     pub locking_chain_issue: Currency<'a>,
 }
 
+crate::serde_with_tag! {
+    /// A credential, by its issuer and type, e.g. in the
+    /// credentials preauthorized to send payments to an account.
+    ///
+    /// See Credentials:
+    /// `<https://xrpl.org/docs/concepts/decentralized-storage/credentials>`
+    #[derive(Debug, PartialEq, Eq, Default, Clone, new)]
+    pub struct Credential<'a> {
+        pub issuer: Cow<'a, str>,
+        /// The type of the credential, as hex.
+        pub credential_type: Cow<'a, str>,
+    }
+}
+
 /// For use with serde defaults.
 fn default_false() -> Option<bool> {
     Some(false)
@@ -1,10 +1,11 @@
 use alloc::borrow::Cow;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
 
 use crate::models::{requests::RequestMethod, Model};
 
-use super::{CommonFields, Request};
+use super::{builder::request_builder, CommonFields, Request};
 
 /// This request returns information about an account's Payment
 /// Channels. This includes only channels where the specified
@@ -30,10 +31,49 @@ use super::{CommonFields, Request};
 ///     Err(_) => None,
 /// };
 ///
-/// assert_eq!(revert, Some(json));
+/// // Only the parameters are serialized, the client adds the command.
+/// let params = r#"{"account":"rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr","marker":12345678}"#.to_string();
+/// assert_eq!(revert, Some(params));
+/// ```
+///
+/// ## With a builder
+///
+/// ```
+/// use xrpl::models::requests::account_channels::AccountChannels;
+/// use xrpl::models::requests::RequestMethod;
+///
+/// let request = AccountChannels::builder()
+///     .account("rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr")
+///     .limit(200)
+///     .build();
+///
+/// assert_eq!(request.common_fields.command, RequestMethod::AccountChannels);
+/// assert_eq!(request.limit, Some(200));
+/// assert_eq!(
+///     request,
+///     AccountChannels::new(
+///         None,
+///         "rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr".into(),
+///         None,
+///         None,
+///         None,
+///         Some(200),
+///         None,
+///     )
+/// );
+/// ```
+///
+/// The account is required, so a request without it does not
+/// compile:
+///
+/// ```compile_fail
+/// use xrpl::models::requests::account_channels::AccountChannels;
+///
+/// let request = AccountChannels::builder().limit(200).build();
 /// ```
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AccountChannels<'a> {
     /// Common fields shared by all requests.
     #[serde(flatten)]
@@ -55,8 +95,9 @@ pub struct AccountChannels<'a> {
     /// payment channels whose destination is this account.
     pub destination_account: Option<Cow<'a, str>>,
     /// Value from a previous paginated response.
-    /// Resume retrieving data where that response left off.
-    pub marker: Option<u32>,
+    /// Resume retrieving data where that response left off. The
+    /// marker is opaque, pass it back as the server returned it.
+    pub marker: Option<Value>,
 }
 
 impl<'a> Model for AccountChannels<'a> {}
@@ -69,12 +110,13 @@ impl<'a> AccountChannels<'a> {
         ledger_hash: Option<Cow<'a, str>>,
         ledger_index: Option<Cow<'a, str>>,
         limit: Option<u16>,
-        marker: Option<u32>,
+        marker: Option<Value>,
     ) -> Self {
         Self {
             common_fields: CommonFields {
                 command: RequestMethod::AccountChannels,
                 id,
+                api_version: None,
             },
             account,
             ledger_hash,
@@ -86,6 +128,14 @@ impl<'a> AccountChannels<'a> {
     }
 }
 
+request_builder! {
+    AccountChannels => AccountChannelsBuilder {
+        required account;
+        strings destination_account, ledger_hash, ledger_index;
+        values limit: u16, marker: Value;
+    }
+}
+
 impl<'a> Request<'a> for AccountChannels<'a> {
     fn get_common_fields(&self) -> &CommonFields<'a> {
         &self.common_fields
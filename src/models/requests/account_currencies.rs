@@ -4,7 +4,7 @@ use serde_with::skip_serializing_none;
 
 use crate::models::{default_false, requests::RequestMethod, Model};
 
-use super::{CommonFields, Request};
+use super::{builder::request_builder, CommonFields, Request};
 
 /// This request retrieves a list of currencies that an account
 /// can send or receive, based on its trust lines. This is not
@@ -13,8 +13,37 @@ use super::{CommonFields, Request};
 ///
 /// See Account Currencies:
 /// `<https://xrpl.org/account_currencies.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::requests::account_currencies::AccountCurrencies;
+/// use xrpl::models::requests::RequestMethod;
+///
+/// let request = AccountCurrencies::builder()
+///     .account("rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr")
+///     .ledger_index("validated")
+///     .strict(true)
+///     .build();
+///
+/// assert_eq!(request.common_fields.command, RequestMethod::AccountCurrencies);
+/// assert_eq!(request.strict, Some(true));
+/// assert_eq!(
+///     request,
+///     AccountCurrencies::new(
+///         None,
+///         "rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr".into(),
+///         None,
+///         Some("validated".into()),
+///         Some(true),
+///     )
+/// );
+/// ```
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AccountCurrencies<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -37,6 +66,14 @@ pub struct AccountCurrencies<'a> {
 
 impl<'a> Model for AccountCurrencies<'a> {}
 
+request_builder! {
+    AccountCurrencies => AccountCurrenciesBuilder {
+        required account;
+        strings ledger_hash, ledger_index;
+        values strict: bool;
+    }
+}
+
 impl<'a> Request<'a> for AccountCurrencies<'a> {
     fn get_common_fields(&self) -> &CommonFields<'a> {
         &self.common_fields
@@ -59,6 +96,7 @@ impl<'a> AccountCurrencies<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::AccountCurrencies,
                 id,
+                api_version: None,
             },
             account,
             ledger_hash,
@@ -4,7 +4,7 @@ use serde_with::skip_serializing_none;
 
 use crate::models::{requests::RequestMethod, Model};
 
-use super::{CommonFields, Request};
+use super::{builder::request_builder, CommonFields, Request};
 
 /// This request retrieves information about an account, its
 /// activity, and its XRP balance. All information retrieved
@@ -12,8 +12,40 @@ use super::{CommonFields, Request};
 ///
 /// See Account Info:
 /// `<https://xrpl.org/account_info.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::requests::account_info::AccountInfo;
+/// use xrpl::models::requests::RequestMethod;
+///
+/// let request = AccountInfo::builder()
+///     .id("1")
+///     .account("rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr")
+///     .ledger_index("validated")
+///     .signer_lists(true)
+///     .build();
+///
+/// assert_eq!(request.common_fields.command, RequestMethod::AccountInfo);
+/// assert_eq!(request.signer_lists, Some(true));
+/// assert_eq!(
+///     request,
+///     AccountInfo::new(
+///         Some("1".into()),
+///         "rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr".into(),
+///         None,
+///         Some("validated".into()),
+///         None,
+///         None,
+///         Some(true),
+///     )
+/// );
+/// ```
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AccountInfo<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -44,6 +76,14 @@ pub struct AccountInfo<'a> {
 
 impl<'a> Model for AccountInfo<'a> {}
 
+request_builder! {
+    AccountInfo => AccountInfoBuilder {
+        required account;
+        strings ledger_hash, ledger_index;
+        values strict: bool, queue: bool, signer_lists: bool;
+    }
+}
+
 impl<'a> Request<'a> for AccountInfo<'a> {
     fn get_common_fields(&self) -> &CommonFields<'a> {
         &self.common_fields
@@ -68,6 +108,7 @@ impl<'a> AccountInfo<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::AccountInfo,
                 id,
+                api_version: None,
             },
             account,
             ledger_hash,
@@ -1,10 +1,11 @@
 use alloc::borrow::Cow;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
 
 use crate::models::{requests::RequestMethod, Model};
 
-use super::{CommonFields, Request};
+use super::{builder::request_builder, CommonFields, Request};
 
 /// This request returns information about an account's trust
 /// lines, including balances in all non-XRP currencies and
@@ -13,8 +14,39 @@ use super::{CommonFields, Request};
 ///
 /// See Account Lines:
 /// `<https://xrpl.org/account_lines.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::requests::account_lines::AccountLines;
+/// use xrpl::models::requests::RequestMethod;
+///
+/// let request = AccountLines::builder()
+///     .account("rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr")
+///     .peer("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe")
+///     .limit(400)
+///     .build();
+///
+/// assert_eq!(request.common_fields.command, RequestMethod::AccountLines);
+/// assert_eq!(request.limit, Some(400));
+/// assert_eq!(
+///     request,
+///     AccountLines::new(
+///         None,
+///         "rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr".into(),
+///         None,
+///         None,
+///         Some(400),
+///         Some("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".into()),
+///         None,
+///     )
+/// );
+/// ```
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AccountLines<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -34,10 +66,22 @@ pub struct AccountLines<'a> {
     /// The Address of a second account. If provided, show only
     /// lines of trust connecting the two accounts.
     pub peer: Option<Cow<'a, str>>,
+    /// Value from a previous paginated response.
+    /// Resume retrieving data where that response left off. The
+    /// marker is opaque, pass it back as the server returned it.
+    pub marker: Option<Value>,
 }
 
 impl<'a> Model for AccountLines<'a> {}
 
+request_builder! {
+    AccountLines => AccountLinesBuilder {
+        required account;
+        strings ledger_hash, ledger_index, peer;
+        values limit: u16, marker: Value;
+    }
+}
+
 impl<'a> Request<'a> for AccountLines<'a> {
     fn get_common_fields(&self) -> &CommonFields<'a> {
         &self.common_fields
@@ -56,17 +100,20 @@ impl<'a> AccountLines<'a> {
         ledger_index: Option<Cow<'a, str>>,
         limit: Option<u16>,
         peer: Option<Cow<'a, str>>,
+        marker: Option<Value>,
     ) -> Self {
         Self {
             common_fields: CommonFields {
                 command: RequestMethod::AccountLines,
                 id,
+                api_version: None,
             },
             account,
             ledger_hash,
             ledger_index,
             limit,
             peer,
+            marker,
         }
     }
 }
@@ -1,15 +1,45 @@
 use alloc::borrow::Cow;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
 
 use crate::models::{requests::RequestMethod, Model};
 
-use super::{CommonFields, Request};
+use super::{builder::request_builder, CommonFields, Request};
 
 /// This method retrieves all of the NFTs currently owned
 /// by the specified account.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::requests::account_nfts::AccountNfts;
+/// use xrpl::models::requests::RequestMethod;
+///
+/// let request = AccountNfts::builder()
+///     .account("rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr")
+///     .limit(100)
+///     .build();
+///
+/// assert_eq!(request.common_fields.command, RequestMethod::AccountNfts);
+/// assert_eq!(request.limit, Some(100));
+/// assert_eq!(
+///     request,
+///     AccountNfts::new(
+///         None,
+///         "rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr".into(),
+///         None,
+///         None,
+///         Some(100),
+///         None,
+///     )
+/// );
+/// ```
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AccountNfts<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -18,17 +48,31 @@ pub struct AccountNfts<'a> {
     /// account's Address. The request returns a list of
     /// NFTs owned by this account.
     pub account: Cow<'a, str>,
+    /// A 20-byte hex string for the ledger version to use.
+    pub ledger_hash: Option<Cow<'a, str>>,
+    /// The ledger index of the ledger to use, or a shortcut
+    /// string to choose a ledger automatically.
+    pub ledger_index: Option<Cow<'a, str>>,
     /// Limit the number of token pages to retrieve. Each page
     /// can contain up to 32 NFTs. The limit value cannot be
     /// lower than 20 or more than 400. The default is 100.
     pub limit: Option<u32>,
     /// Value from a previous paginated response. Resume
-    /// retrieving data where that response left off.
-    pub marker: Option<u32>,
+    /// retrieving data where that response left off. The
+    /// marker is opaque, pass it back as the server returned it.
+    pub marker: Option<Value>,
 }
 
 impl<'a> Model for AccountNfts<'a> {}
 
+request_builder! {
+    AccountNfts => AccountNftsBuilder {
+        required account;
+        strings ledger_hash, ledger_index;
+        values limit: u32, marker: Value;
+    }
+}
+
 impl<'a> Request<'a> for AccountNfts<'a> {
     fn get_common_fields(&self) -> &CommonFields<'a> {
         &self.common_fields
@@ -43,15 +87,20 @@ impl<'a> AccountNfts<'a> {
     pub fn new(
         id: Option<Cow<'a, str>>,
         account: Cow<'a, str>,
+        ledger_hash: Option<Cow<'a, str>>,
+        ledger_index: Option<Cow<'a, str>>,
         limit: Option<u32>,
-        marker: Option<u32>,
+        marker: Option<Value>,
     ) -> Self {
         Self {
             common_fields: CommonFields {
                 command: RequestMethod::AccountNfts,
                 id,
+                api_version: None,
             },
             account,
+            ledger_hash,
+            ledger_index,
             limit,
             marker,
         }
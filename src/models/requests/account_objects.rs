@@ -1,15 +1,17 @@
 use alloc::borrow::Cow;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
 use strum_macros::Display;
 
 use crate::models::{requests::RequestMethod, Model};
 
-use super::{CommonFields, Request};
+use super::{builder::request_builder, CommonFields, Request};
 
 /// Represents the object types that an AccountObjects
 /// Request can ask for.
 #[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Display)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "type")]
@@ -30,8 +32,40 @@ pub enum AccountObjectType {
 ///
 /// See Account Objects:
 /// `<https://xrpl.org/account_objects.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::requests::account_objects::{AccountObjectType, AccountObjects};
+/// use xrpl::models::requests::RequestMethod;
+///
+/// let request = AccountObjects::builder()
+///     .account("rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr")
+///     .r#type(AccountObjectType::Offer)
+///     .limit(10)
+///     .build();
+///
+/// assert_eq!(request.common_fields.command, RequestMethod::AccountObjects);
+/// assert_eq!(request.limit, Some(10));
+/// assert_eq!(
+///     request,
+///     AccountObjects::new(
+///         None,
+///         "rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr".into(),
+///         None,
+///         None,
+///         Some(AccountObjectType::Offer),
+///         None,
+///         Some(10),
+///         None,
+///     )
+/// );
+/// ```
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AccountObjects<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -57,12 +91,21 @@ pub struct AccountObjects<'a> {
     /// connections. The default is 200.
     pub limit: Option<u16>,
     /// Value from a previous paginated response. Resume retrieving
-    /// data where that response left off.
-    pub marker: Option<u32>,
+    /// data where that response left off. The marker is opaque, pass
+    /// it back as the server returned it.
+    pub marker: Option<Value>,
 }
 
 impl<'a> Model for AccountObjects<'a> {}
 
+request_builder! {
+    AccountObjects => AccountObjectsBuilder {
+        required account;
+        strings ledger_hash, ledger_index;
+        values r#type: AccountObjectType, deletion_blockers_only: bool, limit: u16, marker: Value;
+    }
+}
+
 impl<'a> Request<'a> for AccountObjects<'a> {
     fn get_common_fields(&self) -> &CommonFields<'a> {
         &self.common_fields
@@ -82,12 +125,13 @@ impl<'a> AccountObjects<'a> {
         r#type: Option<AccountObjectType>,
         deletion_blockers_only: Option<bool>,
         limit: Option<u16>,
-        marker: Option<u32>,
+        marker: Option<Value>,
     ) -> Self {
         Self {
             common_fields: CommonFields {
                 command: RequestMethod::AccountObjects,
                 id,
+                api_version: None,
             },
             account,
             ledger_hash,
@@ -4,15 +4,45 @@ use serde_with::skip_serializing_none;
 
 use crate::models::{requests::RequestMethod, Model};
 
-use super::{CommonFields, Request};
+use super::{builder::request_builder, CommonFields, Request};
 
 /// This request retrieves a list of offers made by a given account
 /// that are outstanding as of a particular ledger version.
 ///
 /// See Account Offers:
 /// `<https://xrpl.org/account_offers.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::requests::account_offers::AccountOffers;
+/// use xrpl::models::requests::RequestMethod;
+///
+/// let request = AccountOffers::builder()
+///     .account("rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr")
+///     .ledger_index("validated")
+///     .build();
+///
+/// assert_eq!(request.common_fields.command, RequestMethod::AccountOffers);
+/// assert_eq!(request.ledger_index, Some("validated".into()));
+/// assert_eq!(
+///     request,
+///     AccountOffers::new(
+///         None,
+///         "rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr".into(),
+///         None,
+///         Some("validated".into()),
+///         None,
+///         None,
+///         None,
+///     )
+/// );
+/// ```
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AccountOffers<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -40,6 +70,14 @@ pub struct AccountOffers<'a> {
 
 impl<'a> Model for AccountOffers<'a> {}
 
+request_builder! {
+    AccountOffers => AccountOffersBuilder {
+        required account;
+        strings ledger_hash, ledger_index;
+        values limit: u16, strict: bool, marker: u32;
+    }
+}
+
 impl<'a> Request<'a> for AccountOffers<'a> {
     fn get_common_fields(&self) -> &CommonFields<'a> {
         &self.common_fields
@@ -64,6 +102,7 @@ impl<'a> AccountOffers<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::AccountOffers,
                 id,
+                api_version: None,
             },
             account,
             ledger_hash,
@@ -1,18 +1,53 @@
 use alloc::borrow::Cow;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
 
 use crate::models::{requests::RequestMethod, Model};
 
-use super::{CommonFields, Request};
+use super::{builder::request_builder, CommonFields, Request};
 
 /// This request retrieves from the ledger a list of
 /// transactions that involved the specified account.
 ///
 /// See Account Tx:
 /// `<https://xrpl.org/account_tx.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::requests::account_tx::AccountTx;
+/// use xrpl::models::requests::RequestMethod;
+///
+/// let request = AccountTx::builder()
+///     .account("rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr")
+///     .forward(true)
+///     .limit(20)
+///     .build();
+///
+/// assert_eq!(request.common_fields.command, RequestMethod::AccountTx);
+/// assert_eq!(request.limit, Some(20));
+/// assert_eq!(
+///     request,
+///     AccountTx::new(
+///         None,
+///         "rH6ZiHU1PGamME2LvVTxrgvfjQpppWKGmr".into(),
+///         None,
+///         None,
+///         None,
+///         Some(true),
+///         None,
+///         None,
+///         Some(20),
+///         None,
+///     )
+/// );
+/// ```
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AccountTx<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -48,11 +83,19 @@ pub struct AccountTx<'a> {
     /// data where that response left off. This value is stable even
     /// if there is a change in the server's range of available
     /// ledgers.
-    pub marker: Option<u32>,
+    pub marker: Option<Value>,
 }
 
 impl<'a> Model for AccountTx<'a> {}
 
+request_builder! {
+    AccountTx => AccountTxBuilder {
+        required account;
+        strings ledger_hash, ledger_index;
+        values binary: bool, forward: bool, ledger_index_min: u32, ledger_index_max: u32, limit: u16, marker: Value;
+    }
+}
+
 impl<'a> Request<'a> for AccountTx<'a> {
     fn get_common_fields(&self) -> &CommonFields<'a> {
         &self.common_fields
@@ -74,12 +117,13 @@ impl<'a> AccountTx<'a> {
         ledger_index_min: Option<u32>,
         ledger_index_max: Option<u32>,
         limit: Option<u16>,
-        marker: Option<u32>,
+        marker: Option<Value>,
     ) -> Self {
         Self {
             common_fields: CommonFields {
                 command: RequestMethod::AccountTx,
                 id,
+                api_version: None,
             },
             account,
             ledger_hash,
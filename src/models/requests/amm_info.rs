@@ -8,6 +8,7 @@ use super::{CommonFields, Request};
 
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct AMMInfo<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -40,6 +41,7 @@ impl<'a> AMMInfo<'a> {
             common_fields: CommonFields {
                 command: super::RequestMethod::AMMInfo,
                 id,
+                api_version: None,
             },
             amm_account,
             asset,
@@ -13,6 +13,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/book_offers.html#book_offers>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct BookOffers<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -70,6 +71,7 @@ impl<'a> BookOffers<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::BookOffers,
                 id,
+                api_version: None,
             },
             taker_gets,
             taker_pays,
@@ -98,10 +100,66 @@ mod test {
             None,
             None,
         );
-        let serialized = serde_json::to_string(&req).unwrap();
+        let mut serialized = serde_json::to_value(&req).unwrap();
+        assert!(serialized.get("command").is_none());
 
-        let deserialized: BookOffers = serde_json::from_str(&serialized).unwrap();
+        // The transport adds the command to the envelope.
+        serialized["command"] = "book_offers".into();
+        let deserialized: BookOffers = serde_json::from_value(serialized).unwrap();
 
         assert_eq!(req, deserialized);
     }
+
+    #[test]
+    fn test_serialize_xrp_iou_book() {
+        // The example request of `<https://xrpl.org/book_offers.html>`.
+        let req = BookOffers::new(
+            None,
+            Currency::XRP(XRP::new()),
+            Currency::IssuedCurrency(IssuedCurrency::new(
+                "USD".into(),
+                "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+            )),
+            None,
+            None,
+            Some(10),
+            Some("r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59".into()),
+        );
+
+        assert_eq!(
+            serde_json::to_string(&req).unwrap(),
+            r#"{"taker_gets":{"currency":"XRP"},"taker_pays":{"currency":"USD","issuer":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"},"limit":10,"taker":"r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59"}"#
+        );
+    }
+
+    #[test]
+    fn test_deserialize_iou_iou_book() {
+        let req: BookOffers = serde_json::from_str(
+            r#"{
+                "command": "book_offers",
+                "taker_gets": {"currency": "EUR", "issuer": "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq"},
+                "taker_pays": {"currency": "USD", "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B"},
+                "limit": 10
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            req.taker_gets,
+            Currency::IssuedCurrency(IssuedCurrency::new(
+                "EUR".into(),
+                "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq".into()
+            ))
+        );
+        assert!(matches!(req.taker_pays, Currency::IssuedCurrency(_)));
+
+        // A currency without issuer is not read as XRP.
+        assert!(serde_json::from_str::<BookOffers>(
+            r#"{
+                "command": "book_offers",
+                "taker_gets": {"currency": "XRP"},
+                "taker_pays": {"currency": "USD"}
+            }"#,
+        )
+        .is_err());
+    }
 }
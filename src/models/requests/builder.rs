@@ -0,0 +1,138 @@
+//! Builders for request models, as an alternative to their long
+//! positional `new` constructors.
+
+/// A required field of a request builder that is not set yet. `build`
+/// is only available once every required field is set.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Missing;
+
+/// Implement a `builder()` for a request model, with a setter for
+/// each field and `id`. The command is filled in by `build`.
+///
+/// `required` names the string field the request cannot be built
+/// without, `strings` the optional string fields, whose setters take
+/// anything convertible into a `Cow<str>`, and `values` the other
+/// optional fields. Every field of the model must be listed.
+macro_rules! request_builder {
+    (
+        $request:ident => $builder:ident {
+            required $required:ident;
+            $(strings $($string:ident),+;)?
+            $(values $($value:ident: $value_ty:ty),+;)?
+        }
+    ) => {
+        #[doc = concat!(
+            "Builds a [`", stringify!($request), "`]. `build` is available once `",
+            stringify!($required), "` is set."
+        )]
+        #[derive(Debug, Clone)]
+        pub struct $builder<'a, R = $crate::models::requests::Missing> {
+            id: Option<alloc::borrow::Cow<'a, str>>,
+            $required: R,
+            $($($string: Option<alloc::borrow::Cow<'a, str>>,)+)?
+            $($($value: Option<$value_ty>,)+)?
+        }
+
+        impl<'a> $request<'a> {
+            #[doc = concat!("Start building a request. `", stringify!($required), "` is required.")]
+            pub fn builder() -> $builder<'a> {
+                $builder {
+                    id: None,
+                    $required: $crate::models::requests::Missing,
+                    $($($string: None,)+)?
+                    $($($value: None,)+)?
+                }
+            }
+        }
+
+        impl<'a, R> $builder<'a, R> {
+            pub fn $required(
+                self,
+                $required: impl Into<alloc::borrow::Cow<'a, str>>,
+            ) -> $builder<'a, alloc::borrow::Cow<'a, str>> {
+                $builder {
+                    id: self.id,
+                    $required: $required.into(),
+                    $($($string: self.$string,)+)?
+                    $($($value: self.$value,)+)?
+                }
+            }
+
+            $crate::models::requests::builder::request_builder!(@setters $($($string),+)?; $($($value: $value_ty),+)?);
+        }
+
+        impl<'a> $builder<'a, alloc::borrow::Cow<'a, str>> {
+            pub fn build(self) -> $request<'a> {
+                $request {
+                    common_fields: $crate::models::requests::CommonFields {
+                        command: $crate::models::requests::RequestMethod::$request,
+                        id: self.id,
+                        api_version: None,
+                    },
+                    $required: self.$required,
+                    $($($string: self.$string,)+)?
+                    $($($value: self.$value,)+)?
+                }
+            }
+        }
+    };
+    (
+        $request:ident => $builder:ident {
+            $(strings $($string:ident),+;)?
+            $(values $($value:ident: $value_ty:ty),+;)?
+        }
+    ) => {
+        #[doc = concat!("Builds a [`", stringify!($request), "`].")]
+        #[derive(Debug, Clone, Default)]
+        pub struct $builder<'a> {
+            id: Option<alloc::borrow::Cow<'a, str>>,
+            $($($string: Option<alloc::borrow::Cow<'a, str>>,)+)?
+            $($($value: Option<$value_ty>,)+)?
+        }
+
+        impl<'a> $request<'a> {
+            /// Start building a request.
+            pub fn builder() -> $builder<'a> {
+                $builder::default()
+            }
+        }
+
+        impl<'a> $builder<'a> {
+            $crate::models::requests::builder::request_builder!(@setters $($($string),+)?; $($($value: $value_ty),+)?);
+
+            pub fn build(self) -> $request<'a> {
+                $request {
+                    common_fields: $crate::models::requests::CommonFields {
+                        command: $crate::models::requests::RequestMethod::$request,
+                        id: self.id,
+                        api_version: None,
+                    },
+                    $($($string: self.$string,)+)?
+                    $($($value: self.$value,)+)?
+                }
+            }
+        }
+    };
+    (@setters $($string:ident),*; $($value:ident: $value_ty:ty),*) => {
+        pub fn id(mut self, id: impl Into<alloc::borrow::Cow<'a, str>>) -> Self {
+            self.id = Some(id.into());
+            self
+        }
+
+        $(
+            pub fn $string(mut self, $string: impl Into<alloc::borrow::Cow<'a, str>>) -> Self {
+                self.$string = Some($string.into());
+                self
+            }
+        )*
+
+        $(
+            pub fn $value(mut self, $value: $value_ty) -> Self {
+                self.$value = Some($value);
+                self
+            }
+        )*
+    };
+}
+
+pub(crate) use request_builder;
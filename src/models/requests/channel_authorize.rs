@@ -23,6 +23,10 @@ use super::{CommonFields, Request};
 /// claims and take all the money from this payment channel and
 /// anything else using the same key pair.
 ///
+/// Because of this, validation fails unless the request was explicitly
+/// opted in with [`ChannelAuthorize::allow_insecure_remote_signing`].
+/// Prefer signing claims locally with [`crate::core::keypairs::sign`].
+///
 /// See Set Up Secure Signing:
 /// `<https://xrpl.org/set-up-secure-signing.html>`
 ///
@@ -30,6 +34,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/channel_authorize.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct ChannelAuthorize<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -67,6 +72,10 @@ pub struct ChannelAuthorize<'a> {
     /// The signing algorithm of the cryptographic key pair provided.
     /// Valid types are secp256k1 or ed25519. The default is secp256k1.
     pub key_type: Option<CryptoAlgorithm>,
+    /// Set by [`ChannelAuthorize::allow_insecure_remote_signing`].
+    /// Never sent to the server.
+    #[serde(skip)]
+    pub allow_insecure_remote_signing: bool,
 }
 
 impl<'a> Model for ChannelAuthorize<'a> {
@@ -87,6 +96,12 @@ impl<'a> Request<'a> for ChannelAuthorize<'a> {
 
 impl<'a> ChannelAuthorizeError for ChannelAuthorize<'a> {
     fn _get_field_error(&self) -> XRPLModelResult<()> {
+        if !self.allow_insecure_remote_signing {
+            return Err(XRPLModelException::InsecureRemoteSigning {
+                request: "channel_authorize",
+                local_alternative: "xrpl::core::keypairs::sign",
+            });
+        }
         let mut signing_methods = Vec::new();
         for method in [
             self.secret.clone(),
@@ -126,6 +141,7 @@ impl<'a> ChannelAuthorize<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::ChannelAuthorize,
                 id,
+                api_version: None,
             },
             channel_id,
             amount,
@@ -134,8 +150,17 @@ impl<'a> ChannelAuthorize<'a> {
             seed_hex,
             passphrase,
             key_type,
+            allow_insecure_remote_signing: false,
         }
     }
+
+    /// Opt in to sending the secret key to the server. Only do this
+    /// for a server you run or fully trust, e.g. a standalone node or
+    /// an admin connection on localhost.
+    pub fn allow_insecure_remote_signing(mut self) -> Self {
+        self.allow_insecure_remote_signing = true;
+        self
+    }
 }
 
 pub trait ChannelAuthorizeError {
@@ -161,7 +186,8 @@ mod test_channel_authorize_errors {
             Some("".into()),
             None,
             Some(CryptoAlgorithm::SECP256K1),
-        );
+        )
+        .allow_insecure_remote_signing();
 
         assert_eq!(
             channel_authorize
@@ -173,6 +199,32 @@ mod test_channel_authorize_errors {
         );
     }
 
+    #[test]
+    fn test_insecure_remote_signing_error() {
+        let channel_authorize = ChannelAuthorize::new(
+            None,
+            "5DB01B7FFED6B67E6B0414DED11E051D2EE2B7619CE0EAA6286D67A3A4D5BDB3".into(),
+            "1000000".into(),
+            Some("snoPBrXtMeMyMHUVTgbuqAfg1SUTb".into()),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        assert_eq!(
+            channel_authorize.validate(),
+            Err(XRPLModelException::InsecureRemoteSigning {
+                request: "channel_authorize",
+                local_alternative: "xrpl::core::keypairs::sign",
+            })
+        );
+        assert!(channel_authorize
+            .allow_insecure_remote_signing()
+            .validate()
+            .is_ok());
+    }
+
     #[test]
     fn test_serde() {
         let req = ChannelAuthorize::new(
@@ -185,9 +237,17 @@ mod test_channel_authorize_errors {
             None,
             Some(CryptoAlgorithm::SECP256K1),
         );
-        let serialized = serde_json::to_string(&req).unwrap();
+        let opted_in = req.clone().allow_insecure_remote_signing();
+        assert_eq!(
+            serde_json::to_value(&opted_in).unwrap(),
+            serde_json::to_value(&req).unwrap()
+        );
+        let mut serialized = serde_json::to_value(&req).unwrap();
+        assert!(serialized.get("command").is_none());
 
-        let deserialized: ChannelAuthorize = serde_json::from_str(&serialized).unwrap();
+        // The transport adds the command to the envelope.
+        serialized["command"] = "channel_authorize".into();
+        let deserialized: ChannelAuthorize = serde_json::from_value(serialized).unwrap();
 
         assert_eq!(req, deserialized);
     }
@@ -11,6 +11,7 @@ use super::{CommonFields, Request};
 /// payment channel.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct ChannelVerify<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -52,6 +53,7 @@ impl<'a> ChannelVerify<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::ChannelVerify,
                 id,
+                api_version: None,
             },
             channel_id,
             amount,
@@ -1,4 +1,5 @@
 use alloc::borrow::Cow;
+use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -13,6 +14,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/depositauth.html#deposit-authorization>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct DepositAuthorized<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -21,6 +23,10 @@ pub struct DepositAuthorized<'a> {
     pub destination_account: Cow<'a, str>,
     /// The sender of a possible payment.
     pub source_account: Cow<'a, str>,
+    /// The IDs of the Credential objects the sender would present,
+    /// for a recipient preauthorizing credentials rather than
+    /// accounts.
+    pub credentials: Option<Vec<Cow<'a, str>>>,
     /// A 20-byte hex string for the ledger version to use.
     pub ledger_hash: Option<Cow<'a, str>>,
     /// The ledger index of the ledger to use, or a shortcut
@@ -45,6 +51,7 @@ impl<'a> DepositAuthorized<'a> {
         id: Option<Cow<'a, str>>,
         destination_account: Cow<'a, str>,
         source_account: Cow<'a, str>,
+        credentials: Option<Vec<Cow<'a, str>>>,
         ledger_hash: Option<Cow<'a, str>>,
         ledger_index: Option<Cow<'a, str>>,
     ) -> Self {
@@ -52,9 +59,11 @@ impl<'a> DepositAuthorized<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::DepositAuthorized,
                 id,
+                api_version: None,
             },
             source_account,
             destination_account,
+            credentials,
             ledger_hash,
             ledger_index,
         }
@@ -15,6 +15,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/fee.html#fee>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Fee<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -39,6 +40,7 @@ impl<'a> Fee<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Fee,
                 id,
+                api_version: None,
             },
         }
     }
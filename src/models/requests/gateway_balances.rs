@@ -15,6 +15,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/gateway_balances.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct GatewayBalances<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -59,6 +60,7 @@ impl<'a> GatewayBalances<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::GatewayBalances,
                 id,
+                api_version: None,
             },
             account,
             strict,
@@ -4,14 +4,48 @@ use serde_with::skip_serializing_none;
 
 use crate::models::{requests::RequestMethod, Model};
 
-use super::{CommonFields, Request};
+use super::{builder::request_builder, CommonFields, Request};
 
 /// Retrieve information about the public ledger.
 ///
 /// See Ledger Data:
 /// `<https://xrpl.org/ledger.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::requests::ledger::Ledger;
+/// use xrpl::models::requests::RequestMethod;
+///
+/// let request = Ledger::builder()
+///     .ledger_index("validated")
+///     .transactions(true)
+///     .expand(true)
+///     .build();
+///
+/// assert_eq!(request.common_fields.command, RequestMethod::Ledger);
+/// assert_eq!(request.transactions, Some(true));
+/// assert_eq!(
+///     request,
+///     Ledger::new(
+///         None,
+///         None,
+///         None,
+///         Some(true),
+///         None,
+///         None,
+///         Some("validated".into()),
+///         None,
+///         None,
+///         Some(true),
+///     )
+/// );
+/// ```
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Ledger<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -58,6 +92,13 @@ pub struct Ledger<'a> {
 
 impl<'a> Model for Ledger<'a> {}
 
+request_builder! {
+    Ledger => LedgerBuilder {
+        strings ledger_hash, ledger_index;
+        values accounts: bool, binary: bool, expand: bool, full: bool, owner_funds: bool, queue: bool, transactions: bool;
+    }
+}
+
 impl<'a> Request<'a> for Ledger<'a> {
     fn get_common_fields(&self) -> &CommonFields<'a> {
         &self.common_fields
@@ -85,6 +126,7 @@ impl<'a> Ledger<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Ledger,
                 id,
+                api_version: None,
             },
             ledger_hash,
             ledger_index,
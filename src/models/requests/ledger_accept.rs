@@ -0,0 +1,47 @@
+use alloc::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{requests::RequestMethod, Model};
+
+use super::{CommonFields, Request};
+
+/// The ledger_accept method forces the server to close the
+/// current open ledger and move to the next one. It is an admin
+/// method, only available on a server in stand-alone mode, e.g.
+/// to advance the ledger in tests.
+///
+/// See Ledger Accept:
+/// `<https://xrpl.org/ledger_accept.html>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct LedgerAccept<'a> {
+    /// The common fields shared by all requests.
+    #[serde(flatten)]
+    pub common_fields: CommonFields<'a>,
+}
+
+impl<'a> Model for LedgerAccept<'a> {}
+
+impl<'a> Request<'a> for LedgerAccept<'a> {
+    fn get_common_fields(&self) -> &CommonFields<'a> {
+        &self.common_fields
+    }
+
+    fn get_common_fields_mut(&mut self) -> &mut CommonFields<'a> {
+        &mut self.common_fields
+    }
+}
+
+impl<'a> LedgerAccept<'a> {
+    pub fn new(id: Option<Cow<'a, str>>) -> Self {
+        Self {
+            common_fields: CommonFields {
+                command: RequestMethod::LedgerAccept,
+                id,
+                api_version: None,
+            },
+        }
+    }
+}
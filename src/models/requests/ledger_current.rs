@@ -14,6 +14,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/ledger_closed.html#ledger_closed>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct LedgerCurrent<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -38,6 +39,7 @@ impl<'a> LedgerCurrent<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::LedgerCurrent,
                 id,
+                api_version: None,
             },
         }
     }
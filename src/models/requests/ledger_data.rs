@@ -1,11 +1,38 @@
 use alloc::borrow::Cow;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
+use strum_macros::Display;
 
 use crate::models::{requests::RequestMethod, Model};
 
 use super::{CommonFields, Request};
 
+/// The types of ledger entries a LedgerData request can be
+/// filtered to.
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Display)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerDataType {
+    Account,
+    Amendments,
+    Amm,
+    Check,
+    DepositPreauth,
+    Directory,
+    Escrow,
+    Fee,
+    Hashes,
+    NftOffer,
+    NftPage,
+    Offer,
+    PaymentChannel,
+    SignerList,
+    State,
+    Ticket,
+}
+
 /// The ledger_data method retrieves contents of the specified
 /// ledger. You can iterate through several calls to retrieve
 /// the entire contents of a single ledger version.
@@ -14,6 +41,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/ledger_data.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct LedgerData<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -29,9 +57,13 @@ pub struct LedgerData<'a> {
     /// Limit the number of ledger objects to retrieve.
     /// The server is not required to honor this value.
     pub limit: Option<u16>,
-    /// Value from a previous paginated response.
-    /// Resume retrieving data where that response left off.
-    pub marker: Option<u32>,
+    /// Value from a previous paginated response. Resume retrieving
+    /// data where that response left off. The marker is opaque, pass
+    /// it back as the server returned it.
+    pub marker: Option<Value>,
+    /// If included, filter results to include only this type of
+    /// ledger entry.
+    pub r#type: Option<LedgerDataType>,
 }
 
 impl<'a> Model for LedgerData<'a> {}
@@ -53,18 +85,52 @@ impl<'a> LedgerData<'a> {
         ledger_hash: Option<Cow<'a, str>>,
         ledger_index: Option<Cow<'a, str>>,
         limit: Option<u16>,
-        marker: Option<u32>,
+        marker: Option<Value>,
+        r#type: Option<LedgerDataType>,
     ) -> Self {
         Self {
             common_fields: CommonFields {
                 command: RequestMethod::LedgerData,
                 id,
+                api_version: None,
             },
             ledger_hash,
             ledger_index,
             binary,
             limit,
             marker,
+            r#type,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_serialize_type_and_marker() {
+        let request = LedgerData::new(
+            None,
+            Some(true),
+            None,
+            Some("validated".into()),
+            Some(256),
+            Some(Value::from(
+                "0F6AA4D5A0E2D3E0CE6A4E1D0A1EBF2D8CF2E11C5BBF3C1AC71D2B9A9A1E9B4F",
+            )),
+            Some(LedgerDataType::State),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "binary": true,
+                "ledger_index": "validated",
+                "limit": 256,
+                "marker": "0F6AA4D5A0E2D3E0CE6A4E1D0A1EBF2D8CF2E11C5BBF3C1AC71D2B9A9A1E9B4F",
+                "type": "state"
+            })
+        );
+    }
+}
@@ -1,15 +1,18 @@
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, vec::Vec};
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::models::{requests::RequestMethod, Model, XRPLModelException, XRPLModelResult};
+use crate::models::{
+    requests::RequestMethod, Currency, Model, XRPLModelException, XRPLModelResult,
+};
 
 use super::{CommonFields, Request};
 
 /// Required fields for requesting a DepositPreauth if not
 /// querying by object ID.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, new)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct DepositPreauth<'a> {
     pub authorized: Cow<'a, str>,
     pub owner: Cow<'a, str>,
@@ -19,6 +22,7 @@ pub struct DepositPreauth<'a> {
 /// querying by object ID.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, new)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Directory<'a> {
     pub dir_root: Cow<'a, str>,
     pub owner: Cow<'a, str>,
@@ -28,6 +32,7 @@ pub struct Directory<'a> {
 /// Required fields for requesting a Escrow if not querying
 /// by object ID.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, new)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Escrow<'a> {
     pub owner: Cow<'a, str>,
     pub seq: u64,
@@ -36,6 +41,7 @@ pub struct Escrow<'a> {
 /// Required fields for requesting a Escrow if not querying
 /// by object ID.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, new)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Offer<'a> {
     pub account: Cow<'a, str>,
     pub seq: u64,
@@ -44,18 +50,58 @@ pub struct Offer<'a> {
 /// Required fields for requesting a Ticket, if not
 /// querying by object ID.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, new)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Ticket<'a> {
     pub owner: Cow<'a, str>,
     pub ticket_sequence: u64,
 }
 
-/// Required fields for requesting a RippleState.
+/// Required fields for requesting a RippleState: the two
+/// accounts of the trust line and its currency.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, new)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct RippleState<'a> {
-    pub account: Cow<'a, str>,
+    pub accounts: Vec<Cow<'a, str>>,
     pub currency: Cow<'a, str>,
 }
 
+/// Required fields for requesting an AMM, if not querying by
+/// object ID.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, new)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct Amm<'a> {
+    pub asset: Currency<'a>,
+    pub asset2: Currency<'a>,
+}
+
+/// The ledger object to retrieve, by object ID or by the
+/// parameters of its type. Serialized as the one selector field
+/// of the request, e.g. `"offer": {"account": .., "seq": ..}`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerEntrySelector<'a> {
+    /// Any ledger object, by its object ID.
+    Index(Cow<'a, str>),
+    /// An AccountRoot, by the address of its account.
+    AccountRoot(Cow<'a, str>),
+    Amm(Amm<'a>),
+    /// A Check, by its object ID.
+    Check(Cow<'a, str>),
+    DepositPreauth(DepositPreauth<'a>),
+    /// A DID, by the address of its account.
+    Did(Cow<'a, str>),
+    Directory(Directory<'a>),
+    Escrow(Escrow<'a>),
+    /// An NFTokenPage, by its object ID.
+    NftPage(Cow<'a, str>),
+    Offer(Offer<'a>),
+    /// A PayChannel, by its object ID.
+    PaymentChannel(Cow<'a, str>),
+    RippleState(RippleState<'a>),
+    Ticket(Ticket<'a>),
+}
+
 /// The ledger_entry method returns a single ledger object
 /// from the XRP Ledger in its raw format. See ledger formats
 /// for information on the different types of objects you can
@@ -68,86 +114,55 @@ pub struct RippleState<'a> {
 /// `<https://xrpl.org/ledger_entry.html#ledger_entry>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct LedgerEntry<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
     pub common_fields: CommonFields<'a>,
-    pub account_root: Option<Cow<'a, str>>,
+    /// The ledger object to retrieve.
+    #[serde(flatten)]
+    pub selector: LedgerEntrySelector<'a>,
     /// If true, return the requested ledger object's contents as a
     /// hex string in the XRP Ledger's binary format. Otherwise, return
     /// data in JSON format. The default is false.
     pub binary: Option<bool>,
-    pub check: Option<Cow<'a, str>>,
-    pub deposit_preauth: Option<DepositPreauth<'a>>,
-    pub directory: Option<Directory<'a>>,
-    pub escrow: Option<Escrow<'a>>,
-    pub index: Option<Cow<'a, str>>,
     /// A 20-byte hex string for the ledger version to use.
     pub ledger_hash: Option<Cow<'a, str>>,
     /// The ledger index of the ledger to use, or a shortcut string
     /// (e.g. "validated" or "closed" or "current") to choose a ledger
     /// automatically.
     pub ledger_index: Option<Cow<'a, str>>,
-    pub offer: Option<Offer<'a>>,
-    pub payment_channel: Option<Cow<'a, str>>,
-    pub ripple_state: Option<RippleState<'a>>,
-    pub ticket: Option<Ticket<'a>>,
 }
 
 impl<'a: 'static> Model for LedgerEntry<'a> {
     fn get_errors(&self) -> XRPLModelResult<()> {
-        Ok(self._get_field_error()?)
+        self._get_ripple_state_error()
     }
 }
 
 impl<'a> LedgerEntryError for LedgerEntry<'a> {
-    fn _get_field_error(&self) -> XRPLModelResult<()> {
-        let mut signing_methods: u32 = 0;
-        for method in [
-            self.index.clone(),
-            self.account_root.clone(),
-            self.check.clone(),
-        ] {
-            if method.is_some() {
-                signing_methods += 1
+    fn _get_ripple_state_error(&self) -> XRPLModelResult<()> {
+        let LedgerEntrySelector::RippleState(ripple_state) = &self.selector else {
+            return Ok(());
+        };
+        match ripple_state.accounts.as_slice() {
+            [account1, account2] if account1 == account2 => {
+                Err(XRPLModelException::ValueEqualsValue {
+                    field1: "ripple_state.accounts[0]".into(),
+                    field2: "ripple_state.accounts[1]".into(),
+                })
             }
-        }
-        if self.directory.is_some() {
-            signing_methods += 1
-        }
-        if self.offer.is_some() {
-            signing_methods += 1
-        }
-        if self.ripple_state.is_some() {
-            signing_methods += 1
-        }
-        if self.escrow.is_some() {
-            signing_methods += 1
-        }
-        if self.payment_channel.is_some() {
-            signing_methods += 1
-        }
-        if self.deposit_preauth.is_some() {
-            signing_methods += 1
-        }
-        if self.ticket.is_some() {
-            signing_methods += 1
-        }
-        if signing_methods != 1 {
-            Err(XRPLModelException::ExpectedOneOf(&[
-                "index",
-                "account_root",
-                "check",
-                "directory",
-                "offer",
-                "ripple_state",
-                "escrow",
-                "payment_channel",
-                "deposit_preauth",
-                "ticket",
-            ]))
-        } else {
-            Ok(())
+            [_, _] => Ok(()),
+            accounts if accounts.len() < 2 => Err(XRPLModelException::ValueTooShort {
+                field: "ripple_state.accounts".into(),
+                min: 2,
+                found: accounts.len(),
+            }),
+            accounts => Err(XRPLModelException::ValueTooLong {
+                field: "ripple_state.accounts".into(),
+                max: 2,
+                found: accounts.len(),
+            }),
         }
     }
 }
@@ -164,6 +179,29 @@ impl<'a> Request<'a> for LedgerEntry<'a> {
 
 impl<'a> LedgerEntry<'a> {
     pub fn new(
+        id: Option<Cow<'a, str>>,
+        selector: LedgerEntrySelector<'a>,
+        binary: Option<bool>,
+        ledger_hash: Option<Cow<'a, str>>,
+        ledger_index: Option<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            common_fields: CommonFields {
+                command: RequestMethod::LedgerEntry,
+                id,
+                api_version: None,
+            },
+            selector,
+            binary,
+            ledger_hash,
+            ledger_index,
+        }
+    }
+
+    /// Build the request from one optional field per selector, as
+    /// the request was modelled before [`LedgerEntrySelector`].
+    /// Fails unless exactly one selector is given.
+    pub fn from_fields(
         id: Option<Cow<'a, str>>,
         account_root: Option<Cow<'a, str>>,
         binary: Option<bool>,
@@ -178,45 +216,58 @@ impl<'a> LedgerEntry<'a> {
         payment_channel: Option<Cow<'a, str>>,
         ripple_state: Option<RippleState<'a>>,
         ticket: Option<Ticket<'a>>,
-    ) -> Self {
-        Self {
-            common_fields: CommonFields {
-                command: RequestMethod::LedgerEntry,
-                id,
-            },
-            index,
-            account_root,
-            check,
-            payment_channel,
-            deposit_preauth,
-            directory,
-            escrow,
-            offer,
-            ripple_state,
-            ticket,
-            binary,
-            ledger_hash,
-            ledger_index,
+    ) -> XRPLModelResult<Self> {
+        let selectors = [
+            index.map(LedgerEntrySelector::Index),
+            account_root.map(LedgerEntrySelector::AccountRoot),
+            check.map(LedgerEntrySelector::Check),
+            directory.map(LedgerEntrySelector::Directory),
+            offer.map(LedgerEntrySelector::Offer),
+            ripple_state.map(LedgerEntrySelector::RippleState),
+            escrow.map(LedgerEntrySelector::Escrow),
+            payment_channel.map(LedgerEntrySelector::PaymentChannel),
+            deposit_preauth.map(LedgerEntrySelector::DepositPreauth),
+            ticket.map(LedgerEntrySelector::Ticket),
+        ];
+        let mut selectors = selectors.into_iter().flatten();
+        match (selectors.next(), selectors.next()) {
+            (Some(selector), None) => {
+                Ok(Self::new(id, selector, binary, ledger_hash, ledger_index))
+            }
+            _ => Err(XRPLModelException::ExpectedOneOf(&[
+                "index",
+                "account_root",
+                "check",
+                "directory",
+                "offer",
+                "ripple_state",
+                "escrow",
+                "payment_channel",
+                "deposit_preauth",
+                "ticket",
+            ])),
         }
     }
 }
 
 pub trait LedgerEntryError {
     #[allow(clippy::result_large_err)]
-    fn _get_field_error(&self) -> XRPLModelResult<()>;
+    fn _get_ripple_state_error(&self) -> XRPLModelResult<()>;
 }
 
 #[cfg(test)]
 mod test_ledger_entry_errors {
     use super::Offer;
-    use crate::models::Model;
+    use crate::models::{IssuedCurrency, Model, XRP};
     use alloc::string::ToString;
+    use alloc::vec;
+    use serde_json::json;
 
     use super::*;
 
     #[test]
     fn test_fields_error() {
-        let ledger_entry = LedgerEntry::new(
+        let ledger_entry = LedgerEntry::from_fields(
             None,
             Some("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into()),
             None,
@@ -235,27 +286,12 @@ mod test_ledger_entry_errors {
             None,
             None,
         );
-        let _expected = XRPLModelException::ExpectedOneOf(&[
-            "index",
-            "account_root",
-            "check",
-            "directory",
-            "offer",
-            "ripple_state",
-            "escrow",
-            "payment_channel",
-            "deposit_preauth",
-            "ticket",
-        ]);
         assert_eq!(
-            ledger_entry.validate().unwrap_err().to_string().as_str(),
+            ledger_entry.unwrap_err().to_string().as_str(),
             "Expected one of: index, account_root, check, directory, offer, ripple_state, escrow, payment_channel, deposit_preauth, ticket"
         );
-    }
 
-    #[test]
-    fn test_serde() {
-        let req = LedgerEntry::new(
+        let ledger_entry = LedgerEntry::from_fields(
             None,
             Some("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into()),
             None,
@@ -265,19 +301,126 @@ mod test_ledger_entry_errors {
             None,
             None,
             None,
+            Some("validated".into()),
             None,
-            Some(Offer {
-                account: "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
-                seq: 359,
-            }),
             None,
             None,
             None,
+        )
+        .unwrap();
+        assert_eq!(
+            ledger_entry,
+            LedgerEntry::new(
+                None,
+                LedgerEntrySelector::AccountRoot("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into()),
+                None,
+                None,
+                Some("validated".into()),
+            )
         );
-        let serialized = serde_json::to_string(&req).unwrap();
+    }
 
-        let deserialized: LedgerEntry = serde_json::from_str(&serialized).unwrap();
+    #[test]
+    fn test_ripple_state_error() {
+        let ripple_state = |accounts: Vec<Cow<'static, str>>| {
+            LedgerEntry::new(
+                None,
+                LedgerEntrySelector::RippleState(RippleState::new(accounts, "USD".into())),
+                None,
+                None,
+                None,
+            )
+        };
 
-        assert_eq!(req, deserialized);
+        assert!(ripple_state(vec![
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+            "rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8".into(),
+        ])
+        .validate()
+        .is_ok());
+        assert_eq!(
+            ripple_state(vec!["rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into()]).validate(),
+            Err(XRPLModelException::ValueTooShort {
+                field: "ripple_state.accounts".into(),
+                min: 2,
+                found: 1,
+            })
+        );
+        assert_eq!(
+            ripple_state(vec![
+                "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+                "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+            ])
+            .validate(),
+            Err(XRPLModelException::ValueEqualsValue {
+                field1: "ripple_state.accounts[0]".into(),
+                field2: "ripple_state.accounts[1]".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        let cases = [
+            (
+                LedgerEntrySelector::AccountRoot("rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into()),
+                json!({"account_root": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"}),
+            ),
+            (
+                LedgerEntrySelector::Offer(Offer::new(
+                    "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+                    359,
+                )),
+                json!({"offer": {"account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn", "seq": 359}}),
+            ),
+            (
+                LedgerEntrySelector::RippleState(RippleState::new(
+                    vec![
+                        "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+                        "rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8".into(),
+                    ],
+                    "USD".into(),
+                )),
+                json!({"ripple_state": {
+                    "accounts": [
+                        "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                        "rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8"
+                    ],
+                    "currency": "USD"
+                }}),
+            ),
+            (
+                LedgerEntrySelector::Amm(Amm::new(
+                    XRP::new().into(),
+                    IssuedCurrency::new("USD".into(), "rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8".into())
+                        .into(),
+                )),
+                json!({"amm": {
+                    "asset": {"currency": "XRP"},
+                    "asset2": {"currency": "USD", "issuer": "rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8"}
+                }}),
+            ),
+            (
+                LedgerEntrySelector::Index(
+                    "7DB0788C020F02780A673DC74757F23823FA3014C1866E72CC4CD8B226CD6EF4".into(),
+                ),
+                json!({"index": "7DB0788C020F02780A673DC74757F23823FA3014C1866E72CC4CD8B226CD6EF4"}),
+            ),
+        ];
+
+        for (selector, expected) in cases {
+            let req = LedgerEntry::new(None, selector, None, None, Some("validated".into()));
+            let mut serialized = serde_json::to_value(&req).unwrap();
+            assert!(serialized.get("command").is_none());
+            let mut expected = expected;
+            expected["ledger_index"] = "validated".into();
+            assert_eq!(serialized, expected);
+
+            // The transport adds the command to the envelope.
+            serialized["command"] = "ledger_entry".into();
+            let deserialized: LedgerEntry = serde_json::from_value(serialized).unwrap();
+
+            assert_eq!(req, deserialized);
+        }
     }
 }
@@ -15,6 +15,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/manifest.html#manifest>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Manifest<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -43,6 +44,7 @@ impl<'a> Manifest<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Manifest,
                 id,
+                api_version: None,
             },
             public_key,
         }
@@ -8,12 +8,14 @@ pub mod account_offers;
 pub mod account_tx;
 pub mod amm_info;
 pub mod book_offers;
+mod builder;
 pub mod channel_authorize;
 pub mod channel_verify;
 pub mod deposit_authorize;
 pub mod fee;
 pub mod gateway_balances;
 pub mod ledger;
+pub mod ledger_accept;
 pub mod ledger_closed;
 pub mod ledger_current;
 pub mod ledger_data;
@@ -25,9 +27,11 @@ pub mod no_ripple_check;
 pub mod path_find;
 pub mod ping;
 pub mod random;
+pub mod raw;
 pub mod ripple_path_find;
 pub mod server_info;
 pub mod server_state;
+pub mod simulate;
 pub mod submit;
 pub mod submit_multisigned;
 pub mod subscribe;
@@ -35,15 +39,23 @@ pub mod transaction_entry;
 pub mod tx;
 pub mod unsubscribe;
 
-use alloc::borrow::Cow;
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+};
 use derive_new::new;
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
 use strum_macros::Display;
 
+pub use builder::Missing;
+
 /// Represents the different options for the `method`
 /// field in a request.
 #[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Display)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 pub enum RequestMethod {
@@ -64,6 +76,7 @@ pub enum RequestMethod {
     // Transaction methods
     Sign,
     SignFor,
+    Simulate,
     Submit,
     SubmitMultisigned,
     TransactionEntry,
@@ -83,6 +96,7 @@ pub enum RequestMethod {
 
     // Ledger methods
     Ledger,
+    LedgerAccept,
     LedgerClosed,
     LedgerCurrent,
     LedgerData,
@@ -101,9 +115,18 @@ pub enum RequestMethod {
     // Utility methods
     Ping,
     Random,
+
+    /// A method without a request model, sent with a
+    /// [`raw::Raw`] request. It has no name of its own, see
+    /// [`XRPLRequest::method_name`].
+    #[serde(skip)]
+    Other,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+/// Any request model. Serializes to the request's parameters only; the
+/// transport adds the `command` (or JSON-RPC `method`) and `id`. When
+/// deserializing, the `command` field selects the variant.
+#[derive(Debug, Serialize, PartialEq, Eq, Clone)]
 #[serde(untagged)]
 pub enum XRPLRequest<'a> {
     AccountChannels(account_channels::AccountChannels<'a>),
@@ -117,6 +140,7 @@ pub enum XRPLRequest<'a> {
     AMMInfo(amm_info::AMMInfo<'a>),
     GatewayBalances(gateway_balances::GatewayBalances<'a>),
     NoRippleCheck(no_ripple_check::NoRippleCheck<'a>),
+    Simulate(simulate::Simulate<'a>),
     Submit(submit::Submit<'a>),
     SubmitMultisigned(submit_multisigned::SubmitMultisigned<'a>),
     TransactionEntry(transaction_entry::TransactionEntry<'a>),
@@ -130,6 +154,7 @@ pub enum XRPLRequest<'a> {
     PathFind(path_find::PathFind<'a>),
     RipplePathFind(ripple_path_find::RipplePathFind<'a>),
     Ledger(ledger::Ledger<'a>),
+    LedgerAccept(ledger_accept::LedgerAccept<'a>),
     LedgerClosed(ledger_closed::LedgerClosed<'a>),
     LedgerCurrent(ledger_current::LedgerCurrent<'a>),
     LedgerData(ledger_data::LedgerData<'a>),
@@ -142,6 +167,212 @@ pub enum XRPLRequest<'a> {
     ServerState(server_state::ServerState<'a>),
     Ping(ping::Ping<'a>),
     Random(random::Random<'a>),
+    Raw(raw::Raw<'a>),
+}
+
+impl XRPLRequest<'_> {
+    /// The method (websocket `command`) of this request.
+    pub fn method(&self) -> RequestMethod {
+        match self {
+            XRPLRequest::AccountChannels(_) => RequestMethod::AccountChannels,
+            XRPLRequest::AccountCurrencies(_) => RequestMethod::AccountCurrencies,
+            XRPLRequest::AccountInfo(_) => RequestMethod::AccountInfo,
+            XRPLRequest::AccountLines(_) => RequestMethod::AccountLines,
+            XRPLRequest::AccountNfts(_) => RequestMethod::AccountNfts,
+            XRPLRequest::AccountObjects(_) => RequestMethod::AccountObjects,
+            XRPLRequest::AccountOffers(_) => RequestMethod::AccountOffers,
+            XRPLRequest::AccountTx(_) => RequestMethod::AccountTx,
+            XRPLRequest::AMMInfo(_) => RequestMethod::AMMInfo,
+            XRPLRequest::GatewayBalances(_) => RequestMethod::GatewayBalances,
+            XRPLRequest::NoRippleCheck(_) => RequestMethod::NoRippleCheck,
+            XRPLRequest::Simulate(_) => RequestMethod::Simulate,
+            XRPLRequest::Submit(_) => RequestMethod::Submit,
+            XRPLRequest::SubmitMultisigned(_) => RequestMethod::SubmitMultisigned,
+            XRPLRequest::TransactionEntry(_) => RequestMethod::TransactionEntry,
+            XRPLRequest::Tx(_) => RequestMethod::Tx,
+            XRPLRequest::ChannelAuthorize(_) => RequestMethod::ChannelAuthorize,
+            XRPLRequest::ChannelVerify(_) => RequestMethod::ChannelVerify,
+            XRPLRequest::BookOffers(_) => RequestMethod::BookOffers,
+            XRPLRequest::DepositAuthorized(_) => RequestMethod::DepositAuthorized,
+            XRPLRequest::NftBuyOffers(_) => RequestMethod::NftBuyOffers,
+            XRPLRequest::NftSellOffers(_) => RequestMethod::NftSellOffers,
+            XRPLRequest::PathFind(_) => RequestMethod::PathFind,
+            XRPLRequest::RipplePathFind(_) => RequestMethod::RipplePathFind,
+            XRPLRequest::Ledger(_) => RequestMethod::Ledger,
+            XRPLRequest::LedgerAccept(_) => RequestMethod::LedgerAccept,
+            XRPLRequest::LedgerClosed(_) => RequestMethod::LedgerClosed,
+            XRPLRequest::LedgerCurrent(_) => RequestMethod::LedgerCurrent,
+            XRPLRequest::LedgerData(_) => RequestMethod::LedgerData,
+            XRPLRequest::LedgerEntry(_) => RequestMethod::LedgerEntry,
+            XRPLRequest::Subscribe(_) => RequestMethod::Subscribe,
+            XRPLRequest::Unsubscribe(_) => RequestMethod::Unsubscribe,
+            XRPLRequest::Fee(_) => RequestMethod::Fee,
+            XRPLRequest::Manifest(_) => RequestMethod::Manifest,
+            XRPLRequest::ServerInfo(_) => RequestMethod::ServerInfo,
+            XRPLRequest::ServerState(_) => RequestMethod::ServerState,
+            XRPLRequest::Ping(_) => RequestMethod::Ping,
+            XRPLRequest::Random(_) => RequestMethod::Random,
+            XRPLRequest::Raw(request) => request.common_fields.command.clone(),
+        }
+    }
+
+    /// The name of the method, as sent in the websocket `command` or
+    /// the JSON-RPC `method`.
+    pub fn method_name(&self) -> Cow<'_, str> {
+        match self {
+            XRPLRequest::Raw(request) => Cow::Borrowed(request.method.as_ref()),
+            request => Cow::Owned(request.method().to_string()),
+        }
+    }
+
+    /// Whether this request carries a secret key for the server to
+    /// sign with.
+    pub fn sends_secret(&self) -> bool {
+        match self {
+            XRPLRequest::ChannelAuthorize(request) => {
+                request.secret.is_some()
+                    || request.seed.is_some()
+                    || request.seed_hex.is_some()
+                    || request.passphrase.is_some()
+            }
+            XRPLRequest::Raw(request) => {
+                matches!(
+                    request.common_fields.command,
+                    RequestMethod::ChannelAuthorize | RequestMethod::Sign | RequestMethod::SignFor
+                ) && ["secret", "seed", "seed_hex", "passphrase"]
+                    .iter()
+                    .any(|field| request.params.contains_key(*field))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'a, 'de> Deserialize<'de> for XRPLRequest<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<XRPLRequest<'a>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let command = value
+            .get("command")
+            .cloned()
+            .ok_or_else(|| de::Error::missing_field("command"))?;
+        // Methods without a model, e.g. recorded from a raw request,
+        // deserialize to a raw request.
+        let Ok(method) = serde_json::from_value(command) else {
+            return raw_request_from_value(value).map_err(de::Error::custom);
+        };
+
+        match method {
+            RequestMethod::AccountChannels => {
+                serde_json::from_value(value).map(XRPLRequest::AccountChannels)
+            }
+            RequestMethod::AccountCurrencies => {
+                serde_json::from_value(value).map(XRPLRequest::AccountCurrencies)
+            }
+            RequestMethod::AccountInfo => {
+                serde_json::from_value(value).map(XRPLRequest::AccountInfo)
+            }
+            RequestMethod::AccountLines => {
+                serde_json::from_value(value).map(XRPLRequest::AccountLines)
+            }
+            RequestMethod::AccountNfts => {
+                serde_json::from_value(value).map(XRPLRequest::AccountNfts)
+            }
+            RequestMethod::AccountObjects => {
+                serde_json::from_value(value).map(XRPLRequest::AccountObjects)
+            }
+            RequestMethod::AccountOffers => {
+                serde_json::from_value(value).map(XRPLRequest::AccountOffers)
+            }
+            RequestMethod::AccountTx => serde_json::from_value(value).map(XRPLRequest::AccountTx),
+            RequestMethod::AMMInfo => serde_json::from_value(value).map(XRPLRequest::AMMInfo),
+            RequestMethod::GatewayBalances => {
+                serde_json::from_value(value).map(XRPLRequest::GatewayBalances)
+            }
+            RequestMethod::NoRippleCheck => {
+                serde_json::from_value(value).map(XRPLRequest::NoRippleCheck)
+            }
+            RequestMethod::Simulate => serde_json::from_value(value).map(XRPLRequest::Simulate),
+            RequestMethod::Submit => serde_json::from_value(value).map(XRPLRequest::Submit),
+            RequestMethod::SubmitMultisigned => {
+                serde_json::from_value(value).map(XRPLRequest::SubmitMultisigned)
+            }
+            RequestMethod::TransactionEntry => {
+                serde_json::from_value(value).map(XRPLRequest::TransactionEntry)
+            }
+            RequestMethod::Tx => serde_json::from_value(value).map(XRPLRequest::Tx),
+            RequestMethod::ChannelAuthorize => {
+                serde_json::from_value(value).map(XRPLRequest::ChannelAuthorize)
+            }
+            RequestMethod::ChannelVerify => {
+                serde_json::from_value(value).map(XRPLRequest::ChannelVerify)
+            }
+            RequestMethod::BookOffers => serde_json::from_value(value).map(XRPLRequest::BookOffers),
+            RequestMethod::DepositAuthorized => {
+                serde_json::from_value(value).map(XRPLRequest::DepositAuthorized)
+            }
+            RequestMethod::NftBuyOffers => {
+                serde_json::from_value(value).map(XRPLRequest::NftBuyOffers)
+            }
+            RequestMethod::NftSellOffers => {
+                serde_json::from_value(value).map(XRPLRequest::NftSellOffers)
+            }
+            RequestMethod::PathFind => serde_json::from_value(value).map(XRPLRequest::PathFind),
+            RequestMethod::RipplePathFind => {
+                serde_json::from_value(value).map(XRPLRequest::RipplePathFind)
+            }
+            RequestMethod::Ledger => serde_json::from_value(value).map(XRPLRequest::Ledger),
+            RequestMethod::LedgerAccept => {
+                serde_json::from_value(value).map(XRPLRequest::LedgerAccept)
+            }
+            RequestMethod::LedgerClosed => {
+                serde_json::from_value(value).map(XRPLRequest::LedgerClosed)
+            }
+            RequestMethod::LedgerCurrent => {
+                serde_json::from_value(value).map(XRPLRequest::LedgerCurrent)
+            }
+            RequestMethod::LedgerData => serde_json::from_value(value).map(XRPLRequest::LedgerData),
+            RequestMethod::LedgerEntry => {
+                serde_json::from_value(value).map(XRPLRequest::LedgerEntry)
+            }
+            RequestMethod::Subscribe => serde_json::from_value(value).map(XRPLRequest::Subscribe),
+            RequestMethod::Unsubscribe => {
+                serde_json::from_value(value).map(XRPLRequest::Unsubscribe)
+            }
+            RequestMethod::Fee => serde_json::from_value(value).map(XRPLRequest::Fee),
+            RequestMethod::Manifest => serde_json::from_value(value).map(XRPLRequest::Manifest),
+            RequestMethod::ServerInfo => serde_json::from_value(value).map(XRPLRequest::ServerInfo),
+            RequestMethod::ServerState => {
+                serde_json::from_value(value).map(XRPLRequest::ServerState)
+            }
+            RequestMethod::Ping => serde_json::from_value(value).map(XRPLRequest::Ping),
+            RequestMethod::Random => serde_json::from_value(value).map(XRPLRequest::Random),
+            RequestMethod::Sign | RequestMethod::SignFor | RequestMethod::Other => {
+                return Err(de::Error::custom(format!(
+                    "unsupported request method: {method}"
+                )))
+            }
+        }
+        .map_err(de::Error::custom)
+    }
+}
+
+fn raw_request_from_value<'a>(value: Value) -> Result<XRPLRequest<'a>, String> {
+    let Value::Object(mut params) = value else {
+        return Err("expected a request object".into());
+    };
+    let method = match params.remove("command") {
+        Some(Value::String(method)) => method,
+        command => return Err(format!("invalid command: {command:?}")),
+    };
+    let id = match params.remove("id") {
+        Some(Value::String(id)) => Some(Cow::Owned(id)),
+        _ => None,
+    };
+
+    Ok(XRPLRequest::Raw(raw::Raw::new(id, method.into(), params)))
 }
 
 impl<'a> From<account_channels::AccountChannels<'a>> for XRPLRequest<'a> {
@@ -210,6 +441,12 @@ impl<'a> From<no_ripple_check::NoRippleCheck<'a>> for XRPLRequest<'a> {
     }
 }
 
+impl<'a> From<simulate::Simulate<'a>> for XRPLRequest<'a> {
+    fn from(request: simulate::Simulate<'a>) -> Self {
+        XRPLRequest::Simulate(request)
+    }
+}
+
 impl<'a> From<submit::Submit<'a>> for XRPLRequest<'a> {
     fn from(request: submit::Submit<'a>) -> Self {
         XRPLRequest::Submit(request)
@@ -288,6 +525,12 @@ impl<'a> From<ledger::Ledger<'a>> for XRPLRequest<'a> {
     }
 }
 
+impl<'a> From<ledger_accept::LedgerAccept<'a>> for XRPLRequest<'a> {
+    fn from(request: ledger_accept::LedgerAccept<'a>) -> Self {
+        XRPLRequest::LedgerAccept(request)
+    }
+}
+
 impl<'a> From<ledger_closed::LedgerClosed<'a>> for XRPLRequest<'a> {
     fn from(request: ledger_closed::LedgerClosed<'a>) -> Self {
         XRPLRequest::LedgerClosed(request)
@@ -360,6 +603,12 @@ impl<'a> From<random::Random<'a>> for XRPLRequest<'a> {
     }
 }
 
+impl<'a> From<raw::Raw<'a>> for XRPLRequest<'a> {
+    fn from(request: raw::Raw<'a>) -> Self {
+        XRPLRequest::Raw(request)
+    }
+}
+
 impl<'a> Request<'a> for XRPLRequest<'a> {
     fn get_common_fields(&self) -> &CommonFields<'a> {
         match self {
@@ -374,6 +623,7 @@ impl<'a> Request<'a> for XRPLRequest<'a> {
             XRPLRequest::AMMInfo(request) => request.get_common_fields(),
             XRPLRequest::GatewayBalances(request) => request.get_common_fields(),
             XRPLRequest::NoRippleCheck(request) => request.get_common_fields(),
+            XRPLRequest::Simulate(request) => request.get_common_fields(),
             XRPLRequest::Submit(request) => request.get_common_fields(),
             XRPLRequest::SubmitMultisigned(request) => request.get_common_fields(),
             XRPLRequest::TransactionEntry(request) => request.get_common_fields(),
@@ -387,6 +637,7 @@ impl<'a> Request<'a> for XRPLRequest<'a> {
             XRPLRequest::PathFind(request) => request.get_common_fields(),
             XRPLRequest::RipplePathFind(request) => request.get_common_fields(),
             XRPLRequest::Ledger(request) => request.get_common_fields(),
+            XRPLRequest::LedgerAccept(request) => request.get_common_fields(),
             XRPLRequest::LedgerClosed(request) => request.get_common_fields(),
             XRPLRequest::LedgerCurrent(request) => request.get_common_fields(),
             XRPLRequest::LedgerData(request) => request.get_common_fields(),
@@ -399,6 +650,7 @@ impl<'a> Request<'a> for XRPLRequest<'a> {
             XRPLRequest::ServerState(request) => request.get_common_fields(),
             XRPLRequest::Ping(request) => request.get_common_fields(),
             XRPLRequest::Random(request) => request.get_common_fields(),
+            XRPLRequest::Raw(request) => request.get_common_fields(),
         }
     }
 
@@ -415,6 +667,7 @@ impl<'a> Request<'a> for XRPLRequest<'a> {
             XRPLRequest::AMMInfo(request) => request.get_common_fields_mut(),
             XRPLRequest::GatewayBalances(request) => request.get_common_fields_mut(),
             XRPLRequest::NoRippleCheck(request) => request.get_common_fields_mut(),
+            XRPLRequest::Simulate(request) => request.get_common_fields_mut(),
             XRPLRequest::Submit(request) => request.get_common_fields_mut(),
             XRPLRequest::SubmitMultisigned(request) => request.get_common_fields_mut(),
             XRPLRequest::TransactionEntry(request) => request.get_common_fields_mut(),
@@ -428,6 +681,7 @@ impl<'a> Request<'a> for XRPLRequest<'a> {
             XRPLRequest::PathFind(request) => request.get_common_fields_mut(),
             XRPLRequest::RipplePathFind(request) => request.get_common_fields_mut(),
             XRPLRequest::Ledger(request) => request.get_common_fields_mut(),
+            XRPLRequest::LedgerAccept(request) => request.get_common_fields_mut(),
             XRPLRequest::LedgerClosed(request) => request.get_common_fields_mut(),
             XRPLRequest::LedgerCurrent(request) => request.get_common_fields_mut(),
             XRPLRequest::LedgerData(request) => request.get_common_fields_mut(),
@@ -440,18 +694,32 @@ impl<'a> Request<'a> for XRPLRequest<'a> {
             XRPLRequest::ServerState(request) => request.get_common_fields_mut(),
             XRPLRequest::Ping(request) => request.get_common_fields_mut(),
             XRPLRequest::Random(request) => request.get_common_fields_mut(),
+            XRPLRequest::Raw(request) => request.get_common_fields_mut(),
         }
     }
 }
 
 /// The base fields for all request models.
+///
+/// Neither `command` nor `id` is part of a model's serialized
+/// parameters: the transport puts them into the request envelope, e.g.
+/// `command` and `id` next to the parameters for websockets, or
+/// `method` with the parameters wrapped in `params` for JSON-RPC.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, new)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct CommonFields<'a> {
     /// The request method.
+    #[serde(skip_serializing)]
     pub command: RequestMethod,
     /// The unique request id.
+    #[serde(skip_serializing)]
     pub id: Option<Cow<'a, str>>,
+    /// The version of the API to answer with. `None` leaves it to the
+    /// client, see `ApiVersion`, and then to the server, which
+    /// defaults to 1.
+    #[new(default)]
+    pub api_version: Option<u8>,
 }
 
 /// The base trait for all request models.
@@ -463,9 +731,26 @@ pub trait Request<'a> {
 
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct FundFaucet<'a> {
     pub destination: Cow<'a, str>,
     pub usage_context: Option<Cow<'a, str>>,
     pub user_agent: Option<Cow<'a, str>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_selects_variant_by_command() {
+        let request: XRPLRequest =
+            serde_json::from_str(r#"{"command":"server_state","id":"7"}"#).unwrap();
+
+        assert_eq!(request.method(), RequestMethod::ServerState);
+        assert_eq!(request.get_common_fields().id, Some("7".into()));
+        assert!(serde_json::from_str::<XRPLRequest>(r#"{"id":"7"}"#).is_err());
+        assert!(serde_json::from_str::<XRPLRequest>(r#"{"command":"sign"}"#).is_err());
+    }
+}
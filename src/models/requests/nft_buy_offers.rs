@@ -1,5 +1,6 @@
 use alloc::borrow::Cow;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
 
 use crate::models::{requests::RequestMethod, Model};
@@ -9,6 +10,7 @@ use super::{CommonFields, Request};
 /// This method retrieves all of buy offers for the specified NFToken.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct NftBuyOffers<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -26,7 +28,8 @@ pub struct NftBuyOffers<'a> {
     pub limit: Option<u16>,
     /// Value from a previous paginated response.
     /// Resume retrieving data where that response left off.
-    pub marker: Option<u32>,
+    /// The marker is opaque, pass it back as the server returned it.
+    pub marker: Option<Value>,
 }
 
 impl<'a> Model for NftBuyOffers<'a> {}
@@ -48,12 +51,13 @@ impl<'a> NftBuyOffers<'a> {
         ledger_hash: Option<Cow<'a, str>>,
         ledger_index: Option<Cow<'a, str>>,
         limit: Option<u16>,
-        marker: Option<u32>,
+        marker: Option<Value>,
     ) -> Self {
         Self {
             common_fields: CommonFields {
                 command: RequestMethod::NftBuyOffers,
                 id,
+                api_version: None,
             },
             nft_id,
             ledger_hash,
@@ -1,5 +1,6 @@
 use alloc::borrow::Cow;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
 
 use crate::models::{requests::RequestMethod, Model};
@@ -9,12 +10,26 @@ use super::{CommonFields, Request};
 /// This method retrieves all of sell offers for the specified NFToken.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct NftSellOffers<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
     pub common_fields: CommonFields<'a>,
     /// The unique identifier of a NFToken object.
     pub nft_id: Cow<'a, str>,
+    /// A 20-byte hex string for the ledger version to use.
+    pub ledger_hash: Option<Cow<'a, str>>,
+    /// The ledger index of the ledger to use, or a shortcut
+    /// string to choose a ledger automatically.
+    pub ledger_index: Option<Cow<'a, str>>,
+    /// Limit the number of NFT sell offers to retrieve.
+    /// This value cannot be lower than 50 or more than 500.
+    /// The default is 250.
+    pub limit: Option<u16>,
+    /// Value from a previous paginated response.
+    /// Resume retrieving data where that response left off.
+    /// The marker is opaque, pass it back as the server returned it.
+    pub marker: Option<Value>,
 }
 
 impl<'a> Model for NftSellOffers<'a> {}
@@ -30,13 +45,25 @@ impl<'a> Request<'a> for NftSellOffers<'a> {
 }
 
 impl<'a> NftSellOffers<'a> {
-    pub fn new(id: Option<Cow<'a, str>>, nft_id: Cow<'a, str>) -> Self {
+    pub fn new(
+        id: Option<Cow<'a, str>>,
+        nft_id: Cow<'a, str>,
+        ledger_hash: Option<Cow<'a, str>>,
+        ledger_index: Option<Cow<'a, str>>,
+        limit: Option<u16>,
+        marker: Option<Value>,
+    ) -> Self {
         Self {
             common_fields: CommonFields {
                 command: RequestMethod::NftSellOffers,
                 id,
+                api_version: None,
             },
             nft_id,
+            ledger_hash,
+            ledger_index,
+            limit,
+            marker,
         }
     }
 }
@@ -10,6 +10,7 @@ use super::{CommonFields, Request};
 /// Enum representing the options for the address role in
 /// a NoRippleCheckRequest.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Display)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[strum(serialize_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "role")]
@@ -29,6 +30,7 @@ pub enum NoRippleCheckRole {
 /// `<https://xrpl.org/noripple_check.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct NoRippleCheck<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -82,6 +84,7 @@ impl<'a> NoRippleCheck<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::NoRippleCheck,
                 id,
+                api_version: None,
             },
             account,
             role,
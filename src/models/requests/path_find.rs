@@ -3,13 +3,11 @@ use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::models::currency::Currency;
-use crate::models::{requests::RequestMethod, Model, PathStep};
+use crate::models::{requests::RequestMethod, Amount, Model};
 
 use super::{CommonFields, Request};
 
-/// A path is an array. Each member of a path is an object that specifies a step on that path.
-pub type Path<'a> = Vec<PathStep<'a>>;
+pub use crate::models::Path;
 
 /// There are three different modes, or sub-commands, of
 /// the path_find command. Specify which one you want with
@@ -21,6 +19,7 @@ pub type Path<'a> = Vec<PathStep<'a>>;
 /// See Path Find:
 /// `<https://xrpl.org/path_find.html>`
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum PathFindSubcommand {
     #[default]
@@ -58,6 +57,7 @@ pub enum PathFindSubcommand {
 /// `<https://xrpl.org/path_find.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct PathFind<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -71,7 +71,7 @@ pub struct PathFind<'a> {
     /// the value field (for non-XRP currencies). This requests a path
     /// to deliver as much as possible, while spending no more than
     /// the amount specified in send_max (if provided).
-    pub destination_amount: Currency<'a>,
+    pub destination_amount: Amount<'a>,
     /// Unique address of the account to find a path
     /// from. (In other words, the account that would
     /// be sending a payment.)
@@ -85,7 +85,7 @@ pub struct PathFind<'a> {
     pub paths: Option<Vec<Path<'a>>>,
     /// Currency Amount that would be spent in the transaction.
     /// Not compatible with source_currencies.
-    pub send_max: Option<Currency<'a>>,
+    pub send_max: Option<Amount<'a>>,
 }
 
 impl<'a> Model for PathFind<'a> {}
@@ -104,16 +104,17 @@ impl<'a> PathFind<'a> {
     pub fn new(
         id: Option<Cow<'a, str>>,
         destination_account: Cow<'a, str>,
-        destination_amount: Currency<'a>,
+        destination_amount: Amount<'a>,
         source_account: Cow<'a, str>,
         subcommand: PathFindSubcommand,
-        paths: Option<Vec<Vec<PathStep<'a>>>>,
-        send_max: Option<Currency<'a>>,
+        paths: Option<Vec<Path<'a>>>,
+        send_max: Option<Amount<'a>>,
     ) -> Self {
         Self {
             common_fields: CommonFields {
                 command: RequestMethod::PathFind,
                 id,
+                api_version: None,
             },
             subcommand,
             source_account,
@@ -13,6 +13,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/ping.html#ping>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Ping<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -37,6 +38,7 @@ impl<'a> Ping<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Ping,
                 id,
+                api_version: None,
             },
         }
     }
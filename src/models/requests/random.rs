@@ -14,6 +14,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/random.html#random>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Random<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -38,6 +39,7 @@ impl<'a> Random<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Random,
                 id,
+                api_version: None,
             },
         }
     }
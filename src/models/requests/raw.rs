@@ -0,0 +1,78 @@
+use alloc::{borrow::Cow, string::String};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use serde_with::skip_serializing_none;
+
+use crate::models::{requests::RequestMethod, Model};
+
+use super::{CommonFields, Request};
+
+/// A request given as its method name and JSON parameters, for
+/// methods the request models do not cover yet, e.g. one added by a
+/// new amendment.
+///
+/// The parameters are sent as they are; the transport adds the
+/// `command` (or JSON-RPC `method`) and `id` like for any request.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use serde_json::{json, Map};
+/// use xrpl::models::requests::{raw::Raw, RequestMethod, XRPLRequest};
+///
+/// let request = Raw::new(None, "vault_info".into(), Map::new());
+/// assert_eq!(request.common_fields.command, RequestMethod::Other);
+///
+/// // Methods with a model keep their `RequestMethod`.
+/// let request: XRPLRequest = Raw::new(None, "ledger_current".into(), Map::new()).into();
+/// assert_eq!(request.method(), RequestMethod::LedgerCurrent);
+/// assert_eq!(request.method_name(), "ledger_current");
+/// assert_eq!(serde_json::to_value(&request).unwrap(), json!({}));
+/// ```
+#[skip_serializing_none]
+#[derive(Debug, Serialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct Raw<'a> {
+    /// The common fields shared by all requests. The `command` is the
+    /// method's [`RequestMethod`] if it has one,
+    /// [`RequestMethod::Other`] otherwise.
+    #[serde(flatten)]
+    pub common_fields: CommonFields<'a>,
+    /// The name of the method, e.g. `ledger_current`.
+    #[serde(skip)]
+    pub method: Cow<'a, str>,
+    /// The parameters of the request.
+    #[serde(flatten)]
+    pub params: Map<String, Value>,
+}
+
+impl<'a> Model for Raw<'a> {}
+
+impl<'a> Request<'a> for Raw<'a> {
+    fn get_common_fields(&self) -> &CommonFields<'a> {
+        &self.common_fields
+    }
+
+    fn get_common_fields_mut(&mut self) -> &mut CommonFields<'a> {
+        &mut self.common_fields
+    }
+}
+
+impl<'a> Raw<'a> {
+    pub fn new(id: Option<Cow<'a, str>>, method: Cow<'a, str>, params: Map<String, Value>) -> Self {
+        let command =
+            serde_json::from_value(Value::from(method.as_ref())).unwrap_or(RequestMethod::Other);
+
+        Self {
+            common_fields: CommonFields {
+                command,
+                id,
+                api_version: None,
+            },
+            method,
+            params,
+        }
+    }
+}
@@ -3,11 +3,11 @@ use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::models::{currency::Currency, requests::RequestMethod, Model};
+use crate::models::{currency::Currency, requests::RequestMethod, Amount, Model};
 
 use super::{CommonFields, Request};
 
-/// The ripple_path_find method is a simpl<'a>ified version of
+/// The ripple_path_find method is a simplified version of
 /// the path_find method that provides a single response with
 /// a payment path you can use right away. It is available in
 /// both the WebSocket and JSON-RPC APIs. However, the
@@ -25,6 +25,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/ripple_path_find.html#ripple_path_find>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct RipplePathFind<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -38,7 +39,7 @@ pub struct RipplePathFind<'a> {
     /// of the value field (for non-XRP currencies). This requests a
     /// path to deliver as much as possible, while spending no more
     /// than the amount specified in send_max (if provided).
-    pub destination_amount: Currency<'a>,
+    pub destination_amount: Amount<'a>,
     /// Unique address of the account that would send funds
     /// in a transaction.
     pub source_account: Cow<'a, str>,
@@ -49,7 +50,7 @@ pub struct RipplePathFind<'a> {
     pub ledger_index: Option<Cow<'a, str>>,
     /// Currency Amount that would be spent in the transaction.
     /// Cannot be used with source_currencies.
-    pub send_max: Option<Currency<'a>>,
+    pub send_max: Option<Amount<'a>>,
     /// Array of currencies that the source account might want
     /// to spend. Each entry in the array should be a JSON object
     /// with a mandatory currency field and optional issuer field,
@@ -76,17 +77,18 @@ impl<'a> RipplePathFind<'a> {
     pub fn new(
         id: Option<Cow<'a, str>>,
         destination_account: Cow<'a, str>,
-        destination_amount: Currency<'a>,
+        destination_amount: Amount<'a>,
         source_account: Cow<'a, str>,
         ledger_hash: Option<Cow<'a, str>>,
         ledger_index: Option<Cow<'a, str>>,
-        send_max: Option<Currency<'a>>,
+        send_max: Option<Amount<'a>>,
         source_currencies: Option<Vec<Currency<'a>>>,
     ) -> Self {
         Self {
             common_fields: CommonFields {
                 command: RequestMethod::RipplePathFind,
                 id,
+                api_version: None,
             },
             destination_account,
             destination_amount,
@@ -14,6 +14,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/server_info.html#server_info>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct ServerInfo<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -38,6 +39,7 @@ impl<'a> ServerInfo<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::ServerInfo,
                 id,
+                api_version: None,
             },
         }
     }
@@ -19,6 +19,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/server_state.html#server_state>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct ServerState<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -43,6 +44,7 @@ impl<'a> ServerState<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::ServerState,
                 id,
+                api_version: None,
             },
         }
     }
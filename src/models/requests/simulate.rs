@@ -0,0 +1,133 @@
+use alloc::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::{requests::RequestMethod, Model, XRPLModelException, XRPLModelResult};
+
+use super::{CommonFields, Request};
+
+/// The simulate method dry-runs a transaction: the server applies
+/// it to its current ledger and reports the result and metadata,
+/// without submitting it to the network.
+///
+/// The transaction must not be signed. Missing auto-fillable
+/// fields, like `Fee` and `Sequence`, are filled in by the server.
+///
+/// See Simulate:
+/// `<https://xrpl.org/simulate.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use serde_json::json;
+/// use xrpl::models::requests::simulate::Simulate;
+/// use xrpl::models::Model;
+///
+/// let request = Simulate::new(
+///     None,
+///     Some(json!({
+///         "TransactionType": "AccountSet",
+///         "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys"
+///     })),
+///     None,
+///     None,
+/// );
+///
+/// assert!(request.validate().is_ok());
+/// ```
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct Simulate<'a> {
+    /// The common fields shared by all requests.
+    #[serde(flatten)]
+    pub common_fields: CommonFields<'a>,
+    /// If true, return the transaction and metadata as binary
+    /// serialized to hexadecimal strings. The default is false.
+    pub binary: Option<bool>,
+    /// The unsigned transaction to simulate, serialized to
+    /// hexadecimal, as an alternative to `tx_json`.
+    pub tx_blob: Option<Cow<'a, str>>,
+    /// The unsigned transaction to simulate, as JSON.
+    pub tx_json: Option<Value>,
+}
+
+impl<'a> Model for Simulate<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self._get_tx_json_or_tx_blob_error()
+    }
+}
+
+impl<'a> SimulateError for Simulate<'a> {
+    fn _get_tx_json_or_tx_blob_error(&self) -> XRPLModelResult<()> {
+        match (&self.tx_json, &self.tx_blob) {
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            _ => Err(XRPLModelException::ExpectedOneOf(&["tx_json", "tx_blob"])),
+        }
+    }
+}
+
+impl<'a> Request<'a> for Simulate<'a> {
+    fn get_common_fields(&self) -> &CommonFields<'a> {
+        &self.common_fields
+    }
+
+    fn get_common_fields_mut(&mut self) -> &mut CommonFields<'a> {
+        &mut self.common_fields
+    }
+}
+
+impl<'a> Simulate<'a> {
+    pub fn new(
+        id: Option<Cow<'a, str>>,
+        tx_json: Option<Value>,
+        tx_blob: Option<Cow<'a, str>>,
+        binary: Option<bool>,
+    ) -> Self {
+        Self {
+            common_fields: CommonFields {
+                command: RequestMethod::Simulate,
+                id,
+                api_version: None,
+            },
+            binary,
+            tx_blob,
+            tx_json,
+        }
+    }
+}
+
+pub trait SimulateError {
+    fn _get_tx_json_or_tx_blob_error(&self) -> XRPLModelResult<()>;
+}
+
+#[cfg(test)]
+mod test_simulate_errors {
+    use super::*;
+
+    #[test]
+    fn test_tx_json_or_tx_blob_error() {
+        let tx_json = serde_json::json!({ "TransactionType": "AccountSet" });
+
+        assert!(Simulate::new(None, Some(tx_json.clone()), None, None)
+            .validate()
+            .is_ok());
+        assert!(
+            Simulate::new(None, None, Some("1200032200".into()), Some(true))
+                .validate()
+                .is_ok()
+        );
+        assert_eq!(
+            Simulate::new(None, None, None, None).validate(),
+            Err(XRPLModelException::ExpectedOneOf(&["tx_json", "tx_blob"]))
+        );
+        assert!(
+            Simulate::new(None, Some(tx_json), Some("1200032200".into()), None)
+                .validate()
+                .is_err()
+        );
+    }
+}
@@ -35,6 +35,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/submit.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Submit<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -65,6 +66,7 @@ impl<'a> Submit<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Submit,
                 id,
+                api_version: None,
             },
             tx_blob,
             fail_hard,
@@ -19,6 +19,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/submit_multisigned.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct SubmitMultisigned<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -46,6 +47,7 @@ impl<'a> SubmitMultisigned<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::SubmitMultisigned,
                 id,
+                api_version: None,
             },
             fail_hard,
         }
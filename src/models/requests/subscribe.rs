@@ -14,7 +14,8 @@ use super::{CommonFields, Request};
 /// See Subscribe:
 /// `<https://xrpl.org/subscribe.html#subscribe>`
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, new)]
-#[serde(rename_all(serialize = "PascalCase", deserialize = "snake_case"))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
 pub struct SubscribeBook<'a> {
     pub taker: Cow<'a, str>,
     pub taker_gets: Currency<'a>,
@@ -28,6 +29,7 @@ pub struct SubscribeBook<'a> {
 /// Represents possible values of the streams query param
 /// for subscribe.
 #[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Display)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum StreamParameter {
     Consensus,
@@ -49,6 +51,7 @@ pub enum StreamParameter {
 /// `<https://xrpl.org/subscribe.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Subscribe<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -102,6 +105,7 @@ impl<'a> Subscribe<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Subscribe,
                 id,
+                api_version: None,
             },
             books,
             streams,
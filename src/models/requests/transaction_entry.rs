@@ -16,6 +16,7 @@ use super::{CommonFields, Request};
 /// `<https://xrpl.org/transaction_entry.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct TransactionEntry<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -52,6 +53,7 @@ impl<'a> TransactionEntry<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::TransactionEntry,
                 id,
+                api_version: None,
             },
             tx_hash,
             ledger_hash,
@@ -2,16 +2,48 @@ use alloc::borrow::Cow;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::models::{requests::RequestMethod, Model};
+use crate::{
+    models::{requests::RequestMethod, Model, XRPLModelException, XRPLModelResult},
+    utils::ctid::decode_ctid,
+};
 
-use super::{CommonFields, Request};
+use super::{builder::request_builder, CommonFields, Request};
 
 /// The tx method retrieves information on a single transaction.
 ///
 /// See Tx:
 /// `<https://xrpl.org/tx.html>`
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::requests::tx::Tx;
+/// use xrpl::models::requests::RequestMethod;
+///
+/// let request = Tx::builder()
+///     .transaction("C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9")
+///     .binary(false)
+///     .build();
+///
+/// assert_eq!(request.common_fields.command, RequestMethod::Tx);
+/// assert_eq!(request.binary, Some(false));
+/// assert_eq!(
+///     request,
+///     Tx::new(
+///         None,
+///         Some(false),
+///         None,
+///         None,
+///         None,
+///         Some("C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9".into()),
+///     )
+/// );
+/// ```
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Tx<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -20,6 +52,9 @@ pub struct Tx<'a> {
     /// serialized to hexadecimal strings. If false, return
     /// transaction data and metadata as JSON. The default is false.
     pub binary: Option<bool>,
+    /// The concise transaction identifier (CTID) of the transaction
+    /// to look up, as an alternative to `transaction`.
+    pub ctid: Option<Cow<'a, str>>,
     /// Use this with min_ledger to specify a range of up to 1000
     /// ledger indexes, ending with this ledger (inclusive). If the
     /// server cannot find the transaction, it confirms whether it
@@ -34,7 +69,32 @@ pub struct Tx<'a> {
     pub transaction: Option<Cow<'a, str>>,
 }
 
-impl<'a> Model for Tx<'a> {}
+impl<'a> Model for Tx<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self._get_transaction_or_ctid_error()
+    }
+}
+
+impl<'a> TxError for Tx<'a> {
+    fn _get_transaction_or_ctid_error(&self) -> XRPLModelResult<()> {
+        match (&self.transaction, &self.ctid) {
+            (Some(_), None) => Ok(()),
+            (None, Some(ctid)) => {
+                decode_ctid(ctid)?;
+
+                Ok(())
+            }
+            _ => Err(XRPLModelException::ExpectedOneOf(&["transaction", "ctid"])),
+        }
+    }
+}
+
+request_builder! {
+    Tx => TxBuilder {
+        strings ctid, transaction;
+        values binary: bool, max_ledger: u32, min_ledger: u32;
+    }
+}
 
 impl<'a> Request<'a> for Tx<'a> {
     fn get_common_fields(&self) -> &CommonFields<'a> {
@@ -50,6 +110,7 @@ impl<'a> Tx<'a> {
     pub fn new(
         id: Option<Cow<'a, str>>,
         binary: Option<bool>,
+        ctid: Option<Cow<'a, str>>,
         max_ledger: Option<u32>,
         min_ledger: Option<u32>,
         transaction: Option<Cow<'a, str>>,
@@ -58,11 +119,77 @@ impl<'a> Tx<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Tx,
                 id,
+                api_version: None,
             },
             binary,
+            ctid,
             min_ledger,
             max_ledger,
             transaction,
         }
     }
 }
+
+pub trait TxError {
+    fn _get_transaction_or_ctid_error(&self) -> XRPLModelResult<()>;
+}
+
+#[cfg(test)]
+mod test_tx_errors {
+    use super::*;
+
+    const HASH: &str = "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB13FD";
+
+    #[test]
+    fn test_transaction_or_ctid_error() {
+        assert!(Tx::new(None, None, None, None, None, Some(HASH.into()))
+            .validate()
+            .is_ok());
+        assert!(Tx::new(
+            None,
+            None,
+            Some("C005523E00000000".into()),
+            None,
+            None,
+            None
+        )
+        .validate()
+        .is_ok());
+        assert_eq!(
+            Tx::new(None, None, None, None, None, None).validate(),
+            Err(XRPLModelException::ExpectedOneOf(&["transaction", "ctid"]))
+        );
+        assert!(Tx::new(
+            None,
+            None,
+            Some("C005523E00000000".into()),
+            None,
+            None,
+            Some(HASH.into())
+        )
+        .validate()
+        .is_err());
+        assert!(
+            Tx::new(None, None, Some("C005523E0000000".into()), None, None, None)
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_serialize_ctid() {
+        let tx = Tx::new(
+            None,
+            None,
+            Some("C005523E00000000".into()),
+            None,
+            None,
+            None,
+        );
+
+        assert_eq!(
+            serde_json::to_value(&tx).unwrap(),
+            serde_json::json!({ "ctid": "C005523E00000000" })
+        );
+    }
+}
@@ -18,6 +18,7 @@ use super::{CommonFields, Request};
 /// See Unsubscribe:
 /// `<https://xrpl.org/unsubscribe.html>`
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, new)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all(serialize = "PascalCase", deserialize = "snake_case"))]
 pub struct UnsubscribeBook<'a> {
     pub taker_gets: Currency<'a>,
@@ -36,6 +37,7 @@ pub struct UnsubscribeBook<'a> {
 /// `<https://xrpl.org/unsubscribe.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub struct Unsubscribe<'a> {
     /// The common fields shared by all requests.
     #[serde(flatten)]
@@ -85,6 +87,7 @@ impl<'a> Unsubscribe<'a> {
             common_fields: CommonFields {
                 command: RequestMethod::Unsubscribe,
                 id,
+                api_version: None,
             },
             books,
             streams,
@@ -0,0 +1,115 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::{XRPAmount, XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+/// The result of `account_channels`.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AccountChannels<'a> {
+    pub account: Cow<'a, str>,
+    /// The payment channels the account is the source of.
+    pub channels: Vec<Channel<'a>>,
+    pub ledger_hash: Option<Cow<'a, str>>,
+    pub ledger_index: Option<u32>,
+    pub ledger_current_index: Option<u32>,
+    pub limit: Option<u32>,
+    /// Where to resume in a paginated response. Omitted on the last
+    /// page.
+    pub marker: Option<Value>,
+    pub validated: Option<bool>,
+}
+
+/// A payment channel, as listed by `account_channels`.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Channel<'a> {
+    /// The source account of the channel.
+    pub account: Cow<'a, str>,
+    /// The total amount of XRP, in drops, allocated to the channel.
+    pub amount: XRPAmount<'a>,
+    /// The amount of XRP, in drops, already paid out of the channel.
+    pub balance: XRPAmount<'a>,
+    pub channel_id: Cow<'a, str>,
+    pub destination_account: Cow<'a, str>,
+    /// How many seconds the source must wait to close the channel
+    /// while it still has XRP.
+    pub settle_delay: u32,
+    /// When the channel expires, in seconds since the Ripple Epoch.
+    pub expiration: Option<u32>,
+    /// When the channel can be closed at the latest, in seconds
+    /// since the Ripple Epoch.
+    pub cancel_after: Option<u32>,
+    pub destination_tag: Option<u32>,
+    /// The key claims against the channel are signed with, in base58.
+    pub public_key: Option<Cow<'a, str>>,
+    /// The key claims against the channel are signed with, in hex.
+    pub public_key_hex: Option<Cow<'a, str>>,
+    pub source_tag: Option<u32>,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for AccountChannels<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::AccountChannels(account_channels) => Ok(account_channels),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "AccountChannels".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::results::XRPLResponse;
+
+    #[test]
+    fn test_deserialize_account_channels() {
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "account": "rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH",
+                "channels": [{
+                    "account": "rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH",
+                    "amount": "1000",
+                    "balance": "0",
+                    "channel_id": "C7F634794B79DB40E87179A9D1BF05D05797AE7E92DF8E93FD6656E8C4BE3AE7",
+                    "destination_account": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+                    "public_key": "aBR7mdD75Ycs8DRhMgQ4EMUEmBArF8SEh1hfjrT2V9DQTLNbJVqw",
+                    "public_key_hex": "03CFD18E689434F032A4E84C63E2A3A6472D684EAF4FD52CA67742F3E24BAE81B2",
+                    "settle_delay": 60,
+                    "cancel_after": 787654400,
+                    "destination_tag": 20170428
+                }],
+                "ledger_hash": "27F530E5C93ED5C13994812787C1ED073C822BAEC7597964608F2C049C2ACD2D",
+                "ledger_index": 71766343,
+                "limit": 1,
+                "marker": "C7F634794B79DB40E87179A9D1BF05D05797AE7E92DF8E93FD6656E8C4BE3AE7,0",
+                "validated": true
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+        let account_channels = response.try_into_result::<AccountChannels>().unwrap();
+
+        let channel = &account_channels.channels[0];
+        assert_eq!(channel.amount, XRPAmount::from("1000"));
+        assert_eq!(channel.balance, XRPAmount::from("0"));
+        assert_eq!(channel.settle_delay, 60);
+        assert_eq!(channel.expiration, None);
+        assert_eq!(channel.cancel_after, Some(787654400));
+        assert_eq!(channel.destination_tag, Some(20170428));
+        assert!(account_channels.marker.is_some());
+    }
+}
@@ -0,0 +1,76 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+/// The result of `account_currencies`.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AccountCurrencies<'a> {
+    pub ledger_hash: Option<Cow<'a, str>>,
+    pub ledger_index: Option<u32>,
+    /// The currencies the account can receive, from its trust lines
+    /// that are not at their limit.
+    pub receive_currencies: Vec<Cow<'a, str>>,
+    /// The currencies the account can send, from its trust lines
+    /// with a positive balance.
+    pub send_currencies: Vec<Cow<'a, str>>,
+    pub validated: Option<bool>,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for AccountCurrencies<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::AccountCurrencies(account_currencies) => Ok(account_currencies),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "AccountCurrencies".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::results::XRPLResponse;
+
+    #[test]
+    fn test_deserialize_account_currencies() {
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "ledger_index": 11775844,
+                "receive_currencies": [
+                    "BTC", "CNY", "DYM", "EUR", "JOE", "MXN", "USD",
+                    "015841551A748AD2C1F76FF6ECB0CCCD00000000"
+                ],
+                "send_currencies": [
+                    "ASP", "BTC", "CHF", "CNY", "DYM", "EUR", "JOE", "JPY", "MXN", "USD"
+                ],
+                "validated": true
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+        let account_currencies = response.try_into_result::<AccountCurrencies>().unwrap();
+
+        assert_eq!(account_currencies.ledger_index, Some(11775844));
+        assert_eq!(account_currencies.receive_currencies.len(), 8);
+        assert_eq!(
+            account_currencies.receive_currencies[7],
+            "015841551A748AD2C1F76FF6ECB0CCCD00000000"
+        );
+        assert_eq!(account_currencies.send_currencies.len(), 10);
+        assert_eq!(account_currencies.send_currencies[0], "ASP");
+        assert_eq!(account_currencies.validated, Some(true));
+    }
+}
@@ -1,15 +1,106 @@
 use core::convert::TryFrom;
 
-use alloc::string::ToString;
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use serde_with::skip_serializing_none;
 
-use crate::models::{ledger::objects::AccountRoot, XRPLModelException, XRPLModelResult};
+use crate::models::{
+    amount::XRPAmount,
+    ledger::objects::{signer_list::SignerList, AccountRoot},
+    XRPLModelException, XRPLModelResult,
+};
 
 use super::{exceptions::XRPLResultException, XRPLResult};
 
+#[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "Map<String, Value>")]
 pub struct AccountInfo<'a> {
     pub account_data: AccountRoot<'a>,
+    /// Information about the account's queued transactions. Only
+    /// present when requested with `queue` on the current ledger.
+    pub queue_data: Option<QueueData<'a>>,
+    /// The signer lists of the account, at most one. Only present
+    /// when requested with `signer_lists`. API version 1 returns them
+    /// in `account_data`; they are read from there too.
+    pub signer_lists: Option<Vec<SignerList<'a>>>,
+}
+
+#[derive(Deserialize)]
+struct AccountInfoFields<'a> {
+    account_data: AccountRoot<'a>,
+    queue_data: Option<QueueData<'a>>,
+    signer_lists: Option<Vec<SignerList<'a>>>,
+}
+
+impl<'a> TryFrom<Map<String, Value>> for AccountInfo<'a> {
+    type Error = serde_json::Error;
+
+    fn try_from(mut result: Map<String, Value>) -> Result<Self, Self::Error> {
+        if let Some(Value::Object(account_data)) = result.get_mut("account_data") {
+            if let Some(signer_lists) = account_data.remove("signer_lists") {
+                result.entry("signer_lists").or_insert(signer_lists);
+            }
+        }
+        let fields: AccountInfoFields<'a> = serde_json::from_value(Value::Object(result))?;
+
+        Ok(Self {
+            account_data: fields.account_data,
+            queue_data: fields.queue_data,
+            signer_lists: fields.signer_lists,
+        })
+    }
+}
+
+/// The transactions of an account waiting in the server's queue.
+///
+/// See Queue Data:
+/// `<https://xrpl.org/account_info.html#queue-data>`
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QueueData<'a> {
+    /// Number of queued transactions from this account.
+    pub txn_count: u32,
+    /// Whether a queued transaction changes this account's ways
+    /// of authorizing transactions.
+    pub auth_change_queued: Option<bool>,
+    /// The lowest Sequence Number among the queued transactions.
+    pub lowest_sequence: Option<u32>,
+    /// The highest Sequence Number among the queued transactions.
+    pub highest_sequence: Option<u32>,
+    /// The most XRP the queued transactions could send or destroy.
+    pub max_spend_drops_total: Option<XRPAmount<'a>>,
+    /// The queued transactions. Omitted if the server does not
+    /// report them individually.
+    pub transactions: Option<Vec<QueuedTransaction<'a>>>,
+}
+
+/// A single transaction waiting in the server's queue.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QueuedTransaction<'a> {
+    /// Whether this transaction changes the account's ways of
+    /// authorizing transactions.
+    pub auth_change: bool,
+    /// The transaction cost of this transaction, in drops.
+    pub fee: XRPAmount<'a>,
+    /// The transaction cost of this transaction, relative to the
+    /// minimum cost for this type of transaction, in fee levels.
+    pub fee_level: Cow<'a, str>,
+    /// The most XRP this transaction could send or destroy.
+    pub max_spend_drops: XRPAmount<'a>,
+    /// The Sequence Number of this transaction, unless it uses a
+    /// Ticket.
+    pub seq: Option<u32>,
+    /// The Ticket this transaction uses instead of a Sequence Number.
+    pub ticket: Option<u32>,
+    #[serde(rename = "LastLedgerSequence")]
+    pub last_ledger_sequence: Option<u32>,
 }
 
 impl<'a> TryFrom<XRPLResult<'a>> for AccountInfo<'a> {
@@ -26,3 +117,88 @@ impl<'a> TryFrom<XRPLResult<'a>> for AccountInfo<'a> {
         }
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+
+    /// An `account_info` result of API version 2 with a signer list
+    /// of three signers and a quorum of 3.
+    pub(crate) fn account_info_with_signer_list() -> Value {
+        serde_json::json!({
+            "account_data": {
+                "Account": "rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8",
+                "Balance": "999999999960",
+                "Flags": 0,
+                "LedgerEntryType": "AccountRoot",
+                "OwnerCount": 1,
+                "PreviousTxnID": "4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42",
+                "PreviousTxnLgrSeq": 3,
+                "Sequence": 6,
+                "index": "92FA6A9FC8EA6018D5D16532D7795C91BFB0831355BDFDA177E86C8BF997985F"
+            },
+            "signer_lists": [{
+                "Flags": 65536,
+                "LedgerEntryType": "SignerList",
+                "OwnerNode": "0000000000000000",
+                "PreviousTxnID": "5904C0DC72C58A83AEFED2FFC5386356AA83FCA6A88C89D00646E51E687CDBE4",
+                "PreviousTxnLgrSeq": 16061435,
+                "SignerEntries": [
+                    { "SignerEntry": { "Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW", "SignerWeight": 2 } },
+                    { "SignerEntry": { "Account": "raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n", "SignerWeight": 1 } },
+                    { "SignerEntry": { "Account": "rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v", "SignerWeight": 1 } }
+                ],
+                "SignerListID": 0,
+                "SignerQuorum": 3,
+                "index": "A9C28A28B85CD533217F5C0A0C7767666B093FA58A0F2D80026FCC4CD932DDC7"
+            }],
+            "ledger_current_index": 7,
+            "validated": false
+        })
+    }
+
+    #[test]
+    fn test_deserialize_signer_lists() {
+        let account_info: AccountInfo =
+            serde_json::from_value(account_info_with_signer_list()).unwrap();
+
+        let signer_lists = account_info.signer_lists.unwrap();
+        assert_eq!(signer_lists.len(), 1);
+        let signer_list = &signer_lists[0];
+        assert_eq!(signer_list.signer_quorum, 3);
+        assert_eq!(
+            signer_list
+                .signer_entries
+                .iter()
+                .map(|entry| (entry.account.as_str(), entry.signer_weight))
+                .collect::<Vec<_>>(),
+            [
+                ("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW", 2),
+                ("raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n", 1),
+                ("rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deserialize_signer_lists_of_api_v1() {
+        let mut result = account_info_with_signer_list();
+        let signer_lists = result
+            .as_object_mut()
+            .unwrap()
+            .remove("signer_lists")
+            .unwrap();
+        result["account_data"]["signer_lists"] = signer_lists;
+
+        let account_info: AccountInfo = serde_json::from_value(result).unwrap();
+        let expected: AccountInfo =
+            serde_json::from_value(account_info_with_signer_list()).unwrap();
+        assert_eq!(account_info, expected);
+
+        let without: AccountInfo = serde_json::from_value(serde_json::json!({
+            "account_data": account_info_with_signer_list()["account_data"]
+        }))
+        .unwrap();
+        assert_eq!(without.signer_lists, None);
+    }
+}
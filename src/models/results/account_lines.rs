@@ -0,0 +1,128 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::{XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+/// The result of `account_lines`.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AccountLines<'a> {
+    pub account: Cow<'a, str>,
+    /// The trust lines of the account, from its perspective.
+    pub lines: Vec<TrustLine<'a>>,
+    pub ledger_hash: Option<Cow<'a, str>>,
+    pub ledger_index: Option<u32>,
+    pub ledger_current_index: Option<u32>,
+    pub limit: Option<u32>,
+    /// Where to resume in a paginated response. Omitted on the last
+    /// page.
+    pub marker: Option<Value>,
+    pub validated: Option<bool>,
+}
+
+/// A trust line, as listed by `account_lines` from the perspective
+/// of the requested account.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TrustLine<'a> {
+    /// The peer of the trust line.
+    pub account: Cow<'a, str>,
+    /// The balance of the account on the line. Positive if the peer
+    /// owes the account, negative if the account owes the peer.
+    pub balance: Cow<'a, str>,
+    pub currency: Cow<'a, str>,
+    /// The most the account is willing to be owed by the peer.
+    pub limit: Cow<'a, str>,
+    /// The most the peer is willing to be owed by the account.
+    pub limit_peer: Cow<'a, str>,
+    /// The rate incoming balances are valued at, per 1,000,000,000
+    /// units. 0 is face value.
+    pub quality_in: u32,
+    /// The rate outgoing balances are valued at, per 1,000,000,000
+    /// units. 0 is face value.
+    pub quality_out: u32,
+    pub no_ripple: Option<bool>,
+    pub no_ripple_peer: Option<bool>,
+    /// Whether the account authorized the peer to hold its currency.
+    pub authorized: Option<bool>,
+    /// Whether the peer authorized the account to hold its currency.
+    pub peer_authorized: Option<bool>,
+    /// Whether the account froze the line.
+    pub freeze: Option<bool>,
+    /// Whether the peer froze the line.
+    pub freeze_peer: Option<bool>,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for AccountLines<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::AccountLines(account_lines) => Ok(account_lines),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "AccountLines".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::results::XRPLResponse;
+
+    #[test]
+    fn test_deserialize_account_lines() {
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "ledger_current_index": 5,
+                "lines": [{
+                    "account": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+                    "balance": "-2.5",
+                    "currency": "USD",
+                    "limit": "0",
+                    "limit_peer": "100",
+                    "no_ripple_peer": true,
+                    "quality_in": 0,
+                    "quality_out": 0
+                }, {
+                    "account": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+                    "authorized": true,
+                    "balance": "0",
+                    "currency": "015841551A748AD2C1F76FF6ECB0CCCD00000000",
+                    "freeze": true,
+                    "limit": "0",
+                    "limit_peer": "1e15",
+                    "quality_in": 0,
+                    "quality_out": 0
+                }],
+                "validated": false
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+        let account_lines = response.try_into_result::<AccountLines>().unwrap();
+
+        assert_eq!(account_lines.ledger_current_index, Some(5));
+        assert_eq!(account_lines.marker, None);
+        let line = &account_lines.lines[0];
+        assert_eq!(line.balance, "-2.5");
+        assert_eq!(line.limit_peer, "100");
+        assert_eq!(line.no_ripple, None);
+        assert_eq!(line.no_ripple_peer, Some(true));
+        let line = &account_lines.lines[1];
+        assert_eq!(line.authorized, Some(true));
+        assert_eq!(line.freeze, Some(true));
+        assert_eq!(line.freeze_peer, None);
+    }
+}
@@ -0,0 +1,130 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::{XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AccountNfts<'a> {
+    pub account: Cow<'a, str>,
+    pub account_nfts: Vec<AccountNft<'a>>,
+    pub ledger_hash: Option<Cow<'a, str>>,
+    pub ledger_index: Option<u32>,
+    pub ledger_current_index: Option<u32>,
+    pub limit: Option<u32>,
+    /// Where to resume in a paginated response. Omitted on the last
+    /// page.
+    pub marker: Option<Value>,
+    pub validated: Option<bool>,
+}
+
+/// An NFToken owned by the account.
+///
+/// rippled names the fields as in the ledger, Clio answers some
+/// versions in lowercase. Both are accepted.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AccountNft<'a> {
+    #[serde(rename = "Flags", alias = "flags")]
+    pub flags: u32,
+    #[serde(rename = "Issuer", alias = "issuer")]
+    pub issuer: Cow<'a, str>,
+    #[serde(rename = "NFTokenID", alias = "nft_id", alias = "NFTokenId")]
+    pub nft_id: Cow<'a, str>,
+    #[serde(rename = "NFTokenTaxon", alias = "nft_taxon", alias = "taxon")]
+    pub nft_taxon: u32,
+    /// The sequence the issuer minted this NFToken with.
+    pub nft_serial: u32,
+    /// The hex-encoded URI of the NFToken, if it has one.
+    #[serde(rename = "URI", alias = "uri")]
+    pub uri: Option<Cow<'a, str>>,
+    /// The transfer fee, in tenths of a basis point.
+    #[serde(rename = "TransferFee", alias = "transfer_fee")]
+    pub transfer_fee: Option<u16>,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for AccountNfts<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::AccountNfts(account_nfts) => Ok(account_nfts),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "AccountNfts".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::results::XRPLResponse;
+
+    const NFT_ID: &str = "000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65";
+
+    #[test]
+    fn test_rippled_and_clio_responses() {
+        let rippled: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "account": "rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx",
+                "account_nfts": [{
+                    "Flags": 11,
+                    "Issuer": "rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx",
+                    "NFTokenID": NFT_ID,
+                    "NFTokenTaxon": 0,
+                    "TransferFee": 314,
+                    "URI": "697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C67747179353566627A6469",
+                    "nft_serial": 3429
+                }],
+                "ledger_current_index": 17906,
+                "limit": 100,
+                "marker": "000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D64",
+                "validated": false
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+        let clio: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "account": "rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx",
+                "account_nfts": [{
+                    "flags": 11,
+                    "issuer": "rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx",
+                    "nft_id": NFT_ID,
+                    "nft_taxon": 0,
+                    "nft_serial": 3429,
+                    "transfer_fee": 314,
+                    "uri": "697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C67747179353566627A6469"
+                }],
+                "ledger_hash": "9D7F3E3B0A4A3C5F4E8C2A1B3D5F7E9A1C3E5F7A9B1D3F5E7A9C1E3F5A7B9D1F",
+                "ledger_index": 17905,
+                "limit": 100,
+                "validated": true
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+
+        let rippled = rippled.try_into_result::<AccountNfts>().unwrap();
+        let clio = clio.try_into_result::<AccountNfts>().unwrap();
+        assert_eq!(rippled.account_nfts, clio.account_nfts);
+        let nft = &clio.account_nfts[0];
+        assert_eq!(nft.nft_id, NFT_ID);
+        assert_eq!(nft.flags, 11);
+        assert_eq!(nft.nft_serial, 3429);
+        assert_eq!(nft.transfer_fee, Some(314));
+        assert!(rippled.marker.is_some());
+        assert!(clio.marker.is_none());
+    }
+}
@@ -0,0 +1,83 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::{XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AccountObjects<'a> {
+    pub account: Cow<'a, str>,
+    /// The objects owned by the account, in their raw ledger format.
+    pub account_objects: Vec<Value>,
+    pub ledger_hash: Option<Cow<'a, str>>,
+    pub ledger_index: Option<u32>,
+    pub ledger_current_index: Option<u32>,
+    pub limit: Option<u32>,
+    /// Where to resume in a paginated response. Omitted on the last
+    /// page.
+    pub marker: Option<Value>,
+    pub validated: Option<bool>,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for AccountObjects<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::AccountObjects(account_objects) => Ok(account_objects),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "AccountObjects".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::results::XRPLResponse;
+
+    #[test]
+    fn test_deserialize_account_objects() {
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "account": "rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx",
+                "account_objects": [{
+                    "Account": "rsuHaTvJh1bDmDoxX9QcKP7HEBSBt4XsHx",
+                    "Flags": 0,
+                    "LedgerEntryType": "Ticket",
+                    "OwnerNode": "0000000000000000",
+                    "PreviousTxnID": "F19AD4577212D3BEACA0F75FE1BA1644F2E854D46E8D62E9C95D18E9708CBFB1",
+                    "PreviousTxnLgrSeq": 4,
+                    "TicketSequence": 3,
+                    "index": "A5B5D4DCA5F3C4F6F3C1E3D1B3D5F7E9A1C3E5F7A9B1D3F5E7A9C1E3F5A7B9D1"
+                }],
+                "ledger_hash": "9D7F3E3B0A4A3C5F4E8C2A1B3D5F7E9A1C3E5F7A9B1D3F5E7A9C1E3F5A7B9D1F",
+                "ledger_index": 17905,
+                "limit": 200,
+                "marker": "F60ADF645E78B69857D2E4AEC8B7742FEABC8431BD8611D099B428C3E816DF93,94A9F05FEF9A153229E2E997E64919FD75AAE2FB",
+                "validated": true
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+        let account_objects = response.try_into_result::<AccountObjects>().unwrap();
+
+        assert_eq!(account_objects.account_objects.len(), 1);
+        assert_eq!(
+            account_objects.account_objects[0]["LedgerEntryType"],
+            "Ticket"
+        );
+        assert_eq!(account_objects.ledger_index, Some(17905));
+        assert!(account_objects.marker.is_some());
+    }
+}
@@ -1,14 +1,18 @@
 use core::convert::TryFrom;
 
-use alloc::{borrow::Cow, string::ToString, vec::Vec};
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
 
 use crate::models::{
     results::exceptions::XRPLResultException, XRPLModelException, XRPLModelResult,
 };
 
-use super::XRPLResult;
+use super::{api_version::normalize_account_transaction, XRPLResult};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct AccountTx<'a> {
@@ -17,10 +21,29 @@ pub struct AccountTx<'a> {
     pub ledger_index_max: Option<u32>,
     pub limit: Option<u16>,
     pub marker: Option<Value>,
+    /// The transactions, in the layout of API version 1: the fields
+    /// of each transaction in `tx`, next to its `meta`. Those of
+    /// version 2, in `tx_json`, are moved there.
+    #[serde(deserialize_with = "deserialize_transactions")]
     pub transactions: Vec<Value>,
     pub validated: Option<bool>,
 }
 
+fn deserialize_transactions<'de, D>(deserializer: D) -> Result<Vec<Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let transactions = Vec::<Map<String, Value>>::deserialize(deserializer)?;
+
+    Ok(transactions
+        .into_iter()
+        .map(|mut transaction| {
+            normalize_account_transaction(&mut transaction);
+            Value::Object(transaction)
+        })
+        .collect())
+}
+
 impl<'a> TryFrom<XRPLResult<'a>> for AccountTx<'a> {
     type Error = XRPLModelException;
 
@@ -35,3 +58,96 @@ impl<'a> TryFrom<XRPLResult<'a>> for AccountTx<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::results::XRPLResponse;
+
+    fn account_tx(transaction: Value) -> AccountTx<'static> {
+        serde_json::from_value::<XRPLResponse>(serde_json::json!({
+            "result": {
+                "account": "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn",
+                "ledger_index_max": 349758,
+                "ledger_index_min": 32570,
+                "limit": 10,
+                "transactions": [transaction],
+                "validated": true
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap()
+        .try_into_result()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_api_v2() {
+        let meta = serde_json::json!({
+            "AffectedNodes": [],
+            "TransactionIndex": 1,
+            "TransactionResult": "tesSUCCESS",
+            "delivered_amount": "1000000"
+        });
+        let v1 = account_tx(serde_json::json!({
+            "meta": meta,
+            "tx": {
+                "Account": "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn",
+                "Amount": "1000000",
+                "DeliverMax": "1000000",
+                "Destination": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+                "Fee": "12",
+                "Sequence": 5,
+                "TransactionType": "Payment",
+                "date": 754558840,
+                "hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB13FD",
+                "inLedger": 349758,
+                "ledger_index": 349758
+            },
+            "validated": true
+        }));
+        let v2 = account_tx(serde_json::json!({
+            "close_time_iso": "2023-11-29T07:40:40Z",
+            "hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB13FD",
+            "ledger_hash": "3652D7FD0576BC452C0D2E9B747BDD733075971D1A9A1D98125055DEF428721A",
+            "ledger_index": 349758,
+            "meta": meta,
+            "tx_json": {
+                "Account": "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn",
+                "DeliverMax": "1000000",
+                "Destination": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+                "Fee": "12",
+                "Sequence": 5,
+                "TransactionType": "Payment"
+            },
+            "validated": true
+        }));
+
+        for account_tx in [&v1, &v2] {
+            let transaction = &account_tx.transactions[0];
+            assert_eq!(transaction["meta"], meta);
+            assert_eq!(transaction["validated"], true);
+            for field in ["Amount", "date", "hash", "ledger_index", "Sequence"] {
+                assert_eq!(
+                    transaction["tx"][field], v1.transactions[0]["tx"][field],
+                    "{field}"
+                );
+            }
+        }
+        assert!(v2.transactions[0].get("tx_json").is_none());
+
+        // Binary results name the metadata `meta_blob`.
+        let binary = account_tx(serde_json::json!({
+            "ledger_index": 349758,
+            "meta_blob": "201C00000001F8E311006F5620",
+            "tx_blob": "12000022800000002400000005",
+            "validated": true
+        }));
+        assert_eq!(binary.transactions[0]["meta"], "201C00000001F8E311006F5620");
+        assert_eq!(
+            binary.transactions[0]["tx_blob"],
+            "12000022800000002400000005"
+        );
+    }
+}
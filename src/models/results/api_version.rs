@@ -0,0 +1,105 @@
+//! Reading results of API version 2 into the models, which follow
+//! the layout of version 1.
+//!
+//! Version 2 puts the fields of a transaction in `tx_json` instead of
+//! next to its `hash` and `meta`, drops `date` for `close_time_iso`,
+//! renames the binary `meta` to `meta_blob`, drops the `Amount` of
+//! payments for `DeliverMax`, and returns a ledger's `ledger_index`
+//! as a number.
+//!
+//! See API-CHANGELOG:
+//! `<https://github.com/XRPLF/rippled/blob/develop/API-CHANGELOG.md>`
+
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+};
+use chrono::DateTime;
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+use crate::utils::time_conversion::posix_to_ripple_time;
+
+/// Move the fields of a transaction out of its `tx_json`, as `tx`
+/// and the expanded transactions of `ledger` return them in version
+/// 2.
+pub(crate) fn normalize_transaction(transaction: &mut Map<String, Value>) {
+    if let Some(Value::Object(tx_json)) = take_object(transaction, "tx_json") {
+        for (field, value) in tx_json {
+            transaction.entry(field).or_insert(value);
+        }
+    }
+    rename(transaction, "meta_blob", "meta");
+    if let Some(date) = date(transaction) {
+        transaction.entry("date").or_insert(date.into());
+    }
+    normalize_tx_json(transaction);
+}
+
+/// Move the fields of a transaction of `account_tx` into its `tx`,
+/// with its `hash`, `ledger_index` and `date`, as version 1 returns
+/// them.
+pub(crate) fn normalize_account_transaction(entry: &mut Map<String, Value>) {
+    if let Some(Value::Object(mut tx)) = take_object(entry, "tx_json") {
+        for field in ["hash", "ledger_index", "ctid"] {
+            if let Some(value) = entry.get(field) {
+                tx.entry(field).or_insert(value.clone());
+            }
+        }
+        if let Some(date) = date(entry) {
+            tx.entry("date").or_insert(date.into());
+        }
+        normalize_tx_json(&mut tx);
+        entry.insert("tx".into(), Value::Object(tx));
+    }
+    rename(entry, "meta_blob", "meta");
+}
+
+/// Give a payment its `Amount` if it only has `DeliverMax`.
+pub(crate) fn normalize_tx_json(tx_json: &mut Map<String, Value>) {
+    if let Some(deliver_max) = tx_json.get("DeliverMax") {
+        if !tx_json.contains_key("Amount") {
+            let amount = deliver_max.clone();
+            tx_json.insert("Amount".into(), amount);
+        }
+    }
+}
+
+/// Read a `ledger_index` given as a string, like version 1 does, or
+/// as a number, like version 2 does.
+pub(crate) fn deserialize_ledger_index<'de, 'a, D>(
+    deserializer: D,
+) -> Result<Cow<'a, str>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::String(ledger_index) => Ok(ledger_index.into()),
+        Value::Number(ledger_index) => Ok(ledger_index.to_string().into()),
+        found => Err(serde::de::Error::custom(alloc::format!(
+            "expected a ledger index, found {found}"
+        ))),
+    }
+}
+
+fn take_object(map: &mut Map<String, Value>, field: &str) -> Option<Value> {
+    match map.get(field) {
+        Some(Value::Object(_)) => map.remove(field),
+        _ => None,
+    }
+}
+
+fn rename(map: &mut Map<String, Value>, from: &str, to: &str) {
+    if let Some(value) = map.remove(from) {
+        map.entry(to).or_insert(value);
+    }
+}
+
+/// The close time of the ledger of a transaction, in seconds since
+/// the Ripple Epoch, from its `close_time_iso`.
+fn date(transaction: &Map<String, Value>) -> Option<i64> {
+    let close_time = transaction.get("close_time_iso")?.as_str()?;
+    let close_time = DateTime::parse_from_rfc3339(close_time).ok()?;
+
+    posix_to_ripple_time(close_time.timestamp()).ok()
+}
@@ -0,0 +1,152 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{ledger::objects::Offer, Amount, XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+/// The result of `book_offers`.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BookOffers<'a> {
+    /// The offers of the book, best quality first.
+    pub offers: Vec<BookOffer<'a>>,
+    pub ledger_current_index: Option<u32>,
+    pub ledger_hash: Option<Cow<'a, str>>,
+    pub ledger_index: Option<u32>,
+    pub validated: Option<bool>,
+}
+
+/// An offer of an order book, as listed by `book_offers`.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BookOffer<'a> {
+    /// The Offer ledger entry.
+    #[serde(flatten)]
+    pub offer: Offer<'a>,
+    /// The balance the owner has of the currency the offer sells.
+    /// Only listed for the first offer of each owner.
+    pub owner_funds: Option<Cow<'a, str>>,
+    /// The exchange rate of the offer, as what the taker pays
+    /// divided by what the taker gets. XRP is counted in drops.
+    pub quality: Option<Cow<'a, str>>,
+    /// What the taker would get, if the owner cannot fund the whole
+    /// offer.
+    pub taker_gets_funded: Option<Amount<'a>>,
+    /// What the taker would pay, if the owner cannot fund the whole
+    /// offer.
+    pub taker_pays_funded: Option<Amount<'a>>,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for BookOffers<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::BookOffers(book_offers) => Ok(book_offers),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "BookOffers".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{results::XRPLResponse, IssuedCurrencyAmount};
+
+    #[test]
+    fn test_deserialize_book_offers() {
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "ledger_current_index": 7035305,
+                "offers": [{
+                    "Account": "rM3X3QSr8icjTGpaF52dozhbT2BZSXJQYM",
+                    "BookDirectory": "7E5F614417C2D0A7CEFEB73C4AA773ED5B078DE2B5771F6D55055E4C405218EB",
+                    "BookNode": "0000000000000000",
+                    "Flags": 0,
+                    "LedgerEntryType": "Offer",
+                    "OwnerNode": "0000000000000AE0",
+                    "PreviousTxnID": "6956221794397C25A53647182E5C78A439766D600724074C99D78982E37599F1",
+                    "PreviousTxnLgrSeq": 7022646,
+                    "Sequence": 264542,
+                    "TakerGets": {
+                        "currency": "EUR",
+                        "issuer": "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq",
+                        "value": "17.90363633316433"
+                    },
+                    "TakerPays": {
+                        "currency": "USD",
+                        "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                        "value": "27.05340557506234"
+                    },
+                    "index": "96A9104BF3137131FF8310B9174F3B37170E2144C813CA2A1695DF2C5677E811",
+                    "quality": "1.511056473200875"
+                }, {
+                    "Account": "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq",
+                    "BookDirectory": "7E5F614417C2D0A7CEFEB73C4AA773ED5B078DE2B5771F6D55055E4C405218EB",
+                    "BookNode": "0000000000000000",
+                    "Flags": 131072,
+                    "LedgerEntryType": "Offer",
+                    "OwnerNode": "0000000000000001",
+                    "PreviousTxnID": "C4B3A4D3C6B3D4E1A2C3B4D5E6F7A8B9C0D1E2F3A4B5C6D7E8F9A0B1C2D3E4F5",
+                    "PreviousTxnLgrSeq": 7035200,
+                    "Sequence": 12,
+                    "TakerGets": {
+                        "currency": "EUR",
+                        "issuer": "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq",
+                        "value": "10"
+                    },
+                    "TakerPays": {
+                        "currency": "USD",
+                        "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                        "value": "16"
+                    },
+                    "index": "D9A2AD6B8CF3F6F1B2EE5B4A3D0C8A9F5E7C6B2A1D3F4E5C6B7A8D9E0F1A2B3C",
+                    "owner_funds": "4",
+                    "quality": "1.6",
+                    "taker_gets_funded": {
+                        "currency": "EUR",
+                        "issuer": "rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq",
+                        "value": "4"
+                    },
+                    "taker_pays_funded": {
+                        "currency": "USD",
+                        "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                        "value": "6.4"
+                    }
+                }],
+                "validated": false
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+        let book_offers = response.try_into_result::<BookOffers>().unwrap();
+
+        assert_eq!(book_offers.ledger_current_index, Some(7035305));
+        assert_eq!(book_offers.offers.len(), 2);
+        let offer = &book_offers.offers[0];
+        assert_eq!(offer.offer.account, "rM3X3QSr8icjTGpaF52dozhbT2BZSXJQYM");
+        assert_eq!(offer.offer.sequence, 264542);
+        assert_eq!(offer.quality.as_deref(), Some("1.511056473200875"));
+        assert_eq!(offer.taker_gets_funded, None);
+
+        let offer = &book_offers.offers[1];
+        assert_eq!(offer.owner_funds.as_deref(), Some("4"));
+        assert_eq!(
+            offer.taker_pays_funded,
+            Some(Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                "6.4".into()
+            )))
+        );
+    }
+}
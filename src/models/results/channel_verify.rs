@@ -0,0 +1,30 @@
+use core::convert::TryFrom;
+
+use alloc::string::ToString;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+/// The result of `channel_verify`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChannelVerify {
+    /// Whether the signature authorizes the amount from the channel.
+    pub signature_verified: bool,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for ChannelVerify {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::ChannelVerify(channel_verify) => Ok(channel_verify),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "ChannelVerify".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+/// The result of `deposit_authorized`.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DepositAuthorized<'a> {
+    /// Whether the source account may send payments directly to
+    /// the destination account.
+    pub deposit_authorized: bool,
+    pub destination_account: Cow<'a, str>,
+    pub source_account: Cow<'a, str>,
+    /// The IDs of the credentials checked, as given in the request.
+    pub credentials: Option<Vec<Cow<'a, str>>>,
+    pub ledger_hash: Option<Cow<'a, str>>,
+    pub ledger_index: Option<u32>,
+    pub ledger_current_index: Option<u32>,
+    pub validated: Option<bool>,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for DepositAuthorized<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::DepositAuthorized(deposit_authorized) => Ok(deposit_authorized),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "DepositAuthorized".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::results::XRPLResponse;
+
+    #[test]
+    fn test_deserialize_deposit_authorized() {
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "credentials": [
+                    "A182EFBD154C9E80195082F86C1C8952FC0760A654B886F61BB0A59803B4387B"
+                ],
+                "deposit_authorized": true,
+                "destination_account": "rsUiUMpnrgxQp24dJYZDhmV4bE3aBtQyt8",
+                "ledger_hash": "BD03A10653ED9D77DCA859B7A735BF0580088A8F287FA2C5403E0A19C58EF322",
+                "ledger_index": 8,
+                "source_account": "rEhxGqkqPPSxQ3P25J66ft5TwpzV14k2de",
+                "validated": true
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+        let deposit_authorized = response.try_into_result::<DepositAuthorized>().unwrap();
+
+        assert!(deposit_authorized.deposit_authorized);
+        assert_eq!(
+            deposit_authorized.credentials.unwrap(),
+            ["A182EFBD154C9E80195082F86C1C8952FC0760A654B886F61BB0A59803B4387B"]
+        );
+        assert_eq!(deposit_authorized.ledger_index, Some(8));
+    }
+}
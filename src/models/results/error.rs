@@ -0,0 +1,248 @@
+use alloc::string::{String, ToString};
+use core::fmt::Display;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// The `error` of a failed request.
+///
+/// See API Error Codes:
+/// `<https://xrpl.org/error-formatting.html>`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum XRPLErrorCode {
+    /// The address is valid, but the account does not exist in the
+    /// ledger.
+    ActNotFound,
+    ActMalformed,
+    /// The server is amendment blocked and can't answer until it is
+    /// updated.
+    AmendmentBlocked,
+    EntryNotFound,
+    HighFee,
+    Internal,
+    InvalidParams,
+    /// The ledger does not exist, or the server doesn't have it.
+    LgrNotFound,
+    /// The server has no closed ledger, typically while starting up.
+    NoClosed,
+    /// The server has no current ledger, typically while starting up.
+    NoCurrent,
+    /// The server is not connected to the network.
+    NoNetwork,
+    /// The server is not in sync with the network.
+    NotSynced,
+    /// The requested object does not exist, e.g. the offers of an
+    /// NFToken nobody made an offer for.
+    ObjectNotFound,
+    /// Too many requests from this client.
+    SlowDown,
+    /// The server is under too much load.
+    TooBusy,
+    TxnNotFound,
+    UnknownCmd,
+    /// Any error not known to this library.
+    Other(String),
+}
+
+impl XRPLErrorCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            XRPLErrorCode::ActNotFound => "actNotFound",
+            XRPLErrorCode::ActMalformed => "actMalformed",
+            XRPLErrorCode::AmendmentBlocked => "amendmentBlocked",
+            XRPLErrorCode::EntryNotFound => "entryNotFound",
+            XRPLErrorCode::HighFee => "highFee",
+            XRPLErrorCode::Internal => "internal",
+            XRPLErrorCode::InvalidParams => "invalidParams",
+            XRPLErrorCode::LgrNotFound => "lgrNotFound",
+            XRPLErrorCode::NoClosed => "noClosed",
+            XRPLErrorCode::NoCurrent => "noCurrent",
+            XRPLErrorCode::NoNetwork => "noNetwork",
+            XRPLErrorCode::NotSynced => "notSynced",
+            XRPLErrorCode::ObjectNotFound => "objectNotFound",
+            XRPLErrorCode::SlowDown => "slowDown",
+            XRPLErrorCode::TooBusy => "tooBusy",
+            XRPLErrorCode::TxnNotFound => "txnNotFound",
+            XRPLErrorCode::UnknownCmd => "unknownCmd",
+            XRPLErrorCode::Other(error) => error,
+        }
+    }
+
+    /// Whether the same request may succeed later, once the server
+    /// synced or its load went down.
+    pub fn is_retriable(&self) -> bool {
+        matches!(
+            self,
+            XRPLErrorCode::NoClosed
+                | XRPLErrorCode::NoCurrent
+                | XRPLErrorCode::NoNetwork
+                | XRPLErrorCode::NotSynced
+                | XRPLErrorCode::SlowDown
+                | XRPLErrorCode::TooBusy
+        )
+    }
+}
+
+impl From<&str> for XRPLErrorCode {
+    fn from(error: &str) -> Self {
+        match error {
+            "actNotFound" => XRPLErrorCode::ActNotFound,
+            "actMalformed" => XRPLErrorCode::ActMalformed,
+            "amendmentBlocked" => XRPLErrorCode::AmendmentBlocked,
+            "entryNotFound" => XRPLErrorCode::EntryNotFound,
+            "highFee" => XRPLErrorCode::HighFee,
+            "internal" => XRPLErrorCode::Internal,
+            "invalidParams" => XRPLErrorCode::InvalidParams,
+            "lgrNotFound" => XRPLErrorCode::LgrNotFound,
+            "noClosed" => XRPLErrorCode::NoClosed,
+            "noCurrent" => XRPLErrorCode::NoCurrent,
+            "noNetwork" => XRPLErrorCode::NoNetwork,
+            "notSynced" => XRPLErrorCode::NotSynced,
+            "objectNotFound" => XRPLErrorCode::ObjectNotFound,
+            "slowDown" => XRPLErrorCode::SlowDown,
+            "tooBusy" => XRPLErrorCode::TooBusy,
+            "txnNotFound" => XRPLErrorCode::TxnNotFound,
+            "unknownCmd" => XRPLErrorCode::UnknownCmd,
+            error => XRPLErrorCode::Other(error.to_string()),
+        }
+    }
+}
+
+impl Display for XRPLErrorCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for XRPLErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for XRPLErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let error = String::deserialize(deserializer)?;
+
+        Ok(XRPLErrorCode::from(error.as_str()))
+    }
+}
+
+/// The error a server answered a request with.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct XRPLErrorResponse {
+    pub error: XRPLErrorCode,
+    pub error_code: Option<i32>,
+    pub error_message: Option<String>,
+    /// The request, as echoed by the server.
+    pub request: Option<Value>,
+}
+
+impl XRPLErrorResponse {
+    /// Whether the same request may succeed later.
+    pub fn is_retriable(&self) -> bool {
+        self.error.is_retriable()
+    }
+}
+
+impl Display for XRPLErrorResponse {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.error_message {
+            Some(error_message) => write!(f, "{}: {}", self.error, error_message),
+            None => write!(f, "{}", self.error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        results::{account_info::AccountInfo, exceptions::XRPLResultException, XRPLResponse},
+        XRPLModelException,
+    };
+
+    fn error_response(response: Value) -> XRPLErrorResponse {
+        let response: XRPLResponse = serde_json::from_value(response).unwrap();
+        match response.try_into_result::<AccountInfo>() {
+            Err(XRPLModelException::XRPLResultError(XRPLResultException::ErrorResponse(
+                error_response,
+            ))) => error_response,
+            other => panic!("expected an error response, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_websocket_act_not_found() {
+        let error_response = error_response(serde_json::json!({
+            "error": "actNotFound",
+            "error_code": 19,
+            "error_message": "Account not found.",
+            "id": "1",
+            "request": {
+                "account": "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn",
+                "command": "account_info",
+                "id": "1",
+                "ledger_index": "validated"
+            },
+            "status": "error",
+            "type": "response"
+        }));
+
+        assert_eq!(error_response.error, XRPLErrorCode::ActNotFound);
+        assert_eq!(error_response.error_code, Some(19));
+        assert_eq!(
+            error_response.request.unwrap()["account"],
+            "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn"
+        );
+        assert!(!error_response.error.is_retriable());
+    }
+
+    #[test]
+    fn test_json_rpc_invalid_params() {
+        // JSON-RPC nests the error in the result.
+        let error_response = error_response(serde_json::json!({
+            "result": {
+                "error": "invalidParams",
+                "error_code": 31,
+                "error_message": "Missing field 'account'.",
+                "request": {
+                    "command": "account_info",
+                    "ledger_index": "validated"
+                },
+                "status": "error"
+            }
+        }));
+
+        assert_eq!(error_response.error, XRPLErrorCode::InvalidParams);
+        assert_eq!(
+            error_response.to_string(),
+            "invalidParams: Missing field 'account'."
+        );
+    }
+
+    #[test]
+    fn test_too_busy_is_retriable() {
+        let error_response = error_response(serde_json::json!({
+            "error": "tooBusy",
+            "error_code": 9,
+            "error_message": "The server is too busy to help you now.",
+            "status": "error",
+            "type": "response"
+        }));
+
+        assert_eq!(error_response.error, XRPLErrorCode::TooBusy);
+        assert!(error_response.is_retriable());
+
+        // Unknown errors are kept as they are.
+        let error: XRPLErrorCode = serde_json::from_str(r#""newError""#).unwrap();
+        assert_eq!(error, XRPLErrorCode::Other("newError".into()));
+        assert_eq!(serde_json::to_string(&error).unwrap(), r#""newError""#);
+    }
+}
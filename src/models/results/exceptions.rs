@@ -1,13 +1,13 @@
 use alloc::string::String;
 use thiserror_no_std::Error;
 
-use super::XRPLOtherResult;
+use super::{error::XRPLErrorResponse, XRPLOtherResult};
 
 #[derive(Debug, PartialEq, Error)]
 #[non_exhaustive]
 pub enum XRPLResultException {
     #[error("Response error: {0}")]
-    ResponseError(String),
+    ErrorResponse(XRPLErrorResponse),
     #[error("Expected result or error in the response.")]
     ExpectedResultOrError,
     #[error("Unexpected result type (expected {0:?}, got {1:?}).")]
@@ -18,4 +18,16 @@ pub enum XRPLResultException {
     UnwrapOnOther,
     #[error("Expected a XRPL Result model but got `XRPLOtherResult`: {0:?}.")]
     ExpectedResult(XRPLOtherResult),
+    #[error("The server is amendment blocked: {0}")]
+    AmendmentBlocked(String),
+}
+
+impl XRPLResultException {
+    /// The error the server answered with, if that is what failed.
+    pub fn error_response(&self) -> Option<&XRPLErrorResponse> {
+        match self {
+            XRPLResultException::ErrorResponse(error_response) => Some(error_response),
+            _ => None,
+        }
+    }
 }
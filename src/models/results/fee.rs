@@ -2,6 +2,7 @@ use core::convert::TryFrom;
 
 use alloc::string::ToString;
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 use crate::models::{
     amount::XRPAmount, results::exceptions::XRPLResultException, XRPLModelException,
@@ -10,9 +11,12 @@ use crate::models::{
 
 use super::XRPLResult;
 
+#[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Fee<'a> {
     pub drops: Drops<'a>,
+    /// The open ledger the fees apply to.
+    pub ledger_current_index: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -0,0 +1,194 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+/// The result of `gateway_balances`.
+///
+/// A result listing no balances at all is only an account and a
+/// ledger, like most account results, so it is kept as an
+/// [`XRPLOtherResult`](super::XRPLOtherResult) instead.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "GatewayBalancesFields<'a>")]
+pub struct GatewayBalances<'a> {
+    /// The issuing account.
+    pub account: Cow<'a, str>,
+    /// The total amounts issued to others, by currency code,
+    /// excluding the hot wallets. Omitted if there are none.
+    pub obligations: Option<HashMap<Cow<'a, str>, Cow<'a, str>>>,
+    /// The amounts held by each of the hot wallets, by address.
+    pub balances: Option<Balances<'a>>,
+    /// The amounts held by each account whose trust line to the
+    /// issuer is frozen, by address.
+    pub frozen_balances: Option<Balances<'a>>,
+    /// The amounts the issuer holds of other issuers' currencies, by
+    /// issuer address.
+    pub assets: Option<Balances<'a>>,
+    pub ledger_hash: Option<Cow<'a, str>>,
+    pub ledger_index: Option<u32>,
+    pub ledger_current_index: Option<u32>,
+    pub validated: Option<bool>,
+}
+
+type Balances<'a> = HashMap<Cow<'a, str>, Vec<GatewayBalance<'a>>>;
+
+#[derive(Deserialize)]
+struct GatewayBalancesFields<'a> {
+    account: Cow<'a, str>,
+    obligations: Option<HashMap<Cow<'a, str>, Cow<'a, str>>>,
+    balances: Option<Balances<'a>>,
+    frozen_balances: Option<Balances<'a>>,
+    assets: Option<Balances<'a>>,
+    ledger_hash: Option<Cow<'a, str>>,
+    ledger_index: Option<u32>,
+    ledger_current_index: Option<u32>,
+    validated: Option<bool>,
+}
+
+impl<'a> TryFrom<GatewayBalancesFields<'a>> for GatewayBalances<'a> {
+    type Error = &'static str;
+
+    fn try_from(fields: GatewayBalancesFields<'a>) -> Result<Self, Self::Error> {
+        if fields.obligations.is_none()
+            && fields.balances.is_none()
+            && fields.frozen_balances.is_none()
+            && fields.assets.is_none()
+        {
+            return Err("no balances listed");
+        }
+
+        Ok(Self {
+            account: fields.account,
+            obligations: fields.obligations,
+            balances: fields.balances,
+            frozen_balances: fields.frozen_balances,
+            assets: fields.assets,
+            ledger_hash: fields.ledger_hash,
+            ledger_index: fields.ledger_index,
+            ledger_current_index: fields.ledger_current_index,
+            validated: fields.validated,
+        })
+    }
+}
+
+/// An amount of a currency, as listed in the `balances` and `assets`
+/// of `gateway_balances`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GatewayBalance<'a> {
+    pub currency: Cow<'a, str>,
+    pub value: Cow<'a, str>,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for GatewayBalances<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::GatewayBalances(gateway_balances) => Ok(gateway_balances),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "GatewayBalances".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::results::XRPLResponse;
+
+    #[test]
+    fn test_deserialize_gateway_balances() {
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "account": "rMwjYedjc7qqtKYVLiAccJSmCwih4LnE2q",
+                "assets": {
+                    "r9F6wk8HkXrgYWoJ7fsv4VrUBVoqDVtzkH": [
+                        {"currency": "BTC", "value": "5444166510000000e-26"}
+                    ],
+                    "rPFLkxQk6xUGdGYEykqe7PR25Gr7mLHDc8": [
+                        {"currency": "EUR", "value": "4000000000000000e-27"}
+                    ]
+                },
+                "balances": {
+                    "rKm4uWpg9tfwbVSeATv4KxDe6mpE9yPkgJ": [
+                        {"currency": "EUR", "value": "29826.1965999999"}
+                    ],
+                    "ra7JkEzrgeKHdzKgo4EUUVBnxggY4z37kt": [
+                        {"currency": "USD", "value": "13857.70416"}
+                    ]
+                },
+                "ledger_hash": "980FECF48CA4BFDEC896692C31A50D484BDFE865EC101B00259C2E8A8B03BB1C",
+                "ledger_index": 14483212,
+                "obligations": {
+                    "BTC": "5908.324927635318",
+                    "EUR": "992471.7419793958",
+                    "GBP": "4991.38706013193",
+                    "USD": "1997134.20229482"
+                },
+                "validated": true
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+        let gateway_balances = response.try_into_result::<GatewayBalances>().unwrap();
+
+        assert_eq!(
+            gateway_balances.account,
+            "rMwjYedjc7qqtKYVLiAccJSmCwih4LnE2q"
+        );
+        let obligations = gateway_balances.obligations.unwrap();
+        assert_eq!(obligations.len(), 4);
+        assert_eq!(obligations["USD"], "1997134.20229482");
+        let balances = gateway_balances.balances.unwrap();
+        assert_eq!(
+            balances["ra7JkEzrgeKHdzKgo4EUUVBnxggY4z37kt"],
+            [GatewayBalance {
+                currency: "USD".into(),
+                value: "13857.70416".into()
+            }]
+        );
+        assert_eq!(gateway_balances.assets.unwrap().len(), 2);
+        assert_eq!(gateway_balances.frozen_balances, None);
+        assert_eq!(gateway_balances.ledger_index, Some(14483212));
+    }
+
+    #[test]
+    fn test_deserialize_gateway_balances_without_balances() {
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "account": "rMwjYedjc7qqtKYVLiAccJSmCwih4LnE2q",
+                "ledger_current_index": 14483213,
+                "validated": false
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+        assert!(matches!(response.result, Some(XRPLResult::Other(_))));
+
+        // Other account results are not mistaken for it either.
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "account": "rMwjYedjc7qqtKYVLiAccJSmCwih4LnE2q",
+                "lines": [],
+                "ledger_current_index": 14483213,
+                "validated": false
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+        assert!(matches!(response.result, Some(XRPLResult::AccountLines(_))));
+    }
+}
@@ -1,13 +1,26 @@
 use core::convert::TryFrom;
 
-use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
-use crate::models::{
-    results::exceptions::XRPLResultException, XRPLModelException, XRPLModelResult,
+use crate::{
+    models::{
+        amount::XRPAmount, results::exceptions::XRPLResultException, XRPLModelException,
+        XRPLModelResult,
+    },
+    utils::{exceptions::XRPLUtilsResult, time_conversion::ripple_time_to_datetime},
 };
 
-use super::XRPLResult;
+use super::{
+    api_version::{deserialize_ledger_index, normalize_transaction},
+    XRPLResult,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Ledger<'a> {
@@ -15,7 +28,10 @@ pub struct Ledger<'a> {
     pub ledger_hash: Cow<'a, str>,
     pub ledger_index: u32,
     pub validated: Option<bool>,
-    pub queue_data: Option<Cow<'a, str>>,
+    /// Transactions queued for inclusion in a future ledger. Only
+    /// returned when requested with `queue: true` on the current
+    /// ledger.
+    pub queue_data: Option<Vec<QueueData<'a>>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -27,12 +43,101 @@ pub struct LedgerInner<'a> {
     pub close_time_resolution: u32,
     pub closed: bool,
     pub ledger_hash: Cow<'a, str>,
+    /// A string, also with API version 2, which returns a number.
+    #[serde(deserialize_with = "deserialize_ledger_index")]
     pub ledger_index: Cow<'a, str>,
     pub parent_close_time: u32,
     pub parent_hash: Cow<'a, str>,
     pub total_coins: Cow<'a, str>,
     pub transaction_hash: Cow<'a, str>,
-    pub transactions: Option<Vec<Cow<'a, str>>>,
+    /// Only returned when requested with `transactions: true`.
+    pub transactions: Option<Vec<LedgerTransaction<'a>>>,
+}
+
+impl<'a> LedgerInner<'a> {
+    /// The time this ledger was closed, as a UTC datetime.
+    pub fn close_time_datetime(&self) -> XRPLUtilsResult<DateTime<Utc>> {
+        ripple_time_to_datetime(self.close_time as i64)
+    }
+
+    /// The time the previous ledger was closed, as a UTC datetime.
+    pub fn parent_close_time_datetime(&self) -> XRPLUtilsResult<DateTime<Utc>> {
+        ripple_time_to_datetime(self.parent_close_time as i64)
+    }
+}
+
+/// A transaction as listed by the `ledger` method: only its hash,
+/// or the full transaction with its metadata when requested with
+/// `expand: true`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum LedgerTransaction<'a> {
+    Hash(Cow<'a, str>),
+    Expanded(ExpandedTransaction<'a>),
+}
+
+impl<'a> LedgerTransaction<'a> {
+    /// The identifying hash of the transaction.
+    pub fn hash(&self) -> &str {
+        match self {
+            LedgerTransaction::Hash(hash) => hash,
+            LedgerTransaction::Expanded(transaction) => &transaction.hash,
+        }
+    }
+}
+
+/// An expanded transaction, in the layout of API version 1. Those of
+/// version 2, with the transaction in `tx_json`, are read into it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "Map<String, Value>")]
+pub struct ExpandedTransaction<'a> {
+    pub hash: Cow<'a, str>,
+    /// The transaction metadata. Named `metaData` in API v1 and `meta`
+    /// in later versions.
+    #[serde(rename = "metaData", alias = "meta")]
+    pub meta: Option<Value>,
+    /// The remaining transaction fields.
+    #[serde(flatten)]
+    pub tx: Value,
+}
+
+#[derive(Deserialize)]
+struct ExpandedTransactionFields<'a> {
+    hash: Cow<'a, str>,
+    #[serde(rename = "metaData", alias = "meta")]
+    meta: Option<Value>,
+    #[serde(flatten)]
+    tx: Value,
+}
+
+impl<'a> TryFrom<Map<String, Value>> for ExpandedTransaction<'a> {
+    type Error = serde_json::Error;
+
+    fn try_from(mut transaction: Map<String, Value>) -> Result<Self, Self::Error> {
+        normalize_transaction(&mut transaction);
+        let fields: ExpandedTransactionFields<'a> =
+            serde_json::from_value(Value::Object(transaction))?;
+
+        Ok(Self {
+            hash: fields.hash,
+            meta: fields.meta,
+            tx: fields.tx,
+        })
+    }
+}
+
+/// A transaction in the server's queue.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QueueData<'a> {
+    pub account: Cow<'a, str>,
+    pub tx: LedgerTransaction<'a>,
+    pub retries_remaining: u32,
+    pub preflight_result: Cow<'a, str>,
+    pub last_result: Option<Cow<'a, str>>,
+    pub auth_change: Option<bool>,
+    pub fee: Option<XRPAmount<'a>>,
+    pub fee_level: Option<Cow<'a, str>>,
+    pub max_spend_drops: Option<XRPAmount<'a>>,
 }
 
 impl<'a> TryFrom<XRPLResult<'a>> for Ledger<'a> {
@@ -49,3 +154,208 @@ impl<'a> TryFrom<XRPLResult<'a>> for Ledger<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::results::XRPLResponse;
+    use chrono::TimeZone;
+
+    const LEDGER_HEADER: &str = r#"
+        "account_hash": "B258A8BB4743FB74CBBD6E9F67E4A56C4432EA09E5805E4CC2DA26F2DBE8F3D1",
+        "close_flags": 0,
+        "close_time": 638329271,
+        "close_time_human": "2020-Mar-24 01:41:11.000000000 UTC",
+        "close_time_resolution": 10,
+        "closed": true,
+        "ledger_hash": "3652D7FD0576BC452C0D2E9B747BDD733075971D1A9A1D98125055DEF428721A",
+        "ledger_index": "54300940",
+        "parent_close_time": 638329270,
+        "parent_hash": "AE996778246BC81F85D5AF051241DAA577C23BCA04C034A7074F93700194520D",
+        "total_coins": "99991024049618156",
+        "transaction_hash": "FC6FFCB71B2527DDD630EE5409D38913B4D4C026AA6C3B14A3E9D4ED45CFE30D"
+    "#;
+
+    fn response(transactions: &str, queue_data: &str) -> XRPLResponse<'static> {
+        let json = alloc::format!(
+            r#"{{
+                "id": 1,
+                "result": {{
+                    "ledger": {{ {LEDGER_HEADER}, "transactions": {transactions} }},
+                    "ledger_hash": "3652D7FD0576BC452C0D2E9B747BDD733075971D1A9A1D98125055DEF428721A",
+                    "ledger_index": 54300940,
+                    "queue_data": {queue_data},
+                    "validated": true
+                }},
+                "status": "success",
+                "type": "response"
+            }}"#
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_compact_ledger() {
+        let ledger = response(
+            r#"["1D5F2E6E4A1AAD43CBE5BA1CE3A9C2E2D6A7B5D0C0C24D4C0E2F8E2B3C9F0D11"]"#,
+            r#"[{
+                "account": "rsUMx9vQhMeHTjqVmvPAnhVxT5xVaNabdX",
+                "fee": "10",
+                "fee_level": "2560",
+                "max_spend_drops": "1000010",
+                "preflight_result": "tesSUCCESS",
+                "retries_remaining": 10,
+                "tx": "7AFC2E06307C8A7F4D5E7E1F1E5D2A0A4CE92E3F2E1C4A4B0B4F4C1E1A7F9C3A"
+            }]"#,
+        )
+        .try_into_result::<Ledger>()
+        .unwrap();
+
+        let transactions = ledger.ledger.transactions.unwrap();
+        assert_eq!(
+            transactions,
+            [LedgerTransaction::Hash(
+                "1D5F2E6E4A1AAD43CBE5BA1CE3A9C2E2D6A7B5D0C0C24D4C0E2F8E2B3C9F0D11".into()
+            )]
+        );
+
+        let queue_data = ledger.queue_data.unwrap();
+        assert_eq!(queue_data[0].retries_remaining, 10);
+        assert_eq!(queue_data[0].fee, Some(XRPAmount::from("10")));
+        assert_eq!(
+            queue_data[0].tx.hash(),
+            "7AFC2E06307C8A7F4D5E7E1F1E5D2A0A4CE92E3F2E1C4A4B0B4F4C1E1A7F9C3A"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_expanded_ledger() {
+        let ledger = response(
+            r#"[{
+                "Account": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                "Fee": "12",
+                "Sequence": 18,
+                "TransactionType": "AccountSet",
+                "hash": "1D5F2E6E4A1AAD43CBE5BA1CE3A9C2E2D6A7B5D0C0C24D4C0E2F8E2B3C9F0D11",
+                "metaData": {
+                    "AffectedNodes": [],
+                    "TransactionIndex": 0,
+                    "TransactionResult": "tesSUCCESS"
+                }
+            }]"#,
+            r#"[{
+                "account": "rsUMx9vQhMeHTjqVmvPAnhVxT5xVaNabdX",
+                "auth_change": false,
+                "last_result": "terPRE_SEQ",
+                "preflight_result": "tesSUCCESS",
+                "retries_remaining": 9,
+                "tx": {
+                    "Account": "rsUMx9vQhMeHTjqVmvPAnhVxT5xVaNabdX",
+                    "TransactionType": "AccountSet",
+                    "hash": "7AFC2E06307C8A7F4D5E7E1F1E5D2A0A4CE92E3F2E1C4A4B0B4F4C1E1A7F9C3A"
+                }
+            }]"#,
+        )
+        .try_into_result::<Ledger>()
+        .unwrap();
+
+        let transactions = ledger.ledger.transactions.unwrap();
+        let LedgerTransaction::Expanded(transaction) = &transactions[0] else {
+            panic!(
+                "expected an expanded transaction, got {:?}",
+                transactions[0]
+            );
+        };
+        assert_eq!(
+            transaction.hash,
+            "1D5F2E6E4A1AAD43CBE5BA1CE3A9C2E2D6A7B5D0C0C24D4C0E2F8E2B3C9F0D11"
+        );
+        assert_eq!(transaction.tx["TransactionType"], "AccountSet");
+        assert_eq!(
+            transaction.meta.as_ref().unwrap()["TransactionResult"],
+            "tesSUCCESS"
+        );
+
+        let queue_data = ledger.queue_data.unwrap();
+        assert_eq!(queue_data[0].last_result.as_deref(), Some("terPRE_SEQ"));
+        assert!(matches!(queue_data[0].tx, LedgerTransaction::Expanded(_)));
+    }
+
+    #[test]
+    fn test_deserialize_api_v2_ledger() {
+        let transaction = |transaction: Value| {
+            serde_json::json!({
+                "result": {
+                    "ledger": {
+                        "account_hash": "B258A8BB4743FB74CBBD6E9F67E4A56C4432EA09E5805E4CC2DA26F2DBE8F3D1",
+                        "close_flags": 0,
+                        "close_time": 638329271,
+                        "close_time_resolution": 10,
+                        "closed": true,
+                        "ledger_hash": "3652D7FD0576BC452C0D2E9B747BDD733075971D1A9A1D98125055DEF428721A",
+                        "ledger_index": 54300940,
+                        "parent_close_time": 638329270,
+                        "parent_hash": "AE996778246BC81F85D5AF051241DAA577C23BCA04C034A7074F93700194520D",
+                        "total_coins": "99991024049618156",
+                        "transaction_hash": "FC6FFCB71B2527DDD630EE5409D38913B4D4C026AA6C3B14A3E9D4ED45CFE30D",
+                        "transactions": [transaction]
+                    },
+                    "ledger_hash": "3652D7FD0576BC452C0D2E9B747BDD733075971D1A9A1D98125055DEF428721A",
+                    "ledger_index": 54300940,
+                    "validated": true
+                },
+                "status": "success",
+                "type": "response"
+            })
+        };
+        let meta = serde_json::json!({
+            "AffectedNodes": [],
+            "TransactionIndex": 0,
+            "TransactionResult": "tesSUCCESS"
+        });
+        let v1: XRPLResponse = serde_json::from_value(transaction(serde_json::json!({
+            "Account": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            "Fee": "12",
+            "Sequence": 18,
+            "TransactionType": "AccountSet",
+            "hash": "1D5F2E6E4A1AAD43CBE5BA1CE3A9C2E2D6A7B5D0C0C24D4C0E2F8E2B3C9F0D11",
+            "metaData": meta
+        })))
+        .unwrap();
+        let v2: XRPLResponse = serde_json::from_value(transaction(serde_json::json!({
+            "hash": "1D5F2E6E4A1AAD43CBE5BA1CE3A9C2E2D6A7B5D0C0C24D4C0E2F8E2B3C9F0D11",
+            "meta": meta,
+            "tx_json": {
+                "Account": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                "Fee": "12",
+                "Sequence": 18,
+                "TransactionType": "AccountSet"
+            }
+        })))
+        .unwrap();
+
+        let v1 = v1.try_into_result::<Ledger>().unwrap();
+        let v2 = v2.try_into_result::<Ledger>().unwrap();
+        assert_eq!(v2.ledger.ledger_index, "54300940");
+        assert_eq!(v1, v2);
+        let LedgerTransaction::Expanded(transaction) = &v2.ledger.transactions.unwrap()[0] else {
+            panic!("expected an expanded transaction");
+        };
+        assert_eq!(transaction.tx["Sequence"], 18);
+        assert_eq!(transaction.meta.as_ref(), Some(&meta));
+    }
+
+    #[test]
+    fn test_close_time_datetime() {
+        let ledger = response("[]", "null").try_into_result::<Ledger>().unwrap();
+
+        assert_eq!(
+            ledger.ledger.close_time_datetime(),
+            Ok(Utc.with_ymd_and_hms(2020, 3, 24, 1, 41, 11).unwrap())
+        );
+        assert_eq!(
+            ledger.ledger.parent_close_time_datetime(),
+            Ok(Utc.with_ymd_and_hms(2020, 3, 24, 1, 41, 10).unwrap())
+        );
+    }
+}
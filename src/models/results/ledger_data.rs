@@ -0,0 +1,164 @@
+use core::convert::TryFrom;
+
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use serde_with::skip_serializing_none;
+
+use crate::{
+    core::{binarycodec::decode_with_buffer, exceptions::XRPLCoreResult},
+    models::{
+        ledger::objects::LedgerEntry, results::exceptions::XRPLResultException, XRPLModelException,
+        XRPLModelResult,
+    },
+    XRPLSerdeJsonError,
+};
+
+use super::XRPLResult;
+
+/// The result of `ledger_data`: a page of the ledger's state.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LedgerData<'a> {
+    pub ledger_hash: Cow<'a, str>,
+    pub ledger_index: u32,
+    /// The ledger entries of the page. They are decoded on demand,
+    /// see [`LedgerDataEntry::decode`].
+    pub state: Vec<LedgerDataEntry<'a>>,
+    /// Where to resume in a paginated response. Omitted on the last
+    /// page.
+    pub marker: Option<Value>,
+    pub validated: Option<bool>,
+}
+
+/// A ledger entry of a `ledger_data` page, as the server sent it:
+/// serialized to hexadecimal in `data` if `binary` was requested,
+/// as JSON in `fields` otherwise.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LedgerDataEntry<'a> {
+    /// The ID of the ledger entry.
+    pub index: Cow<'a, str>,
+    pub data: Option<Cow<'a, str>>,
+    #[serde(flatten)]
+    pub fields: Map<String, Value>,
+}
+
+impl<'a> LedgerDataEntry<'a> {
+    /// Decode the entry into its model.
+    pub fn decode(&self) -> XRPLCoreResult<LedgerEntry<'static>> {
+        self.decode_with_buffer(&mut Vec::new())
+    }
+
+    /// Decode the entry into its model, decoding binary entries in
+    /// `buffer` to reuse it across entries.
+    pub fn decode_with_buffer(&self, buffer: &mut Vec<u8>) -> XRPLCoreResult<LedgerEntry<'static>> {
+        let mut entry = match &self.data {
+            Some(data) => decode_with_buffer(data, buffer)?,
+            None => Value::Object(self.fields.clone()),
+        };
+        entry["index"] = Value::from(self.index.as_ref());
+
+        Ok(serde_json::from_value(entry).map_err(XRPLSerdeJsonError::from)?)
+    }
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for LedgerData<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::LedgerData(ledger_data) => Ok(ledger_data),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "LedgerData".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::results::XRPLResponse;
+
+    #[test]
+    fn test_deserialize_first_page() {
+        // The first page holds the ledger header, like a `ledger`
+        // result.
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "ledger": {
+                    "account_hash": "B258A8BB4743FB74CBBD6E9F67E4A56C4432EA09E5805E4CC2DA26F2DBE8F3D1",
+                    "close_flags": 0,
+                    "close_time": 638329271,
+                    "close_time_resolution": 10,
+                    "closed": true,
+                    "ledger_hash": "3652D7FD0576BC452C0D2E9B747BDD733075971D1A9A1D98125055DEF428721A",
+                    "ledger_index": "3",
+                    "parent_close_time": 638329270,
+                    "parent_hash": "AE996778246BC81F85D5AF051241DAA577C23BCA04C034A7074F93700194520D",
+                    "total_coins": "99999999999999980",
+                    "transaction_hash": "FC6FFCB71B2527DDD630EE5409D38913B4D4C026AA6C3B14A3E9D4ED45CFE30D"
+                },
+                "ledger_hash": "3652D7FD0576BC452C0D2E9B747BDD733075971D1A9A1D98125055DEF428721A",
+                "ledger_index": 3,
+                "marker": "0F6AA4D5A0E2D3E0CE6A4E1D0A1EBF2D8CF2E11C5BBF3C1AC71D2B9A9A1E9B4F",
+                "state": [{
+                    "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                    "Flags": 0,
+                    "LedgerEntryType": "Ticket",
+                    "OwnerNode": "0000000000000000",
+                    "PreviousTxnID": "F19AD4577212D3BEACA0F75FE1BA1644F2E854D46E8D62E9C95D18E9708CBFB1",
+                    "PreviousTxnLgrSeq": 3,
+                    "TicketSequence": 3,
+                    "index": "7458B6FD22827B3C141CDC88F1F0C72658C9B5D2E40961E45AF6CD31DECC0C29"
+                }],
+                "validated": true
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+        let ledger_data = response.try_into_result::<LedgerData>().unwrap();
+
+        assert_eq!(ledger_data.ledger_index, 3);
+        assert!(ledger_data.marker.is_some());
+        assert_eq!(ledger_data.state[0].data, None);
+        let LedgerEntry::Ticket(ticket) = ledger_data.state[0].decode().unwrap() else {
+            panic!("expected a Ticket");
+        };
+        assert_eq!(ticket.ticket_sequence, 3);
+        assert_eq!(
+            ticket.common_fields.index.as_deref(),
+            Some("7458B6FD22827B3C141CDC88F1F0C72658C9B5D2E40961E45AF6CD31DECC0C29")
+        );
+    }
+
+    #[test]
+    fn test_decode_unknown_entry() {
+        let entry: LedgerDataEntry = serde_json::from_value(serde_json::json!({
+            "LedgerEntryType": "Oracle",
+            "Owner": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "index": "7458B6FD22827B3C141CDC88F1F0C72658C9B5D2E40961E45AF6CD31DECC0C29"
+        }))
+        .unwrap();
+
+        let LedgerEntry::Other(fields) = entry.decode().unwrap() else {
+            panic!("expected an unknown entry");
+        };
+        assert_eq!(fields["Owner"], "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn");
+        assert!(LedgerDataEntry {
+            index: "00".into(),
+            data: Some("ABC".into()),
+            fields: Map::new(),
+        }
+        .decode()
+        .is_err());
+    }
+}
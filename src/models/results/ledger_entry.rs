@@ -0,0 +1,113 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{
+    ledger::objects::LedgerEntry as LedgerObject, results::exceptions::XRPLResultException,
+    XRPLModelException, XRPLModelResult,
+};
+
+use super::XRPLResult;
+
+/// The result of `ledger_entry`: the requested ledger object and
+/// its ID.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LedgerEntry<'a> {
+    /// The ID of the ledger object.
+    pub index: Cow<'a, str>,
+    /// The ledger index of the current ledger, if the object was
+    /// read from it.
+    pub ledger_current_index: Option<u32>,
+    pub ledger_hash: Option<Cow<'a, str>>,
+    /// The ledger index of the ledger the object was read from, if
+    /// not the current ledger.
+    pub ledger_index: Option<u32>,
+    /// The ledger object, unless `binary` was requested.
+    pub node: Option<LedgerObject<'a>>,
+    /// The ledger object serialized to hexadecimal, if `binary` was
+    /// requested.
+    pub node_binary: Option<Cow<'a, str>>,
+    pub validated: Option<bool>,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for LedgerEntry<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::LedgerEntry(ledger_entry) => Ok(ledger_entry),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "LedgerEntry".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::results::XRPLResponse;
+
+    #[test]
+    fn test_deserialize() {
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "index": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8",
+                "ledger_hash": "31850E8E48E76D1064651DF39DF4E9542E8C90A9A9B629F4DE339EB3FA74F726",
+                "ledger_index": 61966146,
+                "node": {
+                    "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                    "Balance": "424021949",
+                    "Flags": 0,
+                    "LedgerEntryType": "AccountRoot",
+                    "OwnerCount": 0,
+                    "PreviousTxnID": "8D7F42ED0621FBCFAE55CC6F2A9403A2AFB205708CCBA3109BB61DB8DDA261B4",
+                    "PreviousTxnLgrSeq": 61965653,
+                    "Sequence": 60005,
+                    "index": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8"
+                },
+                "validated": true
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+        let ledger_entry = response.try_into_result::<LedgerEntry>().unwrap();
+
+        assert_eq!(ledger_entry.ledger_index, Some(61966146));
+        assert_eq!(ledger_entry.node_binary, None);
+        let Some(LedgerObject::AccountRoot(account_root)) = ledger_entry.node else {
+            panic!("expected an AccountRoot");
+        };
+        assert_eq!(account_root.sequence, 60005);
+        assert_eq!(
+            account_root.common_fields.index.as_deref(),
+            Some(ledger_entry.index.as_ref())
+        );
+    }
+
+    #[test]
+    fn test_deserialize_binary() {
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "index": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8",
+                "ledger_current_index": 61966165,
+                "node_binary": "1100612200000000240000EA652500000000",
+                "validated": false
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+        let ledger_entry = response.try_into_result::<LedgerEntry>().unwrap();
+
+        assert_eq!(ledger_entry.ledger_current_index, Some(61966165));
+        assert_eq!(ledger_entry.node, None);
+        assert!(ledger_entry.node_binary.is_some());
+    }
+}
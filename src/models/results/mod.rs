@@ -1,9 +1,26 @@
+pub mod account_channels;
+pub mod account_currencies;
 pub mod account_info;
+pub mod account_lines;
+pub mod account_nfts;
+pub mod account_objects;
 pub mod account_tx;
+mod api_version;
+pub mod book_offers;
+pub mod channel_verify;
+pub mod deposit_authorized;
+pub mod error;
 pub mod exceptions;
 pub mod fee;
+pub mod gateway_balances;
 pub mod ledger;
+pub mod ledger_data;
+pub mod ledger_entry;
+pub mod nft_offers;
+pub mod ripple_path_find;
+pub mod server_info;
 pub mod server_state;
+pub mod simulate;
 pub mod submit;
 pub mod tx;
 
@@ -12,7 +29,6 @@ use crate::XRPLSerdeJsonError;
 use super::{requests::XRPLRequest, XRPLModelException, XRPLModelResult};
 use alloc::{
     borrow::Cow,
-    format,
     string::{String, ToString},
     vec::Vec,
 };
@@ -20,9 +36,11 @@ use core::{
     convert::{TryFrom, TryInto},
     fmt::Display,
 };
+use error::XRPLErrorResponse;
 use exceptions::XRPLResultException;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::{value::Index, Map, Value};
+use serde_with::skip_serializing_none;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum XRPLOptionalResult<T> {
@@ -122,46 +140,155 @@ impl XRPLOtherResult {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(untagged)]
 pub enum XRPLResult<'a> {
+    AccountChannels(account_channels::AccountChannels<'a>),
+    AccountCurrencies(account_currencies::AccountCurrencies<'a>),
     AccountInfo(account_info::AccountInfo<'a>),
+    AccountLines(account_lines::AccountLines<'a>),
+    AccountNfts(account_nfts::AccountNfts<'a>),
+    AccountObjects(account_objects::AccountObjects<'a>),
     AccountTx(account_tx::AccountTx<'a>),
+    BookOffers(book_offers::BookOffers<'a>),
+    ChannelVerify(channel_verify::ChannelVerify),
+    DepositAuthorized(deposit_authorized::DepositAuthorized<'a>),
     Fee(fee::Fee<'a>),
+    GatewayBalances(gateway_balances::GatewayBalances<'a>),
+    // Before `Ledger`, since the first page of `ledger_data` also
+    // holds the ledger header.
+    LedgerData(ledger_data::LedgerData<'a>),
     Ledger(ledger::Ledger<'a>),
+    LedgerEntry(ledger_entry::LedgerEntry<'a>),
+    NftOffers(nft_offers::NftOffers<'a>),
+    RipplePathFind(ripple_path_find::RipplePathFind<'a>),
+    ServerInfo(server_info::ServerInfo<'a>),
     ServerState(server_state::ServerState<'a>),
     Submit(submit::Submit<'a>),
+    // After `Submit`, whose results also carry the engine result
+    // and may carry `applied`.
+    Simulate(simulate::Simulate<'a>),
     Tx(tx::Tx<'a>),
     Other(XRPLOtherResult),
 }
 
+impl<'a> From<account_channels::AccountChannels<'a>> for XRPLResult<'a> {
+    fn from(account_channels: account_channels::AccountChannels<'a>) -> Self {
+        XRPLResult::AccountChannels(account_channels)
+    }
+}
+
+impl<'a> From<account_currencies::AccountCurrencies<'a>> for XRPLResult<'a> {
+    fn from(account_currencies: account_currencies::AccountCurrencies<'a>) -> Self {
+        XRPLResult::AccountCurrencies(account_currencies)
+    }
+}
+
 impl<'a> From<account_info::AccountInfo<'a>> for XRPLResult<'a> {
     fn from(account_info: account_info::AccountInfo<'a>) -> Self {
         XRPLResult::AccountInfo(account_info)
     }
 }
 
+impl<'a> From<account_lines::AccountLines<'a>> for XRPLResult<'a> {
+    fn from(account_lines: account_lines::AccountLines<'a>) -> Self {
+        XRPLResult::AccountLines(account_lines)
+    }
+}
+
+impl<'a> From<account_nfts::AccountNfts<'a>> for XRPLResult<'a> {
+    fn from(account_nfts: account_nfts::AccountNfts<'a>) -> Self {
+        XRPLResult::AccountNfts(account_nfts)
+    }
+}
+
+impl<'a> From<account_objects::AccountObjects<'a>> for XRPLResult<'a> {
+    fn from(account_objects: account_objects::AccountObjects<'a>) -> Self {
+        XRPLResult::AccountObjects(account_objects)
+    }
+}
+
 impl<'a> From<account_tx::AccountTx<'a>> for XRPLResult<'a> {
     fn from(account_tx: account_tx::AccountTx<'a>) -> Self {
         XRPLResult::AccountTx(account_tx)
     }
 }
 
+impl<'a> From<book_offers::BookOffers<'a>> for XRPLResult<'a> {
+    fn from(book_offers: book_offers::BookOffers<'a>) -> Self {
+        XRPLResult::BookOffers(book_offers)
+    }
+}
+
+impl<'a> From<channel_verify::ChannelVerify> for XRPLResult<'a> {
+    fn from(channel_verify: channel_verify::ChannelVerify) -> Self {
+        XRPLResult::ChannelVerify(channel_verify)
+    }
+}
+
+impl<'a> From<deposit_authorized::DepositAuthorized<'a>> for XRPLResult<'a> {
+    fn from(deposit_authorized: deposit_authorized::DepositAuthorized<'a>) -> Self {
+        XRPLResult::DepositAuthorized(deposit_authorized)
+    }
+}
+
 impl<'a> From<fee::Fee<'a>> for XRPLResult<'a> {
     fn from(fee: fee::Fee<'a>) -> Self {
         XRPLResult::Fee(fee)
     }
 }
 
+impl<'a> From<gateway_balances::GatewayBalances<'a>> for XRPLResult<'a> {
+    fn from(gateway_balances: gateway_balances::GatewayBalances<'a>) -> Self {
+        XRPLResult::GatewayBalances(gateway_balances)
+    }
+}
+
+impl<'a> From<ledger_data::LedgerData<'a>> for XRPLResult<'a> {
+    fn from(ledger_data: ledger_data::LedgerData<'a>) -> Self {
+        XRPLResult::LedgerData(ledger_data)
+    }
+}
+
 impl<'a> From<ledger::Ledger<'a>> for XRPLResult<'a> {
     fn from(ledger: ledger::Ledger<'a>) -> Self {
         XRPLResult::Ledger(ledger)
     }
 }
 
+impl<'a> From<ledger_entry::LedgerEntry<'a>> for XRPLResult<'a> {
+    fn from(ledger_entry: ledger_entry::LedgerEntry<'a>) -> Self {
+        XRPLResult::LedgerEntry(ledger_entry)
+    }
+}
+
+impl<'a> From<nft_offers::NftOffers<'a>> for XRPLResult<'a> {
+    fn from(nft_offers: nft_offers::NftOffers<'a>) -> Self {
+        XRPLResult::NftOffers(nft_offers)
+    }
+}
+
+impl<'a> From<ripple_path_find::RipplePathFind<'a>> for XRPLResult<'a> {
+    fn from(ripple_path_find: ripple_path_find::RipplePathFind<'a>) -> Self {
+        XRPLResult::RipplePathFind(ripple_path_find)
+    }
+}
+
+impl<'a> From<server_info::ServerInfo<'a>> for XRPLResult<'a> {
+    fn from(server_info: server_info::ServerInfo<'a>) -> Self {
+        XRPLResult::ServerInfo(server_info)
+    }
+}
+
 impl<'a> From<server_state::ServerState<'a>> for XRPLResult<'a> {
     fn from(server_state: server_state::ServerState<'a>) -> Self {
         XRPLResult::ServerState(server_state)
     }
 }
 
+impl<'a> From<simulate::Simulate<'a>> for XRPLResult<'a> {
+    fn from(simulate: simulate::Simulate<'a>) -> Self {
+        XRPLResult::Simulate(simulate)
+    }
+}
+
 impl<'a> From<submit::Submit<'a>> for XRPLResult<'a> {
     fn from(submit: submit::Submit<'a>) -> Self {
         XRPLResult::Submit(submit)
@@ -200,12 +327,27 @@ impl<'a> TryInto<Value> for XRPLResult<'a> {
 impl XRPLResult<'_> {
     pub(crate) fn get_name(&self) -> String {
         match self {
+            XRPLResult::AccountChannels(_) => "AccountChannels".to_string(),
+            XRPLResult::AccountCurrencies(_) => "AccountCurrencies".to_string(),
             XRPLResult::AccountInfo(_) => "AccountInfo".to_string(),
+            XRPLResult::AccountLines(_) => "AccountLines".to_string(),
+            XRPLResult::AccountNfts(_) => "AccountNfts".to_string(),
+            XRPLResult::AccountObjects(_) => "AccountObjects".to_string(),
             XRPLResult::AccountTx(_) => "AccountTx".to_string(),
+            XRPLResult::BookOffers(_) => "BookOffers".to_string(),
+            XRPLResult::ChannelVerify(_) => "ChannelVerify".to_string(),
+            XRPLResult::DepositAuthorized(_) => "DepositAuthorized".to_string(),
             XRPLResult::Fee(_) => "Fee".to_string(),
+            XRPLResult::GatewayBalances(_) => "GatewayBalances".to_string(),
             XRPLResult::Ledger(_) => "Ledger".to_string(),
+            XRPLResult::LedgerData(_) => "LedgerData".to_string(),
+            XRPLResult::LedgerEntry(_) => "LedgerEntry".to_string(),
+            XRPLResult::NftOffers(_) => "NftOffers".to_string(),
+            XRPLResult::RipplePathFind(_) => "RipplePathFind".to_string(),
+            XRPLResult::ServerInfo(_) => "ServerInfo".to_string(),
             XRPLResult::ServerState(_) => "ServerState".to_string(),
             XRPLResult::Submit(_) => "Submit".to_string(),
+            XRPLResult::Simulate(_) => "Simulate".to_string(),
             XRPLResult::Tx(_) => "Tx".to_string(),
             XRPLResult::Other(_) => "Other".to_string(),
         }
@@ -227,19 +369,31 @@ pub enum ResponseType {
     Transaction,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 pub struct XRPLResponse<'a> {
     pub id: Option<Cow<'a, str>>,
+    /// The API version the server answered with, if it says.
+    pub api_version: Option<u32>,
     pub error: Option<Cow<'a, str>>,
     pub error_code: Option<i32>,
     pub error_message: Option<Cow<'a, str>>,
     pub forwarded: Option<bool>,
     pub request: Option<XRPLRequest<'a>>,
     pub result: Option<XRPLResult<'a>>,
+    /// Whether the server searched the whole requested ledger range
+    /// before answering `txnNotFound`.
+    pub searched_all: Option<bool>,
     pub status: Option<ResponseStatus>,
     pub r#type: Option<ResponseType>,
+    /// `"load"` if the client is close to the server's rate limit.
     pub warning: Option<Cow<'a, str>>,
+    /// The warnings attached to the response. JSON-RPC servers put
+    /// them in the result; they are read from there too.
     pub warnings: Option<Vec<XRPLWarning<'a>>>,
+    /// The result as the server sent it, before it was parsed into
+    /// `result`.
+    #[serde(skip)]
+    raw_result: Option<Value>,
 }
 
 fn is_subscription_stream_item(item: &Map<String, Value>) -> bool {
@@ -259,24 +413,42 @@ impl<'a, 'de> Deserialize<'de> for XRPLResponse<'a> {
         if is_subscription_stream_item(&map) {
             let map_as_value = Value::Object(map);
             Ok(XRPLResponse {
+                result: serde_json::from_value(map_as_value.clone())
+                    .map_err(serde::de::Error::custom)?,
                 id: None,
+                api_version: None,
                 error: None,
                 error_code: None,
                 error_message: None,
                 forwarded: None,
                 request: None,
-                result: serde_json::from_value(map_as_value).map_err(serde::de::Error::custom)?,
+                searched_all: None,
                 status: None,
                 r#type: None,
                 warning: None,
                 warnings: None,
+                raw_result: Some(map_as_value),
             })
         } else {
+            // JSON-RPC servers answer with these in the result.
+            let result_field = |map: &Map<String, Value>, field: &str| {
+                map.get("result")
+                    .and_then(|result| result.get(field))
+                    .cloned()
+            };
+            let api_version = map
+                .remove("api_version")
+                .or_else(|| result_field(&map, "api_version"));
+            let warnings = map
+                .remove("warnings")
+                .or_else(|| result_field(&map, "warnings"));
+            let raw_result = map.remove("result");
             Ok(XRPLResponse {
                 id: map.remove("id").map(|item| match item.as_str() {
                     Some(item_str) => Cow::Owned(item_str.to_string()),
                     None => Cow::Borrowed(""),
                 }),
+                api_version: api_version.and_then(|v| v.as_u64()).map(|v| v as u32),
                 error: map.remove("error").map(|item| match item.as_str() {
                     Some(item_str) => Cow::Owned(item_str.to_string()),
                     None => Cow::Borrowed(""),
@@ -292,10 +464,11 @@ impl<'a, 'de> Deserialize<'de> for XRPLResponse<'a> {
                 forwarded: map.remove("forwarded").and_then(|v| v.as_bool()),
                 request: map
                     .remove("request")
+                    .and_then(|v| serde_json::from_value(v).ok()),
+                result: raw_result
+                    .clone()
                     .map(|v| serde_json::from_value(v).unwrap()),
-                result: map
-                    .remove("result")
-                    .map(|v| serde_json::from_value(v).unwrap()),
+                searched_all: map.remove("searched_all").and_then(|v| v.as_bool()),
                 status: map
                     .remove("status")
                     .map(|v| serde_json::from_value(v).unwrap()),
@@ -306,9 +479,8 @@ impl<'a, 'de> Deserialize<'de> for XRPLResponse<'a> {
                     Some(item_str) => Cow::Owned(item_str.to_string()),
                     None => Cow::Borrowed(""),
                 }),
-                warnings: map
-                    .remove("warnings")
-                    .and_then(|v| serde_json::from_value(v).ok()),
+                warnings: warnings.and_then(|v| serde_json::from_value(v).ok()),
+                raw_result,
             })
         }
     }
@@ -339,27 +511,101 @@ impl<'a> XRPLResponse<'a> {
         }
     }
 
+    /// Whether the server warned that it is amendment blocked: it
+    /// does not know an enabled amendment, so it can not tell the
+    /// state of the ledger.
+    pub fn is_amendment_blocked(&self) -> bool {
+        self.warnings
+            .iter()
+            .flatten()
+            .any(XRPLWarning::is_amendment_blocked)
+    }
+
+    /// Strict mode: fail if the server warned that it is amendment
+    /// blocked, as its answers can not be trusted. The other
+    /// warnings are left to the caller.
+    ///
+    /// # Examples
+    ///
+    /// ## Basic usage
+    ///
+    /// ```
+    /// use xrpl::models::results::{fee::Fee, XRPLResponse};
+    ///
+    /// let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+    ///     "result": {
+    ///         "drops": {
+    ///             "base_fee": "10",
+    ///             "median_fee": "5000",
+    ///             "minimum_fee": "10",
+    ///             "open_ledger_fee": "10"
+    ///         }
+    ///     },
+    ///     "status": "success",
+    ///     "type": "response"
+    /// }))
+    /// .unwrap();
+    /// let fee: Fee = response.deny_amendment_blocked().unwrap().try_into_result().unwrap();
+    /// ```
+    pub fn deny_amendment_blocked(self) -> XRPLModelResult<Self> {
+        match self
+            .warnings
+            .iter()
+            .flatten()
+            .find(|warning| warning.is_amendment_blocked())
+        {
+            Some(warning) => {
+                Err(XRPLResultException::AmendmentBlocked(warning.message.to_string()).into())
+            }
+            None => Ok(self),
+        }
+    }
+
+    /// The error the server answered with, if the request failed.
+    pub fn error_response(&self) -> Option<XRPLErrorResponse> {
+        // JSON-RPC nests the error in the result, WebSocket does not.
+        if self.error.is_some() {
+            return serde_json::from_value(serde_json::to_value(self).ok()?).ok();
+        }
+        // Look at the result as it was sent before converting it, as
+        // this runs for every successful response too.
+        let result = match (&self.raw_result, &self.result) {
+            (Some(raw_result), _) => Cow::Borrowed(raw_result),
+            (None, Some(result)) => Cow::Owned(serde_json::to_value(result).ok()?),
+            (None, None) => return None,
+        };
+        if !result.get("error").is_some_and(Value::is_string) {
+            return None;
+        }
+
+        serde_json::from_value(result.into_owned()).ok()
+    }
+
     pub fn try_into_opt_result<T>(self) -> XRPLModelResult<XRPLOptionalResult<T>>
     where
         T: TryFrom<XRPLResult<'a>, Error = XRPLModelException>,
     {
+        if let Some(error_response) = self.error_response() {
+            return Err(XRPLResultException::ErrorResponse(error_response).into());
+        }
         match self.result {
+            // A result left as JSON, e.g. by `request_raw`, is parsed
+            // like any response would have been.
+            Some(XRPLResult::Other(other)) => {
+                if let Ok(result) = XRPLResult::Other(other.clone()).try_into() {
+                    return Ok(XRPLOptionalResult::Result(result));
+                }
+                let result: XRPLResult<'a> = serde_json::from_value(other.0.clone())?;
+                match result.try_into() {
+                    Ok(result) => Ok(XRPLOptionalResult::Result(result)),
+                    Err(_) => Ok(XRPLOptionalResult::Other(other)),
+                }
+            }
             Some(result) => match result.clone().try_into() {
                 Ok(result) => Ok(XRPLOptionalResult::Result(result)),
                 Err(_) => Ok(XRPLOptionalResult::Other(result.try_into()?)),
             },
-            None => {
-                if let Some(error) = self.error {
-                    Err(XRPLResultException::ResponseError(format!(
-                        "{}: {}",
-                        error,
-                        self.error_message.unwrap_or_default()
-                    ))
-                    .into())
-                } else {
-                    Err(XRPLResultException::ExpectedResultOrError.into())
-                }
-            }
+            None => Err(XRPLResultException::ExpectedResultOrError.into()),
         }
     }
 
@@ -374,11 +620,277 @@ impl<'a> XRPLResponse<'a> {
             }
         }
     }
+
+    /// The result as the server sent it, including the fields the
+    /// crate's result models do not know.
+    pub fn result_as_value(&self) -> Option<&Value> {
+        self.raw_result.as_ref()
+    }
+
+    /// Deserialize the result into any type, e.g. a struct with only
+    /// the fields the caller needs. Unlike [`Self::try_into_result`],
+    /// `T` does not have to be one of the crate's result models: the
+    /// result is handed to serde as the server sent it. The fields of
+    /// the result `T` does not declare are ignored, unless `T` denies
+    /// unknown fields.
+    ///
+    /// Error responses fail with [`XRPLResultException::ErrorResponse`]
+    /// before `T` is deserialized.
+    ///
+    /// # Examples
+    ///
+    /// ## Basic usage
+    ///
+    /// ```
+    /// use serde::Deserialize;
+    /// use xrpl::models::results::XRPLResponse;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Drops {
+    ///     base_fee: String,
+    /// }
+    ///
+    /// #[derive(Deserialize)]
+    /// struct MyFee {
+    ///     drops: Drops,
+    /// }
+    ///
+    /// let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+    ///     "result": {
+    ///         "drops": {
+    ///             "base_fee": "10",
+    ///             "median_fee": "5000",
+    ///             "minimum_fee": "10",
+    ///             "open_ledger_fee": "10"
+    ///         }
+    ///     },
+    ///     "status": "success",
+    ///     "type": "response"
+    /// }))
+    /// .unwrap();
+    /// let fee: MyFee = response.deserialize_result().unwrap();
+    ///
+    /// assert_eq!(fee.drops.base_fee, "10");
+    /// ```
+    pub fn deserialize_result<T>(&self) -> XRPLModelResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        if let Some(error_response) = self.error_response() {
+            return Err(XRPLResultException::ErrorResponse(error_response).into());
+        }
+        let result = self
+            .result_as_value()
+            .ok_or(XRPLResultException::ExpectedResultOrError)?;
+
+        Ok(T::deserialize(result)?)
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A warning the server attached to a response.
+///
+/// See Response Formatting:
+/// `<https://xrpl.org/response-formatting.html#api-warnings>`
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct XRPLWarning<'a> {
-    pub id: Cow<'a, str>,
+    /// The code of the warning, e.g. [`XRPLWarning::AMENDMENT_BLOCKED`].
+    pub id: u32,
     pub message: Cow<'a, str>,
-    pub forwarded: Option<bool>,
+    /// More about the warning, depending on its code.
+    pub details: Option<Value>,
+}
+
+impl<'a> XRPLWarning<'a> {
+    /// An amendment the server does not know has a majority of
+    /// validators and may be enabled soon.
+    pub const UNSUPPORTED_MAJORITY: u32 = 1001;
+    /// An amendment the server does not know is enabled.
+    pub const AMENDMENT_BLOCKED: u32 = 1002;
+    /// The server's validator list has expired.
+    pub const EXPIRED_VALIDATOR_LIST: u32 = 1003;
+
+    pub fn is_amendment_blocked(&self) -> bool {
+        self.id == Self::AMENDMENT_BLOCKED
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::results::fee::Fee;
+
+    #[test]
+    fn test_deserialize_warnings() {
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "api_version": 2,
+            "forwarded": true,
+            "id": "fee_1",
+            "result": {
+                "current_ledger_size": "14",
+                "current_queue_size": "0",
+                "drops": {
+                    "base_fee": "10",
+                    "median_fee": "5000",
+                    "minimum_fee": "10",
+                    "open_ledger_fee": "10"
+                },
+                "expected_ledger_size": "24",
+                "ledger_current_index": 26575101,
+                "levels": {
+                    "median_level": "128000",
+                    "minimum_level": "256",
+                    "open_ledger_level": "256",
+                    "reference_level": "256"
+                },
+                "max_queue_size": "480"
+            },
+            "status": "success",
+            "type": "response",
+            "warning": "load",
+            "warnings": [
+                {
+                    "id": 1001,
+                    "message": "This server is not amendment blocked, but there is a majority of validators voting for an amendment that this server does not support.",
+                    "details": {
+                        "expected_date": 1585097480,
+                        "expected_date_UTC": "2020-Mar-25 00:51:20.000000000 UTC"
+                    }
+                }
+            ]
+        }))
+        .unwrap();
+
+        assert!(response.is_success());
+        assert_eq!(response.id.as_deref(), Some("fee_1"));
+        assert_eq!(response.api_version, Some(2));
+        assert_eq!(response.forwarded, Some(true));
+        assert_eq!(response.r#type, Some(ResponseType::Response));
+        assert_eq!(response.warning.as_deref(), Some("load"));
+        let warnings = response.warnings.as_ref().unwrap();
+        assert_eq!(warnings[0].id, XRPLWarning::UNSUPPORTED_MAJORITY);
+        assert_eq!(
+            warnings[0].details.as_ref().unwrap()["expected_date"],
+            1585097480
+        );
+        assert!(!response.is_amendment_blocked());
+        assert_eq!(response.clone(), response);
+
+        let fee: Fee = response
+            .deny_amendment_blocked()
+            .unwrap()
+            .try_into_result()
+            .unwrap();
+        assert_eq!(fee.drops.base_fee, "10".into());
+    }
+
+    #[test]
+    fn test_amendment_blocked() {
+        // JSON-RPC servers put the warnings in the result.
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "drops": {
+                    "base_fee": "10",
+                    "median_fee": "5000",
+                    "minimum_fee": "10",
+                    "open_ledger_fee": "10"
+                },
+                "status": "success",
+                "warnings": [
+                    {
+                        "id": 1002,
+                        "message": "This server is amendment blocked, and must be updated to be able to stay in sync with the network."
+                    }
+                ]
+            }
+        }))
+        .unwrap();
+
+        assert!(response.is_amendment_blocked());
+        assert_eq!(
+            response.deny_amendment_blocked(),
+            Err(XRPLResultException::AmendmentBlocked(
+                "This server is amendment blocked, and must be updated to be able to stay in sync with the network.".into()
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn test_deserialize_result() {
+        // Only the fields the caller needs, including one the
+        // crate's `AccountInfo` does not model.
+        #[derive(Deserialize)]
+        struct AccountData {
+            #[serde(rename = "Balance")]
+            balance: String,
+            #[serde(rename = "Sequence")]
+            sequence: u32,
+            #[serde(rename = "WalletLocator")]
+            wallet_locator: Option<String>,
+        }
+
+        #[derive(Deserialize)]
+        struct MyAccountInfo {
+            account_data: AccountData,
+            validated: bool,
+        }
+
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "account_data": {
+                    "Account": "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn",
+                    "Balance": "999999999960",
+                    "Flags": 8388608,
+                    "LedgerEntryType": "AccountRoot",
+                    "OwnerCount": 0,
+                    "PreviousTxnID": "4294BEBE5B569A18C0A2702387C9B1E7146DC3A5850C1E87204951C6FDAA4C42",
+                    "PreviousTxnLgrSeq": 3,
+                    "Sequence": 6,
+                    "WalletLocator": "00000000000000000000000000000000000000000000000000000000DEADBEEF",
+                    "index": "92FA6A9FC8EA6018D5D16532D7795C91BFB0831355BDFDA177E86C8BF997985F"
+                },
+                "ledger_current_index": 4,
+                "queue_data": {
+                    "txn_count": 0
+                },
+                "validated": false
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+
+        let account_info: MyAccountInfo = response.deserialize_result().unwrap();
+        assert_eq!(account_info.account_data.balance, "999999999960");
+        assert_eq!(account_info.account_data.sequence, 6);
+        assert_eq!(
+            account_info.account_data.wallet_locator.as_deref(),
+            Some("00000000000000000000000000000000000000000000000000000000DEADBEEF")
+        );
+        assert!(!account_info.validated);
+        assert_eq!(
+            response.result_as_value().unwrap()["ledger_current_index"],
+            4
+        );
+    }
+
+    #[test]
+    fn test_deserialize_result_error_response() {
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "error": "actNotFound",
+            "error_code": 19,
+            "error_message": "Account not found.",
+            "status": "error",
+            "type": "response"
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            response.deserialize_result::<Value>(),
+            Err(XRPLModelException::XRPLResultError(
+                XRPLResultException::ErrorResponse(_)
+            ))
+        ));
+    }
 }
@@ -0,0 +1,106 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::{Amount, XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+/// The result of `nft_buy_offers` and `nft_sell_offers`.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NftOffers<'a> {
+    #[serde(alias = "NFTokenID")]
+    pub nft_id: Cow<'a, str>,
+    pub offers: Vec<NFTOffer<'a>>,
+    pub limit: Option<u32>,
+    /// Where to resume in a paginated response. Omitted on the last
+    /// page.
+    pub marker: Option<Value>,
+}
+
+/// An offer to buy or sell an NFToken.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NFTOffer<'a> {
+    pub amount: Amount<'a>,
+    pub flags: u32,
+    /// The ID of the NFTokenOffer object, to accept or cancel it.
+    pub nft_offer_index: Cow<'a, str>,
+    pub owner: Cow<'a, str>,
+    /// The only account allowed to accept the offer, if any.
+    pub destination: Option<Cow<'a, str>>,
+    /// When the offer expires, in seconds since the Ripple Epoch.
+    pub expiration: Option<u32>,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for NftOffers<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::NftOffers(nft_offers) => Ok(nft_offers),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "NftOffers".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{results::XRPLResponse, IssuedCurrencyAmount};
+
+    const NFT_ID: &str = "00090000D0B007439B080E9B05BF62403911301A7B1F0CFAA048C0A200000007";
+
+    #[test]
+    fn test_deserialize_nft_offers() {
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "nft_id": NFT_ID,
+                "offers": [{
+                    "amount": "1500",
+                    "flags": 1,
+                    "nft_offer_index": "9E28E366573187F8E5B85CE301F229E061A619EE5A589EF740088F8843BF10A1",
+                    "owner": "rLpSRZ1E8JHyNDZeHYsQs1R5cwDCB3uuZt"
+                }, {
+                    "amount": {
+                        "currency": "USD",
+                        "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                        "value": "10"
+                    },
+                    "destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+                    "expiration": 772358400,
+                    "flags": 1,
+                    "nft_offer_index": "E0A3A8E5D2E3B7C1A5F8E4D6B2C8A1E3F5D7B9C1A3E5F7D9B1C3A5E7F9D1B3C5",
+                    "owner": "rLpSRZ1E8JHyNDZeHYsQs1R5cwDCB3uuZt"
+                }],
+                "validated": true
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+
+        let nft_offers = response.try_into_result::<NftOffers>().unwrap();
+        assert_eq!(nft_offers.nft_id, NFT_ID);
+        assert_eq!(nft_offers.offers[0].amount, Amount::from("1500"));
+        assert_eq!(
+            nft_offers.offers[1].amount,
+            IssuedCurrencyAmount::new(
+                "USD".into(),
+                "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+                "10".into()
+            )
+            .into()
+        );
+        assert_eq!(nft_offers.offers[1].expiration, Some(772358400));
+        assert!(nft_offers.marker.is_none());
+    }
+}
@@ -0,0 +1,118 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::{Amount, Path, XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLResultException, XRPLResult};
+
+/// The result of `ripple_path_find`.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RipplePathFind<'a> {
+    /// The ways to deliver the amount, one per currency the source
+    /// account could spend. Empty when no path was found.
+    pub alternatives: Vec<PathAlternative<'a>>,
+    pub destination_account: Cow<'a, str>,
+    /// The currencies the destination account accepts.
+    pub destination_currencies: Vec<Cow<'a, str>>,
+    pub full_reply: Option<bool>,
+    pub ledger_current_index: Option<u32>,
+    pub source_account: Option<Cow<'a, str>>,
+    pub validated: Option<bool>,
+}
+
+/// A way to deliver the requested amount.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PathAlternative<'a> {
+    /// The paths to use as the `paths` of the Payment.
+    pub paths_computed: Vec<Path<'a>>,
+    /// What the source account would spend, to use as the
+    /// `send_max` of the Payment, with some slippage if needed.
+    pub source_amount: Amount<'a>,
+    /// What the destination account would receive, if it differs
+    /// from the requested amount.
+    pub destination_amount: Option<Amount<'a>>,
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for RipplePathFind<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::RipplePathFind(ripple_path_find) => Ok(ripple_path_find),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "RipplePathFind".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{results::XRPLResponse, IssuedCurrencyAmount};
+
+    #[test]
+    fn test_deserialize_ripple_path_find() {
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": {
+                "alternatives": [{
+                    "paths_computed": [[{
+                        "account": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                        "type": 1,
+                        "type_hex": "0000000000000001"
+                    }]],
+                    "source_amount": {
+                        "currency": "USD",
+                        "issuer": "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59",
+                        "value": "0.001002"
+                    }
+                }, {
+                    "paths_computed": [[{
+                        "currency": "USD",
+                        "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                        "type": 48,
+                        "type_hex": "0000000000000030"
+                    }]],
+                    "source_amount": "207414"
+                }],
+                "destination_account": "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59",
+                "destination_currencies": ["USD", "XRP"],
+                "full_reply": true,
+                "ledger_current_index": 8201,
+                "source_account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+                "validated": false
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+        let ripple_path_find = response.try_into_result::<RipplePathFind>().unwrap();
+
+        assert_eq!(ripple_path_find.alternatives.len(), 2);
+        let alternative = &ripple_path_find.alternatives[0];
+        assert_eq!(
+            alternative.paths_computed[0][0].account.as_deref(),
+            Some("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B")
+        );
+        assert_eq!(
+            alternative.source_amount,
+            Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+                "USD".into(),
+                "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59".into(),
+                "0.001002".into()
+            ))
+        );
+        assert_eq!(
+            ripple_path_find.alternatives[1].source_amount,
+            Amount::XRPAmount("207414".into())
+        );
+        assert_eq!(ripple_path_find.destination_currencies, ["USD", "XRP"]);
+    }
+}
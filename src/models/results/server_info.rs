@@ -0,0 +1,183 @@
+use core::{convert::TryFrom, str::FromStr};
+
+use alloc::{borrow::Cow, string::ToString};
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::models::{
+    results::exceptions::XRPLResultException, XRPLModelException, XRPLModelResult,
+};
+
+use super::XRPLResult;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ServerInfo<'a> {
+    pub info: Info<'a>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Info<'a> {
+    pub build_version: Cow<'a, str>,
+    /// The multiplier to the transaction cost the server is
+    /// currently enforcing. Not reported in reporting mode.
+    #[serde(default, deserialize_with = "deserialize_optional_decimal")]
+    pub load_factor: Option<BigDecimal>,
+    pub network_id: Option<u32>,
+    /// How many other rippled servers this one is currently
+    /// connected to. Not reported in reporting mode.
+    pub peers: Option<u32>,
+    pub server_state: Option<Cow<'a, str>>,
+    pub validated_ledger: Option<ValidatedLedger<'a>>,
+}
+
+/// Information about the most recent fully-validated ledger,
+/// with fees and reserves expressed in XRP.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ValidatedLedger<'a> {
+    pub age: Option<u32>,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub base_fee_xrp: BigDecimal,
+    pub hash: Cow<'a, str>,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub reserve_base_xrp: BigDecimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub reserve_inc_xrp: BigDecimal,
+    pub seq: u32,
+}
+
+/// rippled reports XRP values as JSON floats. Parse them from their
+/// shortest decimal representation so `0.00001` does not turn into the
+/// exact binary expansion of the float.
+fn deserialize_decimal<'de, D>(deserializer: D) -> Result<BigDecimal, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = match Value::deserialize(deserializer)? {
+        Value::String(value) => value,
+        Value::Number(value) => value.to_string(),
+        value => {
+            return Err(serde::de::Error::custom(alloc::format!(
+                "expected a decimal number, found {value}"
+            )))
+        }
+    };
+
+    BigDecimal::from_str(&value).map_err(serde::de::Error::custom)
+}
+
+fn deserialize_optional_decimal<'de, D>(deserializer: D) -> Result<Option<BigDecimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_decimal(deserializer).map(Some)
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for ServerInfo<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::ServerInfo(server_info) => Ok(server_info),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "ServerInfo".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::results::XRPLResponse;
+    use bigdecimal::FromPrimitive;
+
+    #[test]
+    fn test_deserialize_server_info() {
+        let json = r#"{
+            "id": 1,
+            "result": {
+                "info": {
+                    "build_version": "2.3.0",
+                    "complete_ledgers": "32570-92571239",
+                    "hostid": "PLAY",
+                    "load_factor": 1,
+                    "network_id": 0,
+                    "peers": 97,
+                    "pubkey_node": "n9KQK8yvTDcZdGyhu2bZ3b2TPXUzCr5LRJ4i2VNaCNB1Zx4PAkJb",
+                    "server_state": "full",
+                    "validated_ledger": {
+                        "age": 2,
+                        "base_fee_xrp": 0.00001,
+                        "hash": "AD2B7B9F22E4E9FE3B7AC6B22A3B6D5C0F9A9DB2D4D68A0D21B0F4E6B1DAD4D1",
+                        "reserve_base_xrp": 1,
+                        "reserve_inc_xrp": 0.2,
+                        "seq": 92571239
+                    },
+                    "validation_quorum": 28
+                },
+                "status": "success"
+            },
+            "status": "success",
+            "type": "response"
+        }"#;
+        let response: XRPLResponse = serde_json::from_str(json).unwrap();
+        let info = response.try_into_result::<ServerInfo>().unwrap().info;
+        let validated_ledger = info.validated_ledger.unwrap();
+
+        assert_eq!(info.build_version, "2.3.0");
+        assert_eq!(info.load_factor, BigDecimal::from_u32(1));
+        assert_eq!(info.network_id, Some(0));
+        assert_eq!(info.peers, Some(97));
+        assert_eq!(validated_ledger.seq, 92571239);
+        assert_eq!(
+            validated_ledger.base_fee_xrp,
+            BigDecimal::from_str("0.00001").unwrap()
+        );
+        assert_eq!(
+            validated_ledger.reserve_base_xrp,
+            BigDecimal::from_u32(1).unwrap()
+        );
+        assert_eq!(
+            validated_ledger.reserve_inc_xrp,
+            BigDecimal::from_str("0.2").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_deserialize_reporting_mode_server_info() {
+        let json = r#"{
+            "result": {
+                "info": {
+                    "build_version": "1.12.0",
+                    "complete_ledgers": "32570-86452710",
+                    "reporting": {
+                        "etl_sources": [],
+                        "is_writer": true,
+                        "last_publish_time": "2024-Mar-04 10:15:12.389318 UTC"
+                    },
+                    "server_state": "full",
+                    "validated_ledger": {
+                        "base_fee_xrp": 0.00001,
+                        "hash": "0F8B7F3AF0E2A4A7E5C87A1EE8F3DF3D6B9F8F0F1E0E4F0E7A6C4B8A0D6F8E21",
+                        "reserve_base_xrp": 10,
+                        "reserve_inc_xrp": 2,
+                        "seq": 86452710
+                    }
+                }
+            },
+            "status": "success",
+            "type": "response"
+        }"#;
+        let response: XRPLResponse = serde_json::from_str(json).unwrap();
+        let info = response.try_into_result::<ServerInfo>().unwrap().info;
+
+        assert_eq!(info.build_version, "1.12.0");
+        assert_eq!(info.load_factor, None);
+        assert_eq!(info.network_id, None);
+        assert_eq!(info.peers, None);
+        assert_eq!(info.validated_ledger.unwrap().seq, 86452710);
+    }
+}
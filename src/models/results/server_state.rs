@@ -18,10 +18,22 @@ pub struct ServerState<'a> {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct State<'a> {
     pub build_version: Cow<'a, str>,
+    /// The baseline amount of server load used in transaction
+    /// cost calculations. Not reported in reporting mode.
+    pub load_base: Option<u32>,
+    /// The load factor the server is currently enforcing, relative
+    /// to `load_base`. Not reported in reporting mode.
+    pub load_factor: Option<u32>,
     pub network_id: Option<u32>,
+    /// How many other rippled servers this one is currently
+    /// connected to. Not reported in reporting mode.
+    pub peers: Option<u32>,
+    pub server_state: Option<Cow<'a, str>>,
     pub validated_ledger: Option<ValidatedLedger<'a>>,
 }
 
+/// Information about the most recent fully-validated ledger,
+/// with fees and reserves expressed in drops.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ValidatedLedger<'a> {
     pub base_fee: XRPAmount<'a>,
@@ -46,3 +58,87 @@ impl<'a> TryFrom<XRPLResult<'a>> for ServerState<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::results::XRPLResponse;
+
+    #[test]
+    fn test_deserialize_server_state() {
+        let json = r#"{
+            "id": 1,
+            "result": {
+                "state": {
+                    "build_version": "2.3.0",
+                    "complete_ledgers": "32570-92571239",
+                    "load_base": 256,
+                    "load_factor": 256,
+                    "network_id": 0,
+                    "peers": 97,
+                    "server_state": "full",
+                    "validated_ledger": {
+                        "base_fee": 10,
+                        "close_time": 787654321,
+                        "hash": "AD2B7B9F22E4E9FE3B7AC6B22A3B6D5C0F9A9DB2D4D68A0D21B0F4E6B1DAD4D1",
+                        "reserve_base": 1000000,
+                        "reserve_inc": 200000,
+                        "seq": 92571239
+                    },
+                    "validation_quorum": 28
+                },
+                "status": "success"
+            },
+            "status": "success",
+            "type": "response"
+        }"#;
+        let response: XRPLResponse = serde_json::from_str(json).unwrap();
+        let state = response.try_into_result::<ServerState>().unwrap().state;
+        let validated_ledger = state.validated_ledger.unwrap();
+
+        assert_eq!(state.build_version, "2.3.0");
+        assert_eq!(state.load_factor, Some(256));
+        assert_eq!(state.network_id, Some(0));
+        assert_eq!(state.peers, Some(97));
+        assert_eq!(validated_ledger.base_fee, XRPAmount::from("10"));
+        assert_eq!(validated_ledger.reserve_base, XRPAmount::from("1000000"));
+        assert_eq!(validated_ledger.reserve_inc, XRPAmount::from("200000"));
+        assert_eq!(validated_ledger.seq, 92571239);
+    }
+
+    #[test]
+    fn test_deserialize_reporting_mode_server_state() {
+        let json = r#"{
+            "result": {
+                "state": {
+                    "build_version": "1.12.0",
+                    "complete_ledgers": "32570-86452710",
+                    "reporting": {
+                        "etl_sources": [],
+                        "is_writer": true,
+                        "last_publish_time": "2024-Mar-04 10:15:12.389318 UTC"
+                    },
+                    "server_state": "full",
+                    "validated_ledger": {
+                        "base_fee": 10,
+                        "close_time": 762949511,
+                        "hash": "0F8B7F3AF0E2A4A7E5C87A1EE8F3DF3D6B9F8F0F1E0E4F0E7A6C4B8A0D6F8E21",
+                        "reserve_base": 10000000,
+                        "reserve_inc": 2000000,
+                        "seq": 86452710
+                    }
+                }
+            },
+            "status": "success",
+            "type": "response"
+        }"#;
+        let response: XRPLResponse = serde_json::from_str(json).unwrap();
+        let state = response.try_into_result::<ServerState>().unwrap().state;
+
+        assert_eq!(state.build_version, "1.12.0");
+        assert_eq!(state.load_factor, None);
+        assert_eq!(state.network_id, None);
+        assert_eq!(state.peers, None);
+        assert_eq!(state.validated_ledger.unwrap().seq, 86452710);
+    }
+}
@@ -0,0 +1,63 @@
+use core::convert::TryFrom;
+
+use alloc::{borrow::Cow, string::ToString};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::{
+    results::exceptions::XRPLResultException, transactions::metadata::TransactionMetadata,
+    XRPLModelException, XRPLModelResult,
+};
+
+use super::XRPLResult;
+
+/// The result of `simulate`: what the transaction would do if it
+/// were submitted to the current ledger.
+///
+/// The metadata works with the parsers of
+/// [`txn_parser`](crate::utils::txn_parser), e.g. to preview the
+/// amount a payment would deliver.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Simulate<'a> {
+    /// Always false: a simulated transaction is never applied.
+    pub applied: bool,
+    pub engine_result: Cow<'a, str>,
+    pub engine_result_code: i32,
+    pub engine_result_message: Cow<'a, str>,
+    /// The ledger the transaction was simulated against.
+    pub ledger_index: Option<u32>,
+    /// The metadata the transaction would have, unless `binary`
+    /// was requested.
+    pub meta: Option<TransactionMetadata<'a>>,
+    /// The metadata as hexadecimal, if `binary` was requested.
+    pub meta_blob: Option<Cow<'a, str>>,
+    /// The transaction as hexadecimal, if `binary` was requested.
+    pub tx_blob: Option<Cow<'a, str>>,
+    /// The transaction with the fields the server filled in, unless
+    /// `binary` was requested.
+    pub tx_json: Option<Value>,
+}
+
+impl<'a> Simulate<'a> {
+    /// Whether the transaction would succeed.
+    pub fn is_success(&self) -> bool {
+        self.engine_result == "tesSUCCESS"
+    }
+}
+
+impl<'a> TryFrom<XRPLResult<'a>> for Simulate<'a> {
+    type Error = XRPLModelException;
+
+    fn try_from(result: XRPLResult<'a>) -> XRPLModelResult<Self> {
+        match result {
+            XRPLResult::Simulate(simulate) => Ok(simulate),
+            res => Err(XRPLResultException::UnexpectedResultType(
+                "Simulate".to_string(),
+                res.get_name(),
+            )
+            .into()),
+        }
+    }
+}
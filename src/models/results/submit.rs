@@ -1,14 +1,14 @@
 use core::convert::TryFrom;
 
 use alloc::{borrow::Cow, string::ToString};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 
 use crate::models::{
     results::exceptions::XRPLResultException, XRPLModelException, XRPLModelResult,
 };
 
-use super::XRPLResult;
+use super::{api_version::normalize_tx_json, XRPLResult};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Submit<'a> {
@@ -16,6 +16,9 @@ pub struct Submit<'a> {
     pub engine_result_code: i32,
     pub engine_result_message: Cow<'a, str>,
     pub tx_blob: Cow<'a, str>,
+    /// The transaction as submitted. Payments have their `Amount`, also
+    /// with API version 2, which only returns their `DeliverMax`.
+    #[serde(deserialize_with = "deserialize_tx_json")]
     pub tx_json: Value,
     pub accepted: Option<bool>,
     pub account_sequence_available: Option<u32>,
@@ -28,6 +31,18 @@ pub struct Submit<'a> {
     pub validated_ledger_index: Option<u32>,
 }
 
+fn deserialize_tx_json<'de, D>(deserializer: D) -> Result<Value, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut tx_json = Value::deserialize(deserializer)?;
+    if let Value::Object(tx_json) = &mut tx_json {
+        normalize_tx_json(tx_json);
+    }
+
+    Ok(tx_json)
+}
+
 impl<'a> TryFrom<XRPLResult<'a>> for Submit<'a> {
     type Error = XRPLModelException;
 
@@ -42,3 +57,60 @@ impl<'a> TryFrom<XRPLResult<'a>> for Submit<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::results::XRPLResponse;
+
+    fn submit(tx_json: Value) -> Submit<'static> {
+        serde_json::from_value::<XRPLResponse>(serde_json::json!({
+            "result": {
+                "accepted": true,
+                "applied": true,
+                "broadcast": true,
+                "engine_result": "tesSUCCESS",
+                "engine_result_code": 0,
+                "engine_result_message": "The transaction was applied. Only final in a validated ledger.",
+                "kept": true,
+                "queued": false,
+                "tx_blob": "1200002280000000240000000561400000000000000A68400000000000000C",
+                "tx_json": tx_json
+            },
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap()
+        .try_into_result()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_api_v2() {
+        let tx_json = serde_json::json!({
+            "Account": "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn",
+            "DeliverMax": "1000000",
+            "Destination": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+            "Fee": "12",
+            "Sequence": 5,
+            "TransactionType": "Payment",
+            "hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB13FD"
+        });
+        let mut v1_tx_json = tx_json.clone();
+        v1_tx_json["Amount"] = "1000000".into();
+
+        let v1 = submit(v1_tx_json);
+        let v2 = submit(tx_json);
+
+        assert_eq!(v2.tx_json["Amount"], "1000000");
+        assert_eq!(v1, v2);
+        assert_eq!(v2.engine_result, "tesSUCCESS");
+
+        // Other transactions have no amount to fill in.
+        let account_set = submit(serde_json::json!({
+            "Account": "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn",
+            "TransactionType": "AccountSet"
+        }));
+        assert!(account_set.tx_json.get("Amount").is_none());
+    }
+}
@@ -1,18 +1,26 @@
 use core::convert::TryFrom;
 
-use alloc::{borrow::Cow, string::ToString};
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
 
 use crate::models::{
     results::exceptions::XRPLResultException, XRPLModelException, XRPLModelResult,
 };
 
-use super::XRPLResult;
+use super::{api_version::normalize_transaction, XRPLResult};
 
+/// The result of `tx`, in the layout of API version 1. Results of
+/// version 2, with the transaction in `tx_json`, are read into it.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "Map<String, Value>")]
 pub struct Tx<'a> {
-    pub ctid: Cow<'a, str>,
+    /// The concise transaction identifier of the transaction. Only
+    /// returned for validated transactions by servers that support it.
+    pub ctid: Option<Cow<'a, str>>,
     pub date: u32,
     pub hash: Cow<'a, str>,
     pub ledger_index: u32,
@@ -26,6 +34,40 @@ pub struct Tx<'a> {
     pub in_ledger: Option<u32>,
 }
 
+#[derive(Deserialize)]
+struct TxFields<'a> {
+    ctid: Option<Cow<'a, str>>,
+    date: u32,
+    hash: Cow<'a, str>,
+    ledger_index: u32,
+    meta: Value,
+    #[serde(flatten)]
+    various: Value,
+    validated: Option<bool>,
+    #[serde(rename = "inLedger")]
+    in_ledger: Option<u32>,
+}
+
+impl<'a> TryFrom<Map<String, Value>> for Tx<'a> {
+    type Error = serde_json::Error;
+
+    fn try_from(mut result: Map<String, Value>) -> Result<Self, Self::Error> {
+        normalize_transaction(&mut result);
+        let fields: TxFields<'a> = serde_json::from_value(Value::Object(result))?;
+
+        Ok(Self {
+            ctid: fields.ctid,
+            date: fields.date,
+            hash: fields.hash,
+            ledger_index: fields.ledger_index,
+            meta: fields.meta,
+            various: fields.various,
+            validated: fields.validated,
+            in_ledger: fields.in_ledger,
+        })
+    }
+}
+
 impl<'a> TryFrom<XRPLResult<'a>> for Tx<'a> {
     type Error = XRPLModelException;
 
@@ -38,3 +80,123 @@ impl<'a> TryFrom<XRPLResult<'a>> for Tx<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{models::results::XRPLResponse, utils::ctid::decode_ctid};
+
+    fn tx_result(ctid: Option<&str>) -> Tx<'static> {
+        let mut result = serde_json::json!({
+            "Account": "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn",
+            "TransactionType": "AccountSet",
+            "date": 638329271,
+            "hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB13FD",
+            "ledger_index": 349758,
+            "meta": {
+                "TransactionIndex": 0,
+                "TransactionResult": "tesSUCCESS"
+            },
+            "validated": true
+        });
+        if let Some(ctid) = ctid {
+            result["ctid"] = ctid.into();
+        }
+        let response: XRPLResponse = serde_json::from_value(serde_json::json!({
+            "result": result,
+            "status": "success",
+            "type": "response"
+        }))
+        .unwrap();
+
+        response.try_into_result().unwrap()
+    }
+
+    #[test]
+    fn test_ctid() {
+        let tx = tx_result(Some("C005563E00000001"));
+        assert_eq!(
+            decode_ctid(tx.ctid.as_deref().unwrap()).unwrap(),
+            (tx.ledger_index, 0, 1)
+        );
+
+        // Servers without CTID support omit it.
+        assert!(tx_result(None).ctid.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_api_v2() {
+        let meta = serde_json::json!({
+            "AffectedNodes": [],
+            "TransactionIndex": 1,
+            "TransactionResult": "tesSUCCESS",
+            "delivered_amount": "1000000"
+        });
+        let response = |result: Value| -> Tx<'static> {
+            serde_json::from_value::<XRPLResponse>(serde_json::json!({
+                "result": result,
+                "status": "success",
+                "type": "response"
+            }))
+            .unwrap()
+            .try_into_result()
+            .unwrap()
+        };
+        let v1 = response(serde_json::json!({
+            "Account": "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn",
+            "Amount": "1000000",
+            "DeliverMax": "1000000",
+            "Destination": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+            "Fee": "12",
+            "Sequence": 5,
+            "TransactionType": "Payment",
+            "ctid": "C005563E00010001",
+            "date": 754558840,
+            "hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB13FD",
+            "inLedger": 349758,
+            "ledger_index": 349758,
+            "meta": meta,
+            "validated": true
+        }));
+        let v2 = response(serde_json::json!({
+            "close_time_iso": "2023-11-29T07:40:40Z",
+            "ctid": "C005563E00010001",
+            "hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB13FD",
+            "ledger_hash": "3652D7FD0576BC452C0D2E9B747BDD733075971D1A9A1D98125055DEF428721A",
+            "ledger_index": 349758,
+            "meta": meta,
+            "tx_json": {
+                "Account": "rG1QQv2nh2gr7RCZ1P8YYcBUKCCN633jCn",
+                "DeliverMax": "1000000",
+                "Destination": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+                "Fee": "12",
+                "Sequence": 5,
+                "TransactionType": "Payment"
+            },
+            "validated": true
+        }));
+
+        for tx in [&v1, &v2] {
+            assert_eq!(tx.date, 754558840);
+            assert_eq!(tx.ledger_index, 349758);
+            assert_eq!(tx.meta, meta);
+            assert_eq!(tx.various["TransactionType"], "Payment");
+            assert_eq!(tx.various["Amount"], "1000000");
+        }
+        assert_eq!(v1.hash, v2.hash);
+        assert_eq!(v1.ctid, v2.ctid);
+        assert!(v2.various.get("tx_json").is_none());
+
+        // Binary results name the metadata `meta_blob`.
+        let binary = response(serde_json::json!({
+            "close_time_iso": "2023-11-29T07:40:40Z",
+            "hash": "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB13FD",
+            "ledger_index": 349758,
+            "meta_blob": "201C00000001F8E311006F5620",
+            "tx_blob": "12000022800000002400000005",
+            "validated": true
+        }));
+        assert_eq!(binary.meta, "201C00000001F8E311006F5620");
+        assert_eq!(binary.date, 754558840);
+    }
+}
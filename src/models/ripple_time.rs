@@ -0,0 +1,59 @@
+//! Typed accessors for fields holding a time in seconds since the
+//! Ripple Epoch, e.g. `Expiration` or `FinishAfter`.
+
+use chrono::{DateTime, Utc};
+
+use crate::utils::time_conversion::{datetime_to_ripple_time, ripple_time_to_datetime};
+
+use super::XRPLModelResult;
+
+/// Every `u32` is a representable time, so this only fails if chrono
+/// cannot represent it either.
+pub(crate) fn ripple_time_field_to_datetime(ripple_time: Option<u32>) -> Option<DateTime<Utc>> {
+    ripple_time.and_then(|ripple_time| ripple_time_to_datetime(ripple_time.into()).ok())
+}
+
+/// Fails for datetimes before the Ripple Epoch or after
+/// [`MAX_XRPL_TIME`](crate::utils::time_conversion::MAX_XRPL_TIME)
+/// instead of wrapping around.
+pub(crate) fn datetime_to_ripple_time_field(datetime: DateTime<Utc>) -> XRPLModelResult<u32> {
+    let ripple_time = datetime_to_ripple_time(datetime)?;
+
+    // `datetime_to_ripple_time` checked the range.
+    Ok(ripple_time as u32)
+}
+
+/// Implement a `<field>_datetime` getter and optionally a
+/// `set_<field>_datetime` setter for each `Option<u32>` ripple time
+/// field of a model.
+macro_rules! ripple_time_accessors {
+    ($model:ident { $($field:ident => $getter:ident $(, $setter:ident)?;)+ }) => {
+        impl<'a> $model<'a> {
+            $(
+                #[doc = concat!("The `", stringify!($field), "` time as a UTC datetime, if set.")]
+                pub fn $getter(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+                    $crate::models::ripple_time::ripple_time_field_to_datetime(self.$field)
+                }
+
+                $(
+                    #[doc = concat!(
+                        "Set the `", stringify!($field), "` time from a UTC datetime. ",
+                        "Fails for datetimes the XRP Ledger can't represent."
+                    )]
+                    pub fn $setter(
+                        &mut self,
+                        datetime: chrono::DateTime<chrono::Utc>,
+                    ) -> $crate::models::XRPLModelResult<()> {
+                        self.$field = Some(
+                            $crate::models::ripple_time::datetime_to_ripple_time_field(datetime)?,
+                        );
+
+                        Ok(())
+                    }
+                )?
+            )+
+        }
+    };
+}
+
+pub(crate) use ripple_time_accessors;
@@ -0,0 +1,219 @@
+//! JSON schemas of the request and transaction models, for tooling that
+//! generates forms or validates user input.
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use serde::Serialize;
+use serde_json::Value;
+use strum::IntoEnumIterator;
+
+use super::{requests, transactions};
+
+/// The JSON schema of a request or transaction model by its type name,
+/// e.g. `"Payment"` or `"AccountInfo"`. `None` if there is no such
+/// model.
+///
+/// The schemas describe the JSON the models serialize to, with the
+/// fields named as on the wire. For a request that is its parameters:
+/// the transport adds the `command` and `id`.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::schema_for;
+///
+/// let schema = schema_for("Payment").unwrap();
+/// assert_eq!(schema["properties"]["Flags"]["type"], "integer");
+/// assert!(schema_for("NotAModel").is_none());
+/// ```
+pub fn schema_for(type_name: &str) -> Option<Value> {
+    let schema = match type_name {
+        "AccountDelete" => schema::<transactions::account_delete::AccountDelete>(),
+        "AccountSet" => schema::<transactions::account_set::AccountSet>(),
+        "AMMBid" => schema::<transactions::amm_bid::AMMBid>(),
+        "AMMCreate" => schema::<transactions::amm_create::AMMCreate>(),
+        "AMMDelete" => schema::<transactions::amm_delete::AMMDelete>(),
+        "AMMDeposit" => schema::<transactions::amm_deposit::AMMDeposit>(),
+        "AMMVote" => schema::<transactions::amm_vote::AMMVote>(),
+        "AMMWithdraw" => schema::<transactions::amm_withdraw::AMMWithdraw>(),
+        "CheckCancel" => schema::<transactions::check_cancel::CheckCancel>(),
+        "CheckCash" => schema::<transactions::check_cash::CheckCash>(),
+        "CheckCreate" => schema::<transactions::check_create::CheckCreate>(),
+        "DepositPreauth" => schema::<transactions::deposit_preauth::DepositPreauth>(),
+        "EscrowCancel" => schema::<transactions::escrow_cancel::EscrowCancel>(),
+        "EscrowCreate" => schema::<transactions::escrow_create::EscrowCreate>(),
+        "EscrowFinish" => schema::<transactions::escrow_finish::EscrowFinish>(),
+        "NFTokenAcceptOffer" => schema::<transactions::nftoken_accept_offer::NFTokenAcceptOffer>(),
+        "NFTokenBurn" => schema::<transactions::nftoken_burn::NFTokenBurn>(),
+        "NFTokenCancelOffer" => schema::<transactions::nftoken_cancel_offer::NFTokenCancelOffer>(),
+        "NFTokenCreateOffer" => schema::<transactions::nftoken_create_offer::NFTokenCreateOffer>(),
+        "NFTokenMint" => schema::<transactions::nftoken_mint::NFTokenMint>(),
+        "OfferCancel" => schema::<transactions::offer_cancel::OfferCancel>(),
+        "OfferCreate" => schema::<transactions::offer_create::OfferCreate>(),
+        "Payment" => schema::<transactions::payment::Payment>(),
+        "PaymentChannelClaim" => {
+            schema::<transactions::payment_channel_claim::PaymentChannelClaim>()
+        }
+        "PaymentChannelCreate" => {
+            schema::<transactions::payment_channel_create::PaymentChannelCreate>()
+        }
+        "PaymentChannelFund" => schema::<transactions::payment_channel_fund::PaymentChannelFund>(),
+        #[cfg(feature = "xahau")]
+        "SetHook" => schema::<transactions::set_hook::SetHook>(),
+        "SetRegularKey" => schema::<transactions::set_regular_key::SetRegularKey>(),
+        "SignerListSet" => schema::<transactions::signer_list_set::SignerListSet>(),
+        "TicketCreate" => schema::<transactions::ticket_create::TicketCreate>(),
+        "TrustSet" => schema::<transactions::trust_set::TrustSet>(),
+        "XChainAccountCreateCommit" => {
+            schema::<transactions::xchain_account_create_commit::XChainAccountCreateCommit>()
+        }
+        "XChainAddAccountCreateAttestation" => schema::<
+            transactions::xchain_add_account_create_attestation::XChainAddAccountCreateAttestation,
+        >(),
+        "XChainAddClaimAttestation" => {
+            schema::<transactions::xchain_add_claim_attestation::XChainAddClaimAttestation>()
+        }
+        "XChainClaim" => schema::<transactions::xchain_claim::XChainClaim>(),
+        "XChainCommit" => schema::<transactions::xchain_commit::XChainCommit>(),
+        "XChainCreateBridge" => schema::<transactions::xchain_create_bridge::XChainCreateBridge>(),
+        "XChainCreateClaimID" => {
+            schema::<transactions::xchain_create_claim_id::XChainCreateClaimID>()
+        }
+        "XChainModifyBridge" => schema::<transactions::xchain_modify_bridge::XChainModifyBridge>(),
+        "EnableAmendment" => schema::<transactions::pseudo_transactions::EnableAmendment>(),
+        "SetFee" => schema::<transactions::pseudo_transactions::SetFee>(),
+        "UNLModify" => schema::<transactions::pseudo_transactions::UNLModify>(),
+        "AccountChannels" => schema::<requests::account_channels::AccountChannels>(),
+        "AccountCurrencies" => schema::<requests::account_currencies::AccountCurrencies>(),
+        "AccountInfo" => schema::<requests::account_info::AccountInfo>(),
+        "AccountLines" => schema::<requests::account_lines::AccountLines>(),
+        "AccountNfts" => schema::<requests::account_nfts::AccountNfts>(),
+        "AccountObjects" => schema::<requests::account_objects::AccountObjects>(),
+        "AccountOffers" => schema::<requests::account_offers::AccountOffers>(),
+        "AccountTx" => schema::<requests::account_tx::AccountTx>(),
+        "AMMInfo" => schema::<requests::amm_info::AMMInfo>(),
+        "GatewayBalances" => schema::<requests::gateway_balances::GatewayBalances>(),
+        "NoRippleCheck" => schema::<requests::no_ripple_check::NoRippleCheck>(),
+        "Simulate" => schema::<requests::simulate::Simulate>(),
+        "Submit" => schema::<requests::submit::Submit>(),
+        "SubmitMultisigned" => schema::<requests::submit_multisigned::SubmitMultisigned>(),
+        "TransactionEntry" => schema::<requests::transaction_entry::TransactionEntry>(),
+        "Tx" => schema::<requests::tx::Tx>(),
+        "ChannelAuthorize" => schema::<requests::channel_authorize::ChannelAuthorize>(),
+        "ChannelVerify" => schema::<requests::channel_verify::ChannelVerify>(),
+        "BookOffers" => schema::<requests::book_offers::BookOffers>(),
+        "DepositAuthorized" => schema::<requests::deposit_authorize::DepositAuthorized>(),
+        "NftBuyOffers" => schema::<requests::nft_buy_offers::NftBuyOffers>(),
+        "NftSellOffers" => schema::<requests::nft_sell_offers::NftSellOffers>(),
+        "PathFind" => schema::<requests::path_find::PathFind>(),
+        "RipplePathFind" => schema::<requests::ripple_path_find::RipplePathFind>(),
+        "Ledger" => schema::<requests::ledger::Ledger>(),
+        "LedgerAccept" => schema::<requests::ledger_accept::LedgerAccept>(),
+        "LedgerClosed" => schema::<requests::ledger_closed::LedgerClosed>(),
+        "LedgerCurrent" => schema::<requests::ledger_current::LedgerCurrent>(),
+        "LedgerData" => schema::<requests::ledger_data::LedgerData>(),
+        "LedgerEntry" => schema::<requests::ledger_entry::LedgerEntry>(),
+        "Subscribe" => schema::<requests::subscribe::Subscribe>(),
+        "Unsubscribe" => schema::<requests::unsubscribe::Unsubscribe>(),
+        "Fee" => schema::<requests::fee::Fee>(),
+        "Manifest" => schema::<requests::manifest::Manifest>(),
+        "ServerInfo" => schema::<requests::server_info::ServerInfo>(),
+        "ServerState" => schema::<requests::server_state::ServerState>(),
+        "Ping" => schema::<requests::ping::Ping>(),
+        "Random" => schema::<requests::random::Random>(),
+        _ => return None,
+    };
+
+    Some(schema)
+}
+
+fn schema<T: schemars::JsonSchema>() -> Value {
+    schemars::generate::SchemaSettings::default()
+        .for_serialize()
+        .into_generator()
+        .into_root_schema_for::<T>()
+        .to_value()
+}
+
+/// The schema of a `Serialize_repr` enum: the integers its variants
+/// serialize to.
+fn repr_enum_schema<T>() -> schemars::Schema
+where
+    T: IntoEnumIterator + Serialize,
+{
+    let values: Vec<Value> = T::iter()
+        .filter_map(|variant| serde_json::to_value(variant).ok())
+        .collect();
+
+    schemars::json_schema!({
+        "type": "integer",
+        "enum": values,
+    })
+}
+
+/// Implements `JsonSchema` for `Serialize_repr` enums, which the derive
+/// would describe by their variant names.
+macro_rules! repr_json_schema {
+    ($($name:ident),+) => {
+        $(
+            impl schemars::JsonSchema for $name {
+                fn schema_name() -> Cow<'static, str> {
+                    stringify!($name).into()
+                }
+
+                fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+                    repr_enum_schema::<Self>()
+                }
+            }
+        )+
+    };
+}
+
+use transactions::{account_set::AccountSetFlag, pseudo_transactions::UNLModifyDisabling};
+
+repr_json_schema!(AccountSetFlag, UNLModifyDisabling);
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_payment_schema() {
+        let schema = schema_for("Payment").unwrap();
+
+        let required = schema["required"].as_array().unwrap();
+        assert!(required.contains(&json!("Account")));
+        assert!(required.contains(&json!("Destination")));
+        assert_eq!(schema["properties"]["Flags"]["type"], "integer");
+        // Memos are written tagged, as `{"Memo": {...}}`.
+        assert_eq!(schema["$defs"]["Memo"]["required"], json!(["Memo"]));
+        assert_eq!(
+            schema["$defs"]["Memo"]["properties"]["Memo"].get("required"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_repr_enum_schema() {
+        let schema = schema_for("AccountSet").unwrap();
+
+        assert_eq!(schema["$defs"]["AccountSetFlag"]["type"], "integer");
+        assert!(schema["$defs"]["AccountSetFlag"]["enum"]
+            .as_array()
+            .unwrap()
+            .contains(&json!(16)));
+    }
+
+    #[test]
+    fn test_request_schema() {
+        let schema = schema_for("AccountInfo").unwrap();
+
+        assert_eq!(schema["required"], json!(["account"]));
+        assert!(schema_for("Raw").is_none());
+        assert!(schema_for("NotAModel").is_none());
+    }
+}
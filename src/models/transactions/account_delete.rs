@@ -3,13 +3,15 @@ use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::core::addresscodec::xaddress_to_classic_address;
 use crate::models::amount::XRPAmount;
+use crate::models::transactions::exceptions::XRPLAccountDeleteException;
 use crate::models::transactions::CommonFields;
+use crate::models::NoFlags;
 use crate::models::{
     transactions::{Transaction, TransactionType},
-    Model,
+    Model, XRPLModelException, XRPLModelResult,
 };
-use crate::models::{FlagCollection, NoFlags};
 
 use super::{Memo, Signer};
 
@@ -22,6 +24,7 @@ use super::{Memo, Signer};
 /// `<https://xrpl.org/accountdelete.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct AccountDelete<'a> {
     /// The base fields for all transaction models.
@@ -44,7 +47,14 @@ pub struct AccountDelete<'a> {
     pub destination_tag: Option<u32>,
 }
 
-impl<'a> Model for AccountDelete<'a> {}
+impl<'a> Model for AccountDelete<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
+        self._get_destination_error()?;
+
+        Ok(())
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for AccountDelete<'a> {
     fn get_transaction_type(&self) -> TransactionType {
@@ -76,20 +86,15 @@ impl<'a> AccountDelete<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::AccountDelete,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::AccountDelete)
             },
             destination,
             destination_tag,
@@ -97,6 +102,103 @@ impl<'a> AccountDelete<'a> {
     }
 }
 
+impl<'a> AccountDeleteError for AccountDelete<'a> {
+    fn _get_destination_error(&self) -> XRPLModelResult<()> {
+        // Either address may be an X-address, which is only the same
+        // account as a classic address if their classic parts match.
+        let (destination, xaddress_tag) = match xaddress_to_classic_address(&self.destination) {
+            Ok((classic_address, tag, _)) => (Cow::from(classic_address), tag),
+            Err(_) => (self.destination.clone(), None),
+        };
+        let account = match xaddress_to_classic_address(&self.common_fields.account) {
+            Ok((classic_address, _, _)) => Cow::from(classic_address),
+            Err(_) => self.common_fields.account.clone(),
+        };
+        if destination == account {
+            return Err(XRPLModelException::ValueEqualsValue {
+                field1: "destination".into(),
+                field2: "account".into(),
+            });
+        }
+        match (xaddress_tag, self.destination_tag) {
+            (Some(xaddress_tag), Some(destination_tag)) if xaddress_tag != destination_tag => {
+                Err(XRPLAccountDeleteException::DestinationTagMismatch {
+                    xaddress_tag,
+                    destination_tag,
+                }
+                .into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+pub trait AccountDeleteError {
+    fn _get_destination_error(&self) -> XRPLModelResult<()>;
+}
+
+#[cfg(test)]
+mod test_account_delete_error {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::core::addresscodec::classic_address_to_xaddress;
+
+    fn account_delete<'a>(
+        destination: Cow<'a, str>,
+        destination_tag: Option<u32>,
+    ) -> AccountDelete<'a> {
+        AccountDelete::new(
+            "rWYkbWkCeg8dP6rXALnjgZSjjLyih5NXm".into(),
+            None,
+            Some("2000000".into()),
+            None,
+            None,
+            Some(2470665),
+            None,
+            None,
+            None,
+            destination,
+            destination_tag,
+        )
+    }
+
+    #[test]
+    fn test_destination_error() {
+        assert!(account_delete("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".into(), Some(13)).is_valid());
+
+        let to_self = account_delete("rWYkbWkCeg8dP6rXALnjgZSjjLyih5NXm".into(), None);
+        assert_eq!(
+            to_self.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `\"destination\"` is not allowed to be the same as the value of the field `\"account\"`"
+        );
+
+        // The same account, behind an X-address.
+        let xaddress =
+            classic_address_to_xaddress("rWYkbWkCeg8dP6rXALnjgZSjjLyih5NXm", Some(13), false)
+                .unwrap();
+        assert!(!account_delete(xaddress.into(), None).is_valid());
+    }
+
+    #[test]
+    fn test_destination_tag_error() {
+        let xaddress =
+            classic_address_to_xaddress("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe", Some(13), false)
+                .unwrap();
+
+        assert!(account_delete(xaddress.clone().into(), None).is_valid());
+        assert!(account_delete(xaddress.clone().into(), Some(13)).is_valid());
+        assert_eq!(
+            account_delete(xaddress.into(), Some(14))
+                .validate()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "The tag of the destination X-address (13) does not match the field `destination_tag` (14)"
+        );
+    }
+}
+
 #[cfg(test)]
 mod test_serde {
     use super::*;
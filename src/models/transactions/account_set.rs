@@ -1,4 +1,5 @@
 use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
@@ -19,10 +20,36 @@ use crate::{
     },
 };
 
-use super::FlagCollection;
+use super::{builder::transaction_builder, FlagCollection};
 
 /// Transactions of the AccountSet type support additional values
-/// in the Flags field. This enum represents those options.
+/// in the Flags field. This enum represents those options. They
+/// predate `SetFlag` and `ClearFlag`, which should be preferred.
+///
+/// See AccountSet flags:
+/// `<https://xrpl.org/accountset.html#accountset-flags>`
+#[derive(
+    Debug, Eq, PartialEq, Clone, Serialize_repr, Deserialize_repr, Display, AsRefStr, EnumIter, Copy,
+)]
+#[repr(u32)]
+pub enum AccountSetTfFlag {
+    /// The same as `SetFlag`: `AsfRequireDest`.
+    TfRequireDestTag = 0x00010000,
+    /// The same as `ClearFlag`: `AsfRequireDest`.
+    TfOptionalDestTag = 0x00020000,
+    /// The same as `SetFlag`: `AsfRequireAuth`.
+    TfRequireAuth = 0x00040000,
+    /// The same as `ClearFlag`: `AsfRequireAuth`.
+    TfOptionalAuth = 0x00080000,
+    /// The same as `SetFlag`: `AsfDisallowXRP`.
+    TfDisallowXRP = 0x00100000,
+    /// The same as `ClearFlag`: `AsfDisallowXRP`.
+    TfAllowXRP = 0x00200000,
+}
+
+/// The account options an AccountSet transaction can enable with
+/// `SetFlag` or disable with `ClearFlag`. These are values, not
+/// bits of the Flags field.
 ///
 /// See AccountSet flags:
 /// `<https://xrpl.org/accountset.html#accountset-flags>`
@@ -63,6 +90,23 @@ pub enum AccountSetFlag {
     AsfRequireAuth = 2,
     /// Require a destination tag to send transactions to this account.
     AsfRequireDest = 1,
+    /// Block incoming NFTokenOffers.
+    /// (Added by the DisallowIncoming amendment.)
+    AsfDisallowIncomingNFTokenOffer = 12,
+    /// Block incoming Checks.
+    /// (Added by the DisallowIncoming amendment.)
+    AsfDisallowIncomingCheck = 13,
+    /// Block incoming Payment Channels.
+    /// (Added by the DisallowIncoming amendment.)
+    AsfDisallowIncomingPayChan = 14,
+    /// Block incoming trust lines.
+    /// (Added by the DisallowIncoming amendment.)
+    AsfDisallowIncomingTrustline = 15,
+    /// Allow the account to claw back the tokens it issues. Can only
+    /// be enabled if the account has an empty owner directory, and
+    /// can never be disabled.
+    /// (Added by the Clawback amendment.)
+    AsfAllowTrustLineClawback = 16,
 }
 
 /// An AccountSet transaction modifies the properties of an
@@ -72,6 +116,7 @@ pub enum AccountSetFlag {
 /// `<https://xrpl.org/accountset.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct AccountSet<'a> {
     /// The base fields for all transaction models.
@@ -79,7 +124,7 @@ pub struct AccountSet<'a> {
     /// See Transaction Common Fields:
     /// `<https://xrpl.org/transaction-common-fields.html>`
     #[serde(flatten)]
-    pub common_fields: CommonFields<'a, AccountSetFlag>,
+    pub common_fields: CommonFields<'a, AccountSetTfFlag>,
     // The custom fields for the AccountSet model.
     //
     // See AccountSet fields:
@@ -88,7 +133,8 @@ pub struct AccountSet<'a> {
     pub clear_flag: Option<AccountSetFlag>,
     /// The domain that owns this account, as a string of hex
     /// representing the ASCII for the domain in lowercase.
-    /// Cannot be more than 256 bytes in length.
+    /// Cannot be more than 256 bytes in length. An empty string
+    /// removes the domain.
     pub domain: Option<Cow<'a, str>>,
     /// Hash of an email address to be used for generating an
     /// avatar image. Conventionally, clients use Gravatar
@@ -121,6 +167,7 @@ pub struct AccountSet<'a> {
 
 impl<'a> Model for AccountSet<'a> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         self._get_tick_size_error()?;
         self._get_transfer_rate_error()?;
         self._get_domain_error()?;
@@ -131,20 +178,16 @@ impl<'a> Model for AccountSet<'a> {
     }
 }
 
-impl<'a> Transaction<'a, AccountSetFlag> for AccountSet<'a> {
-    fn has_flag(&self, flag: &AccountSetFlag) -> bool {
-        self.common_fields.has_flag(flag)
-    }
-
+impl<'a> Transaction<'a, AccountSetTfFlag> for AccountSet<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.common_fields.get_transaction_type()
     }
 
-    fn get_common_fields(&self) -> &CommonFields<'_, AccountSetFlag> {
+    fn get_common_fields(&self) -> &CommonFields<'_, AccountSetTfFlag> {
         self.common_fields.get_common_fields()
     }
 
-    fn get_mut_common_fields(&mut self) -> &mut CommonFields<'a, AccountSetFlag> {
+    fn get_mut_common_fields(&mut self) -> &mut CommonFields<'a, AccountSetTfFlag> {
         self.common_fields.get_mut_common_fields()
     }
 }
@@ -197,18 +240,27 @@ impl<'a> AccountSetError for AccountSet<'a> {
     }
 
     fn _get_domain_error(&self) -> Result<(), XRPLModelException> {
-        if let Some(domain) = self.domain.clone() {
-            if domain.to_lowercase().as_str() != domain {
-                Err(XRPLModelException::InvalidValueFormat {
+        if let Some(domain) = &self.domain {
+            // The hex digits may be of either case, the domain itself
+            // must be lowercase.
+            let Ok(decoded) = hex::decode(domain.as_ref()) else {
+                return Err(XRPLModelException::InvalidValueFormat {
                     field: "domain".into(),
-                    found: domain.into(),
-                    format: "lowercase".into(),
-                })
-            } else if domain.len() > MAX_DOMAIN_LENGTH {
+                    format: "hex".into(),
+                    found: domain.to_string(),
+                });
+            };
+            if decoded.len() > MAX_DOMAIN_LENGTH {
                 Err(XRPLModelException::ValueTooLong {
                     field: "domain".into(),
                     max: MAX_DOMAIN_LENGTH,
-                    found: domain.len(),
+                    found: decoded.len(),
+                })
+            } else if decoded.iter().any(u8::is_ascii_uppercase) {
+                Err(XRPLModelException::InvalidValueFormat {
+                    field: "domain".into(),
+                    format: "lowercase".into(),
+                    found: String::from_utf8_lossy(&decoded).into_owned(),
                 })
             } else {
                 Ok(())
@@ -219,14 +271,11 @@ impl<'a> AccountSetError for AccountSet<'a> {
     }
 
     fn _get_clear_flag_error(&self) -> Result<(), XRPLModelException> {
-        if self.clear_flag.is_some() && self.set_flag.is_some() && self.clear_flag == self.set_flag
-        {
-            Err(XRPLAccountSetException::SetAndUnsetSameFlag {
-                found: self.clear_flag.unwrap(),
+        match (&self.clear_flag, &self.set_flag) {
+            (Some(clear_flag), Some(set_flag)) if clear_flag == set_flag => {
+                Err(XRPLAccountSetException::SetAndUnsetSameFlag { found: *clear_flag }.into())
             }
-            .into())
-        } else {
-            Ok(())
+            _ => Ok(()),
         }
     }
 
@@ -276,7 +325,7 @@ impl<'a> AccountSet<'a> {
         account: Cow<'a, str>,
         account_txn_id: Option<Cow<'a, str>>,
         fee: Option<XRPAmount<'a>>,
-        flags: Option<FlagCollection<AccountSetFlag>>,
+        flags: Option<FlagCollection<AccountSetTfFlag>>,
         last_ledger_sequence: Option<u32>,
         memos: Option<Vec<Memo>>,
         sequence: Option<u32>,
@@ -294,8 +343,6 @@ impl<'a> AccountSet<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::AccountSet,
                 account_txn_id,
                 fee,
                 flags: flags.unwrap_or_default(),
@@ -305,9 +352,7 @@ impl<'a> AccountSet<'a> {
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::AccountSet)
             },
             clear_flag,
             domain,
@@ -329,6 +374,13 @@ pub trait AccountSetError {
     fn _get_nftoken_minter_error(&self) -> Result<(), XRPLModelException>;
 }
 
+transaction_builder! {
+    AccountSet<AccountSetTfFlag> => AccountSetBuilder {
+        into domain: Cow<'a, str>, email_hash: Cow<'a, str>, message_key: Cow<'a, str>, nftoken_minter: Cow<'a, str>;
+        values clear_flag: AccountSetFlag, set_flag: AccountSetFlag, transfer_rate: u32, tick_size: u32;
+    }
+}
+
 #[cfg(test)]
 mod test_account_set_errors {
 
@@ -374,6 +426,11 @@ mod test_account_set_errors {
             account_set.validate().unwrap_err().to_string().as_str(),
             "The value of the field `\"tick_size\"` is defined above its maximum (max 15, found 16)"
         );
+
+        for tick_size in [0, 3, 15] {
+            account_set.tick_size = Some(tick_size);
+            assert!(account_set.validate().is_ok());
+        }
     }
 
     #[test]
@@ -413,6 +470,16 @@ mod test_account_set_errors {
             account_set.validate().unwrap_err().to_string().as_str(),
             "The value of the field `\"transfer_rate\"` is defined above its maximum (max 2000000000, found 2000000001)"
         );
+
+        let transfer_rate_too_low = Some(1);
+        account_set.transfer_rate = transfer_rate_too_low;
+
+        assert!(account_set.validate().is_err());
+
+        for transfer_rate in [0, 1000000000, 2000000000] {
+            account_set.transfer_rate = Some(transfer_rate);
+            assert!(account_set.validate().is_ok());
+        }
     }
 
     #[test]
@@ -437,7 +504,8 @@ mod test_account_set_errors {
             None,
             None,
         );
-        let domain_not_lowercase = Some("https://Example.com/".into());
+        // "https://Example.com/"
+        let domain_not_lowercase = Some("68747470733A2F2F4578616D706C652E636F6D2F".into());
         account_set.domain = domain_not_lowercase;
 
         assert_eq!(
@@ -445,13 +513,30 @@ mod test_account_set_errors {
             "The value of the field `\"domain\"` does not have the correct format (expected \"lowercase\", found \"https://Example.com/\")"
         );
 
-        let domain_too_long = Some("https://example.com/aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".into());
+        let domain_not_hex = Some("example.com".into());
+        account_set.domain = domain_not_hex;
+
+        assert_eq!(
+            account_set.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `\"domain\"` does not have the correct format (expected \"hex\", found \"example.com\")"
+        );
+
+        let domain_too_long = Some("61".repeat(257).into());
         account_set.domain = domain_too_long;
 
         assert_eq!(
             account_set.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `\"domain\"` exceeds its maximum length of characters (max 256, found 270)"
+            "The value of the field `\"domain\"` exceeds its maximum length of characters (max 256, found 257)"
         );
+
+        // The hex digits may be uppercase, and an empty domain removes
+        // it.
+        for domain in ["6578616D706C652E636F6D", "6578616d706c652e636f6d", ""] {
+            account_set.domain = Some(domain.into());
+            assert!(account_set.validate().is_ok());
+        }
+        account_set.domain = Some("61".repeat(256).into());
+        assert!(account_set.validate().is_ok());
     }
 
     #[test]
@@ -481,6 +566,23 @@ mod test_account_set_errors {
             account_set.validate().unwrap_err().to_string().as_str(),
             "A flag cannot be set and unset at the same time (found AsfDisallowXRP)"
         );
+
+        let mut account_set = account_set;
+        account_set.set_flag = Some(AccountSetFlag::AsfDisallowIncomingCheck);
+        account_set.clear_flag = Some(AccountSetFlag::AsfDisallowIncomingCheck);
+
+        assert_eq!(
+            account_set.validate().unwrap_err().to_string().as_str(),
+            "A flag cannot be set and unset at the same time (found AsfDisallowIncomingCheck)"
+        );
+
+        account_set.clear_flag = Some(AccountSetFlag::AsfDisallowXRP);
+
+        assert!(account_set.validate().is_ok());
+
+        account_set.set_flag = None;
+
+        assert!(account_set.validate().is_ok());
     }
 
     #[test]
@@ -568,4 +670,37 @@ mod tests {
         let deserialized: AccountSet = serde_json::from_str(default_json_str).unwrap();
         assert_eq!(default_txn, deserialized);
     }
+
+    #[test]
+    fn test_serde_disallow_incoming_flags() {
+        let flags = [
+            (AccountSetFlag::AsfDisallowIncomingNFTokenOffer, 12),
+            (AccountSetFlag::AsfDisallowIncomingCheck, 13),
+            (AccountSetFlag::AsfDisallowIncomingPayChan, 14),
+            (AccountSetFlag::AsfDisallowIncomingTrustline, 15),
+            (AccountSetFlag::AsfAllowTrustLineClawback, 16),
+        ];
+
+        for (flag, value) in flags {
+            assert_eq!(serde_json::to_value(flag).unwrap(), value);
+            let deserialized: AccountSet = serde_json::from_str(&alloc::format!(
+                r#"{{"Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","TransactionType":"AccountSet","Flags":0,"SetFlag":{}}}"#,
+                value
+            ))
+            .unwrap();
+            assert_eq!(deserialized.set_flag, Some(flag));
+        }
+    }
+
+    #[test]
+    fn test_serde_tf_flags() {
+        let json = r#"{"Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","TransactionType":"AccountSet","Flags":65536}"#;
+        let deserialized: AccountSet = serde_json::from_str(json).unwrap();
+
+        assert!(deserialized
+            .common_fields
+            .flags
+            .contains(&AccountSetTfFlag::TfRequireDestTag));
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+    }
 }
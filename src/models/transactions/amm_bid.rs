@@ -3,8 +3,8 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::{
-    transactions::TransactionType, Currency, FlagCollection, IssuedCurrencyAmount, Model, NoFlags,
-    XRPAmount,
+    transactions::TransactionType, Currency, IssuedCurrencyAmount, Model, NoFlags, XRPAmount,
+    XRPLModelResult,
 };
 
 use super::{AuthAccount, CommonFields, Memo, Signer, Transaction};
@@ -19,6 +19,7 @@ use super::{AuthAccount, CommonFields, Memo, Signer, Transaction};
 /// to the AMM, decreasing the outstanding balance of LP Tokens.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct AMMBid<'a> {
     #[serde(flatten)]
@@ -41,7 +42,11 @@ pub struct AMMBid<'a> {
     pub auth_accounts: Option<Vec<AuthAccount>>,
 }
 
-impl Model for AMMBid<'_> {}
+impl Model for AMMBid<'_> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for AMMBid<'a> {
     fn get_common_fields(&self) -> &CommonFields<'_, NoFlags> {
@@ -76,20 +81,15 @@ impl<'a> AMMBid<'_> {
     ) -> AMMBid<'a> {
         AMMBid {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::AMMBid,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::AMMBid)
             },
             asset,
             asset2,
@@ -2,7 +2,7 @@ use alloc::{borrow::Cow, vec::Vec};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::models::{Amount, FlagCollection, Model, NoFlags, XRPAmount, XRPLModelResult};
+use crate::models::{Amount, Model, NoFlags, XRPAmount, XRPLModelResult};
 
 use super::{
     exceptions::{XRPLAMMCreateException, XRPLTransactionException},
@@ -29,6 +29,7 @@ pub const AMM_CREATE_MAX_FEE: u16 = 1000;
 /// so it's best to set the trading fee based on the volatility of the asset pair.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct AMMCreate<'a> {
     #[serde(flatten)]
@@ -47,6 +48,7 @@ pub struct AMMCreate<'a> {
 
 impl Model for AMMCreate<'_> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         self.get_tranding_fee_error()?;
 
         Ok(())
@@ -84,20 +86,15 @@ impl<'a> AMMCreate<'a> {
     ) -> AMMCreate<'a> {
         AMMCreate {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::AMMCreate,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::AMMCreate)
             },
             amount,
             amount2,
@@ -2,7 +2,7 @@ use alloc::{borrow::Cow, vec::Vec};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::models::{Currency, FlagCollection, Model, NoFlags, XRPAmount};
+use crate::models::{Currency, Model, NoFlags, XRPAmount, XRPLModelResult};
 
 use super::{CommonFields, Memo, Signer, Transaction, TransactionType};
 
@@ -19,6 +19,7 @@ use super::{CommonFields, Memo, Signer, Transaction, TransactionType};
 /// deleted by the last such transaction.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct AMMDelete<'a> {
     #[serde(flatten)]
@@ -30,7 +31,11 @@ pub struct AMMDelete<'a> {
     pub asset2: Currency<'a>,
 }
 
-impl Model for AMMDelete<'_> {}
+impl Model for AMMDelete<'_> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for AMMDelete<'a> {
     fn get_common_fields(&self) -> &CommonFields<'_, NoFlags> {
@@ -62,20 +67,15 @@ impl<'a> AMMDelete<'a> {
     ) -> AMMDelete<'a> {
         AMMDelete {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::AMMDelete,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::AMMDelete)
             },
             asset,
             asset2,
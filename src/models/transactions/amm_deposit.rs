@@ -34,6 +34,7 @@ pub enum AMMDepositFlag {
 /// to hold the LP Tokens.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct AMMDeposit<'a> {
     #[serde(flatten)]
@@ -61,6 +62,7 @@ pub struct AMMDeposit<'a> {
 
 impl Model for AMMDeposit<'_> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         if self.amount2.is_some() && self.amount.is_none() {
             Err(XRPLModelException::FieldRequiresField {
                 field1: "amount2".into(),
@@ -73,7 +75,7 @@ impl Model for AMMDeposit<'_> {
             })
         } else if self.lp_token_out.is_none() && self.amount.is_none() {
             Err(XRPLModelException::ExpectedOneOf(
-                ["lp_token_out", "amount"].as_ref().into(),
+                ["lp_token_out", "amount"].as_ref(),
             ))
         } else {
             Ok(())
@@ -116,8 +118,6 @@ impl<'a> AMMDeposit<'a> {
     ) -> AMMDeposit<'a> {
         AMMDeposit {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::AMMDeposit,
                 account_txn_id,
                 fee,
                 flags: flags.unwrap_or_default(),
@@ -127,9 +127,7 @@ impl<'a> AMMDeposit<'a> {
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::AMMDeposit)
             },
             asset,
             asset2,
@@ -223,3 +221,19 @@ mod test_errors {
         assert!(deposit.get_errors().is_err());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_flag() {
+        let deposit: AMMDeposit = serde_json::from_str(
+            r#"{"Account":"rPEPPER7kfTD9w2To4CQk6UCfuHM9c6GDY","TransactionType":"AMMDeposit","Flags":524288,"Asset":{"currency":"XRP"},"Asset2":{"currency":"ETH","issuer":"rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd"},"Amount":"1000000"}"#,
+        )
+        .unwrap();
+        assert!(deposit.has_flag(&AMMDepositFlag::TfSingleAsset));
+        assert!(!deposit.has_flag(&AMMDepositFlag::TfTwoAsset));
+        assert!(!deposit.has_flag(&AMMDepositFlag::TfLpToken));
+    }
+}
@@ -2,9 +2,7 @@ use alloc::{borrow::Cow, vec::Vec};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::models::{
-    Currency, FlagCollection, Model, NoFlags, XRPAmount, XRPLModelException, XRPLModelResult,
-};
+use crate::models::{Currency, Model, NoFlags, XRPAmount, XRPLModelException, XRPLModelResult};
 
 use super::{CommonFields, Memo, Signer, Transaction, TransactionType};
 
@@ -18,8 +16,10 @@ pub const AMM_VOTE_MAX_TRADING_FEE: u16 = 1000;
 /// of the votes.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct AMMVote<'a> {
+    #[serde(flatten)]
     pub common_fields: CommonFields<'a, NoFlags>,
     /// The definition for one of the assets in the AMM's pool.
     pub asset: Currency<'a>,
@@ -34,6 +34,7 @@ pub struct AMMVote<'a> {
 
 impl Model for AMMVote<'_> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         if let Some(trading_fee) = self.trading_fee {
             if trading_fee > AMM_VOTE_MAX_TRADING_FEE {
                 return Err(XRPLModelException::ValueTooHigh {
@@ -79,20 +80,15 @@ impl<'a> AMMVote<'a> {
     ) -> AMMVote<'a> {
         AMMVote {
             common_fields: CommonFields {
-                account,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                transaction_type: TransactionType::AMMVote,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::AMMVote)
             },
             asset,
             asset2,
@@ -28,8 +28,10 @@ pub enum AMMWithdrawFlag {
 /// AMM's liquidity provider tokens (LP Tokens).
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct AMMWithdraw<'a> {
+    #[serde(flatten)]
     pub common_fields: CommonFields<'a, AMMWithdrawFlag>,
     /// The definition for one of the assets in the AMM's pool.
     pub asset: Currency<'a>,
@@ -53,6 +55,7 @@ pub struct AMMWithdraw<'a> {
 
 impl Model for AMMWithdraw<'_> {
     fn get_errors(&self) -> crate::models::XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         if self.amount2.is_some() && self.amount.is_none() {
             Err(crate::models::XRPLModelException::FieldRequiresField {
                 field1: "amount2".into(),
@@ -104,7 +107,6 @@ impl<'a> AMMWithdraw<'a> {
     ) -> Self {
         AMMWithdraw {
             common_fields: CommonFields {
-                account,
                 account_txn_id,
                 fee,
                 flags: flags.unwrap_or_default(),
@@ -114,10 +116,7 @@ impl<'a> AMMWithdraw<'a> {
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
-                transaction_type: TransactionType::AMMWithdraw,
+                ..CommonFields::with_account(account, TransactionType::AMMWithdraw)
             },
             asset,
             asset2,
@@ -128,3 +127,19 @@ impl<'a> AMMWithdraw<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_flag() {
+        let withdraw: AMMWithdraw = serde_json::from_str(
+            r#"{"Account":"rPEPPER7kfTD9w2To4CQk6UCfuHM9c6GDY","TransactionType":"AMMWithdraw","Flags":131072,"Asset":{"currency":"XRP"},"Asset2":{"currency":"ETH","issuer":"rP9jPyP5kyvFRb6ZiRghAGw5u8SGAmU4bd"}}"#,
+        )
+        .unwrap();
+        assert!(withdraw.has_flag(&AMMWithdrawFlag::TfWithdrawAll));
+        assert!(!withdraw.has_flag(&AMMWithdrawFlag::TfOneAssetWithdrawAll));
+        assert!(!withdraw.has_flag(&AMMWithdrawFlag::TfSingleAsset));
+    }
+}
@@ -0,0 +1,298 @@
+//! Builders for transaction models, as an alternative to their long
+//! positional `new` constructors.
+
+use alloc::borrow::Cow;
+use core::fmt::Debug;
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+use crate::models::{FlagCollection, XRPAmount};
+
+use super::{CommonFields, Memo};
+
+/// The setters of the fields common to all transactions, shared by
+/// the builders of every transaction type.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::transactions::payment::{Payment, PaymentFlag};
+/// use xrpl::models::transactions::{CommonFieldsBuilder, Memo};
+///
+/// let payment = Payment::builder(
+///     "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+///     "1000000".into(),
+///     "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".into(),
+/// )
+/// .destination_tag(1)
+/// .fee("12")
+/// .sequence(7)
+/// .source_tag(1)
+/// .memo(Memo::from_text("invoice 42", None, None))
+/// .flag(PaymentFlag::TfNoDirectRipple)
+/// .build()
+/// .unwrap();
+///
+/// assert_eq!(payment.destination_tag, Some(1));
+/// assert_eq!(payment.common_fields.fee, Some("12".into()));
+/// assert_eq!(payment.common_fields.memos.unwrap().len(), 1);
+/// ```
+pub trait CommonFieldsBuilder<'a, F>: Sized
+where
+    F: IntoEnumIterator + Serialize + Debug,
+{
+    fn get_mut_common_fields(&mut self) -> &mut CommonFields<'a, F>;
+
+    fn account_txn_id(mut self, account_txn_id: impl Into<Cow<'a, str>>) -> Self {
+        self.get_mut_common_fields().account_txn_id = Some(account_txn_id.into());
+        self
+    }
+
+    fn fee(mut self, fee: impl Into<XRPAmount<'a>>) -> Self {
+        self.get_mut_common_fields().fee = Some(fee.into());
+        self
+    }
+
+    /// Add a flag to the flags already set.
+    fn flag(mut self, flag: F) -> Self {
+        self.get_mut_common_fields().flags.flags.push(flag);
+        self
+    }
+
+    /// Replace the flags already set.
+    fn flags(mut self, flags: impl Into<FlagCollection<F>>) -> Self {
+        self.get_mut_common_fields().flags = flags.into();
+        self
+    }
+
+    fn last_ledger_sequence(mut self, last_ledger_sequence: u32) -> Self {
+        self.get_mut_common_fields().last_ledger_sequence = Some(last_ledger_sequence);
+        self
+    }
+
+    /// Add a memo to the memos already set.
+    fn memo(mut self, memo: Memo) -> Self {
+        self.get_mut_common_fields()
+            .memos
+            .get_or_insert_with(Default::default)
+            .push(memo);
+        self
+    }
+
+    fn network_id(mut self, network_id: u32) -> Self {
+        self.get_mut_common_fields().network_id = Some(network_id);
+        self
+    }
+
+    fn sequence(mut self, sequence: u32) -> Self {
+        self.get_mut_common_fields().sequence = Some(sequence);
+        self
+    }
+
+    fn source_tag(mut self, source_tag: u32) -> Self {
+        self.get_mut_common_fields().source_tag = Some(source_tag);
+        self
+    }
+
+    fn ticket_sequence(mut self, ticket_sequence: u32) -> Self {
+        self.get_mut_common_fields().ticket_sequence = Some(ticket_sequence);
+        self
+    }
+}
+
+/// Implement a `builder(account, <required fields>)` for a
+/// transaction model, with a setter for each optional field and the
+/// common fields through [`CommonFieldsBuilder`]. `build` validates
+/// the transaction.
+///
+/// `required` lists the fields the transaction cannot be built
+/// without, in the order of `builder`'s parameters, `into` the
+/// optional fields whose setters take anything convertible into
+/// their type, and `values` the other optional fields. Every field
+/// of the model must be listed.
+macro_rules! transaction_builder {
+    (
+        $transaction:ident<$flags:ty> => $builder:ident {
+            $(required $($required:ident: $required_ty:ty),+;)?
+            $(into $($into:ident: $into_ty:ty),+;)?
+            $(values $($value:ident: $value_ty:ty),+;)?
+        }
+    ) => {
+        #[doc = concat!(
+            "Builds a [`", stringify!($transaction), "`], validating it in `build`."
+        )]
+        #[derive(Debug, Clone)]
+        pub struct $builder<'a> {
+            transaction: $transaction<'a>,
+        }
+
+        impl<'a> $transaction<'a> {
+            /// Start building a transaction from its required fields.
+            pub fn builder(
+                account: impl Into<alloc::borrow::Cow<'a, str>>,
+                $($($required: $required_ty,)+)?
+            ) -> $builder<'a> {
+                $builder {
+                    transaction: $transaction {
+                        common_fields: $crate::models::transactions::CommonFields::new(
+                            account.into(),
+                            $crate::models::transactions::TransactionType::$transaction,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                        ),
+                        $($($required,)+)?
+                        $($($into: None,)+)?
+                        $($($value: None,)+)?
+                    },
+                }
+            }
+        }
+
+        impl<'a> $builder<'a> {
+            $($(
+                pub fn $into(mut self, $into: impl Into<$into_ty>) -> Self {
+                    self.transaction.$into = Some($into.into());
+                    self
+                }
+            )+)?
+
+            $($(
+                pub fn $value(mut self, $value: $value_ty) -> Self {
+                    self.transaction.$value = Some($value);
+                    self
+                }
+            )+)?
+
+            /// Validate the transaction and return it.
+            pub fn build(self) -> $crate::models::XRPLModelResult<$transaction<'a>> {
+                $crate::models::Model::validate(&self.transaction)?;
+
+                Ok(self.transaction)
+            }
+        }
+
+        impl<'a> $crate::models::transactions::CommonFieldsBuilder<'a, $flags> for $builder<'a> {
+            fn get_mut_common_fields(
+                &mut self,
+            ) -> &mut $crate::models::transactions::CommonFields<'a, $flags> {
+                &mut self.transaction.common_fields
+            }
+        }
+    };
+}
+
+pub(crate) use transaction_builder;
+
+#[cfg(all(test, feature = "helpers"))]
+mod test {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::{
+        asynch::transaction::sign,
+        models::{
+            transactions::{
+                nftoken_accept_offer::NFTokenAcceptOffer,
+                nftoken_mint::{NFTokenMint, NFTokenMintFlag},
+                payment::Payment,
+                Transaction,
+            },
+            Model,
+        },
+        wallet::Wallet,
+    };
+
+    fn wallet() -> Wallet {
+        Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap()
+    }
+
+    #[test]
+    fn test_build_and_sign_payment() {
+        let wallet = wallet();
+        let mut payment = Payment::builder(
+            wallet.classic_address.as_str(),
+            "1000000".into(),
+            "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".into(),
+        )
+        .destination_tag(42)
+        .fee("12")
+        .sequence(1)
+        .last_ledger_sequence(100)
+        .memo(Memo::from_text("rent", None, None))
+        .build()
+        .unwrap();
+
+        assert_eq!(
+            payment,
+            Payment::new(
+                wallet.classic_address.as_str().into(),
+                None,
+                Some("12".into()),
+                None,
+                Some(100),
+                Some([Memo::from_text("rent", None, None)].to_vec()),
+                Some(1),
+                None,
+                None,
+                None,
+                "1000000".into(),
+                "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".into(),
+                None,
+                Some(42),
+                None,
+                None,
+                None,
+            )
+        );
+        sign(&mut payment, &wallet, false).unwrap();
+        assert!(payment.get_common_fields().is_signed());
+    }
+
+    #[test]
+    fn test_build_and_sign_nftoken_mint() {
+        let wallet = wallet();
+        let mut nftoken_mint = NFTokenMint::builder(wallet.classic_address.as_str(), 0)
+            .transfer_fee(314)
+            .uri("697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C67747179353566627A6469")
+            .flag(NFTokenMintFlag::TfTransferable)
+            .fee("10")
+            .sequence(2)
+            .build()
+            .unwrap();
+
+        assert!(nftoken_mint.has_flag(&NFTokenMintFlag::TfTransferable));
+        assert_eq!(nftoken_mint.transfer_fee, Some(314));
+        sign(&mut nftoken_mint, &wallet, false).unwrap();
+        assert!(nftoken_mint.is_valid());
+        assert!(nftoken_mint.get_common_fields().is_signed());
+    }
+
+    #[test]
+    fn test_build_rejects_invalid_transactions() {
+        assert!(
+            NFTokenMint::builder("rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys", 0)
+                .transfer_fee(50001)
+                .build()
+                .is_err()
+        );
+
+        // A broker fee needs an offer to broker.
+        let error = NFTokenAcceptOffer::builder("rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys")
+            .nftoken_broker_fee("100")
+            .build()
+            .unwrap_err();
+        assert!(error.to_string().contains("nftoken_sell_offer"));
+    }
+}
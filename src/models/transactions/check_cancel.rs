@@ -5,11 +5,11 @@ use serde_with::skip_serializing_none;
 
 use crate::models::amount::XRPAmount;
 use crate::models::transactions::CommonFields;
+use crate::models::NoFlags;
 use crate::models::{
     transactions::{Transaction, TransactionType},
-    Model,
+    Model, XRPLModelResult,
 };
-use crate::models::{FlagCollection, NoFlags};
 
 use super::{Memo, Signer};
 
@@ -22,6 +22,7 @@ use super::{Memo, Signer};
 /// `<https://xrpl.org/checkcancel.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct CheckCancel<'a> {
     /// The base fields for all transaction models.
@@ -39,7 +40,11 @@ pub struct CheckCancel<'a> {
     pub check_id: Cow<'a, str>,
 }
 
-impl<'a> Model for CheckCancel<'a> {}
+impl<'a> Model for CheckCancel<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for CheckCancel<'a> {
     fn get_transaction_type(&self) -> TransactionType {
@@ -70,20 +75,15 @@ impl<'a> CheckCancel<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::CheckCancel,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::CheckCancel)
             },
             check_id,
         }
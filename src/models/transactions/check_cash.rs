@@ -4,13 +4,13 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::amount::XRPAmount;
-use crate::models::transactions::CommonFields;
+use crate::models::transactions::{get_negative_amount_error, CommonFields};
 use crate::models::{
     amount::Amount,
     transactions::{Memo, Signer, Transaction, TransactionType},
     Model,
 };
-use crate::models::{FlagCollection, NoFlags, XRPLModelException, XRPLModelResult};
+use crate::models::{NoFlags, XRPLModelException, XRPLModelResult};
 
 /// Cancels an unredeemed Check, removing it from the ledger without
 /// sending any money. The source or the destination of the check can
@@ -21,6 +21,7 @@ use crate::models::{FlagCollection, NoFlags, XRPLModelException, XRPLModelResult
 /// `<https://xrpl.org/checkcash.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct CheckCash<'a> {
     /// The base fields for all transaction models.
@@ -45,9 +46,12 @@ pub struct CheckCash<'a> {
     pub deliver_min: Option<Amount<'a>>,
 }
 
-impl<'a: 'static> Model for CheckCash<'a> {
+impl<'a> Model for CheckCash<'a> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         self._get_amount_and_deliver_min_error()?;
+        get_negative_amount_error("amount", self.amount.as_ref())?;
+        get_negative_amount_error("deliver_min", self.deliver_min.as_ref())?;
 
         Ok(())
     }
@@ -99,20 +103,15 @@ impl<'a> CheckCash<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::CheckCash,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::CheckCash)
             },
             check_id,
             amount,
@@ -5,15 +5,16 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::amount::XRPAmount;
+use crate::models::ripple_time::ripple_time_accessors;
 use crate::models::transactions::CommonFields;
+use crate::models::NoFlags;
 use crate::models::{
     amount::Amount,
     transactions::{Transaction, TransactionType},
-    Model,
+    Model, XRPLModelResult,
 };
-use crate::models::{FlagCollection, NoFlags};
 
-use super::{Memo, Signer};
+use super::{builder::transaction_builder, get_negative_amount_error, Memo, Signer};
 
 /// Create a Check object in the ledger, which is a deferred
 /// payment that can be cashed by its intended destination.
@@ -22,6 +23,7 @@ use super::{Memo, Signer};
 /// `<https://xrpl.org/checkcreate.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct CheckCreate<'a> {
     /// The base fields for all transaction models.
@@ -50,7 +52,14 @@ pub struct CheckCreate<'a> {
     pub invoice_id: Option<Cow<'a, str>>,
 }
 
-impl<'a> Model for CheckCreate<'a> {}
+impl<'a> Model for CheckCreate<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
+        get_negative_amount_error("send_max", Some(&self.send_max))?;
+
+        Ok(())
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for CheckCreate<'a> {
     fn get_transaction_type(&self) -> TransactionType {
@@ -85,20 +94,15 @@ impl<'a> CheckCreate<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::CheckCreate,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::CheckCreate)
             },
             destination,
             send_max,
@@ -109,6 +113,18 @@ impl<'a> CheckCreate<'a> {
     }
 }
 
+ripple_time_accessors!(CheckCreate {
+    expiration => expiration_datetime, set_expiration_datetime;
+});
+
+transaction_builder! {
+    CheckCreate<NoFlags> => CheckCreateBuilder {
+        required destination: Cow<'a, str>, send_max: Amount<'a>;
+        into invoice_id: Cow<'a, str>;
+        values destination_tag: u32, expiration: u32;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
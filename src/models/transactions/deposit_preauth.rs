@@ -9,7 +9,7 @@ use crate::models::{
     transactions::{Memo, Signer, Transaction, TransactionType},
     Model,
 };
-use crate::models::{FlagCollection, NoFlags, XRPLModelException, XRPLModelResult};
+use crate::models::{NoFlags, XRPLModelException, XRPLModelResult};
 
 /// A DepositPreauth transaction gives another account pre-approval
 /// to deliver payments to the sender of this transaction.
@@ -18,6 +18,7 @@ use crate::models::{FlagCollection, NoFlags, XRPLModelException, XRPLModelResult
 /// `<https://xrpl.org/depositpreauth.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct DepositPreauth<'a> {
     /// The base fields for all transaction models.
@@ -36,8 +37,9 @@ pub struct DepositPreauth<'a> {
     pub unauthorize: Option<Cow<'a, str>>,
 }
 
-impl<'a: 'static> Model for DepositPreauth<'a> {
+impl<'a> Model for DepositPreauth<'a> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         self._get_authorize_and_unauthorize_error()?;
 
         Ok(())
@@ -89,20 +91,15 @@ impl<'a> DepositPreauth<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::DepositPreauth,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::DepositPreauth)
             },
             authorize,
             unauthorize,
@@ -6,11 +6,11 @@ use serde_with::skip_serializing_none;
 
 use crate::models::amount::XRPAmount;
 use crate::models::transactions::CommonFields;
+use crate::models::NoFlags;
 use crate::models::{
     transactions::{Transaction, TransactionType},
-    Model,
+    Model, XRPLModelResult,
 };
-use crate::models::{FlagCollection, NoFlags};
 
 use super::{Memo, Signer};
 
@@ -20,6 +20,7 @@ use super::{Memo, Signer};
 /// `<https://xrpl.org/escrowcancel.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct EscrowCancel<'a> {
     /// The base fields for all transaction models.
@@ -38,7 +39,11 @@ pub struct EscrowCancel<'a> {
     pub offer_sequence: u32,
 }
 
-impl<'a> Model for EscrowCancel<'a> {}
+impl<'a> Model for EscrowCancel<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for EscrowCancel<'a> {
     fn get_transaction_type(&self) -> TransactionType {
@@ -70,20 +75,15 @@ impl<'a> EscrowCancel<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::EscrowCancel,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::EscrowCancel)
             },
             owner,
             offer_sequence,
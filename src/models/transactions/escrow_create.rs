@@ -4,12 +4,15 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::amount::XRPAmount;
+use crate::models::ripple_time::ripple_time_accessors;
 use crate::models::transactions::CommonFields;
 use crate::models::{
     transactions::{Memo, Signer, Transaction, TransactionType},
     Model,
 };
-use crate::models::{FlagCollection, NoFlags, XRPLModelException, XRPLModelResult};
+use crate::models::{NoFlags, XRPLModelException, XRPLModelResult};
+
+use super::builder::transaction_builder;
 
 /// Creates an Escrow, which requests XRP until the escrow process either finishes or is canceled.
 ///
@@ -17,6 +20,7 @@ use crate::models::{FlagCollection, NoFlags, XRPLModelException, XRPLModelResult
 /// `<https://xrpl.org/escrowcreate.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct EscrowCreate<'a> {
     /// The base fields for all transaction models.
@@ -54,8 +58,12 @@ pub struct EscrowCreate<'a> {
     pub condition: Option<Cow<'a, str>>,
 }
 
-impl<'a: 'static> Model for EscrowCreate<'a> {
+impl<'a> Model for EscrowCreate<'a> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
+        if self.amount.is_negative() {
+            return Err(XRPLModelException::ValueNegative("amount".into()));
+        }
         self._get_finish_after_error()?;
 
         Ok(())
@@ -115,20 +123,15 @@ impl<'a> EscrowCreate<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::EscrowCreate,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::EscrowCreate)
             },
             amount,
             destination,
@@ -144,6 +147,19 @@ pub trait EscrowCreateError {
     fn _get_finish_after_error(&self) -> XRPLModelResult<()>;
 }
 
+ripple_time_accessors!(EscrowCreate {
+    cancel_after => cancel_after_datetime, set_cancel_after_datetime;
+    finish_after => finish_after_datetime, set_finish_after_datetime;
+});
+
+transaction_builder! {
+    EscrowCreate<NoFlags> => EscrowCreateBuilder {
+        required amount: XRPAmount<'a>, destination: Cow<'a, str>;
+        into condition: Cow<'a, str>;
+        values cancel_after: u32, destination_tag: u32, finish_after: u32;
+    }
+}
+
 #[cfg(test)]
 mod test_escrow_create_errors {
     use crate::models::Model;
@@ -219,3 +235,66 @@ mod tests {
         assert_eq!(default_txn, deserialized);
     }
 }
+
+#[cfg(test)]
+mod test_ripple_time_accessors {
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+
+    use super::*;
+
+    fn escrow_create() -> EscrowCreate<'static> {
+        EscrowCreate::new(
+            "r4jQDHCUvgcBAa5EzcB1D8BHGcjYP9eBC2".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            XRPAmount::from("100000000"),
+            "rKSJdNqtEKD4DFrYeRLdCGgamCR8KxD8Rq".into(),
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_ripple_epoch() {
+        let mut escrow_create = escrow_create();
+        assert!(escrow_create.finish_after_datetime().is_none());
+
+        let ripple_epoch = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+        escrow_create
+            .set_finish_after_datetime(ripple_epoch)
+            .unwrap();
+        assert_eq!(escrow_create.finish_after, Some(0));
+        assert_eq!(escrow_create.finish_after_datetime(), Some(ripple_epoch));
+
+        let before_ripple_epoch = ripple_epoch - Duration::seconds(1);
+        assert!(escrow_create
+            .set_cancel_after_datetime(before_ripple_epoch)
+            .is_err());
+        assert!(escrow_create.cancel_after.is_none());
+    }
+
+    #[test]
+    fn test_max_ripple_time() {
+        let mut escrow_create = escrow_create();
+        let max_ripple_time: DateTime<Utc> = Utc.with_ymd_and_hms(2136, 2, 7, 6, 28, 15).unwrap();
+        escrow_create
+            .set_cancel_after_datetime(max_ripple_time)
+            .unwrap();
+        assert_eq!(escrow_create.cancel_after, Some(u32::MAX));
+        assert_eq!(escrow_create.cancel_after_datetime(), Some(max_ripple_time));
+
+        // 2^32 would wrap around to the Ripple Epoch.
+        let result =
+            escrow_create.set_cancel_after_datetime(max_ripple_time + Duration::seconds(1));
+        assert!(matches!(result, Err(XRPLModelException::XRPLUtilsError(_))));
+        assert_eq!(escrow_create.cancel_after, Some(u32::MAX));
+    }
+}
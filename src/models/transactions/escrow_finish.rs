@@ -3,14 +3,14 @@ use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::models::NoFlags;
 use crate::models::{
     amount::XRPAmount,
     transactions::{Memo, Signer, Transaction, TransactionType},
     Model, XRPLModelException, XRPLModelResult,
 };
-use crate::models::{FlagCollection, NoFlags};
 
-use super::CommonFields;
+use super::{builder::transaction_builder, CommonFields};
 
 /// Finishes an Escrow and delivers XRP from a held payment to the recipient.
 ///
@@ -18,6 +18,7 @@ use super::CommonFields;
 /// `<https://xrpl.org/escrowfinish.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct EscrowFinish<'a> {
     // The base fields for all transaction models.
@@ -44,8 +45,9 @@ pub struct EscrowFinish<'a> {
     pub fulfillment: Option<Cow<'a, str>>,
 }
 
-impl<'a: 'static> Model for EscrowFinish<'a> {
+impl<'a> Model for EscrowFinish<'a> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         self._get_condition_and_fulfillment_error()?;
 
         Ok(())
@@ -99,20 +101,15 @@ impl<'a> EscrowFinish<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::EscrowFinish,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::EscrowFinish)
             },
             owner,
             offer_sequence,
@@ -126,6 +123,13 @@ pub trait EscrowFinishError {
     fn _get_condition_and_fulfillment_error(&self) -> XRPLModelResult<()>;
 }
 
+transaction_builder! {
+    EscrowFinish<NoFlags> => EscrowFinishBuilder {
+        required owner: Cow<'a, str>, offer_sequence: u32;
+        into condition: Cow<'a, str>, fulfillment: Cow<'a, str>;
+    }
+}
+
 #[cfg(test)]
 mod test_escrow_finish_errors {
 
@@ -7,6 +7,10 @@ use thiserror_no_std::Error;
 
 #[derive(Debug, PartialEq, Error)]
 pub enum XRPLTransactionException {
+    #[error("{0}")]
+    XRPLCommonFieldsError(#[from] XRPLCommonFieldsException),
+    #[error("{0}")]
+    XRPLAccountDeleteError(#[from] XRPLAccountDeleteException),
     #[error("{0}")]
     XRPLAccountSetError(#[from] XRPLAccountSetException),
     #[error("{0}")]
@@ -27,10 +31,15 @@ pub enum XRPLTransactionException {
     XRPLXChainModifyBridgeError(#[from] XRPLXChainModifyBridgeException),
     #[error("{0}")]
     XRPLAMMCreateError(#[from] XRPLAMMCreateException),
+    #[cfg(feature = "xahau")]
+    #[error("{0}")]
+    XRPLSetHookError(#[from] XRPLSetHookException),
     #[error("{0}")]
     XRPLCoreError(#[from] XRPLCoreException),
     #[error("The transaction must be signed")]
     TxMustBeSigned,
+    #[error("Expected a {expected} transaction, found {found:?}")]
+    UnexpectedTransactionType { expected: String, found: String },
 }
 
 #[cfg(feature = "std")]
@@ -42,8 +51,50 @@ pub enum XRPLTransactionFieldException {
     InvalidCommonField(String),
     #[error("There is no account field named `{0:?}`")]
     UnknownAccountField(String),
+    #[error("{0:?} value does not match X-Address tag")]
+    TagFieldMismatch(String),
+}
+
+/// A problem with the fields every transaction has.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum XRPLCommonFieldsException {
+    /// The fee is not a whole, non-negative number of drops.
+    #[error("The fee must be a whole, non-negative number of drops (found {0:?})")]
+    InvalidFee(String),
+    /// The fee is more than all XRP in existence.
+    #[error("The fee exceeds all XRP in existence (max {max:?} drops, found {found:?})")]
+    FeeTooHigh { max: u64, found: u64 },
+    /// A transaction using a ticket has a sequence other than 0.
+    #[error("The field `sequence` must be 0 or omitted when using a ticket (found {0:?})")]
+    SequenceWithTicket(u32),
+    /// A transaction using a ticket also depends on the account's
+    /// previous transaction.
+    #[error("The field `account_txn_id` cannot be defined when using a ticket")]
+    AccountTxnIdWithTicket,
+    /// More signers than a signer list can have.
+    #[error("A transaction can have at most {max:?} signers (found {found:?})")]
+    TooManySigners { max: usize, found: usize },
 }
 
+#[cfg(feature = "std")]
+impl alloc::error::Error for XRPLCommonFieldsException {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum XRPLAccountDeleteException {
+    /// The destination is an X-address with a tag other than the
+    /// `destination_tag`.
+    #[error("The tag of the destination X-address ({xaddress_tag:?}) does not match the field `destination_tag` ({destination_tag:?})")]
+    DestinationTagMismatch {
+        xaddress_tag: u32,
+        destination_tag: u32,
+    },
+}
+
+#[cfg(feature = "std")]
+impl alloc::error::Error for XRPLAccountDeleteException {}
+
 #[derive(Debug, PartialEq, Error)]
 pub enum XRPLAccountSetException {
     /// A field can only be defined if a transaction flag is set.
@@ -106,6 +157,9 @@ pub enum XRPLPaymentException {
     /// An account set flag can only be set if a field is defined.
     #[error("For the flag `{flag:?}` to be set it is required to define the field `{field:?}`")]
     FlagRequiresField { flag: PaymentFlag, field: String },
+    /// A field can only be defined if a transaction flag is set.
+    #[error("For the field `{field:?}` to be defined it is required to set the flag `{flag:?}`")]
+    FieldRequiresFlag { field: String, flag: PaymentFlag },
 }
 
 #[cfg(feature = "std")]
@@ -146,7 +200,7 @@ pub enum XRPLSignerListSetException {
     /// A collection contains an invalid value.
     #[error("The field `{field:?}` contains an invalid value (found {found:?})")]
     CollectionInvalidItem { field: String, found: String },
-    #[error("The field `signer_quorum` must be below or equal to the sum of `signer_weight` in `signer_entries`")]
+    #[error("The field `signer_quorum` must be below or equal to the sum of `signer_weight` in `signer_entries` (max {max:?}, found {found:?})")]
     SignerQuorumExceedsSignerWeight { max: u32, found: u32 },
 }
 
@@ -214,3 +268,14 @@ pub enum XRPLAMMCreateException {
 
 #[cfg(feature = "std")]
 impl alloc::error::Error for XRPLAMMCreateException {}
+
+#[cfg(feature = "xahau")]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum XRPLSetHookException {
+    #[error("A SetHook transaction must have between 1 and {max:?} Hooks (found {found:?})")]
+    HookCountOutOfRange { max: usize, found: usize },
+}
+
+#[cfg(all(feature = "xahau", feature = "std"))]
+impl alloc::error::Error for XRPLSetHookException {}
@@ -0,0 +1,353 @@
+use alloc::{borrow::Cow, vec::Vec};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use serde_with::skip_serializing_none;
+
+use crate::models::{
+    ledger::objects::{LedgerEntry, LedgerEntryType},
+    Amount,
+};
+
+/// The `delivered_amount` of transactions from before 2014-01-20,
+/// when the amount delivered was not recorded.
+pub const DELIVERED_AMOUNT_UNAVAILABLE: &str = "unavailable";
+
+/// A ledger entry created, modified or deleted by a transaction.
+/// The fields of the entry are left as JSON, since they depend on
+/// its type; [`AffectedNode::ledger_entry`] reads them into the
+/// entry's model.
+///
+/// See Transaction Metadata:
+/// `<https://xrpl.org/transaction-metadata.html#affectednodes>`
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum AffectedNode<'a> {
+    #[serde(rename_all = "PascalCase")]
+    CreatedNode {
+        ledger_entry_type: Cow<'a, str>,
+        ledger_index: Cow<'a, str>,
+        new_fields: Value,
+    },
+    #[serde(rename_all = "PascalCase")]
+    ModifiedNode {
+        ledger_entry_type: Cow<'a, str>,
+        ledger_index: Cow<'a, str>,
+        final_fields: Option<Value>,
+        previous_fields: Option<Value>,
+        #[serde(rename = "PreviousTxnID")]
+        previous_txn_id: Option<Cow<'a, str>>,
+        previous_txn_lgr_seq: Option<u32>,
+    },
+    #[serde(rename_all = "PascalCase")]
+    DeletedNode {
+        ledger_entry_type: Cow<'a, str>,
+        ledger_index: Cow<'a, str>,
+        final_fields: Value,
+        previous_fields: Option<Value>,
+    },
+}
+
+impl<'a> AffectedNode<'a> {
+    pub fn ledger_entry_type(&self) -> &str {
+        match self {
+            AffectedNode::CreatedNode {
+                ledger_entry_type, ..
+            }
+            | AffectedNode::ModifiedNode {
+                ledger_entry_type, ..
+            }
+            | AffectedNode::DeletedNode {
+                ledger_entry_type, ..
+            } => ledger_entry_type,
+        }
+    }
+
+    pub fn ledger_index(&self) -> &str {
+        match self {
+            AffectedNode::CreatedNode { ledger_index, .. }
+            | AffectedNode::ModifiedNode { ledger_index, .. }
+            | AffectedNode::DeletedNode { ledger_index, .. } => ledger_index,
+        }
+    }
+
+    /// The type of the entry, if this library has a model for it.
+    pub fn get_ledger_entry_type(&self) -> Option<LedgerEntryType> {
+        serde_json::from_value(Value::from(self.ledger_entry_type())).ok()
+    }
+
+    /// The entry as the transaction left it: its `NewFields` if it
+    /// was created, its `FinalFields` otherwise. For a deleted entry,
+    /// that is its state right before it was deleted.
+    ///
+    /// The metadata omits fields with default values, so the entry
+    /// is `LedgerEntry::Other` if a field its model requires is
+    /// missing, like the `PreviousTxnID` of most modified entries.
+    pub fn ledger_entry(&self) -> LedgerEntry<'static> {
+        let fields = match self {
+            AffectedNode::CreatedNode { new_fields, .. } => Some(new_fields),
+            AffectedNode::ModifiedNode { final_fields, .. } => final_fields.as_ref(),
+            AffectedNode::DeletedNode { final_fields, .. } => Some(final_fields),
+        };
+        let mut entry = match fields {
+            Some(Value::Object(fields)) => fields.clone(),
+            _ => Map::new(),
+        };
+        entry.insert(
+            "LedgerEntryType".into(),
+            Value::from(self.ledger_entry_type()),
+        );
+        entry.insert("index".into(), Value::from(self.ledger_index()));
+        entry.entry("Flags").or_insert(Value::from(0));
+
+        let entry = Value::Object(entry);
+
+        LedgerEntry::deserialize(&entry).unwrap_or(LedgerEntry::Other(entry))
+    }
+}
+
+/// The metadata of a transaction: its result and the ledger
+/// entries it changed.
+///
+/// See Transaction Metadata:
+/// `<https://xrpl.org/transaction-metadata.html>`
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "PascalCase")]
+pub struct TransactionMetadata<'a> {
+    pub affected_nodes: Vec<AffectedNode<'a>>,
+    pub transaction_index: u32,
+    pub transaction_result: Cow<'a, str>,
+    /// The amount actually delivered by a Payment, as recorded in
+    /// the ledger. Prefer `delivered_amount`, which the server also
+    /// fills in for transactions from before this field existed.
+    pub delivered_amount: Option<Amount<'a>>,
+    /// The amount actually delivered, added by the server. It is
+    /// `"unavailable"` for transactions from before 2014-01-20.
+    #[serde(rename = "delivered_amount")]
+    pub delivered_amount_synthetic: Option<Amount<'a>>,
+    /// The ID of the NFToken minted or accepted, added by the
+    /// server.
+    #[serde(rename = "nftoken_id")]
+    pub nftoken_id: Option<Cow<'a, str>>,
+    /// The IDs of the NFTokens of the offers cancelled, added by
+    /// the server.
+    #[serde(rename = "nftoken_ids")]
+    pub nftoken_ids: Option<Vec<Cow<'a, str>>>,
+    /// The ID of the offer created, added by the server.
+    #[serde(rename = "offer_id")]
+    pub offer_id: Option<Cow<'a, str>>,
+}
+
+impl<'a> TransactionMetadata<'a> {
+    /// The types of the entries the transaction affected, in the
+    /// order they first appear. Types this library has no model for
+    /// are left out.
+    pub fn affected_node_types(&self) -> Vec<LedgerEntryType> {
+        let mut types = Vec::new();
+        for ledger_entry_type in self
+            .affected_nodes
+            .iter()
+            .filter_map(AffectedNode::get_ledger_entry_type)
+        {
+            if !types.contains(&ledger_entry_type) {
+                types.push(ledger_entry_type);
+            }
+        }
+
+        types
+    }
+
+    /// The entries of the given type the transaction created.
+    pub fn created_objects_of_type(
+        &self,
+        ledger_entry_type: LedgerEntryType,
+    ) -> Vec<LedgerEntry<'static>> {
+        self.affected_nodes
+            .iter()
+            .filter(|node| {
+                matches!(node, AffectedNode::CreatedNode { .. })
+                    && node.get_ledger_entry_type().as_ref() == Some(&ledger_entry_type)
+            })
+            .map(AffectedNode::ledger_entry)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escrow_finish_metadata() {
+        let meta: TransactionMetadata = serde_json::from_value(serde_json::json!({
+            "AffectedNodes": [
+                {
+                    "ModifiedNode": {
+                        "FinalFields": {
+                            "Account": "rKDvgGUsNPZxsgmoemfrgXPS2Not4co2op",
+                            "Balance": "400000000",
+                            "Flags": 0,
+                            "OwnerCount": 0,
+                            "Sequence": 8
+                        },
+                        "LedgerEntryType": "AccountRoot",
+                        "LedgerIndex": "13F1A95D7AAB7108D5CE7EEAF504B2894B8C674E6D68499076441C4837282BF8",
+                        "PreviousFields": {
+                            "Balance": "399999988",
+                            "OwnerCount": 1
+                        },
+                        "PreviousTxnID": "3BEA1E1C8B4CA4D3D4FCCBB0BA41C17A75BD9346D2E6B0EE8B1A9FB3A38C9A3D",
+                        "PreviousTxnLgrSeq": 28991004
+                    }
+                },
+                {
+                    "ModifiedNode": {
+                        "FinalFields": {
+                            "Flags": 0,
+                            "Indexes": [],
+                            "Owner": "rKDvgGUsNPZxsgmoemfrgXPS2Not4co2op",
+                            "RootIndex": "4B6C4E7E8F7D6BDA27EC1B5A3D6DC4D6AA1DF0C8C2B6E6B1C4F79C1C5A83C05B"
+                        },
+                        "LedgerEntryType": "DirectoryNode",
+                        "LedgerIndex": "4B6C4E7E8F7D6BDA27EC1B5A3D6DC4D6AA1DF0C8C2B6E6B1C4F79C1C5A83C05B"
+                    }
+                },
+                {
+                    "DeletedNode": {
+                        "FinalFields": {
+                            "Account": "rKDvgGUsNPZxsgmoemfrgXPS2Not4co2op",
+                            "Amount": "10000",
+                            "Destination": "rKDvgGUsNPZxsgmoemfrgXPS2Not4co2op",
+                            "FinishAfter": 558396600,
+                            "Flags": 0,
+                            "OwnerNode": "0000000000000000",
+                            "PreviousTxnID": "3BEA1E1C8B4CA4D3D4FCCBB0BA41C17A75BD9346D2E6B0EE8B1A9FB3A38C9A3D",
+                            "PreviousTxnLgrSeq": 28991004
+                        },
+                        "LedgerEntryType": "Escrow",
+                        "LedgerIndex": "DC5F3851D8A1AB622F957761E5963BC5BD439D5C24AC6AD7AC4523F0640244AC"
+                    }
+                }
+            ],
+            "TransactionIndex": 3,
+            "TransactionResult": "tesSUCCESS"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            meta.affected_node_types(),
+            [
+                LedgerEntryType::AccountRoot,
+                LedgerEntryType::DirectoryNode,
+                LedgerEntryType::Escrow
+            ]
+        );
+        assert!(meta
+            .created_objects_of_type(LedgerEntryType::Escrow)
+            .is_empty());
+        // The metadata leaves the `PreviousTxnID` of a modified
+        // AccountRoot out of its fields.
+        assert!(matches!(
+            meta.affected_nodes[0].ledger_entry(),
+            LedgerEntry::Other(_)
+        ));
+        let LedgerEntry::DirectoryNode(directory) = meta.affected_nodes[1].ledger_entry() else {
+            panic!("expected a DirectoryNode");
+        };
+        assert!(directory.indexes.is_empty());
+        let LedgerEntry::Escrow(escrow) = meta.affected_nodes[2].ledger_entry() else {
+            panic!("expected an Escrow");
+        };
+        assert_eq!(escrow.amount, Amount::XRPAmount("10000".into()));
+        assert_eq!(escrow.finish_after, Some(558396600));
+        assert_eq!(
+            escrow.common_fields.index.as_deref(),
+            Some("DC5F3851D8A1AB622F957761E5963BC5BD439D5C24AC6AD7AC4523F0640244AC")
+        );
+    }
+
+    #[test]
+    fn test_nftoken_mint_metadata() {
+        let meta: TransactionMetadata = serde_json::from_value(serde_json::json!({
+            "AffectedNodes": [
+                {
+                    "ModifiedNode": {
+                        "FinalFields": {
+                            "Account": "rJ6JCUuA6GDbVyNXKBmvLCKrRjm6PFDxBr",
+                            "Balance": "99999988",
+                            "Flags": 0,
+                            "MintedNFTokens": 1,
+                            "OwnerCount": 1,
+                            "Sequence": 2
+                        },
+                        "LedgerEntryType": "AccountRoot",
+                        "LedgerIndex": "1ED8DDFD80F275CB1CE7F18BB9D906655DE8029805D8B95FB9020B30425821EB",
+                        "PreviousFields": {
+                            "Balance": "100000000",
+                            "OwnerCount": 0,
+                            "Sequence": 1
+                        },
+                        "PreviousTxnID": "8F0A2E1C4D9ABB7D2F2B7B19B0A4C0E3B6C5E1B2F1C6BC0F0C3C5C8A0E9E0D1A",
+                        "PreviousTxnLgrSeq": 46
+                    }
+                },
+                {
+                    "CreatedNode": {
+                        "LedgerEntryType": "NFTokenPage",
+                        "LedgerIndex": "C1C35BC0A1C4E89D1B1E14D6CFE5B8F97DE4C9B3FFFFFFFFFFFFFFFFFFFFFFFF",
+                        "NewFields": {
+                            "NFTokens": [
+                                {
+                                    "NFToken": {
+                                        "NFTokenID": "00080000C1C35BC0A1C4E89D1B1E14D6CFE5B8F97DE4C9B30000099B00000000",
+                                        "URI": "697066733A2F2F62616679"
+                                    }
+                                }
+                            ]
+                        }
+                    }
+                }
+            ],
+            "TransactionIndex": 0,
+            "TransactionResult": "tesSUCCESS",
+            "nftoken_id": "00080000C1C35BC0A1C4E89D1B1E14D6CFE5B8F97DE4C9B30000099B00000000"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            meta.affected_node_types(),
+            [LedgerEntryType::AccountRoot, LedgerEntryType::NFTokenPage]
+        );
+        let pages = meta.created_objects_of_type(LedgerEntryType::NFTokenPage);
+        let [LedgerEntry::NFTokenPage(page)] = pages.as_slice() else {
+            panic!("expected an NFTokenPage");
+        };
+        assert_eq!(Some(&page.nftokens[0].nftoken_id), meta.nftoken_id.as_ref());
+        assert_eq!(
+            page.nftokens[0].uri.as_deref(),
+            Some("697066733A2F2F62616679")
+        );
+    }
+
+    #[test]
+    fn test_unknown_entry_type() {
+        let node: AffectedNode = serde_json::from_value(serde_json::json!({
+            "CreatedNode": {
+                "LedgerEntryType": "Oracle",
+                "LedgerIndex": "7458B6FD22827B3C141CDC88F1F0C72658C9B5D2E40961E45AF6CD31DECC0C29",
+                "NewFields": {
+                    "Owner": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(node.get_ledger_entry_type(), None);
+        let LedgerEntry::Other(fields) = node.ledger_entry() else {
+            panic!("expected an unknown entry");
+        };
+        assert_eq!(fields["Owner"], "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn");
+    }
+}
@@ -6,6 +6,7 @@ pub mod amm_delete;
 pub mod amm_deposit;
 pub mod amm_vote;
 pub mod amm_withdraw;
+mod builder;
 pub mod check_cancel;
 pub mod check_cash;
 pub mod check_create;
@@ -14,6 +15,7 @@ pub mod escrow_cancel;
 pub mod escrow_create;
 pub mod escrow_finish;
 pub mod exceptions;
+pub mod metadata;
 pub mod nftoken_accept_offer;
 pub mod nftoken_burn;
 pub mod nftoken_cancel_offer;
@@ -26,6 +28,8 @@ pub mod payment_channel_claim;
 pub mod payment_channel_create;
 pub mod payment_channel_fund;
 pub mod pseudo_transactions;
+#[cfg(feature = "xahau")]
+pub mod set_hook;
 pub mod set_regular_key;
 pub mod signer_list_set;
 pub mod ticket_create;
@@ -38,30 +42,37 @@ pub mod xchain_commit;
 pub mod xchain_create_bridge;
 pub mod xchain_create_claim_id;
 pub mod xchain_modify_bridge;
+mod xrpl_transaction;
 
-use super::{FlagCollection, XRPLModelResult};
+use super::{
+    describe::describe_transaction, FlagCollection, Model, XRPLModelException, XRPLModelResult,
+};
+use crate::constants::MAX_SIGNER_ENTRIES;
 use crate::core::binarycodec::encode;
-use crate::models::amount::XRPAmount;
+use crate::core::ledger::hash_signed_tx;
+use crate::models::amount::{Amount, XRPAmount};
+use crate::utils::MAX_DROPS;
 use crate::{_serde::txn_flags, serde_with_tag};
 use alloc::borrow::Cow;
-use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::fmt::Debug;
 use derive_new::new;
-use exceptions::XRPLTransactionException;
+use exceptions::{XRPLCommonFieldsException, XRPLTransactionException};
 use serde::de::DeserializeOwned;
 use serde::ser::SerializeMap;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
-use sha2::{Digest, Sha512};
 use strum::IntoEnumIterator;
-use strum_macros::{AsRefStr, Display};
+use strum_macros::{AsRefStr, Display, EnumIter};
 
-const TRANSACTION_HASH_PREFIX: u32 = 0x54584E00;
+pub use builder::CommonFieldsBuilder;
+pub use xrpl_transaction::{UntypedCommonFields, XRPLTransaction};
 
 /// Enum containing the different Transaction types.
-#[derive(Debug, Clone, Serialize, Deserialize, Display, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, Display, PartialEq, Eq, EnumIter)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 pub enum TransactionType {
     AccountDelete,
     AccountSet,
@@ -89,6 +100,8 @@ pub enum TransactionType {
     PaymentChannelClaim,
     PaymentChannelCreate,
     PaymentChannelFund,
+    #[cfg(feature = "xahau")]
+    SetHook,
     SetRegularKey,
     SignerListSet,
     TicketCreate,
@@ -107,6 +120,20 @@ pub enum TransactionType {
     UNLModify,
 }
 
+impl TransactionType {
+    /// Whether this is a pseudo-transaction: one validators apply to
+    /// a ledger, rather than one an account signs and submits.
+    ///
+    /// See Pseudo-Transactions:
+    /// `<https://xrpl.org/pseudo-transaction-types.html>`
+    pub fn is_pseudo_transaction(&self) -> bool {
+        matches!(
+            self,
+            TransactionType::EnableAmendment | TransactionType::SetFee | TransactionType::UNLModify
+        )
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, new)]
 #[serde(rename_all = "PascalCase")]
 pub struct PreparedTransaction<'a, T> {
@@ -170,6 +197,7 @@ where
     /// The network ID of the chain this transaction is intended for.
     /// MUST BE OMITTED for Mainnet and some test networks.
     /// REQUIRED on chains whose network ID is 1025 or higher.
+    #[serde(rename = "NetworkID")]
     pub network_id: Option<u32>,
     /// The sequence number of the account sending the transaction.
     /// A transaction is only valid if the Sequence number is exactly
@@ -236,6 +264,30 @@ where
             txn_signature,
         }
     }
+
+    /// The common fields of a `transaction_type` transaction sent by
+    /// `account`, with no flags and every optional field unset.
+    ///
+    /// Models build their common fields on top of it, so that a new
+    /// common field does not have to be added to each of them.
+    pub fn with_account(account: Cow<'a, str>, transaction_type: TransactionType) -> Self {
+        CommonFields {
+            account,
+            transaction_type,
+            account_txn_id: None,
+            fee: None,
+            flags: FlagCollection::default(),
+            last_ledger_sequence: None,
+            memos: None,
+            network_id: None,
+            sequence: None,
+            signers: None,
+            signing_pub_key: None,
+            source_tag: None,
+            ticket_sequence: None,
+            txn_signature: None,
+        }
+    }
 }
 
 impl<T> CommonFields<'_, T>
@@ -244,21 +296,88 @@ where
 {
     pub fn is_signed(&self) -> bool {
         if let Some(signers) = &self.signers {
-            signers
-                .iter()
-                .all(|signer| signer.txn_signature.len() > 0 && signer.signing_pub_key.len() > 0)
+            signers.iter().all(|signer| {
+                !signer.txn_signature.is_empty() && !signer.signing_pub_key.is_empty()
+            })
         } else {
             self.txn_signature.is_some() && self.signing_pub_key.is_some()
         }
     }
 }
 
+impl<T> Model for CommonFields<'_, T>
+where
+    T: IntoEnumIterator + Serialize + Debug,
+{
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self._get_fee_error()?;
+        self._get_ticket_error()?;
+        self._get_signers_error()?;
+        for memo in self.memos.iter().flatten() {
+            memo.get_errors()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> CommonFields<'_, T>
+where
+    T: IntoEnumIterator + Serialize + Debug,
+{
+    fn _get_fee_error(&self) -> XRPLModelResult<()> {
+        let Some(fee) = &self.fee else {
+            return Ok(());
+        };
+        let drops = fee
+            .0
+            .parse::<u64>()
+            .map_err(|_| XRPLCommonFieldsException::InvalidFee(fee.0.to_string()))?;
+        if drops > MAX_DROPS {
+            return Err(XRPLCommonFieldsException::FeeTooHigh {
+                max: MAX_DROPS,
+                found: drops,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    fn _get_ticket_error(&self) -> XRPLModelResult<()> {
+        if self.ticket_sequence.is_none() {
+            return Ok(());
+        }
+        if let Some(sequence) = self.sequence.filter(|sequence| *sequence != 0) {
+            return Err(XRPLCommonFieldsException::SequenceWithTicket(sequence).into());
+        }
+        if self.account_txn_id.is_some() {
+            return Err(XRPLCommonFieldsException::AccountTxnIdWithTicket.into());
+        }
+
+        Ok(())
+    }
+
+    fn _get_signers_error(&self) -> XRPLModelResult<()> {
+        match &self.signers {
+            Some(signers) if signers.len() > MAX_SIGNER_ENTRIES => {
+                Err(XRPLCommonFieldsException::TooManySigners {
+                    max: MAX_SIGNER_ENTRIES,
+                    found: signers.len(),
+                }
+                .into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 impl<'a, T> Transaction<'a, T> for CommonFields<'a, T>
 where
     T: IntoEnumIterator + Serialize + PartialEq + core::fmt::Debug,
 {
     fn has_flag(&self, flag: &T) -> bool {
-        self.flags.0.contains(flag)
+        self.flags.contains(flag)
     }
 
     fn get_transaction_type(&self) -> TransactionType {
@@ -274,6 +393,33 @@ where
     }
 }
 
+/// The same fields as [`UntypedCommonFields`], whose `Flags` are the
+/// plain number they are on the wire, with the `TransactionType`
+/// restricted to the known types.
+#[cfg(feature = "json-schema")]
+impl<F> schemars::JsonSchema for CommonFields<'_, F>
+where
+    F: IntoEnumIterator + Serialize + core::fmt::Debug,
+{
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "CommonFields".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        let mut schema = UntypedCommonFields::json_schema(generator);
+        let transaction_type = generator.subschema_for::<TransactionType>();
+        if let Some(Value::Object(properties)) = schema.get_mut("properties") {
+            properties.insert("TransactionType".into(), transaction_type.to_value());
+        }
+
+        schema
+    }
+}
+
 fn flag_collection_default<T>() -> FlagCollection<T>
 where
     T: IntoEnumIterator + Serialize + core::fmt::Debug,
@@ -300,6 +446,145 @@ pub struct Memo {
 }
 }
 
+/// The most bytes the fields of a memo can hold together.
+pub const MEMO_MAX_LENGTH: usize = 1024;
+
+impl Memo {
+    /// Build a memo of `data` alone, which must already be hex.
+    ///
+    /// # Examples
+    ///
+    /// ## Basic usage
+    ///
+    /// ```
+    /// use xrpl::models::transactions::Memo;
+    ///
+    /// let memo = Memo::data("68656C6C6F").with_type("74657874");
+    ///
+    /// assert_eq!(memo.memo_data.as_deref(), Some("68656C6C6F"));
+    /// assert_eq!(memo.memo_type.as_deref(), Some("74657874"));
+    /// assert_eq!(Memo::from("68656C6C6F"), Memo::data("68656C6C6F"));
+    /// ```
+    pub fn data(data: impl Into<String>) -> Self {
+        Self {
+            memo_data: Some(data.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Set the memo's format, which must already be hex.
+    pub fn with_format(mut self, format: impl Into<String>) -> Self {
+        self.memo_format = Some(format.into());
+        self
+    }
+
+    /// Set the memo's type, which must already be hex.
+    pub fn with_type(mut self, memo_type: impl Into<String>) -> Self {
+        self.memo_type = Some(memo_type.into());
+        self
+    }
+
+    /// Build a memo from text, hex-encoding it as the ledger expects.
+    ///
+    /// # Examples
+    ///
+    /// ## Basic usage
+    ///
+    /// ```
+    /// use xrpl::models::transactions::Memo;
+    ///
+    /// let memo = Memo::from_text("invoice 42", None, Some("text/plain"));
+    ///
+    /// assert_eq!(memo.memo_data.as_deref(), Some("696E766F696365203432"));
+    /// assert_eq!(memo.memo_data_utf8().as_deref(), Some("invoice 42"));
+    /// ```
+    pub fn from_text(data: &str, format: Option<&str>, memo_type: Option<&str>) -> Self {
+        Self {
+            memo_data: Some(hex::encode_upper(data)),
+            memo_format: format.map(hex::encode_upper),
+            memo_type: memo_type.map(hex::encode_upper),
+        }
+    }
+
+    /// Build a memo from fields that are already hex, failing if
+    /// one is not.
+    pub fn from_hex(
+        data: Option<&str>,
+        format: Option<&str>,
+        memo_type: Option<&str>,
+    ) -> XRPLModelResult<Self> {
+        let memo = Self {
+            memo_data: data.map(Into::into),
+            memo_format: format.map(Into::into),
+            memo_type: memo_type.map(Into::into),
+        };
+        memo.validate()?;
+
+        Ok(memo)
+    }
+
+    /// The memo's data as text, if it is hex-encoded UTF-8.
+    pub fn memo_data_utf8(&self) -> Option<String> {
+        let bytes = hex::decode(self.memo_data.as_ref()?).ok()?;
+
+        String::from_utf8(bytes).ok()
+    }
+
+    fn _get_hex_error(&self) -> XRPLModelResult<()> {
+        for (field, value) in [
+            ("memo_data", &self.memo_data),
+            ("memo_format", &self.memo_format),
+            ("memo_type", &self.memo_type),
+        ] {
+            if let Some(value) = value {
+                get_hex_error(field, value)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn _get_length_error(&self) -> XRPLModelResult<()> {
+        let length = [&self.memo_data, &self.memo_format, &self.memo_type]
+            .into_iter()
+            .flatten()
+            .map(|value| value.len() / 2)
+            .sum();
+        if length > MEMO_MAX_LENGTH {
+            return Err(XRPLModelException::ValueTooLong {
+                field: "memo".into(),
+                max: MEMO_MAX_LENGTH,
+                found: length,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// A memo of hex `MemoData` alone, see [`Memo::data`].
+impl From<&str> for Memo {
+    fn from(data: &str) -> Self {
+        Memo::data(data)
+    }
+}
+
+/// A memo of hex `MemoData` alone, see [`Memo::data`].
+impl From<String> for Memo {
+    fn from(data: String) -> Self {
+        Memo::data(data)
+    }
+}
+
+impl Model for Memo {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self._get_hex_error()?;
+        self._get_length_error()?;
+
+        Ok(())
+    }
+}
+
 serde_with_tag! {
     /// Represents one entry in a list of AuthAccounts used in AMMBid transaction.
     #[derive(Debug, Clone, PartialEq, Eq, new)]
@@ -308,19 +593,35 @@ serde_with_tag! {
     }
 }
 
+serde_with_tag! {
 /// One Signer in a multi-signature. A multi-signed transaction
 /// can have an array of up to 8 Signers, each contributing a
 /// signature, in the Signers field.
 ///
 /// See Signers Field:
 /// `<https://xrpl.org/transaction-common-fields.html#signers-field>`
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Default, Clone, new)]
-#[serde(rename_all = "PascalCase")]
+// `#[derive(Serialize)]` is defined in the macro
+#[derive(Debug, PartialEq, Eq, Default, Clone)]
 pub struct Signer<'a> {
     pub account: Cow<'a, str>,
     pub txn_signature: Cow<'a, str>,
     pub signing_pub_key: Cow<'a, str>,
 }
+}
+
+impl<'a> Signer<'a> {
+    pub fn new(
+        account: impl Into<Cow<'a, str>>,
+        txn_signature: impl Into<Cow<'a, str>>,
+        signing_pub_key: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            account: account.into(),
+            txn_signature: txn_signature.into(),
+            signing_pub_key: signing_pub_key.into(),
+        }
+    }
+}
 
 /// Standard functions for transactions.
 pub trait Transaction<'a, T>
@@ -329,8 +630,7 @@ where
     T: IntoEnumIterator + Serialize + Debug + PartialEq,
 {
     fn has_flag(&self, flag: &T) -> bool {
-        let _txn_flag = flag;
-        false
+        self.get_common_fields().has_flag(flag)
     }
 
     fn get_transaction_type(&self) -> TransactionType;
@@ -339,10 +639,66 @@ where
 
     fn get_mut_common_fields(&mut self) -> &mut CommonFields<'a, T>;
 
-    fn get_field_value(&self, field: &str) -> XRPLModelResult<Option<String>> {
+    fn get_fee<'b>(&'b self) -> Option<&'b XRPAmount<'b>>
+    where
+        T: 'b,
+    {
+        self.get_common_fields().fee.as_ref()
+    }
+
+    fn set_fee(&mut self, fee: XRPAmount<'a>) {
+        self.get_mut_common_fields().fee = Some(fee);
+    }
+
+    fn get_sequence(&self) -> Option<u32> {
+        self.get_common_fields().sequence
+    }
+
+    fn set_sequence(&mut self, sequence: u32) {
+        self.get_mut_common_fields().sequence = Some(sequence);
+    }
+
+    fn get_ticket_sequence(&self) -> Option<u32> {
+        self.get_common_fields().ticket_sequence
+    }
+
+    fn get_last_ledger_sequence(&self) -> Option<u32> {
+        self.get_common_fields().last_ledger_sequence
+    }
+
+    fn set_last_ledger_sequence(&mut self, last_ledger_sequence: u32) {
+        self.get_mut_common_fields().last_ledger_sequence = Some(last_ledger_sequence);
+    }
+
+    fn get_network_id(&self) -> Option<u32> {
+        self.get_common_fields().network_id
+    }
+
+    fn set_network_id(&mut self, network_id: u32) {
+        self.get_mut_common_fields().network_id = Some(network_id);
+    }
+
+    fn set_signing_pub_key(&mut self, signing_pub_key: Cow<'a, str>) {
+        self.get_mut_common_fields().signing_pub_key = Some(signing_pub_key);
+    }
+
+    fn set_txn_signature(&mut self, txn_signature: Cow<'a, str>) {
+        self.get_mut_common_fields().txn_signature = Some(txn_signature);
+    }
+
+    /// Read a field by its `tx_json` name, e.g. `Fulfillment`. This
+    /// serializes the whole transaction, so it is meant for fields
+    /// the typed accessors don't cover.
+    fn get_field_value<R>(&self, field: &str) -> XRPLModelResult<Option<R>>
+    where
+        R: DeserializeOwned,
+    {
         let value = serde_json::to_value(self)?;
 
-        Ok(value.get(field).map(|v| v.to_string()))
+        match value.get(field) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => Ok(Some(R::deserialize(value)?)),
+        }
     }
 
     fn is_signed(&self) -> bool {
@@ -351,33 +707,163 @@ where
     }
 
     /// Hashes the Transaction object as the ledger does. Only valid for signed
-    /// Transaction objects.
-    fn get_hash(&self) -> XRPLModelResult<Cow<str>>
+    /// Transaction objects, or pseudo-transactions, which are never signed.
+    fn get_hash(&self) -> XRPLModelResult<Cow<'_, str>>
     where
         Self: Serialize + DeserializeOwned + Debug + Clone,
     {
         if self.get_common_fields().txn_signature.is_none()
             && self.get_common_fields().signers.is_none()
+            && !self.get_transaction_type().is_pseudo_transaction()
         {
             return Err(XRPLTransactionException::TxMustBeSigned.into());
         }
-        let prefix = format!("{:X}", TRANSACTION_HASH_PREFIX);
         let tx_hex = encode(self).map_err(XRPLTransactionException::XRPLCoreError)?;
-        let tx_hex = prefix + &tx_hex;
-        let tx_bytes = hex::decode(&tx_hex)?;
-        let mut hasher = Sha512::new();
-        hasher.update(&tx_bytes);
-        let hash = hasher.finalize();
-        let hex_string = hex::encode_upper(hash);
-        let result = hex_string[..64].to_string();
+        let hash = hash_signed_tx(&tx_hex).map_err(XRPLTransactionException::XRPLCoreError)?;
+
+        Ok(hash.into())
+    }
+
+    /// Serialize the transaction as rippled's `tx_json`: PascalCase
+    /// fields in rippled's order (see [`canonical_tx_json`]), the flags
+    /// folded into the numeric `Flags` value and no `null` fields.
+    fn to_tx_json(&self) -> XRPLModelResult<Value> {
+        let mut tx_json = serde_json::to_value(self)?;
+        strip_nulls(&mut tx_json);
+
+        Ok(canonical_tx_json(tx_json))
+    }
+
+    /// Encode the transaction as rippled's hex `tx_blob`.
+    fn to_blob(&self) -> XRPLModelResult<String>
+    where
+        Self: Sized,
+    {
+        Ok(encode(self).map_err(XRPLTransactionException::XRPLCoreError)?)
+    }
+
+    /// Render the transaction as a readable multi-line summary, for
+    /// command line tools and logs: its type, its fields with amounts
+    /// in XRP, its flags by name, its memos decoded and whether it is
+    /// signed, with its hash if so.
+    ///
+    /// # Examples
+    ///
+    /// ## Basic usage
+    ///
+    /// ```
+    /// use xrpl::models::transactions::{payment::Payment, Transaction};
+    ///
+    /// let payment: Payment = serde_json::from_str(r#"{
+    ///     "TransactionType": "Payment",
+    ///     "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+    ///     "Destination": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+    ///     "Amount": "1000000",
+    ///     "Fee": "12"
+    /// }"#).unwrap();
+    ///
+    /// println!("{}", payment.describe());
+    /// ```
+    fn describe(&self) -> String {
+        let tx_json = match self.to_tx_json() {
+            Ok(tx_json) => tx_json,
+            Err(error) => return alloc::format!("{}: {error}", self.get_transaction_type()),
+        };
+        describe_transaction(&tx_json, self.get_common_fields().flags.to_string())
+    }
+
+    /// Build the transaction from rippled's `tx_json`.
+    fn from_tx_json(tx_json: Value) -> XRPLModelResult<Self>
+    where
+        Self: DeserializeOwned,
+    {
+        Ok(serde_json::from_value(tx_json)?)
+    }
+}
+
+/// Fail unless `value` is hex, in whole bytes, as the ledger stores
+/// blob fields.
+pub(crate) fn get_hex_error(field: &str, value: &str) -> XRPLModelResult<()> {
+    if !value.len().is_multiple_of(2) || !value.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(XRPLModelException::InvalidValueFormat {
+            field: field.into(),
+            format: "hex".into(),
+            found: value.into(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Fail if `amount` is negative. Amounts may be negative in
+/// metadata and balance changes, but never in a transaction.
+pub(crate) fn get_negative_amount_error(
+    field: &str,
+    amount: Option<&Amount>,
+) -> XRPLModelResult<()> {
+    if amount.is_some_and(Amount::is_negative) {
+        return Err(XRPLModelException::ValueNegative(field.into()));
+    }
+
+    Ok(())
+}
+
+/// Sort the fields of a `tx_json`, and of the objects in it, by name
+/// as rippled outputs them. The order of serde_json's objects is the
+/// insertion order if any crate in the build enables its
+/// `preserve_order` feature, so this keeps the output stable for
+/// hashing and diffing either way.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::transactions::canonical_tx_json;
+///
+/// let tx_json = canonical_tx_json(serde_json::json!({
+///     "TransactionType": "AccountSet",
+///     "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+///     "Fee": "12"
+/// }));
+///
+/// assert_eq!(
+///     tx_json.to_string(),
+///     r#"{"Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Fee":"12","TransactionType":"AccountSet"}"#,
+/// );
+/// ```
+pub fn canonical_tx_json(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut fields: Vec<(String, Value)> = map.into_iter().collect();
+            fields.sort_unstable_by(|(name, _), (other_name, _)| name.cmp(other_name));
+
+            Value::Object(
+                fields
+                    .into_iter()
+                    .map(|(name, field)| (name, canonical_tx_json(field)))
+                    .collect(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(canonical_tx_json).collect()),
+        value => value,
+    }
+}
 
-        Ok(result.into())
+fn strip_nulls(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|_, field| !field.is_null());
+            map.values_mut().for_each(strip_nulls);
+        }
+        Value::Array(values) => values.iter_mut().for_each(strip_nulls),
+        _ => {}
     }
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Display, AsRefStr)]
 pub enum Flag {
-    AccountSet(account_set::AccountSetFlag),
+    AccountSet(account_set::AccountSetTfFlag),
     NFTokenCreateOffer(nftoken_create_offer::NFTokenCreateOfferFlag),
     NFTokenMint(nftoken_mint::NFTokenMintFlag),
     OfferCreate(offer_create::OfferCreateFlag),
@@ -443,3 +929,472 @@ mod test_tx_common_fields {
         assert_eq!(tx.get_hash().unwrap(), expected_hash);
     }
 }
+
+#[cfg(test)]
+mod test_common_fields_errors {
+    use super::*;
+    use account_set::AccountSet;
+    use alloc::vec;
+    use serde_json::json;
+
+    fn account_set(fields: Value) -> XRPLModelResult<AccountSet<'static>> {
+        let mut tx_json = json!({
+            "TransactionType": "AccountSet",
+            "Account": "rEbY5Tr5B6AjyjuVRhajpnvCWLGkYk5z6",
+        });
+        tx_json
+            .as_object_mut()
+            .unwrap()
+            .extend(fields.as_object().unwrap().clone());
+
+        Ok(serde_json::from_value(tx_json)?)
+    }
+
+    fn common_fields_error(fields: Value) -> Option<XRPLCommonFieldsException> {
+        match account_set(fields).unwrap().validate() {
+            Ok(()) => None,
+            Err(XRPLModelException::XRPLTransactionError(
+                XRPLTransactionException::XRPLCommonFieldsError(error),
+            )) => Some(error),
+            Err(error) => panic!("unexpected error: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn test_fee_error() {
+        assert_eq!(common_fields_error(json!({"Fee": "12"})), None);
+        assert_eq!(
+            common_fields_error(json!({"Fee": "-12"})),
+            Some(XRPLCommonFieldsException::InvalidFee("-12".into()))
+        );
+        assert_eq!(
+            common_fields_error(json!({"Fee": "1.5"})),
+            Some(XRPLCommonFieldsException::InvalidFee("1.5".into()))
+        );
+        assert_eq!(
+            common_fields_error(json!({"Fee": "100000000000000001"})),
+            Some(XRPLCommonFieldsException::FeeTooHigh {
+                max: MAX_DROPS,
+                found: 100000000000000001
+            })
+        );
+    }
+
+    #[test]
+    fn test_ticket_error() {
+        assert_eq!(
+            common_fields_error(json!({"Sequence": 0, "TicketSequence": 5})),
+            None
+        );
+        assert_eq!(
+            common_fields_error(json!({"Sequence": 3, "TicketSequence": 5})),
+            Some(XRPLCommonFieldsException::SequenceWithTicket(3))
+        );
+        assert_eq!(
+            common_fields_error(json!({
+                "AccountTxnID": "66F3D6158CAB6E53405F8C264DB39F07D8D0454433A63DDFB98218ED1BC99B60",
+                "TicketSequence": 5
+            })),
+            Some(XRPLCommonFieldsException::AccountTxnIdWithTicket)
+        );
+        assert_eq!(
+            common_fields_error(json!({
+                "AccountTxnID": "66F3D6158CAB6E53405F8C264DB39F07D8D0454433A63DDFB98218ED1BC99B60",
+                "Sequence": 3
+            })),
+            None
+        );
+    }
+
+    #[test]
+    fn test_signers_error() {
+        let signer = json!({
+            "Signer": {
+                "Account": "rEbY5Tr5B6AjyjuVRhajpnvCWLGkYk5z6",
+                "TxnSignature": "71135999783658A0CB4EBCF02E59ACD94C4D06D5",
+                "SigningPubKey": "ED93BFA583E83331E9DC498DE4558CE4861ACFAB9385EBBC43BC56A0D9845A1DF2"
+            }
+        });
+        assert_eq!(
+            common_fields_error(json!({"Signers": vec![signer.clone(); MAX_SIGNER_ENTRIES]})),
+            None
+        );
+        assert_eq!(
+            common_fields_error(json!({"Signers": vec![signer; MAX_SIGNER_ENTRIES + 1]})),
+            Some(XRPLCommonFieldsException::TooManySigners {
+                max: MAX_SIGNER_ENTRIES,
+                found: MAX_SIGNER_ENTRIES + 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_source_tag_out_of_range() {
+        assert!(account_set(json!({"SourceTag": u32::MAX})).is_ok());
+        assert!(account_set(json!({"SourceTag": u32::MAX as u64 + 1})).is_err());
+        assert!(account_set(json!({"SourceTag": -1})).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_tx_json {
+    use super::*;
+    use account_set::AccountSet;
+    use escrow_create::EscrowCreate;
+    use offer_create::OfferCreate;
+    use payment::Payment;
+    use serde_json::json;
+    use trust_set::TrustSet;
+
+    fn assert_round_trip<'a, T, F>(tx_json: Value)
+    where
+        T: Transaction<'a, F> + DeserializeOwned + PartialEq + Debug,
+        F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    {
+        let tx = T::from_tx_json(tx_json.clone()).unwrap();
+
+        assert_eq!(tx.to_tx_json().unwrap(), tx_json);
+        assert_eq!(T::from_tx_json(tx.to_tx_json().unwrap()).unwrap(), tx);
+        assert_eq!(tx.to_blob().unwrap(), encode(&tx_json).unwrap());
+    }
+
+    #[test]
+    fn test_tx_json_round_trip() {
+        assert_round_trip::<Payment, _>(json!({
+            "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+            "Amount": {
+                "currency": "USD",
+                "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "value": "1234.5678"
+            },
+            "Destination": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            "Fee": "12",
+            "Flags": 131072,
+            "Sequence": 359,
+            "TransactionType": "Payment"
+        }));
+        assert_round_trip::<AccountSet, _>(json!({
+            "Account": "rEbY5Tr5B6AjyjuVRhajpnvCWLGkYk5z6",
+            "Domain": "6578616D706C652E636F6D",
+            "Fee": "10",
+            "Flags": 0,
+            "LastLedgerSequence": 596447,
+            "Sequence": 596427,
+            "SetFlag": 5,
+            "TransactionType": "AccountSet"
+        }));
+        assert_round_trip::<OfferCreate, _>(json!({
+            "Account": "rLyttXLh7Ttca9CMUaD3exVoXY2fn2zwj3",
+            "Fee": "10",
+            "Flags": 655360,
+            "Sequence": 7,
+            "TakerGets": "6000000",
+            "TakerPays": {
+                "currency": "GKO",
+                "issuer": "ruazs5h1qEsqpke88pcqnaseXdm6od2xc",
+                "value": "2"
+            },
+            "TransactionType": "OfferCreate"
+        }));
+        assert_round_trip::<TrustSet, _>(json!({
+            "Account": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+            "Fee": "12",
+            "Flags": 262144,
+            "LastLedgerSequence": 8007750,
+            "LimitAmount": {
+                "currency": "USD",
+                "issuer": "rsP3mgGb2tcYUrxiLFiHJiQXhsziegtwBc",
+                "value": "100"
+            },
+            "Sequence": 12,
+            "TransactionType": "TrustSet"
+        }));
+        assert_round_trip::<EscrowCreate, _>(json!({
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "Amount": "10000",
+            "CancelAfter": 533257958,
+            "Destination": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+            "DestinationTag": 23480,
+            "Fee": "12",
+            "FinishAfter": 533171558,
+            "Flags": 0,
+            "Sequence": 1,
+            "SourceTag": 11747,
+            "TransactionType": "EscrowCreate"
+        }));
+    }
+
+    #[test]
+    fn test_tagged_objects_bare_form() {
+        let memo = json!({"MemoData": "72656E74", "MemoType": "687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963"});
+        let signer = json!({
+            "Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+            "SigningPubKey": "02B3EC4E5DD96029A647CFA20DA07FE1F85296505552CCAC114087E66B46BD77DF",
+            "TxnSignature": "30450221009C195DBBF7967E223D8626CA19CF02073667F2B22E206727BFE848FF42BEAC8A022048C323B0BED19A988BDBEFA974B6DE8AA9DCAE250AA82BBD1221787032A864E5"
+        });
+        let tx_json = |memo: Value, signer: Value| {
+            json!({
+                "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+                "Amount": "1000000",
+                "Destination": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                "Fee": "30",
+                "Flags": 0,
+                "Memos": [memo],
+                "Sequence": 359,
+                "Signers": [signer],
+                "SigningPubKey": "",
+                "TransactionType": "Payment"
+            })
+        };
+        let wrapped = tx_json(json!({"Memo": memo}), json!({"Signer": signer}));
+
+        // Bare memos and signers are read like rippled's wrapped ones,
+        // and always written wrapped.
+        let payment = Payment::from_tx_json(tx_json(memo.clone(), signer.clone())).unwrap();
+        assert_eq!(payment, Payment::from_tx_json(wrapped.clone()).unwrap());
+        assert_eq!(payment.to_tx_json().unwrap(), wrapped);
+        assert_eq!(payment.to_blob().unwrap(), encode(&wrapped).unwrap());
+
+        let error = serde_json::from_value::<Memo>(json!({"Memos": memo})).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with(r#"expected a `Memo` as `{"Memo": {...}}` or as its fields: "#));
+        assert!(serde_json::from_value::<Signer>(json!({"Account": "r"})).is_err());
+    }
+
+    #[test]
+    fn test_tx_json_key_order() {
+        let payment = Payment::from_tx_json(json!({
+            "TransactionType": "Payment",
+            "Sequence": 359,
+            "Memos": [{"Memo": {"MemoType": "74657874", "MemoData": "72656E74"}}],
+            "Flags": 0,
+            "Fee": "12",
+            "Destination": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            "Amount": {
+                "value": "1",
+                "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "currency": "USD"
+            },
+            "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys"
+        }))
+        .unwrap();
+        let expected = concat!(
+            r#"{"Account":"rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys","#,
+            r#""Amount":{"currency":"USD","issuer":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","value":"1"},"#,
+            r#""Destination":"rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh","Fee":"12","Flags":0,"#,
+            r#""Memos":[{"Memo":{"MemoData":"72656E74","MemoType":"74657874"}}],"#,
+            r#""Sequence":359,"TransactionType":"Payment"}"#
+        );
+
+        for _ in 0..3 {
+            assert_eq!(payment.to_tx_json().unwrap().to_string(), expected);
+        }
+        let tx_json = payment.to_tx_json().unwrap();
+        assert_eq!(canonical_tx_json(tx_json.clone()), tx_json);
+    }
+
+    #[test]
+    fn test_flags_are_folded() {
+        let offer = OfferCreate::from_tx_json(json!({
+            "Account": "rLyttXLh7Ttca9CMUaD3exVoXY2fn2zwj3",
+            "Flags": 655360,
+            "TakerGets": "6000000",
+            "TakerPays": "5000000",
+            "TransactionType": "OfferCreate"
+        }))
+        .unwrap();
+
+        assert_eq!(offer.common_fields.flags.bits(), 655360);
+        assert!(offer.has_flag(&offer_create::OfferCreateFlag::TfSell));
+        assert_eq!(offer.to_tx_json().unwrap()["Flags"], 655360);
+    }
+
+    #[test]
+    fn test_common_field_setters() {
+        let mut payment = Payment::from_tx_json(json!({
+            "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+            "Amount": "1000000",
+            "Destination": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "TransactionType": "Payment"
+        }))
+        .unwrap();
+        assert_eq!(payment.get_fee(), None);
+        assert_eq!(payment.get_sequence(), None);
+
+        payment.set_fee(XRPAmount::from("12"));
+        payment.set_sequence(42);
+        payment.set_last_ledger_sequence(1000);
+        payment.set_network_id(21337);
+        payment.set_signing_pub_key(
+            "ED5F5AC8B98974A3CA843326D9B88CEBD0560177B973EE0B149F782CFAA06DC66A".into(),
+        );
+        payment.set_txn_signature("CB199E1BFD4E3DAA105E4832EEDFA36413E1F44205E4EFB9E27E826044C21E3E2E848BBC8195E8959BADF887599B7310AD1B7047EF11B682E0D068F73749750E".into());
+
+        assert_eq!(payment.get_fee(), Some(&XRPAmount::from("12")));
+        assert_eq!(payment.get_sequence(), Some(42));
+        assert_eq!(payment.get_last_ledger_sequence(), Some(1000));
+        assert_eq!(payment.get_network_id(), Some(21337));
+        assert!(payment.is_signed());
+        assert_eq!(
+            payment.to_blob().unwrap(),
+            encode(&json!({
+                "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+                "Amount": "1000000",
+                "Destination": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "Fee": "12",
+                "Flags": 0,
+                "LastLedgerSequence": 1000,
+                "NetworkID": 21337,
+                "Sequence": 42,
+                "SigningPubKey": "ED5F5AC8B98974A3CA843326D9B88CEBD0560177B973EE0B149F782CFAA06DC66A",
+                "TransactionType": "Payment",
+                "TxnSignature": "CB199E1BFD4E3DAA105E4832EEDFA36413E1F44205E4EFB9E27E826044C21E3E2E848BBC8195E8959BADF887599B7310AD1B7047EF11B682E0D068F73749750E"
+            }))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_get_field_value() {
+        let escrow_create = EscrowCreate::from_tx_json(json!({
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "Amount": "10000",
+            "CancelAfter": 533257958,
+            "Destination": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+            "TransactionType": "EscrowCreate"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            escrow_create.get_field_value::<u32>("CancelAfter"),
+            Ok(Some(533257958))
+        );
+        assert_eq!(
+            escrow_create.get_field_value::<String>("Destination"),
+            Ok(Some("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".into()))
+        );
+        assert_eq!(
+            escrow_create.get_field_value::<u32>("FinishAfter"),
+            Ok(None)
+        );
+        assert!(escrow_create.get_field_value::<u32>("Destination").is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_memo {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::models::transactions::payment::Payment;
+
+    #[test]
+    fn test_memo_serde_shape() {
+        let memo = Memo::from_text("invoice 42", Some("text/plain"), None);
+        let json = serde_json::to_value(&memo).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "Memo": {
+                    "MemoData": "696E766F696365203432",
+                    "MemoFormat": "746578742F706C61696E"
+                }
+            })
+        );
+        // Owned JSON deserializes too, not only borrowed strings.
+        assert_eq!(serde_json::from_value::<Memo>(json).unwrap(), memo);
+        assert_eq!(memo.memo_data_utf8().as_deref(), Some("invoice 42"));
+    }
+
+    #[test]
+    fn test_memo_and_signer_constructors_round_trip() {
+        let memo = Memo::data("68656C6C6F")
+            .with_format("746578742F706C61696E")
+            .with_type("74657874");
+        let json = serde_json::to_value(&memo).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "Memo": {
+                    "MemoData": "68656C6C6F",
+                    "MemoFormat": "746578742F706C61696E",
+                    "MemoType": "74657874"
+                }
+            })
+        );
+        assert_eq!(serde_json::from_value::<Memo>(json).unwrap(), memo);
+        assert_eq!(
+            serde_json::to_value(Memo::from("68656C6C6F")).unwrap(),
+            serde_json::json!({"Memo": {"MemoData": "68656C6C6F"}})
+        );
+        assert_eq!(
+            Memo::from("68656C6C6F".to_string()),
+            Memo::data("68656C6C6F")
+        );
+
+        let signer = Signer::new(
+            "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+            "3045".to_string(),
+            Cow::from("02B3EC"),
+        );
+        let json = serde_json::to_value(&signer).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "Signer": {
+                    "Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+                    "SigningPubKey": "02B3EC",
+                    "TxnSignature": "3045"
+                }
+            })
+        );
+        assert_eq!(serde_json::from_value::<Signer>(json).unwrap(), signer);
+    }
+
+    #[test]
+    fn test_memo_invalid_hex() {
+        assert_eq!(
+            Memo::from_hex(Some("invoice 42"), None, None).unwrap_err(),
+            XRPLModelException::InvalidValueFormat {
+                field: "memo_data".into(),
+                format: "hex".into(),
+                found: "invoice 42".into(),
+            }
+        );
+        // Odd lengths are not whole bytes.
+        assert!(Memo::from_hex(None, None, Some("ABC")).is_err());
+        assert!(Memo::from_hex(Some("72656E74"), None, Some("abcd")).is_ok());
+
+        let invalid = Memo::data("not hex");
+        assert_eq!(invalid.memo_data_utf8(), None);
+    }
+
+    #[test]
+    fn test_memo_too_large() {
+        let data = "A".repeat(MEMO_MAX_LENGTH);
+        assert!(Memo::from_text(&data, None, None).validate().is_ok());
+        let memo = Memo::from_text(&data, None, Some("text/plain"));
+        assert_eq!(
+            memo.validate().unwrap_err(),
+            XRPLModelException::ValueTooLong {
+                field: "memo".into(),
+                max: MEMO_MAX_LENGTH,
+                found: MEMO_MAX_LENGTH + 10,
+            }
+        );
+
+        // Transactions check their memos.
+        let mut payment: Payment = serde_json::from_value(serde_json::json!({
+            "TransactionType": "Payment",
+            "Account": "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+            "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+            "Amount": "1000000"
+        }))
+        .unwrap();
+        assert!(payment.validate().is_ok());
+        payment.common_fields.memos = Some(alloc::vec![memo]);
+        assert!(payment.validate().is_err());
+    }
+}
@@ -11,9 +11,9 @@ use crate::models::{
     transactions::{Memo, Signer, Transaction, TransactionType},
     Model,
 };
-use crate::models::{FlagCollection, NoFlags, XRPLModelException, XRPLModelResult};
+use crate::models::{NoFlags, XRPLModelException, XRPLModelResult};
 
-use super::CommonFields;
+use super::{builder::transaction_builder, CommonFields};
 
 /// Accept offers to buy or sell an NFToken.
 ///
@@ -21,6 +21,7 @@ use super::CommonFields;
 /// `<https://xrpl.org/nftokenacceptoffer.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct NFTokenAcceptOffer<'a> {
     // The base fields for all transaction models.
@@ -54,8 +55,9 @@ pub struct NFTokenAcceptOffer<'a> {
     pub nftoken_broker_fee: Option<Amount<'a>>,
 }
 
-impl<'a: 'static> Model for NFTokenAcceptOffer<'a> {
+impl<'a> Model for NFTokenAcceptOffer<'a> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         self._get_brokered_mode_error()?;
         self._get_nftoken_broker_fee_error()?;
 
@@ -122,20 +124,15 @@ impl<'a> NFTokenAcceptOffer<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::NFTokenAcceptOffer,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::NFTokenAcceptOffer)
             },
             nftoken_sell_offer,
             nftoken_buy_offer,
@@ -149,6 +146,12 @@ pub trait NFTokenAcceptOfferError {
     fn _get_nftoken_broker_fee_error(&self) -> XRPLModelResult<()>;
 }
 
+transaction_builder! {
+    NFTokenAcceptOffer<NoFlags> => NFTokenAcceptOfferBuilder {
+        into nftoken_sell_offer: Cow<'a, str>, nftoken_buy_offer: Cow<'a, str>, nftoken_broker_fee: Amount<'a>;
+    }
+}
+
 #[cfg(test)]
 mod test_nftoken_accept_offer_error {
 
@@ -218,7 +221,6 @@ mod test_nftoken_accept_offer_error {
 
 #[cfg(test)]
 mod tests {
-    use alloc::string::ToString;
     use alloc::vec;
 
     use super::*;
@@ -230,14 +232,9 @@ mod tests {
             None,
             Some("12".into()),
             Some(75447550),
-            Some(vec![Memo::new(
-                Some(
-                    "61356534373538372D633134322D346663382D616466362D393666383562356435386437"
-                        .to_string(),
-                ),
-                None,
-                None,
-            )]),
+            Some(vec![
+                "61356534373538372D633134322D346663382D616466362D393666383562356435386437".into(),
+            ]),
             Some(68549302),
             None,
             None,
@@ -246,7 +243,7 @@ mod tests {
             None,
             None,
         );
-        let default_json_str = r#"{"Account":"r9spUPhPBfB6kQeF6vPhwmtFwRhBh2JUCG","TransactionType":"NFTokenAcceptOffer","Fee":"12","Flags":0,"LastLedgerSequence":75447550,"Memos":[{"Memo":{"MemoData":"61356534373538372D633134322D346663382D616466362D393666383562356435386437","MemoFormat":null,"MemoType":null}}],"Sequence":68549302,"NFTokenSellOffer":"68CD1F6F906494EA08C9CB5CAFA64DFA90D4E834B7151899B73231DE5A0C3B77"}"#;
+        let default_json_str = r#"{"Account":"r9spUPhPBfB6kQeF6vPhwmtFwRhBh2JUCG","TransactionType":"NFTokenAcceptOffer","Fee":"12","Flags":0,"LastLedgerSequence":75447550,"Memos":[{"Memo":{"MemoData":"61356534373538372D633134322D346663382D616466362D393666383562356435386437"}}],"Sequence":68549302,"NFTokenSellOffer":"68CD1F6F906494EA08C9CB5CAFA64DFA90D4E834B7151899B73231DE5A0C3B77"}"#;
         // Serialize
         let default_json_value = serde_json::to_value(default_json_str).unwrap();
         let serialized_string = serde_json::to_string(&default_txn).unwrap();
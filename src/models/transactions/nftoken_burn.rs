@@ -5,13 +5,13 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::amount::XRPAmount;
+use crate::models::NoFlags;
 use crate::models::{
     transactions::{Memo, Signer, Transaction, TransactionType},
-    Model,
+    Model, XRPLModelResult,
 };
-use crate::models::{FlagCollection, NoFlags};
 
-use super::CommonFields;
+use super::{builder::transaction_builder, CommonFields};
 
 /// Removes a NFToken object from the NFTokenPage in which it is being held,
 /// effectively removing the token from the ledger (burning it).
@@ -20,6 +20,7 @@ use super::CommonFields;
 /// `<https://xrpl.org/nftokenburn.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct NFTokenBurn<'a> {
     // The base fields for all transaction models.
@@ -46,7 +47,11 @@ pub struct NFTokenBurn<'a> {
     pub owner: Option<Cow<'a, str>>,
 }
 
-impl<'a> Model for NFTokenBurn<'a> {}
+impl<'a> Model for NFTokenBurn<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for NFTokenBurn<'a> {
     fn get_transaction_type(&self) -> TransactionType {
@@ -78,20 +83,15 @@ impl<'a> NFTokenBurn<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::NFTokenBurn,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::NFTokenBurn)
             },
             nftoken_id,
             owner,
@@ -99,6 +99,13 @@ impl<'a> NFTokenBurn<'a> {
     }
 }
 
+transaction_builder! {
+    NFTokenBurn<NoFlags> => NFTokenBurnBuilder {
+        required nftoken_id: Cow<'a, str>;
+        into owner: Cow<'a, str>;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
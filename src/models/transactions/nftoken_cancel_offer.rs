@@ -9,9 +9,9 @@ use crate::models::{
     transactions::{Memo, Signer, Transaction, TransactionType},
     Model,
 };
-use crate::models::{FlagCollection, NoFlags, XRPLModelResult};
+use crate::models::{NoFlags, XRPLModelResult};
 
-use super::CommonFields;
+use super::{builder::transaction_builder, CommonFields};
 
 /// Cancels existing token offers created using NFTokenCreateOffer.
 ///
@@ -19,6 +19,7 @@ use super::CommonFields;
 /// `<https://xrpl.org/nftokencanceloffer.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct NFTokenCancelOffer<'a> {
     // The base fields for all transaction models.
@@ -40,13 +41,13 @@ pub struct NFTokenCancelOffer<'a> {
     /// objects, but the IDs of the NFTokenOffer objects). Each entry must be a
     /// different object ID of an NFTokenOffer object; the transaction is invalid
     /// if the array contains duplicate entries.
-    #[serde(borrow)]
     #[serde(rename = "NFTokenOffers")]
     pub nftoken_offers: Vec<Cow<'a, str>>,
 }
 
-impl<'a: 'static> Model for NFTokenCancelOffer<'a> {
+impl<'a> Model for NFTokenCancelOffer<'a> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         self._get_nftoken_offers_error()?;
 
         Ok(())
@@ -96,20 +97,15 @@ impl<'a> NFTokenCancelOffer<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::NFTokenCancelOffer,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::NFTokenCancelOffer)
             },
             nftoken_offers,
         }
@@ -120,6 +116,12 @@ pub trait NFTokenCancelOfferError {
     fn _get_nftoken_offers_error(&self) -> XRPLModelResult<()>;
 }
 
+transaction_builder! {
+    NFTokenCancelOffer<NoFlags> => NFTokenCancelOfferBuilder {
+        required nftoken_offers: Vec<Cow<'a, str>>;
+    }
+}
+
 #[cfg(test)]
 mod test_nftoken_cancel_offer_error {
     use alloc::string::ToString;
@@ -13,9 +13,10 @@ use crate::models::{
 };
 
 use crate::models::amount::{Amount, XRPAmount};
+use crate::models::ripple_time::ripple_time_accessors;
 use crate::models::transactions::exceptions::XRPLNFTokenCreateOfferException;
 
-use super::{CommonFields, FlagCollection};
+use super::{builder::transaction_builder, CommonFields, FlagCollection};
 
 /// Transactions of the NFTokenCreateOffer type support additional values
 /// in the Flags field. This enum represents those options.
@@ -40,6 +41,7 @@ pub enum NFTokenCreateOfferFlag {
 /// `<https://xrpl.org/nftokencreateoffer.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct NFTokenCreateOffer<'a> {
     // The base fields for all transaction models.
@@ -78,8 +80,9 @@ pub struct NFTokenCreateOffer<'a> {
     pub destination: Option<Cow<'a, str>>,
 }
 
-impl<'a: 'static> Model for NFTokenCreateOffer<'a> {
+impl<'a> Model for NFTokenCreateOffer<'a> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         self._get_amount_error()?;
         self._get_destination_error()?;
         self._get_owner_error()?;
@@ -89,10 +92,6 @@ impl<'a: 'static> Model for NFTokenCreateOffer<'a> {
 }
 
 impl<'a> Transaction<'a, NFTokenCreateOfferFlag> for NFTokenCreateOffer<'a> {
-    fn has_flag(&self, flag: &NFTokenCreateOfferFlag) -> bool {
-        self.common_fields.has_flag(flag)
-    }
-
     fn get_transaction_type(&self) -> TransactionType {
         self.common_fields.get_transaction_type()
     }
@@ -179,8 +178,6 @@ impl<'a> NFTokenCreateOffer<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::NFTokenCreateOffer,
                 account_txn_id,
                 fee,
                 flags: flags.unwrap_or_default(),
@@ -190,9 +187,7 @@ impl<'a> NFTokenCreateOffer<'a> {
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::NFTokenCreateOffer)
             },
             nftoken_id,
             amount,
@@ -209,6 +204,18 @@ pub trait NFTokenCreateOfferError {
     fn _get_owner_error(&self) -> XRPLModelResult<()>;
 }
 
+ripple_time_accessors!(NFTokenCreateOffer {
+    expiration => expiration_datetime, set_expiration_datetime;
+});
+
+transaction_builder! {
+    NFTokenCreateOffer<NFTokenCreateOfferFlag> => NFTokenCreateOfferBuilder {
+        required amount: Amount<'a>, nftoken_id: Cow<'a, str>;
+        into destination: Cow<'a, str>, owner: Cow<'a, str>;
+        values expiration: u32;
+    }
+}
+
 #[cfg(test)]
 mod test_nftoken_create_offer_error {
     use alloc::string::ToString;
@@ -362,4 +369,19 @@ mod tests {
         let deserialized: NFTokenCreateOffer = serde_json::from_str(default_json_str).unwrap();
         assert_eq!(default_txn, deserialized);
     }
+
+    #[test]
+    fn test_has_flag() {
+        let json = |flags: u32| {
+            alloc::format!(
+                r#"{{"Account":"rEXAMPLEXAMPLEXAMPLEXAMPLEXAMPLE","TransactionType":"NFTokenCreateOffer","Flags":{},"NFTokenID":"000100001E962F495F07A990F4ED55ACCFEEF365DBAA76B6A048C0A200000007","Amount":"1000000"}}"#,
+                flags
+            )
+        };
+        let sell_offer: NFTokenCreateOffer = serde_json::from_str(&json(1)).unwrap();
+        assert!(sell_offer.has_flag(&NFTokenCreateOfferFlag::TfSellOffer));
+
+        let buy_offer: NFTokenCreateOffer = serde_json::from_str(&json(0)).unwrap();
+        assert!(!buy_offer.has_flag(&NFTokenCreateOfferFlag::TfSellOffer));
+    }
 }
@@ -1,4 +1,5 @@
 use alloc::borrow::Cow;
+use alloc::string::String;
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
@@ -15,7 +16,7 @@ use crate::{
 
 use crate::models::amount::XRPAmount;
 
-use super::{CommonFields, FlagCollection};
+use super::{builder::transaction_builder, get_hex_error, CommonFields, FlagCollection};
 
 /// Transactions of the NFTokenMint type support additional values
 /// in the Flags field. This enum represents those options.
@@ -46,6 +47,7 @@ pub enum NFTokenMintFlag {
 /// `<https://xrpl.org/nftokenmint.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct NFTokenMint<'a> {
     // The base fields for all transaction models.
@@ -89,6 +91,7 @@ pub struct NFTokenMint<'a> {
 
 impl<'a> Model for NFTokenMint<'a> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         self._get_issuer_error()?;
         self._get_transfer_fee_error()?;
         self._get_uri_error()?;
@@ -98,10 +101,6 @@ impl<'a> Model for NFTokenMint<'a> {
 }
 
 impl<'a> Transaction<'a, NFTokenMintFlag> for NFTokenMint<'a> {
-    fn has_flag(&self, flag: &NFTokenMintFlag) -> bool {
-        self.common_fields.has_flag(flag)
-    }
-
     fn get_transaction_type(&self) -> TransactionType {
         self.common_fields.get_transaction_type()
     }
@@ -122,8 +121,7 @@ impl<'a> NFTokenMintError for NFTokenMint<'a> {
                 Err(XRPLModelException::ValueEqualsValue {
                     field1: "issuer".into(),
                     field2: "account".into(),
-                }
-                .into())
+                })
             } else {
                 Ok(())
             }
@@ -139,8 +137,7 @@ impl<'a> NFTokenMintError for NFTokenMint<'a> {
                     field: "transfer_fee".into(),
                     max: MAX_TRANSFER_FEE,
                     found: transfer_fee,
-                }
-                .into())
+                })
             } else {
                 Ok(())
             }
@@ -150,16 +147,15 @@ impl<'a> NFTokenMintError for NFTokenMint<'a> {
     }
 
     fn _get_uri_error(&self) -> XRPLModelResult<()> {
-        if let Some(uri) = self.uri.clone() {
+        if let Some(uri) = &self.uri {
             if uri.len() > MAX_URI_LENGTH {
                 Err(XRPLModelException::ValueTooLong {
                     field: "uri".into(),
                     max: MAX_URI_LENGTH,
                     found: uri.len(),
-                }
-                .into())
+                })
             } else {
-                Ok(())
+                get_hex_error("uri", uri)
             }
         } else {
             Ok(())
@@ -186,8 +182,6 @@ impl<'a> NFTokenMint<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::NFTokenMint,
                 account_txn_id,
                 fee,
                 flags: flags.unwrap_or_default(),
@@ -197,9 +191,7 @@ impl<'a> NFTokenMint<'a> {
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::NFTokenMint)
             },
             nftoken_taxon,
             issuer,
@@ -207,6 +199,39 @@ impl<'a> NFTokenMint<'a> {
             uri,
         }
     }
+
+    /// Set the URI from text, hex-encoding it as the ledger expects.
+    ///
+    /// # Examples
+    ///
+    /// ## Basic usage
+    ///
+    /// ```
+    /// use xrpl::models::transactions::nftoken_mint::NFTokenMint;
+    ///
+    /// let mut nftoken_mint: NFTokenMint = serde_json::from_str(r#"{
+    ///     "TransactionType": "NFTokenMint",
+    ///     "Account": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+    ///     "NFTokenTaxon": 0
+    /// }"#).unwrap();
+    /// nftoken_mint.set_uri_from_str("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf4dfuylqabf3oclgtqy55fbzdi");
+    ///
+    /// assert!(nftoken_mint.uri.as_deref().unwrap().starts_with("697066733A2F2F"));
+    /// assert_eq!(
+    ///     nftoken_mint.uri_as_str().as_deref(),
+    ///     Some("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf4dfuylqabf3oclgtqy55fbzdi")
+    /// );
+    /// ```
+    pub fn set_uri_from_str(&mut self, uri: &str) {
+        self.uri = Some(hex::encode_upper(uri).into());
+    }
+
+    /// The URI as text, if it is hex-encoded UTF-8.
+    pub fn uri_as_str(&self) -> Option<String> {
+        let bytes = hex::decode(self.uri.as_deref()?).ok()?;
+
+        String::from_utf8(bytes).ok()
+    }
 }
 
 pub trait NFTokenMintError {
@@ -215,6 +240,14 @@ pub trait NFTokenMintError {
     fn _get_uri_error(&self) -> XRPLModelResult<()>;
 }
 
+transaction_builder! {
+    NFTokenMint<NFTokenMintFlag> => NFTokenMintBuilder {
+        required nftoken_taxon: u32;
+        into issuer: Cow<'a, str>, uri: Cow<'a, str>;
+        values transfer_fee: u32;
+    }
+}
+
 #[cfg(test)]
 mod test_nftoken_mint_error {
 
@@ -297,11 +330,60 @@ mod test_nftoken_mint_error {
             "The value of the field `\"uri\"` exceeds its maximum length of characters (max 512, found 513)"
         );
     }
+
+    #[test]
+    fn test_uri_length_boundary() {
+        let mut nftoken_mint: NFTokenMint = serde_json::from_value(serde_json::json!({
+            "TransactionType": "NFTokenMint",
+            "Account": "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
+            "NFTokenTaxon": 0
+        }))
+        .unwrap();
+
+        // 256 bytes is the most the ledger stores.
+        nftoken_mint.set_uri_from_str(&"a".repeat(MAX_URI_LENGTH / 2));
+        assert_eq!(nftoken_mint.uri.as_ref().unwrap().len(), MAX_URI_LENGTH);
+        assert!(nftoken_mint.validate().is_ok());
+
+        nftoken_mint.set_uri_from_str(&"a".repeat(MAX_URI_LENGTH / 2 + 1));
+        assert_eq!(
+            nftoken_mint.validate().unwrap_err(),
+            XRPLModelException::ValueTooLong {
+                field: "uri".into(),
+                max: MAX_URI_LENGTH,
+                found: MAX_URI_LENGTH + 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_uri_not_hex() {
+        let mut nftoken_mint: NFTokenMint = serde_json::from_value(serde_json::json!({
+            "TransactionType": "NFTokenMint",
+            "Account": "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb",
+            "NFTokenTaxon": 0,
+            "URI": "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf4dfuylqabf3oclgtqy55fbzdi"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            nftoken_mint.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `\"uri\"` does not have the correct format (expected \"hex\", found \"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf4dfuylqabf3oclgtqy55fbzdi\")"
+        );
+        assert_eq!(nftoken_mint.uri_as_str(), None);
+
+        nftoken_mint
+            .set_uri_from_str("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf4dfuylqabf3oclgtqy55fbzdi");
+        assert!(nftoken_mint.validate().is_ok());
+        assert_eq!(
+            nftoken_mint.uri_as_str().as_deref(),
+            Some("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf4dfuylqabf3oclgtqy55fbzdi")
+        );
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use alloc::string::ToString;
     use alloc::vec;
 
     use super::*;
@@ -314,7 +396,7 @@ mod tests {
             Some("10".into()),
             Some(vec![NFTokenMintFlag::TfTransferable].into()),
             None,
-            Some(vec![Memo::new(Some("72656E74".to_string()), None, Some("687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963".to_string()))]),
+            Some(vec![Memo::data("72656E74").with_type("687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963")]),
             None,
             None,
             None,
@@ -324,7 +406,7 @@ mod tests {
             Some(314),
             Some("697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C67747179353566627A6469".into()),
         );
-        let default_json_str = r#"{"Account":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","TransactionType":"NFTokenMint","Fee":"10","Flags":8,"Memos":[{"Memo":{"MemoData":"72656E74","MemoFormat":null,"MemoType":"687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963"}}],"NFTokenTaxon":0,"TransferFee":314,"URI":"697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C67747179353566627A6469"}"#;
+        let default_json_str = r#"{"Account":"rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B","TransactionType":"NFTokenMint","Fee":"10","Flags":8,"Memos":[{"Memo":{"MemoData":"72656E74","MemoType":"687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963"}}],"NFTokenTaxon":0,"TransferFee":314,"URI":"697066733A2F2F62616679626569676479727A74357366703775646D37687537367568377932366E6634646675796C71616266336F636C67747179353566627A6469"}"#;
         // Serialize
         let default_json_value = serde_json::to_value(default_json_str).unwrap();
         let serialized_string = serde_json::to_string(&default_txn).unwrap();
@@ -335,4 +417,15 @@ mod tests {
         let deserialized: NFTokenMint = serde_json::from_str(default_json_str).unwrap();
         assert_eq!(default_txn, deserialized);
     }
+
+    #[test]
+    fn test_has_flag() {
+        let nftoken_mint: NFTokenMint = serde_json::from_str(
+            r#"{"Account":"rEXAMPLEXAMPLEXAMPLEXAMPLEXAMPLE","TransactionType":"NFTokenMint","Flags":9,"NFTokenTaxon":0}"#,
+        )
+        .unwrap();
+        assert!(nftoken_mint.has_flag(&NFTokenMintFlag::TfBurnable));
+        assert!(nftoken_mint.has_flag(&NFTokenMintFlag::TfTransferable));
+        assert!(!nftoken_mint.has_flag(&NFTokenMintFlag::TfOnlyXRP));
+    }
 }
@@ -5,13 +5,13 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::amount::XRPAmount;
+use crate::models::NoFlags;
 use crate::models::{
     transactions::{Memo, Signer, Transaction, TransactionType},
-    Model,
+    Model, XRPLModelResult,
 };
-use crate::models::{FlagCollection, NoFlags};
 
-use super::CommonFields;
+use super::{builder::transaction_builder, CommonFields};
 
 /// Removes an Offer object from the XRP Ledger.
 ///
@@ -19,6 +19,7 @@ use super::CommonFields;
 /// `<https://xrpl.org/offercancel.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct OfferCancel<'a> {
     // The base fields for all transaction models.
@@ -41,7 +42,11 @@ pub struct OfferCancel<'a> {
     pub offer_sequence: u32,
 }
 
-impl<'a> Model for OfferCancel<'a> {}
+impl<'a> Model for OfferCancel<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for OfferCancel<'a> {
     fn get_transaction_type(&self) -> TransactionType {
@@ -72,26 +77,27 @@ impl<'a> OfferCancel<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::OfferCancel,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::OfferCancel)
             },
             offer_sequence,
         }
     }
 }
 
+transaction_builder! {
+    OfferCancel<NoFlags> => OfferCancelBuilder {
+        required offer_sequence: u32;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -9,12 +9,15 @@ use strum_macros::{AsRefStr, Display, EnumIter};
 use crate::models::{
     amount::Amount,
     transactions::{Memo, Signer, Transaction, TransactionType},
-    Model,
+    Model, XRPLModelResult,
 };
 
 use crate::models::amount::XRPAmount;
+use crate::models::ripple_time::ripple_time_accessors;
 
-use super::{CommonFields, FlagCollection};
+use super::{
+    builder::transaction_builder, get_negative_amount_error, CommonFields, FlagCollection,
+};
 
 /// Transactions of the OfferCreate type support additional values
 /// in the Flags field. This enum represents those options.
@@ -53,6 +56,7 @@ pub enum OfferCreateFlag {
 /// `<https://xrpl.org/offercreate.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct OfferCreate<'a> {
     // The base fields for all transaction models.
@@ -79,13 +83,17 @@ pub struct OfferCreate<'a> {
     pub offer_sequence: Option<u32>,
 }
 
-impl<'a> Model for OfferCreate<'a> {}
+impl<'a> Model for OfferCreate<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
+        get_negative_amount_error("taker_gets", Some(&self.taker_gets))?;
+        get_negative_amount_error("taker_pays", Some(&self.taker_pays))?;
 
-impl<'a> Transaction<'a, OfferCreateFlag> for OfferCreate<'a> {
-    fn has_flag(&self, flag: &OfferCreateFlag) -> bool {
-        self.common_fields.has_flag(flag)
+        Ok(())
     }
+}
 
+impl<'a> Transaction<'a, OfferCreateFlag> for OfferCreate<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.common_fields.get_transaction_type()
     }
@@ -118,8 +126,6 @@ impl<'a> OfferCreate<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::OfferCreate,
                 account_txn_id,
                 fee,
                 flags: flags.unwrap_or_default(),
@@ -129,9 +135,7 @@ impl<'a> OfferCreate<'a> {
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::OfferCreate)
             },
             taker_gets,
             taker_pays,
@@ -141,6 +145,17 @@ impl<'a> OfferCreate<'a> {
     }
 }
 
+ripple_time_accessors!(OfferCreate {
+    expiration => expiration_datetime, set_expiration_datetime;
+});
+
+transaction_builder! {
+    OfferCreate<OfferCreateFlag> => OfferCreateBuilder {
+        required taker_gets: Amount<'a>, taker_pays: Amount<'a>;
+        values expiration: u32, offer_sequence: u32;
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::models::amount::{IssuedCurrencyAmount, XRPAmount};
@@ -174,6 +189,18 @@ mod test {
         assert!(!txn.has_flag(&OfferCreateFlag::TfPassive));
     }
 
+    #[test]
+    fn test_has_flag_from_json() {
+        let txn: OfferCreate = serde_json::from_str(
+            r#"{"Account":"rpXhhWmCvDwkzNtRbm7mmD1vZqdfatQNEe","TransactionType":"OfferCreate","Flags":589824,"TakerGets":"1000000","TakerPays":{"currency":"USD","issuer":"rhub8VRN55s94qWKDv6jmDy1pUykJzF3wq","value":"0.3"}}"#,
+        )
+        .unwrap();
+        assert!(txn.has_flag(&OfferCreateFlag::TfPassive));
+        assert!(txn.has_flag(&OfferCreateFlag::TfSell));
+        assert!(!txn.has_flag(&OfferCreateFlag::TfImmediateOrCancel));
+        assert!(!txn.has_flag(&OfferCreateFlag::TfFillOrKill));
+    }
+
     #[test]
     fn test_get_transaction_type() {
         let txn: OfferCreate = OfferCreate::new(
@@ -8,13 +8,15 @@ use strum_macros::{AsRefStr, Display, EnumIter};
 use crate::models::{
     amount::Amount,
     transactions::{Memo, Signer, Transaction, TransactionType},
-    Model, PathStep, XRPLModelResult,
+    Model, Path, XRPLModelResult,
 };
 
 use crate::models::amount::XRPAmount;
 use crate::models::transactions::exceptions::XRPLPaymentException;
 
-use super::{CommonFields, FlagCollection};
+use super::{
+    builder::transaction_builder, get_negative_amount_error, CommonFields, FlagCollection,
+};
 
 /// Transactions of the Payment type support additional values
 /// in the Flags field. This enum represents those options.
@@ -46,6 +48,7 @@ pub enum PaymentFlag {
 /// `<https://xrpl.org/payment.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct Payment<'a> {
     // The base fields for all transaction models.
@@ -75,7 +78,7 @@ pub struct Payment<'a> {
     pub invoice_id: Option<u32>,
     /// Array of payment paths to be used for this transaction. Must be omitted for
     /// XRP-to-XRP transactions.
-    pub paths: Option<Vec<Vec<PathStep<'a>>>>,
+    pub paths: Option<Vec<Path<'a>>>,
     /// Highest amount of source currency this transaction is allowed to cost, including
     /// transfer fees, exchange rates, and slippage . Does not include the XRP destroyed
     /// as a cost for submitting the transaction. For non-XRP amounts, the nested field
@@ -87,21 +90,22 @@ pub struct Payment<'a> {
     pub deliver_min: Option<Amount<'a>>,
 }
 
-impl<'a: 'static> Model for Payment<'a> {
+impl<'a> Model for Payment<'a> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
+        get_negative_amount_error("amount", Some(&self.amount))?;
+        get_negative_amount_error("send_max", self.send_max.as_ref())?;
+        get_negative_amount_error("deliver_min", self.deliver_min.as_ref())?;
         self._get_xrp_transaction_error()?;
         self._get_partial_payment_error()?;
         self._get_exchange_error()?;
+        self._get_paths_error()?;
 
         Ok(())
     }
 }
 
 impl<'a> Transaction<'a, PaymentFlag> for Payment<'a> {
-    fn has_flag(&self, flag: &PaymentFlag) -> bool {
-        self.common_fields.has_flag(flag)
-    }
-
     fn get_transaction_type(&self) -> TransactionType {
         self.common_fields.get_transaction_type()
     }
@@ -117,58 +121,52 @@ impl<'a> Transaction<'a, PaymentFlag> for Payment<'a> {
 
 impl<'a> PaymentError for Payment<'a> {
     fn _get_xrp_transaction_error(&self) -> XRPLModelResult<()> {
-        if self.amount.is_xrp() && self.send_max.is_none() {
-            if self.paths.is_some() {
-                Err(XRPLPaymentException::IllegalOption {
-                    field: "paths".into(),
-                    context: "XRP to XRP payments".into(),
-                }
-                .into())
-            } else if self.common_fields.account == self.destination {
-                Err(XRPLPaymentException::ValueEqualsValueInContext {
-                    field1: "account".into(),
-                    field2: "destination".into(),
-                    context: "XRP to XRP Payments".into(),
-                }
-                .into())
-            } else {
-                Ok(())
+        let is_xrp_to_xrp =
+            self.amount.is_xrp() && self.send_max.as_ref().is_none_or(Amount::is_xrp);
+        if is_xrp_to_xrp && self.paths.is_some() {
+            Err(XRPLPaymentException::IllegalOption {
+                field: "paths".into(),
+                context: "XRP to XRP payments".into(),
+            }
+            .into())
+        } else if self.amount.is_xrp()
+            && self.send_max.is_none()
+            && self.common_fields.account == self.destination
+        {
+            Err(XRPLPaymentException::ValueEqualsValueInContext {
+                field1: "account".into(),
+                field2: "destination".into(),
+                context: "XRP to XRP Payments".into(),
             }
+            .into())
         } else {
             Ok(())
         }
     }
 
     fn _get_partial_payment_error(&self) -> XRPLModelResult<()> {
-        if let Some(send_max) = &self.send_max {
-            if !self.has_flag(&PaymentFlag::TfPartialPayment)
-                && send_max.is_xrp()
-                && self.amount.is_xrp()
-            {
-                Err(XRPLPaymentException::IllegalOption {
-                    field: "send_max".into(),
-                    context: "XRP to XRP non-partial payments".into(),
-                }
-                .into())
-            } else {
-                Ok(())
-            }
-        } else if self.has_flag(&PaymentFlag::TfPartialPayment) {
+        let is_partial_payment = self.has_flag(&PaymentFlag::TfPartialPayment);
+        if is_partial_payment && self.send_max.is_none() {
             Err(XRPLPaymentException::FlagRequiresField {
                 flag: PaymentFlag::TfPartialPayment,
                 field: "send_max".into(),
             }
             .into())
-        } else if !self.has_flag(&PaymentFlag::TfPartialPayment) {
-            if let Some(_deliver_min) = &self.deliver_min {
-                Err(XRPLPaymentException::IllegalOption {
-                    field: "deliver_min".into(),
-                    context: "XRP to XRP non-partial payments".into(),
-                }
-                .into())
-            } else {
-                Ok(())
+        } else if !is_partial_payment && self.deliver_min.is_some() {
+            Err(XRPLPaymentException::FieldRequiresFlag {
+                field: "deliver_min".into(),
+                flag: PaymentFlag::TfPartialPayment,
+            }
+            .into())
+        } else if !is_partial_payment
+            && self.amount.is_xrp()
+            && self.send_max.as_ref().is_some_and(Amount::is_xrp)
+        {
+            Err(XRPLPaymentException::IllegalOption {
+                field: "send_max".into(),
+                context: "XRP to XRP non-partial payments".into(),
             }
+            .into())
         } else {
             Ok(())
         }
@@ -185,6 +183,31 @@ impl<'a> PaymentError for Payment<'a> {
 
         Ok(())
     }
+
+    fn _get_paths_error(&self) -> XRPLModelResult<()> {
+        // Without `send_max` the source pays in the currency of
+        // `amount`, so a path converting to any other currency needs
+        // it.
+        let (Some(paths), None, Amount::IssuedCurrencyAmount(amount)) =
+            (&self.paths, &self.send_max, &self.amount)
+        else {
+            return Ok(());
+        };
+        let is_cross_currency = paths.iter().flatten().any(|step| {
+            step.currency
+                .as_ref()
+                .is_some_and(|currency| *currency != amount.currency)
+        });
+        if is_cross_currency {
+            Err(XRPLPaymentException::OptionRequired {
+                field: "send_max".into(),
+                context: "cross-currency payments".into(),
+            }
+            .into())
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl<'a> Payment<'a> {
@@ -204,13 +227,11 @@ impl<'a> Payment<'a> {
         deliver_min: Option<Amount<'a>>,
         destination_tag: Option<u32>,
         invoice_id: Option<u32>,
-        paths: Option<Vec<Vec<PathStep<'a>>>>,
+        paths: Option<Vec<Path<'a>>>,
         send_max: Option<Amount<'a>>,
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::Payment,
                 account_txn_id,
                 fee,
                 flags: flags.unwrap_or_default(),
@@ -220,9 +241,7 @@ impl<'a> Payment<'a> {
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::Payment)
             },
             amount,
             destination,
@@ -239,6 +258,15 @@ pub trait PaymentError {
     fn _get_xrp_transaction_error(&self) -> XRPLModelResult<()>;
     fn _get_partial_payment_error(&self) -> XRPLModelResult<()>;
     fn _get_exchange_error(&self) -> XRPLModelResult<()>;
+    fn _get_paths_error(&self) -> XRPLModelResult<()>;
+}
+
+transaction_builder! {
+    Payment<PaymentFlag> => PaymentBuilder {
+        required amount: Amount<'a>, destination: Cow<'a, str>;
+        into deliver_min: Amount<'a>, send_max: Amount<'a>;
+        values destination_tag: u32, invoice_id: u32, paths: Vec<Path<'a>>;
+    }
 }
 
 #[cfg(test)]
@@ -336,8 +364,76 @@ mod test_payment_error {
 
         assert_eq!(
             payment.validate().unwrap_err().to_string().as_str(),
-            "The optional field `\"deliver_min\"` is not allowed to be defined for \"XRP to XRP non-partial payments\""
+            "For the field `\"deliver_min\"` to be defined it is required to set the flag `TfPartialPayment`"
+        );
+
+        // Also when not XRP to XRP.
+        payment.send_max = Some(Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+            "USD".into(),
+            "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+            "10".into(),
+        )));
+        assert!(!payment.is_valid());
+        payment.common_fields.flags = vec![PaymentFlag::TfPartialPayment].into();
+        assert!(payment.is_valid());
+    }
+
+    #[test]
+    fn test_paths_error() {
+        let path = vec![vec![PathStep {
+            currency: Some("EUR".into()),
+            issuer: Some("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into()),
+            ..Default::default()
+        }]];
+        let mut payment = Payment::new(
+            "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+            None,
+            None,
+            Some(vec![PaymentFlag::TfPartialPayment].into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Amount::XRPAmount("1000000".into()),
+            "rLSn6Z3T8uCxbcd1oxwfGQN1Fdn5CyGujK".into(),
+            None,
+            None,
+            None,
+            Some(path.clone()),
+            Some(Amount::XRPAmount("1100000".into())),
+        );
+
+        // XRP to XRP, even with `send_max`.
+        assert_eq!(
+            payment.validate().unwrap_err().to_string().as_str(),
+            "The optional field `\"paths\"` is not allowed to be defined for \"XRP to XRP payments\""
         );
+
+        payment.common_fields.flags = FlagCollection::default();
+        payment.amount = Amount::IssuedCurrencyAmount(IssuedCurrencyAmount::new(
+            "USD".into(),
+            "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+            "10".into(),
+        ));
+        payment.send_max = None;
+
+        assert_eq!(
+            payment.validate().unwrap_err().to_string().as_str(),
+            "The optional field `\"send_max\"` is required to be defined for \"cross-currency payments\""
+        );
+
+        payment.send_max = Some(Amount::XRPAmount("1100000".into()));
+        assert!(payment.is_valid());
+
+        // Rippling through an account in the same currency.
+        payment.send_max = None;
+        payment.paths = Some(vec![vec![PathStep {
+            account: Some("rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into()),
+            ..Default::default()
+        }]]);
+        assert!(payment.is_valid());
     }
 
     #[test]
@@ -371,6 +467,25 @@ mod test_payment_error {
             "The optional field `\"send_max\"` is required to be defined for \"exchanges\""
         );
     }
+
+    #[test]
+    fn test_negative_amount_error() {
+        // A negative amount parses, but does not validate.
+        let mut payment: Payment = serde_json::from_str(
+            r#"{"TransactionType":"Payment","Account":"rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb","Destination":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","Amount":"-1000000","Fee":"12"}"#,
+        )
+        .unwrap();
+        assert_eq!(payment.amount, Amount::XRPAmount("-1000000".into()));
+        assert_eq!(
+            payment.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `\"amount\"` is not allowed to be negative"
+        );
+
+        payment.amount = Amount::XRPAmount("1000000".into());
+        assert!(payment.is_valid());
+        payment.common_fields.fee = Some("-12".into());
+        assert!(!payment.is_valid());
+    }
 }
 
 #[cfg(test)]
@@ -417,4 +532,15 @@ mod tests {
         let deserialized: Payment = serde_json::from_str(default_json_str).unwrap();
         assert_eq!(default_txn, deserialized);
     }
+
+    #[test]
+    fn test_has_flag() {
+        let payment: Payment = serde_json::from_str(
+            r#"{"Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","TransactionType":"Payment","Flags":196608,"Amount":"1000000","Destination":"ra5nK24KXen9AHvsdFTKHSANinZseWnPcX"}"#,
+        )
+        .unwrap();
+        assert!(payment.has_flag(&PaymentFlag::TfPartialPayment));
+        assert!(payment.has_flag(&PaymentFlag::TfNoDirectRipple));
+        assert!(!payment.has_flag(&PaymentFlag::TfLimitQuality));
+    }
 }
@@ -8,7 +8,7 @@ use strum_macros::{AsRefStr, Display, EnumIter};
 
 use crate::models::{
     transactions::{Memo, Signer, Transaction, TransactionType},
-    Model,
+    Model, XRPLModelResult,
 };
 
 use crate::models::amount::XRPAmount;
@@ -49,6 +49,7 @@ pub enum PaymentChannelClaimFlag {
 /// `<https://xrpl.org/paymentchannelclaim.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct PaymentChannelClaim<'a> {
     // The base fields for all transaction models.
@@ -88,13 +89,13 @@ pub struct PaymentChannelClaim<'a> {
     pub public_key: Option<Cow<'a, str>>,
 }
 
-impl<'a> Model for PaymentChannelClaim<'a> {}
-
-impl<'a> Transaction<'a, PaymentChannelClaimFlag> for PaymentChannelClaim<'a> {
-    fn has_flag(&self, flag: &PaymentChannelClaimFlag) -> bool {
-        self.common_fields.has_flag(flag)
+impl<'a> Model for PaymentChannelClaim<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
     }
+}
 
+impl<'a> Transaction<'a, PaymentChannelClaimFlag> for PaymentChannelClaim<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.common_fields.get_transaction_type()
     }
@@ -128,8 +129,6 @@ impl<'a> PaymentChannelClaim<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::PaymentChannelClaim,
                 account_txn_id,
                 fee,
                 flags: flags.unwrap_or_default(),
@@ -139,9 +138,7 @@ impl<'a> PaymentChannelClaim<'a> {
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::PaymentChannelClaim)
             },
             channel,
             balance,
@@ -186,4 +183,14 @@ mod tests {
         let deserialized: PaymentChannelClaim = serde_json::from_str(default_json_str).unwrap();
         assert_eq!(default_txn, deserialized);
     }
+
+    #[test]
+    fn test_has_flag() {
+        let claim: PaymentChannelClaim = serde_json::from_str(
+            r#"{"Account":"ra5nK24KXen9AHvsdFTKHSANinZseWnPcX","TransactionType":"PaymentChannelClaim","Flags":131072,"Channel":"C1AE6DDDEEC05CF2978C0BAD6FE302948E9533691DC749DCDD3B9E5992CA6198"}"#,
+        )
+        .unwrap();
+        assert!(claim.has_flag(&PaymentChannelClaimFlag::TfClose));
+        assert!(!claim.has_flag(&PaymentChannelClaimFlag::TfRenew));
+    }
 }
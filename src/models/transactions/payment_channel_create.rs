@@ -5,11 +5,12 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::amount::XRPAmount;
+use crate::models::ripple_time::ripple_time_accessors;
+use crate::models::NoFlags;
 use crate::models::{
     transactions::{Memo, Signer, Transaction, TransactionType},
-    Model,
+    Model, XRPLModelException, XRPLModelResult,
 };
-use crate::models::{FlagCollection, NoFlags};
 
 use super::CommonFields;
 
@@ -19,6 +20,7 @@ use super::CommonFields;
 /// `<https://xrpl.org/paymentchannelcreate.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct PaymentChannelCreate<'a> {
     // The base fields for all transaction models.
@@ -58,7 +60,16 @@ pub struct PaymentChannelCreate<'a> {
     pub destination_tag: Option<u32>,
 }
 
-impl<'a> Model for PaymentChannelCreate<'a> {}
+impl<'a> Model for PaymentChannelCreate<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
+        if self.amount.is_negative() {
+            return Err(XRPLModelException::ValueNegative("amount".into()));
+        }
+
+        Ok(())
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for PaymentChannelCreate<'a> {
     fn get_transaction_type(&self) -> TransactionType {
@@ -94,20 +105,15 @@ impl<'a> PaymentChannelCreate<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::PaymentChannelCreate,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::PaymentChannelCreate)
             },
             amount,
             destination,
@@ -119,6 +125,10 @@ impl<'a> PaymentChannelCreate<'a> {
     }
 }
 
+ripple_time_accessors!(PaymentChannelCreate {
+    cancel_after => cancel_after_datetime, set_cancel_after_datetime;
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
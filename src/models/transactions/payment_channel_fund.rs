@@ -4,12 +4,13 @@ use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::models::ripple_time::ripple_time_accessors;
+use crate::models::NoFlags;
 use crate::models::{
     amount::XRPAmount,
     transactions::{Memo, Signer, Transaction, TransactionType},
-    Model,
+    Model, XRPLModelException, XRPLModelResult,
 };
-use crate::models::{FlagCollection, NoFlags};
 
 use super::CommonFields;
 
@@ -20,6 +21,7 @@ use super::CommonFields;
 /// `<https://xrpl.org/paymentchannelfund.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct PaymentChannelFund<'a> {
     // The base fields for all transaction models.
@@ -50,7 +52,16 @@ pub struct PaymentChannelFund<'a> {
     pub expiration: Option<u32>,
 }
 
-impl<'a> Model for PaymentChannelFund<'a> {}
+impl<'a> Model for PaymentChannelFund<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
+        if self.amount.is_negative() {
+            return Err(XRPLModelException::ValueNegative("amount".into()));
+        }
+
+        Ok(())
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for PaymentChannelFund<'a> {
     fn get_transaction_type(&self) -> TransactionType {
@@ -83,20 +94,15 @@ impl<'a> PaymentChannelFund<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::PaymentChannelFund,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::PaymentChannelFund)
             },
             amount,
             channel,
@@ -105,6 +111,10 @@ impl<'a> PaymentChannelFund<'a> {
     }
 }
 
+ripple_time_accessors!(PaymentChannelFund {
+    expiration => expiration_datetime, set_expiration_datetime;
+});
+
 #[cfg(test)]
 mod tests {
     use crate::models::amount::XRPAmount;
@@ -10,7 +10,7 @@ use crate::models::amount::XRPAmount;
 use crate::models::transactions::{CommonFields, FlagCollection, Memo, Signer};
 use crate::models::{
     transactions::{Transaction, TransactionType},
-    Model,
+    Model, XRPLModelResult,
 };
 
 #[derive(
@@ -30,6 +30,7 @@ pub enum EnableAmendmentFlag {
 /// `<https://xrpl.org/enableamendment.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct EnableAmendment<'a> {
     // The base fields for all transaction models.
@@ -50,13 +51,13 @@ pub struct EnableAmendment<'a> {
     pub ledger_sequence: u32,
 }
 
-impl<'a> Model for EnableAmendment<'a> {}
-
-impl<'a> Transaction<'a, EnableAmendmentFlag> for EnableAmendment<'a> {
-    fn has_flag(&self, flag: &EnableAmendmentFlag) -> bool {
-        self.common_fields.has_flag(flag)
+impl<'a> Model for EnableAmendment<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
     }
+}
 
+impl<'a> Transaction<'a, EnableAmendmentFlag> for EnableAmendment<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.common_fields.get_transaction_type()
     }
@@ -78,32 +79,77 @@ impl<'a> EnableAmendment<'a> {
         flags: Option<FlagCollection<EnableAmendmentFlag>>,
         last_ledger_sequence: Option<u32>,
         memos: Option<Vec<Memo>>,
+        network_id: Option<u32>,
         sequence: Option<u32>,
         signers: Option<Vec<Signer<'a>>>,
+        signing_pub_key: Option<Cow<'a, str>>,
         source_tag: Option<u32>,
         ticket_sequence: Option<u32>,
+        txn_signature: Option<Cow<'a, str>>,
         amendment: Cow<'a, str>,
         ledger_sequence: u32,
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::EnableAmendment,
                 account_txn_id,
                 fee,
                 flags: flags.unwrap_or_default(),
                 last_ledger_sequence,
                 memos,
+                network_id,
                 sequence,
                 signers,
+                signing_pub_key,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                txn_signature,
+                ..CommonFields::with_account(account, TransactionType::EnableAmendment)
             },
             amendment,
             ledger_sequence,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_serde() {
+        let default_txn = EnableAmendment::new(
+            "rrrrrrrrrrrrrrrrrrrrrhoLvTp".into(),
+            None,
+            Some("0".into()),
+            Some(vec![EnableAmendmentFlag::TfGotMajority].into()),
+            None,
+            None,
+            None,
+            Some(0),
+            None,
+            Some("".into()),
+            None,
+            None,
+            None,
+            "42426C4D4F1009EE67080A9B7965B44656D7714D104A72F9B4369F97ABF044EE".into(),
+            21225473,
+        );
+        let default_json_str = r#"{"Account":"rrrrrrrrrrrrrrrrrrrrrhoLvTp","TransactionType":"EnableAmendment","Fee":"0","Flags":65536,"Sequence":0,"SigningPubKey":"","Amendment":"42426C4D4F1009EE67080A9B7965B44656D7714D104A72F9B4369F97ABF044EE","LedgerSequence":21225473}"#;
+        // Serialize
+        let default_json_value = serde_json::to_value(default_json_str).unwrap();
+        let serialized_string = serde_json::to_string(&default_txn).unwrap();
+        let serialized_value = serde_json::to_value(&serialized_string).unwrap();
+        assert_eq!(serialized_value, default_json_value);
+
+        // Deserialize
+        let deserialized: EnableAmendment = serde_json::from_str(default_json_str).unwrap();
+        assert_eq!(default_txn, deserialized);
+        assert!(deserialized.has_flag(&EnableAmendmentFlag::TfGotMajority));
+        assert_eq!(
+            deserialized.get_transaction_type(),
+            TransactionType::EnableAmendment
+        );
+    }
+}
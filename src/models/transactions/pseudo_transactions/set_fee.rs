@@ -1,4 +1,5 @@
 use alloc::borrow::Cow;
+use alloc::string::ToString;
 use alloc::vec::Vec;
 
 use serde::{Deserialize, Serialize};
@@ -6,16 +7,23 @@ use serde_with::skip_serializing_none;
 
 use crate::models::amount::XRPAmount;
 use crate::models::transactions::{CommonFields, Memo, Signer};
+use crate::models::NoFlags;
 use crate::models::{
     transactions::{Transaction, TransactionType},
-    Model,
+    Model, XRPLModelException, XRPLModelResult,
 };
-use crate::models::{FlagCollection, NoFlags};
 
 /// See SetFee:
 /// `<https://xrpl.org/setfee.html>`
+///
+/// The fields depend on whether the XRPFees amendment was enabled
+/// when the pseudo-transaction was applied: before, it sets
+/// `BaseFee`, `ReferenceFeeUnits`, `ReserveBase` and
+/// `ReserveIncrement`; after, `BaseFeeDrops`, `ReserveBaseDrops` and
+/// `ReserveIncrementDrops`.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct SetFee<'a> {
     // The base fields for all transaction models.
@@ -32,14 +40,74 @@ pub struct SetFee<'a> {
     ///
     /// See SetFee fields:
     /// `<https://xrpl.org/setfee.html#setfee-fields>`
-    pub base_fee: XRPAmount<'a>,
-    pub reference_fee_units: u32,
-    pub reserve_base: u32,
-    pub reserve_increment: u32,
-    pub ledger_sequence: u32,
+    ///
+    /// The cost of the reference transaction in drops, as a
+    /// hexadecimal UInt64. Before XRPFees only.
+    pub base_fee: Option<XRPAmount<'a>>,
+    /// Before XRPFees only.
+    pub reference_fee_units: Option<u32>,
+    /// Before XRPFees only.
+    pub reserve_base: Option<u32>,
+    /// Before XRPFees only.
+    pub reserve_increment: Option<u32>,
+    /// The index of the ledger version where this pseudo-transaction
+    /// appears. Omitted for some historical SetFee pseudo-transactions.
+    pub ledger_sequence: Option<u32>,
+    /// The cost of the reference transaction. After XRPFees only.
+    pub base_fee_drops: Option<XRPAmount<'a>>,
+    /// The base reserve. After XRPFees only.
+    pub reserve_base_drops: Option<XRPAmount<'a>>,
+    /// The owner reserve. After XRPFees only.
+    pub reserve_increment_drops: Option<XRPAmount<'a>>,
+}
+
+impl<'a> Model for SetFee<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
+        self._get_fee_fields_error()
+    }
 }
 
-impl<'a> Model for SetFee<'a> {}
+impl<'a> SetFeeError for SetFee<'a> {
+    fn _get_fee_fields_error(&self) -> XRPLModelResult<()> {
+        let legacy_fields = [
+            ("BaseFee", self.base_fee.is_some()),
+            ("ReferenceFeeUnits", self.reference_fee_units.is_some()),
+            ("ReserveBase", self.reserve_base.is_some()),
+            ("ReserveIncrement", self.reserve_increment.is_some()),
+        ];
+        let drops_fields = [
+            ("BaseFeeDrops", self.base_fee_drops.is_some()),
+            ("ReserveBaseDrops", self.reserve_base_drops.is_some()),
+            (
+                "ReserveIncrementDrops",
+                self.reserve_increment_drops.is_some(),
+            ),
+        ];
+        let is_drops_layout = drops_fields.iter().any(|(_, is_set)| *is_set);
+        if is_drops_layout && legacy_fields.iter().any(|(_, is_set)| *is_set) {
+            return Err(XRPLModelException::InvalidFieldCombination {
+                field: "BaseFeeDrops",
+                other_fields: &[
+                    "BaseFee",
+                    "ReferenceFeeUnits",
+                    "ReserveBase",
+                    "ReserveIncrement",
+                ],
+            });
+        }
+
+        let fields: &[(&str, bool)] = if is_drops_layout {
+            &drops_fields
+        } else {
+            &legacy_fields
+        };
+        match fields.iter().find(|(_, is_set)| !is_set) {
+            Some((name, _)) => Err(XRPLModelException::MissingField(name.to_string())),
+            None => Ok(()),
+        }
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for SetFee<'a> {
     fn get_transaction_type(&self) -> TransactionType {
@@ -62,38 +130,197 @@ impl<'a> SetFee<'a> {
         fee: Option<XRPAmount<'a>>,
         last_ledger_sequence: Option<u32>,
         memos: Option<Vec<Memo>>,
+        network_id: Option<u32>,
         sequence: Option<u32>,
         signers: Option<Vec<Signer<'a>>>,
+        signing_pub_key: Option<Cow<'a, str>>,
         source_tag: Option<u32>,
         ticket_sequence: Option<u32>,
-        base_fee: XRPAmount<'a>,
-        reference_fee_units: u32,
-        reserve_base: u32,
-        reserve_increment: u32,
-        ledger_sequence: u32,
+        txn_signature: Option<Cow<'a, str>>,
+        base_fee: Option<XRPAmount<'a>>,
+        reference_fee_units: Option<u32>,
+        reserve_base: Option<u32>,
+        reserve_increment: Option<u32>,
+        ledger_sequence: Option<u32>,
+        base_fee_drops: Option<XRPAmount<'a>>,
+        reserve_base_drops: Option<XRPAmount<'a>>,
+        reserve_increment_drops: Option<XRPAmount<'a>>,
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::SetFee,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
+                network_id,
                 sequence,
                 signers,
+                signing_pub_key,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                txn_signature,
+                ..CommonFields::with_account(account, TransactionType::SetFee)
             },
             base_fee,
             reference_fee_units,
             reserve_base,
             reserve_increment,
             ledger_sequence,
+            base_fee_drops,
+            reserve_base_drops,
+            reserve_increment_drops,
         }
     }
 }
+
+pub trait SetFeeError {
+    fn _get_fee_fields_error(&self) -> XRPLModelResult<()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde() {
+        // SetFee pseudo-transaction from validated mainnet ledger 3721729
+        // (hash 1C15FEA3E1D50F96B6598607FC773FF1F6E0125F30160144BE0C5CBC52F5151B).
+        let default_txn = SetFee::new(
+            "rrrrrrrrrrrrrrrrrrrrrhoLvTp".into(),
+            None,
+            Some("0".into()),
+            None,
+            None,
+            None,
+            Some(0),
+            None,
+            Some("".into()),
+            None,
+            None,
+            None,
+            Some("000000000000000A".into()),
+            Some(10),
+            Some(20000000),
+            Some(5000000),
+            None,
+            None,
+            None,
+            None,
+        );
+        let default_json_str = r#"{"Account":"rrrrrrrrrrrrrrrrrrrrrhoLvTp","TransactionType":"SetFee","Fee":"0","Flags":0,"Sequence":0,"SigningPubKey":"","BaseFee":"000000000000000A","ReferenceFeeUnits":10,"ReserveBase":20000000,"ReserveIncrement":5000000}"#;
+        // Serialize
+        let default_json_value = serde_json::to_value(default_json_str).unwrap();
+        let serialized_string = serde_json::to_string(&default_txn).unwrap();
+        let serialized_value = serde_json::to_value(&serialized_string).unwrap();
+        assert_eq!(serialized_value, default_json_value);
+
+        // Deserialize
+        let deserialized: SetFee = serde_json::from_str(default_json_str).unwrap();
+        assert_eq!(default_txn, deserialized);
+    }
+
+    #[test]
+    fn test_deserialize_mainnet_fixture() {
+        let mainnet_json_str = r#"{
+            "Account": "rrrrrrrrrrrrrrrrrrrrrhoLvTp",
+            "BaseFee": "000000000000000A",
+            "Fee": "0",
+            "ReferenceFeeUnits": 10,
+            "ReserveBase": 20000000,
+            "ReserveIncrement": 5000000,
+            "Sequence": 0,
+            "SigningPubKey": "",
+            "TransactionType": "SetFee"
+        }"#;
+        let txn: SetFee = serde_json::from_str(mainnet_json_str).unwrap();
+
+        assert_eq!(txn.get_transaction_type(), TransactionType::SetFee);
+        assert_eq!(
+            txn.get_common_fields().account,
+            "rrrrrrrrrrrrrrrrrrrrrhoLvTp"
+        );
+        assert_eq!(txn.get_common_fields().signing_pub_key, Some("".into()));
+        assert_eq!(txn.base_fee, Some("000000000000000A".into()));
+        assert_eq!(txn.ledger_sequence, None);
+        assert!(txn.get_errors().is_ok());
+    }
+
+    #[test]
+    fn test_xrp_fees_layout() {
+        // Since XRPFees, the fees and reserves are amounts in drops.
+        let txn: SetFee = serde_json::from_value(serde_json::json!({
+            "Account": "rrrrrrrrrrrrrrrrrrrrrhoLvTp",
+            "BaseFeeDrops": "10",
+            "Fee": "0",
+            "LedgerSequence": 92090368,
+            "ReserveBaseDrops": "1000000",
+            "ReserveIncrementDrops": "200000",
+            "Sequence": 0,
+            "SigningPubKey": "",
+            "TransactionType": "SetFee"
+        }))
+        .unwrap();
+
+        assert_eq!(txn.base_fee, None);
+        assert_eq!(txn.reserve_base_drops, Some("1000000".into()));
+        assert!(txn.get_errors().is_ok());
+        // Pseudo-transactions are never signed, but still hashed.
+        assert_eq!(
+            txn.get_hash().unwrap(),
+            crate::core::ledger::hash_signed_tx(&txn.to_blob().unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fee_fields_error() {
+        let set_fee = |base_fee: Option<&'static str>, base_fee_drops: Option<&'static str>| {
+            SetFee::new(
+                "rrrrrrrrrrrrrrrrrrrrrhoLvTp".into(),
+                None,
+                Some("0".into()),
+                None,
+                None,
+                None,
+                Some(0),
+                None,
+                Some("".into()),
+                None,
+                None,
+                None,
+                base_fee.map(Into::into),
+                Some(10),
+                Some(20000000),
+                Some(5000000),
+                None,
+                base_fee_drops.map(Into::into),
+                Some("1000000".into()),
+                Some("200000".into()),
+            )
+        };
+
+        let mut txn = set_fee(Some("000000000000000A"), Some("10"));
+        assert_eq!(
+            txn.validate().unwrap_err().to_string(),
+            "Invalid field combination: BaseFeeDrops with [\"BaseFee\", \"ReferenceFeeUnits\", \"ReserveBase\", \"ReserveIncrement\"]"
+        );
+
+        txn.base_fee_drops = None;
+        txn.reserve_base_drops = None;
+        txn.reserve_increment_drops = None;
+        assert!(txn.validate().is_ok());
+        txn.reserve_increment = None;
+        assert_eq!(
+            txn.validate(),
+            Err(XRPLModelException::MissingField("ReserveIncrement".into()))
+        );
+
+        let mut txn = set_fee(None, None);
+        txn.reference_fee_units = None;
+        txn.reserve_base = None;
+        txn.reserve_increment = None;
+        assert_eq!(
+            txn.validate(),
+            Err(XRPLModelException::MissingField("BaseFeeDrops".into()))
+        );
+    }
+}
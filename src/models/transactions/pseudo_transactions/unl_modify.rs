@@ -7,12 +7,12 @@ use serde_with::skip_serializing_none;
 use strum_macros::{AsRefStr, Display, EnumIter};
 
 use crate::models::transactions::{CommonFields, Memo, Signer};
+use crate::models::NoFlags;
 use crate::models::{
     amount::XRPAmount,
     transactions::{Transaction, TransactionType},
-    Model,
+    Model, XRPLModelResult,
 };
-use crate::models::{FlagCollection, NoFlags};
 
 #[derive(
     Debug, Eq, PartialEq, Clone, Serialize_repr, Deserialize_repr, Display, AsRefStr, EnumIter,
@@ -27,6 +27,7 @@ pub enum UNLModifyDisabling {
 /// `<https://xrpl.org/unlmodify.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct UNLModify<'a> {
     // The base fields for all transaction models.
@@ -44,11 +45,17 @@ pub struct UNLModify<'a> {
     /// See UNLModify fields:
     /// `<https://xrpl.org/unlmodify.html#unlmodify-fields>`
     pub ledger_sequence: u32,
+    #[serde(rename = "UNLModifyDisabling")]
     pub unlmodify_disabling: UNLModifyDisabling,
+    #[serde(rename = "UNLModifyValidator")]
     pub unlmodify_validator: Cow<'a, str>,
 }
 
-impl<'a> Model for UNLModify<'a> {}
+impl<'a> Model for UNLModify<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for UNLModify<'a> {
     fn get_transaction_type(&self) -> TransactionType {
@@ -71,30 +78,31 @@ impl<'a> UNLModify<'a> {
         fee: Option<XRPAmount<'a>>,
         last_ledger_sequence: Option<u32>,
         memos: Option<Vec<Memo>>,
+        network_id: Option<u32>,
         sequence: Option<u32>,
         signers: Option<Vec<Signer<'a>>>,
+        signing_pub_key: Option<Cow<'a, str>>,
         source_tag: Option<u32>,
         ticket_sequence: Option<u32>,
+        txn_signature: Option<Cow<'a, str>>,
         ledger_sequence: u32,
         unlmodify_disabling: UNLModifyDisabling,
         unlmodify_validator: Cow<'a, str>,
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::UNLModify,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
+                network_id,
                 sequence,
                 signers,
+                signing_pub_key,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                txn_signature,
+                ..CommonFields::with_account(account, TransactionType::UNLModify)
             },
             ledger_sequence,
             unlmodify_disabling,
@@ -102,3 +110,63 @@ impl<'a> UNLModify<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde() {
+        let default_txn = UNLModify::new(
+            "".into(),
+            None,
+            Some("0".into()),
+            None,
+            None,
+            None,
+            Some(0),
+            None,
+            Some("".into()),
+            None,
+            None,
+            None,
+            1600000,
+            UNLModifyDisabling::Enable,
+            "EDB6FC8E803EE8EDC2793F1EC917B2EE41D35255618DEB91D3F9B1FC89B75D4539".into(),
+        );
+        let default_json_str = r#"{"Account":"","TransactionType":"UNLModify","Fee":"0","Flags":0,"Sequence":0,"SigningPubKey":"","LedgerSequence":1600000,"UNLModifyDisabling":1,"UNLModifyValidator":"EDB6FC8E803EE8EDC2793F1EC917B2EE41D35255618DEB91D3F9B1FC89B75D4539"}"#;
+        // Serialize
+        let default_json_value = serde_json::to_value(default_json_str).unwrap();
+        let serialized_string = serde_json::to_string(&default_txn).unwrap();
+        let serialized_value = serde_json::to_value(&serialized_string).unwrap();
+        assert_eq!(serialized_value, default_json_value);
+
+        // Deserialize
+        let deserialized: UNLModify = serde_json::from_str(default_json_str).unwrap();
+        assert_eq!(default_txn, deserialized);
+        assert_eq!(
+            deserialized.get_transaction_type(),
+            TransactionType::UNLModify
+        );
+    }
+
+    #[test]
+    fn test_decode_blob() {
+        // rippled encodes the zero `Account` of UNLModify with a length
+        // of zero.
+        let blob = "120066220000000024000000002600186A006840000000000000007300701321EDB6FC8E803EE8EDC2793F1EC917B2EE41D35255618DEB91D3F9B1FC89B75D4539810000101101";
+        let txn: UNLModify =
+            serde_json::from_value(crate::core::binarycodec::decode(blob).unwrap()).unwrap();
+
+        assert_eq!(
+            txn.get_common_fields().account,
+            "rrrrrrrrrrrrrrrrrrrrrhoLvTp"
+        );
+        assert_eq!(txn.unlmodify_disabling, UNLModifyDisabling::Enable);
+        assert_eq!(txn.to_blob().unwrap(), blob);
+        assert_eq!(
+            txn.get_hash().unwrap(),
+            crate::core::ledger::hash_signed_tx(blob).unwrap()
+        );
+    }
+}
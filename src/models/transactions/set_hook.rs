@@ -0,0 +1,347 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use derive_new::new;
+use serde::{ser::SerializeMap, Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use serde_with::skip_serializing_none;
+use strum_macros::{AsRefStr, Display, EnumIter};
+
+use crate::models::transactions::exceptions::XRPLSetHookException;
+use crate::models::{
+    amount::XRPAmount,
+    transactions::{Memo, Signer, Transaction, TransactionType},
+    FlagCollection, Model, NoFlags, XRPLModelException, XRPLModelResult,
+};
+use crate::serde_with_tag;
+
+use super::{get_hex_error, CommonFields};
+
+/// The maximum number of Hooks an account can have installed.
+pub const MAX_HOOKS: usize = 10;
+/// The length of `HookOn` and `HookNamespace`, 256 bits as hex.
+const HASH256_HEX_LENGTH: usize = 64;
+
+/// Flags of a Hook in a SetHook transaction.
+///
+/// See SetHook Flags:
+/// `<https://xrpl-hooks.readme.io/docs/sethook-transaction#flags>`
+#[derive(
+    Debug, Eq, PartialEq, Clone, Serialize_repr, Deserialize_repr, Display, AsRefStr, EnumIter,
+)]
+#[repr(u32)]
+pub enum HookFlag {
+    /// Replace the Hook already installed at this position.
+    HsfOverride = 0x00000001,
+    /// Delete the state of the Hook's namespace.
+    HsfNsDelete = 0x00000002,
+    /// Allow the Hook to be executed as a collect call.
+    HsfCollect = 0x00000004,
+}
+
+serde_with_tag! {
+    /// A parameter passed to a Hook, readable with `hook_param`.
+    #[derive(Debug, PartialEq, Eq, Default, Clone, new)]
+    pub struct HookParameter {
+        /// The name of the parameter, as hex.
+        pub hook_parameter_name: String,
+        /// The value of the parameter, as hex. Omitted to delete a
+        /// parameter set at install time.
+        pub hook_parameter_value: Option<String>,
+    }
+}
+
+serde_with_tag! {
+    /// Allows another account's Hook to modify this Hook's state.
+    #[derive(Debug, PartialEq, Eq, Default, Clone, new)]
+    pub struct HookGrant {
+        /// The hash of the Hook allowed to modify the state.
+        pub hook_hash: String,
+        /// The account whose Hook is allowed, any account if omitted.
+        pub authorize: Option<String>,
+    }
+}
+
+serde_with_tag! {
+    /// One position in an account's Hook chain. An empty Hook leaves
+    /// the position unchanged.
+    ///
+    /// See Hooks Field:
+    /// `<https://xrpl-hooks.readme.io/docs/sethook-transaction>`
+    #[derive(Debug, PartialEq, Eq, Default, Clone, new)]
+    pub struct Hook {
+        /// The WebAssembly bytecode of the Hook, as hex. Empty to
+        /// delete the Hook at this position.
+        pub create_code: Option<String>,
+        /// The hash of a Hook already on the ledger, to install it
+        /// without its code.
+        pub hook_hash: Option<String>,
+        /// The transaction types the Hook is triggered by, as a
+        /// 256-bit hex bitmask with inverted bits for most types.
+        pub hook_on: Option<String>,
+        /// The 256-bit namespace of the Hook's state, as hex.
+        pub hook_namespace: Option<String>,
+        pub hook_api_version: Option<u16>,
+        pub hook_parameters: Option<Vec<HookParameter>>,
+        pub hook_grants: Option<Vec<HookGrant>>,
+        pub flags: Option<FlagCollection<HookFlag>>,
+    }
+}
+
+/// Install, update or delete the Hooks of an account. Xahau only.
+///
+/// See SetHook:
+/// `<https://xrpl-hooks.readme.io/docs/sethook-transaction>`
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "PascalCase")]
+pub struct SetHook<'a> {
+    // The base fields for all transaction models.
+    //
+    // See Transaction Types:
+    // `<https://xrpl.org/transaction-types.html>`
+    //
+    // See Transaction Common Fields:
+    // `<https://xrpl.org/transaction-common-fields.html>`
+    /// The type of transaction.
+    #[serde(flatten)]
+    pub common_fields: CommonFields<'a, NoFlags>,
+    // The custom fields for the SetHook model.
+    //
+    // See SetHook fields:
+    // `<https://xrpl-hooks.readme.io/docs/sethook-transaction>`
+    /// The Hook chain of the account, one entry per position.
+    pub hooks: Vec<Hook>,
+}
+
+impl<'a> Model for SetHook<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
+        self._get_hooks_error()?;
+
+        Ok(())
+    }
+}
+
+impl<'a> Transaction<'a, NoFlags> for SetHook<'a> {
+    fn get_transaction_type(&self) -> TransactionType {
+        self.common_fields.get_transaction_type()
+    }
+
+    fn get_common_fields(&self) -> &CommonFields<'_, NoFlags> {
+        self.common_fields.get_common_fields()
+    }
+
+    fn get_mut_common_fields(&mut self) -> &mut CommonFields<'a, NoFlags> {
+        self.common_fields.get_mut_common_fields()
+    }
+}
+
+impl<'a> SetHookError for SetHook<'a> {
+    fn _get_hooks_error(&self) -> XRPLModelResult<()> {
+        if self.hooks.is_empty() || self.hooks.len() > MAX_HOOKS {
+            return Err(XRPLSetHookException::HookCountOutOfRange {
+                max: MAX_HOOKS,
+                found: self.hooks.len(),
+            }
+            .into());
+        }
+        for hook in &self.hooks {
+            for (field, value) in [
+                ("hook_on", &hook.hook_on),
+                ("hook_namespace", &hook.hook_namespace),
+                ("hook_hash", &hook.hook_hash),
+            ] {
+                if let Some(value) = value {
+                    if value.len() != HASH256_HEX_LENGTH || hex::decode(value).is_err() {
+                        return Err(XRPLModelException::InvalidValueFormat {
+                            field: field.into(),
+                            format: "64 hex characters".into(),
+                            found: value.clone(),
+                        });
+                    }
+                }
+            }
+            if let Some(create_code) = &hook.create_code {
+                get_hex_error("create_code", create_code)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> SetHook<'a> {
+    pub fn new(
+        account: Cow<'a, str>,
+        account_txn_id: Option<Cow<'a, str>>,
+        fee: Option<XRPAmount<'a>>,
+        last_ledger_sequence: Option<u32>,
+        memos: Option<Vec<Memo>>,
+        sequence: Option<u32>,
+        signers: Option<Vec<Signer<'a>>>,
+        source_tag: Option<u32>,
+        ticket_sequence: Option<u32>,
+        hooks: Vec<Hook>,
+    ) -> Self {
+        Self {
+            common_fields: CommonFields {
+                account_txn_id,
+                fee,
+                last_ledger_sequence,
+                memos,
+                sequence,
+                signers,
+                source_tag,
+                ticket_sequence,
+                ..CommonFields::with_account(account, TransactionType::SetHook)
+            },
+            hooks,
+        }
+    }
+}
+
+pub trait SetHookError {
+    fn _get_hooks_error(&self) -> XRPLModelResult<()>;
+}
+
+#[cfg(test)]
+mod test_set_hook_error {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use super::*;
+
+    fn set_hook(hooks: Vec<Hook>) -> SetHook<'static> {
+        SetHook::new(
+            "rKxLZ6Y7Q8XX5YdC4zSCH1LK1kf1YM5tQb".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            hooks,
+        )
+    }
+
+    #[test]
+    fn test_hook_count_error() {
+        assert_eq!(
+            set_hook(vec![])
+                .validate()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "A SetHook transaction must have between 1 and 10 Hooks (found 0)"
+        );
+        assert!(set_hook(vec![Hook::default(); 10]).validate().is_ok());
+        assert_eq!(
+            set_hook(vec![Hook::default(); 11])
+                .validate()
+                .unwrap_err()
+                .to_string()
+                .as_str(),
+            "A SetHook transaction must have between 1 and 10 Hooks (found 11)"
+        );
+    }
+
+    #[test]
+    fn test_hook_hex_error() {
+        let mut set_hook = set_hook(vec![Hook {
+            hook_on: Some("F".repeat(63)),
+            ..Default::default()
+        }]);
+
+        assert_eq!(
+            set_hook.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `\"hook_on\"` does not have the correct format (expected \"64 hex characters\", found \"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF\")"
+        );
+
+        set_hook.hooks[0].hook_on = Some("F".repeat(64));
+        set_hook.hooks[0].hook_namespace = Some("Z".repeat(64));
+        assert!(set_hook.validate().is_err());
+
+        set_hook.hooks[0].hook_namespace = Some("0".repeat(64));
+        set_hook.hooks[0].create_code = Some("0061736D0".into());
+        assert!(set_hook.validate().is_err());
+
+        // An empty CreateCode deletes the Hook.
+        set_hook.hooks[0].create_code = Some("".into());
+        assert!(set_hook.validate().is_ok());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_serde() {
+        let json = serde_json::json!({
+            "Account": "rKxLZ6Y7Q8XX5YdC4zSCH1LK1kf1YM5tQb",
+            "TransactionType": "SetHook",
+            "Fee": "2000000",
+            "Flags": 0,
+            "Hooks": [
+                {
+                    "Hook": {
+                        "CreateCode": "0061736D01000000011C0460057F7F7F7F7F017E60037F7F7E017E60027F7F017F60017F017E02230303656E76057472616365000003656E7606616363657074000103656E76025F670002030201030503010002062B077F0141B088040B7F004180080B7F0041A2080B7F004180080B7F0041B088040B7F0041000B7F0041010B07080104686F6F6B00030AC4800001C0800001017F230041106B220124002001200036020C41920841134180084112410010001A41011002",
+                        "HookOn": "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFBFFFFE",
+                        "HookNamespace": "CAFECAFECAFECAFECAFECAFECAFECAFECAFECAFECAFECAFECAFECAFECAFECAFE",
+                        "HookApiVersion": 0,
+                        "HookParameters": [
+                            {
+                                "HookParameter": {
+                                    "HookParameterName": "414D4F554E54",
+                                    "HookParameterValue": "00E1F50500000000"
+                                }
+                            }
+                        ],
+                        "Flags": 1
+                    }
+                },
+                {
+                    "Hook": {}
+                },
+                {
+                    "Hook": {
+                        "HookHash": "A5B8D62154DA1C329BE13582086B52612476720CEBD097EB85CEE1455E1C70A6",
+                        "HookGrants": [
+                            {
+                                "HookGrant": {
+                                    "HookHash": "A5B8D62154DA1C329BE13582086B52612476720CEBD097EB85CEE1455E1C70A6",
+                                    "Authorize": "rwyypATD1dQxDbdQjMvrqnsHr2cQw5rjMh"
+                                }
+                            }
+                        ]
+                    }
+                }
+            ]
+        });
+
+        let set_hook: SetHook = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(set_hook.hooks.len(), 3);
+        assert!(set_hook.hooks[0]
+            .flags
+            .as_ref()
+            .unwrap()
+            .contains(&HookFlag::HsfOverride));
+        assert_eq!(
+            set_hook.hooks[0].hook_parameters,
+            Some(vec![HookParameter::new(
+                "414D4F554E54".into(),
+                Some("00E1F50500000000".into())
+            )])
+        );
+        assert_eq!(set_hook.hooks[1], Hook::default());
+        assert!(set_hook.validate().is_ok());
+
+        assert_eq!(serde_json::to_value(&set_hook).unwrap(), json);
+    }
+}
@@ -5,11 +5,11 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::amount::XRPAmount;
+use crate::models::NoFlags;
 use crate::models::{
     transactions::{Memo, Signer, Transaction, TransactionType},
-    Model,
+    Model, XRPLModelResult,
 };
-use crate::models::{FlagCollection, NoFlags};
 
 use super::CommonFields;
 
@@ -23,6 +23,7 @@ use super::CommonFields;
 /// `<https://xrpl.org/setregularkey.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct SetRegularKey<'a> {
     // The base fields for all transaction models.
@@ -45,7 +46,11 @@ pub struct SetRegularKey<'a> {
     pub regular_key: Option<Cow<'a, str>>,
 }
 
-impl<'a> Model for SetRegularKey<'a> {}
+impl<'a> Model for SetRegularKey<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for SetRegularKey<'a> {
     fn get_transaction_type(&self) -> TransactionType {
@@ -76,20 +81,15 @@ impl<'a> SetRegularKey<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::SetRegularKey,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::SetRegularKey)
             },
             regular_key,
         }
@@ -6,25 +6,27 @@ use derive_new::new;
 use serde::{ser::SerializeMap, Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::constants::{MAX_SIGNER_ENTRIES, WALLET_LOCATOR_LENGTH};
 use crate::models::transactions::exceptions::XRPLSignerListSetException;
-use crate::models::FlagCollection;
 use crate::models::NoFlags;
-use crate::models::XRPLModelResult;
 use crate::models::{
     amount::XRPAmount,
     transactions::{Memo, Signer, Transaction, TransactionType},
     Model,
 };
+use crate::models::{XRPLModelException, XRPLModelResult};
 use crate::serde_with_tag;
 
 use super::CommonFields;
 
 serde_with_tag! {
     #[derive(Debug, PartialEq, Eq, Default, Clone, new)]
-    #[skip_serializing_none]
     pub struct SignerEntry {
         pub account: String,
         pub signer_weight: u16,
+        /// Arbitrary 256-bit hexadecimal data, e.g. to identify the
+        /// signer. Requires the ExpandedSignerList amendment.
+        pub wallet_locator: Option<String>,
     }
 }
 
@@ -37,6 +39,7 @@ serde_with_tag! {
 /// `<https://xrpl.org/signerlistset.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct SignerListSet<'a> {
     // The base fields for all transaction models.
@@ -65,6 +68,7 @@ pub struct SignerListSet<'a> {
 
 impl<'a> Model for SignerListSet<'a> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         self._get_signer_entries_error()?;
         self._get_signer_quorum_error()?;
 
@@ -102,14 +106,28 @@ impl<'a> SignerListSetError for SignerListSet<'a> {
                     found: signer_entries.len(),
                 }
                 .into())
-            } else if signer_entries.len() > 8 {
+            } else if signer_entries.len() > MAX_SIGNER_ENTRIES {
                 Err(XRPLSignerListSetException::CollectionTooManyItems {
                     field: "signer_entries".into(),
-                    max: 8_usize,
+                    max: MAX_SIGNER_ENTRIES,
                     found: signer_entries.len(),
                 }
                 .into())
             } else {
+                for signer_entry in signer_entries {
+                    if let Some(wallet_locator) = &signer_entry.wallet_locator {
+                        if wallet_locator.len() != WALLET_LOCATOR_LENGTH
+                            || hex::decode(wallet_locator).is_err()
+                        {
+                            return Err(XRPLModelException::InvalidValueFormat {
+                                field: "wallet_locator".into(),
+                                format: "64 hex characters".into(),
+                                found: wallet_locator.clone(),
+                            });
+                        }
+                    }
+                }
+
                 Ok(())
             }
         } else {
@@ -120,8 +138,8 @@ impl<'a> SignerListSetError for SignerListSet<'a> {
     fn _get_signer_quorum_error(&self) -> XRPLModelResult<()> {
         let mut accounts = Vec::new();
         let mut signer_weight_sum: u32 = 0;
-        if self.signer_entries.is_some() {
-            for signer_entry in self.signer_entries.as_ref().unwrap() {
+        if let Some(signer_entries) = &self.signer_entries {
+            for signer_entry in signer_entries {
                 accounts.push(signer_entry.account.clone());
                 let weight: u32 = signer_entry.signer_weight.into();
                 signer_weight_sum += weight;
@@ -133,7 +151,7 @@ impl<'a> SignerListSetError for SignerListSet<'a> {
             if check_account.contains(&account) {
                 return Err(XRPLSignerListSetException::CollectionItemDuplicate {
                     field: "signer_entries".into(),
-                    found: account.into(),
+                    found: account,
                 }
                 .into());
             } else {
@@ -187,20 +205,15 @@ impl<'a> SignerListSet<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::SignerListSet,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::SignerListSet)
             },
             signer_quorum,
             signer_entries,
@@ -238,6 +251,7 @@ mod test_signer_list_set_error {
             Some(vec![SignerEntry {
                 account: "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(),
                 signer_weight: 2,
+                wallet_locator: None,
             }]),
         );
 
@@ -277,61 +291,34 @@ mod test_signer_list_set_error {
         );
 
         signer_list_set.signer_entries = Some(vec![
-            SignerEntry {
-                account: "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(),
-                signer_weight: 1,
-            },
-            SignerEntry {
-                account: "rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v".to_string(),
-                signer_weight: 1,
-            },
-            SignerEntry {
-                account: "rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v".to_string(),
-                signer_weight: 2,
-            },
-            SignerEntry {
-                account: "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".to_string(),
-                signer_weight: 2,
-            },
-            SignerEntry {
-                account: "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".to_string(),
-                signer_weight: 1,
-            },
-            SignerEntry {
-                account: "rXTZ5g8X7mrAYEe7iFeM9fiS4ccueyurG".to_string(),
-                signer_weight: 1,
-            },
-            SignerEntry {
-                account: "rPbMHxs7vy5t6e19tYfqG7XJ6Fog8EPZLk".to_string(),
-                signer_weight: 2,
-            },
-            SignerEntry {
-                account: "r3rhWeE31Jt5sWmi4QiGLMZnY3ENgqw96W".to_string(),
-                signer_weight: 3,
-            },
-            SignerEntry {
-                account: "rchGBxcD1A1C2tdxF6papQYZ8kjRKMYcL".to_string(),
-                signer_weight: 2,
-            },
+            SignerEntry::new(
+                "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(),
+                1,
+                None
+            );
+            33
         ]);
 
         assert_eq!(
             signer_list_set.validate().unwrap_err().to_string().as_str(),
-            "The value of the field `\"signer_entries\"` has too many items in it (max 8, found 9)"
+            "The value of the field `\"signer_entries\"` has too many items in it (max 32, found 33)"
         );
 
         signer_list_set.signer_entries = Some(vec![
             SignerEntry {
                 account: "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".to_string(),
                 signer_weight: 1,
+                wallet_locator: None,
             },
             SignerEntry {
                 account: "rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v".to_string(),
                 signer_weight: 2,
+                wallet_locator: None,
             },
             SignerEntry {
                 account: "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".to_string(),
                 signer_weight: 2,
+                wallet_locator: None,
             },
         ]);
 
@@ -343,22 +330,25 @@ mod test_signer_list_set_error {
         signer_list_set.signer_entries = Some(vec![SignerEntry {
             account: "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(),
             signer_weight: 3,
+            wallet_locator: None,
         }]);
         signer_list_set.signer_quorum = 10;
 
         assert_eq!(
             signer_list_set.validate().unwrap_err().to_string().as_str(),
-            "The field `signer_quorum` must be below or equal to the sum of `signer_weight` in `signer_entries`"
+            "The field `signer_quorum` must be below or equal to the sum of `signer_weight` in `signer_entries` (max 3, found 10)"
         );
 
         signer_list_set.signer_entries = Some(vec![
             SignerEntry {
                 account: "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(),
                 signer_weight: 3,
+                wallet_locator: None,
             },
             SignerEntry {
                 account: "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(),
                 signer_weight: 2,
+                wallet_locator: None,
             },
         ]);
         signer_list_set.signer_quorum = 2;
@@ -368,6 +358,60 @@ mod test_signer_list_set_error {
             "The value of the field `\"signer_entries\"` has a duplicate in it (found \"rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW\")"
         );
     }
+
+    #[test]
+    fn test_signer_quorum_equal_to_weight_sum() {
+        let signer_list_set = SignerListSet::new(
+            "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            3,
+            Some(vec![
+                SignerEntry::new("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(), 2, None),
+                SignerEntry::new("rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v".to_string(), 1, None),
+            ]),
+        );
+
+        assert!(signer_list_set.validate().is_ok());
+    }
+
+    #[test]
+    fn test_wallet_locator_error() {
+        let mut signer_list_set = SignerListSet::new(
+            "rU4EE1FskCPJw5QkLx1iGgdWiJa6HeqYyb".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            1,
+            Some(vec![SignerEntry::new(
+                "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(),
+                1,
+                Some("ABCD".to_string()),
+            )]),
+        );
+
+        assert_eq!(
+            signer_list_set.validate().unwrap_err().to_string().as_str(),
+            "The value of the field `\"wallet_locator\"` does not have the correct format (expected \"64 hex characters\", found \"ABCD\")"
+        );
+
+        signer_list_set.signer_entries.as_mut().unwrap()[0].wallet_locator = Some("Z".repeat(64));
+        assert!(signer_list_set.validate().is_err());
+
+        signer_list_set.signer_entries.as_mut().unwrap()[0].wallet_locator = Some("0".repeat(64));
+        assert!(signer_list_set.validate().is_ok());
+    }
 }
 
 #[cfg(test)]
@@ -391,9 +435,9 @@ mod tests {
             None,
             3,
             Some(vec![
-                SignerEntry::new("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(), 2),
-                SignerEntry::new("rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v".to_string(), 1),
-                SignerEntry::new("raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n".to_string(), 1),
+                SignerEntry::new("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(), 2, None),
+                SignerEntry::new("rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v".to_string(), 1, None),
+                SignerEntry::new("raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n".to_string(), 1, None),
             ]),
         );
         let default_json_str = r#"{"Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","TransactionType":"SignerListSet","Fee":"12","Flags":0,"SignerQuorum":3,"SignerEntries":[{"SignerEntry":{"Account":"rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW","SignerWeight":2}},{"SignerEntry":{"Account":"rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v","SignerWeight":1}},{"SignerEntry":{"Account":"raKEEVSGnKSD9Zyvxu4z6Pqpm4ABH8FS6n","SignerWeight":1}}]}"#;
@@ -407,4 +451,68 @@ mod tests {
         let deserialized: SignerListSet = serde_json::from_str(default_json_str).unwrap();
         assert_eq!(default_txn, deserialized);
     }
+
+    #[test]
+    fn test_serde_bare_signer_entries() {
+        // Signer entries without their `SignerEntry` wrapper, as other
+        // libraries write them.
+        let json = serde_json::json!({
+            "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "TransactionType": "SignerListSet",
+            "Fee": "12",
+            "Flags": 0,
+            "SignerQuorum": 3,
+            "SignerEntries": [
+                {"Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW", "SignerWeight": 2},
+                {"SignerEntry": {"Account": "rUpy3eEg8rqjqfUoLeBnZkscbKbFsKXC3v", "SignerWeight": 1}}
+            ]
+        });
+        let txn: SignerListSet = serde_json::from_value(json).unwrap();
+
+        let signer_entries = txn.signer_entries.as_ref().unwrap();
+        assert_eq!(
+            signer_entries[0],
+            SignerEntry::new("rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(), 2, None)
+        );
+        let serialized = serde_json::to_value(&txn).unwrap();
+        assert_eq!(
+            serialized["SignerEntries"][0],
+            serde_json::json!({"SignerEntry": {"Account": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW", "SignerWeight": 2}})
+        );
+        assert_eq!(
+            crate::core::binarycodec::encode(&txn).unwrap(),
+            crate::core::binarycodec::encode(&serialized).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_serde_wallet_locator() {
+        let wallet_locator =
+            "03075791D76F0E0A7B5E6AA6E2B23D8B0E2A1CD5D4B4A0F1AB1B3B5E3C3D3F1F".to_string();
+        let txn = SignerListSet::new(
+            "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn".into(),
+            None,
+            Some("12".into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            1,
+            Some(vec![SignerEntry::new(
+                "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW".to_string(),
+                1,
+                Some(wallet_locator.clone()),
+            )]),
+        );
+        let json_str = r#"{"Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","TransactionType":"SignerListSet","Fee":"12","Flags":0,"SignerQuorum":1,"SignerEntries":[{"SignerEntry":{"Account":"rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW","SignerWeight":1,"WalletLocator":"03075791D76F0E0A7B5E6AA6E2B23D8B0E2A1CD5D4B4A0F1AB1B3B5E3C3D3F1F"}}]}"#;
+
+        assert_eq!(serde_json::to_string(&txn).unwrap(), json_str);
+        let deserialized: SignerListSet = serde_json::from_str(json_str).unwrap();
+        assert_eq!(deserialized, txn);
+        assert!(crate::core::binarycodec::encode(&txn)
+            .unwrap()
+            .contains(&wallet_locator));
+    }
 }
@@ -4,11 +4,11 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::amount::XRPAmount;
+use crate::models::NoFlags;
 use crate::models::{
     transactions::{Memo, Signer, Transaction, TransactionType},
-    Model,
+    Model, XRPLModelResult,
 };
-use crate::models::{FlagCollection, NoFlags};
 
 use super::CommonFields;
 
@@ -18,6 +18,7 @@ use super::CommonFields;
 /// `<https://xrpl.org/ticketcreate.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct TicketCreate<'a> {
     // The base fields for all transaction models.
@@ -39,7 +40,11 @@ pub struct TicketCreate<'a> {
     pub ticket_count: u32,
 }
 
-impl<'a> Model for TicketCreate<'a> {}
+impl<'a> Model for TicketCreate<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for TicketCreate<'a> {
     fn get_transaction_type(&self) -> TransactionType {
@@ -70,20 +75,15 @@ impl<'a> TicketCreate<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::TicketCreate,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::TicketCreate)
             },
             ticket_count,
         }
@@ -8,12 +8,12 @@ use strum_macros::{AsRefStr, Display, EnumIter};
 
 use crate::models::{
     transactions::{Memo, Signer, Transaction, TransactionType},
-    Model,
+    Model, XRPLModelResult,
 };
 
 use crate::models::amount::{IssuedCurrencyAmount, XRPAmount};
 
-use super::{CommonFields, FlagCollection};
+use super::{builder::transaction_builder, CommonFields, FlagCollection};
 
 /// Transactions of the TrustSet type support additional values
 /// in the Flags field. This enum represents those options.
@@ -44,6 +44,7 @@ pub enum TrustSetFlag {
 /// `<https://xrpl.org/trustset.html>`
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct TrustSet<'a> {
     // The base fields for all transaction models.
@@ -70,13 +71,13 @@ pub struct TrustSet<'a> {
     pub quality_out: Option<u32>,
 }
 
-impl<'a> Model for TrustSet<'a> {}
-
-impl<'a> Transaction<'a, TrustSetFlag> for TrustSet<'a> {
-    fn has_flag(&self, flag: &TrustSetFlag) -> bool {
-        self.common_fields.has_flag(flag)
+impl<'a> Model for TrustSet<'a> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
     }
+}
 
+impl<'a> Transaction<'a, TrustSetFlag> for TrustSet<'a> {
     fn get_transaction_type(&self) -> TransactionType {
         self.common_fields.get_transaction_type()
     }
@@ -108,8 +109,6 @@ impl<'a> TrustSet<'a> {
     ) -> Self {
         Self {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::TrustSet,
                 account_txn_id,
                 fee,
                 flags: flags.unwrap_or_default(),
@@ -119,9 +118,7 @@ impl<'a> TrustSet<'a> {
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::TrustSet)
             },
             limit_amount,
             quality_in,
@@ -130,6 +127,13 @@ impl<'a> TrustSet<'a> {
     }
 }
 
+transaction_builder! {
+    TrustSet<TrustSetFlag> => TrustSetBuilder {
+        required limit_amount: IssuedCurrencyAmount<'a>;
+        values quality_in: u32, quality_out: u32;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,4 +171,16 @@ mod tests {
         let deserialized: TrustSet = serde_json::from_str(default_json_str).unwrap();
         assert_eq!(default_txn, deserialized);
     }
+
+    #[test]
+    fn test_has_flag() {
+        let trust_set: TrustSet = serde_json::from_str(
+            r#"{"Account":"rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn","TransactionType":"TrustSet","Flags":1310720,"LimitAmount":{"currency":"USD","issuer":"rsP3mgGb2tcYUrxiLFiHJiQXhsziegtwBc","value":"100"}}"#,
+        )
+        .unwrap();
+        assert!(trust_set.has_flag(&TrustSetFlag::TfClearNoRipple));
+        assert!(trust_set.has_flag(&TrustSetFlag::TfSetFreeze));
+        assert!(!trust_set.has_flag(&TrustSetFlag::TfSetNoRipple));
+        assert!(!trust_set.has_flag(&TrustSetFlag::TfClearFreeze));
+    }
 }
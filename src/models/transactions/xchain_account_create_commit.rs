@@ -4,12 +4,13 @@ use alloc::{borrow::Cow, vec::Vec};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::models::{Amount, FlagCollection, Model, NoFlags, XChainBridge, XRPAmount};
+use crate::models::{Amount, Model, NoFlags, XChainBridge, XRPAmount, XRPLModelResult};
 
 use super::{CommonFields, Memo, Signer, Transaction, TransactionType};
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct XChainAccountCreateCommit<'a> {
     #[serde(flatten)]
@@ -21,7 +22,11 @@ pub struct XChainAccountCreateCommit<'a> {
     pub signature_reward: Option<Amount<'a>>,
 }
 
-impl Model for XChainAccountCreateCommit<'_> {}
+impl Model for XChainAccountCreateCommit<'_> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for XChainAccountCreateCommit<'a> {
     fn get_transaction_type(&self) -> super::TransactionType {
@@ -55,20 +60,15 @@ impl<'a> XChainAccountCreateCommit<'a> {
     ) -> XChainAccountCreateCommit<'a> {
         XChainAccountCreateCommit {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::XChainAccountCreateCommit,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::XChainAccountCreateCommit)
             },
             amount,
             destination,
@@ -2,12 +2,13 @@ use alloc::{borrow::Cow, vec::Vec};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::models::{Amount, FlagCollection, Model, NoFlags, XChainBridge, XRPAmount};
+use crate::models::{Amount, Model, NoFlags, XChainBridge, XRPAmount, XRPLModelResult};
 
 use super::{CommonFields, Memo, Signer, Transaction, TransactionType};
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct XChainAddAccountCreateAttestation<'a> {
     #[serde(flatten)]
@@ -27,7 +28,11 @@ pub struct XChainAddAccountCreateAttestation<'a> {
     pub xchain_bridge: XChainBridge<'a>,
 }
 
-impl Model for XChainAddAccountCreateAttestation<'_> {}
+impl Model for XChainAddAccountCreateAttestation<'_> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for XChainAddAccountCreateAttestation<'a> {
     fn get_transaction_type(&self) -> super::TransactionType {
@@ -68,20 +73,18 @@ impl<'a> XChainAddAccountCreateAttestation<'a> {
     ) -> XChainAddAccountCreateAttestation<'a> {
         XChainAddAccountCreateAttestation {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::XChainAddAccountCreateAttestation,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(
+                    account,
+                    TransactionType::XChainAddAccountCreateAttestation,
+                )
             },
             amount,
             attestation_reward_account,
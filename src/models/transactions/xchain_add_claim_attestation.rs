@@ -2,12 +2,13 @@ use alloc::{borrow::Cow, vec::Vec};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::models::{Amount, FlagCollection, Model, NoFlags, XChainBridge};
+use crate::models::{Amount, Model, NoFlags, XChainBridge, XRPLModelResult};
 
 use super::{CommonFields, Transaction, TransactionType};
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct XChainAddClaimAttestation<'a> {
     #[serde(flatten)]
@@ -26,7 +27,11 @@ pub struct XChainAddClaimAttestation<'a> {
     pub destination: Option<Cow<'a, str>>,
 }
 
-impl Model for XChainAddClaimAttestation<'_> {}
+impl Model for XChainAddClaimAttestation<'_> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for XChainAddClaimAttestation<'a> {
     fn get_transaction_type(&self) -> super::TransactionType {
@@ -66,20 +71,15 @@ impl<'a> XChainAddClaimAttestation<'a> {
     ) -> XChainAddClaimAttestation<'a> {
         XChainAddClaimAttestation {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::XChainAddClaimAttestation,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::XChainAddClaimAttestation)
             },
             amount,
             attestation_reward_account,
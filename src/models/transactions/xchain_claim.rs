@@ -3,14 +3,15 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::{
-    transactions::exceptions::XRPLXChainClaimException, Amount, Currency, FlagCollection, Model,
-    NoFlags, XChainBridge, XRPLModelResult,
+    transactions::exceptions::XRPLXChainClaimException, Amount, Currency, Model, NoFlags,
+    XChainBridge, XRPLModelResult,
 };
 
 use super::{CommonFields, Memo, Signer, Transaction, TransactionType};
 
 #[skip_serializing_none]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct XChainClaim<'a> {
     #[serde(flatten)]
@@ -27,6 +28,7 @@ pub struct XChainClaim<'a> {
 
 impl Model for XChainClaim<'_> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         self.get_amount_mismatch_error()
     }
 }
@@ -64,8 +66,6 @@ impl<'a> XChainClaim<'a> {
     ) -> XChainClaim<'a> {
         XChainClaim {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::XChainClaim,
                 account_txn_id,
                 fee,
                 last_ledger_sequence,
@@ -74,10 +74,7 @@ impl<'a> XChainClaim<'a> {
                 signers,
                 source_tag,
                 ticket_sequence,
-                flags: FlagCollection::default(),
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::XChainClaim)
             },
             amount,
             destination,
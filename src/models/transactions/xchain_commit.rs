@@ -2,12 +2,13 @@ use alloc::{borrow::Cow, vec::Vec};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::models::{Amount, FlagCollection, Model, NoFlags, XChainBridge, XRPAmount};
+use crate::models::{Amount, Model, NoFlags, XChainBridge, XRPAmount, XRPLModelResult};
 
 use super::{CommonFields, Memo, Signer, Transaction, TransactionType};
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct XChainCommit<'a> {
     #[serde(flatten)]
@@ -20,7 +21,11 @@ pub struct XChainCommit<'a> {
     pub other_chain_destination: Option<Cow<'a, str>>,
 }
 
-impl Model for XChainCommit<'_> {}
+impl Model for XChainCommit<'_> {
+    fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()
+    }
+}
 
 impl<'a> Transaction<'a, NoFlags> for XChainCommit<'a> {
     fn get_common_fields(&self) -> &CommonFields<'_, NoFlags> {
@@ -54,8 +59,6 @@ impl<'a> XChainCommit<'a> {
     ) -> XChainCommit<'a> {
         XChainCommit {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::XChainCommit,
                 account_txn_id,
                 fee,
                 last_ledger_sequence,
@@ -64,10 +67,7 @@ impl<'a> XChainCommit<'a> {
                 signers,
                 source_tag,
                 ticket_sequence,
-                flags: FlagCollection::default(),
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::XChainCommit)
             },
             amount,
             other_chain_destination,
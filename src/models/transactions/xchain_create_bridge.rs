@@ -3,14 +3,15 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::models::{
-    transactions::exceptions::XRPLXChainCreateBridgeException, Amount, FlagCollection, Model,
-    NoFlags, XChainBridge, XRPAmount, XRPLModelResult, XRP,
+    transactions::exceptions::XRPLXChainCreateBridgeException, Amount, Model, NoFlags,
+    XChainBridge, XRPAmount, XRPLModelResult, XRP,
 };
 
 use super::{CommonFields, Memo, Signer, Transaction, TransactionType};
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct XChainCreateBridge<'a> {
     #[serde(flatten)]
@@ -23,6 +24,7 @@ pub struct XChainCreateBridge<'a> {
 
 impl Model for XChainCreateBridge<'_> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         self.get_same_door_error()?;
         self.get_account_door_mismatch_error()?;
         self.get_cross_currency_bridge_not_allowed_error()?;
@@ -61,20 +63,15 @@ impl<'a> XChainCreateBridge<'a> {
     ) -> XChainCreateBridge<'a> {
         XChainCreateBridge {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::XChainCreateBridge,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::XChainCreateBridge)
             },
             signature_reward,
             xchain_bridge,
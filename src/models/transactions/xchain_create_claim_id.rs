@@ -5,15 +5,16 @@ use serde_with::skip_serializing_none;
 use crate::{
     core::addresscodec::is_valid_classic_address,
     models::{
-        transactions::exceptions::XRPLXChainCreateClaimIDException, FlagCollection, Model, NoFlags,
-        XChainBridge, XRPAmount, XRPLModelResult,
+        transactions::exceptions::XRPLXChainCreateClaimIDException, Model, NoFlags, XChainBridge,
+        XRPAmount, XRPLModelResult,
     },
 };
 
 use super::{CommonFields, Memo, Signer, Transaction, TransactionType};
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct XChainCreateClaimID<'a> {
     #[serde(flatten)]
@@ -26,6 +27,7 @@ pub struct XChainCreateClaimID<'a> {
 
 impl Model for XChainCreateClaimID<'_> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         self.get_other_chain_source_is_invalid_error()
     }
 }
@@ -61,20 +63,15 @@ impl<'a> XChainCreateClaimID<'a> {
     ) -> XChainCreateClaimID<'a> {
         XChainCreateClaimID {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::XChainCreateClaimID,
                 account_txn_id,
                 fee,
-                flags: FlagCollection::default(),
                 last_ledger_sequence,
                 memos,
                 sequence,
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::XChainCreateClaimID)
             },
             other_chain_source,
             signature_reward,
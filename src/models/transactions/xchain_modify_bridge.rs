@@ -21,7 +21,8 @@ pub enum XChainModifyBridgeFlags {
 }
 
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "PascalCase")]
 pub struct XChainModifyBridge<'a> {
     #[serde(flatten)]
@@ -34,6 +35,7 @@ pub struct XChainModifyBridge<'a> {
 
 impl Model for XChainModifyBridge<'_> {
     fn get_errors(&self) -> XRPLModelResult<()> {
+        self.common_fields.get_errors()?;
         self.get_must_change_or_clear_error()?;
         self.get_account_door_mismatch_error()?;
         self.get_cannot_have_min_account_create_amount()?;
@@ -73,8 +75,6 @@ impl<'a> XChainModifyBridge<'a> {
     ) -> XChainModifyBridge<'a> {
         XChainModifyBridge {
             common_fields: CommonFields {
-                account,
-                transaction_type: TransactionType::XChainModifyBridge,
                 account_txn_id,
                 fee,
                 flags: flags.unwrap_or_default(),
@@ -84,9 +84,7 @@ impl<'a> XChainModifyBridge<'a> {
                 signers,
                 source_tag,
                 ticket_sequence,
-                network_id: None,
-                signing_pub_key: None,
-                txn_signature: None,
+                ..CommonFields::with_account(account, TransactionType::XChainModifyBridge)
             },
             xchain_bridge,
             min_account_create_amount,
@@ -127,3 +125,42 @@ impl<'a> XChainModifyBridge<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn modify_bridge(flags: u32) -> XChainModifyBridge<'static> {
+        serde_json::from_str(&alloc::format!(
+            r#"{{
+                "Account": "rwEqJ2UaQHe7jihxGqmx6J4xdbGiiyMaGa",
+                "TransactionType": "XChainModifyBridge",
+                "Flags": {},
+                "XChainBridge": {{
+                    "LockingChainDoor": "rMAXACCrp3Y8PpswXcg3bKggHX76V3F8M4",
+                    "LockingChainIssue": {{
+                        "currency": "XRP"
+                    }},
+                    "IssuingChainDoor": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+                    "IssuingChainIssue": {{
+                        "currency": "XRP"
+                    }}
+                }}
+            }}"#,
+            flags
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_has_flag() {
+        assert!(modify_bridge(65536).has_flag(&XChainModifyBridgeFlags::TfClearAccountCreateAmount));
+        assert!(!modify_bridge(0).has_flag(&XChainModifyBridgeFlags::TfClearAccountCreateAmount));
+    }
+
+    #[test]
+    fn test_clear_account_create_amount() {
+        assert!(modify_bridge(65536).get_errors().is_ok());
+        assert!(modify_bridge(0).get_errors().is_err());
+    }
+}
@@ -0,0 +1,1232 @@
+use core::convert::TryFrom;
+
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use serde_with::skip_serializing_none;
+
+use crate::models::{Model, XRPAmount, XRPLModelException, XRPLModelResult};
+
+use super::{exceptions::XRPLTransactionException, CommonFields, Memo, Signer, TransactionType};
+
+/// The fields common to all transactions, whatever their type. The
+/// flags are their numeric `Flags` value, as what they mean depends
+/// on the type.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "PascalCase")]
+pub struct UntypedCommonFields<'a> {
+    pub account: Cow<'a, str>,
+    pub transaction_type: Cow<'a, str>,
+    #[serde(rename = "AccountTxnID")]
+    pub account_txn_id: Option<Cow<'a, str>>,
+    pub fee: Option<XRPAmount<'a>>,
+    #[serde(default)]
+    pub flags: u32,
+    pub last_ledger_sequence: Option<u32>,
+    pub memos: Option<Vec<Memo>>,
+    #[serde(rename = "NetworkID")]
+    pub network_id: Option<u32>,
+    pub sequence: Option<u32>,
+    pub signers: Option<Vec<Signer<'a>>>,
+    pub signing_pub_key: Option<Cow<'a, str>>,
+    pub source_tag: Option<u32>,
+    pub ticket_sequence: Option<u32>,
+    pub txn_signature: Option<Cow<'a, str>>,
+}
+
+impl<'a, F> From<&CommonFields<'a, F>> for UntypedCommonFields<'a>
+where
+    F: strum::IntoEnumIterator + Serialize + core::fmt::Debug,
+{
+    fn from(common_fields: &CommonFields<'a, F>) -> Self {
+        Self {
+            account: common_fields.account.clone(),
+            transaction_type: common_fields.transaction_type.to_string().into(),
+            account_txn_id: common_fields.account_txn_id.clone(),
+            fee: common_fields.fee.clone(),
+            flags: common_fields.flags.bits(),
+            last_ledger_sequence: common_fields.last_ledger_sequence,
+            memos: common_fields.memos.clone(),
+            network_id: common_fields.network_id,
+            sequence: common_fields.sequence,
+            signers: common_fields.signers.clone(),
+            signing_pub_key: common_fields.signing_pub_key.clone(),
+            source_tag: common_fields.source_tag,
+            ticket_sequence: common_fields.ticket_sequence,
+            txn_signature: common_fields.txn_signature.clone(),
+        }
+    }
+}
+
+macro_rules! xrpl_transaction {
+    ($($(#[$attr:meta])* $variant:ident($transaction:ty),)+) => {
+        /// Any transaction, typed according to its `TransactionType`.
+        /// A transaction of a type this library has no model for is
+        /// kept as JSON in `Other` rather than failing.
+        ///
+        /// # Examples
+        ///
+        /// ## Basic usage
+        ///
+        /// ```
+        /// use core::convert::TryInto;
+        /// use xrpl::models::transactions::{payment::Payment, XRPLTransaction};
+        ///
+        /// let transaction: XRPLTransaction = serde_json::from_str(r#"{
+        ///     "TransactionType": "Payment",
+        ///     "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+        ///     "Destination": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+        ///     "Amount": "1000000",
+        ///     "Fee": "12",
+        ///     "Sequence": 2
+        /// }"#).unwrap();
+        /// assert_eq!(transaction.common_fields().unwrap().sequence, Some(2));
+        ///
+        /// let payment: Payment = transaction.try_into().unwrap();
+        /// assert_eq!(payment.destination, "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX");
+        /// ```
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[non_exhaustive]
+        pub enum XRPLTransaction<'a> {
+            $($(#[$attr])* $variant($transaction),)+
+            Other(Value),
+        }
+
+        impl<'a> XRPLTransaction<'a> {
+            /// The type of the transaction, or `None` for a type this
+            /// library has no model for.
+            pub fn transaction_type(&self) -> Option<TransactionType> {
+                match self {
+                    $($(#[$attr])* XRPLTransaction::$variant(_) => Some(TransactionType::$variant),)+
+                    XRPLTransaction::Other(_) => None,
+                }
+            }
+
+            /// The fields common to all transactions. Fails only for an
+            /// `Other` transaction that lacks them.
+            pub fn common_fields(&self) -> XRPLModelResult<UntypedCommonFields<'a>> {
+                match self {
+                    $($(#[$attr])* XRPLTransaction::$variant(transaction) => {
+                        Ok(UntypedCommonFields::from(&transaction.common_fields))
+                    })+
+                    XRPLTransaction::Other(value) => Ok(serde_json::from_value(value.clone())?),
+                }
+            }
+
+            fn from_value(value: Value) -> Result<Self, serde_json::Error> {
+                let transaction_type = match value.get("TransactionType") {
+                    Some(Value::String(transaction_type)) => transaction_type.clone(),
+                    _ => return Err(serde::de::Error::missing_field("TransactionType")),
+                };
+                match transaction_type.as_str() {
+                    $($(#[$attr])* stringify!($variant) => {
+                        Ok(XRPLTransaction::$variant(serde_json::from_value(value)?))
+                    })+
+                    _ => Ok(XRPLTransaction::Other(value)),
+                }
+            }
+        }
+
+        impl Serialize for XRPLTransaction<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match self {
+                    $($(#[$attr])* XRPLTransaction::$variant(transaction) => {
+                        transaction.serialize(serializer)
+                    })+
+                    XRPLTransaction::Other(value) => value.serialize(serializer),
+                }
+            }
+        }
+
+        impl Model for XRPLTransaction<'_> {
+            fn get_errors(&self) -> XRPLModelResult<()> {
+                match self {
+                    $($(#[$attr])* XRPLTransaction::$variant(transaction) => transaction.get_errors(),)+
+                    XRPLTransaction::Other(_) => Ok(()),
+                }
+            }
+        }
+
+        $(
+            $(#[$attr])*
+            impl<'a> From<$transaction> for XRPLTransaction<'a> {
+                fn from(transaction: $transaction) -> Self {
+                    XRPLTransaction::$variant(transaction)
+                }
+            }
+
+            $(#[$attr])*
+            impl<'a> TryFrom<XRPLTransaction<'a>> for $transaction {
+                type Error = XRPLModelException;
+
+                fn try_from(transaction: XRPLTransaction<'a>) -> XRPLModelResult<Self> {
+                    match transaction {
+                        XRPLTransaction::$variant(transaction) => Ok(transaction),
+                        transaction => Err(XRPLTransactionException::UnexpectedTransactionType {
+                            expected: stringify!($variant).to_string(),
+                            found: transaction.transaction_type_name(),
+                        }
+                        .into()),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+xrpl_transaction! {
+    AccountDelete(super::account_delete::AccountDelete<'a>),
+    AccountSet(super::account_set::AccountSet<'a>),
+    AMMBid(super::amm_bid::AMMBid<'a>),
+    AMMCreate(super::amm_create::AMMCreate<'a>),
+    AMMDelete(super::amm_delete::AMMDelete<'a>),
+    AMMDeposit(super::amm_deposit::AMMDeposit<'a>),
+    AMMVote(super::amm_vote::AMMVote<'a>),
+    AMMWithdraw(super::amm_withdraw::AMMWithdraw<'a>),
+    CheckCancel(super::check_cancel::CheckCancel<'a>),
+    CheckCash(super::check_cash::CheckCash<'a>),
+    CheckCreate(super::check_create::CheckCreate<'a>),
+    DepositPreauth(super::deposit_preauth::DepositPreauth<'a>),
+    EscrowCancel(super::escrow_cancel::EscrowCancel<'a>),
+    EscrowCreate(super::escrow_create::EscrowCreate<'a>),
+    EscrowFinish(super::escrow_finish::EscrowFinish<'a>),
+    NFTokenAcceptOffer(super::nftoken_accept_offer::NFTokenAcceptOffer<'a>),
+    NFTokenBurn(super::nftoken_burn::NFTokenBurn<'a>),
+    NFTokenCancelOffer(super::nftoken_cancel_offer::NFTokenCancelOffer<'a>),
+    NFTokenCreateOffer(super::nftoken_create_offer::NFTokenCreateOffer<'a>),
+    NFTokenMint(super::nftoken_mint::NFTokenMint<'a>),
+    OfferCancel(super::offer_cancel::OfferCancel<'a>),
+    OfferCreate(super::offer_create::OfferCreate<'a>),
+    Payment(super::payment::Payment<'a>),
+    PaymentChannelClaim(super::payment_channel_claim::PaymentChannelClaim<'a>),
+    PaymentChannelCreate(super::payment_channel_create::PaymentChannelCreate<'a>),
+    PaymentChannelFund(super::payment_channel_fund::PaymentChannelFund<'a>),
+    #[cfg(feature = "xahau")]
+    SetHook(super::set_hook::SetHook<'a>),
+    SetRegularKey(super::set_regular_key::SetRegularKey<'a>),
+    SignerListSet(super::signer_list_set::SignerListSet<'a>),
+    TicketCreate(super::ticket_create::TicketCreate<'a>),
+    TrustSet(super::trust_set::TrustSet<'a>),
+    XChainAccountCreateCommit(super::xchain_account_create_commit::XChainAccountCreateCommit<'a>),
+    XChainAddAccountCreateAttestation(
+        super::xchain_add_account_create_attestation::XChainAddAccountCreateAttestation<'a>
+    ),
+    XChainAddClaimAttestation(super::xchain_add_claim_attestation::XChainAddClaimAttestation<'a>),
+    XChainClaim(super::xchain_claim::XChainClaim<'a>),
+    XChainCommit(super::xchain_commit::XChainCommit<'a>),
+    XChainCreateBridge(super::xchain_create_bridge::XChainCreateBridge<'a>),
+    XChainCreateClaimID(super::xchain_create_claim_id::XChainCreateClaimID<'a>),
+    XChainModifyBridge(super::xchain_modify_bridge::XChainModifyBridge<'a>),
+    EnableAmendment(super::pseudo_transactions::EnableAmendment<'a>),
+    SetFee(super::pseudo_transactions::SetFee<'a>),
+    UNLModify(super::pseudo_transactions::UNLModify<'a>),
+}
+
+impl XRPLTransaction<'_> {
+    /// The `Destination` of the transaction, for the types that have
+    /// one, e.g. a Payment or an EscrowCreate.
+    pub fn destination(&self) -> Option<&str> {
+        match self {
+            XRPLTransaction::AccountDelete(transaction) => Some(&transaction.destination),
+            XRPLTransaction::CheckCreate(transaction) => Some(&transaction.destination),
+            XRPLTransaction::EscrowCreate(transaction) => Some(&transaction.destination),
+            XRPLTransaction::NFTokenCreateOffer(transaction) => transaction.destination.as_deref(),
+            XRPLTransaction::Payment(transaction) => Some(&transaction.destination),
+            XRPLTransaction::PaymentChannelCreate(transaction) => Some(&transaction.destination),
+            XRPLTransaction::XChainAccountCreateCommit(transaction) => {
+                Some(&transaction.destination)
+            }
+            XRPLTransaction::XChainAddAccountCreateAttestation(transaction) => {
+                Some(&transaction.destination)
+            }
+            XRPLTransaction::XChainAddClaimAttestation(transaction) => {
+                transaction.destination.as_deref()
+            }
+            XRPLTransaction::XChainClaim(transaction) => Some(&transaction.destination),
+            XRPLTransaction::Other(value) => value.get("Destination").and_then(Value::as_str),
+            _ => None,
+        }
+    }
+
+    fn transaction_type_name(&self) -> String {
+        match self {
+            XRPLTransaction::Other(value) => value
+                .get("TransactionType")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            transaction => transaction
+                .transaction_type()
+                .map(|transaction_type| transaction_type.to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for XRPLTransaction<'_> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+
+        XRPLTransaction::from_value(value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::convert::TryInto;
+
+    use super::*;
+    use crate::models::transactions::{
+        nftoken_mint::NFTokenMintFlag, offer_create::OfferCreate, payment::Payment,
+    };
+
+    fn mixed_transactions() -> Value {
+        serde_json::json!([
+            {
+                "TransactionType": "Payment",
+                "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "Destination": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+                "Amount": "1000000",
+                "Fee": "12",
+                "Flags": 131072,
+                "Sequence": 2
+            },
+            {
+                "TransactionType": "OfferCreate",
+                "Account": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+                "Fee": "12",
+                "Flags": 0,
+                "Sequence": 8,
+                "TakerGets": "6000000",
+                "TakerPays": {
+                    "currency": "GKO",
+                    "issuer": "ruazs5h1qEsqpke88pcqnaseXdm6od2xc",
+                    "value": "2"
+                }
+            },
+            {
+                "TransactionType": "TrustSet",
+                "Account": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+                "Fee": "12",
+                "Flags": 262144,
+                "LimitAmount": {
+                    "currency": "USD",
+                    "issuer": "rsP3mgGb2tcYUrxiLFiHJiQXhsziegtwBc",
+                    "value": "100"
+                },
+                "Sequence": 12
+            },
+            {
+                "TransactionType": "AccountSet",
+                "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+                "Domain": "6578616D706C652E636F6D",
+                "Fee": "12",
+                "Sequence": 5,
+                "SetFlag": 5
+            },
+            {
+                "TransactionType": "NFTokenMint",
+                "Account": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                "Fee": "10",
+                "Flags": 8,
+                "NFTokenTaxon": 0,
+                "TransferFee": 314
+            },
+            {
+                "TransactionType": "Clawback",
+                "Account": "rp6abvbTbjoce8ZDJkT6snvxTZSYMBCC9S",
+                "Amount": {
+                    "currency": "FOO",
+                    "issuer": "rsA2LpzuawewSBQXkiju3YQTMzW13pAAdW",
+                    "value": "314.159"
+                },
+                "Fee": "10",
+                "Flags": 0,
+                "Sequence": 3
+            }
+        ])
+    }
+
+    #[test]
+    fn test_deserialize_mixed_transactions() {
+        let transactions: Vec<XRPLTransaction> =
+            serde_json::from_value(mixed_transactions()).unwrap();
+
+        assert_eq!(
+            transactions
+                .iter()
+                .map(XRPLTransaction::transaction_type)
+                .collect::<Vec<_>>(),
+            [
+                Some(TransactionType::Payment),
+                Some(TransactionType::OfferCreate),
+                Some(TransactionType::TrustSet),
+                Some(TransactionType::AccountSet),
+                Some(TransactionType::NFTokenMint),
+                None,
+            ]
+        );
+        assert!(matches!(
+            &transactions[4],
+            XRPLTransaction::NFTokenMint(nftoken_mint)
+                if nftoken_mint.common_fields.flags.contains(&NFTokenMintFlag::TfTransferable)
+        ));
+
+        let common_fields = transactions
+            .iter()
+            .map(|transaction| transaction.common_fields().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(common_fields[0].flags, 131072);
+        assert_eq!(common_fields[2].sequence, Some(12));
+        assert_eq!(common_fields[3].transaction_type, "AccountSet");
+        assert_eq!(common_fields[5].transaction_type, "Clawback");
+        assert_eq!(
+            common_fields[5].account,
+            "rp6abvbTbjoce8ZDJkT6snvxTZSYMBCC9S"
+        );
+
+        // Serializing writes each transaction as its own type does.
+        let serialized = serde_json::to_value(&transactions).unwrap();
+        assert_eq!(serialized[5], mixed_transactions()[5]);
+        assert_eq!(
+            serde_json::from_value::<Vec<XRPLTransaction>>(serialized).unwrap(),
+            transactions
+        );
+    }
+
+    #[test]
+    fn test_downcast() {
+        let transactions: Vec<XRPLTransaction> =
+            serde_json::from_value(mixed_transactions()).unwrap();
+
+        let payment: Payment = transactions[0].clone().try_into().unwrap();
+        assert_eq!(payment.destination, "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX");
+        assert_eq!(XRPLTransaction::from(payment), transactions[0]);
+
+        let error = TryInto::<OfferCreate>::try_into(transactions[0].clone()).unwrap_err();
+        assert_eq!(
+            error,
+            XRPLTransactionException::UnexpectedTransactionType {
+                expected: "OfferCreate".into(),
+                found: "Payment".into(),
+            }
+            .into()
+        );
+        assert!(TryInto::<Payment>::try_into(transactions[5].clone()).is_err());
+    }
+
+    #[test]
+    fn test_invalid_transactions() {
+        // A known type is not kept as `Other` when its model rejects it.
+        assert!(
+            serde_json::from_value::<XRPLTransaction>(serde_json::json!({
+                "TransactionType": "Payment",
+                "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"
+            }))
+            .is_err()
+        );
+        assert!(
+            serde_json::from_value::<XRPLTransaction>(serde_json::json!({
+                "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn"
+            }))
+            .is_err()
+        );
+    }
+}
+
+/// Builds one transaction of every type through its constructor. The
+/// match in `build` has no catch-all, so a new transaction type does
+/// not compile until it is added here.
+#[cfg(test)]
+mod every_transaction_type {
+    use alloc::{borrow::Cow, vec};
+    use core::fmt::Debug;
+    use strum::IntoEnumIterator;
+
+    use super::*;
+    use crate::models::transactions::{
+        account_delete::AccountDelete,
+        account_set::AccountSet,
+        amm_bid::AMMBid,
+        amm_create::AMMCreate,
+        amm_delete::AMMDelete,
+        amm_deposit::AMMDeposit,
+        amm_vote::AMMVote,
+        amm_withdraw::AMMWithdraw,
+        check_cancel::CheckCancel,
+        check_cash::CheckCash,
+        check_create::CheckCreate,
+        deposit_preauth::DepositPreauth,
+        escrow_cancel::EscrowCancel,
+        escrow_create::EscrowCreate,
+        escrow_finish::EscrowFinish,
+        nftoken_accept_offer::NFTokenAcceptOffer,
+        nftoken_burn::NFTokenBurn,
+        nftoken_cancel_offer::NFTokenCancelOffer,
+        nftoken_create_offer::NFTokenCreateOffer,
+        nftoken_mint::NFTokenMint,
+        offer_cancel::OfferCancel,
+        offer_create::OfferCreate,
+        payment::Payment,
+        payment_channel_claim::PaymentChannelClaim,
+        payment_channel_create::PaymentChannelCreate,
+        payment_channel_fund::PaymentChannelFund,
+        pseudo_transactions::{EnableAmendment, SetFee, UNLModify, UNLModifyDisabling},
+        set_regular_key::SetRegularKey,
+        signer_list_set::SignerEntry,
+        signer_list_set::SignerListSet,
+        ticket_create::TicketCreate,
+        trust_set::TrustSet,
+        xchain_account_create_commit::XChainAccountCreateCommit,
+        xchain_add_account_create_attestation::XChainAddAccountCreateAttestation,
+        xchain_add_claim_attestation::XChainAddClaimAttestation,
+        xchain_claim::XChainClaim,
+        xchain_commit::XChainCommit,
+        xchain_create_bridge::XChainCreateBridge,
+        xchain_create_claim_id::XChainCreateClaimID,
+        xchain_modify_bridge::XChainModifyBridge,
+        Transaction,
+    };
+    use crate::models::{
+        Amount, Currency, IssuedCurrency, IssuedCurrencyAmount, XChainBridge, XRP,
+    };
+
+    const ACCOUNT: &str = "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn";
+    const DESTINATION: &str = "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX";
+    const ISSUER: &str = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+    const HASH: &str = "C6C4C8B6B6D3B6E7E5C1C5A6E3F3F5C5C5E7E5C1C5A6E3F3F5C5C5E7E5C1C5A6";
+    const PUBLIC_KEY: &str = "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020";
+
+    fn account() -> Cow<'static, str> {
+        ACCOUNT.into()
+    }
+
+    fn usd() -> Currency<'static> {
+        IssuedCurrency::new("USD".into(), ISSUER.into()).into()
+    }
+
+    fn usd_amount(value: &'static str) -> IssuedCurrencyAmount<'static> {
+        IssuedCurrencyAmount::new("USD".into(), ISSUER.into(), value.into())
+    }
+
+    fn xrp_amount(drops: &'static str) -> Amount<'static> {
+        Amount::XRPAmount(drops.into())
+    }
+
+    fn bridge() -> XChainBridge<'static> {
+        XChainBridge {
+            issuing_chain_door: DESTINATION.into(),
+            issuing_chain_issue: XRP::new().into(),
+            locking_chain_door: ISSUER.into(),
+            locking_chain_issue: XRP::new().into(),
+        }
+    }
+
+    /// Check a transaction through its `Transaction` and `Model`
+    /// implementations before erasing its type.
+    fn checked<'a, T, F>(transaction: T) -> XRPLTransaction<'a>
+    where
+        T: Transaction<'a, F> + Model + Into<XRPLTransaction<'a>>,
+        F: strum::IntoEnumIterator + Serialize + Debug + PartialEq,
+    {
+        let common_fields = transaction.get_common_fields();
+        assert_eq!(common_fields.account, ACCOUNT);
+        assert_eq!(
+            common_fields.transaction_type,
+            transaction.get_transaction_type()
+        );
+        if let Err(error) = transaction.get_errors() {
+            panic!("{} is invalid: {error}", common_fields.transaction_type);
+        }
+
+        transaction.into()
+    }
+
+    fn build(transaction_type: TransactionType) -> XRPLTransaction<'static> {
+        match transaction_type {
+            TransactionType::AccountDelete => checked(AccountDelete::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                DESTINATION.into(),
+                None,
+            )),
+            TransactionType::AccountSet => checked(AccountSet::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some("6578616D706C652E636F6D".into()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )),
+            TransactionType::AMMBid => checked(AMMBid::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                XRP::new().into(),
+                usd(),
+                None,
+                None,
+                None,
+            )),
+            TransactionType::AMMCreate => checked(AMMCreate::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                xrp_amount("1000000"),
+                Amount::IssuedCurrencyAmount(usd_amount("10")),
+                500,
+            )),
+            TransactionType::AMMDelete => checked(AMMDelete::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                XRP::new().into(),
+                usd(),
+            )),
+            TransactionType::AMMDeposit => checked(AMMDeposit::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                XRP::new().into(),
+                usd(),
+                Some(xrp_amount("1000000")),
+                None,
+                None,
+                None,
+            )),
+            TransactionType::AMMVote => checked(AMMVote::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                XRP::new().into(),
+                usd(),
+                Some(600),
+            )),
+            TransactionType::AMMWithdraw => checked(AMMWithdraw::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                XRP::new().into(),
+                usd(),
+                Some(xrp_amount("1000000")),
+                None,
+                None,
+                None,
+            )),
+            TransactionType::CheckCancel => checked(CheckCancel::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                HASH.into(),
+            )),
+            TransactionType::CheckCash => checked(CheckCash::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                HASH.into(),
+                Some(xrp_amount("1000000")),
+                None,
+            )),
+            TransactionType::CheckCreate => checked(CheckCreate::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                DESTINATION.into(),
+                xrp_amount("1000000"),
+                None,
+                None,
+                None,
+            )),
+            TransactionType::DepositPreauth => checked(DepositPreauth::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(DESTINATION.into()),
+                None,
+            )),
+            TransactionType::EscrowCancel => checked(EscrowCancel::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                ACCOUNT.into(),
+                7,
+            )),
+            TransactionType::EscrowCreate => checked(EscrowCreate::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                "1000000".into(),
+                DESTINATION.into(),
+                Some(800000000),
+                None,
+                None,
+                Some(700000000),
+            )),
+            TransactionType::EscrowFinish => checked(EscrowFinish::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                ACCOUNT.into(),
+                7,
+                None,
+                None,
+            )),
+            TransactionType::NFTokenAcceptOffer => checked(NFTokenAcceptOffer::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(HASH.into()),
+                None,
+                None,
+            )),
+            TransactionType::NFTokenBurn => checked(NFTokenBurn::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                HASH.into(),
+                None,
+            )),
+            TransactionType::NFTokenCancelOffer => checked(NFTokenCancelOffer::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                vec![HASH.into()],
+            )),
+            TransactionType::NFTokenCreateOffer => checked(NFTokenCreateOffer::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                xrp_amount("1000000"),
+                HASH.into(),
+                None,
+                None,
+                Some(DESTINATION.into()),
+            )),
+            TransactionType::NFTokenMint => checked(NFTokenMint::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                0,
+                None,
+                None,
+                None,
+            )),
+            TransactionType::OfferCancel => checked(OfferCancel::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                7,
+            )),
+            TransactionType::OfferCreate => checked(OfferCreate::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                xrp_amount("1000000"),
+                Amount::IssuedCurrencyAmount(usd_amount("1")),
+                None,
+                None,
+            )),
+            TransactionType::Payment => checked(Payment::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                xrp_amount("1000000"),
+                DESTINATION.into(),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )),
+            TransactionType::PaymentChannelClaim => checked(PaymentChannelClaim::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                HASH.into(),
+                None,
+                None,
+                None,
+                None,
+            )),
+            TransactionType::PaymentChannelCreate => checked(PaymentChannelCreate::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                "1000000".into(),
+                DESTINATION.into(),
+                PUBLIC_KEY.into(),
+                86400,
+                None,
+                None,
+            )),
+            TransactionType::PaymentChannelFund => checked(PaymentChannelFund::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                "1000000".into(),
+                HASH.into(),
+                None,
+            )),
+            #[cfg(feature = "xahau")]
+            TransactionType::SetHook => {
+                checked(crate::models::transactions::set_hook::SetHook::new(
+                    account(),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    vec![Default::default()],
+                ))
+            }
+            TransactionType::SetRegularKey => checked(SetRegularKey::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(DESTINATION.into()),
+            )),
+            TransactionType::SignerListSet => checked(SignerListSet::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                1,
+                Some(vec![SignerEntry::new(DESTINATION.into(), 1, None)]),
+            )),
+            TransactionType::TicketCreate => checked(TicketCreate::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                1,
+            )),
+            TransactionType::TrustSet => checked(TrustSet::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                usd_amount("100"),
+                None,
+                None,
+            )),
+            TransactionType::XChainAccountCreateCommit => checked(XChainAccountCreateCommit::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                xrp_amount("10000000"),
+                DESTINATION.into(),
+                bridge(),
+                Some(xrp_amount("100")),
+            )),
+            TransactionType::XChainAddAccountCreateAttestation => {
+                checked(XChainAddAccountCreateAttestation::new(
+                    account(),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    xrp_amount("10000000"),
+                    ACCOUNT.into(),
+                    ACCOUNT.into(),
+                    DESTINATION.into(),
+                    ISSUER.into(),
+                    PUBLIC_KEY.into(),
+                    HASH.into(),
+                    xrp_amount("100"),
+                    1,
+                    "1".into(),
+                    bridge(),
+                ))
+            }
+            TransactionType::XChainAddClaimAttestation => checked(XChainAddClaimAttestation::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                xrp_amount("10000000"),
+                ACCOUNT.into(),
+                ACCOUNT.into(),
+                ISSUER.into(),
+                PUBLIC_KEY.into(),
+                HASH.into(),
+                1,
+                bridge(),
+                "1".into(),
+                None,
+            )),
+            TransactionType::XChainClaim => checked(XChainClaim::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                xrp_amount("10000000"),
+                DESTINATION.into(),
+                bridge(),
+                "1".into(),
+                None,
+            )),
+            TransactionType::XChainCommit => checked(XChainCommit::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                xrp_amount("10000000"),
+                bridge(),
+                "1".into(),
+                None,
+            )),
+            TransactionType::XChainCreateBridge => checked(XChainCreateBridge::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                xrp_amount("100"),
+                bridge(),
+                Some("10000000".into()),
+            )),
+            TransactionType::XChainCreateClaimID => checked(XChainCreateClaimID::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                ISSUER.into(),
+                "100".into(),
+                bridge(),
+            )),
+            TransactionType::XChainModifyBridge => checked(XChainModifyBridge::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                bridge(),
+                None,
+                Some(xrp_amount("200")),
+            )),
+            TransactionType::EnableAmendment => checked(EnableAmendment::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                HASH.into(),
+                1,
+            )),
+            TransactionType::SetFee => checked(SetFee::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(1),
+                Some("10".into()),
+                Some("10000000".into()),
+                Some("2000000".into()),
+            )),
+            TransactionType::UNLModify => checked(UNLModify::new(
+                account(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                1,
+                UNLModifyDisabling::Disable,
+                PUBLIC_KEY.into(),
+            )),
+        }
+    }
+
+    #[test]
+    fn test_every_transaction_type() {
+        for transaction_type in TransactionType::iter() {
+            let transaction = build(transaction_type.clone());
+
+            assert_eq!(
+                transaction.transaction_type(),
+                Some(transaction_type.clone())
+            );
+            assert_eq!(transaction.common_fields().unwrap().account, ACCOUNT);
+            assert!(transaction.get_errors().is_ok());
+            // It reads back as the same type.
+            let value = serde_json::to_value(&transaction).unwrap();
+            assert_eq!(
+                serde_json::from_value::<XRPLTransaction>(value).unwrap(),
+                transaction,
+                "{transaction_type}"
+            );
+        }
+    }
+}
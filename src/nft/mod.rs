@@ -0,0 +1,84 @@
+use embassy_futures::block_on;
+
+use crate::{
+    asynch::{
+        clients::XRPLClient,
+        exceptions::XRPLHelperResult,
+        nft::{
+            accept_nft_offer as async_accept_nft_offer, burn_nft as async_burn_nft,
+            create_nft_sell_offer as async_create_nft_sell_offer, mint_nft as async_mint_nft,
+        },
+    },
+    models::{transactions::nftoken_mint::NFTokenMintFlag, Amount, FlagCollection},
+    wallet::Wallet,
+};
+use alloc::string::String;
+
+pub fn mint_nft<C>(
+    client: &C,
+    wallet: &Wallet,
+    uri: Option<&str>,
+    taxon: u32,
+    flags: Option<FlagCollection<NFTokenMintFlag>>,
+    transfer_fee: Option<u32>,
+) -> XRPLHelperResult<String>
+where
+    C: XRPLClient,
+{
+    block_on(async_mint_nft(
+        client,
+        wallet,
+        uri,
+        taxon,
+        flags,
+        transfer_fee,
+    ))
+}
+
+pub fn create_nft_sell_offer<C>(
+    client: &C,
+    wallet: &Wallet,
+    nftoken_id: &str,
+    amount: Amount<'_>,
+    destination: Option<&str>,
+    expiration: Option<u32>,
+) -> XRPLHelperResult<String>
+where
+    C: XRPLClient,
+{
+    block_on(async_create_nft_sell_offer(
+        client,
+        wallet,
+        nftoken_id,
+        amount,
+        destination,
+        expiration,
+    ))
+}
+
+pub fn accept_nft_offer<C>(
+    client: &C,
+    wallet: &Wallet,
+    sell_offer: Option<&str>,
+    buy_offer: Option<&str>,
+    broker_fee: Option<Amount<'_>>,
+) -> XRPLHelperResult<String>
+where
+    C: XRPLClient,
+{
+    block_on(async_accept_nft_offer(
+        client, wallet, sell_offer, buy_offer, broker_fee,
+    ))
+}
+
+pub fn burn_nft<C>(
+    client: &C,
+    wallet: &Wallet,
+    nftoken_id: &str,
+    owner: Option<&str>,
+) -> XRPLHelperResult<String>
+where
+    C: XRPLClient,
+{
+    block_on(async_burn_nft(client, wallet, nftoken_id, owner))
+}
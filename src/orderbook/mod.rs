@@ -0,0 +1,39 @@
+use embassy_futures::block_on;
+
+use crate::{
+    asynch::{
+        clients::XRPLClient,
+        exceptions::XRPLHelperResult,
+        orderbook::{
+            estimate_offer_crossing as async_estimate_offer_crossing,
+            get_order_book as async_get_order_book,
+        },
+    },
+    models::{transactions::offer_create::OfferCreate, Currency},
+};
+
+pub use crate::asynch::orderbook::{
+    OfferCrossingEstimate, OrderBook, OrderBookEntry, OrderBookSide,
+};
+
+pub fn get_order_book<'a: 'b, 'b, C>(
+    client: &C,
+    taker_gets: Currency<'a>,
+    taker_pays: Currency<'a>,
+    depth: u16,
+) -> XRPLHelperResult<OrderBook<'b>>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_order_book(client, taker_gets, taker_pays, depth))
+}
+
+pub fn estimate_offer_crossing<C>(
+    client: &C,
+    offer: &OfferCreate<'_>,
+) -> XRPLHelperResult<OfferCrossingEstimate>
+where
+    C: XRPLClient,
+{
+    block_on(async_estimate_offer_crossing(client, offer))
+}
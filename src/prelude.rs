@@ -0,0 +1,128 @@
+//! The types, traits and helpers most programs need, to import at
+//! once.
+//!
+//! The helpers are the async ones. Their blocking versions keep
+//! their module paths, e.g. [`crate::transaction::submit_and_wait`].
+//! The request and result models are reachable through the
+//! [`requests`] and [`results`] modules, whose models share names,
+//! e.g. `requests::account_info::AccountInfo` and
+//! `results::account_info::AccountInfo`.
+//!
+//! # Examples
+//!
+//! ## Basic usage
+//!
+//! ```
+//! use xrpl::prelude::*;
+//!
+//! let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+//! let mut payment = Payment::builder(
+//!     wallet.classic_address.as_str(),
+//!     XRPAmount::from("1000000").into(),
+//!     "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".into(),
+//! )
+//! .fee("12")
+//! .sequence(1)
+//! .memo(Memo::from_text("hi", None, None))
+//! .build()
+//! .unwrap();
+//! sign(&mut payment, &wallet, false).unwrap();
+//!
+//! assert!(payment.validate().is_ok());
+//! assert_eq!(payment.get_transaction_type(), TransactionType::Payment);
+//! assert_eq!(decode(&encode(&payment).unwrap()).unwrap()["Amount"], "1000000");
+//!
+//! let request: XRPLRequest = requests::fee::Fee::new(None).into();
+//! assert_eq!(request.method(), RequestMethod::Fee);
+//! ```
+//!
+//! ## Reading and writing the binary format
+//!
+//! ```
+//! use xrpl::prelude::*;
+//!
+//! let mut serializer = BinarySerializer::new();
+//! serializer.write_length_encoded(&[0, 17, 34], true);
+//! let mut parser = BinaryParser::from(serializer.as_slice());
+//!
+//! assert_eq!(parser.read_length_prefix().unwrap(), 3);
+//! assert_eq!(parser.read_uint8().unwrap(), 0);
+//! assert_eq!(parser.read_uint16().unwrap(), 17 << 8 | 34);
+//! assert!(parser.is_end(None));
+//! ```
+//!
+//! ## Submitting a transaction
+//!
+//! ```no_run
+//! use xrpl::prelude::*;
+//!
+//! # #[cfg(feature = "std")]
+//! # async fn run() -> Result<(), XRPLError> {
+//! let client = AsyncJsonRpcClient::connect("https://s.altnet.rippletest.net:51234".parse().unwrap());
+//! let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0)?;
+//! let mut payment = Payment::builder(
+//!     wallet.classic_address.as_str(),
+//!     XRPAmount::from("1000000").into(),
+//!     "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".into(),
+//! )
+//! .build()?;
+//!
+//! let balance = get_xrp_balance(wallet.classic_address.as_str().into(), &client, None).await?;
+//! let tx = submit_and_wait(&mut payment, &client, Some(&wallet), Some(true), Some(true)).await?;
+//! let response = client.request(requests::fee::Fee::new(None).into()).await?;
+//! # let _ = (balance, tx, response);
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::XRPLError;
+
+#[cfg(feature = "core")]
+pub use crate::core::{
+    addresscodec::{
+        classic_address_to_xaddress, is_valid_classic_address, is_valid_xaddress,
+        xaddress_to_classic_address,
+    },
+    binarycodec::{
+        decode, encode, encode_for_multisigning, encode_for_signing, BinaryParser,
+        BinarySerializer, Parser, Serialization,
+    },
+};
+
+#[cfg(feature = "models")]
+pub use crate::models::{
+    requests::{self, Request, RequestMethod, XRPLRequest},
+    results::{self, XRPLResponse, XRPLResult},
+    transactions::{
+        self, account_set::AccountSet, offer_cancel::OfferCancel, offer_create::OfferCreate,
+        payment::Payment, trust_set::TrustSet, CommonFieldsBuilder, Memo, Signer, Transaction,
+        TransactionType, XRPLTransaction,
+    },
+    Amount, Currency, FlagCollection, IssuedCurrency, IssuedCurrencyAmount, Model, XRPAmount, XRP,
+};
+
+#[cfg(feature = "wallet")]
+pub use crate::wallet::Wallet;
+
+#[cfg(any(feature = "json-rpc", feature = "websocket"))]
+pub use crate::{
+    asynch::clients::{ApiVersion, XRPLAsyncClient, XRPLClient},
+    clients::{XRPLSyncClient, XRPLSyncWebsocketIO},
+};
+
+#[cfg(feature = "json-rpc")]
+pub use crate::{asynch::clients::AsyncJsonRpcClient, clients::json_rpc::JsonRpcClient};
+
+#[cfg(feature = "websocket")]
+pub use crate::{
+    asynch::clients::{AsyncWebSocketClient, XRPLAsyncWebsocketIO},
+    clients::websocket::WebSocketClient,
+};
+
+#[cfg(feature = "helpers")]
+pub use crate::asynch::{
+    account::{does_account_exist, get_account_root, get_next_valid_seq_number, get_xrp_balance},
+    exceptions::XRPLHelperResult,
+    ledger::{get_fee, get_latest_validated_ledger_sequence},
+    transaction::{autofill, autofill_and_sign, sign, sign_and_submit, submit, submit_and_wait},
+};
@@ -0,0 +1,127 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    asynch::{
+        exceptions::XRPLHelperResult,
+        transaction::{exceptions::XRPLSignTransactionException, sign_with_key},
+    },
+    core::{binarycodec::encode, ledger::hash_signed_tx},
+    models::transactions::{payment::Payment, Transaction},
+    wallet::Wallet,
+};
+
+/// A transaction signed offline, ready to submit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedBlob {
+    /// The signed transaction, as submitted with `submit`.
+    pub tx_blob: String,
+    /// The hash identifying the transaction once it is in a ledger.
+    pub hash: String,
+}
+
+/// Sign Payments offline, e.g. for bulk payouts.
+///
+/// The Payments get consecutive Sequence Numbers from
+/// `starting_sequence` and the same `last_ledger_sequence`. Without
+/// a client nothing is autofilled, so each Payment needs its `Fee`.
+/// The wallet's key is parsed once for all of them.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::transactions::payment::Payment;
+/// use xrpl::transaction::sign_batch;
+/// use xrpl::wallet::Wallet;
+///
+/// let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+/// let payments: Vec<Payment> = ["1000000", "2000000"]
+///     .into_iter()
+///     .map(|amount| {
+///         serde_json::from_value(serde_json::json!({
+///             "TransactionType": "Payment",
+///             "Account": wallet.classic_address,
+///             "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+///             "Amount": amount,
+///             "Fee": "12"
+///         }))
+///         .unwrap()
+///     })
+///     .collect();
+///
+/// let signed = sign_batch(payments, &wallet, 5, 1000).unwrap();
+///
+/// assert_eq!(signed.len(), 2);
+/// assert_eq!(signed[0].hash.len(), 64);
+/// ```
+pub fn sign_batch(
+    transactions: Vec<Payment<'static>>,
+    wallet: &Wallet,
+    starting_sequence: u32,
+    last_ledger_sequence: u32,
+) -> XRPLHelperResult<Vec<SignedBlob>> {
+    let signing_key = wallet.signing_key()?;
+
+    transactions
+        .into_iter()
+        .zip(starting_sequence..)
+        .map(|(mut transaction, sequence)| {
+            let common_fields = transaction.get_mut_common_fields();
+            if common_fields.fee.is_none() {
+                return Err(XRPLSignTransactionException::FeeRequired.into());
+            }
+            common_fields.sequence = Some(sequence);
+            common_fields.last_ledger_sequence = Some(last_ledger_sequence);
+            sign_with_key(&mut transaction, wallet, &signing_key, false)?;
+            let tx_blob = encode(&transaction)?;
+            let hash = hash_signed_tx(&tx_blob)?;
+
+            Ok(SignedBlob { tx_blob, hash })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::asynch::transaction::sign;
+
+    const DESTINATION: &str = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe";
+
+    fn payments(wallet: &Wallet, count: usize) -> Vec<Payment<'static>> {
+        (0..count)
+            .map(|index| {
+                serde_json::from_value(serde_json::json!({
+                    "TransactionType": "Payment",
+                    "Account": wallet.classic_address,
+                    "Destination": DESTINATION,
+                    "Amount": (1_000_000 + index).to_string(),
+                    "Fee": "12"
+                }))
+                .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sign_batch() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let signed = sign_batch(payments(&wallet, 3), &wallet, 5, 1000).unwrap();
+
+        // Signing one by one gives the same transactions.
+        for ((sequence, mut payment), signed) in (5..).zip(payments(&wallet, 3)).zip(&signed) {
+            payment.common_fields.sequence = Some(sequence);
+            payment.common_fields.last_ledger_sequence = Some(1000);
+            sign(&mut payment, &wallet, false).unwrap();
+            assert_eq!(encode(&payment).unwrap(), signed.tx_blob);
+            assert_eq!(signed.hash, hash_signed_tx(&signed.tx_blob).unwrap());
+        }
+
+        let mut without_fee = payments(&wallet, 1);
+        without_fee[0].common_fields.fee = None;
+        assert!(sign_batch(without_fee, &wallet, 5, 1000).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::string::String;
 use thiserror_no_std::Error;
 
 #[derive(Debug, PartialEq, Error)]
@@ -6,3 +7,26 @@ pub enum XRPLMultisignException {
     #[error("No signers set in the transaction. Use `sign` function with `multisign = true`.")]
     NoSigners,
 }
+
+#[derive(Debug, PartialEq, Error)]
+#[non_exhaustive]
+pub enum XRPLVerifyBlobException {
+    #[error("The transaction is neither signed nor multi-signed")]
+    NotSigned,
+    #[error("The transaction of `{account}` is signed with the key of `{signing_account}`. Whether it is the regular key of `{account}` cannot be verified offline")]
+    SigningKeyMismatch {
+        account: String,
+        signing_account: String,
+    },
+}
+
+#[derive(Debug, PartialEq, Error)]
+#[non_exhaustive]
+pub enum XRPLSigningPayloadException {
+    #[error("The transaction is already signed")]
+    AlreadySigned,
+    #[error(
+        "The transaction has no SigningPubKey. Set the public key of the external signer first"
+    )]
+    MissingSigningPubKey,
+}
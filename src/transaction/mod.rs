@@ -1,5 +1,9 @@
+mod batch;
 pub mod exceptions;
 mod multisign;
+mod payment_channel;
+mod signing_payload;
+mod verify;
 
 use core::fmt::Debug;
 
@@ -7,15 +11,18 @@ use crate::{
     asynch::{
         clients::XRPLAsyncClient,
         exceptions::XRPLHelperResult,
+        ledger::FeeStrategy,
         transaction::{
             autofill as async_autofill, autofill_and_sign as async_autofill_and_sign,
+            autofill_with_fee_strategy as async_autofill_with_fee_strategy,
             calculate_fee_per_transaction_type as async_calculate_fee_per_transaction_type,
-            sign_and_submit as async_sign_and_submit, submit as async_submit,
+            check_fee as async_check_fee, sign_and_submit as async_sign_and_submit,
+            sign_with as async_sign_with, simulate as async_simulate, submit as async_submit,
             submit_and_wait as async_submit_and_wait,
         },
     },
     models::{
-        results::{submit::Submit, tx::Tx},
+        results::{simulate::Simulate, submit::Submit, tx::Tx},
         transactions::Transaction,
         Model, XRPAmount,
     },
@@ -25,8 +32,24 @@ use embassy_futures::block_on;
 use serde::{de::DeserializeOwned, Serialize};
 use strum::IntoEnumIterator;
 
-pub use crate::asynch::transaction::sign;
+pub use crate::asynch::transaction::{sign, sign_fully_canonical, Signer, DEFAULT_MAX_FEE};
+pub use batch::*;
 pub use multisign::*;
+pub use payment_channel::*;
+pub use signing_payload::*;
+pub use verify::*;
+
+pub fn sign_with<'a, T, F>(
+    transaction: &mut T,
+    signer: &impl Signer,
+    multisign: bool,
+) -> XRPLHelperResult<()>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone + Debug,
+{
+    block_on(async_sign_with(transaction, signer, multisign))
+}
 
 pub fn sign_and_submit<'a, 'b, T, F, C>(
     transaction: &mut T,
@@ -62,6 +85,26 @@ where
     block_on(async_autofill(transaction, client, signers_count))
 }
 
+pub fn autofill_with_fee_strategy<'a, 'b, F, T, C, S>(
+    transaction: &mut T,
+    client: &'b C,
+    signers_count: Option<u8>,
+    fee_strategy: &S,
+) -> XRPLHelperResult<()>
+where
+    T: Transaction<'a, F> + Model + Clone,
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    C: XRPLAsyncClient,
+    S: FeeStrategy,
+{
+    block_on(async_autofill_with_fee_strategy(
+        transaction,
+        client,
+        signers_count,
+        fee_strategy,
+    ))
+}
+
 pub fn autofill_and_sign<'a, 'b, T, F, C>(
     transaction: &mut T,
     client: &'b C,
@@ -90,6 +133,15 @@ where
     block_on(async_submit(transaction, client))
 }
 
+pub fn simulate<'a, T, F, C>(client: &C, transaction: &T) -> XRPLHelperResult<Simulate<'a>>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Model + Serialize + Clone + Debug,
+    C: XRPLAsyncClient,
+{
+    block_on(async_simulate(client, transaction))
+}
+
 pub fn submit_and_wait<'a: 'b, 'b, T, F, C>(
     transaction: &'b mut T,
     client: &C,
@@ -111,6 +163,15 @@ where
     ))
 }
 
+pub fn check_fee<'a, T, F, C>(transaction: &T, client: &C) -> XRPLHelperResult<()>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F>,
+    C: XRPLAsyncClient,
+{
+    block_on(async_check_fee(transaction, client))
+}
+
 pub fn calculate_fee_per_transaction_type<'a, 'b, 'c, T, F, C>(
     transaction: &T,
     client: Option<&'b C>,
@@ -28,7 +28,9 @@ where
     }
     decoded_tx_signers
         .sort_by_key(|signer| decode_classic_address(signer.account.as_ref()).unwrap());
-    transaction.get_mut_common_fields().signers = Some(decoded_tx_signers);
+    let common_fields = transaction.get_mut_common_fields();
+    common_fields.signing_pub_key = Some("".into());
+    common_fields.signers = Some(decoded_tx_signers);
 
     Ok(())
 }
@@ -0,0 +1,101 @@
+use alloc::string::String;
+
+use crate::{
+    asynch::exceptions::XRPLHelperResult,
+    core::{
+        addresscodec::decode_account_public_key,
+        binarycodec::encode_for_signing_claim,
+        keypairs::{is_valid_message, sign},
+    },
+    models::XRPAmount,
+    wallet::Wallet,
+};
+
+/// Sign a claim of `amount` from the payment channel `channel_id`
+/// with the key of the channel, as `channel_authorize` does but
+/// without sending the secret to a server.
+pub fn sign_payment_channel_claim(
+    channel_id: &str,
+    amount: XRPAmount<'_>,
+    wallet: &Wallet,
+) -> XRPLHelperResult<String> {
+    let claim = hex::decode(encode_for_signing_claim(channel_id, amount.try_into()?)?)?;
+
+    Ok(sign(&claim, &wallet.private_key)?)
+}
+
+/// Whether `signature` authorizes claiming `amount` from the payment
+/// channel `channel_id`, checked offline. `public_key` is the key of
+/// the channel, in hex or base58.
+///
+/// Like `channel_verify`, this does not check the channel exists or
+/// holds `amount`.
+pub fn is_valid_payment_channel_claim(
+    channel_id: &str,
+    amount: XRPAmount<'_>,
+    signature: &str,
+    public_key: &str,
+) -> XRPLHelperResult<bool> {
+    let claim = hex::decode(encode_for_signing_claim(channel_id, amount.try_into()?)?)?;
+    let public_key = if hex::decode(public_key).is_ok() {
+        public_key.into()
+    } else {
+        hex::encode_upper(decode_account_public_key(public_key)?)
+    };
+
+    Ok(is_valid_message(&claim, signature, &public_key))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::addresscodec::encode_account_public_key;
+
+    const CHANNEL_ID: &str = "5DB01B7FFED6B67E6B0414DED11E051D2EE2B7619CE0EAA6286D67A3A4D5BDB3";
+
+    #[test]
+    fn test_sign_and_verify_claim() {
+        for seed in [
+            "sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5",
+            "snoPBrXtMeMyMHUVTgbuqAfg1SUTb",
+        ] {
+            let wallet = Wallet::new(seed, 0).unwrap();
+            let signature =
+                sign_payment_channel_claim(CHANNEL_ID, "1000000".into(), &wallet).unwrap();
+
+            assert!(is_valid_payment_channel_claim(
+                CHANNEL_ID,
+                "1000000".into(),
+                &signature,
+                &wallet.public_key
+            )
+            .unwrap());
+            // The key of a channel is listed in base58 too.
+            let public_key =
+                encode_account_public_key(&hex::decode(&wallet.public_key).unwrap()).unwrap();
+            assert!(is_valid_payment_channel_claim(
+                CHANNEL_ID,
+                "1000000".into(),
+                &signature,
+                &public_key
+            )
+            .unwrap());
+
+            assert!(!is_valid_payment_channel_claim(
+                CHANNEL_ID,
+                "1000001".into(),
+                &signature,
+                &wallet.public_key
+            )
+            .unwrap());
+        }
+    }
+
+    #[test]
+    fn test_invalid_claim() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+
+        assert!(sign_payment_channel_claim("5DB01B7F", "1000000".into(), &wallet).is_err());
+        assert!(sign_payment_channel_claim(CHANNEL_ID, "1.5".into(), &wallet).is_err());
+    }
+}
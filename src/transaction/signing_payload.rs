@@ -0,0 +1,257 @@
+use alloc::string::String;
+use core::fmt::Debug;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use strum::IntoEnumIterator;
+
+use crate::{
+    asynch::exceptions::XRPLHelperResult,
+    core::{binarycodec::encode_for_signing, hashing::sha512_half},
+    models::{transactions::Transaction, Model},
+    transaction::exceptions::XRPLSigningPayloadException,
+    wallet::signing::prepare_transaction,
+};
+
+/// The fields an external signer sets itself.
+const SIGNATURE_FIELDS: [&str; 3] = ["SigningPubKey", "TxnSignature", "Signers"];
+
+/// What an external signer, e.g. a hardware wallet, signs for a
+/// transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SigningPayload {
+    /// The transaction encoded for signing, in hex, the exact bytes
+    /// ED25519 keys sign.
+    pub hex: String,
+    /// The SHA-512 half of those bytes, in hex, the digest SECP256K1
+    /// keys sign.
+    pub hash: String,
+}
+
+/// Hand a prepared transaction to an external signer app: the
+/// `txjson` payload of a XUMM sign request, without the signature
+/// fields the app sets itself.
+///
+/// # Errors
+///
+/// If the transaction is invalid or already signed.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::transactions::payment::Payment;
+/// use xrpl::transaction::to_xumm_payload;
+///
+/// let payment: Payment = serde_json::from_value(serde_json::json!({
+///     "TransactionType": "Payment",
+///     "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+///     "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+///     "Amount": "1000000",
+///     "Fee": "12",
+///     "Sequence": 1
+/// }))
+/// .unwrap();
+///
+/// let payload = to_xumm_payload(&payment).unwrap();
+///
+/// assert_eq!(payload["txjson"]["Destination"], "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe");
+/// ```
+pub fn to_xumm_payload<'a, T, F>(transaction: &T) -> XRPLHelperResult<Value>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Model + Serialize,
+{
+    check_unsigned(transaction)?;
+
+    let mut txjson = serde_json::to_value(transaction)?;
+    if let Value::Object(fields) = &mut txjson {
+        fields.retain(|name, value| !value.is_null() && !SIGNATURE_FIELDS.contains(&name.as_str()));
+    }
+
+    Ok(serde_json::json!({ "txjson": txjson }))
+}
+
+/// The bytes to sign a prepared transaction with an external signer,
+/// and their digest for signers that sign digests.
+///
+/// The `SigningPubKey` is part of what is signed, so it must be set
+/// to the public key of the signer first. The transaction is
+/// prepared the same way [`sign`](crate::transaction::sign) prepares
+/// it, e.g. X-addresses are converted to classic addresses; submit
+/// the transaction as prepared by `sign` with the returned signature
+/// set as its `TxnSignature`.
+///
+/// # Errors
+///
+/// If the transaction is invalid, already signed or has no
+/// `SigningPubKey`.
+pub fn to_signing_payload<'a, T, F>(transaction: &T) -> XRPLHelperResult<SigningPayload>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone,
+{
+    check_unsigned(transaction)?;
+    let public_key = match transaction.get_common_fields().signing_pub_key.as_deref() {
+        Some(public_key) if !public_key.is_empty() => String::from(public_key),
+        _ => return Err(XRPLSigningPayloadException::MissingSigningPubKey.into()),
+    };
+
+    let mut prepared = transaction.clone();
+    prepare_transaction(&mut prepared, &public_key)?;
+    let hex = encode_for_signing(&prepared)?;
+    let hash = hex::encode_upper(sha512_half(&hex::decode(&hex)?));
+
+    Ok(SigningPayload { hex, hash })
+}
+
+fn check_unsigned<'a, T, F>(transaction: &T) -> XRPLHelperResult<()>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Model,
+{
+    transaction.validate()?;
+
+    let common_fields = transaction.get_common_fields();
+    let has_signers = common_fields
+        .signers
+        .as_ref()
+        .is_some_and(|signers| !signers.is_empty());
+    if common_fields.txn_signature.is_some() || has_signers {
+        return Err(XRPLSigningPayloadException::AlreadySigned.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        asynch::{exceptions::XRPLError, transaction::sign},
+        core::keypairs::{is_valid_message, signing_digest},
+        models::transactions::payment::Payment,
+        wallet::Wallet,
+    };
+
+    const ED25519_PUBLIC_KEY: &str =
+        "ED01FA53FA5A7E77798F882ECE20B1ABC00BB358A9E55A202D0D0676BD0CE37A63";
+
+    fn payment(signing_pub_key: Option<&str>) -> Payment<'static> {
+        let mut payment: Payment = serde_json::from_value(serde_json::json!({
+            "TransactionType": "Payment",
+            "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+            "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+            "Amount": "1000000",
+            "Fee": "12",
+            "Sequence": 1,
+            "Memos": [{ "Memo": { "MemoData": "68656C6C6F" } }]
+        }))
+        .unwrap();
+        payment.common_fields.signing_pub_key = signing_pub_key.map(|key| String::from(key).into());
+        payment
+    }
+
+    #[test]
+    fn test_xumm_payload_fixture() {
+        let payload = to_xumm_payload(&payment(Some(ED25519_PUBLIC_KEY))).unwrap();
+
+        assert_eq!(
+            payload,
+            serde_json::json!({
+                "txjson": {
+                    "TransactionType": "Payment",
+                    "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+                    "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+                    "Amount": "1000000",
+                    "Fee": "12",
+                    "Flags": 0,
+                    "Sequence": 1,
+                    "Memos": [{ "Memo": { "MemoData": "68656C6C6F" } }]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_signing_payload_fixture() {
+        let payload = to_signing_payload(&payment(Some(ED25519_PUBLIC_KEY))).unwrap();
+
+        assert_eq!(
+            payload,
+            SigningPayload {
+                hex: "53545800120000220000000024000000016140000000000F424068400000000000000C7321ED01FA53FA5A7E77798F882ECE20B1ABC00BB358A9E55A202D0D0676BD0CE37A638114DD76483FACDEE26E60D8A586BB58D09F27045C468314F667B0CA50CC7709A220B0561B85E53A48461FA8F9EA7D0568656C6C6FE1F1".into(),
+                hash: "8DD2BB52676644A0E5EC9F3354E191E492ADD354335ACA170D18F6C5F1953A24".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_signing_payload_matches_sign() {
+        for seed in [
+            "sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5",
+            "snoPBrXtMeMyMHUVTgbuqAfg1SUTb",
+        ] {
+            let wallet = Wallet::new(seed, 0).unwrap();
+            let mut payment = payment(Some(&wallet.public_key));
+            payment.common_fields.account = wallet.classic_address.clone().into();
+            let payload = to_signing_payload(&payment).unwrap();
+
+            let message = hex::decode(&payload.hex).unwrap();
+            assert_eq!(
+                hex::encode_upper(signing_digest(&message, &wallet.public_key)),
+                if seed.starts_with("sEd") {
+                    payload.hex.clone()
+                } else {
+                    payload.hash.clone()
+                }
+            );
+
+            sign(&mut payment, &wallet, false).unwrap();
+            let signature = payment.common_fields.txn_signature.as_deref().unwrap();
+            assert!(is_valid_message(&message, signature, &wallet.public_key));
+        }
+    }
+
+    #[test]
+    fn test_refuses_signed_transactions() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let mut payment = payment(None);
+        payment.common_fields.account = wallet.classic_address.clone().into();
+        sign(&mut payment, &wallet, false).unwrap();
+
+        assert!(matches!(
+            to_xumm_payload(&payment),
+            Err(XRPLError::XRPLSigningPayloadError(
+                XRPLSigningPayloadException::AlreadySigned
+            ))
+        ));
+        assert!(matches!(
+            to_signing_payload(&payment),
+            Err(XRPLError::XRPLSigningPayloadError(
+                XRPLSigningPayloadException::AlreadySigned
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_signing_payload_needs_signing_pub_key() {
+        assert!(matches!(
+            to_signing_payload(&payment(None)),
+            Err(XRPLError::XRPLSigningPayloadError(
+                XRPLSigningPayloadException::MissingSigningPubKey
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_refuses_invalid_transactions() {
+        let mut payment = payment(Some(ED25519_PUBLIC_KEY));
+        payment.destination = payment.common_fields.account.clone();
+
+        assert!(to_xumm_payload(&payment).unwrap_err().is_validation_error());
+        assert!(to_signing_payload(&payment)
+            .unwrap_err()
+            .is_validation_error());
+    }
+}
@@ -0,0 +1,317 @@
+use alloc::{string::String, vec::Vec};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::{
+    asynch::exceptions::XRPLHelperResult,
+    core::{
+        addresscodec::decode_classic_address,
+        binarycodec::decoder::decode_fields,
+        hashing::{TRANSACTION_MULTISIG_PREFIX, TRANSACTION_SIGNATURE_PREFIX},
+        keypairs::{derive_classic_address, is_valid_message},
+        ledger::hash_signed_tx,
+    },
+    transaction::exceptions::XRPLVerifyBlobException,
+};
+
+/// What gives the signers authority over the Account of a
+/// transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SigningAuthority {
+    /// Signed with the master key of the Account.
+    MasterKey,
+    /// Multi-signed. Whether the signers are on the signer list of
+    /// the Account, with enough weight, is ledger state and cannot be
+    /// verified offline.
+    SignerList,
+}
+
+/// A signed transaction blob, decoded and checked.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedTransaction<T> {
+    pub transaction: T,
+    /// The hash identifying the transaction once it is in a ledger.
+    pub hash: String,
+    /// The addresses of the keys that signed the transaction.
+    pub signers: Vec<String>,
+    /// Whether every signature matches the transaction. `false` when
+    /// the transaction was changed after it was signed.
+    pub signatures_valid: bool,
+    pub signing_authority: SigningAuthority,
+}
+
+/// Decode a signed transaction blob, e.g. one signed on a cold
+/// wallet, and check its signatures before submitting it.
+///
+/// A single-signed transaction must be signed with the master key
+/// of its Account. Any other key may be the Account's regular key,
+/// which cannot be verified offline, so the blob is rejected with
+/// `SigningKeyMismatch`. A multi-signed transaction is accepted with
+/// `SigningAuthority::SignerList` for the same reason.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::core::binarycodec::encode;
+/// use xrpl::models::transactions::payment::Payment;
+/// use xrpl::transaction::{sign, verify_and_decode_blob, SigningAuthority};
+/// use xrpl::wallet::Wallet;
+///
+/// let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+/// let mut payment: Payment = serde_json::from_value(serde_json::json!({
+///     "TransactionType": "Payment",
+///     "Account": wallet.classic_address,
+///     "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+///     "Amount": "1000000",
+///     "Fee": "12",
+///     "Sequence": 1
+/// }))
+/// .unwrap();
+/// sign(&mut payment, &wallet, false).unwrap();
+///
+/// let decoded = verify_and_decode_blob::<Payment>(&encode(&payment).unwrap()).unwrap();
+///
+/// assert!(decoded.signatures_valid);
+/// assert_eq!(decoded.signers, [wallet.classic_address.as_str()]);
+/// assert_eq!(decoded.signing_authority, SigningAuthority::MasterKey);
+/// assert_eq!(decoded.transaction, payment);
+/// ```
+pub fn verify_and_decode_blob<T>(tx_blob: &str) -> XRPLHelperResult<DecodedTransaction<T>>
+where
+    T: DeserializeOwned,
+{
+    let bytes = hex::decode(tx_blob)?;
+    // The signatures are over the signing fields exactly as they are
+    // in the blob, so take their bytes instead of encoding them again.
+    let mut signing_fields = Vec::new();
+    let mut json = Map::new();
+    for decoded in decode_fields(&bytes)? {
+        if decoded.field.is_signing {
+            signing_fields.extend_from_slice(&bytes[decoded.span.clone()]);
+        }
+        json.insert(decoded.field.name.clone(), decoded.value);
+    }
+    let hash = hash_signed_tx(tx_blob)?;
+    let signing_pub_key = json
+        .get("SigningPubKey")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+
+    let (signers, signatures_valid, signing_authority) = if !signing_pub_key.is_empty() {
+        let txn_signature = json
+            .get("TxnSignature")
+            .and_then(Value::as_str)
+            .ok_or(XRPLVerifyBlobException::NotSigned)?;
+        let account = json
+            .get("Account")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let signing_account = derive_classic_address(signing_pub_key)?;
+        if signing_account != account {
+            return Err(XRPLVerifyBlobException::SigningKeyMismatch {
+                account: account.into(),
+                signing_account,
+            }
+            .into());
+        }
+        let mut signing_data = TRANSACTION_SIGNATURE_PREFIX.to_be_bytes().to_vec();
+        signing_data.extend_from_slice(&signing_fields);
+        let signature_valid = is_valid_message(&signing_data, txn_signature, signing_pub_key);
+
+        (
+            Vec::from([signing_account]),
+            signature_valid,
+            SigningAuthority::MasterKey,
+        )
+    } else if let Some(Value::Array(signers)) = json.get("Signers") {
+        let mut signer_accounts = Vec::new();
+        let mut signatures_valid = !signers.is_empty();
+        for signer in signers {
+            let signer = &signer["Signer"];
+            let (Some(account), Some(signing_pub_key), Some(txn_signature)) = (
+                signer["Account"].as_str(),
+                signer["SigningPubKey"].as_str(),
+                signer["TxnSignature"].as_str(),
+            ) else {
+                return Err(XRPLVerifyBlobException::NotSigned.into());
+            };
+            // Each signer signs the transaction followed by their own
+            // AccountID.
+            let mut signing_data = TRANSACTION_MULTISIG_PREFIX.to_be_bytes().to_vec();
+            signing_data.extend_from_slice(&signing_fields);
+            signing_data.extend_from_slice(&decode_classic_address(account)?);
+            signatures_valid &= is_valid_message(&signing_data, txn_signature, signing_pub_key);
+            signer_accounts.push(derive_classic_address(signing_pub_key)?);
+        }
+
+        (
+            signer_accounts,
+            signatures_valid,
+            SigningAuthority::SignerList,
+        )
+    } else {
+        return Err(XRPLVerifyBlobException::NotSigned.into());
+    };
+
+    Ok(DecodedTransaction {
+        transaction: serde_json::from_value(Value::Object(json))?,
+        hash,
+        signers,
+        signatures_valid,
+        signing_authority,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{borrow::Cow, string::ToString};
+
+    use super::*;
+    use crate::{
+        asynch::{exceptions::XRPLError, transaction::sign},
+        core::binarycodec::{decode, encode},
+        models::{
+            transactions::{account_set::AccountSet, payment::Payment},
+            Amount, XRPAmount,
+        },
+        transaction::multisign,
+        wallet::Wallet,
+    };
+
+    fn wallet() -> Wallet {
+        Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap()
+    }
+
+    fn payment(account: &str) -> Payment<'static> {
+        serde_json::from_value(serde_json::json!({
+            "TransactionType": "Payment",
+            "Account": account,
+            "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+            "Amount": "1000000",
+            "Fee": "12",
+            "Sequence": 1
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_valid_blob() {
+        let wallet = wallet();
+        let mut payment = payment(&wallet.classic_address);
+        sign(&mut payment, &wallet, false).unwrap();
+        let tx_blob = encode(&payment).unwrap();
+
+        let decoded = verify_and_decode_blob::<Payment>(&tx_blob).unwrap();
+
+        assert!(decoded.signatures_valid);
+        assert_eq!(decoded.hash, hash_signed_tx(&tx_blob).unwrap());
+        assert_eq!(decoded.signers, [wallet.classic_address.as_str()]);
+        assert_eq!(decoded.signing_authority, SigningAuthority::MasterKey);
+        assert_eq!(decoded.transaction, payment);
+    }
+
+    #[test]
+    fn test_tampered_amount() {
+        let wallet = wallet();
+        let mut payment = payment(&wallet.classic_address);
+        sign(&mut payment, &wallet, false).unwrap();
+        let tx_blob = encode(&payment).unwrap();
+        let mut tampered = decode(&tx_blob).unwrap();
+        tampered["Amount"] = "100000000".into();
+        let tampered_blob = encode(&tampered).unwrap();
+
+        let decoded = verify_and_decode_blob::<Payment>(&tampered_blob).unwrap();
+
+        assert!(!decoded.signatures_valid);
+        assert_eq!(
+            decoded.transaction.amount,
+            Amount::XRPAmount(XRPAmount::from("100000000"))
+        );
+        assert_ne!(decoded.hash, hash_signed_tx(&tx_blob).unwrap());
+    }
+
+    #[test]
+    fn test_signing_key_mismatch() {
+        let wallet = wallet();
+        let other_wallet = Wallet::create(None).unwrap();
+        let mut payment = payment(&wallet.classic_address);
+        sign(&mut payment, &other_wallet, false).unwrap();
+
+        let error = verify_and_decode_blob::<Payment>(&encode(&payment).unwrap()).unwrap_err();
+
+        assert!(matches!(
+            error,
+            XRPLError::XRPLVerifyBlobError(XRPLVerifyBlobException::SigningKeyMismatch {
+                ref account,
+                ref signing_account,
+            }) if *account == wallet.classic_address && *signing_account == other_wallet.classic_address
+        ));
+        assert!(error.to_string().contains("cannot be verified offline"));
+    }
+
+    #[test]
+    fn test_multisigned_blob() {
+        let wallet = wallet();
+        let wallet1 = Wallet::create(None).unwrap();
+        let wallet2 = Wallet::create(None).unwrap();
+        let mut multi_signed_tx = AccountSet::new(
+            Cow::from(wallet.classic_address.clone()),
+            None,
+            Some("12".into()),
+            None,
+            None,
+            None,
+            Some(1),
+            None,
+            None,
+            None,
+            None,
+            Some("6578616d706c652e636f6d".into()), // "example.com"
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let mut tx_1 = multi_signed_tx.clone();
+        sign(&mut tx_1, &wallet1, true).unwrap();
+        let mut tx_2 = multi_signed_tx.clone();
+        sign(&mut tx_2, &wallet2, true).unwrap();
+        let tx_list = [tx_1, tx_2].to_vec();
+        multisign(&mut multi_signed_tx, &tx_list).unwrap();
+        let tx_blob = encode(&multi_signed_tx).unwrap();
+
+        let decoded = verify_and_decode_blob::<AccountSet>(&tx_blob).unwrap();
+
+        assert!(decoded.signatures_valid);
+        assert_eq!(decoded.signing_authority, SigningAuthority::SignerList);
+        let mut signers = decoded.signers.clone();
+        signers.sort();
+        let mut expected = [
+            wallet1.classic_address.clone(),
+            wallet2.classic_address.clone(),
+        ];
+        expected.sort();
+        assert_eq!(signers, expected);
+
+        let mut tampered = decode(&tx_blob).unwrap();
+        tampered["Sequence"] = 2.into();
+        let decoded = verify_and_decode_blob::<AccountSet>(&encode(&tampered).unwrap()).unwrap();
+        assert!(!decoded.signatures_valid);
+    }
+
+    #[test]
+    fn test_unsigned_blob() {
+        let tx_blob = encode(&payment(&wallet().classic_address)).unwrap();
+
+        assert!(matches!(
+            verify_and_decode_blob::<Payment>(&tx_blob),
+            Err(XRPLError::XRPLVerifyBlobError(
+                XRPLVerifyBlobException::NotSigned
+            ))
+        ));
+    }
+}
@@ -0,0 +1,58 @@
+use embassy_futures::block_on;
+
+use crate::{
+    asynch::{
+        clients::XRPLClient,
+        exceptions::XRPLHelperResult,
+        trustline::{
+            create_trustline as async_create_trustline, get_trustline as async_get_trustline,
+            set_trustline_flag as async_set_trustline_flag, TrustlineChange,
+        },
+    },
+    models::{results::account_lines::TrustLine, transactions::trust_set::TrustSetFlag},
+    wallet::Wallet,
+};
+use alloc::string::String;
+
+pub fn get_trustline<C>(
+    client: &C,
+    account: &str,
+    peer: &str,
+    currency: &str,
+) -> XRPLHelperResult<Option<TrustLine<'static>>>
+where
+    C: XRPLClient,
+{
+    block_on(async_get_trustline(client, account, peer, currency))
+}
+
+pub fn create_trustline<C>(
+    client: &C,
+    wallet: &Wallet,
+    currency: &str,
+    issuer: &str,
+    limit: &str,
+    no_ripple: bool,
+) -> XRPLHelperResult<TrustlineChange>
+where
+    C: XRPLClient,
+{
+    block_on(async_create_trustline(
+        client, wallet, currency, issuer, limit, no_ripple,
+    ))
+}
+
+pub fn set_trustline_flag<C>(
+    client: &C,
+    wallet: &Wallet,
+    currency: &str,
+    peer: &str,
+    flag: TrustSetFlag,
+) -> XRPLHelperResult<String>
+where
+    C: XRPLClient,
+{
+    block_on(async_set_trustline_flag(
+        client, wallet, currency, peer, flag,
+    ))
+}
@@ -0,0 +1,166 @@
+//! Concise transaction identifiers (CTIDs), which reference a validated
+//! transaction by its ledger, its index in the ledger and its network.
+//!
+//! See XLS-37 Concise Transaction Identifier:
+//! `<https://github.com/XRPLF/XRPL-Standards/tree/master/XLS-0037-concise-transaction-identifier-ctid>`
+
+use alloc::{format, string::String};
+
+use crate::utils::exceptions::{XRPLCTIDException, XRPLUtilsResult};
+
+/// The largest ledger sequence a CTID can reference. The leading `C`
+/// nibble leaves 28 bits for it.
+pub const MAX_CTID_LEDGER_SEQUENCE: u32 = 0x0FFF_FFFF;
+/// The length of a CTID as hex.
+pub const CTID_LENGTH: usize = 16;
+/// The leading nibble of every CTID.
+const CTID_PREFIX: u64 = 0xC;
+
+/// Encode the CTID of the transaction at `txn_index` in the ledger
+/// `ledger_seq` of the network `network_id`.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::ctid::encode_ctid;
+///
+/// assert_eq!(encode_ctid(13249191, 12, 1).unwrap(), "C0CA2AA7000C0001");
+/// assert!(encode_ctid(0x10000000, 0, 0).is_err());
+/// ```
+pub fn encode_ctid(ledger_seq: u32, txn_index: u16, network_id: u16) -> XRPLUtilsResult<String> {
+    if ledger_seq > MAX_CTID_LEDGER_SEQUENCE {
+        return Err(XRPLCTIDException::LedgerSequenceTooLarge {
+            max: MAX_CTID_LEDGER_SEQUENCE,
+            found: ledger_seq,
+        }
+        .into());
+    }
+    let ctid = (CTID_PREFIX << 60)
+        | (u64::from(ledger_seq) << 32)
+        | (u64::from(txn_index) << 16)
+        | u64::from(network_id);
+
+    Ok(format!("{ctid:016X}"))
+}
+
+/// Decode a CTID into its ledger sequence, transaction index and
+/// network ID. Only the canonical form is accepted: 16 uppercase hex
+/// characters starting with `C`.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::ctid::decode_ctid;
+///
+/// assert_eq!(decode_ctid("C0CA2AA7000C0001").unwrap(), (13249191, 12, 1));
+/// assert!(decode_ctid("D0CA2AA7000C0001").is_err());
+/// ```
+pub fn decode_ctid(ctid: &str) -> XRPLUtilsResult<(u32, u16, u16)> {
+    if ctid.len() != CTID_LENGTH {
+        return Err(XRPLCTIDException::InvalidLength {
+            expected: CTID_LENGTH,
+            found: ctid.len(),
+        }
+        .into());
+    }
+    if !ctid
+        .bytes()
+        .all(|byte| byte.is_ascii_digit() || (b'A'..=b'F').contains(&byte))
+    {
+        return Err(XRPLCTIDException::InvalidHex(ctid.into()).into());
+    }
+    // Checked above to be 16 hex characters.
+    let value =
+        u64::from_str_radix(ctid, 16).map_err(|_| XRPLCTIDException::InvalidHex(ctid.into()))?;
+    if value >> 60 != CTID_PREFIX {
+        return Err(XRPLCTIDException::InvalidPrefix(ctid.into()).into());
+    }
+
+    Ok((
+        ((value >> 32) as u32) & MAX_CTID_LEDGER_SEQUENCE,
+        (value >> 16) as u16,
+        value as u16,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::exceptions::XRPLUtilsException;
+
+    /// The test vectors of XLS-37.
+    const VECTORS: [(u32, u16, u16, &str); 4] = [
+        (0x0FFF_FFFF, 0xFFFF, 0xFFFF, "CFFFFFFFFFFFFFFF"),
+        (0, 0, 0, "C000000000000000"),
+        (1, 2, 3, "C000000100020003"),
+        (13249191, 12, 1, "C0CA2AA7000C0001"),
+    ];
+
+    #[test]
+    fn test_encode_ctid() {
+        for (ledger_seq, txn_index, network_id, ctid) in VECTORS {
+            assert_eq!(
+                encode_ctid(ledger_seq, txn_index, network_id).unwrap(),
+                ctid
+            );
+        }
+        assert_eq!(
+            encode_ctid(0x1000_0000, 0, 0),
+            Err(XRPLUtilsException::CTIDError(
+                XRPLCTIDException::LedgerSequenceTooLarge {
+                    max: MAX_CTID_LEDGER_SEQUENCE,
+                    found: 0x1000_0000
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_decode_ctid() {
+        for (ledger_seq, txn_index, network_id, ctid) in VECTORS {
+            assert_eq!(
+                decode_ctid(ctid).unwrap(),
+                (ledger_seq, txn_index, network_id)
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_invalid_ctid() {
+        // A CTID has no checksum, so a corrupted one only fails if it
+        // is no longer well-formed.
+        assert!(matches!(
+            decode_ctid("D0CA2AA7000C0001"),
+            Err(XRPLUtilsException::CTIDError(
+                XRPLCTIDException::InvalidPrefix(_)
+            ))
+        ));
+        assert!(matches!(
+            decode_ctid("C0CA2AA7000C000"),
+            Err(XRPLUtilsException::CTIDError(
+                XRPLCTIDException::InvalidLength {
+                    expected: 16,
+                    found: 15
+                }
+            ))
+        ));
+        assert!(matches!(
+            decode_ctid("C0CA2AA7000C00001"),
+            Err(XRPLUtilsException::CTIDError(
+                XRPLCTIDException::InvalidLength { .. }
+            ))
+        ));
+        for ctid in ["c0ca2aa7000c0001", "C0CA2AA7000C000G", "+0CA2AA7000C0001"] {
+            assert!(matches!(
+                decode_ctid(ctid),
+                Err(XRPLUtilsException::CTIDError(
+                    XRPLCTIDException::InvalidHex(_)
+                ))
+            ));
+        }
+    }
+}
@@ -12,6 +12,8 @@ pub type XRPLUtilsResult<T, E = XRPLUtilsException> = core::result::Result<T, E>
 pub enum XRPLUtilsException {
     #[error("XRPL Time Range error: {0}")]
     XRPLTimeRangeError(#[from] XRPLTimeRangeException),
+    #[error("CTID error: {0}")]
+    CTIDError(#[from] XRPLCTIDException),
     #[error("XRP Range error: {0}")]
     XRPRangeError(#[from] XRPRangeException),
     #[error("ISO Code error: {0}")]
@@ -24,6 +26,8 @@ pub enum XRPLUtilsException {
     SerdeJsonError(#[from] XRPLSerdeJsonError),
     #[error("Invalid Hex error: {0}")]
     FromHexError(#[from] hex::FromHexError),
+    #[error("Parse int error: {0}")]
+    ParseIntError(#[from] core::num::ParseIntError),
 }
 
 #[derive(Debug, Clone, PartialEq, Error)]
@@ -37,6 +41,19 @@ pub enum XRPLTimeRangeException {
     InvalidLocalTime,
 }
 
+#[derive(Debug, Clone, PartialEq, Error)]
+#[non_exhaustive]
+pub enum XRPLCTIDException {
+    #[error("Invalid CTID ledger sequence (max: {max} found: {found})")]
+    LedgerSequenceTooLarge { max: u32, found: u32 },
+    #[error("Invalid CTID length (expected: {expected} found: {found})")]
+    InvalidLength { expected: usize, found: usize },
+    #[error("Invalid CTID, expected uppercase hex (found: {0})")]
+    InvalidHex(String),
+    #[error("Invalid CTID, expected a leading `C` (found: {0})")]
+    InvalidPrefix(String),
+}
+
 #[derive(Debug, Clone, PartialEq, Error)]
 #[non_exhaustive]
 pub enum XRPRangeException {
@@ -50,6 +67,10 @@ pub enum XRPRangeException {
     InvalidXRPAmountTooSmall { min: String, found: String },
     #[error("Invalid XRP amount too large (max: {max} found: {found})")]
     InvalidXRPAmountTooLarge { max: u64, found: String },
+    #[error(
+        "Invalid Issued Currency value, too many significant digits (max: {max} found: {found})"
+    )]
+    InvalidICPrecision { max: u8, found: usize },
     #[error("Invalid Issued Currency precision too small (min: {min} found: {found})")]
     InvalidICPrecisionTooSmall { min: i32, found: i32 },
     #[error("Invalid Issued Currency precision too large (max: {max} found: {found})")]
@@ -60,6 +81,14 @@ pub enum XRPRangeException {
     InvalidICSerializationLength { expected: usize, found: usize },
     #[error("Invalid Issued Currency amount overflow (max: {max} found: {found})")]
     UnexpectedICAmountOverflow { max: usize, found: usize },
+    #[error("Invalid transfer rate (min: {min} max: {max} found: {found})")]
+    InvalidTransferRate { min: u32, max: u32, found: String },
+    #[error("Invalid amount, expected a plain decimal number (found: {found})")]
+    InvalidAmountFormat { found: String },
+    #[error("Invalid negative amount (found: {found})")]
+    InvalidNegativeAmount { found: String },
+    #[error("Invalid XRP amount, too many decimal places (max: {max} found: {found})")]
+    InvalidXRPAmountPrecision { max: u32, found: String },
 }
 
 #[derive(Debug, Clone, PartialEq, Error)]
@@ -92,6 +121,9 @@ impl From<serde_json::Error> for XRPLUtilsException {
 #[cfg(feature = "std")]
 impl alloc::error::Error for XRPLTimeRangeException {}
 
+#[cfg(feature = "std")]
+impl alloc::error::Error for XRPLCTIDException {}
+
 #[cfg(feature = "std")]
 impl alloc::error::Error for XRPRangeException {}
 
@@ -0,0 +1,294 @@
+//! The value of an issued currency amount, in the number domain of
+//! the XRP Ledger.
+//!
+//! See Issued Currency Precision:
+//! `<https://xrpl.org/currency-formats.html#issued-currency-precision>`
+
+use core::{convert::TryFrom, fmt::Display, str::FromStr};
+
+use alloc::string::{String, ToString};
+use bigdecimal::{num_bigint::BigInt, BigDecimal, Signed, ToPrimitive, Zero};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    exceptions::{XRPLUtilsException, XRPLUtilsResult, XRPRangeException},
+    MAX_IOU_EXPONENT, MAX_IOU_PRECISION, MIN_IOU_EXPONENT,
+};
+
+/// The smallest mantissa of a normalized non-zero value.
+pub const MIN_IOU_MANTISSA: i64 = 1_000_000_000_000_000;
+/// The largest mantissa of a normalized value.
+pub const MAX_IOU_MANTISSA: i64 = 9_999_999_999_999_999;
+
+/// The value of an issued currency amount: a mantissa of up to 16
+/// significant digits and an exponent between -96 and 80, so from
+/// `1e-81` to `9999999999999999e80` in magnitude.
+///
+/// A value outside of that range, or with more significant digits,
+/// is rejected rather than rounded. Converting from a float goes
+/// through its shortest decimal representation, so `0.1` is exact
+/// but `0.1 + 0.2` has 17 significant digits and is rejected.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use bigdecimal::BigDecimal;
+/// use core::str::FromStr;
+/// use xrpl::utils::IOUValue;
+///
+/// let value = IOUValue::from_str("12.5").unwrap();
+/// assert_eq!(value.mantissa(), 1250000000000000);
+/// assert_eq!(value.exponent(), -14);
+/// assert_eq!(BigDecimal::from(value), BigDecimal::from_str("12.5").unwrap());
+///
+/// assert!(IOUValue::from_str("9999999999999999e80").is_ok());
+/// assert!(IOUValue::from_str("1e-82").is_err());
+/// assert!(IOUValue::from_f64(0.1 + 0.2).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct IOUValue {
+    mantissa: i64,
+    exponent: i32,
+}
+
+impl IOUValue {
+    pub const ZERO: Self = Self {
+        mantissa: 0,
+        exponent: 0,
+    };
+
+    /// The value `mantissa * 10^exponent`, normalized. Fails if it
+    /// cannot be represented without losing digits.
+    pub fn new(mantissa: i64, exponent: i32) -> XRPLUtilsResult<Self> {
+        Self::normalize(mantissa.into(), exponent.into())
+    }
+
+    /// A value from a float, through its shortest decimal
+    /// representation.
+    pub fn from_f64(value: f64) -> XRPLUtilsResult<Self> {
+        if !value.is_finite() {
+            return Err(XRPRangeException::InvalidICAmount.into());
+        }
+
+        Self::from_str(&value.to_string())
+    }
+
+    /// The signed mantissa, between 10^15 and 10^16 - 1 in magnitude,
+    /// or 0.
+    pub fn mantissa(&self) -> i64 {
+        self.mantissa
+    }
+
+    pub fn exponent(&self) -> i32 {
+        self.exponent
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.mantissa < 0
+    }
+
+    fn normalize(mantissa: BigInt, mut exponent: i64) -> XRPLUtilsResult<Self> {
+        if mantissa.is_zero() {
+            return Ok(Self::ZERO);
+        }
+        let is_negative = mantissa.is_negative();
+        let mut mantissa = mantissa.abs();
+        // Trailing zeros are not significant.
+        let ten = BigInt::from(10);
+        while (&mantissa % &ten).is_zero() {
+            mantissa /= &ten;
+            exponent += 1;
+        }
+        let precision = mantissa.to_string().len();
+        if precision > MAX_IOU_PRECISION as usize {
+            return Err(XRPRangeException::InvalidICPrecision {
+                max: MAX_IOU_PRECISION,
+                found: precision,
+            }
+            .into());
+        }
+        let mut mantissa = mantissa
+            .to_i64()
+            .ok_or(XRPRangeException::InvalidICAmount)?;
+        while mantissa < MIN_IOU_MANTISSA {
+            mantissa *= 10;
+            exponent -= 1;
+        }
+        let found = exponent.clamp(i32::MIN.into(), i32::MAX.into()) as i32;
+        if found > MAX_IOU_EXPONENT {
+            Err(XRPRangeException::InvalidICPrecisionTooLarge {
+                max: MAX_IOU_EXPONENT,
+                found,
+            }
+            .into())
+        } else if found < MIN_IOU_EXPONENT {
+            Err(XRPRangeException::InvalidICPrecisionTooSmall {
+                min: MIN_IOU_EXPONENT,
+                found,
+            }
+            .into())
+        } else {
+            Ok(Self {
+                mantissa: if is_negative { -mantissa } else { mantissa },
+                exponent: found,
+            })
+        }
+    }
+}
+
+impl TryFrom<&BigDecimal> for IOUValue {
+    type Error = XRPLUtilsException;
+
+    fn try_from(value: &BigDecimal) -> XRPLUtilsResult<Self> {
+        let (mantissa, scale) = value.as_bigint_and_exponent();
+
+        Self::normalize(mantissa, -scale)
+    }
+}
+
+impl TryFrom<BigDecimal> for IOUValue {
+    type Error = XRPLUtilsException;
+
+    fn try_from(value: BigDecimal) -> XRPLUtilsResult<Self> {
+        Self::try_from(&value)
+    }
+}
+
+impl From<IOUValue> for BigDecimal {
+    fn from(value: IOUValue) -> Self {
+        BigDecimal::new(value.mantissa.into(), -i64::from(value.exponent)).normalized()
+    }
+}
+
+impl FromStr for IOUValue {
+    type Err = XRPLUtilsException;
+
+    fn from_str(value: &str) -> XRPLUtilsResult<Self> {
+        Self::try_from(BigDecimal::from_str(value)?)
+    }
+}
+
+impl Display for IOUValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", BigDecimal::from(*self))
+    }
+}
+
+impl Serialize for IOUValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for IOUValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        IOUValue::from_str(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_exponent_boundaries() {
+        let smallest = IOUValue::from_str("1e-81").unwrap();
+        assert_eq!(smallest.mantissa(), MIN_IOU_MANTISSA);
+        assert_eq!(smallest.exponent(), MIN_IOU_EXPONENT);
+        assert_eq!(
+            IOUValue::from_str("-0.000000000000000000000000000000000000000000000000000000000000000000000000000000001").unwrap(),
+            IOUValue::new(-MIN_IOU_MANTISSA, MIN_IOU_EXPONENT).unwrap()
+        );
+
+        let largest = IOUValue::from_str("9999999999999999e80").unwrap();
+        assert_eq!(largest.mantissa(), MAX_IOU_MANTISSA);
+        assert_eq!(largest.exponent(), MAX_IOU_EXPONENT);
+        assert_eq!(
+            BigDecimal::from(largest),
+            BigDecimal::from_str("9999999999999999e80").unwrap()
+        );
+
+        assert!(matches!(
+            IOUValue::from_str("9.99e-82"),
+            Err(XRPLUtilsException::XRPRangeError(
+                XRPRangeException::InvalidICPrecisionTooSmall { found: -97, .. }
+            ))
+        ));
+        assert!(matches!(
+            IOUValue::from_str("1e96"),
+            Err(XRPLUtilsException::XRPRangeError(
+                XRPRangeException::InvalidICPrecisionTooLarge { found: 81, .. }
+            ))
+        ));
+        assert!(IOUValue::from_str("1e-100000").is_err());
+    }
+
+    #[test]
+    fn test_precision() {
+        assert_eq!(
+            IOUValue::from_str("1111111111111111000").unwrap(),
+            IOUValue::new(1111111111111111, 3).unwrap()
+        );
+        assert!(matches!(
+            IOUValue::from_str("1111111111111111.1"),
+            Err(XRPLUtilsException::XRPRangeError(
+                XRPRangeException::InvalidICPrecision { found: 17, .. }
+            ))
+        ));
+        // Trailing zeros are not significant, but digits are not
+        // dropped to fit the mantissa.
+        assert_eq!(
+            IOUValue::new(10_000_000_000_000_000, 0).unwrap(),
+            IOUValue::new(1, 16).unwrap()
+        );
+        assert!(IOUValue::new(10_000_000_000_000_001, 0).is_err());
+    }
+
+    #[test]
+    fn test_zero() {
+        for zero in ["0", "-0", "0.0", "0e-200"] {
+            assert_eq!(IOUValue::from_str(zero).unwrap(), IOUValue::ZERO);
+        }
+        assert_eq!(IOUValue::ZERO.to_string(), "0");
+    }
+
+    #[test]
+    fn test_floats() {
+        assert_eq!(
+            IOUValue::from_f64(0.1).unwrap(),
+            IOUValue::from_str("0.1").unwrap()
+        );
+        assert_eq!(
+            IOUValue::from_f64(-1e-81).unwrap(),
+            IOUValue::new(-1, -81).unwrap()
+        );
+        assert!(IOUValue::from_f64(0.1 + 0.2).is_err());
+        assert!(IOUValue::from_f64(f64::NAN).is_err());
+        assert!(IOUValue::from_f64(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_serde() {
+        let value = IOUValue::from_str("-12.50").unwrap();
+        let serialized = serde_json::to_string(&value).unwrap();
+        assert_eq!(serialized, r#""-12.5""#);
+
+        let deserialized: IOUValue = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, value);
+        assert!(serde_json::from_str::<IOUValue>(r#""1e-82""#).is_err());
+    }
+}
@@ -1,18 +1,43 @@
 //! Convenience utilities for the XRP Ledger
 
+pub mod ctid;
 pub mod exceptions;
+pub mod iou_value;
+pub mod ratio;
 pub mod time_conversion;
 #[cfg(feature = "models")]
 pub(crate) mod transactions;
+#[cfg(feature = "models")]
+pub mod txn_parser;
 pub mod xrpl_conversion;
 
+pub use self::ctid::*;
+pub use self::iou_value::*;
+pub use self::ratio::*;
 pub use self::time_conversion::*;
+#[cfg(feature = "models")]
+pub use self::txn_parser::*;
 pub use self::xrpl_conversion::*;
 
 use crate::constants::*;
+use alloc::boxed::Box;
 use alloc::vec::Vec;
+use once_cell::race::OnceBox;
 use regex::Regex;
 
+// Compiling a regex costs far more than matching it, and these are
+// matched for every address and currency encoded.
+static HEX_CURRENCY: OnceBox<Regex> = OnceBox::new();
+static ISO_CURRENCY: OnceBox<Regex> = OnceBox::new();
+
+fn hex_currency() -> &'static Regex {
+    HEX_CURRENCY.get_or_init(|| Box::new(Regex::new(HEX_CURRENCY_REGEX).expect("HEX_CURRENCY")))
+}
+
+fn iso_currency() -> &'static Regex {
+    ISO_CURRENCY.get_or_init(|| Box::new(Regex::new(ISO_CURRENCY_REGEX).expect("ISO_CURRENCY")))
+}
+
 /// Determine if the address string is a hex address.
 ///
 /// # Examples
@@ -27,8 +52,7 @@ use regex::Regex;
 /// assert!(is_hex_address(value));
 /// ```
 pub fn is_hex_address(value: &str) -> bool {
-    let regex = Regex::new(HEX_CURRENCY_REGEX).expect("is_hex_address");
-    regex.is_match(value)
+    hex_currency().is_match(value)
 }
 
 /// Tests if value is a valid 3-char iso code.
@@ -45,8 +69,7 @@ pub fn is_hex_address(value: &str) -> bool {
 /// assert!(is_iso_code(value));
 /// ```
 pub fn is_iso_code(value: &str) -> bool {
-    let regex = Regex::new(ISO_CURRENCY_REGEX).expect("is_iso_code");
-    regex.is_match(value)
+    iso_currency().is_match(value)
 }
 
 /// Tests if value is a valid 40-char hex currency string.
@@ -63,8 +86,7 @@ pub fn is_iso_code(value: &str) -> bool {
 /// assert!(is_iso_hex(value));
 /// ```
 pub fn is_iso_hex(value: &str) -> bool {
-    let regex = Regex::new(HEX_CURRENCY_REGEX).expect("_is_hex");
-    regex.is_match(value)
+    hex_currency().is_match(value)
 }
 
 /// Converter to byte array with endianness.
@@ -0,0 +1,264 @@
+//! A unit-safe ratio for percentages, fractions and basis points.
+
+use core::{fmt::Display, ops::Mul, str::FromStr};
+
+use alloc::string::{String, ToString};
+use bigdecimal::{BigDecimal, One, RoundingMode, Zero};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::constants::{MAX_TRANSFER_RATE, MIN_TRANSFER_RATE, SPECIAL_CASE_TRANFER_RATE};
+
+use super::exceptions::{XRPLUtilsResult, XRPRangeException};
+
+/// A ratio such as a fee cushion, a slippage tolerance or a
+/// transfer fee. It is stored as a fraction, so 1.5% is `0.015`.
+///
+/// Construct it with the constructor matching the unit you have,
+/// so 1 and 0.01 can't be confused for 1%. Serializes as its
+/// fraction string.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::Ratio;
+///
+/// let from_percent = Ratio::from_percent(1.5).unwrap();
+/// let from_fraction = Ratio::from_fraction(0.015).unwrap();
+/// let from_basis_points = Ratio::from_basis_points(150);
+///
+/// assert_eq!(from_percent, from_fraction);
+/// assert_eq!(from_percent, from_basis_points);
+/// assert_eq!(from_percent.to_string(), "0.015");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ratio(BigDecimal);
+
+impl Ratio {
+    /// A ratio of a percentage, e.g. `1.5` for 1.5%.
+    pub fn from_percent(percent: f64) -> XRPLUtilsResult<Self> {
+        Ok(Self(parse_f64(percent)? / BigDecimal::from(100)))
+    }
+
+    /// A ratio of a fraction, e.g. `0.015` for 1.5%.
+    pub fn from_fraction(fraction: f64) -> XRPLUtilsResult<Self> {
+        Ok(Self(parse_f64(fraction)?))
+    }
+
+    /// A ratio of basis points, e.g. `150` for 1.5%.
+    pub fn from_basis_points(basis_points: u32) -> Self {
+        Self(BigDecimal::from(basis_points) / BigDecimal::from(10_000))
+    }
+
+    /// The ratio as a fraction, e.g. `0.015` for 1.5%.
+    pub fn as_fraction(&self) -> &BigDecimal {
+        &self.0
+    }
+
+    /// The ratio as a percentage, e.g. `1.5` for 1.5%.
+    pub fn to_percent(&self) -> BigDecimal {
+        (&self.0 * BigDecimal::from(100)).normalized()
+    }
+
+    /// Scale a value up by the ratio, e.g. to add a cushion of 20%
+    /// to a fee: `value * (1 + ratio)`.
+    pub fn add_to(&self, value: &BigDecimal) -> BigDecimal {
+        value * (BigDecimal::one() + &self.0)
+    }
+
+    /// Scale a value down by the ratio, e.g. to apply a slippage
+    /// tolerance of 1% to an amount: `value * (1 - ratio)`.
+    pub fn subtract_from(&self, value: &BigDecimal) -> BigDecimal {
+        value * (BigDecimal::one() - &self.0)
+    }
+}
+
+fn parse_f64(value: f64) -> XRPLUtilsResult<BigDecimal> {
+    // Go through the shortest decimal representation so `0.015`
+    // does not turn into the exact binary expansion of the float.
+    Ok(BigDecimal::from_str(&value.to_string())?)
+}
+
+impl Display for Ratio {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0.normalized())
+    }
+}
+
+impl FromStr for Ratio {
+    type Err = bigdecimal::ParseBigDecimalError;
+
+    /// Parse a ratio from its fraction string.
+    fn from_str(fraction: &str) -> Result<Self, Self::Err> {
+        Ok(Self(BigDecimal::from_str(fraction)?))
+    }
+}
+
+impl Mul<&Ratio> for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn mul(self, ratio: &Ratio) -> BigDecimal {
+        self * &ratio.0
+    }
+}
+
+impl Mul<&Ratio> for BigDecimal {
+    type Output = BigDecimal;
+
+    fn mul(self, ratio: &Ratio) -> BigDecimal {
+        self * &ratio.0
+    }
+}
+
+impl Serialize for Ratio {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Ratio {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let fraction = String::deserialize(deserializer)?;
+
+        Ratio::from_str(&fraction).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Convert an account's `TransferRate` into the transfer fee it
+/// charges. A `TransferRate` of 0 means no fee.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::{transfer_rate_to_ratio, Ratio};
+///
+/// let ratio = transfer_rate_to_ratio(1005000000).unwrap();
+///
+/// assert_eq!(ratio, Ratio::from_percent(0.5).unwrap());
+/// ```
+pub fn transfer_rate_to_ratio(transfer_rate: u32) -> XRPLUtilsResult<Ratio> {
+    if transfer_rate == SPECIAL_CASE_TRANFER_RATE {
+        return Ok(Ratio(BigDecimal::zero()));
+    }
+    if !(MIN_TRANSFER_RATE..=MAX_TRANSFER_RATE).contains(&transfer_rate) {
+        return Err(XRPRangeException::InvalidTransferRate {
+            min: MIN_TRANSFER_RATE,
+            max: MAX_TRANSFER_RATE,
+            found: transfer_rate.to_string(),
+        }
+        .into());
+    }
+    let fee = BigDecimal::from(transfer_rate - MIN_TRANSFER_RATE);
+
+    Ok(Ratio(fee / BigDecimal::from(MIN_TRANSFER_RATE)))
+}
+
+/// Convert a transfer fee into the `TransferRate` to set on an
+/// account. The fee must be between 0% and 100% and is rounded
+/// down to the precision of `TransferRate`.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::{ratio_to_transfer_rate, Ratio};
+///
+/// let transfer_rate = ratio_to_transfer_rate(&Ratio::from_percent(0.5).unwrap()).unwrap();
+///
+/// assert_eq!(transfer_rate, 1005000000);
+/// ```
+pub fn ratio_to_transfer_rate(ratio: &Ratio) -> XRPLUtilsResult<u32> {
+    let fee = &ratio.0;
+    if fee.is_zero() {
+        return Ok(SPECIAL_CASE_TRANFER_RATE);
+    }
+    if fee < &BigDecimal::zero() || fee > &BigDecimal::one() {
+        return Err(XRPRangeException::InvalidTransferRate {
+            min: MIN_TRANSFER_RATE,
+            max: MAX_TRANSFER_RATE,
+            found: ratio.to_string(),
+        }
+        .into());
+    }
+    let transfer_rate = (BigDecimal::one() + fee) * BigDecimal::from(MIN_TRANSFER_RATE);
+    let transfer_rate = transfer_rate.with_scale_round(0, RoundingMode::Down);
+
+    Ok(u32::from_str(&transfer_rate.to_string())?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_constructors_are_equivalent() {
+        let from_percent = Ratio::from_percent(1.5).unwrap();
+        let from_fraction = Ratio::from_fraction(0.015).unwrap();
+        let from_basis_points = Ratio::from_basis_points(150);
+
+        assert_eq!(from_percent, from_fraction);
+        assert_eq!(from_fraction, from_basis_points);
+        assert_eq!(
+            from_percent.to_percent(),
+            BigDecimal::from_str("1.5").unwrap()
+        );
+        assert_eq!(
+            Ratio::from_percent(100.0).unwrap(),
+            Ratio::from_fraction(1.0).unwrap()
+        );
+        assert!(Ratio::from_percent(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let fee = BigDecimal::from(10);
+        let cushion = Ratio::from_percent(20.0).unwrap();
+
+        assert_eq!(&fee * &cushion, BigDecimal::from(2));
+        assert_eq!(cushion.add_to(&fee), BigDecimal::from(12));
+        assert_eq!(cushion.subtract_from(&fee), BigDecimal::from(8));
+    }
+
+    #[test]
+    fn test_serde() {
+        let ratio = Ratio::from_basis_points(150);
+        let serialized = serde_json::to_string(&ratio).unwrap();
+        assert_eq!(serialized, r#""0.015""#);
+
+        let deserialized: Ratio = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, ratio);
+        assert!(serde_json::from_str::<Ratio>(r#""1%""#).is_err());
+    }
+
+    #[test]
+    fn test_transfer_rate_conversions() {
+        for (transfer_rate, percent) in [
+            (0, 0.0),
+            (1000000000, 0.0),
+            (1005000000, 0.5),
+            (1234567890, 23.456789),
+            (2000000000, 100.0),
+        ] {
+            let ratio = transfer_rate_to_ratio(transfer_rate).unwrap();
+            assert_eq!(ratio, Ratio::from_percent(percent).unwrap());
+            if transfer_rate != 1000000000 {
+                assert_eq!(ratio_to_transfer_rate(&ratio).unwrap(), transfer_rate);
+            }
+        }
+
+        assert!(transfer_rate_to_ratio(999999999).is_err());
+        assert!(transfer_rate_to_ratio(2000000001).is_err());
+        assert!(ratio_to_transfer_rate(&Ratio::from_percent(100.1).unwrap()).is_err());
+        assert!(ratio_to_transfer_rate(&Ratio::from_percent(-1.0).unwrap()).is_err());
+    }
+}
@@ -10,8 +10,9 @@ use super::exceptions::XRPLUtilsResult;
 
 /// The "Ripple Epoch" of 2000-01-01T00:00:00 UTC
 pub const RIPPLE_EPOCH: i64 = 946684800;
-/// The maximum time that can be expressed on the XRPL
-pub const MAX_XRPL_TIME: i64 = i64::pow(2, 32);
+/// The maximum time that can be expressed on the XRPL. Ripple times
+/// are unsigned 32-bit integers, so this is 2136-02-07T06:28:15 UTC.
+pub const MAX_XRPL_TIME: i64 = u32::MAX as i64;
 
 /// Ensures time does not exceed max representable on XRPL.
 fn _ripple_check_max<T>(time: i64, ok: T) -> XRPLUtilsResult<T> {
@@ -136,6 +137,8 @@ mod test {
         assert_eq!(posix_to_ripple_time(RIPPLE_EPOCH), Ok(0_i64));
     }
 
+    // `Utc::now` needs the clock, which only std builds have.
+    #[cfg(feature = "std")]
     #[test]
     fn accept_posix_round_trip() {
         let current_time: i64 = Utc::now().timestamp();
@@ -145,6 +148,7 @@ mod test {
         assert_eq!(Ok(current_time), round_trip_time);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn accept_datetime_round_trip() -> XRPLUtilsResult<()> {
         let current_time: DateTime<Utc> = match Utc.timestamp_opt(Utc::now().timestamp(), 0) {
@@ -194,6 +198,23 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn accept_max_xrpl_time() -> XRPLUtilsResult<()> {
+        let expected = match Utc.with_ymd_and_hms(2136, 2, 7, 6, 28, 15) {
+            LocalResult::Single(dt) => dt,
+            _ => return Err(XRPLTimeRangeException::InvalidLocalTime.into()),
+        };
+        assert_eq!(Ok(expected), ripple_time_to_datetime(u32::MAX.into()));
+        assert_eq!(Ok(u32::MAX.into()), datetime_to_ripple_time(expected));
+
+        // 2^32 no longer fits the ripple time fields.
+        assert!(ripple_time_to_datetime(MAX_XRPL_TIME + 1).is_err());
+        assert!(datetime_to_ripple_time(expected + chrono::Duration::seconds(1)).is_err());
+        assert!(ripple_time_to_posix(MAX_XRPL_TIME + 1).is_err());
+
+        Ok(())
+    }
+
     /// "Ripple Epoch" time's equivalent to the
     /// "Year 2038 problem" is not until 2136
     /// because it uses an *unsigned* 32-bit int
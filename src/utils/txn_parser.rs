@@ -0,0 +1,304 @@
+//! Read what a transaction did from its metadata.
+
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec::Vec,
+};
+use serde_json::Value;
+
+use crate::models::{
+    transactions::{
+        metadata::{AffectedNode, TransactionMetadata, DELIVERED_AMOUNT_UNAVAILABLE},
+        payment::{Payment, PaymentFlag},
+        Transaction,
+    },
+    Amount,
+};
+
+/// Get the amount a Payment actually delivered.
+///
+/// Never read the `Amount` of a Payment to know what was received:
+/// with the tfPartialPayment flag, it is only an upper bound. This
+/// prefers the amount recorded in the metadata and only falls back
+/// to `Amount` when the Payment could not deliver less.
+///
+/// Returns `None` if the Payment failed, or if it is from before
+/// 2014-01-20 and the amount delivered is unknown.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::models::transactions::{metadata::TransactionMetadata, payment::Payment};
+/// use xrpl::models::Amount;
+/// use xrpl::utils::get_delivered_amount;
+///
+/// let payment: Payment = serde_json::from_str(r#"{
+///     "TransactionType": "Payment",
+///     "Account": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+///     "Destination": "ra5nK24KXen9AHvsdFTKHSANinZseWnPcX",
+///     "Amount": "1000000",
+///     "Flags": 131072
+/// }"#).unwrap();
+/// let meta: TransactionMetadata = serde_json::from_str(r#"{
+///     "AffectedNodes": [],
+///     "TransactionIndex": 0,
+///     "TransactionResult": "tesSUCCESS",
+///     "DeliveredAmount": "1"
+/// }"#).unwrap();
+///
+/// assert_eq!(get_delivered_amount(&meta, &payment), Some(Amount::from("1")));
+/// ```
+pub fn get_delivered_amount<'a>(
+    meta: &TransactionMetadata<'a>,
+    tx: &Payment<'a>,
+) -> Option<Amount<'a>> {
+    if meta.transaction_result != "tesSUCCESS" {
+        return None;
+    }
+    if let Some(amount) = &meta.delivered_amount_synthetic {
+        return match amount {
+            Amount::XRPAmount(amount) if amount.0 == DELIVERED_AMOUNT_UNAVAILABLE => None,
+            amount => Some(amount.clone()),
+        };
+    }
+    if let Some(amount) = &meta.delivered_amount {
+        return Some(amount.clone());
+    }
+    if tx.has_flag(&PaymentFlag::TfPartialPayment) {
+        return None;
+    }
+
+    Some(tx.amount.clone())
+}
+
+/// Get the IDs of the NFTokens a transaction minted.
+pub fn get_minted_nftoken_ids(meta: &TransactionMetadata<'_>) -> Vec<String> {
+    let (before, after) = nftoken_ids_before_and_after(meta);
+
+    after.difference(&before).map(|id| id.to_string()).collect()
+}
+
+/// Get the IDs of the NFTokens a transaction burned.
+pub fn get_burned_nftoken_ids(meta: &TransactionMetadata<'_>) -> Vec<String> {
+    let (before, after) = nftoken_ids_before_and_after(meta);
+
+    before.difference(&after).map(|id| id.to_string()).collect()
+}
+
+/// Collect the NFToken IDs held by the NFTokenPages a transaction
+/// touched, before and after it. Tokens move between pages when a
+/// page is split or merged, so the pages are compared as a whole.
+fn nftoken_ids_before_and_after<'b>(
+    meta: &'b TransactionMetadata<'_>,
+) -> (BTreeSet<&'b str>, BTreeSet<&'b str>) {
+    let mut before = BTreeSet::new();
+    let mut after = BTreeSet::new();
+    for node in &meta.affected_nodes {
+        if node.ledger_entry_type() != "NFTokenPage" {
+            continue;
+        }
+        match node {
+            AffectedNode::CreatedNode { new_fields, .. } => {
+                after.extend(nftoken_ids(Some(new_fields)));
+            }
+            AffectedNode::ModifiedNode {
+                final_fields,
+                previous_fields,
+                ..
+            } => {
+                after.extend(nftoken_ids(final_fields.as_ref()));
+                match previous_fields
+                    .as_ref()
+                    .filter(|fields| fields.get("NFTokens").is_some())
+                {
+                    Some(previous_fields) => before.extend(nftoken_ids(Some(previous_fields))),
+                    None => before.extend(nftoken_ids(final_fields.as_ref())),
+                }
+            }
+            AffectedNode::DeletedNode {
+                final_fields,
+                previous_fields,
+                ..
+            } => {
+                match previous_fields
+                    .as_ref()
+                    .filter(|fields| fields.get("NFTokens").is_some())
+                {
+                    Some(previous_fields) => before.extend(nftoken_ids(Some(previous_fields))),
+                    None => before.extend(nftoken_ids(Some(final_fields))),
+                }
+            }
+        }
+    }
+
+    (before, after)
+}
+
+fn nftoken_ids(fields: Option<&Value>) -> impl Iterator<Item = &str> {
+    fields
+        .and_then(|fields| fields.get("NFTokens"))
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|nftoken| nftoken["NFToken"]["NFTokenID"].as_str())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::IssuedCurrencyAmount;
+
+    const MINTED_NFTOKEN_ID: &str =
+        "000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65";
+    const KEPT_NFTOKEN_ID: &str =
+        "000B013A95F14B0044F78A264E41713C64B5F89242540EE2A0E3C4C500000D61";
+
+    fn payment(flags: u32) -> Payment<'static> {
+        serde_json::from_value(serde_json::json!({
+            "TransactionType": "Payment",
+            "Account": "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+            "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+            "Amount": {
+                "currency": "USD",
+                "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                "value": "1000"
+            },
+            "Fee": "12",
+            "Flags": flags,
+            "Sequence": 2
+        }))
+        .unwrap()
+    }
+
+    fn meta(extra: Value) -> TransactionMetadata<'static> {
+        let mut meta = serde_json::json!({
+            "AffectedNodes": [],
+            "TransactionIndex": 4,
+            "TransactionResult": "tesSUCCESS"
+        });
+        for (key, value) in extra.as_object().unwrap() {
+            meta[key] = value.clone();
+        }
+
+        serde_json::from_value(meta).unwrap()
+    }
+
+    #[test]
+    fn test_partial_payment_delivered_amount() {
+        // A partial payment asking for 1000 USD that delivered 0.01 USD.
+        let tx = payment(PaymentFlag::TfPartialPayment as u32);
+        let delivered: Amount = IssuedCurrencyAmount::new(
+            "USD".into(),
+            "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B".into(),
+            "0.01".into(),
+        )
+        .into();
+        let meta = meta(serde_json::json!({
+            "AffectedNodes": [{
+                "ModifiedNode": {
+                    "FinalFields": {
+                        "Balance": {
+                            "currency": "USD",
+                            "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji",
+                            "value": "-0.01"
+                        },
+                        "Flags": 131072,
+                        "HighLimit": {
+                            "currency": "USD",
+                            "issuer": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+                            "value": "1000"
+                        },
+                        "LowLimit": {
+                            "currency": "USD",
+                            "issuer": "rvYAfWj5gh67oV6fW32ZzP3Aw4Eubs59B",
+                            "value": "0"
+                        }
+                    },
+                    "LedgerEntryType": "RippleState",
+                    "LedgerIndex": "7EDC6F1D3EC6A93C4CC3E5E5F3D0FDDE3E2FB38D1A8E4E4A5A6F8A3E2B6C8D0E",
+                    "PreviousFields": {
+                        "Balance": {
+                            "currency": "USD",
+                            "issuer": "rrrrrrrrrrrrrrrrrrrrBZbvji",
+                            "value": "0"
+                        }
+                    },
+                    "PreviousTxnID": "0A0E7E8B0CB3E98E0A2E0C1C1C5F9F3D8F4C5F7A9E2D8B6B4A5C3D2E1F0A9B8C",
+                    "PreviousTxnLgrSeq": 14091160
+                }
+            }],
+            "DeliveredAmount": delivered,
+            "delivered_amount": delivered
+        }));
+
+        assert_eq!(get_delivered_amount(&meta, &tx), Some(delivered));
+        assert_ne!(get_delivered_amount(&meta, &tx), Some(tx.amount));
+    }
+
+    #[test]
+    fn test_delivered_amount_fallbacks() {
+        let full_payment = payment(0);
+        let partial_payment = payment(PaymentFlag::TfPartialPayment as u32);
+
+        // Without a recorded amount, only a full payment delivered `Amount`.
+        assert_eq!(
+            get_delivered_amount(&meta(serde_json::json!({})), &full_payment),
+            Some(full_payment.amount.clone())
+        );
+        assert_eq!(
+            get_delivered_amount(&meta(serde_json::json!({})), &partial_payment),
+            None
+        );
+        // Before 2014-01-20 the amount delivered is unknown.
+        let unavailable = meta(serde_json::json!({ "delivered_amount": "unavailable" }));
+        assert_eq!(get_delivered_amount(&unavailable, &full_payment), None);
+        // A failed payment delivers nothing.
+        let failed = meta(serde_json::json!({ "TransactionResult": "tecPATH_PARTIAL" }));
+        assert_eq!(get_delivered_amount(&failed, &full_payment), None);
+    }
+
+    #[test]
+    fn test_minted_and_burned_nftoken_ids() {
+        let nftoken = |id: &str| serde_json::json!({ "NFToken": { "NFTokenID": id } });
+        let minted = meta(serde_json::json!({
+            "AffectedNodes": [{
+                "ModifiedNode": {
+                    "FinalFields": {
+                        "Flags": 0,
+                        "NFTokens": [nftoken(KEPT_NFTOKEN_ID), nftoken(MINTED_NFTOKEN_ID)]
+                    },
+                    "LedgerEntryType": "NFTokenPage",
+                    "LedgerIndex": "95F14B0044F78A264E41713C64B5F89242540EE2FFFFFFFFFFFFFFFFFFFFFFFF",
+                    "PreviousFields": { "NFTokens": [nftoken(KEPT_NFTOKEN_ID)] }
+                }
+            }, {
+                "ModifiedNode": {
+                    "FinalFields": { "Account": "rNCFjv8Ek5oDrNiMJ3pw6eLLFtMjZLJnf2" },
+                    "LedgerEntryType": "AccountRoot",
+                    "LedgerIndex": "B3E7D3A0A2F1E2B8B1A3B6C4F6E8D0A2B4C6E8F0A2B4C6E8F0A2B4C6E8F0A2B4"
+                }
+            }],
+            "nftoken_id": MINTED_NFTOKEN_ID
+        }));
+        assert_eq!(get_minted_nftoken_ids(&minted), [MINTED_NFTOKEN_ID]);
+        assert!(get_burned_nftoken_ids(&minted).is_empty());
+        assert_eq!(minted.nftoken_id.as_deref(), Some(MINTED_NFTOKEN_ID));
+
+        // Burning the last NFToken of a page deletes the page.
+        let burned = meta(serde_json::json!({
+            "AffectedNodes": [{
+                "DeletedNode": {
+                    "FinalFields": { "Flags": 0 },
+                    "LedgerEntryType": "NFTokenPage",
+                    "LedgerIndex": "95F14B0044F78A264E41713C64B5F89242540EE2FFFFFFFFFFFFFFFFFFFFFFFF",
+                    "PreviousFields": { "NFTokens": [nftoken(MINTED_NFTOKEN_ID)] }
+                }
+            }]
+        }));
+        assert!(get_minted_nftoken_ids(&burned).is_empty());
+        assert_eq!(get_burned_nftoken_ids(&burned), [MINTED_NFTOKEN_ID]);
+    }
+}
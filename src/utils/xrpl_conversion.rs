@@ -1,15 +1,13 @@
 //! Conversions between XRP drops and native number types.
 
 use crate::utils::exceptions::XRPRangeException;
-use alloc::format;
 use alloc::string::String;
 use alloc::string::ToString;
-use bigdecimal::BigDecimal;
-use regex::Regex;
 use rust_decimal::prelude::*;
 use rust_decimal::Decimal;
 
 use super::exceptions::XRPLUtilsResult;
+use super::IOUValue;
 
 /// Indivisible unit of XRP
 pub(crate) const _ONE_DROP: Decimal = Decimal::from_parts(1, 0, 0, false, 6);
@@ -29,95 +27,14 @@ pub const MAX_IOU_EXPONENT: i32 = 80;
 /// Maximum IC precision
 pub const MAX_IOU_PRECISION: u8 = 16;
 
-/// Checked remainder. Computes self % other, returning None if overflow occurred.
-fn checked_rem(first: &BigDecimal, second: &BigDecimal) -> Option<BigDecimal> {
-    // If second is zero, return None to avoid division by zero
-    if second.is_zero() {
-        return None;
-    }
-
-    // Perform the division and handle the case where there's no overflow
-    match checked_div(first, second) {
-        Some(div) => {
-            // Get the integer part of the division
-            let int_part = div.with_scale(0); // Truncate to integer
-
-            // Calculate remainder: remainder = first - (int_part * second)
-            let rem = first - &(int_part * second);
-
-            Some(rem)
-        }
-        None => None, // If the division fails, return None (overflow case)
-    }
-}
-
-/// Checked division. Computes self / other, returning None if overflow occurred.
-fn checked_div(first: &BigDecimal, second: &BigDecimal) -> Option<BigDecimal> {
-    // If second is zero, return None to avoid division by zero
-    if second.is_zero() {
-        return None;
-    }
-
-    // Perform the division and return Some(result) if successful
-    Some(first / second)
-}
-
-/// Checked multiplication. Computes self * other, returning None if overflow occurred.
-fn checked_mul(first: &BigDecimal, second: &BigDecimal) -> Option<BigDecimal> {
-    // Perform the multiplication
-    let result = first * second;
-
-    // Since BigDecimal supports arbitrary precision, we don't need to check for overflow.
-    // Simply return the result wrapped in Some.
-    Some(result)
-}
-
-/// TODO Make less bootleg
-/// Get the precision of a number.
-fn _calculate_precision(value: &str) -> XRPLUtilsResult<usize> {
-    let decimal = BigDecimal::from_str(value)?.normalized();
-    let regex = Regex::new("[^1-9]").expect("_calculate_precision");
-
-    if checked_rem(&decimal, &BigDecimal::one()).is_some() {
-        let stripped = regex
-            .replace(&decimal.to_string(), "")
-            .replace(['.', '0'], "");
-        Ok(stripped.len())
-    } else {
-        let quantized = decimal.with_scale(2);
-        let stripped = regex
-            .replace(&quantized.to_string(), "")
-            .replace(['.', '0'], "");
-        Ok(stripped.len())
-    }
-}
-
-/// Ensure that the value after being multiplied by the
-/// exponent does not contain a decimal.
-fn _verify_no_decimal(decimal: BigDecimal) -> XRPLUtilsResult<()> {
-    let (mantissa, scale) = decimal.as_bigint_and_exponent();
-    let decimal = BigDecimal::from_i64(scale).expect("_verify_no_decimal");
-
-    let value: String = if decimal == BigDecimal::zero() {
-        mantissa.to_string()
-    } else {
-        (&decimal * &decimal).to_string()
-        // decimal
-        //     .checked_mul(decimal)
-        //     .unwrap_or(Decimal::ZERO)
-        //     .to_string()
-    };
-
-    if value.contains('.') {
-        Err(XRPRangeException::InvalidValueContainsDecimal.into())
-    } else {
-        Ok(())
-    }
-}
-
 /// Convert a numeric XRP amount to drops of XRP.
 /// Return an equivalent amount in drops of XRP.
 ///
+/// The amount must be a plain decimal number with at most six
+/// decimal places and no more than [`MAX_XRP`]. Scientific notation
+/// and negative amounts are rejected; see [`signed_xrp_to_drops`]
+/// for amounts that may be negative.
+///
 /// # Examples
 ///
 /// ## Basic usage
@@ -141,28 +58,53 @@ fn _verify_no_decimal(decimal: BigDecimal) -> XRPLUtilsResult<()> {
 /// assert_eq!(Some(drops), conversion);
 /// ```
 pub fn xrp_to_drops(xrp: &str) -> XRPLUtilsResult<String> {
-    let xrp_d = Decimal::from_str(xrp)?;
+    _xrp_to_drops(_parse_plain_decimal(xrp)?, xrp, false)
+}
 
-    if xrp_d < _ONE_DROP && xrp_d != Decimal::ZERO {
-        Err(XRPRangeException::InvalidXRPAmountTooSmall {
-            min: ONE_DROP.to_string(),
-            found: xrp.to_string(),
-        }
-        .into())
-    } else if xrp_d.gt(&Decimal::new(MAX_XRP as i64, 0)) {
-        Err(XRPRangeException::InvalidXRPAmountTooLarge {
-            max: MAX_XRP,
-            found: xrp.into(),
-        }
-        .into())
-    } else {
-        Ok(format!("{}", (xrp_d / _ONE_DROP).trunc()))
-    }
+/// Convert a decimal XRP amount to drops of XRP.
+///
+/// Applies the same checks as [`xrp_to_drops`].
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::xrp_decimal_to_drops;
+/// use rust_decimal::Decimal;
+///
+/// assert_eq!(
+///     xrp_decimal_to_drops(Decimal::new(15, 1)),
+///     Ok("1500000".to_string())
+/// );
+/// ```
+pub fn xrp_decimal_to_drops(xrp: Decimal) -> XRPLUtilsResult<String> {
+    _xrp_to_drops(xrp, &xrp.to_string(), false)
+}
+
+/// Convert a possibly negative XRP amount, such as a balance
+/// change, to drops of XRP.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::signed_xrp_to_drops;
+///
+/// assert_eq!(signed_xrp_to_drops("-1.5"), Ok("-1500000".to_string()));
+/// ```
+pub fn signed_xrp_to_drops(xrp: &str) -> XRPLUtilsResult<String> {
+    _xrp_to_drops(_parse_plain_decimal(xrp)?, xrp, true)
 }
 
 /// Convert from drops to decimal XRP.
 /// Return an equivalent amount of XRP from drops.
 ///
+/// The amount must be a non-negative whole number of drops no
+/// larger than [`MAX_DROPS`]; see [`signed_drops_to_xrp`] for
+/// amounts that may be negative.
+///
 /// # Examples
 ///
 /// ## Basic usage
@@ -185,17 +127,96 @@ pub fn xrp_to_drops(xrp: &str) -> XRPLUtilsResult<String> {
 /// assert_eq!(Some(xrp), conversion);
 /// ```
 pub fn drops_to_xrp(drops: &str) -> XRPLUtilsResult<String> {
-    let drops_d = Decimal::from_str(drops)?;
-    let xrp = drops_d * _ONE_DROP;
+    _drops_to_xrp(drops, false)
+}
+
+/// Convert a possibly negative amount of drops, such as a balance
+/// change, to decimal XRP.
+///
+/// # Examples
+///
+/// ## Basic usage
+///
+/// ```
+/// use xrpl::utils::signed_drops_to_xrp;
+///
+/// assert_eq!(signed_drops_to_xrp("-1500000"), Ok("-1.5".to_string()));
+/// ```
+pub fn signed_drops_to_xrp(drops: &str) -> XRPLUtilsResult<String> {
+    _drops_to_xrp(drops, true)
+}
+
+/// Parse a plain decimal number, rejecting anything `Decimal`
+/// would otherwise accept such as exponents, a leading `+`,
+/// underscores or a dangling decimal point.
+fn _parse_plain_decimal(value: &str) -> XRPLUtilsResult<Decimal> {
+    let unsigned = value.strip_prefix('-').unwrap_or(value);
+    let (integer, fraction) = match unsigned.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (unsigned, None),
+    };
+    let is_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+
+    if is_digits(integer) && fraction.is_none_or(is_digits) {
+        Ok(Decimal::from_str(value)?)
+    } else {
+        Err(XRPRangeException::InvalidAmountFormat {
+            found: value.to_string(),
+        }
+        .into())
+    }
+}
+
+fn _xrp_to_drops(xrp: Decimal, found: &str, allow_negative: bool) -> XRPLUtilsResult<String> {
+    let xrp = xrp.normalize();
+    let magnitude = xrp.abs();
+
+    if xrp.is_sign_negative() && !allow_negative {
+        Err(XRPRangeException::InvalidNegativeAmount {
+            found: found.to_string(),
+        }
+        .into())
+    } else if magnitude < _ONE_DROP && !magnitude.is_zero() {
+        Err(XRPRangeException::InvalidXRPAmountTooSmall {
+            min: ONE_DROP.to_string(),
+            found: found.to_string(),
+        }
+        .into())
+    } else if magnitude.scale() > _ONE_DROP.scale() {
+        Err(XRPRangeException::InvalidXRPAmountPrecision {
+            max: _ONE_DROP.scale(),
+            found: found.to_string(),
+        }
+        .into())
+    } else if magnitude > Decimal::from(MAX_XRP) {
+        Err(XRPRangeException::InvalidXRPAmountTooLarge {
+            max: MAX_XRP,
+            found: found.to_string(),
+        }
+        .into())
+    } else {
+        Ok((xrp * Decimal::from(XRP_DROPS)).normalize().to_string())
+    }
+}
+
+fn _drops_to_xrp(drops: &str, allow_negative: bool) -> XRPLUtilsResult<String> {
+    let drops_d = _parse_plain_decimal(drops)?.normalize();
 
-    if xrp.gt(&Decimal::new(MAX_XRP as i64, 0)) {
+    if drops.contains('.') {
+        Err(XRPRangeException::InvalidValueContainsDecimal.into())
+    } else if drops_d.is_sign_negative() && !allow_negative {
+        Err(XRPRangeException::InvalidNegativeAmount {
+            found: drops.to_string(),
+        }
+        .into())
+    } else if drops_d.abs() > Decimal::from(MAX_DROPS) {
         Err(XRPRangeException::InvalidDropsAmountTooLarge {
-            max: MAX_XRP.to_string(),
+            max: MAX_DROPS.to_string(),
             found: drops.to_string(),
         }
         .into())
     } else {
-        Ok(xrp.normalize().to_string())
+        Ok((drops_d * _ONE_DROP).normalize().to_string())
     }
 }
 
@@ -227,6 +248,10 @@ pub fn verify_valid_xrp_value(xrp_value: &str) -> XRPLUtilsResult<()> {
     match decimal {
         xrp if xrp.is_zero() => Ok(()),
         xrp if xrp.ge(&_ONE_DROP) && xrp.le(&max) => Ok(()),
+        xrp if xrp.is_sign_negative() => Err(XRPRangeException::InvalidNegativeAmount {
+            found: xrp_value.to_string(),
+        }
+        .into()),
         xrp if xrp.lt(&_ONE_DROP) => Err(XRPRangeException::InvalidXRPAmountTooSmall {
             min: ONE_DROP.to_string(),
             found: xrp.to_string(),
@@ -264,28 +289,9 @@ pub fn verify_valid_xrp_value(xrp_value: &str) -> XRPLUtilsResult<()> {
 /// assert!(valid);
 /// ```
 pub fn verify_valid_ic_value(ic_value: &str) -> XRPLUtilsResult<()> {
-    let decimal = BigDecimal::from_str(ic_value)?.normalized();
-    let scale = -(decimal.fractional_digit_count() as i32);
-    let prec = _calculate_precision(ic_value)?;
+    IOUValue::from_str(ic_value)?;
 
-    match decimal {
-        ic if ic.is_zero() => Ok(()),
-        _ if prec > MAX_IOU_PRECISION as usize || scale > MAX_IOU_EXPONENT => {
-            Err(XRPRangeException::InvalidICPrecisionTooLarge {
-                max: MAX_IOU_EXPONENT,
-                found: scale,
-            }
-            .into())
-        }
-        _ if prec > MAX_IOU_PRECISION as usize || scale < MIN_IOU_EXPONENT => {
-            Err(XRPRangeException::InvalidICPrecisionTooSmall {
-                min: MIN_IOU_EXPONENT,
-                found: scale,
-            }
-            .into())
-        }
-        _ => _verify_no_decimal(decimal),
-    }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -321,6 +327,13 @@ mod test {
         assert!(verify_valid_xrp_value(&MAX_DROPS.to_string()).is_ok());
         assert!(verify_valid_xrp_value("0.0000001").is_err());
         assert!(verify_valid_xrp_value("100000000000000001").is_err());
+        assert_eq!(
+            verify_valid_xrp_value("-1"),
+            Err(XRPRangeException::InvalidNegativeAmount {
+                found: "-1".to_string()
+            }
+            .into())
+        );
     }
 
     #[test]
@@ -414,4 +427,108 @@ mod test {
     fn accept_too_big_drops() {
         assert!(xrp_to_drops(&(MAX_XRP + 1).to_string()).is_err());
     }
+
+    #[test]
+    fn test_xrp_to_drops_rejects_extra_decimals() {
+        assert_eq!(
+            xrp_to_drops("1.0000001"),
+            Err(XRPRangeException::InvalidXRPAmountPrecision {
+                max: 6,
+                found: "1.0000001".to_string(),
+            }
+            .into())
+        );
+        assert_eq!(
+            xrp_decimal_to_drops(Decimal::new(10000001, 7)),
+            Err(XRPRangeException::InvalidXRPAmountPrecision {
+                max: 6,
+                found: "1.0000001".to_string(),
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn test_xrp_to_drops_normalizes_trailing_zeros() {
+        assert_eq!(xrp_to_drops("1.500000000"), Ok("1500000".to_string()));
+        assert_eq!(xrp_to_drops("0.0000000"), Ok("0".to_string()));
+        assert_eq!(
+            xrp_decimal_to_drops(Decimal::new(1500000000, 9)),
+            Ok("1500000".to_string())
+        );
+        assert_eq!(drops_to_xrp("1500000"), Ok("1.5".to_string()));
+    }
+
+    #[test]
+    fn test_xrp_to_drops_max_boundary() {
+        assert_eq!(
+            xrp_to_drops("100000000000.000000"),
+            Ok(MAX_DROPS.to_string())
+        );
+        assert_eq!(
+            xrp_to_drops("100000000000.000001"),
+            Err(XRPRangeException::InvalidXRPAmountTooLarge {
+                max: MAX_XRP,
+                found: "100000000000.000001".to_string(),
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn test_conversions_reject_malformed_numbers() {
+        for case in [
+            "1e11", "1E5", "+1", ".5", "5.", "1_000", "", "-", "1.5.0", " 1",
+        ] {
+            assert_eq!(
+                xrp_to_drops(case),
+                Err(XRPRangeException::InvalidAmountFormat {
+                    found: case.to_string(),
+                }
+                .into())
+            );
+            assert_eq!(
+                drops_to_xrp(case),
+                Err(XRPRangeException::InvalidAmountFormat {
+                    found: case.to_string(),
+                }
+                .into())
+            );
+        }
+    }
+
+    #[test]
+    fn test_conversions_reject_negative_amounts() {
+        assert_eq!(
+            xrp_to_drops("-1"),
+            Err(XRPRangeException::InvalidNegativeAmount {
+                found: "-1".to_string(),
+            }
+            .into())
+        );
+        assert_eq!(
+            drops_to_xrp("-1"),
+            Err(XRPRangeException::InvalidNegativeAmount {
+                found: "-1".to_string(),
+            }
+            .into())
+        );
+        assert_eq!(xrp_to_drops("-0"), Ok("0".to_string()));
+        assert_eq!(signed_xrp_to_drops("-1.5"), Ok("-1500000".to_string()));
+        assert_eq!(signed_drops_to_xrp("-1500000"), Ok("-1.5".to_string()));
+        assert!(signed_xrp_to_drops("-0.0000001").is_err());
+    }
+
+    #[test]
+    fn test_drops_to_xrp_rejects_fractional_drops() {
+        assert_eq!(
+            drops_to_xrp("1.5"),
+            Err(XRPRangeException::InvalidValueContainsDecimal.into())
+        );
+        assert_eq!(
+            drops_to_xrp("1.0"),
+            Err(XRPRangeException::InvalidValueContainsDecimal.into())
+        );
+        assert!(drops_to_xrp(&(MAX_DROPS + 1).to_string()).is_err());
+    }
 }
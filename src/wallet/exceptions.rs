@@ -1,6 +1,9 @@
 use thiserror_no_std::Error;
 
+use alloc::string::String;
+
 use crate::core::exceptions::XRPLCoreException;
+use crate::XRPLSerdeJsonError;
 
 pub type XRPLWalletResult<T, E = XRPLWalletException> = core::result::Result<T, E>;
 
@@ -9,4 +12,26 @@ pub type XRPLWalletResult<T, E = XRPLWalletException> = core::result::Result<T,
 pub enum XRPLWalletException {
     #[error("XRPL Core error: {0}")]
     XRPLCoreError(#[from] XRPLCoreException),
+    #[error("serde_json error: {0}")]
+    SerdeJsonError(#[from] XRPLSerdeJsonError),
+    #[error("Invalid keystore: {0}")]
+    InvalidKeystore(String),
+    #[error("Unsupported keystore version: {0}")]
+    UnsupportedKeystoreVersion(u32),
+    #[error("Wrong keystore password")]
+    WrongKeystorePassword,
+    /// The password is right, but the encrypted seed or the address
+    /// were changed.
+    #[error("The keystore was tampered with")]
+    KeystoreTampered,
+    #[error("Failed to encrypt the keystore")]
+    KeystoreEncryptionFailed,
+    /// The transaction is prepared for another key than the wallet's.
+    #[error(
+        "The SigningPubKey {signing_pub_key} is not the public key of the wallet ({public_key})"
+    )]
+    SigningPubKeyMismatch {
+        signing_pub_key: String,
+        public_key: String,
+    },
 }
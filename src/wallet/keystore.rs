@@ -0,0 +1,348 @@
+//! Encrypted keystore files, to keep a Wallet's seed at rest.
+//!
+//! The seed is encrypted with AES-256-GCM, under a key derived
+//! from a password with scrypt. The file is JSON and carries its
+//! format version, so files written by older releases stay
+//! readable.
+
+use aes_gcm::{
+    aead::{Aead, KeyInit, Payload},
+    Aes256Gcm, Nonce,
+};
+use alloc::string::String;
+use alloc::vec::Vec;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+use super::exceptions::{XRPLWalletException, XRPLWalletResult};
+use super::Wallet;
+use crate::{XRPLError, XRPLSerdeJsonError};
+
+/// The keystore format version written by this release.
+pub const KEYSTORE_VERSION: u32 = 1;
+
+const CIPHER: &str = "aes-256-gcm";
+const KDF: &str = "scrypt";
+const SALT_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 12;
+/// The first half of the derived key encrypts, the second half
+/// checks the password.
+const DERIVED_KEY_LENGTH: usize = 64;
+/// The largest scrypt parameters accepted from a keystore file, so
+/// a crafted file cannot make the import use more than 1 GiB of
+/// memory or run for minutes. They are well above the recommended
+/// ones this release writes.
+const MAX_LOG_N: u8 = 20;
+const MAX_R: u32 = 8;
+const MAX_P: u32 = 4;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Keystore {
+    version: u32,
+    /// The classic address of the Wallet, to tell files apart
+    /// without the password.
+    address: String,
+    crypto: KeystoreCrypto,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeystoreCrypto {
+    cipher: String,
+    ciphertext: String,
+    nonce: String,
+    kdf: String,
+    kdfparams: ScryptParams,
+    /// SHA-256 of the second half of the derived key, to tell a
+    /// wrong password from a tampered file.
+    check: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ScryptParams {
+    log_n: u8,
+    r: u32,
+    p: u32,
+    salt: String,
+}
+
+impl ScryptParams {
+    fn new(log_n: u8, r: u32, p: u32) -> Self {
+        let mut salt = [0u8; SALT_LENGTH];
+        rand_hc::Hc128Rng::from_entropy().fill(&mut salt);
+
+        Self {
+            log_n,
+            r,
+            p,
+            salt: hex::encode(salt),
+        }
+    }
+
+    /// Reject parameters above [`MAX_LOG_N`], [`MAX_R`] and
+    /// [`MAX_P`].
+    fn check_bounds(&self) -> XRPLWalletResult<()> {
+        if self.log_n > MAX_LOG_N || self.r > MAX_R || self.p > MAX_P {
+            return Err(XRPLWalletException::InvalidKeystore(
+                "scrypt parameters too large".into(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn derive_key(&self, password: &str) -> XRPLWalletResult<[u8; DERIVED_KEY_LENGTH]> {
+        let params =
+            scrypt::Params::new(self.log_n, self.r, self.p, DERIVED_KEY_LENGTH).map_err(|_| {
+                XRPLWalletException::InvalidKeystore("invalid scrypt parameters".into())
+            })?;
+        let salt = decode_hex(&self.salt)?;
+        let mut derived_key = [0u8; DERIVED_KEY_LENGTH];
+        scrypt::scrypt(password.as_bytes(), &salt, &params, &mut derived_key).map_err(|_| {
+            XRPLWalletException::InvalidKeystore("invalid scrypt parameters".into())
+        })?;
+
+        Ok(derived_key)
+    }
+}
+
+impl Default for ScryptParams {
+    fn default() -> Self {
+        Self::new(
+            scrypt::Params::RECOMMENDED_LOG_N,
+            scrypt::Params::RECOMMENDED_R,
+            scrypt::Params::RECOMMENDED_P,
+        )
+    }
+}
+
+impl Wallet {
+    /// Export the Wallet's seed as an encrypted keystore file,
+    /// protected by `password`.
+    ///
+    /// # Examples
+    ///
+    /// ## Basic usage
+    ///
+    /// ```no_run
+    /// use xrpl::wallet::Wallet;
+    ///
+    /// let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+    /// let keystore = wallet.to_keystore("correct horse battery staple").unwrap();
+    /// let restored = Wallet::from_keystore(&keystore, "correct horse battery staple").unwrap();
+    ///
+    /// assert_eq!(restored.classic_address, wallet.classic_address);
+    /// ```
+    pub fn to_keystore(&self, password: &str) -> XRPLWalletResult<String> {
+        self.to_keystore_with_params(password, ScryptParams::default())
+    }
+
+    pub(crate) fn to_keystore_with_params(
+        &self,
+        password: &str,
+        kdfparams: ScryptParams,
+    ) -> XRPLWalletResult<String> {
+        let mut derived_key = kdfparams.derive_key(password)?;
+        let mut nonce = [0u8; NONCE_LENGTH];
+        rand_hc::Hc128Rng::from_entropy().fill(&mut nonce);
+
+        let cipher = Aes256Gcm::new_from_slice(&derived_key[..32])
+            .map_err(|_| XRPLWalletException::KeystoreEncryptionFailed)?;
+        let check = hex::encode(Sha256::digest(&derived_key[32..]));
+        derived_key.zeroize();
+        // The address is authenticated along with the seed.
+        let ciphertext = cipher
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: self.seed.as_bytes(),
+                    aad: self.classic_address.as_bytes(),
+                },
+            )
+            .map_err(|_| XRPLWalletException::KeystoreEncryptionFailed)?;
+
+        let keystore = Keystore {
+            version: KEYSTORE_VERSION,
+            address: self.classic_address.clone(),
+            crypto: KeystoreCrypto {
+                cipher: CIPHER.into(),
+                ciphertext: hex::encode(ciphertext),
+                nonce: hex::encode(nonce),
+                kdf: KDF.into(),
+                kdfparams,
+                check,
+            },
+        };
+
+        Ok(serde_json::to_string(&keystore).map_err(XRPLSerdeJsonError::from)?)
+    }
+
+    /// Import a Wallet from a keystore file written by
+    /// [`Wallet::to_keystore`].
+    pub fn from_keystore(keystore: &str, password: &str) -> Result<Self, XRPLError> {
+        let keystore: Keystore =
+            serde_json::from_str(keystore).map_err(XRPLSerdeJsonError::from)?;
+        if keystore.version != KEYSTORE_VERSION {
+            return Err(XRPLWalletException::UnsupportedKeystoreVersion(keystore.version).into());
+        }
+        let crypto = keystore.crypto;
+        if crypto.cipher != CIPHER || crypto.kdf != KDF {
+            return Err(
+                XRPLWalletException::InvalidKeystore("unsupported cipher or kdf".into()).into(),
+            );
+        }
+
+        crypto.kdfparams.check_bounds()?;
+        let mut derived_key = crypto.kdfparams.derive_key(password)?;
+        let check = hex::encode(Sha256::digest(&derived_key[32..]));
+        if !bool::from(check.as_bytes().ct_eq(crypto.check.as_bytes())) {
+            derived_key.zeroize();
+            return Err(XRPLWalletException::WrongKeystorePassword.into());
+        }
+        let cipher = Aes256Gcm::new_from_slice(&derived_key[..32])
+            .map_err(|_| XRPLWalletException::KeystoreTampered)?;
+        derived_key.zeroize();
+        let nonce = decode_hex(&crypto.nonce)?;
+        if nonce.len() != NONCE_LENGTH {
+            return Err(XRPLWalletException::InvalidKeystore("invalid nonce".into()).into());
+        }
+        let mut seed = cipher
+            .decrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &decode_hex(&crypto.ciphertext)?,
+                    aad: keystore.address.as_bytes(),
+                },
+            )
+            .map_err(|_| XRPLWalletException::KeystoreTampered)?;
+
+        let wallet = match core::str::from_utf8(&seed) {
+            Ok(seed) => Wallet::new(seed, 0),
+            Err(_) => Err(XRPLWalletException::KeystoreTampered.into()),
+        };
+        seed.zeroize();
+
+        wallet
+    }
+}
+
+fn decode_hex(value: &str) -> XRPLWalletResult<Vec<u8>> {
+    hex::decode(value).map_err(|_| XRPLWalletException::InvalidKeystore("invalid hex".into()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    const PASSWORD: &str = "correct horse battery staple";
+
+    /// Cheap parameters, as the recommended ones take seconds
+    /// without optimizations.
+    fn fast_params() -> ScryptParams {
+        ScryptParams::new(10, 8, 1)
+    }
+
+    fn keystore() -> (Wallet, String) {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let keystore = wallet
+            .to_keystore_with_params(PASSWORD, fast_params())
+            .unwrap();
+
+        (wallet, keystore)
+    }
+
+    fn from_keystore_error(keystore: &str, password: &str) -> XRPLWalletException {
+        match Wallet::from_keystore(keystore, password).unwrap_err() {
+            XRPLError::XRPLWalletError(error) => error,
+            error => panic!("expected a wallet error, got {error:?}"),
+        }
+    }
+
+    #[test]
+    fn test_keystore_round_trip() {
+        let (wallet, keystore) = keystore();
+        assert!(!keystore.contains(&wallet.seed));
+
+        let restored = Wallet::from_keystore(&keystore, PASSWORD).unwrap();
+        assert_eq!(restored.seed, wallet.seed);
+        assert_eq!(restored.classic_address, wallet.classic_address);
+
+        // Each export uses its own salt and nonce.
+        let other = wallet
+            .to_keystore_with_params(PASSWORD, fast_params())
+            .unwrap();
+        assert_ne!(other, keystore);
+    }
+
+    #[test]
+    fn test_keystore_wrong_password() {
+        let (_, keystore) = keystore();
+
+        assert_eq!(
+            from_keystore_error(&keystore, "wrong password"),
+            XRPLWalletException::WrongKeystorePassword
+        );
+    }
+
+    #[test]
+    fn test_keystore_tampered() {
+        let (_, keystore) = keystore();
+        let mut json: serde_json::Value = serde_json::from_str(&keystore).unwrap();
+
+        let mut ciphertext = json["crypto"]["ciphertext"].as_str().unwrap().to_string();
+        let flipped = if ciphertext.starts_with('0') {
+            "1"
+        } else {
+            "0"
+        };
+        ciphertext.replace_range(..1, flipped);
+        let mut tampered = json.clone();
+        tampered["crypto"]["ciphertext"] = ciphertext.into();
+        assert_eq!(
+            from_keystore_error(&tampered.to_string(), PASSWORD),
+            XRPLWalletException::KeystoreTampered
+        );
+
+        // The address is authenticated too.
+        json["address"] = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".into();
+        assert_eq!(
+            from_keystore_error(&json.to_string(), PASSWORD),
+            XRPLWalletException::KeystoreTampered
+        );
+    }
+
+    #[test]
+    fn test_keystore_version() {
+        let (_, keystore) = keystore();
+        let mut json: serde_json::Value = serde_json::from_str(&keystore).unwrap();
+        assert_eq!(json["version"], KEYSTORE_VERSION);
+
+        json["version"] = 2.into();
+        assert_eq!(
+            from_keystore_error(&json.to_string(), PASSWORD),
+            XRPLWalletException::UnsupportedKeystoreVersion(2)
+        );
+    }
+
+    #[test]
+    fn test_keystore_scrypt_bounds() {
+        let (_, keystore) = keystore();
+        let json: serde_json::Value = serde_json::from_str(&keystore).unwrap();
+
+        for (param, value) in [
+            ("log_n", MAX_LOG_N as u32 + 1),
+            ("r", MAX_R + 1),
+            ("p", MAX_P + 1),
+        ] {
+            let mut crafted = json.clone();
+            crafted["crypto"]["kdfparams"][param] = value.into();
+            assert_eq!(
+                from_keystore_error(&crafted.to_string(), PASSWORD),
+                XRPLWalletException::InvalidKeystore("scrypt parameters too large".into())
+            );
+        }
+    }
+}
@@ -3,12 +3,20 @@
 pub mod exceptions;
 #[cfg(feature = "helpers")]
 pub mod faucet_generation;
+#[cfg(feature = "wallet-keystore")]
+pub mod keystore;
+#[cfg(feature = "models")]
+pub(crate) mod signing;
 
 use crate::constants::CryptoAlgorithm;
 use crate::core::addresscodec::classic_address_to_xaddress;
+use crate::core::addresscodec::seed_from_entropy_hex;
+use crate::core::addresscodec::utils::SEED_LENGTH;
 use crate::core::keypairs::derive_classic_address;
 use crate::core::keypairs::derive_keypair;
 use crate::core::keypairs::generate_seed;
+use crate::core::keypairs::SigningKey;
+use crate::XRPLError;
 use alloc::string::String;
 use core::fmt::Display;
 use exceptions::XRPLWalletResult;
@@ -56,7 +64,7 @@ impl Drop for Wallet {
 
 impl Wallet {
     /// Generate a new Wallet.
-    pub fn new(seed: &str, sequence: u64) -> XRPLWalletResult<Self> {
+    pub fn new(seed: &str, sequence: u64) -> Result<Self, XRPLError> {
         let (public_key, private_key) = derive_keypair(seed, false)?;
         let classic_address = derive_classic_address(&public_key)?;
 
@@ -70,14 +78,41 @@ impl Wallet {
     }
 
     /// Generates a new seed and Wallet.
-    pub fn create(crypto_algorithm: Option<CryptoAlgorithm>) -> XRPLWalletResult<Self> {
+    pub fn create(crypto_algorithm: Option<CryptoAlgorithm>) -> Result<Self, XRPLError> {
         Self::new(&generate_seed(None, crypto_algorithm)?, 0)
     }
 
+    /// Generate a Wallet from 16 bytes of entropy, e.g. from a
+    /// hardware random number generator.
+    pub fn from_entropy(
+        entropy: &[u8; SEED_LENGTH],
+        crypto_algorithm: CryptoAlgorithm,
+    ) -> Result<Self, XRPLError> {
+        let mut seed = generate_seed(Some(*entropy), Some(crypto_algorithm))?;
+        let wallet = Self::new(&seed, 0);
+        seed.zeroize();
+
+        wallet
+    }
+
+    /// Generate a Wallet from 16 bytes of hex-encoded entropy,
+    /// as exported by some other wallets.
+    pub fn from_entropy_hex(
+        entropy_hex: &str,
+        crypto_algorithm: CryptoAlgorithm,
+        sequence: u64,
+    ) -> Result<Self, XRPLError> {
+        let mut seed = seed_from_entropy_hex(entropy_hex, crypto_algorithm)?;
+        let wallet = Self::new(&seed, sequence);
+        seed.zeroize();
+
+        wallet
+    }
+
     /// Returns the X-Address of the Wallet's account.
     pub fn get_xaddress(
         &self,
-        tag: Option<u64>,
+        tag: Option<u32>,
         is_test_network: bool,
     ) -> XRPLWalletResult<String> {
         Ok(classic_address_to_xaddress(
@@ -86,6 +121,12 @@ impl Wallet {
             is_test_network,
         )?)
     }
+
+    /// Returns the Wallet's private key parsed for signing many
+    /// transactions.
+    pub fn signing_key(&self) -> XRPLWalletResult<SigningKey> {
+        Ok(SigningKey::from_private_key(&self.private_key)?)
+    }
 }
 
 impl Display for Wallet {
@@ -98,3 +139,50 @@ impl Display for Wallet {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::addresscodec::entropy_hex_from_seed;
+
+    #[test]
+    fn test_from_entropy() {
+        let entropy = hex::decode("DEDCE9CE67B451D852FD4E846FCDE31C").unwrap();
+        let wallet =
+            Wallet::from_entropy(&entropy.try_into().unwrap(), CryptoAlgorithm::SECP256K1).unwrap();
+
+        assert_eq!(wallet.seed, "snoPBrXtMeMyMHUVTgbuqAfg1SUTb");
+        assert_eq!(wallet.classic_address, "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+    }
+
+    #[test]
+    fn test_from_entropy_hex() {
+        // The genesis account, derived from the "masterpassphrase" seed.
+        let wallet = Wallet::from_entropy_hex(
+            "DEDCE9CE67B451D852FD4E846FCDE31C",
+            CryptoAlgorithm::SECP256K1,
+            0,
+        )
+        .unwrap();
+        assert_eq!(wallet.seed, "snoPBrXtMeMyMHUVTgbuqAfg1SUTb");
+        assert_eq!(wallet.classic_address, "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+
+        // Shared test vectors of xrpl-py and xrpl.js.
+        for (seed, classic_address) in [
+            (
+                "sp5fghtJtpUorTwvof1NpDXAzNwf5",
+                "rU6K7V3Po4snVhBBaU29sesqs2qTQJWDw1",
+            ),
+            (
+                "sEdSKaCy2JT7JaM7v95H9SxkhP9wS2r",
+                "rLUEXYuLiQptky37CqLcm9USQpPiz5rkpD",
+            ),
+        ] {
+            let (entropy_hex, algorithm) = entropy_hex_from_seed(seed).unwrap();
+            let wallet = Wallet::from_entropy_hex(&entropy_hex, algorithm, 0).unwrap();
+
+            assert_eq!(wallet.seed, seed);
+            assert_eq!(wallet.classic_address, classic_address);
+        }
+    }
+}
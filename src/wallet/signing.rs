@@ -0,0 +1,323 @@
+//! Signing transactions with a wallet.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::{
+    core::{
+        addresscodec::{is_valid_xaddress, xaddress_to_classic_address},
+        binarycodec::{encode, encode_for_signing},
+        keypairs::{is_valid_message, SigningKey},
+        ledger::hash_signed_tx,
+    },
+    models::{
+        transactions::{
+            exceptions::XRPLTransactionFieldException, PreparedTransaction, SignedTransaction,
+            Transaction,
+        },
+        Model,
+    },
+    utils::transactions::{
+        get_transaction_field_value, set_transaction_field_value, validate_transaction_has_field,
+    },
+    XRPLError,
+};
+
+use super::{exceptions::XRPLWalletException, Wallet};
+
+type XRPLSigningResult<T> = core::result::Result<T, XRPLError>;
+
+impl<'a, T> PreparedTransaction<'a, T> {
+    /// Sign the transaction with the key of `wallet`, which must be the
+    /// key of `signing_pub_key`. The transaction is validated and
+    /// prepared the way the `sign` helper prepares it.
+    ///
+    /// # Errors
+    ///
+    /// If the transaction is invalid, or `signing_pub_key` is not the
+    /// public key of `wallet`.
+    ///
+    /// # Examples
+    ///
+    /// ## Basic usage
+    ///
+    /// ```
+    /// use xrpl::models::transactions::{payment::Payment, PreparedTransaction};
+    /// use xrpl::wallet::Wallet;
+    ///
+    /// let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+    /// let payment: Payment = serde_json::from_value(serde_json::json!({
+    ///     "TransactionType": "Payment",
+    ///     "Account": wallet.classic_address,
+    ///     "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+    ///     "Amount": "1000000",
+    ///     "Fee": "12",
+    ///     "Sequence": 1
+    /// }))
+    /// .unwrap();
+    ///
+    /// let signed = PreparedTransaction::new(payment, wallet.public_key.clone().into())
+    ///     .sign(&wallet)
+    ///     .unwrap();
+    ///
+    /// assert!(signed.verify().unwrap());
+    /// assert_eq!(signed.hash().unwrap().len(), 64);
+    /// ```
+    pub fn sign<F>(self, wallet: &Wallet) -> XRPLSigningResult<SignedTransaction<'a, T>>
+    where
+        F: IntoEnumIterator + Serialize + Debug + PartialEq,
+        T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone,
+    {
+        if self.signing_pub_key != wallet.public_key {
+            return Err(XRPLWalletException::SigningPubKeyMismatch {
+                signing_pub_key: self.signing_pub_key.to_string(),
+                public_key: wallet.public_key.clone(),
+            }
+            .into());
+        }
+
+        self.sign_with_key(&wallet.signing_key()?)
+    }
+
+    /// Like [`PreparedTransaction::sign`], with the key already parsed.
+    pub(crate) fn sign_with_key<F>(
+        mut self,
+        signing_key: &SigningKey,
+    ) -> XRPLSigningResult<SignedTransaction<'a, T>>
+    where
+        F: IntoEnumIterator + Serialize + Debug + PartialEq,
+        T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone,
+    {
+        let payload = self.signing_payload()?;
+        let signature = signing_key.sign(&payload)?;
+        let signed = SignedTransaction::new(self, signature.into());
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            hash = signed.hash().ok(),
+            key_type = crate::_tracing::key_type(&signed.prepared_transaction.signing_pub_key),
+            "signed transaction",
+        );
+
+        Ok(signed)
+    }
+
+    /// Validate and prepare the transaction, and return what is signed.
+    pub(crate) fn signing_payload<F>(&mut self) -> XRPLSigningResult<Vec<u8>>
+    where
+        F: IntoEnumIterator + Serialize + Debug + PartialEq,
+        T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone,
+    {
+        self.transaction.validate()?;
+        prepare_transaction(&mut self.transaction, &self.signing_pub_key)?;
+
+        Ok(hex::decode(encode_for_signing(&self.transaction)?)?)
+    }
+}
+
+impl<'a, T> SignedTransaction<'a, T>
+where
+    T: Serialize,
+{
+    /// The signed transaction in hex, as submitted with `submit`.
+    pub fn blob(&self) -> XRPLSigningResult<String> {
+        Ok(encode(self)?)
+    }
+
+    /// The hash identifying the transaction once it is in a ledger.
+    pub fn hash(&self) -> XRPLSigningResult<String> {
+        Ok(hash_signed_tx(&self.blob()?)?)
+    }
+
+    /// Whether `txn_signature` is a signature of the transaction by
+    /// the key of `signing_pub_key`.
+    pub fn verify(&self) -> XRPLSigningResult<bool> {
+        let payload = hex::decode(encode_for_signing(&self.prepared_transaction)?)?;
+
+        Ok(is_valid_message(
+            &payload,
+            &self.txn_signature,
+            &self.prepared_transaction.signing_pub_key,
+        ))
+    }
+
+    /// The transaction with its `SigningPubKey` and `TxnSignature` set.
+    pub fn into_transaction<F>(self) -> T
+    where
+        F: IntoEnumIterator + Serialize + Debug + PartialEq,
+        T: Transaction<'a, F>,
+    {
+        let PreparedTransaction {
+            mut transaction,
+            signing_pub_key,
+        } = self.prepared_transaction;
+        transaction.set_signing_pub_key(signing_pub_key);
+        transaction.set_txn_signature(self.txn_signature);
+
+        transaction
+    }
+}
+
+impl Wallet {
+    /// Sign `transaction` with the key of the wallet, see
+    /// [`PreparedTransaction::sign`].
+    pub fn sign<'a, T, F>(&self, transaction: T) -> XRPLSigningResult<SignedTransaction<'a, T>>
+    where
+        F: IntoEnumIterator + Serialize + Debug + PartialEq,
+        T: Transaction<'a, F> + Model + Serialize + DeserializeOwned + Clone,
+    {
+        PreparedTransaction::new(transaction, self.public_key.clone().into()).sign(self)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+enum AccountFieldType {
+    Account,
+    Destination,
+}
+
+pub(crate) fn prepare_transaction<'a, T, F>(
+    transaction: &mut T,
+    public_key: &str,
+) -> XRPLSigningResult<()>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Serialize + DeserializeOwned + Clone,
+{
+    transaction.set_signing_pub_key(public_key.to_string().into());
+
+    validate_account_xaddress(transaction, AccountFieldType::Account)?;
+    if validate_transaction_has_field(transaction, "Destination").is_ok() {
+        validate_account_xaddress(transaction, AccountFieldType::Destination)?;
+    }
+
+    let _ = convert_to_classic_address(transaction, "Unauthorize");
+    let _ = convert_to_classic_address(transaction, "Authorize");
+    // EscrowCancel, EscrowFinish
+    let _ = convert_to_classic_address(transaction, "Owner");
+    // SetRegularKey
+
+    let _ = convert_to_classic_address(transaction, "RegularKey");
+
+    Ok(())
+}
+
+fn validate_account_xaddress<'a, T, F>(
+    prepared_transaction: &mut T,
+    account_field: AccountFieldType,
+) -> XRPLSigningResult<()>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Serialize + DeserializeOwned + Clone,
+{
+    let (account_field_name, tag_field_name) = match serde_json::to_string(&account_field) {
+        Ok(name) => {
+            let name_str = name.as_str().trim();
+            if name_str == "\"Account\"" {
+                ("Account", "SourceTag")
+            } else if name_str == "\"Destination\"" {
+                ("Destination", "DestinationTag")
+            } else {
+                return Err(XRPLTransactionFieldException::UnknownAccountField(
+                    name_str.to_string(),
+                )
+                .into());
+            }
+        }
+        Err(error) => return Err(error.into()),
+    };
+    let account_address = match account_field {
+        AccountFieldType::Account => prepared_transaction.get_common_fields().account.clone(),
+        AccountFieldType::Destination => {
+            get_transaction_field_value(prepared_transaction, "Destination")?
+        }
+    };
+
+    if is_valid_xaddress(&account_address) {
+        let (address, tag, _) = xaddress_to_classic_address(&account_address)?;
+        validate_transaction_has_field(prepared_transaction, account_field_name)?;
+        set_transaction_field_value(prepared_transaction, account_field_name, address)?;
+
+        if validate_transaction_has_field(prepared_transaction, tag_field_name).is_ok()
+            && get_transaction_field_value(prepared_transaction, tag_field_name).unwrap_or(Some(0))
+                != tag
+        {
+            Err(XRPLTransactionFieldException::TagFieldMismatch(tag_field_name.to_string()).into())
+        } else {
+            set_transaction_field_value(prepared_transaction, tag_field_name, tag)?;
+
+            Ok(())
+        }
+    } else {
+        Ok(())
+    }
+}
+
+fn convert_to_classic_address<'a, T, F>(
+    transaction: &mut T,
+    field_name: &str,
+) -> XRPLSigningResult<()>
+where
+    F: IntoEnumIterator + Serialize + Debug + PartialEq,
+    T: Transaction<'a, F> + Serialize + DeserializeOwned + Clone,
+{
+    let address = get_transaction_field_value::<F, _, String>(transaction, field_name)?;
+    if is_valid_xaddress(&address) {
+        let classic_address = match xaddress_to_classic_address(&address) {
+            Ok(t) => t.0,
+            Err(error) => return Err(error.into()),
+        };
+        Ok(set_transaction_field_value(
+            transaction,
+            field_name,
+            classic_address,
+        )?)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::models::transactions::payment::Payment;
+
+    fn payment(account: &str) -> Payment<'static> {
+        serde_json::from_value(serde_json::json!({
+            "TransactionType": "Payment",
+            "Account": account,
+            "Destination": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+            "Amount": "1000000",
+            "Fee": "12",
+            "Sequence": 1
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sign_checks_the_signing_pub_key() {
+        let wallet = Wallet::new("sEdT7wHTCLzDG7ueaw4hroSTBvH7Mk5", 0).unwrap();
+        let other = Wallet::new("snoPBrXtMeMyMHUVTgbuqAfg1SUTb", 0).unwrap();
+
+        let signed = PreparedTransaction::new(
+            payment(&wallet.classic_address),
+            wallet.public_key.clone().into(),
+        )
+        .sign(&wallet)
+        .unwrap();
+        assert!(signed.verify().unwrap());
+
+        let error = PreparedTransaction::new(
+            payment(&wallet.classic_address),
+            other.public_key.clone().into(),
+        )
+        .sign(&wallet)
+        .unwrap_err();
+        assert!(matches!(
+            error,
+            XRPLError::XRPLWalletError(XRPLWalletException::SigningPubKeyMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+//! Allocation-count regression test for the binary codec.
+//!
+//! A counting allocator replaces the global one for this test
+//! binary, so it lives in its own file.
+#![cfg(all(feature = "core", feature = "std"))]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use serde_json::{json, Value};
+use xrpl::core::binarycodec::{encode, encode_into};
+
+/// Encoding `payment()` took 169 allocations while each field's
+/// bytes were copied around, and takes 129 now. Most of the rest is
+/// building the serde_json::Value.
+const MAX_ENCODE_INTO_ALLOCATIONS: usize = 140;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    // Only count the test's own thread, not the test harness.
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if COUNTING.with(Cell::get) {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if COUNTING.with(Cell::get) {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn count_allocations(f: impl FnOnce()) -> usize {
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    COUNTING.with(|counting| counting.set(true));
+    f();
+    COUNTING.with(|counting| counting.set(false));
+
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+fn payment() -> Value {
+    json!({
+        "Account": "rMBzp8CgpE441cp5PVyA9rpVV7oT8hP3ys",
+        "Amount": {
+            "currency": "USD",
+            "issuer": "rf1BiGeXwwQoi8Z2ueFYTEXSwuJYfV2Jpn",
+            "value": "1234.5678"
+        },
+        "Destination": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+        "Fee": "12",
+        "Flags": 2147483648u32,
+        "LastLedgerSequence": 8820051,
+        "Memos": [
+            { "Memo": { "MemoData": "72656E74", "MemoType": "687474703A2F2F6578616D706C652E636F6D2F6D656D6F2F67656E65726963" } },
+            { "Memo": { "MemoData": "72656E74" } }
+        ],
+        "Sequence": 359,
+        "SigningPubKey": "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020",
+        "TransactionType": "Payment",
+    })
+}
+
+#[test]
+fn test_encode_into_allocations() {
+    let tx = payment();
+    let mut buffer = Vec::new();
+    // Load the definitions and compile the regexes up front.
+    encode_into(&tx, &mut buffer).unwrap();
+    let capacity = buffer.capacity();
+
+    let encode_allocations = count_allocations(|| {
+        encode(&tx).unwrap();
+    });
+    let encode_into_allocations = count_allocations(|| {
+        buffer.clear();
+        encode_into(&tx, &mut buffer).unwrap();
+    });
+
+    // The reused buffer was large enough, and was not grown.
+    assert_eq!(buffer.capacity(), capacity);
+    assert_eq!(hex::encode_upper(&buffer), encode(&tx).unwrap());
+    assert!(encode_into_allocations < encode_allocations);
+    assert!(
+        encode_into_allocations <= MAX_ENCODE_INTO_ALLOCATIONS,
+        "encode_into allocated {encode_into_allocations} times"
+    );
+}
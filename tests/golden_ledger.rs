@@ -0,0 +1,256 @@
+//! Golden ledger snapshots.
+//!
+//! Every directory under `tests/golden/transactions` is one case:
+//!
+//! - `tx_blob.hex`: the transaction as the ledger stores it.
+//! - `tx.json`: the transaction as rippled returns it. Its `hash` is
+//!   only set when it was published with the transaction, never
+//!   computed from the blob.
+//! - `meta_blob.hex` and `meta.json` (optional): the transaction's
+//!   metadata. The fields rippled adds to the JSON, like
+//!   `delivered_amount` and `nftoken_id`, are checked against the
+//!   metadata parsers of [`xrpl::utils`] and are not encoded.
+//!
+//! Every directory under `tests/golden/ledgers` holds a `header.hex`
+//! and the `header.json` rippled returns for it, with its `hash`.
+//!
+//! To add a case, drop a new directory with these files, or fetch
+//! them from a server with the `fetch_golden` example of
+//! `examples/std`.
+#![cfg(all(feature = "core", feature = "std"))]
+
+use std::{fs, path::PathBuf};
+
+use serde_json::{Map, Value};
+use xrpl::core::{
+    binarycodec::{decode, encode, encode_for_multisigning, encode_for_signing, BinaryParser},
+    keypairs::is_valid_message,
+    ledger::{hash_ledger_header, hash_signed_tx, hash_tx_blob_with_meta, LedgerHeader},
+};
+
+fn golden_cases(kind: &str) -> Vec<PathBuf> {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(kind);
+    let mut cases: Vec<PathBuf> = fs::read_dir(&root)
+        .unwrap_or_else(|e| panic!("{}: {e}", root.display()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect();
+    cases.sort();
+    assert!(!cases.is_empty(), "no golden cases in {}", root.display());
+
+    cases
+}
+
+fn read_hex(path: PathBuf) -> String {
+    fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+        .trim()
+        .to_uppercase()
+}
+
+fn read_json(path: PathBuf) -> Value {
+    let json = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+
+    serde_json::from_str(&json).unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+}
+
+/// Split the fields rippled adds to the metadata JSON, whose names
+/// start in lowercase, from the fields of the binary metadata.
+fn split_synthetic_fields(meta_json: &Value) -> (Value, Map<String, Value>) {
+    let (synthetic, fields) = meta_json
+        .as_object()
+        .unwrap()
+        .clone()
+        .into_iter()
+        .partition(|(field, _)| field.starts_with(|c: char| c.is_ascii_lowercase()));
+
+    (Value::Object(fields), synthetic)
+}
+
+#[cfg(feature = "models")]
+fn verify_metadata_parsers(case: &str, tx_json: &Value, meta_json: &Value) {
+    use xrpl::{
+        models::{transactions::metadata::TransactionMetadata, Amount},
+        utils::{get_burned_nftoken_ids, get_delivered_amount, get_minted_nftoken_ids},
+    };
+
+    let meta: TransactionMetadata =
+        serde_json::from_value(meta_json.clone()).unwrap_or_else(|e| panic!("{case}: {e}"));
+    let succeeded = meta.transaction_result == "tesSUCCESS";
+    match tx_json["TransactionType"].as_str().unwrap() {
+        "Payment" => {
+            let payment =
+                serde_json::from_value(tx_json.clone()).unwrap_or_else(|e| panic!("{case}: {e}"));
+            let expected = match &meta_json["delivered_amount"] {
+                Value::Null => None,
+                Value::String(amount) if amount == "unavailable" => None,
+                amount => Some(serde_json::from_value::<Amount>(amount.clone()).unwrap()),
+            };
+            if succeeded || expected.is_none() {
+                assert_eq!(
+                    get_delivered_amount(&meta, &payment),
+                    expected,
+                    "{case}: delivered amount"
+                );
+            }
+        }
+        "NFTokenMint" if succeeded => {
+            assert_eq!(
+                get_minted_nftoken_ids(&meta),
+                [meta_json["nftoken_id"].as_str().unwrap()],
+                "{case}: minted NFToken"
+            );
+        }
+        "NFTokenBurn" if succeeded => {
+            assert_eq!(
+                get_burned_nftoken_ids(&meta),
+                [tx_json["NFTokenID"].as_str().unwrap()],
+                "{case}: burned NFToken"
+            );
+        }
+        _ => {}
+    }
+}
+
+/// Whether a signature is fully canonical. Older transactions may
+/// carry secp256k1 signatures with a high S value, which no longer
+/// verify.
+fn is_fully_canonical(signature: &str) -> bool {
+    match secp256k1::ecdsa::Signature::from_der(&hex::decode(signature).unwrap()) {
+        Ok(signature) => {
+            let mut normalized = signature;
+            normalized.normalize_s();
+            normalized == signature
+        }
+        // ED25519
+        Err(_) => true,
+    }
+}
+
+fn verify_signatures(case: &str, tx_json: &Value) {
+    if let Some(signature) = tx_json["TxnSignature"].as_str() {
+        let message = hex::decode(encode_for_signing(tx_json).unwrap()).unwrap();
+        let public_key = tx_json["SigningPubKey"].as_str().unwrap();
+        assert_eq!(
+            is_valid_message(&message, signature, public_key),
+            is_fully_canonical(signature),
+            "{case}: TxnSignature"
+        );
+    }
+    if let Some(signers) = tx_json["Signers"].as_array() {
+        for signer in signers {
+            let signer = &signer["Signer"];
+            let account = signer["Account"].as_str().unwrap();
+            let message =
+                hex::decode(encode_for_multisigning(tx_json, account.into()).unwrap()).unwrap();
+            let signature = signer["TxnSignature"].as_str().unwrap();
+            assert_eq!(
+                is_valid_message(
+                    &message,
+                    signature,
+                    signer["SigningPubKey"].as_str().unwrap()
+                ),
+                is_fully_canonical(signature),
+                "{case}: signature from {account}"
+            );
+        }
+    }
+}
+
+#[test]
+fn golden_transactions() {
+    for path in golden_cases("transactions") {
+        let case = path.file_name().unwrap().to_string_lossy().into_owned();
+        let tx_blob = read_hex(path.join("tx_blob.hex"));
+        let mut tx_json = read_json(path.join("tx.json"));
+        let hash = tx_json.as_object_mut().unwrap().remove("hash");
+
+        let encoded = encode(&tx_json).unwrap_or_else(|e| panic!("{case}: {e}"));
+        assert_eq!(encoded, tx_blob, "{case}: encode");
+        assert_eq!(decode(&tx_blob).unwrap(), tx_json, "{case}: decode");
+        if let Some(hash) = hash {
+            assert_eq!(hash_signed_tx(&tx_blob).unwrap(), hash, "{case}: hash");
+        }
+        verify_signatures(&case, &tx_json);
+
+        let meta_path = path.join("meta_blob.hex");
+        if meta_path.exists() {
+            let meta_blob = read_hex(meta_path);
+            let meta_json = read_json(path.join("meta.json"));
+            let (meta_fields, _) = split_synthetic_fields(&meta_json);
+            assert_eq!(
+                encode(&meta_fields).unwrap(),
+                meta_blob,
+                "{case}: encode meta"
+            );
+            assert_eq!(
+                decode(&meta_blob).unwrap(),
+                meta_fields,
+                "{case}: decode meta"
+            );
+            assert!(
+                hash_tx_blob_with_meta(&tx_blob, &meta_blob).is_ok(),
+                "{case}: meta"
+            );
+            #[cfg(feature = "models")]
+            verify_metadata_parsers(&case, &tx_json, &meta_json);
+        }
+    }
+}
+
+#[test]
+fn golden_ledger_headers() {
+    for path in golden_cases("ledgers") {
+        let case = path.file_name().unwrap().to_string_lossy().into_owned();
+        let header_hex = read_hex(path.join("header.hex"));
+        let header_json = read_json(path.join("header.json"));
+
+        let mut parser = BinaryParser::try_from(header_hex.as_str()).unwrap();
+        let header = LedgerHeader::from_parser(&mut parser).unwrap();
+        assert_eq!(
+            header.ledger_index as u64,
+            header_json["ledger_index"].as_u64().unwrap(),
+            "{case}: ledger_index"
+        );
+        assert_eq!(
+            header.total_coins.to_string(),
+            header_json["total_coins"].as_str().unwrap(),
+            "{case}: total_coins"
+        );
+        for (field, value) in [
+            ("parent_hash", &header.parent_hash),
+            ("transaction_hash", &header.transaction_hash),
+            ("account_hash", &header.account_hash),
+        ] {
+            assert_eq!(
+                value,
+                header_json[field].as_str().unwrap(),
+                "{case}: {field}"
+            );
+        }
+        for (field, value) in [
+            ("parent_close_time", header.parent_close_time as u64),
+            ("close_time", header.close_time as u64),
+            ("close_time_resolution", header.close_time_resolution as u64),
+            ("close_flags", header.close_flags as u64),
+        ] {
+            assert_eq!(
+                value,
+                header_json[field].as_u64().unwrap(),
+                "{case}: {field}"
+            );
+        }
+        assert_eq!(
+            hex::encode_upper(header.to_bytes().unwrap()),
+            header_hex,
+            "{case}: round trip"
+        );
+        assert_eq!(
+            hash_ledger_header(&header).unwrap(),
+            header_json["hash"].as_str().unwrap(),
+            "{case}: hash"
+        );
+    }
+}
@@ -1 +1,88 @@
+#[cfg(all(feature = "websocket", not(feature = "std")))]
+mod embedded_websocket {
+    use core::net::{IpAddr, SocketAddr};
+    use std::io;
 
+    use embedded_io_adapters::tokio_1::FromTokio;
+    use embedded_nal_async::{AddrType, Dns, TcpConnect};
+    use rand::rngs::OsRng;
+    use tokio::net::TcpStream;
+    use xrpl::{
+        asynch::clients::{AsyncWebSocketClient, NoVerify, SingleExecutorMutex, XRPLAsyncClient},
+        models::{
+            requests::server_info::ServerInfo, results::server_info::ServerInfo as ServerInfoResult,
+        },
+    };
+
+    struct TokioTcp;
+
+    impl TcpConnect for TokioTcp {
+        type Error = io::Error;
+        type Connection<'a> = FromTokio<TcpStream>;
+
+        async fn connect<'a>(&'a self, remote: SocketAddr) -> io::Result<Self::Connection<'a>> {
+            Ok(FromTokio::new(TcpStream::connect(remote).await?))
+        }
+    }
+
+    struct TokioDns;
+
+    impl Dns for TokioDns {
+        type Error = io::Error;
+
+        async fn get_host_by_name(&self, host: &str, _: AddrType) -> io::Result<IpAddr> {
+            tokio::net::lookup_host((host, 0))
+                .await?
+                .next()
+                .map(|address| address.ip())
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, host))
+        }
+
+        async fn get_host_by_address(&self, _: IpAddr, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::Unsupported.into())
+        }
+    }
+
+    #[tokio::test]
+    #[ignore = "connects to the public testnet"]
+    async fn test_connect_wss_testnet() {
+        let mut tls_read_buffer = [0; 16640];
+        let mut tls_write_buffer = [0; 4096];
+        let client =
+            AsyncWebSocketClient::<4096, _, _, SingleExecutorMutex, _>::connect::<NoVerify, _, _>(
+                "wss://s.altnet.rippletest.net:51233",
+                &TokioTcp,
+                &TokioDns,
+                OsRng,
+                &mut tls_read_buffer,
+                &mut tls_write_buffer,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let response = client.request(ServerInfo::new(None).into()).await.unwrap();
+        let server_info = response.try_into_result::<ServerInfoResult>().unwrap();
+        assert!(!server_info.info.build_version.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_connect_errors() {
+        let mut tls_read_buffer = [0; 16640];
+        let mut tls_write_buffer = [0; 4096];
+        let error =
+            AsyncWebSocketClient::<4096, _, _, SingleExecutorMutex, _>::connect::<NoVerify, _, _>(
+                "https://s.altnet.rippletest.net:51233",
+                &TokioTcp,
+                &TokioDns,
+                OsRng,
+                &mut tls_read_buffer,
+                &mut tls_write_buffer,
+                None,
+            )
+            .await
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("scheme"));
+    }
+}